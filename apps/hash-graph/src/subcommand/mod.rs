@@ -1,6 +1,7 @@
 mod completions;
 mod migrate;
 mod reindex_cache;
+mod replay_queries;
 mod server;
 mod snapshot;
 #[cfg(feature = "test-server")]
@@ -18,6 +19,7 @@ pub use self::test_server::{TestServerArgs, test_server};
 pub use self::{
     completions::{CompletionsArgs, completions},
     migrate::{MigrateArgs, migrate},
+    replay_queries::{ReplayQueriesArgs, replay_queries},
     server::{ServerArgs, server},
     snapshot::{SnapshotArgs, snapshot},
     type_fetcher::{TypeFetcherArgs, type_fetcher},
@@ -45,6 +47,12 @@ pub enum Subcommand {
     /// This is only needed if the backend was changed in an uncommon way such as schemas being
     /// updated in place. This is a rare operation and should be avoided if possible.
     ReindexCache(ReindexCacheArgs),
+    /// Replays a query log against a running Graph.
+    ///
+    /// Queries are logged by running the server with `--log-queries`. The report contains the
+    /// latency percentiles of every endpoint and the number of results of every query, which can
+    /// be compared against the report of a previous replay.
+    ReplayQueries(ReplayQueriesArgs),
     /// Test server
     #[cfg(feature = "test-server")]
     TestServer(TestServerArgs),
@@ -79,6 +87,7 @@ impl Subcommand {
             }
             Self::Snapshot(args) => block_on(snapshot(args), tracing_config),
             Self::ReindexCache(args) => block_on(reindex_cache(args), tracing_config),
+            Self::ReplayQueries(args) => block_on(replay_queries(args), tracing_config),
             #[cfg(feature = "test-server")]
             Self::TestServer(args) => block_on(test_server(args), tracing_config),
         }
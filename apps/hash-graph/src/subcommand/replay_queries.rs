@@ -0,0 +1,1018 @@
+use alloc::collections::BTreeMap;
+use core::{num::NonZero, time::Duration};
+use std::{
+    fs::File,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use clap::Parser;
+use error_stack::{Report, ResultExt as _};
+use futures::{StreamExt as _, stream};
+use reqwest::{
+    Client, Method, Url,
+    header::{CONTENT_TYPE, HeaderName},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use tokio::time::{Instant, sleep_until};
+
+use crate::{error::GraphError, subcommand::server::HttpAddress};
+
+const ACTOR_HEADER: HeaderName = HeaderName::from_static("x-authenticated-user-actor-id");
+
+#[derive(Debug, Parser)]
+pub struct ReplayQueriesArgs {
+    /// The query log to replay as written by `hash-graph server --log-queries`.
+    pub log_file: PathBuf,
+
+    /// The address of the Graph the queries are replayed against.
+    #[clap(flatten)]
+    pub http_address: HttpAddress,
+
+    /// The maximum number of queries in flight at the same time.
+    #[clap(long, default_value = "8")]
+    pub concurrency: NonZero<usize>,
+
+    /// Replays the queries with their original pacing sped up by this factor.
+    ///
+    /// A factor of `1` replays the queries at the rate they were logged, `2` twice as fast. If
+    /// not specified, the queries are sent as fast as the concurrency allows.
+    #[clap(long, value_parser = parse_speed_factor)]
+    pub speed_factor: Option<f64>,
+
+    /// Writes the report to this file instead of stdout.
+    #[clap(long)]
+    pub report: Option<PathBuf>,
+
+    /// A report of a previous replay to compare the result counts against.
+    #[clap(long)]
+    pub baseline: Option<PathBuf>,
+}
+
+fn parse_speed_factor(value: &str) -> Result<f64, String> {
+    let speed_factor = value
+        .parse::<f64>()
+        .map_err(|error| format!("`{value}` is not a number: {error}"))?;
+    if speed_factor.is_finite() && speed_factor > 0.0 {
+        Ok(speed_factor)
+    } else {
+        Err("the speed factor has to be a positive number".to_owned())
+    }
+}
+
+/// A single record of the query log.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LoggedQuery {
+    #[serde(skip)]
+    line: usize,
+    endpoint: String,
+    #[serde(default)]
+    query: JsonValue,
+    actor: String,
+    /// The time in milliseconds the query originally took.
+    elapsed: Option<u64>,
+    /// The UNIX timestamp in milliseconds the query was originally received at.
+    started_at: Option<u64>,
+}
+
+impl LoggedQuery {
+    /// Returns the method and the path segments of the REST endpoint which was logged.
+    ///
+    /// Returns `None` if the endpoint is unknown or the logged query is missing a path parameter.
+    fn route(&self) -> Option<(Method, Vec<&str>)> {
+        let parameter = |key: &str| self.query.get(key).and_then(JsonValue::as_str);
+
+        Some(match self.endpoint.as_str() {
+            "checkAccountGroupPermission" => (Method::GET, vec![
+                "account_groups",
+                parameter("account_group_id")?,
+                "permissions",
+                parameter("permission")?,
+            ]),
+            "getDataTypes" => (Method::POST, vec!["data-types", "query"]),
+            "getDataTypeSubgraph" => (Method::POST, vec!["data-types", "query", "subgraph"]),
+            "getDataTypeAuthorizationRelationships" => (Method::GET, vec![
+                "data-types",
+                parameter("data_type_id")?,
+                "relationships",
+            ]),
+            "checkDataTypePermission" => (Method::GET, vec![
+                "data-types",
+                parameter("data_type_id")?,
+                "permissions",
+                parameter("permission")?,
+            ]),
+            "getPropertyTypes" => (Method::POST, vec!["property-types", "query"]),
+            "getPropertyTypeSubgraph" => {
+                (Method::POST, vec!["property-types", "query", "subgraph"])
+            }
+            "getPropertyTypeAuthorizationRelationships" => (Method::GET, vec![
+                "property-types",
+                parameter("property_type_id")?,
+                "relationships",
+            ]),
+            "checkPropertyTypePermission" => (Method::GET, vec![
+                "property-types",
+                parameter("property_type_id")?,
+                "permissions",
+                parameter("permission")?,
+            ]),
+            "getEntityTypes" => (Method::POST, vec!["entity-types", "query"]),
+            "getClosedMultiEntityTypes" => (Method::POST, vec!["entity-types", "query", "multi"]),
+            "getEntityTypeSubgraph" => (Method::POST, vec!["entity-types", "query", "subgraph"]),
+            "getEntityTypeAuthorizationRelationships" => (Method::GET, vec![
+                "entity-types",
+                parameter("entity_type_id")?,
+                "relationships",
+            ]),
+            "checkEntityTypePermission" => (Method::GET, vec![
+                "entity-types",
+                parameter("entity_type_id")?,
+                "permissions",
+                parameter("permission")?,
+            ]),
+            "getEntities" => (Method::POST, vec!["entities", "query"]),
+            "getEntitiesByHashQl" => (Method::POST, vec!["entities", "query", "hashql"]),
+            "countEntities" => (Method::POST, vec!["entities", "query", "count"]),
+            "aggregateEntities" => (Method::POST, vec!["entities", "query", "aggregate"]),
+            "getEntitySubgraph" => (Method::POST, vec!["entities", "query", "subgraph"]),
+            "validateEntity" => (Method::POST, vec!["entities", "validate"]),
+            "diffEntity" => (Method::POST, vec!["entities", "diff"]),
+            "getEntityAuthorizationRelationships" => (Method::GET, vec![
+                "entities",
+                parameter("entity_id")?,
+                "relationships",
+            ]),
+            "checkEntityPermission" => (Method::GET, vec![
+                "entities",
+                parameter("entity_id")?,
+                "permissions",
+                parameter("permission")?,
+            ]),
+            _ => return None,
+        })
+    }
+}
+
+/// Derives the number of results from the response of an endpoint.
+///
+/// Permission checks are counted as `1` if the permission is granted and `0` otherwise.
+fn result_count(response: &JsonValue) -> Option<u64> {
+    match response {
+        JsonValue::Array(values) => u64::try_from(values.len()).ok(),
+        JsonValue::Number(number) => number.as_u64(),
+        JsonValue::Object(object) => {
+            if let Some(JsonValue::Bool(has_permission)) = object.get("has_permission") {
+                return Some(u64::from(*has_permission));
+            }
+
+            [
+                "entities",
+                "dataTypes",
+                "propertyTypes",
+                "entityTypes",
+                "groups",
+            ]
+            .into_iter()
+            .find_map(|key| object.get(key)?.as_array())
+            .or_else(|| object.get("subgraph")?.get("roots")?.as_array())
+            .and_then(|values| u64::try_from(values.len()).ok())
+        }
+        _ => None,
+    }
+}
+
+struct ReplayOutcome {
+    line: usize,
+    endpoint: String,
+    latency: Duration,
+    original_latency: Option<Duration>,
+    result_count: Result<Option<u64>, Report<GraphError>>,
+}
+
+async fn replay_query(client: &Client, base_url: &Url, query: LoggedQuery) -> ReplayOutcome {
+    let start = Instant::now();
+    let result_count = send_query(client, base_url, &query).await;
+
+    ReplayOutcome {
+        line: query.line,
+        endpoint: query.endpoint,
+        latency: start.elapsed(),
+        original_latency: query.elapsed.map(Duration::from_millis),
+        result_count,
+    }
+}
+
+async fn send_query(
+    client: &Client,
+    base_url: &Url,
+    query: &LoggedQuery,
+) -> Result<Option<u64>, Report<GraphError>> {
+    let (method, segments) = query
+        .route()
+        .ok_or(GraphError)
+        .attach_printable_lazy(|| format!("unsupported endpoint `{}`", query.endpoint))?;
+
+    let mut url = base_url.clone();
+    url.path_segments_mut()
+        .map_err(|()| GraphError)
+        .attach_printable("base URL cannot have path segments")?
+        .pop_if_empty()
+        .extend(segments);
+
+    let mut request = client
+        .request(method.clone(), url)
+        .header(ACTOR_HEADER, &query.actor);
+    if method == Method::POST {
+        request = request
+            .header(CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&query.query).change_context(GraphError)?);
+    }
+
+    let response = request.send().await.change_context(GraphError)?;
+    let status = response.status();
+    let body = response.bytes().await.change_context(GraphError)?;
+    if !status.is_success() {
+        return Err(Report::new(GraphError).attach_printable(format!(
+            "request failed with status {status}: {}",
+            String::from_utf8_lossy(&body)
+        )));
+    }
+
+    if body.is_empty() {
+        return Ok(None);
+    }
+    let response = serde_json::from_slice::<JsonValue>(&body).change_context(GraphError)?;
+    Ok(result_count(&response))
+}
+
+/// Latency percentiles in milliseconds.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LatencyPercentiles {
+    p50: f64,
+    p90: f64,
+    p99: f64,
+    max: f64,
+}
+
+impl LatencyPercentiles {
+    fn from_latencies(mut latencies: Vec<Duration>) -> Option<Self> {
+        latencies.sort_unstable();
+        let max = *latencies.last()?;
+
+        // Nearest-rank method, the rank is at least one as `latencies` is not empty.
+        let percentile =
+            |percent: usize| latencies[(latencies.len() * percent).div_ceil(100).saturating_sub(1)];
+
+        Some(Self {
+            p50: as_millis(percentile(50)),
+            p90: as_millis(percentile(90)),
+            p99: as_millis(percentile(99)),
+            max: as_millis(max),
+        })
+    }
+}
+
+#[expect(
+    clippy::float_arithmetic,
+    reason = "Sub-millisecond latencies should not be rounded"
+)]
+fn as_millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EndpointReport {
+    queries: usize,
+    errors: usize,
+    latency: Option<LatencyPercentiles>,
+    original_latency: Option<LatencyPercentiles>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResultCountMismatch {
+    line: usize,
+    endpoint: String,
+    baseline: Option<u64>,
+    replay: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReplayReport {
+    endpoints: BTreeMap<String, EndpointReport>,
+    /// The number of results of every successfully replayed query keyed by its line in the log.
+    result_counts: BTreeMap<usize, Option<u64>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    mismatches: Vec<ResultCountMismatch>,
+}
+
+/// The part of a previous [`ReplayReport`] which is used as baseline.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BaselineReport {
+    result_counts: BTreeMap<usize, Option<u64>>,
+}
+
+impl ReplayReport {
+    fn new(outcomes: Vec<ReplayOutcome>) -> Self {
+        let mut latencies = BTreeMap::<_, (EndpointReport, Vec<_>, Vec<_>)>::new();
+        let mut result_counts = BTreeMap::new();
+
+        for outcome in outcomes {
+            let (report, replay_latencies, original_latencies) = latencies
+                .entry(outcome.endpoint.clone())
+                .or_insert_with(|| {
+                    (
+                        EndpointReport {
+                            queries: 0,
+                            errors: 0,
+                            latency: None,
+                            original_latency: None,
+                        },
+                        Vec::new(),
+                        Vec::new(),
+                    )
+                });
+            report.queries += 1;
+            original_latencies.extend(outcome.original_latency);
+
+            match outcome.result_count {
+                Ok(count) => {
+                    replay_latencies.push(outcome.latency);
+                    result_counts.insert(outcome.line, count);
+                }
+                Err(report_error) => {
+                    report.errors += 1;
+                    tracing::warn!(
+                        line = outcome.line,
+                        endpoint = %outcome.endpoint,
+                        error = ?report_error,
+                        "Could not replay query"
+                    );
+                }
+            }
+        }
+
+        Self {
+            endpoints: latencies
+                .into_iter()
+                .map(|(endpoint, (mut report, replay, original))| {
+                    report.latency = LatencyPercentiles::from_latencies(replay);
+                    report.original_latency = LatencyPercentiles::from_latencies(original);
+                    (endpoint, report)
+                })
+                .collect(),
+            result_counts,
+            mismatches: Vec::new(),
+        }
+    }
+
+    fn compare_with(&mut self, baseline: &BaselineReport, endpoints: &BTreeMap<usize, String>) {
+        self.mismatches = self
+            .result_counts
+            .iter()
+            .filter_map(|(line, &replay)| {
+                let &expected = baseline.result_counts.get(line)?;
+                (expected != replay).then(|| ResultCountMismatch {
+                    line: *line,
+                    endpoint: endpoints.get(line).cloned().unwrap_or_default(),
+                    baseline: expected,
+                    replay,
+                })
+            })
+            .collect();
+
+        for mismatch in &self.mismatches {
+            tracing::warn!(
+                line = mismatch.line,
+                endpoint = %mismatch.endpoint,
+                baseline = mismatch.baseline,
+                replay = mismatch.replay,
+                "Result count differs from baseline"
+            );
+        }
+    }
+}
+
+async fn read_query_log(path: &Path) -> Result<Vec<LoggedQuery>, Report<GraphError>> {
+    let log = tokio::fs::read_to_string(path)
+        .await
+        .change_context(GraphError)
+        .attach_printable_lazy(|| format!("could not read query log `{}`", path.display()))?;
+
+    log.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let mut query = serde_json::from_str::<LoggedQuery>(line)
+                .change_context(GraphError)
+                .attach_printable_lazy(|| format!("invalid query in line {}", index + 1))?;
+            query.line = index + 1;
+            Ok(query)
+        })
+        .collect()
+}
+
+/// Replays a query log against a running Graph.
+///
+/// # Errors
+///
+/// Returns an error if the query log or the baseline cannot be read or the report cannot be
+/// written. Failing queries are reported but do not abort the replay.
+pub async fn replay_queries(args: ReplayQueriesArgs) -> Result<(), Report<GraphError>> {
+    let mut queries = read_query_log(&args.log_file).await?;
+    // The log is written when a query finishes, so it's reordered by the time it was received.
+    queries.sort_by_key(|query| query.started_at);
+    let endpoints = queries
+        .iter()
+        .map(|query| (query.line, query.endpoint.clone()))
+        .collect::<BTreeMap<_, _>>();
+
+    let baseline = args
+        .baseline
+        .as_ref()
+        .map(|path| {
+            File::open(path)
+                .map(io::BufReader::new)
+                .change_context(GraphError)
+                .and_then(|reader| {
+                    serde_json::from_reader::<_, BaselineReport>(reader).change_context(GraphError)
+                })
+                .attach_printable_lazy(|| format!("could not read baseline `{}`", path.display()))
+        })
+        .transpose()?;
+
+    let base_url =
+        Url::parse(&format!("http://{}", args.http_address)).change_context(GraphError)?;
+    let client = Client::new();
+
+    tracing::info!(queries = queries.len(), %base_url, "Replaying queries");
+    let first_started_at = queries.iter().filter_map(|query| query.started_at).min();
+    let replay_start = Instant::now();
+    let outcomes = stream::iter(queries)
+        .map(|query| {
+            let offset = args
+                .speed_factor
+                .zip(first_started_at)
+                .zip(query.started_at)
+                .map(|((speed_factor, first_started_at), started_at)| {
+                    Duration::from_millis(started_at.saturating_sub(first_started_at))
+                        .div_f64(speed_factor)
+                });
+            let (client, base_url) = (&client, &base_url);
+
+            async move {
+                if let Some(offset) = offset {
+                    sleep_until(replay_start + offset).await;
+                }
+                replay_query(client, base_url, query).await
+            }
+        })
+        .buffer_unordered(args.concurrency.get())
+        .collect::<Vec<_>>()
+        .await;
+    tracing::info!(elapsed = ?replay_start.elapsed(), "Replayed queries");
+
+    let mut report = ReplayReport::new(outcomes);
+    if let Some(baseline) = &baseline {
+        report.compare_with(baseline, &endpoints);
+    }
+
+    for (endpoint, endpoint_report) in &report.endpoints {
+        tracing::info!(
+            %endpoint,
+            queries = endpoint_report.queries,
+            errors = endpoint_report.errors,
+            latency = ?endpoint_report.latency,
+            original_latency = ?endpoint_report.original_latency,
+            "Endpoint summary"
+        );
+    }
+
+    let mut writer: Box<dyn Write> = if let Some(path) = &args.report {
+        let file = File::create(path)
+            .change_context(GraphError)
+            .attach_printable_lazy(|| format!("could not create report `{}`", path.display()))?;
+        Box::new(io::BufWriter::new(file))
+    } else {
+        Box::new(io::stdout().lock())
+    };
+    serde_json::to_writer_pretty(&mut writer, &report).change_context(GraphError)?;
+    writer.flush().change_context(GraphError)
+}
+
+#[cfg(test)]
+mod tests {
+    use hash_graph_api::rest::{OpenApiDocumentation, OpenApiQuery};
+    use hash_graph_authorization::schema::{
+        AccountGroupPermission, DataTypePermission, EntityPermission, EntityTypePermission,
+        PropertyTypePermission,
+    };
+    use hash_graph_store::entity::DiffEntityParams;
+    use hash_graph_types::{account::AccountGroupId, knowledge::entity::EntityId};
+    use serde_json::json;
+    use type_system::url::VersionedUrl;
+    use utoipa::OpenApi as _;
+
+    use super::*;
+
+    const ACTOR_ID: &str = "0d3d2b33-3c0a-4a94-8d11-8fa3d4e5d6f7";
+
+    fn versioned_url(kind: &str, name: &str) -> VersionedUrl {
+        serde_json::from_value(json!(format!(
+            "https://blockprotocol.org/@alice/types/{kind}/{name}/v/1"
+        )))
+        .expect("URL should be valid")
+    }
+
+    fn entity_id() -> EntityId {
+        serde_json::from_value(json!(
+            "a2ed6e70-4a4c-4f2a-9a0f-8b9bfb3b3f0e~5f1e2d3c-4b5a-4978-8695-a4b3c2d1e0f9"
+        ))
+        .expect("entity ID should be valid")
+    }
+
+    /// Writes the query to a log record the same way as the query logger of the server does and
+    /// reads it back.
+    fn log(query: OpenApiQuery<'_>) -> LoggedQuery {
+        let mut record = serde_json::to_value(query).expect("query should be serializable");
+        let object = record.as_object_mut().expect("record should be an object");
+        object.insert("actor".to_owned(), json!(ACTOR_ID));
+        object.insert("startedAt".to_owned(), json!(1_700_000_000_000_u64));
+        object.insert("elapsed".to_owned(), json!(12));
+
+        serde_json::from_value(record).expect("record should be a valid logged query")
+    }
+
+    /// Asserts that the logged query is replayed against `path`, which has to be a path of the REST
+    /// API served for `method`.
+    ///
+    /// Path parameters in `path` are written in the same `{name}` syntax as in the router.
+    #[track_caller]
+    fn assert_route(query: OpenApiQuery<'_>, method: &Method, path: &str) {
+        let openapi =
+            serde_json::to_value(OpenApiDocumentation::openapi()).expect("spec should serialize");
+        assert!(
+            openapi["paths"][path]
+                .get(method.as_str().to_lowercase())
+                .is_some(),
+            "`{method} {path}` is not served by the REST API"
+        );
+
+        let logged = log(query);
+        let (route_method, segments) = logged.route().expect("endpoint should be replayable");
+        assert_eq!(route_method, *method);
+
+        let expected = path.trim_start_matches('/').split('/').collect::<Vec<_>>();
+        assert_eq!(
+            segments.len(),
+            expected.len(),
+            "{segments:?} does not match `{path}`"
+        );
+        for (segment, expected) in segments.iter().zip(expected) {
+            if let Some(parameter) = expected
+                .strip_prefix('{')
+                .and_then(|parameter| parameter.strip_suffix('}'))
+            {
+                assert_eq!(
+                    Some(*segment),
+                    logged.query[parameter].as_str(),
+                    "path parameter `{parameter}` does not match"
+                );
+            } else {
+                assert_eq!(*segment, expected, "{segments:?} does not match `{path}`");
+            }
+        }
+    }
+
+    #[test]
+    fn route_check_account_group_permission() {
+        let account_group_id: AccountGroupId =
+            serde_json::from_value(json!("5f1e2d3c-4b5a-4978-8695-a4b3c2d1e0f9"))
+                .expect("account group ID should be valid");
+        assert_route(
+            OpenApiQuery::CheckAccountGroupPermission {
+                account_group_id,
+                permission: AccountGroupPermission::AddMember,
+            },
+            &Method::GET,
+            "/account_groups/{account_group_id}/permissions/{permission}",
+        );
+    }
+
+    #[test]
+    fn route_get_data_types() {
+        assert_route(
+            OpenApiQuery::GetDataTypes(&json!({})),
+            &Method::POST,
+            "/data-types/query",
+        );
+    }
+
+    #[test]
+    fn route_get_data_type_subgraph() {
+        assert_route(
+            OpenApiQuery::GetDataTypeSubgraph(&json!({})),
+            &Method::POST,
+            "/data-types/query/subgraph",
+        );
+    }
+
+    #[test]
+    fn route_get_data_type_authorization_relationships() {
+        assert_route(
+            OpenApiQuery::GetDataTypeAuthorizationRelationships {
+                data_type_id: &versioned_url("data-type", "text"),
+            },
+            &Method::GET,
+            "/data-types/{data_type_id}/relationships",
+        );
+    }
+
+    #[test]
+    fn route_check_data_type_permission() {
+        assert_route(
+            OpenApiQuery::CheckDataTypePermission {
+                data_type_id: &versioned_url("data-type", "text"),
+                permission: DataTypePermission::View,
+            },
+            &Method::GET,
+            "/data-types/{data_type_id}/permissions/{permission}",
+        );
+    }
+
+    #[test]
+    fn route_get_property_types() {
+        assert_route(
+            OpenApiQuery::GetPropertyTypes(&json!({})),
+            &Method::POST,
+            "/property-types/query",
+        );
+    }
+
+    #[test]
+    fn route_get_property_type_subgraph() {
+        assert_route(
+            OpenApiQuery::GetPropertyTypeSubgraph(&json!({})),
+            &Method::POST,
+            "/property-types/query/subgraph",
+        );
+    }
+
+    #[test]
+    fn route_get_property_type_authorization_relationships() {
+        assert_route(
+            OpenApiQuery::GetPropertyTypeAuthorizationRelationships {
+                property_type_id: &versioned_url("property-type", "name"),
+            },
+            &Method::GET,
+            "/property-types/{property_type_id}/relationships",
+        );
+    }
+
+    #[test]
+    fn route_check_property_type_permission() {
+        assert_route(
+            OpenApiQuery::CheckPropertyTypePermission {
+                property_type_id: &versioned_url("property-type", "name"),
+                permission: PropertyTypePermission::Update,
+            },
+            &Method::GET,
+            "/property-types/{property_type_id}/permissions/{permission}",
+        );
+    }
+
+    #[test]
+    fn route_get_entity_types() {
+        assert_route(
+            OpenApiQuery::GetEntityTypes(&json!({})),
+            &Method::POST,
+            "/entity-types/query",
+        );
+    }
+
+    #[test]
+    fn route_get_closed_multi_entity_types() {
+        assert_route(
+            OpenApiQuery::GetClosedMultiEntityTypes(&json!({})),
+            &Method::POST,
+            "/entity-types/query/multi",
+        );
+    }
+
+    #[test]
+    fn route_get_entity_type_subgraph() {
+        assert_route(
+            OpenApiQuery::GetEntityTypeSubgraph(&json!({})),
+            &Method::POST,
+            "/entity-types/query/subgraph",
+        );
+    }
+
+    #[test]
+    fn route_get_entity_type_authorization_relationships() {
+        assert_route(
+            OpenApiQuery::GetEntityTypeAuthorizationRelationships {
+                entity_type_id: &versioned_url("entity-type", "person"),
+            },
+            &Method::GET,
+            "/entity-types/{entity_type_id}/relationships",
+        );
+    }
+
+    #[test]
+    fn route_check_entity_type_permission() {
+        assert_route(
+            OpenApiQuery::CheckEntityTypePermission {
+                entity_type_id: &versioned_url("entity-type", "person"),
+                permission: EntityTypePermission::Instantiate,
+            },
+            &Method::GET,
+            "/entity-types/{entity_type_id}/permissions/{permission}",
+        );
+    }
+
+    #[test]
+    fn route_get_entities() {
+        assert_route(
+            OpenApiQuery::GetEntities(&json!({})),
+            &Method::POST,
+            "/entities/query",
+        );
+    }
+
+    #[test]
+    fn route_get_entities_by_hash_ql() {
+        assert_route(
+            OpenApiQuery::GetEntitiesByHashQl(&json!({})),
+            &Method::POST,
+            "/entities/query/hashql",
+        );
+    }
+
+    #[test]
+    fn route_count_entities() {
+        assert_route(
+            OpenApiQuery::CountEntities(&json!({})),
+            &Method::POST,
+            "/entities/query/count",
+        );
+    }
+
+    #[test]
+    fn route_aggregate_entities() {
+        assert_route(
+            OpenApiQuery::AggregateEntities(&json!({})),
+            &Method::POST,
+            "/entities/query/aggregate",
+        );
+    }
+
+    #[test]
+    fn route_get_entity_subgraph() {
+        assert_route(
+            OpenApiQuery::GetEntitySubgraph(&json!({})),
+            &Method::POST,
+            "/entities/query/subgraph",
+        );
+    }
+
+    #[test]
+    fn route_validate_entity() {
+        assert_route(
+            OpenApiQuery::ValidateEntity(&json!({})),
+            &Method::POST,
+            "/entities/validate",
+        );
+    }
+
+    #[test]
+    fn route_diff_entity() {
+        let params = DiffEntityParams {
+            first_entity_id: entity_id(),
+            first_decision_time: None,
+            first_transaction_time: None,
+            second_entity_id: entity_id(),
+            second_decision_time: None,
+            second_transaction_time: None,
+        };
+        assert_route(
+            OpenApiQuery::DiffEntity(&params),
+            &Method::POST,
+            "/entities/diff",
+        );
+    }
+
+    #[test]
+    fn route_get_entity_authorization_relationships() {
+        assert_route(
+            OpenApiQuery::GetEntityAuthorizationRelationships {
+                entity_id: entity_id(),
+            },
+            &Method::GET,
+            "/entities/{entity_id}/relationships",
+        );
+    }
+
+    #[test]
+    fn route_check_entity_permission() {
+        assert_route(
+            OpenApiQuery::CheckEntityPermission {
+                entity_id: entity_id(),
+                permission: EntityPermission::FullAccess,
+            },
+            &Method::GET,
+            "/entities/{entity_id}/permissions/{permission}",
+        );
+    }
+
+    #[test]
+    fn route_rejects_unknown_endpoints_and_missing_parameters() {
+        let query = |endpoint: &str, query: JsonValue| LoggedQuery {
+            line: 1,
+            endpoint: endpoint.to_owned(),
+            query,
+            actor: ACTOR_ID.to_owned(),
+            elapsed: None,
+            started_at: None,
+        };
+
+        assert!(query("createEntity", json!({})).route().is_none());
+        assert!(
+            query("checkEntityPermission", json!({ "permission": "view" }))
+                .route()
+                .is_none()
+        );
+        assert!(
+            query(
+                "getEntityAuthorizationRelationships",
+                json!({ "entity_id": 5 })
+            )
+            .route()
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn result_count_of_lists_and_numbers() {
+        assert_eq!(result_count(&json!([1, 2, 3])), Some(3));
+        assert_eq!(result_count(&json!([])), Some(0));
+        assert_eq!(result_count(&json!(42)), Some(42));
+        assert_eq!(result_count(&json!(-1)), None);
+        assert_eq!(result_count(&json!("text")), None);
+        assert_eq!(result_count(&JsonValue::Null), None);
+    }
+
+    #[test]
+    fn result_count_of_permissions() {
+        assert_eq!(result_count(&json!({ "has_permission": true })), Some(1));
+        assert_eq!(result_count(&json!({ "has_permission": false })), Some(0));
+    }
+
+    #[test]
+    fn result_count_of_query_responses() {
+        assert_eq!(result_count(&json!({ "entities": [{}, {}] })), Some(2));
+        assert_eq!(result_count(&json!({ "dataTypes": [{}] })), Some(1));
+        assert_eq!(result_count(&json!({ "propertyTypes": [] })), Some(0));
+        assert_eq!(
+            result_count(&json!({ "entityTypes": [{}, {}, {}], "cursor": null })),
+            Some(3)
+        );
+        assert_eq!(result_count(&json!({ "groups": [{}] })), Some(1));
+        assert_eq!(
+            result_count(&json!({ "subgraph": { "roots": [{}, {}], "vertices": {} } })),
+            Some(2)
+        );
+        assert_eq!(result_count(&json!({ "subgraph": {} })), None);
+        assert_eq!(result_count(&json!({ "count": 5 })), None);
+    }
+
+    fn milliseconds(values: impl IntoIterator<Item = u64>) -> Vec<Duration> {
+        values.into_iter().map(Duration::from_millis).collect()
+    }
+
+    /// Asserts the `p50`, `p90`, `p99`, and `max` latencies in milliseconds.
+    #[track_caller]
+    #[expect(
+        clippy::float_arithmetic,
+        reason = "Converted latencies are not exact and have to be compared with a tolerance"
+    )]
+    fn assert_percentiles(percentiles: &LatencyPercentiles, expected: [u32; 4]) {
+        let actual = [
+            percentiles.p50,
+            percentiles.p90,
+            percentiles.p99,
+            percentiles.max,
+        ];
+        assert!(
+            actual
+                .iter()
+                .zip(expected)
+                .all(|(actual, expected)| (actual - f64::from(expected)).abs() < 1e-6),
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+
+    #[test]
+    fn percentiles_of_no_latencies() {
+        assert!(LatencyPercentiles::from_latencies(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn percentiles_of_single_latency() {
+        let percentiles = LatencyPercentiles::from_latencies(milliseconds([7]))
+            .expect("latencies should not be empty");
+
+        assert_percentiles(&percentiles, [7; 4]);
+    }
+
+    #[test]
+    fn percentiles_use_nearest_rank() {
+        // The ranks are the smallest ones covering the percentage: 5, 9, and 10 of 10 values.
+        let percentiles =
+            LatencyPercentiles::from_latencies(milliseconds([10, 1, 9, 2, 8, 3, 7, 4, 6, 5]))
+                .expect("latencies should not be empty");
+
+        assert_percentiles(&percentiles, [5, 9, 10, 10]);
+
+        let percentiles = LatencyPercentiles::from_latencies(milliseconds((1..=200).rev()))
+            .expect("latencies should not be empty");
+
+        assert_percentiles(&percentiles, [100, 180, 198, 200]);
+    }
+
+    fn outcome(line: usize, result_count: Result<Option<u64>, ()>) -> ReplayOutcome {
+        ReplayOutcome {
+            line,
+            endpoint: "getEntities".to_owned(),
+            latency: Duration::from_millis(1),
+            original_latency: None,
+            result_count: result_count.map_err(|()| Report::new(GraphError)),
+        }
+    }
+
+    #[test]
+    fn compare_with_baseline() {
+        let mut report = ReplayReport::new(vec![
+            outcome(1, Ok(Some(3))),
+            outcome(2, Ok(Some(4))),
+            outcome(3, Ok(None)),
+            outcome(4, Ok(Some(1))),
+            outcome(5, Err(())),
+            outcome(6, Ok(Some(2))),
+        ]);
+        let baseline = BaselineReport {
+            result_counts: BTreeMap::from([
+                (1, Some(3)),
+                (2, Some(5)),
+                (3, Some(0)),
+                (4, None),
+                (5, Some(1)),
+            ]),
+        };
+        let endpoints = BTreeMap::from([
+            (1, "getEntities".to_owned()),
+            (2, "getEntities".to_owned()),
+            (3, "countEntities".to_owned()),
+        ]);
+
+        report.compare_with(&baseline, &endpoints);
+
+        // Line 5 failed and line 6 is not part of the baseline, so neither can differ.
+        let mismatches = report
+            .mismatches
+            .iter()
+            .map(|mismatch| {
+                (
+                    mismatch.line,
+                    mismatch.endpoint.as_str(),
+                    mismatch.baseline,
+                    mismatch.replay,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(mismatches, [
+            (2, "getEntities", Some(5), Some(4)),
+            (3, "countEntities", Some(0), None),
+            (4, "", None, Some(1)),
+        ]);
+    }
+
+    #[test]
+    fn compare_with_matching_baseline() {
+        let mut report = ReplayReport::new(vec![outcome(1, Ok(Some(3))), outcome(2, Ok(None))]);
+        let baseline = BaselineReport {
+            result_counts: BTreeMap::from([(1, Some(3)), (2, None)]),
+        };
+
+        report.compare_with(&baseline, &BTreeMap::new());
+
+        assert!(report.mismatches.is_empty());
+    }
+}
@@ -10,7 +10,7 @@ use std::path::PathBuf;
 use clap::Parser;
 use error_stack::{Report, ResultExt as _};
 use futures::{StreamExt as _, channel::mpsc};
-use harpc_codec::json::JsonCodec;
+use harpc_codec::any::AnyCodec;
 use harpc_server::Server;
 use hash_codec::bytes::JsonLinesEncoder;
 use hash_graph_api::{
@@ -198,7 +198,8 @@ where
             store: dependencies.store,
             authorization_api: dependencies.authorization_api,
             temporal_client: dependencies.temporal_client,
-            codec: JsonCodec,
+            domain_validator: dependencies.domain_validator,
+            codec: AnyCodec::default(),
         },
         server.events(),
     );
@@ -337,6 +338,7 @@ pub async fn server(args: ServerArgs) -> Result<(), Report<GraphError>> {
                 store: Arc::clone(&dependencies.store),
                 authorization_api: Arc::clone(&dependencies.authorization_api),
                 temporal_client: temporal_client_fn(args.temporal_host, args.temporal_port).await?,
+                domain_validator: dependencies.domain_regex.clone(),
                 codec: (),
             })?;
         }
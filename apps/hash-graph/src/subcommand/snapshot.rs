@@ -7,7 +7,10 @@ use hash_graph_authorization::{
     zanzibar::ZanzibarClient,
 };
 use hash_graph_postgres_store::{
-    snapshot::{SnapshotDumpSettings, SnapshotEntry, SnapshotStore},
+    snapshot::{
+        SnapshotDumpSettings, SnapshotEntry, SnapshotRestoreSettings, SnapshotStore,
+        validate_snapshot,
+    },
     store::{DatabaseConnectionInfo, DatabasePoolConfig, PostgresStorePool, PostgresStoreSettings},
 };
 use hash_graph_store::pool::StorePool as _;
@@ -60,6 +63,10 @@ pub struct SnapshotDumpArgs {
     pub no_relations: bool,
 }
 
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "This is a configuration struct"
+)]
 #[derive(Debug, Parser)]
 pub struct SnapshotRestoreArgs {
     /// Whether to skip the validation checks.
@@ -73,6 +80,24 @@ pub struct SnapshotRestoreArgs {
     /// Whether to skip the authorization restoring.
     #[clap(long)]
     pub skip_authorization: bool,
+
+    /// Continue a previously interrupted restore from its last checkpoint.
+    ///
+    /// The same snapshot has to be provided again, entries which were already restored are
+    /// skipped.
+    #[clap(long, conflicts_with = "dry_run")]
+    pub resume: bool,
+
+    /// Discard the checkpoint of a previously interrupted restore and start from the beginning.
+    ///
+    /// Without this flag, the restore is refused if a checkpoint exists and `--resume` is not
+    /// given.
+    #[clap(long, conflicts_with_all = ["dry_run", "resume"])]
+    pub restart: bool,
+
+    /// Only validate the snapshot without connecting to or writing into the store.
+    #[clap(long)]
+    pub dry_run: bool,
 }
 
 #[derive(Debug, Parser)]
@@ -109,6 +134,24 @@ pub struct SnapshotArgs {
 pub async fn snapshot(args: SnapshotArgs) -> Result<(), Report<GraphError>> {
     SnapshotEntry::install_error_stack_hook();
 
+    if let SnapshotCommand::Restore(SnapshotRestoreArgs { dry_run: true, .. }) = &args.command {
+        let read = FramedRead::new(io::BufReader::new(io::stdin()), JsonLinesDecoder::default());
+        let checkpoint = validate_snapshot(read)
+            .await
+            .change_context(GraphError)
+            .attach_printable("Failed to validate snapshot")?;
+
+        for (kind, entries) in checkpoint.iter() {
+            tracing::info!(%kind, entries, "Validated snapshot entries");
+        }
+        tracing::info!(
+            entries = checkpoint.total(),
+            "Snapshot validated successfully"
+        );
+
+        return Ok(());
+    }
+
     let mut pool = PostgresStorePool::new(
         &args.db_info,
         &args.pool_config,
@@ -178,6 +221,12 @@ pub async fn snapshot(args: SnapshotArgs) -> Result<(), Report<GraphError>> {
         }
         SnapshotCommand::Restore(args) => {
             pool.settings.validate_links = !args.skip_validation;
+            let settings = SnapshotRestoreSettings {
+                chunk_size: 10_000,
+                ignore_validation_errors: args.ignore_validation_errors,
+                resume: args.resume,
+                restart: args.restart,
+            };
 
             let read =
                 FramedRead::new(io::BufReader::new(io::stdin()), JsonLinesDecoder::default());
@@ -191,7 +240,7 @@ pub async fn snapshot(args: SnapshotArgs) -> Result<(), Report<GraphError>> {
                             report
                         })?,
                 )
-                    .restore_snapshot(read, 10_000, args.ignore_validation_errors)
+                    .restore_snapshot(read, settings)
                     .await
             } else {
                 SnapshotStore::new(pool.acquire(NoAuthorization, None).await
@@ -200,7 +249,7 @@ pub async fn snapshot(args: SnapshotArgs) -> Result<(), Report<GraphError>> {
                         tracing::error!(error = ?report, "Failed to acquire database connection");
                         report
                     })?)
-                    .restore_snapshot(read, 10_000, args.ignore_validation_errors)
+                    .restore_snapshot(read, settings)
                     .await
             }
             .change_context(GraphError)
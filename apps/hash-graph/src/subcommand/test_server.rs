@@ -1,19 +1,27 @@
+use alloc::sync::Arc;
 use core::{net::SocketAddr, time::Duration};
 
+use axum::Router;
 use clap::Parser;
 use error_stack::{Report, ResultExt as _};
+use futures::future;
+use hash_graph_api::rest::{RestRouterDependencies, rest_api_router};
 use hash_graph_authorization::{
     AuthorizationApi as _,
-    backend::{SpiceDbOpenApi, ZanzibarBackend as _},
+    backend::{EmbeddedZanzibar, SpiceDbOpenApi, ZanzibarBackend},
     zanzibar::ZanzibarClient,
 };
+use hash_graph_memory_store::store::{MemoryStorePool, MemoryStoreSettings};
 use hash_graph_postgres_store::{
     snapshot::SnapshotEntry,
     store::{DatabaseConnectionInfo, DatabasePoolConfig, PostgresStorePool, PostgresStoreSettings},
 };
+use hash_graph_type_fetcher::FetchingPool;
+use regex::Regex;
 use reqwest::Client;
-use tokio::{net::TcpListener, time::timeout};
+use tokio::{io, net::TcpListener, task::JoinHandle, time::timeout};
 use tokio_postgres::NoTls;
+use type_system::schema::DomainValidator;
 
 use crate::{
     error::{GraphError, HealthcheckError},
@@ -46,16 +54,48 @@ pub struct TestServerArgs {
     pub timeout: Option<u64>,
 
     /// The host the Spice DB server is listening at.
-    #[clap(long, env = "HASH_SPICEDB_HOST")]
-    pub spicedb_host: String,
+    #[clap(
+        long,
+        env = "HASH_SPICEDB_HOST",
+        required_unless_present = "memory_store"
+    )]
+    pub spicedb_host: Option<String>,
 
     /// The port the Spice DB server is listening at.
-    #[clap(long, env = "HASH_SPICEDB_HTTP_PORT")]
-    pub spicedb_http_port: u16,
+    #[clap(
+        long,
+        env = "HASH_SPICEDB_HTTP_PORT",
+        required_unless_present = "memory_store"
+    )]
+    pub spicedb_http_port: Option<u16>,
 
     /// The secret key used to authenticate with the Spice DB server.
     #[clap(long, env = "HASH_SPICEDB_GRPC_PRESHARED_KEY")]
     pub spicedb_grpc_preshared_key: Option<String>,
+
+    /// Keeps all data in memory instead of connecting to the database.
+    ///
+    /// As the data is not shared with other processes, the test server also serves the REST API
+    /// at `--graph-api-host` and `--graph-api-port`. Permissions are evaluated in-process, so no
+    /// Spice DB server is required. Restoring snapshots is not supported.
+    #[clap(long, default_value_t = false)]
+    pub memory_store: bool,
+
+    /// The host the REST API is listening at when using the in-memory store.
+    #[clap(long, default_value = "127.0.0.1", requires = "memory_store")]
+    pub graph_api_host: String,
+
+    /// The port the REST API is listening at when using the in-memory store.
+    #[clap(long, default_value_t = 4000, requires = "memory_store")]
+    pub graph_api_port: u16,
+
+    /// A regex which *new* Type System URLs are checked against when using the in-memory store.
+    #[clap(
+        long,
+        default_value_t = Regex::new(r"http://localhost:3000/@(?P<shortname>[\w-]+)/types/(?P<kind>(?:data-type)|(?:property-type)|(?:entity-type))/[\w\-_%]+/").unwrap(),
+        env = "HASH_GRAPH_ALLOWED_URL_DOMAIN_PATTERN",
+    )]
+    pub allowed_url_domain: Regex,
 }
 
 pub async fn test_server(args: TestServerArgs) -> Result<(), Report<GraphError>> {
@@ -71,49 +111,127 @@ pub async fn test_server(args: TestServerArgs) -> Result<(), Report<GraphError>>
         .change_context(GraphError);
     }
 
-    let pool = PostgresStorePool::new(
-        &args.db_info,
-        &args.pool_config,
-        NoTls,
-        PostgresStoreSettings::default(),
-    )
-    .await
-    .change_context(GraphError)
-    .map_err(|report| {
-        tracing::error!(error = ?report, "Failed to connect to database");
-        report
-    })?;
-
-    let mut spicedb_client = SpiceDbOpenApi::new(
-        format!("{}:{}", args.spicedb_host, args.spicedb_http_port),
-        args.spicedb_grpc_preshared_key.as_deref(),
-    )
-    .change_context(GraphError)?;
-    spicedb_client
-        .import_schema(include_str!(
-            "../../../../libs/@local/graph/authorization/schemas/v1__initial_schema.zed"
-        ))
-        .await
+    let (router, rest_server) = if args.memory_store {
+        let zanzibar_client = zanzibar_client(EmbeddedZanzibar::new()).await?;
+        let (router, rest_server) = memory_store_router(&args, zanzibar_client).await?;
+        (router, Some(rest_server))
+    } else {
+        let spicedb_client = SpiceDbOpenApi::new(
+            format!(
+                "{}:{}",
+                args.spicedb_host
+                    .as_deref()
+                    .expect("the Spice DB host is required without the in-memory store"),
+                args.spicedb_http_port
+                    .expect("the Spice DB port is required without the in-memory store")
+            ),
+            args.spicedb_grpc_preshared_key.as_deref(),
+        )
         .change_context(GraphError)?;
+        let zanzibar_client = zanzibar_client(spicedb_client).await?;
 
-    let mut zanzibar_client = ZanzibarClient::new(spicedb_client);
-    zanzibar_client.seed().await.change_context(GraphError)?;
+        let pool = PostgresStorePool::new(
+            &args.db_info,
+            &args.pool_config,
+            NoTls,
+            PostgresStoreSettings::default(),
+        )
+        .await
+        .change_context(GraphError)
+        .map_err(|report| {
+            tracing::error!(error = ?report, "Failed to connect to database");
+            report
+        })?;
 
-    let router = hash_graph_test_server::routes(pool, zanzibar_client);
+        (hash_graph_test_server::routes(pool, zanzibar_client), None)
+    };
 
     tracing::info!("Listening on {}", args.http_address);
-    axum::serve(
+    let server = axum::serve(
         TcpListener::bind((args.http_address.api_host, args.http_address.api_port))
             .await
             .change_context(GraphError)?,
         router.into_make_service_with_connect_info::<SocketAddr>(),
-    )
-    .await
-    .expect("failed to start server");
+    );
+
+    if let Some(rest_server) = rest_server {
+        future::try_join(
+            async {
+                server
+                    .await
+                    .change_context(GraphError)
+                    .attach_printable("failed to start server")
+            },
+            async {
+                rest_server
+                    .await
+                    .change_context(GraphError)?
+                    .change_context(GraphError)
+                    .attach_printable("failed to start REST API server")
+            },
+        )
+        .await?;
+    } else {
+        server.await.expect("failed to start server");
+    }
 
     Ok(())
 }
 
+/// Imports the authorization schema into `backend` and seeds it.
+async fn zanzibar_client<B>(mut backend: B) -> Result<ZanzibarClient<B>, Report<GraphError>>
+where
+    B: ZanzibarBackend + Send + Sync,
+{
+    backend
+        .import_schema(include_str!(
+            "../../../../libs/@local/graph/authorization/schemas/v1__initial_schema.zed"
+        ))
+        .await
+        .change_context(GraphError)?;
+
+    let mut zanzibar_client = ZanzibarClient::new(backend);
+    zanzibar_client.seed().await.change_context(GraphError)?;
+    Ok(zanzibar_client)
+}
+
+/// Spawns the REST API using an in-memory store and returns the routes to reset it.
+async fn memory_store_router(
+    args: &TestServerArgs,
+    zanzibar_client: ZanzibarClient<EmbeddedZanzibar>,
+) -> Result<(Router, JoinHandle<io::Result<()>>), Report<GraphError>> {
+    let pool = MemoryStorePool::new(MemoryStoreSettings::default());
+
+    let api_router = rest_api_router(RestRouterDependencies {
+        store: Arc::new(FetchingPool::<_, (String, u16)>::new_offline(pool.clone())),
+        authorization_api: Arc::new(zanzibar_client.clone()),
+        domain_regex: DomainValidator::new(args.allowed_url_domain.clone()),
+        temporal_client: None,
+        query_logger: None,
+    });
+    let api_listener = TcpListener::bind((args.graph_api_host.as_str(), args.graph_api_port))
+        .await
+        .change_context(GraphError)?;
+
+    tracing::info!(
+        "REST API listening on {}:{}",
+        args.graph_api_host,
+        args.graph_api_port
+    );
+    let rest_server = tokio::spawn(
+        axum::serve(
+            api_listener,
+            api_router.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .into_future(),
+    );
+
+    Ok((
+        hash_graph_test_server::memory_store_routes(pool, zanzibar_client),
+        rest_server,
+    ))
+}
+
 pub async fn healthcheck(address: HttpAddress) -> Result<(), Report<HealthcheckError>> {
     let request_url = format!("http://{address}/snapshot");
 
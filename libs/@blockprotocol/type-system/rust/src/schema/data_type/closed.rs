@@ -10,7 +10,7 @@ use thiserror::Error;
 
 use crate::{
     schema::{
-        DataType, DataTypeUuid, InheritanceDepth, ValueLabel,
+        DataType, DataTypeUuid, InheritanceDepth, JsonSchemaValueType, ValueLabel,
         data_type::{DataTypeEdge, constraint::ValueConstraints},
     },
     url::VersionedUrl,
@@ -102,6 +102,28 @@ impl ClosedDataType {
             r#abstract: data_type.r#abstract,
         })
     }
+
+    /// Returns the JSON type of the values described by this data type.
+    ///
+    /// Returns `None` if the constraints allow values of different JSON types, e.g. an `anyOf`
+    /// of a string and a number.
+    #[must_use]
+    pub fn value_type(&self) -> Option<JsonSchemaValueType> {
+        self.all_of
+            .iter()
+            .flat_map(|constraints| match constraints {
+                ValueConstraints::Typed(constraints) => {
+                    vec![constraints.value_type()]
+                }
+                ValueConstraints::AnyOf(constraints) => constraints
+                    .any_of
+                    .iter()
+                    .map(|schema| schema.constraints.value_type())
+                    .collect(),
+            })
+            .all_equal_value()
+            .ok()
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -24,7 +24,9 @@ pub use self::{
         StringValidationError,
     },
 };
-use crate::schema::{ValueLabel, data_type::closed::ResolveClosedDataTypeError};
+use crate::schema::{
+    JsonSchemaValueType, ValueLabel, data_type::closed::ResolveClosedDataTypeError,
+};
 
 pub trait Constraint: Sized {
     /// Combines the current constraints with the provided one.
@@ -190,6 +192,21 @@ pub enum SingleValueConstraints {
     Object,
 }
 
+impl SingleValueConstraints {
+    /// Returns the JSON type of values satisfying these constraints.
+    #[must_use]
+    pub const fn value_type(&self) -> JsonSchemaValueType {
+        match self {
+            Self::Null => JsonSchemaValueType::Null,
+            Self::Boolean => JsonSchemaValueType::Boolean,
+            Self::Number(_) => JsonSchemaValueType::Number,
+            Self::String(_) => JsonSchemaValueType::String,
+            Self::Array(_) => JsonSchemaValueType::Array,
+            Self::Object => JsonSchemaValueType::Object,
+        }
+    }
+}
+
 impl Constraint for SingleValueConstraints {
     fn intersection(
         self,
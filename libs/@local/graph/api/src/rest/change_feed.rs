@@ -0,0 +1,239 @@
+//! Web routes for reading the change feed of the graph.
+
+use alloc::sync::Arc;
+use core::time::Duration;
+
+use axum::{
+    BoxError, Extension, Router,
+    body::Body,
+    extract::Query,
+    http::header,
+    response::{IntoResponse as _, Response},
+    routing::{get, post},
+};
+use bytes::Bytes;
+use error_stack::{Report, ResultExt as _};
+use futures::stream;
+use hash_graph_authorization::AuthorizationApiPool;
+use hash_graph_store::{
+    change_feed::{
+        ChangeFeedStore as _, GraphChange, GraphChangeId, GraphChangeKind, ReadGraphChangesParams,
+        ReadGraphChangesResponse,
+    },
+    error::QueryError,
+    pool::StorePool,
+};
+use hash_graph_types::account::AccountId;
+use hash_temporal_client::TemporalClient;
+use serde::Deserialize;
+use utoipa::{IntoParams, OpenApi};
+
+use super::api_resource::RoutedResource;
+use crate::rest::{AuthenticatedUserHeader, json::Json, status::report_to_response};
+
+/// The number of changes read from the store at once when streaming the change feed.
+const STREAM_BATCH_SIZE: usize = 100;
+
+/// The time to wait before polling the store again if no new changes were recorded.
+const STREAM_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        read_graph_changes,
+        stream_graph_changes,
+    ),
+    components(
+        schemas(
+            GraphChangeId,
+            GraphChangeKind,
+            GraphChange,
+            ReadGraphChangesParams,
+            ReadGraphChangesResponse,
+        ),
+    ),
+    tags(
+        (name = "ChangeFeed", description = "Change feed API")
+    )
+)]
+pub(crate) struct ChangeFeedResource;
+
+impl RoutedResource for ChangeFeedResource {
+    /// Create routes for reading the change feed.
+    fn routes<S, A>() -> Router
+    where
+        S: StorePool + Send + Sync + 'static,
+        A: AuthorizationApiPool + Send + Sync + 'static,
+    {
+        Router::new().nest(
+            "/changes",
+            Router::new()
+                .route("/query", post(read_graph_changes::<S, A>))
+                .route("/stream", get(stream_graph_changes::<S, A>)),
+        )
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/changes/query",
+    request_body = ReadGraphChangesParams,
+    tag = "ChangeFeed",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", body = ReadGraphChangesResponse, description = "The changes recorded after the provided cursor"),
+
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn read_graph_changes<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<ReadGraphChangesParams>,
+) -> Result<Json<ReadGraphChangesResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .read_graph_changes(actor_id, params)
+        .await
+        .map(Json)
+        .map_err(report_to_response)
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct StreamGraphChangesParams {
+    /// Only changes recorded after this change are streamed.
+    ///
+    /// If not specified, the feed is streamed from the beginning.
+    #[param(value_type = Option<i64>)]
+    after: Option<GraphChangeId>,
+}
+
+/// The state carried between the batches of the change feed stream.
+struct ChangeStream<S, A> {
+    store_pool: Arc<S>,
+    authorization_api_pool: Arc<A>,
+    temporal_client: Option<Arc<TemporalClient>>,
+    actor_id: AccountId,
+    cursor: Option<GraphChangeId>,
+}
+
+impl<S, A> ChangeStream<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    /// Waits for the next changes after the current cursor and returns them as JSON lines.
+    ///
+    /// The store is acquired for every read, so no connection is held while waiting for new
+    /// changes.
+    async fn next_batch(&mut self) -> Result<Bytes, Report<QueryError>> {
+        loop {
+            let authorization_api = self
+                .authorization_api_pool
+                .acquire()
+                .await
+                .change_context(QueryError)?;
+            let store = self
+                .store_pool
+                .acquire(authorization_api, self.temporal_client.clone())
+                .await
+                .change_context(QueryError)?;
+
+            let response = store
+                .read_graph_changes(self.actor_id, ReadGraphChangesParams {
+                    after: self.cursor,
+                    limit: STREAM_BATCH_SIZE,
+                })
+                .await?;
+            self.cursor = response.cursor;
+
+            if !response.changes.is_empty() {
+                let mut lines = Vec::new();
+                for change in response.changes {
+                    serde_json::to_writer(&mut lines, &change).change_context(QueryError)?;
+                    lines.push(b'\n');
+                }
+                return Ok(Bytes::from(lines));
+            }
+
+            drop(store);
+            tokio::time::sleep(STREAM_POLL_INTERVAL).await;
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/changes/stream",
+    tag = "ChangeFeed",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+        StreamGraphChangesParams,
+    ),
+    responses(
+        (status = 200, content_type = "application/x-ndjson", body = GraphChange, description = "A never-ending stream of changes, one JSON encoded change per line"),
+
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn stream_graph_changes<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Query(params): Query<StreamGraphChangesParams>,
+) -> Response
+where
+    S: StorePool + Send + Sync + 'static,
+    A: AuthorizationApiPool + Send + Sync + 'static,
+{
+    let changes = stream::try_unfold(
+        ChangeStream {
+            store_pool: store_pool.0,
+            authorization_api_pool: authorization_api_pool.0,
+            temporal_client: temporal_client.0,
+            actor_id,
+            cursor: params.after,
+        },
+        |mut state| async move {
+            let batch = state
+                .next_batch()
+                .await
+                .inspect_err(|report| tracing::error!(error = ?report, "Could not read changes"))
+                .map_err(BoxError::from)?;
+            Ok::<_, BoxError>(Some((batch, state)))
+        },
+    );
+
+    (
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        Body::from_stream(changes),
+    )
+        .into_response()
+}
@@ -165,17 +165,17 @@ impl RoutedResource for DataTypeResource {
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
-struct CreateDataTypeRequest {
+pub struct CreateDataTypeRequest {
     #[schema(inline)]
-    schema: MaybeListOfDataType,
-    owned_by_id: OwnedById,
-    relationships: Vec<DataTypeRelationAndSubject>,
+    pub(crate) schema: MaybeListOfDataType,
+    pub(crate) owned_by_id: OwnedById,
+    pub(crate) relationships: Vec<DataTypeRelationAndSubject>,
     #[serde(
         default,
         skip_serializing_if = "ProvidedOntologyEditionProvenance::is_empty"
     )]
-    provenance: ProvidedOntologyEditionProvenance,
-    conversions: HashMap<BaseUrl, Conversions>,
+    pub(crate) provenance: ProvidedOntologyEditionProvenance,
+    pub(crate) conversions: HashMap<BaseUrl, Conversions>,
 }
 
 #[utoipa::path(
@@ -445,11 +445,22 @@ where
 
 #[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-struct GetDataTypeSubgraphResponse {
+pub struct GetDataTypeSubgraphResponse {
     subgraph: Subgraph,
     cursor: Option<VersionedUrl>,
 }
 
+impl From<hash_graph_store::data_type::GetDataTypeSubgraphResponse>
+    for GetDataTypeSubgraphResponse
+{
+    fn from(response: hash_graph_store::data_type::GetDataTypeSubgraphResponse) -> Self {
+        Self {
+            subgraph: Subgraph::from(response.subgraph),
+            cursor: response.cursor,
+        }
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/data-types/query/subgraph",
@@ -511,12 +522,7 @@ where
         )
         .await
         .map_err(report_to_response)
-        .map(|response| {
-            Json(GetDataTypeSubgraphResponse {
-                subgraph: Subgraph::from(response.subgraph),
-                cursor: response.cursor,
-            })
-        });
+        .map(|response| Json(GetDataTypeSubgraphResponse::from(response)));
     if let Some(query_logger) = &mut query_logger {
         query_logger.send().await.map_err(report_to_response)?;
     }
@@ -525,17 +531,17 @@ where
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
-struct UpdateDataTypeRequest {
+pub struct UpdateDataTypeRequest {
     #[schema(value_type = UpdateDataType)]
-    schema: serde_json::Value,
-    type_to_update: VersionedUrl,
-    relationships: Vec<DataTypeRelationAndSubject>,
+    pub(crate) schema: serde_json::Value,
+    pub(crate) type_to_update: VersionedUrl,
+    pub(crate) relationships: Vec<DataTypeRelationAndSubject>,
     #[serde(
         default,
         skip_serializing_if = "ProvidedOntologyEditionProvenance::is_empty"
     )]
-    provenance: ProvidedOntologyEditionProvenance,
-    conversions: HashMap<BaseUrl, Conversions>,
+    pub(crate) provenance: ProvidedOntologyEditionProvenance,
+    pub(crate) conversions: HashMap<BaseUrl, Conversions>,
 }
 
 #[utoipa::path(
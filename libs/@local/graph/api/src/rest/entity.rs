@@ -1,13 +1,15 @@
 //! Web routes for CRU operations on entities.
 
-use alloc::sync::Arc;
+use alloc::{borrow::Cow, sync::Arc};
+use core::str::FromStr as _;
 use std::collections::HashMap;
 
+use async_trait::async_trait;
 use axum::{
     Extension, Router,
-    extract::Path,
-    http::StatusCode,
-    response::Response,
+    extract::{FromRequestParts, Path, Query},
+    http::{HeaderValue, StatusCode, header, request::Parts},
+    response::{IntoResponse as _, Response},
     routing::{get, post},
 };
 use error_stack::{Report, ResultExt as _};
@@ -25,17 +27,25 @@ use hash_graph_postgres_store::store::error::{EntityDoesNotExist, RaceConditionO
 use hash_graph_store::{
     account::AccountStore as _,
     entity::{
-        ClosedMultiEntityTypeMap, CountEntitiesParams, CreateEntityRequest, DiffEntityParams,
-        DiffEntityResult, EntityQueryCursor, EntityQueryPath, EntityQuerySorting,
-        EntityQuerySortingRecord, EntityQuerySortingToken, EntityQueryToken, EntityStore as _,
-        EntityTypesError, EntityValidationReport, EntityValidationType, GetEntitiesParams,
-        GetEntitiesResponse, GetEntitySubgraphParams, LinkDataStateError, LinkDataValidationReport,
-        LinkError, LinkTargetError, LinkValidationReport, LinkedEntityError,
-        MetadataValidationReport, PatchEntityParams, PropertyMetadataValidationReport,
-        QueryConversion, UnexpectedEntityType, UpdateEntityEmbeddingsParams,
-        ValidateEntityComponents, ValidateEntityParams,
+        AggregateEntitiesParams, AggregateEntitiesResponse, ClosedMultiEntityTypeMap,
+        CountEntitiesParams, CreateEntityRequest, DiffEntityParams, DiffEntityResult,
+        EntityAggregation, EntityAggregationFunction, EntityAggregationGroup, EntityHistoryEntry,
+        EntityImportColumn, EntityImportFormat, EntityQueryCursor, EntityQueryPath,
+        EntityQuerySorting, EntityQuerySortingRecord, EntityQuerySortingToken, EntityQueryToken,
+        EntityRevertTarget, EntityStore as _, EntityTypesError, EntityValidationReport,
+        EntityValidationType, GetEntitiesParams, GetEntitiesResponse, GetEntityHistoryParams,
+        GetEntityHistoryResponse, GetEntitySubgraphParams, ImportEntitiesParams,
+        ImportEntitiesResponse, ImportRowError, ImportRowReport, InvalidImportValue,
+        LinkDataStateError, LinkDataValidationReport, LinkError, LinkTargetError,
+        LinkValidationReport, LinkedEntityError, MalformedImportRow, MergeEntitiesParams,
+        MergeEntitiesResponse, MetadataValidationReport, MigrateEntityTypeParams,
+        MigrateEntityTypeResponse, PatchEntityParams, PropertyMergeStrategy,
+        PropertyMetadataValidationReport, QueryConversion, RevertEntityParams,
+        UnexpectedEntityType, UpdateEntityEmbeddingsParams, ValidateEntityComponents,
+        ValidateEntityParams,
     },
     entity_type::{EntityTypeResolveDefinitions, IncludeEntityTypeOption},
+    error::{EntityHistoryCursorNotFound, EntityRevertTargetNotFound},
     filter::Filter,
     pool::StorePool,
     query::{NullOrdering, Ordering},
@@ -54,9 +64,9 @@ use hash_graph_types::{
         },
         link::LinkData,
         property::{
-            ArrayMetadata, ObjectMetadata, Property, PropertyDiff, PropertyMetadata,
-            PropertyMetadataObject, PropertyObject, PropertyPatchOperation, PropertyPath,
-            PropertyPathElement, PropertyProvenance, PropertyWithMetadata,
+            ArrayMetadata, ObjectMetadata, PatchTestFailed, Property, PropertyDiff,
+            PropertyMetadata, PropertyMetadataObject, PropertyObject, PropertyPatchOperation,
+            PropertyPath, PropertyPathElement, PropertyProvenance, PropertyWithMetadata,
             PropertyWithMetadataArray, PropertyWithMetadataObject, PropertyWithMetadataValue,
             ValueMetadata,
             visitor::{
@@ -73,14 +83,19 @@ use hash_graph_types::{
     },
     owned_by_id::OwnedById,
 };
+use hash_status::Status;
 use hash_temporal_client::TemporalClient;
+use hql_eval::graph::compile_filter;
 use serde::{Deserialize, Serialize};
 use type_system::url::VersionedUrl;
-use utoipa::{OpenApi, ToSchema};
+use utoipa::{IntoParams, OpenApi, ToSchema};
+use uuid::Uuid;
 
 use crate::rest::{
     AuthenticatedUserHeader, OpenApiQuery, PermissionResponse, QueryLogger,
-    api_resource::RoutedResource, json::Json, status::report_to_response,
+    api_resource::RoutedResource,
+    json::Json,
+    status::{report_to_response, status_to_response},
     utoipa_typedef::subgraph::Subgraph,
 };
 
@@ -89,12 +104,20 @@ use crate::rest::{
     paths(
         create_entity,
         create_entities,
+        get_entity,
+        import_entities,
         validate_entity,
         check_entity_permission,
         get_entities,
+        get_entities_by_hashql,
         get_entity_subgraph,
         count_entities,
+        aggregate_entities,
         patch_entity,
+        merge_entities,
+        revert_entity,
+        migrate_entity_type,
+        get_entity_history,
         update_entity_embeddings,
         diff_entity,
 
@@ -114,7 +137,20 @@ use crate::rest::{
             PropertyWithMetadataArray,
             PropertyWithMetadataObject,
             ValidateEntityParams,
+            ImportEntitiesParams,
+            ImportEntitiesResponse,
+            EntityImportFormat,
+            EntityImportColumn,
+            ImportRowReport,
+            ImportRowError,
+            MalformedImportRow,
+            InvalidImportValue,
             CountEntitiesParams,
+            AggregateEntitiesParams,
+            AggregateEntitiesResponse,
+            EntityAggregation,
+            EntityAggregationFunction,
+            EntityAggregationGroup,
             EntityValidationType,
             ValidateEntityComponents,
             Embedding,
@@ -124,6 +160,15 @@ use crate::rest::{
 
             PatchEntityParams,
             PropertyPatchOperation,
+            MergeEntitiesParams,
+            MergeEntitiesResponse,
+            PropertyMergeStrategy,
+            RevertEntityParams,
+            EntityRevertTarget,
+            MigrateEntityTypeParams,
+            MigrateEntityTypeResponse,
+            GetEntityHistoryResponse,
+            EntityHistoryEntry,
 
             EntityRelationAndSubject,
             EntityPermission,
@@ -138,6 +183,7 @@ use crate::rest::{
             EntitySetting,
 
             GetEntitiesRequest,
+            GetEntitiesHashQlRequest,
             GetEntitySubgraphRequest,
             EntityQueryCursor,
             Ordering,
@@ -234,20 +280,26 @@ impl RoutedResource for EntityResource {
             Router::new()
                 .route("/", post(create_entity::<S, A>).patch(patch_entity::<S, A>))
                 .route("/bulk", post(create_entities::<S, A>))
+                .route("/import", post(import_entities::<S, A>))
                 .route(
                     "/relationships",
                     post(modify_entity_authorization_relationships::<A>),
                 )
                 .route("/diff", post(diff_entity::<S, A>))
+                .route("/merge", post(merge_entities::<S, A>))
+                .route("/migrate", post(migrate_entity_type::<S, A>))
+                .route("/revert", post(revert_entity::<S, A>))
                 .route("/validate", post(validate_entity::<S, A>))
                 .route("/embeddings", post(update_entity_embeddings::<S, A>))
                 .nest(
                     "/:entity_id",
                     Router::new()
+                        .route("/", get(get_entity::<S, A>))
                         .route(
                             "/relationships",
                             get(get_entity_authorization_relationships::<A>),
                         )
+                        .route("/history", get(get_entity_history::<S, A>))
                         .route(
                             "/administrators/:administrator",
                             post(add_entity_administrator::<A, S>)
@@ -266,8 +318,10 @@ impl RoutedResource for EntityResource {
                     "/query",
                     Router::new()
                         .route("/", post(get_entities::<S, A>))
+                        .route("/hashql", post(get_entities_by_hashql::<S, A>))
                         .route("/subgraph", post(get_entity_subgraph::<S, A>))
-                        .route("/count", post(count_entities::<S, A>)),
+                        .route("/count", post(count_entities::<S, A>))
+                        .route("/aggregate", post(aggregate_entities::<S, A>)),
                 ),
         )
     }
@@ -374,6 +428,50 @@ where
         .map(Json)
 }
 
+#[utoipa::path(
+    post,
+    path = "/entities/import",
+    request_body = ImportEntitiesParams,
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The per-row import reports and the created entities", body = ImportEntitiesResponse),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 500, description = "Store error occurred"),
+    ),
+)]
+#[tracing::instrument(level = "info", skip_all)]
+async fn import_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<ImportEntitiesParams>,
+) -> Result<Json<ImportEntitiesResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .import_entities(actor_id, params)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
 #[utoipa::path(
     post,
     path = "/entities/validate",
@@ -720,6 +818,150 @@ where
     response
 }
 
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "Parameter struct deserialized from JSON"
+)]
+pub struct GetEntitiesHashQlRequest<'s, 'p> {
+    /// The J-Expr source of the condition the returned entities have to satisfy.
+    query: String,
+    temporal_axes: QueryTemporalAxesUnresolved,
+    include_drafts: bool,
+    limit: Option<usize>,
+    #[serde(borrow, default)]
+    conversions: Vec<QueryConversion<'p>>,
+    #[serde(borrow)]
+    sorting_paths: Option<Vec<EntityQuerySortingRecord<'p>>>,
+    #[serde(borrow)]
+    cursor: Option<EntityQueryCursor<'s>>,
+    #[serde(default)]
+    include_count: bool,
+    #[serde(default)]
+    include_entity_types: Option<IncludeEntityTypeOption>,
+    #[serde(default)]
+    include_web_ids: bool,
+    #[serde(default)]
+    include_created_by_ids: bool,
+    #[serde(default)]
+    include_edition_created_by_ids: bool,
+    #[serde(default)]
+    include_type_ids: bool,
+}
+
+impl<'p> GetEntitiesHashQlRequest<'_, 'p> {
+    fn into_params(self, filter: Filter<'p, Entity>) -> GetEntitiesParams<'p> {
+        GetEntitiesParams {
+            filter,
+            sorting: generate_sorting_paths(
+                self.sorting_paths,
+                self.limit,
+                self.cursor,
+                &self.temporal_axes,
+            ),
+            limit: self.limit,
+            conversions: self.conversions,
+            include_drafts: self.include_drafts,
+            include_count: self.include_count,
+            include_entity_types: self.include_entity_types,
+            temporal_axes: self.temporal_axes,
+            include_web_ids: self.include_web_ids,
+            include_created_by_ids: self.include_created_by_ids,
+            include_edition_created_by_ids: self.include_edition_created_by_ids,
+            include_type_ids: self.include_type_ids,
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/query/hashql",
+    request_body = GetEntitiesHashQlRequest,
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/json",
+            body = GetEntitiesResponse,
+            description = "A list of entities that satisfy the given HashQL query.",
+        ),
+        (status = 400, content_type = "application/json", description = "The HashQL query could not be compiled, the diagnostics are returned in the status contents"),
+        (status = 422, content_type = "text/plain", description = "Provided request is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip_all,
+    fields(actor=%actor_id, %request)
+)]
+async fn get_entities_by_hashql<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    mut query_logger: Option<Extension<QueryLogger>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Json<GetEntitiesResponse<'static>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    if let Some(query_logger) = &mut query_logger {
+        query_logger.capture(actor_id, OpenApiQuery::GetEntitiesByHashQl(&request));
+    }
+
+    let request = GetEntitiesHashQlRequest::deserialize(&request)
+        .map_err(Report::from)
+        .map_err(report_to_response)?;
+
+    let filter = compile_filter(request.query.as_bytes()).map_err(|diagnostics| {
+        status_to_response(Status::new(
+            hash_status::StatusCode::InvalidArgument,
+            diagnostics
+                .first()
+                .map(|diagnostic| diagnostic.category.as_ref().canonical_name().to_string()),
+            diagnostics,
+        ))
+    })?;
+
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    let response = store
+        .get_entities(actor_id, request.into_params(filter))
+        .await
+        .map(|response| {
+            Json(GetEntitiesResponse {
+                entities: response.entities,
+                cursor: response.cursor.map(EntityQueryCursor::into_owned),
+                count: response.count,
+                closed_multi_entity_types: response.closed_multi_entity_types,
+                definitions: response.definitions,
+                web_ids: response.web_ids,
+                created_by_ids: response.created_by_ids,
+                edition_created_by_ids: response.edition_created_by_ids,
+                type_ids: response.type_ids,
+            })
+        })
+        .map_err(report_to_response);
+    if let Some(query_logger) = &mut query_logger {
+        query_logger.send().await.map_err(report_to_response)?;
+    }
+    response
+}
+
 #[derive(Debug, Clone, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 #[expect(
@@ -780,7 +1022,7 @@ impl<'q, 's, 'p: 'q> From<GetEntitySubgraphRequest<'q, 's, 'p>> for GetEntitySub
 
 #[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-struct GetEntitySubgraphResponse<'r> {
+pub struct GetEntitySubgraphResponse<'r> {
     subgraph: Subgraph,
     #[serde(borrow)]
     cursor: Option<EntityQueryCursor<'r>>,
@@ -805,6 +1047,24 @@ struct GetEntitySubgraphResponse<'r> {
     type_ids: Option<HashMap<VersionedUrl, usize>>,
 }
 
+impl From<hash_graph_store::entity::GetEntitySubgraphResponse<'_>>
+    for GetEntitySubgraphResponse<'static>
+{
+    fn from(response: hash_graph_store::entity::GetEntitySubgraphResponse<'_>) -> Self {
+        Self {
+            subgraph: response.subgraph.into(),
+            cursor: response.cursor.map(EntityQueryCursor::into_owned),
+            count: response.count,
+            closed_multi_entity_types: response.closed_multi_entity_types,
+            definitions: response.definitions,
+            web_ids: response.web_ids,
+            created_by_ids: response.created_by_ids,
+            edition_created_by_ids: response.edition_created_by_ids,
+            type_ids: response.type_ids,
+        }
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/entities/query/subgraph",
@@ -871,19 +1131,7 @@ where
     let response = store
         .get_entity_subgraph(actor_id, request.into())
         .await
-        .map(|response| {
-            Json(GetEntitySubgraphResponse {
-                subgraph: response.subgraph.into(),
-                cursor: response.cursor.map(EntityQueryCursor::into_owned),
-                count: response.count,
-                closed_multi_entity_types: response.closed_multi_entity_types,
-                definitions: response.definitions,
-                web_ids: response.web_ids,
-                created_by_ids: response.created_by_ids,
-                edition_created_by_ids: response.edition_created_by_ids,
-                type_ids: response.type_ids,
-            })
-        })
+        .map(|response| Json(GetEntitySubgraphResponse::from(response)))
         .map_err(report_to_response);
     if let Some(query_logger) = &mut query_logger {
         query_logger.send().await.map_err(report_to_response)?;
@@ -956,19 +1204,247 @@ where
     response
 }
 
+#[utoipa::path(
+    post,
+    path = "/entities/query/aggregate",
+    request_body = AggregateEntitiesParams,
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/json",
+            body = AggregateEntitiesResponse,
+        ),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client, request)
+)]
+async fn aggregate_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    mut query_logger: Option<Extension<QueryLogger>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Json<AggregateEntitiesResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    if let Some(query_logger) = &mut query_logger {
+        query_logger.capture(actor_id, OpenApiQuery::AggregateEntities(&request));
+    }
+
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    let response = store
+        .aggregate_entities(
+            actor_id,
+            AggregateEntitiesParams::deserialize(&request)
+                .map_err(Report::from)
+                .map_err(report_to_response)?,
+        )
+        .await
+        .map(Json)
+        .map_err(report_to_response);
+    if let Some(query_logger) = &mut query_logger {
+        query_logger.send().await.map_err(report_to_response)?;
+    }
+    response
+}
+
+/// The `If-Match` header of a request as specified in [RFC 9110].
+///
+/// [RFC 9110]: https://www.rfc-editor.org/rfc/rfc9110#name-if-match
+#[derive(Debug)]
+enum IfMatchHeader {
+    /// The header is missing or `*`, which matches any edition.
+    Any,
+    /// The editions listed as strong entity tags.
+    ///
+    /// `If-Match` uses the strong comparison, so weak entity tags and entity tags which are not
+    /// an edition ID never match and are not included.
+    Editions(Vec<EntityEditionId>),
+}
+
+impl IfMatchHeader {
+    const MALFORMED: &'static str = "`If-Match` header contains a malformed entity tag";
+
+    /// Parses a comma-separated list of entity tags and appends the listed editions.
+    fn parse_entity_tags(
+        mut value: &str,
+        editions: &mut Vec<EntityEditionId>,
+    ) -> Result<(), &'static str> {
+        loop {
+            value = value.trim_start_matches([' ', '\t', ',']);
+            if value.is_empty() {
+                return Ok(());
+            }
+
+            let (weak, tag) = value
+                .strip_prefix("W/")
+                .map_or((false, value), |tag| (true, tag));
+            let (opaque_tag, remaining) = tag
+                .strip_prefix('"')
+                .and_then(|tag| tag.split_once('"'))
+                .ok_or(Self::MALFORMED)?;
+
+            value = remaining.trim_start_matches([' ', '\t']);
+            if !value.is_empty() && !value.starts_with(',') {
+                return Err(Self::MALFORMED);
+            }
+
+            if !weak {
+                if let Ok(edition_id) = Uuid::from_str(opaque_tag) {
+                    editions.push(EntityEditionId::new(edition_id));
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for IfMatchHeader {
+    type Rejection = (StatusCode, Cow<'static, str>);
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let mut header_values = parts.headers.get_all(header::IF_MATCH).iter().peekable();
+        if header_values.peek().is_none() {
+            return Ok(Self::Any);
+        }
+
+        let mut editions = Vec::new();
+        for header_value in header_values {
+            let header_string = header_value
+                .to_str()
+                .map_err(|error| (StatusCode::BAD_REQUEST, Cow::Owned(error.to_string())))?
+                .trim_matches([' ', '\t']);
+            if header_string == "*" {
+                return Ok(Self::Any);
+            }
+            Self::parse_entity_tags(header_string, &mut editions)
+                .map_err(|error| (StatusCode::BAD_REQUEST, Cow::Borrowed(error)))?;
+        }
+        Ok(Self::Editions(editions))
+    }
+}
+
+/// Responds with the entity and its edition ID as entity tag in the `ETag` header.
+fn entity_response(entity: Entity) -> Response {
+    let entity_tag = HeaderValue::try_from(format!(
+        "\"{}\"",
+        entity.metadata.record_id.edition_id.as_uuid()
+    ))
+    .expect("a quoted UUID should be a valid header value");
+    ([(header::ETAG, entity_tag)], Json(entity)).into_response()
+}
+
+#[utoipa::path(
+    get,
+    path = "/entities/{entity_id}",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("entity_id" = EntityId, Path, description = "The ID of the entity to read"),
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/json",
+            description = "The current edition of the entity",
+            body = Entity,
+            headers(
+                ("ETag" = String, description = "The edition ID of the entity as entity tag"),
+            ),
+        ),
+
+        (status = 403, description = "Insufficient permissions to read the entity"),
+        (status = 404, description = "The entity was not found"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn get_entity<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    Path(entity_id): Path<EntityId>,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+) -> Result<Response, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .get_entity_by_id(actor_id, entity_id, None, None)
+        .await
+        .map_err(|report| {
+            if report.contains::<EntityDoesNotExist>() {
+                report.attach(hash_status::StatusCode::NotFound)
+            } else if report.contains::<PermissionAssertion>() {
+                report.attach(hash_status::StatusCode::PermissionDenied)
+            } else {
+                report
+            }
+        })
+        .map_err(report_to_response)
+        .map(entity_response)
+}
+
 #[utoipa::path(
     patch,
     path = "/entities",
     tag = "Entity",
     params(
         ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("If-Match" = Option<String>, Header, description = "Only apply the patch if the current edition of the entity is one of the listed entity tags or `*`, takes precedence over `expectedEditionId`"),
     ),
     responses(
-        (status = 200, content_type = "application/json", description = "The updated entity", body = Entity),
+        (
+            status = 200,
+            content_type = "application/json",
+            description = "The updated entity",
+            body = Entity,
+            headers(
+                ("ETag" = String, description = "The edition ID of the updated entity as entity tag"),
+            ),
+        ),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
         (status = 423, content_type = "text/plain", description = "The entity that should be updated was unexpectedly updated at the same time"),
 
+        (status = 400, description = "The `If-Match` header is malformed or does not contain `expectedEditionId`"),
         (status = 404, description = "Entity ID or Entity Type URL was not found"),
+        (status = 409, description = "A test operation of the patch did not match the current properties or the entity was updated since the expected edition"),
+        (status = 412, description = "The `If-Match` header does not contain a strong entity tag of an edition"),
         (status = 500, description = "Store error occurred"),
     ),
     request_body = PatchEntityParams,
@@ -982,8 +1458,9 @@ async fn patch_entity<S, A>(
     store_pool: Extension<Arc<S>>,
     authorization_api_pool: Extension<Arc<A>>,
     temporal_client: Extension<Option<Arc<TemporalClient>>>,
-    Json(params): Json<PatchEntityParams>,
-) -> Result<Json<Entity>, Response>
+    if_match: IfMatchHeader,
+    Json(mut params): Json<PatchEntityParams>,
+) -> Result<Response, Response>
 where
     S: StorePool + Send + Sync,
     A: AuthorizationApiPool + Send + Sync,
@@ -998,6 +1475,52 @@ where
         .await
         .map_err(report_to_response)?;
 
+    if let IfMatchHeader::Editions(editions) = if_match {
+        if let Some(expected_edition_id) = params.expected_edition_id {
+            if !editions.contains(&expected_edition_id) {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    "`If-Match` header does not contain `expectedEditionId`",
+                )
+                    .into_response());
+            }
+        } else {
+            params.expected_edition_id = match editions.as_slice() {
+                [] => {
+                    return Err((
+                        StatusCode::PRECONDITION_FAILED,
+                        "`If-Match` header does not contain a strong entity tag of an edition",
+                    )
+                        .into_response());
+                }
+                [edition_id] => Some(*edition_id),
+                [first_edition_id, ..] => {
+                    // The store only compares against a single edition, so the current edition is
+                    // expected if it's listed. Otherwise, the patch is rejected with a mismatch.
+                    let current_edition_id = store
+                        .get_entity_by_id(actor_id, params.entity_id, None, None)
+                        .await
+                        .map_err(|report| {
+                            if report.contains::<EntityDoesNotExist>() {
+                                report.attach(hash_status::StatusCode::NotFound)
+                            } else {
+                                report
+                            }
+                        })
+                        .map_err(report_to_response)?
+                        .metadata
+                        .record_id
+                        .edition_id;
+                    Some(if editions.contains(&current_edition_id) {
+                        current_edition_id
+                    } else {
+                        *first_edition_id
+                    })
+                }
+            };
+        }
+    }
+
     store
         .patch_entity(actor_id, params)
         .await
@@ -1006,6 +1529,256 @@ where
                 report.attach(hash_status::StatusCode::NotFound)
             } else if report.contains::<RaceConditionOnUpdate>() {
                 report.attach(hash_status::StatusCode::Cancelled)
+            } else if report.contains::<PatchTestFailed>() {
+                report.attach(hash_status::StatusCode::Aborted)
+            } else {
+                report
+            }
+        })
+        .map_err(report_to_response)
+        .map(entity_response)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/merge",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The surviving entity and the replaced link entities", body = MergeEntitiesResponse),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 423, content_type = "text/plain", description = "One of the entities was unexpectedly updated at the same time"),
+
+        (status = 400, description = "The surviving entity is part of the merged entities"),
+        (status = 403, description = "Insufficient permissions to update the involved entities"),
+        (status = 404, description = "One of the entities was not found"),
+        (status = 409, description = "One of the entities was updated while the merge was in progress"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = MergeEntitiesParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn merge_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<MergeEntitiesParams>,
+) -> Result<Json<MergeEntitiesResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .merge_entities(actor_id, params)
+        .await
+        .map_err(|report| {
+            if report.contains::<EntityDoesNotExist>() {
+                report.attach(hash_status::StatusCode::NotFound)
+            } else if report.contains::<RaceConditionOnUpdate>() {
+                report.attach(hash_status::StatusCode::Cancelled)
+            } else {
+                report
+            }
+        })
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/revert",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The reverted entity", body = Entity),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 423, content_type = "text/plain", description = "The entity that should be reverted was unexpectedly updated at the same time"),
+
+        (status = 404, description = "The entity does not exist at the requested target"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = RevertEntityParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn revert_entity<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<RevertEntityParams>,
+) -> Result<Json<Entity>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .revert_entity(actor_id, params)
+        .await
+        .map_err(|report| {
+            if report.contains::<EntityDoesNotExist>()
+                || report.contains::<EntityRevertTargetNotFound>()
+            {
+                report.attach(hash_status::StatusCode::NotFound)
+            } else if report.contains::<RaceConditionOnUpdate>() {
+                report.attach(hash_status::StatusCode::Cancelled)
+            } else {
+                report
+            }
+        })
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/migrate",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The validation reports of the invalid entities and the migrated entities", body = MigrateEntityTypeResponse),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 423, content_type = "text/plain", description = "One of the entities was unexpectedly updated at the same time"),
+
+        (status = 403, description = "Insufficient permissions to update one of the entities"),
+        (status = 409, description = "One of the entities was updated while the migration was in progress"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = MigrateEntityTypeParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn migrate_entity_type<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<MigrateEntityTypeParams>,
+) -> Result<Json<MigrateEntityTypeResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .migrate_entity_type(actor_id, params)
+        .await
+        .map_err(|report| {
+            if report.contains::<RaceConditionOnUpdate>() {
+                report.attach(hash_status::StatusCode::Cancelled)
+            } else {
+                report
+            }
+        })
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct GetEntityHistoryQuery {
+    /// Only editions after this edition are returned.
+    #[param(value_type = Option<EntityEditionId>)]
+    after: Option<EntityEditionId>,
+    /// The maximum number of editions to return.
+    limit: Option<usize>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/entities/{entity_id}/history",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("entity_id" = EntityId, Path, description = "The entity ID to read the history for"),
+        GetEntityHistoryQuery,
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The editions of the entity with the changes each edition introduced", body = GetEntityHistoryResponse),
+
+        (status = 404, description = "The edition to continue after is not an edition of the entity"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn get_entity_history<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    Path(entity_id): Path<EntityId>,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Query(query): Query<GetEntityHistoryQuery>,
+) -> Result<Json<GetEntityHistoryResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .get_entity_history(actor_id, GetEntityHistoryParams {
+            entity_id,
+            after: query.after,
+            limit: query.limit,
+        })
+        .await
+        .map_err(|report| {
+            if report.contains::<EntityHistoryCursorNotFound>() {
+                report.attach(hash_status::StatusCode::NotFound)
             } else {
                 report
             }
@@ -1639,3 +2412,42 @@ where
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[cfg(test)]
+mod tests {
+    use hash_graph_types::knowledge::entity::EntityEditionId;
+    use uuid::Uuid;
+
+    use super::IfMatchHeader;
+
+    const FIRST: Uuid = Uuid::from_u128(1);
+    const SECOND: Uuid = Uuid::from_u128(2);
+
+    fn parse(value: &str) -> Result<Vec<EntityEditionId>, &'static str> {
+        let mut editions = Vec::new();
+        IfMatchHeader::parse_entity_tags(value, &mut editions)?;
+        Ok(editions)
+    }
+
+    #[test]
+    fn entity_tag_list() {
+        assert_eq!(
+            parse(&format!("\"{FIRST}\", W/\"{SECOND}\",\t\"{SECOND}\"")),
+            Ok(vec![
+                EntityEditionId::new(FIRST),
+                EntityEditionId::new(SECOND)
+            ])
+        );
+        assert_eq!(parse(&format!("W/\"{FIRST}\"")), Ok(Vec::new()));
+        assert_eq!(parse("\"not-an-edition\""), Ok(Vec::new()));
+        assert_eq!(parse(" , "), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn malformed_entity_tags() {
+        assert!(parse(&FIRST.to_string()).is_err());
+        assert!(parse(&format!("\"{FIRST}")).is_err());
+        assert!(parse(&format!("\"{FIRST}\" \"{SECOND}\"")).is_err());
+        assert!(parse("*").is_err());
+    }
+}
@@ -167,15 +167,15 @@ impl RoutedResource for EntityTypeResource {
     }
 }
 
-#[derive(Debug, Deserialize, ToSchema)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
-struct CreateEntityTypeRequest {
+pub struct CreateEntityTypeRequest {
     #[schema(inline)]
-    schema: MaybeListOfEntityType,
-    owned_by_id: OwnedById,
-    relationships: Vec<EntityTypeRelationAndSubject>,
+    pub(crate) schema: MaybeListOfEntityType,
+    pub(crate) owned_by_id: OwnedById,
+    pub(crate) relationships: Vec<EntityTypeRelationAndSubject>,
     #[serde(default)]
-    provenance: ProvidedOntologyEditionProvenance,
+    pub(crate) provenance: ProvidedOntologyEditionProvenance,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -829,7 +829,7 @@ where
 
 #[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-struct GetEntityTypeSubgraphResponse {
+pub struct GetEntityTypeSubgraphResponse {
     subgraph: Subgraph,
     cursor: Option<VersionedUrl>,
     count: Option<usize>,
@@ -841,6 +841,20 @@ struct GetEntityTypeSubgraphResponse {
     edition_created_by_ids: Option<HashMap<EditionCreatedById, usize>>,
 }
 
+impl From<hash_graph_store::entity_type::GetEntityTypeSubgraphResponse>
+    for GetEntityTypeSubgraphResponse
+{
+    fn from(response: hash_graph_store::entity_type::GetEntityTypeSubgraphResponse) -> Self {
+        Self {
+            subgraph: Subgraph::from(response.subgraph),
+            cursor: response.cursor,
+            count: response.count,
+            web_ids: response.web_ids,
+            edition_created_by_ids: response.edition_created_by_ids,
+        }
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/entity-types/query/subgraph",
@@ -899,31 +913,23 @@ where
         )
         .await
         .map_err(report_to_response)
-        .map(|response| {
-            Json(GetEntityTypeSubgraphResponse {
-                subgraph: Subgraph::from(response.subgraph),
-                cursor: response.cursor,
-                count: response.count,
-                web_ids: response.web_ids,
-                edition_created_by_ids: response.edition_created_by_ids,
-            })
-        });
+        .map(|response| Json(GetEntityTypeSubgraphResponse::from(response)));
     if let Some(query_logger) = &mut query_logger {
         query_logger.send().await.map_err(report_to_response)?;
     }
     response
 }
 
-#[derive(Debug, Deserialize, ToSchema)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
-struct UpdateEntityTypeRequest {
+pub struct UpdateEntityTypeRequest {
     #[schema(value_type = UpdateEntityType)]
-    schema: serde_json::Value,
-    type_to_update: VersionedUrl,
+    pub(crate) schema: serde_json::Value,
+    pub(crate) type_to_update: VersionedUrl,
 
-    relationships: Vec<EntityTypeRelationAndSubject>,
+    pub(crate) relationships: Vec<EntityTypeRelationAndSubject>,
     #[serde(default)]
-    provenance: ProvidedOntologyEditionProvenance,
+    pub(crate) provenance: ProvidedOntologyEditionProvenance,
 }
 
 #[utoipa::path(
@@ -3,6 +3,7 @@
 //! Handler methods are grouped by routes that make up the REST API.
 
 pub mod account;
+pub mod change_feed;
 pub mod data_type;
 pub mod entity;
 pub mod entity_type;
@@ -13,11 +14,14 @@ pub mod web;
 
 mod api_resource;
 mod json;
-mod utoipa_typedef;
+pub(crate) mod utoipa_typedef;
 
 use alloc::{borrow::Cow, sync::Arc};
 use core::str::FromStr as _;
-use std::{fs, io, time::Instant};
+use std::{
+    fs, io,
+    time::{Instant, SystemTime},
+};
 
 use async_trait::async_trait;
 use axum::{
@@ -198,6 +202,7 @@ where
 {
     vec![
         account::AccountResource::routes::<S, A>(),
+        change_feed::ChangeFeedResource::routes::<S, A>(),
         data_type::DataTypeResource::routes::<S, A>(),
         property_type::PropertyTypeResource::routes::<S, A>(),
         entity_type::EntityTypeResource::routes::<S, A>(),
@@ -209,6 +214,7 @@ where
 fn api_documentation() -> Vec<openapi::OpenApi> {
     vec![
         account::AccountResource::documentation(),
+        change_feed::ChangeFeedResource::documentation(),
         data_type::DataTypeResource::documentation(),
         property_type::PropertyTypeResource::documentation(),
         entity_type::EntityTypeResource::documentation(),
@@ -243,9 +249,17 @@ impl QueryLogger {
         let mut record = serde_json::to_value(query)
             .change_context(QueryLoggingError)
             .expect("query should be serializable");
-        record
-            .as_object_mut()
-            .map(|object| object.insert("actor".to_owned(), JsonValue::String(actor.to_string())));
+        if let Some(object) = record.as_object_mut() {
+            object.insert("actor".to_owned(), JsonValue::String(actor.to_string()));
+            // The wall-clock start time allows replaying the queries with their original pacing.
+            if let Some(started_at) = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .ok()
+                .and_then(|duration| JsonNumber::from_u128(duration.as_millis()))
+            {
+                object.insert("startedAt".to_owned(), JsonValue::Number(started_at));
+            }
+        }
         self.value = Some(record);
         self.created_at = Instant::now();
     }
@@ -317,7 +331,9 @@ pub enum OpenApiQuery<'a> {
         permission: EntityTypePermission,
     },
     GetEntities(&'a JsonValue),
+    GetEntitiesByHashQl(&'a JsonValue),
     CountEntities(&'a JsonValue),
+    AggregateEntities(&'a JsonValue),
     GetEntitySubgraph(&'a JsonValue),
     ValidateEntity(&'a JsonValue),
     DiffEntity(&'a DiffEntityParams),
@@ -787,6 +803,54 @@ impl Modify for FilterSchemaAddon {
                                 )
                                 .required("containsSegment"),
                         )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("LikeFilter"))
+                                .property(
+                                    "like",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("like"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("ILikeFilter"))
+                                .property(
+                                    "iLike",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("iLike"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("RegexFilter"))
+                                .property(
+                                    "regex",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("regex"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("FullTextMatchFilter"))
+                                .property(
+                                    "fullTextMatch",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("fullTextMatch"),
+                        )
                         .build(),
                 )
                 .into(),
@@ -830,6 +894,20 @@ impl Modify for FilterSchemaAddon {
                 )
                 .into(),
             );
+            components.schemas.insert(
+                "EntityQueryPath".to_owned(),
+                schema::Schema::Array(
+                    ArrayBuilder::new()
+                        .items(
+                            OneOfBuilder::new()
+                                .item(Ref::from_schema_name("EntityQueryToken"))
+                                .item(ObjectBuilder::new().schema_type(SchemaType::String))
+                                .item(ObjectBuilder::new().schema_type(SchemaType::Number)),
+                        )
+                        .build(),
+                )
+                .into(),
+            );
             components.schemas.insert(
                 "EntityQuerySortingPath".to_owned(),
                 schema::Schema::Array(
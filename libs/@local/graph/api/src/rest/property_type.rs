@@ -161,16 +161,16 @@ impl RoutedResource for PropertyTypeResource {
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
-struct CreatePropertyTypeRequest {
+pub struct CreatePropertyTypeRequest {
     #[schema(inline)]
-    schema: MaybeListOfPropertyType,
-    owned_by_id: OwnedById,
-    relationships: Vec<PropertyTypeRelationAndSubject>,
+    pub(crate) schema: MaybeListOfPropertyType,
+    pub(crate) owned_by_id: OwnedById,
+    pub(crate) relationships: Vec<PropertyTypeRelationAndSubject>,
     #[serde(
         default,
         skip_serializing_if = "ProvidedOntologyEditionProvenance::is_empty"
     )]
-    provenance: ProvidedOntologyEditionProvenance,
+    pub(crate) provenance: ProvidedOntologyEditionProvenance,
 }
 
 #[utoipa::path(
@@ -434,11 +434,22 @@ where
 
 #[derive(Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-struct GetPropertyTypeSubgraphResponse {
+pub struct GetPropertyTypeSubgraphResponse {
     subgraph: Subgraph,
     cursor: Option<VersionedUrl>,
 }
 
+impl From<hash_graph_store::property_type::GetPropertyTypeSubgraphResponse>
+    for GetPropertyTypeSubgraphResponse
+{
+    fn from(response: hash_graph_store::property_type::GetPropertyTypeSubgraphResponse) -> Self {
+        Self {
+            subgraph: Subgraph::from(response.subgraph),
+            cursor: response.cursor,
+        }
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/property-types/query/subgraph",
@@ -502,12 +513,7 @@ where
         )
         .await
         .map_err(report_to_response)
-        .map(|response| {
-            Json(GetPropertyTypeSubgraphResponse {
-                subgraph: Subgraph::from(response.subgraph),
-                cursor: response.cursor,
-            })
-        });
+        .map(|response| Json(GetPropertyTypeSubgraphResponse::from(response)));
     if let Some(query_logger) = &mut query_logger {
         query_logger.send().await.map_err(report_to_response)?;
     }
@@ -516,16 +522,16 @@ where
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
-struct UpdatePropertyTypeRequest {
+pub struct UpdatePropertyTypeRequest {
     #[schema(value_type = UpdatePropertyType)]
-    schema: serde_json::Value,
-    type_to_update: VersionedUrl,
-    relationships: Vec<PropertyTypeRelationAndSubject>,
+    pub(crate) schema: serde_json::Value,
+    pub(crate) type_to_update: VersionedUrl,
+    pub(crate) relationships: Vec<PropertyTypeRelationAndSubject>,
     #[serde(
         default,
         skip_serializing_if = "ProvidedOntologyEditionProvenance::is_empty"
     )]
-    provenance: ProvidedOntologyEditionProvenance,
+    pub(crate) provenance: ProvidedOntologyEditionProvenance,
 }
 
 #[utoipa::path(
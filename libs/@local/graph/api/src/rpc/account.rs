@@ -1,20 +1,9 @@
 use alloc::{borrow::Cow, sync::Arc};
-use core::{
-    error::{self, Error},
-    marker::PhantomData,
-};
+use core::error::{self, Error};
 
 use error_stack::{Report, ResultExt as _};
-use harpc_client::{connection::Connection, utils::invoke_call_discrete};
-use harpc_codec::{decode::ReportDecoder, encode::Encoder};
-use harpc_server::{
-    error::{DelegationError, Forbidden},
-    session::Session,
-    utils::{delegate_call_discrete, parse_procedure_id},
-};
-use harpc_system::delegate::SubsystemDelegate;
-use harpc_tower::{body::Body, either::Either, request::Request, response::Response};
-use harpc_types::{error_code::ErrorCode, response_kind::ResponseKind};
+use harpc_server::{error::Forbidden, session::Session};
+use harpc_types::error_code::ErrorCode;
 use hash_graph_authorization::{
     AuthorizationApi as _, AuthorizationApiPool,
     backend::ModifyRelationshipOperation,
@@ -34,7 +23,7 @@ use hash_graph_types::{
 };
 use hash_temporal_client::TemporalClient;
 
-use super::session::Account;
+use super::{GraphSubsystemId, session::Account};
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct PermissionResponse {
@@ -58,21 +47,25 @@ impl Error for AccountNotFoundError {
 #[display("unable to fullfil account request")]
 pub struct AccountError;
 
+#[harpc_system::service(subsystem = GraphSubsystemId::Account, version = "0.0")]
 pub trait AccountSystem {
     type ExecutionScope;
 
+    #[procedure(id = 0x00)]
     async fn create_account(
         &self,
         scope: Self::ExecutionScope,
         params: InsertAccountIdParams,
     ) -> Result<AccountId, Report<AccountError>>;
 
+    #[procedure(id = 0x01)]
     async fn create_account_group(
         &self,
         scope: Self::ExecutionScope,
         params: InsertAccountGroupIdParams,
     ) -> Result<AccountGroupId, Report<AccountError>>;
 
+    #[procedure(id = 0x02)]
     async fn check_account_group_permission(
         &self,
         scope: Self::ExecutionScope,
@@ -80,6 +73,7 @@ pub trait AccountSystem {
         permission: AccountGroupPermission,
     ) -> Result<PermissionResponse, Report<AccountError>>;
 
+    #[procedure(id = 0x03)]
     async fn add_account_group_member(
         &self,
         scope: Self::ExecutionScope,
@@ -87,6 +81,7 @@ pub trait AccountSystem {
         account_id: AccountId,
     ) -> Result<(), Report<AccountError>>;
 
+    #[procedure(id = 0x04)]
     async fn remove_account_group_member(
         &self,
         scope: Self::ExecutionScope,
@@ -95,118 +90,6 @@ pub trait AccountSystem {
     ) -> Result<(), Report<AccountError>>;
 }
 
-// TODO: this can be auto generated by the `harpc` crate
-pub mod meta {
-    //! The `meta` module contains the metadata for the account service.
-    //! In the future this will be automatically generated by the `harpc` crate.
-
-    use frunk::HList;
-    use harpc_system::{
-        Subsystem,
-        procedure::{Procedure, ProcedureIdentifier},
-    };
-    use harpc_types::{procedure::ProcedureId, version::Version};
-
-    use crate::rpc::GraphSubsystemId;
-
-    pub enum AccountProcedureId {
-        CreateAccount,
-        CreateAccountGroup,
-        CheckAccountGroupPermission,
-        AddAccountGroupMember,
-        RemoveAccountGroupMember,
-    }
-
-    impl ProcedureIdentifier for AccountProcedureId {
-        type Subsystem = AccountSystem;
-
-        fn from_id(id: ProcedureId) -> Option<Self> {
-            match id.value() {
-                0x00 => Some(Self::CreateAccount),
-                0x01 => Some(Self::CreateAccountGroup),
-                0x02 => Some(Self::CheckAccountGroupPermission),
-                0x03 => Some(Self::AddAccountGroupMember),
-                0x04 => Some(Self::RemoveAccountGroupMember),
-                _ => None,
-            }
-        }
-
-        fn into_id(self) -> ProcedureId {
-            match self {
-                Self::CreateAccount => ProcedureId::new(0x00),
-                Self::CreateAccountGroup => ProcedureId::new(0x01),
-                Self::CheckAccountGroupPermission => ProcedureId::new(0x02),
-                Self::AddAccountGroupMember => ProcedureId::new(0x03),
-                Self::RemoveAccountGroupMember => ProcedureId::new(0x04),
-            }
-        }
-    }
-
-    pub struct AccountSystem;
-
-    impl Subsystem for AccountSystem {
-        type ProcedureId = AccountProcedureId;
-        type Procedures = HList![
-            ProcedureCreateAccount,
-            ProcedureCreateAccountGroup,
-            ProcedureCheckAccountGroupPermission,
-            ProcedureAddAccountGroupMember,
-            ProcedureRemoveAccountGroupMember
-        ];
-        type SubsystemId = GraphSubsystemId;
-
-        const ID: GraphSubsystemId = GraphSubsystemId::Account;
-        const VERSION: Version = Version {
-            major: 0x00,
-            minor: 0x00,
-        };
-    }
-
-    pub struct ProcedureCreateAccount;
-
-    impl Procedure for ProcedureCreateAccount {
-        type Subsystem = AccountSystem;
-
-        const ID: <Self::Subsystem as Subsystem>::ProcedureId = AccountProcedureId::CreateAccount;
-    }
-
-    pub struct ProcedureCreateAccountGroup;
-
-    impl Procedure for ProcedureCreateAccountGroup {
-        type Subsystem = AccountSystem;
-
-        const ID: <Self::Subsystem as Subsystem>::ProcedureId =
-            AccountProcedureId::CreateAccountGroup;
-    }
-
-    pub struct ProcedureCheckAccountGroupPermission;
-
-    impl Procedure for ProcedureCheckAccountGroupPermission {
-        type Subsystem = AccountSystem;
-
-        const ID: <Self::Subsystem as Subsystem>::ProcedureId =
-            AccountProcedureId::CheckAccountGroupPermission;
-    }
-
-    pub struct ProcedureAddAccountGroupMember;
-
-    impl Procedure for ProcedureAddAccountGroupMember {
-        type Subsystem = AccountSystem;
-
-        const ID: <Self::Subsystem as Subsystem>::ProcedureId =
-            AccountProcedureId::AddAccountGroupMember;
-    }
-
-    pub struct ProcedureRemoveAccountGroupMember;
-
-    impl Procedure for ProcedureRemoveAccountGroupMember {
-        type Subsystem = AccountSystem;
-
-        const ID: <Self::Subsystem as Subsystem>::ProcedureId =
-            AccountProcedureId::RemoveAccountGroupMember;
-    }
-}
-
 #[derive(Debug)]
 #[derive_where::derive_where(Clone)]
 pub struct AccountServer<S, A> {
@@ -462,217 +345,3 @@ where
         Ok(())
     }
 }
-
-// TODO: this can be auto generated by the `harpc` crate
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-pub struct AccountDelegate<T> {
-    inner: T,
-}
-
-impl<T> AccountDelegate<T> {
-    #[must_use]
-    pub const fn new(inner: T) -> Self {
-        Self { inner }
-    }
-}
-
-impl<T, C> SubsystemDelegate<C> for AccountDelegate<T>
-where
-    T: AccountSystem<
-            create_account(..): Send,
-            create_account_group(..): Send,
-            check_account_group_permission(..): Send,
-            add_account_group_member(..): Send,
-            remove_account_group_member(..): Send,
-            ExecutionScope: Send,
-        > + Send,
-    C: Encoder + ReportDecoder + Clone + Send,
-{
-    type Error = Report<DelegationError>;
-    type ExecutionScope = T::ExecutionScope;
-    type Subsystem = meta::AccountSystem;
-
-    type Body<Source>
-        = impl Body<Control: AsRef<ResponseKind>, Error = <C as Encoder>::Error>
-    where
-        Source: Body<Control = !, Error: Send + Sync> + Send;
-
-    async fn call<B>(
-        self,
-        request: Request<B>,
-        scope: T::ExecutionScope,
-        codec: C,
-    ) -> Result<Response<Self::Body<B>>, Self::Error>
-    where
-        B: Body<Control = !, Error: Send + Sync> + Send,
-    {
-        let id = parse_procedure_id(&request)?;
-
-        // The Either chain here isn't... great, but the only other way would be to box. To box we'd
-        // need to require that the `Decoder<Output>` is both `Send` and `Sync`, which it can be,
-        // but to completely write out the trait bound is a bit of a pain.
-        // We would instead most likely need to add `+ Sync` to the GAT, which would over-constrain
-        // it unnecessarily, but would _in theory_ allow us to remove the `Either` chain.
-        match id {
-            meta::AccountProcedureId::CreateAccount => {
-                delegate_call_discrete(request, codec, |params| async move {
-                    self.inner.create_account(scope, params).await
-                })
-                .await
-                .map(|response| response.map_body(Either::Left))
-            }
-            meta::AccountProcedureId::CreateAccountGroup => {
-                delegate_call_discrete(request, codec, |params| async move {
-                    self.inner.create_account_group(scope, params).await
-                })
-                .await
-                .map(|response| response.map_body(Either::Left).map_body(Either::Right))
-            }
-            meta::AccountProcedureId::CheckAccountGroupPermission => delegate_call_discrete(
-                request,
-                codec,
-                |(account_group_id, permission)| async move {
-                    self.inner
-                        .check_account_group_permission(scope, account_group_id, permission)
-                        .await
-                },
-            )
-            .await
-            .map(|response| {
-                response
-                    .map_body(Either::Left)
-                    .map_body(Either::Right)
-                    .map_body(Either::Right)
-            }),
-            meta::AccountProcedureId::AddAccountGroupMember => delegate_call_discrete(
-                request,
-                codec,
-                |(account_group_id, account_id)| async move {
-                    self.inner
-                        .add_account_group_member(scope, account_group_id, account_id)
-                        .await
-                },
-            )
-            .await
-            .map(|response| {
-                response
-                    .map_body(Either::Left)
-                    .map_body(Either::Right)
-                    .map_body(Either::Right)
-                    .map_body(Either::Right)
-            }),
-            meta::AccountProcedureId::RemoveAccountGroupMember => delegate_call_discrete(
-                request,
-                codec,
-                |(account_group_id, account_id)| async move {
-                    self.inner
-                        .remove_account_group_member(scope, account_group_id, account_id)
-                        .await
-                },
-            )
-            .await
-            .map(|response| {
-                response
-                    .map_body(Either::Right)
-                    .map_body(Either::Right)
-                    .map_body(Either::Right)
-                    .map_body(Either::Right)
-            }),
-        }
-    }
-}
-
-// TODO: this can be auto generated by the `harpc` crate
-#[derive_where::derive_where(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-pub struct AccountClient<S, C> {
-    _session: PhantomData<fn() -> *const S>,
-    _codec: PhantomData<fn() -> *const C>,
-}
-
-impl<S, C> AccountClient<S, C> {
-    #[must_use]
-    pub fn new() -> Self {
-        Self {
-            _session: PhantomData,
-            _codec: PhantomData,
-        }
-    }
-}
-
-impl<S, C> Default for AccountClient<S, C> {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl<S, C> AccountSystem for AccountClient<S, C>
-where
-    S: harpc_client::connection::ConnectionService<C>,
-    C: harpc_client::connection::ConnectionCodec,
-{
-    type ExecutionScope = Connection<S, C>;
-
-    async fn create_account(
-        &self,
-        scope: Connection<S, C>,
-        params: InsertAccountIdParams,
-    ) -> Result<AccountId, Report<AccountError>> {
-        invoke_call_discrete(scope, meta::AccountProcedureId::CreateAccount, [params])
-            .await
-            .change_context(AccountError)
-    }
-
-    async fn create_account_group(
-        &self,
-        scope: Connection<S, C>,
-        params: InsertAccountGroupIdParams,
-    ) -> Result<AccountGroupId, Report<AccountError>> {
-        invoke_call_discrete(scope, meta::AccountProcedureId::CreateAccountGroup, [
-            params,
-        ])
-        .await
-        .change_context(AccountError)
-    }
-
-    async fn check_account_group_permission(
-        &self,
-        scope: Connection<S, C>,
-        account_group_id: AccountGroupId,
-        permission: AccountGroupPermission,
-    ) -> Result<PermissionResponse, Report<AccountError>> {
-        invoke_call_discrete(
-            scope,
-            meta::AccountProcedureId::CheckAccountGroupPermission,
-            [(account_group_id, permission)],
-        )
-        .await
-        .change_context(AccountError)
-    }
-
-    async fn add_account_group_member(
-        &self,
-        scope: Connection<S, C>,
-        account_group_id: AccountGroupId,
-        account_id: AccountId,
-    ) -> Result<(), Report<AccountError>> {
-        invoke_call_discrete(scope, meta::AccountProcedureId::AddAccountGroupMember, [(
-            account_group_id,
-            account_id,
-        )])
-        .await
-        .change_context(AccountError)
-    }
-
-    async fn remove_account_group_member(
-        &self,
-        scope: Connection<S, C>,
-        account_group_id: AccountGroupId,
-        account_id: AccountId,
-    ) -> Result<(), Report<AccountError>> {
-        invoke_call_discrete(scope, meta::AccountProcedureId::RemoveAccountGroupMember, [
-            (account_group_id, account_id),
-        ])
-        .await
-        .change_context(AccountError)
-    }
-}
@@ -0,0 +1,110 @@
+use alloc::{borrow::Cow, sync::Arc};
+
+use error_stack::{Report, ResultExt as _};
+use harpc_server::{error::Forbidden, session::Session};
+use hash_graph_authorization::AuthorizationApiPool;
+use hash_graph_store::{
+    change_feed::{ChangeFeedStore as _, ReadGraphChangesParams, ReadGraphChangesResponse},
+    pool::StorePool,
+};
+use hash_graph_types::account::AccountId;
+use hash_temporal_client::TemporalClient;
+
+use super::{GraphSubsystemId, session::Account};
+
+#[must_use]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::Error)]
+#[display("unable to fullfil change feed request")]
+pub struct ChangeFeedError;
+
+#[harpc_system::service(subsystem = GraphSubsystemId::ChangeFeed, version = "0.0")]
+pub trait ChangeFeedSystem {
+    type ExecutionScope;
+
+    /// Reads the changes recorded after [`ReadGraphChangesParams::after`].
+    ///
+    /// To follow the feed, the returned cursor is passed as `after` to the next call.
+    #[procedure(id = 0x00)]
+    async fn read_graph_changes(
+        &self,
+        scope: Self::ExecutionScope,
+        params: ReadGraphChangesParams,
+    ) -> Result<ReadGraphChangesResponse, Report<ChangeFeedError>>;
+}
+
+#[derive(Debug)]
+#[derive_where::derive_where(Clone)]
+pub struct ChangeFeedServer<S, A> {
+    pub authorization_api_pool: Arc<A>,
+    pub temporal_client: Option<Arc<TemporalClient>>,
+    pub store_pool: Arc<S>,
+}
+
+impl<S, A> ChangeFeedServer<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    async fn store(&self) -> Result<S::Store<'_, A::Api<'_>>, Report<ChangeFeedError>> {
+        let authorization_api = self
+            .authorization_api_pool
+            .acquire()
+            .await
+            .inspect_err(|error| {
+                tracing::error!(?error, "Could not acquire access to the authorization API");
+            })
+            .change_context(ChangeFeedError)?;
+
+        self.store_pool
+            .acquire(authorization_api, self.temporal_client.clone())
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not acquire store");
+            })
+            .change_context(ChangeFeedError)
+    }
+
+    fn actor(session: &Session<Account>) -> Result<AccountId, Report<ChangeFeedError>> {
+        let &Account {
+            actor_id: Some(actor_id),
+        } = session.get()
+        else {
+            let request_info = session.request_info();
+
+            return Err(Report::new(Forbidden {
+                subsystem: request_info.subsystem,
+                procedure: request_info.procedure,
+                reason: Cow::Borrowed("user authentication required"),
+            })
+            .change_context(ChangeFeedError));
+        };
+
+        Ok(actor_id)
+    }
+}
+
+impl<S, A> ChangeFeedSystem for ChangeFeedServer<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    type ExecutionScope = Session<Account>;
+
+    async fn read_graph_changes(
+        &self,
+        scope: Session<Account>,
+        params: ReadGraphChangesParams,
+    ) -> Result<ReadGraphChangesResponse, Report<ChangeFeedError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let store = self.store().await?;
+
+        store
+            .read_graph_changes(actor_id, params)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not read graph changes");
+            })
+            .change_context(ChangeFeedError)
+    }
+}
@@ -0,0 +1,415 @@
+use alloc::{borrow::Cow, sync::Arc};
+
+use error_stack::{Report, ResultExt as _};
+use futures::{StreamExt as _, stream::BoxStream};
+use harpc_server::{error::Forbidden, session::Session};
+use hash_graph_authorization::{
+    AuthorizationApi as _, AuthorizationApiPool, schema::DataTypePermission, zanzibar::Consistency,
+};
+use hash_graph_postgres_store::ontology::patch_id_and_parse;
+use hash_graph_store::{
+    data_type::{
+        ArchiveDataTypeParams, CreateDataTypeParams, DataTypeStore as _, GetDataTypeSubgraphParams,
+        GetDataTypesParams, UnarchiveDataTypeParams, UpdateDataTypesParams,
+    },
+    pool::StorePool,
+    query::ConflictBehavior,
+};
+use hash_graph_types::{
+    account::AccountId,
+    ontology::{
+        DataTypeMetadata, OntologyTemporalMetadata, OntologyTypeClassificationMetadata,
+        ProvidedOntologyEditionProvenance,
+    },
+};
+use hash_temporal_client::TemporalClient;
+use type_system::{
+    schema::{DataTypeUuid, DomainValidator, ValidateOntologyType as _},
+    url::{OntologyTypeVersion, VersionedUrl},
+};
+
+use super::{
+    GraphSubsystemId,
+    account::PermissionResponse,
+    pagination::{deserialize_request, paginate},
+    session::Account,
+};
+use crate::rest::data_type::{
+    CreateDataTypeRequest, GetDataTypeSubgraphResponse, UpdateDataTypeRequest,
+};
+
+#[must_use]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::Error)]
+#[display("unable to fullfil data type request")]
+pub struct DataTypeError;
+
+#[harpc_system::service(subsystem = GraphSubsystemId::DataType, version = "0.0")]
+pub trait DataTypeSystem {
+    type ExecutionScope;
+
+    #[procedure(id = 0x00)]
+    async fn create_data_types(
+        &self,
+        scope: Self::ExecutionScope,
+        request: CreateDataTypeRequest,
+    ) -> Result<Vec<DataTypeMetadata>, Report<DataTypeError>>;
+
+    /// Queries the data types matching the request.
+    ///
+    /// The request and the pages of the response have the same format as the request and the
+    /// response of the `/data-types/query` endpoint.
+    ///
+    /// If a `limit` is specified, it is used as the page size and every page is sent as its own
+    /// item until all data types have been returned.
+    #[procedure(id = 0x01, stream)]
+    async fn query_data_types(
+        &self,
+        scope: Self::ExecutionScope,
+        request: serde_json::Value,
+    ) -> Result<
+        BoxStream<'static, Result<serde_json::Value, Report<DataTypeError>>>,
+        Report<DataTypeError>,
+    >;
+
+    /// Queries the subgraphs rooted at the data types matching the request.
+    ///
+    /// The request and the pages of the response have the same format as the request and the
+    /// response of the `/data-types/query/subgraph` endpoint.
+    ///
+    /// Pages are streamed the same way as in [`query_data_types`](Self::query_data_types).
+    #[procedure(id = 0x02, stream)]
+    async fn query_data_type_subgraph(
+        &self,
+        scope: Self::ExecutionScope,
+        request: serde_json::Value,
+    ) -> Result<
+        BoxStream<'static, Result<serde_json::Value, Report<DataTypeError>>>,
+        Report<DataTypeError>,
+    >;
+
+    #[procedure(id = 0x03)]
+    async fn update_data_type(
+        &self,
+        scope: Self::ExecutionScope,
+        request: UpdateDataTypeRequest,
+    ) -> Result<DataTypeMetadata, Report<DataTypeError>>;
+
+    #[procedure(id = 0x04)]
+    async fn archive_data_type(
+        &self,
+        scope: Self::ExecutionScope,
+        data_type_id: VersionedUrl,
+    ) -> Result<OntologyTemporalMetadata, Report<DataTypeError>>;
+
+    #[procedure(id = 0x05)]
+    async fn unarchive_data_type(
+        &self,
+        scope: Self::ExecutionScope,
+        data_type_id: VersionedUrl,
+        provenance: ProvidedOntologyEditionProvenance,
+    ) -> Result<OntologyTemporalMetadata, Report<DataTypeError>>;
+
+    #[procedure(id = 0x06)]
+    async fn check_data_type_permission(
+        &self,
+        scope: Self::ExecutionScope,
+        data_type_id: VersionedUrl,
+        permission: DataTypePermission,
+    ) -> Result<PermissionResponse, Report<DataTypeError>>;
+}
+
+#[derive_where::derive_where(Clone)]
+pub struct DataTypeServer<S, A> {
+    pub authorization_api_pool: Arc<A>,
+    pub temporal_client: Option<Arc<TemporalClient>>,
+    pub store_pool: Arc<S>,
+    pub domain_validator: DomainValidator,
+}
+
+impl<S, A> DataTypeServer<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    async fn authorization_api(&self) -> Result<A::Api<'_>, Report<DataTypeError>> {
+        self.authorization_api_pool
+            .acquire()
+            .await
+            .inspect_err(|error| {
+                tracing::error!(?error, "Could not acquire access to the authorization API");
+            })
+            .change_context(DataTypeError)
+    }
+
+    async fn store(&self) -> Result<S::Store<'_, A::Api<'_>>, Report<DataTypeError>> {
+        let authorization_api = self.authorization_api().await?;
+
+        self.store_pool
+            .acquire(authorization_api, self.temporal_client.clone())
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not acquire store");
+            })
+            .change_context(DataTypeError)
+    }
+
+    fn actor(session: &Session<Account>) -> Result<AccountId, Report<DataTypeError>> {
+        let &Account {
+            actor_id: Some(actor_id),
+        } = session.get()
+        else {
+            let request_info = session.request_info();
+
+            return Err(Report::new(Forbidden {
+                subsystem: request_info.subsystem,
+                procedure: request_info.procedure,
+                reason: Cow::Borrowed("user authentication required"),
+            })
+            .change_context(DataTypeError));
+        };
+
+        Ok(actor_id)
+    }
+}
+
+impl<S, A> DataTypeSystem for DataTypeServer<S, A>
+where
+    S: StorePool + Send + Sync + 'static,
+    A: AuthorizationApiPool + Send + Sync + 'static,
+{
+    type ExecutionScope = Session<Account>;
+
+    async fn create_data_types(
+        &self,
+        scope: Session<Account>,
+        request: CreateDataTypeRequest,
+    ) -> Result<Vec<DataTypeMetadata>, Report<DataTypeError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let CreateDataTypeRequest {
+            schema,
+            owned_by_id,
+            relationships,
+            provenance,
+            conversions,
+        } = request;
+
+        let params = schema
+            .into_iter()
+            .map(|schema| {
+                self.domain_validator
+                    .validate(&schema)
+                    .inspect_err(|report| {
+                        tracing::error!(error=?report, id=%schema.id, "Data type ID failed to validate");
+                    })
+                    .change_context(DataTypeError)?;
+
+                Ok(CreateDataTypeParams {
+                    schema,
+                    classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+                    relationships: relationships.clone(),
+                    conflict_behavior: ConflictBehavior::Fail,
+                    provenance: provenance.clone(),
+                    conversions: conversions.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, Report<DataTypeError>>>()?;
+
+        let mut store = self.store().await?;
+
+        store
+            .create_data_types(actor_id, params)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not create data types");
+            })
+            .change_context(DataTypeError)
+    }
+
+    async fn query_data_types(
+        &self,
+        scope: Session<Account>,
+        request: serde_json::Value,
+    ) -> Result<
+        BoxStream<'static, Result<serde_json::Value, Report<DataTypeError>>>,
+        Report<DataTypeError>,
+    > {
+        let actor_id = Self::actor(&scope)?;
+
+        let server = self.clone();
+        Ok(paginate(request, "after", DataTypeError, move |request| {
+            let server = server.clone();
+            async move {
+                let params: GetDataTypesParams = deserialize_request(&request, DataTypeError)?;
+
+                let store = server.store().await?;
+                let response = store
+                    .get_data_types(actor_id, params)
+                    .await
+                    .inspect_err(|report| {
+                        tracing::error!(error=?report, "Could not read data types");
+                    })
+                    .change_context(DataTypeError)?;
+
+                let cursor = response
+                    .cursor
+                    .as_ref()
+                    .map(serde_json::to_value)
+                    .transpose()
+                    .change_context(DataTypeError)?;
+
+                Ok((response, cursor))
+            }
+        })
+        .boxed())
+    }
+
+    async fn query_data_type_subgraph(
+        &self,
+        scope: Session<Account>,
+        request: serde_json::Value,
+    ) -> Result<
+        BoxStream<'static, Result<serde_json::Value, Report<DataTypeError>>>,
+        Report<DataTypeError>,
+    > {
+        let actor_id = Self::actor(&scope)?;
+
+        let server = self.clone();
+        Ok(paginate(request, "after", DataTypeError, move |request| {
+            let server = server.clone();
+            async move {
+                let params: GetDataTypeSubgraphParams =
+                    deserialize_request(&request, DataTypeError)?;
+
+                let store = server.store().await?;
+                let response = store
+                    .get_data_type_subgraph(actor_id, params)
+                    .await
+                    .inspect_err(|report| {
+                        tracing::error!(error=?report, "Could not read data type subgraph");
+                    })
+                    .change_context(DataTypeError)?;
+
+                let cursor = response
+                    .cursor
+                    .as_ref()
+                    .map(serde_json::to_value)
+                    .transpose()
+                    .change_context(DataTypeError)?;
+
+                Ok((GetDataTypeSubgraphResponse::from(response), cursor))
+            }
+        })
+        .boxed())
+    }
+
+    async fn update_data_type(
+        &self,
+        scope: Session<Account>,
+        request: UpdateDataTypeRequest,
+    ) -> Result<DataTypeMetadata, Report<DataTypeError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let UpdateDataTypeRequest {
+            schema,
+            mut type_to_update,
+            relationships,
+            provenance,
+            conversions,
+        } = request;
+
+        type_to_update.version = OntologyTypeVersion::new(type_to_update.version.inner() + 1);
+
+        let data_type =
+            patch_id_and_parse(&type_to_update, schema).change_context(DataTypeError)?;
+
+        let mut store = self.store().await?;
+
+        store
+            .update_data_type(actor_id, UpdateDataTypesParams {
+                schema: data_type,
+                relationships,
+                provenance,
+                conversions,
+            })
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not update data type");
+            })
+            .change_context(DataTypeError)
+    }
+
+    async fn archive_data_type(
+        &self,
+        scope: Session<Account>,
+        data_type_id: VersionedUrl,
+    ) -> Result<OntologyTemporalMetadata, Report<DataTypeError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let mut store = self.store().await?;
+
+        store
+            .archive_data_type(actor_id, ArchiveDataTypeParams {
+                data_type_id: Cow::Owned(data_type_id),
+            })
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not archive data type");
+            })
+            .change_context(DataTypeError)
+    }
+
+    async fn unarchive_data_type(
+        &self,
+        scope: Session<Account>,
+        data_type_id: VersionedUrl,
+        provenance: ProvidedOntologyEditionProvenance,
+    ) -> Result<OntologyTemporalMetadata, Report<DataTypeError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let mut store = self.store().await?;
+
+        store
+            .unarchive_data_type(actor_id, UnarchiveDataTypeParams {
+                data_type_id,
+                provenance,
+            })
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not unarchive data type");
+            })
+            .change_context(DataTypeError)
+    }
+
+    async fn check_data_type_permission(
+        &self,
+        scope: Session<Account>,
+        data_type_id: VersionedUrl,
+        permission: DataTypePermission,
+    ) -> Result<PermissionResponse, Report<DataTypeError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let check = self
+            .authorization_api()
+            .await?
+            .check_data_type_permission(
+                actor_id,
+                permission,
+                DataTypeUuid::from_url(&data_type_id),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .inspect_err(|error| {
+                tracing::error!(
+                    ?error,
+                    "Could not check if permission on the data type is granted to the specified \
+                     actor"
+                );
+            })
+            .change_context(DataTypeError)?;
+
+        Ok(PermissionResponse {
+            has_permission: check.has_permission,
+        })
+    }
+}
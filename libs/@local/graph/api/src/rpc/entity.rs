@@ -0,0 +1,327 @@
+use alloc::{borrow::Cow, sync::Arc};
+use std::collections::HashSet;
+
+use error_stack::{Report, ResultExt as _};
+use futures::{StreamExt as _, stream::BoxStream};
+use harpc_server::{error::Forbidden, session::Session};
+use hash_graph_authorization::{
+    AuthorizationApi as _, AuthorizationApiPool,
+    schema::{EntityPermission, EntityRelationAndSubject},
+    zanzibar::Consistency,
+};
+use hash_graph_store::{
+    entity::{CreateEntityParams, EntityStore as _, PatchEntityParams},
+    pool::StorePool,
+};
+use hash_graph_types::{
+    account::AccountId,
+    knowledge::entity::{Entity, EntityId, ProvidedEntityEditionProvenance},
+};
+use hash_temporal_client::TemporalClient;
+
+use super::{
+    GraphSubsystemId,
+    account::PermissionResponse,
+    pagination::{deserialize_request, paginate},
+    session::Account,
+};
+use crate::rest::entity::{
+    GetEntitiesRequest, GetEntitySubgraphRequest, GetEntitySubgraphResponse,
+};
+
+#[must_use]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::Error)]
+#[display("unable to fullfil entity request")]
+pub struct EntityError;
+
+#[harpc_system::service(subsystem = GraphSubsystemId::Entity, version = "0.0")]
+pub trait EntitySystem {
+    type ExecutionScope;
+
+    #[procedure(id = 0x00)]
+    async fn create_entity(
+        &self,
+        scope: Self::ExecutionScope,
+        params: CreateEntityParams<Vec<EntityRelationAndSubject>>,
+    ) -> Result<Entity, Report<EntityError>>;
+
+    /// Queries the entities matching the request.
+    ///
+    /// The request and the pages of the response have the same format as the request and the
+    /// response of the `/entities/query` endpoint.
+    ///
+    /// If a `limit` is specified, it is used as the page size and every page is sent as its own
+    /// item until all entities have been returned.
+    #[procedure(id = 0x01, stream)]
+    async fn query_entities(
+        &self,
+        scope: Self::ExecutionScope,
+        request: serde_json::Value,
+    ) -> Result<
+        BoxStream<'static, Result<serde_json::Value, Report<EntityError>>>,
+        Report<EntityError>,
+    >;
+
+    /// Queries the subgraphs rooted at the entities matching the request.
+    ///
+    /// The request and the pages of the response have the same format as the request and the
+    /// response of the `/entities/query/subgraph` endpoint.
+    ///
+    /// Pages are streamed the same way as in [`query_entities`](Self::query_entities).
+    #[procedure(id = 0x02, stream)]
+    async fn query_entity_subgraph(
+        &self,
+        scope: Self::ExecutionScope,
+        request: serde_json::Value,
+    ) -> Result<
+        BoxStream<'static, Result<serde_json::Value, Report<EntityError>>>,
+        Report<EntityError>,
+    >;
+
+    #[procedure(id = 0x03)]
+    async fn patch_entity(
+        &self,
+        scope: Self::ExecutionScope,
+        params: PatchEntityParams,
+    ) -> Result<Entity, Report<EntityError>>;
+
+    #[procedure(id = 0x04)]
+    async fn archive_entity(
+        &self,
+        scope: Self::ExecutionScope,
+        entity_id: EntityId,
+    ) -> Result<Entity, Report<EntityError>>;
+
+    #[procedure(id = 0x05)]
+    async fn check_entity_permission(
+        &self,
+        scope: Self::ExecutionScope,
+        entity_id: EntityId,
+        permission: EntityPermission,
+    ) -> Result<PermissionResponse, Report<EntityError>>;
+}
+
+#[derive(Debug)]
+#[derive_where::derive_where(Clone)]
+pub struct EntityServer<S, A> {
+    pub authorization_api_pool: Arc<A>,
+    pub temporal_client: Option<Arc<TemporalClient>>,
+    pub store_pool: Arc<S>,
+}
+
+impl<S, A> EntityServer<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    async fn authorization_api(&self) -> Result<A::Api<'_>, Report<EntityError>> {
+        self.authorization_api_pool
+            .acquire()
+            .await
+            .inspect_err(|error| {
+                tracing::error!(?error, "Could not acquire access to the authorization API");
+            })
+            .change_context(EntityError)
+    }
+
+    async fn store(&self) -> Result<S::Store<'_, A::Api<'_>>, Report<EntityError>> {
+        let authorization_api = self.authorization_api().await?;
+
+        self.store_pool
+            .acquire(authorization_api, self.temporal_client.clone())
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not acquire store");
+            })
+            .change_context(EntityError)
+    }
+
+    fn actor(session: &Session<Account>) -> Result<AccountId, Report<EntityError>> {
+        let &Account {
+            actor_id: Some(actor_id),
+        } = session.get()
+        else {
+            let request_info = session.request_info();
+
+            return Err(Report::new(Forbidden {
+                subsystem: request_info.subsystem,
+                procedure: request_info.procedure,
+                reason: Cow::Borrowed("user authentication required"),
+            })
+            .change_context(EntityError));
+        };
+
+        Ok(actor_id)
+    }
+}
+
+impl<S, A> EntitySystem for EntityServer<S, A>
+where
+    S: StorePool + Send + Sync + 'static,
+    A: AuthorizationApiPool + Send + Sync + 'static,
+{
+    type ExecutionScope = Session<Account>;
+
+    async fn create_entity(
+        &self,
+        scope: Session<Account>,
+        params: CreateEntityParams<Vec<EntityRelationAndSubject>>,
+    ) -> Result<Entity, Report<EntityError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let mut store = self.store().await?;
+
+        store
+            .create_entity(actor_id, params)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not create entity");
+            })
+            .change_context(EntityError)
+    }
+
+    async fn query_entities(
+        &self,
+        scope: Session<Account>,
+        request: serde_json::Value,
+    ) -> Result<
+        BoxStream<'static, Result<serde_json::Value, Report<EntityError>>>,
+        Report<EntityError>,
+    > {
+        let actor_id = Self::actor(&scope)?;
+
+        let server = self.clone();
+        Ok(paginate(request, "cursor", EntityError, move |request| {
+            let server = server.clone();
+            async move {
+                let request: GetEntitiesRequest = deserialize_request(&request, EntityError)?;
+
+                let store = server.store().await?;
+                let response = store
+                    .get_entities(actor_id, request.into())
+                    .await
+                    .inspect_err(|report| {
+                        tracing::error!(error=?report, "Could not read entities");
+                    })
+                    .change_context(EntityError)?;
+
+                let cursor = response
+                    .cursor
+                    .as_ref()
+                    .map(serde_json::to_value)
+                    .transpose()
+                    .change_context(EntityError)?;
+
+                Ok((response, cursor))
+            }
+        })
+        .boxed())
+    }
+
+    async fn query_entity_subgraph(
+        &self,
+        scope: Session<Account>,
+        request: serde_json::Value,
+    ) -> Result<
+        BoxStream<'static, Result<serde_json::Value, Report<EntityError>>>,
+        Report<EntityError>,
+    > {
+        let actor_id = Self::actor(&scope)?;
+
+        let server = self.clone();
+        Ok(paginate(request, "cursor", EntityError, move |request| {
+            let server = server.clone();
+            async move {
+                let request: GetEntitySubgraphRequest = deserialize_request(&request, EntityError)?;
+
+                let store = server.store().await?;
+                let response = store
+                    .get_entity_subgraph(actor_id, request.into())
+                    .await
+                    .inspect_err(|report| {
+                        tracing::error!(error=?report, "Could not read entity subgraph");
+                    })
+                    .change_context(EntityError)?;
+
+                let cursor = response
+                    .cursor
+                    .as_ref()
+                    .map(serde_json::to_value)
+                    .transpose()
+                    .change_context(EntityError)?;
+
+                Ok((GetEntitySubgraphResponse::from(response), cursor))
+            }
+        })
+        .boxed())
+    }
+
+    async fn patch_entity(
+        &self,
+        scope: Session<Account>,
+        params: PatchEntityParams,
+    ) -> Result<Entity, Report<EntityError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let mut store = self.store().await?;
+
+        store
+            .patch_entity(actor_id, params)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not patch entity");
+            })
+            .change_context(EntityError)
+    }
+
+    async fn archive_entity(
+        &self,
+        scope: Session<Account>,
+        entity_id: EntityId,
+    ) -> Result<Entity, Report<EntityError>> {
+        self.patch_entity(scope, PatchEntityParams {
+            entity_id,
+            expected_edition_id: None,
+            decision_time: None,
+            entity_type_ids: HashSet::new(),
+            properties: Vec::new(),
+            draft: None,
+            archived: Some(true),
+            confidence: None,
+            link_data: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+    }
+
+    async fn check_entity_permission(
+        &self,
+        scope: Session<Account>,
+        entity_id: EntityId,
+        permission: EntityPermission,
+    ) -> Result<PermissionResponse, Report<EntityError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let check = self
+            .authorization_api()
+            .await?
+            .check_entity_permission(
+                actor_id,
+                permission,
+                entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .inspect_err(|error| {
+                tracing::error!(
+                    ?error,
+                    "Could not check if permission on the entity is granted to the specified actor"
+                );
+            })
+            .change_context(EntityError)?;
+
+        Ok(PermissionResponse {
+            has_permission: check.has_permission,
+        })
+    }
+}
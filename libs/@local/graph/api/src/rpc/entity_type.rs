@@ -0,0 +1,413 @@
+use alloc::{borrow::Cow, sync::Arc};
+
+use error_stack::{Report, ResultExt as _};
+use futures::{StreamExt as _, stream::BoxStream};
+use harpc_server::{error::Forbidden, session::Session};
+use hash_graph_authorization::{
+    AuthorizationApi as _, AuthorizationApiPool, schema::EntityTypePermission,
+    zanzibar::Consistency,
+};
+use hash_graph_postgres_store::ontology::patch_id_and_parse;
+use hash_graph_store::{
+    entity_type::{
+        ArchiveEntityTypeParams, CreateEntityTypeParams, EntityTypeStore as _,
+        GetEntityTypeSubgraphParams, GetEntityTypesParams, UnarchiveEntityTypeParams,
+        UpdateEntityTypesParams,
+    },
+    pool::StorePool,
+    query::ConflictBehavior,
+};
+use hash_graph_types::{
+    account::AccountId,
+    ontology::{
+        EntityTypeMetadata, OntologyTemporalMetadata, OntologyTypeClassificationMetadata,
+        ProvidedOntologyEditionProvenance,
+    },
+};
+use hash_temporal_client::TemporalClient;
+use type_system::{
+    schema::{DomainValidator, EntityTypeUuid, ValidateOntologyType as _},
+    url::{OntologyTypeVersion, VersionedUrl},
+};
+
+use super::{
+    GraphSubsystemId,
+    account::PermissionResponse,
+    pagination::{deserialize_request, paginate},
+    session::Account,
+};
+use crate::rest::entity_type::{
+    CreateEntityTypeRequest, GetEntityTypeSubgraphResponse, UpdateEntityTypeRequest,
+};
+
+#[must_use]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::Error)]
+#[display("unable to fullfil entity type request")]
+pub struct EntityTypeError;
+
+#[harpc_system::service(subsystem = GraphSubsystemId::EntityType, version = "0.0")]
+pub trait EntityTypeSystem {
+    type ExecutionScope;
+
+    #[procedure(id = 0x00)]
+    async fn create_entity_types(
+        &self,
+        scope: Self::ExecutionScope,
+        request: CreateEntityTypeRequest,
+    ) -> Result<Vec<EntityTypeMetadata>, Report<EntityTypeError>>;
+
+    /// Queries the entity types matching the request.
+    ///
+    /// The request and the pages of the response have the same format as the request and the
+    /// response of the `/entity-types/query` endpoint.
+    ///
+    /// If a `limit` is specified, it is used as the page size and every page is sent as its own
+    /// item until all entity types have been returned.
+    #[procedure(id = 0x01, stream)]
+    async fn query_entity_types(
+        &self,
+        scope: Self::ExecutionScope,
+        request: serde_json::Value,
+    ) -> Result<
+        BoxStream<'static, Result<serde_json::Value, Report<EntityTypeError>>>,
+        Report<EntityTypeError>,
+    >;
+
+    /// Queries the subgraphs rooted at the entity types matching the request.
+    ///
+    /// The request and the pages of the response have the same format as the request and the
+    /// response of the `/entity-types/query/subgraph` endpoint.
+    ///
+    /// Pages are streamed the same way as in [`query_entity_types`](Self::query_entity_types).
+    #[procedure(id = 0x02, stream)]
+    async fn query_entity_type_subgraph(
+        &self,
+        scope: Self::ExecutionScope,
+        request: serde_json::Value,
+    ) -> Result<
+        BoxStream<'static, Result<serde_json::Value, Report<EntityTypeError>>>,
+        Report<EntityTypeError>,
+    >;
+
+    #[procedure(id = 0x03)]
+    async fn update_entity_type(
+        &self,
+        scope: Self::ExecutionScope,
+        request: UpdateEntityTypeRequest,
+    ) -> Result<EntityTypeMetadata, Report<EntityTypeError>>;
+
+    #[procedure(id = 0x04)]
+    async fn archive_entity_type(
+        &self,
+        scope: Self::ExecutionScope,
+        entity_type_id: VersionedUrl,
+    ) -> Result<OntologyTemporalMetadata, Report<EntityTypeError>>;
+
+    #[procedure(id = 0x05)]
+    async fn unarchive_entity_type(
+        &self,
+        scope: Self::ExecutionScope,
+        entity_type_id: VersionedUrl,
+        provenance: ProvidedOntologyEditionProvenance,
+    ) -> Result<OntologyTemporalMetadata, Report<EntityTypeError>>;
+
+    #[procedure(id = 0x06)]
+    async fn check_entity_type_permission(
+        &self,
+        scope: Self::ExecutionScope,
+        entity_type_id: VersionedUrl,
+        permission: EntityTypePermission,
+    ) -> Result<PermissionResponse, Report<EntityTypeError>>;
+}
+
+#[derive_where::derive_where(Clone)]
+pub struct EntityTypeServer<S, A> {
+    pub authorization_api_pool: Arc<A>,
+    pub temporal_client: Option<Arc<TemporalClient>>,
+    pub store_pool: Arc<S>,
+    pub domain_validator: DomainValidator,
+}
+
+impl<S, A> EntityTypeServer<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    async fn authorization_api(&self) -> Result<A::Api<'_>, Report<EntityTypeError>> {
+        self.authorization_api_pool
+            .acquire()
+            .await
+            .inspect_err(|error| {
+                tracing::error!(?error, "Could not acquire access to the authorization API");
+            })
+            .change_context(EntityTypeError)
+    }
+
+    async fn store(&self) -> Result<S::Store<'_, A::Api<'_>>, Report<EntityTypeError>> {
+        let authorization_api = self.authorization_api().await?;
+
+        self.store_pool
+            .acquire(authorization_api, self.temporal_client.clone())
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not acquire store");
+            })
+            .change_context(EntityTypeError)
+    }
+
+    fn actor(session: &Session<Account>) -> Result<AccountId, Report<EntityTypeError>> {
+        let &Account {
+            actor_id: Some(actor_id),
+        } = session.get()
+        else {
+            let request_info = session.request_info();
+
+            return Err(Report::new(Forbidden {
+                subsystem: request_info.subsystem,
+                procedure: request_info.procedure,
+                reason: Cow::Borrowed("user authentication required"),
+            })
+            .change_context(EntityTypeError));
+        };
+
+        Ok(actor_id)
+    }
+}
+
+impl<S, A> EntityTypeSystem for EntityTypeServer<S, A>
+where
+    S: StorePool + Send + Sync + 'static,
+    A: AuthorizationApiPool + Send + Sync + 'static,
+{
+    type ExecutionScope = Session<Account>;
+
+    async fn create_entity_types(
+        &self,
+        scope: Session<Account>,
+        request: CreateEntityTypeRequest,
+    ) -> Result<Vec<EntityTypeMetadata>, Report<EntityTypeError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let CreateEntityTypeRequest {
+            schema,
+            owned_by_id,
+            relationships,
+            provenance,
+        } = request;
+
+        let params = schema
+            .into_iter()
+            .map(|schema| {
+                self.domain_validator
+                    .validate(&schema)
+                    .inspect_err(|report| {
+                        tracing::error!(error=?report, id=%schema.id, "Entity type ID failed to validate");
+                    })
+                    .change_context(EntityTypeError)?;
+
+                Ok(CreateEntityTypeParams {
+                    schema,
+                    classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+                    relationships: relationships.clone(),
+                    conflict_behavior: ConflictBehavior::Fail,
+                    provenance: provenance.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, Report<EntityTypeError>>>()?;
+
+        let mut store = self.store().await?;
+
+        store
+            .create_entity_types(actor_id, params)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not create entity types");
+            })
+            .change_context(EntityTypeError)
+    }
+
+    async fn query_entity_types(
+        &self,
+        scope: Session<Account>,
+        request: serde_json::Value,
+    ) -> Result<
+        BoxStream<'static, Result<serde_json::Value, Report<EntityTypeError>>>,
+        Report<EntityTypeError>,
+    > {
+        let actor_id = Self::actor(&scope)?;
+
+        let server = self.clone();
+        Ok(paginate(request, "after", EntityTypeError, move |request| {
+            let server = server.clone();
+            async move {
+                let params: GetEntityTypesParams = deserialize_request(&request, EntityTypeError)?;
+
+                let store = server.store().await?;
+                let response = store
+                    .get_entity_types(actor_id, params)
+                    .await
+                    .inspect_err(|report| {
+                        tracing::error!(error=?report, "Could not read entity types");
+                    })
+                    .change_context(EntityTypeError)?;
+
+                let cursor = response
+                    .cursor
+                    .as_ref()
+                    .map(serde_json::to_value)
+                    .transpose()
+                    .change_context(EntityTypeError)?;
+
+                Ok((response, cursor))
+            }
+        })
+        .boxed())
+    }
+
+    async fn query_entity_type_subgraph(
+        &self,
+        scope: Session<Account>,
+        request: serde_json::Value,
+    ) -> Result<
+        BoxStream<'static, Result<serde_json::Value, Report<EntityTypeError>>>,
+        Report<EntityTypeError>,
+    > {
+        let actor_id = Self::actor(&scope)?;
+
+        let server = self.clone();
+        Ok(paginate(request, "after", EntityTypeError, move |request| {
+            let server = server.clone();
+            async move {
+                let params: GetEntityTypeSubgraphParams =
+                    deserialize_request(&request, EntityTypeError)?;
+
+                let store = server.store().await?;
+                let response = store
+                    .get_entity_type_subgraph(actor_id, params)
+                    .await
+                    .inspect_err(|report| {
+                        tracing::error!(error=?report, "Could not read entity type subgraph");
+                    })
+                    .change_context(EntityTypeError)?;
+
+                let cursor = response
+                    .cursor
+                    .as_ref()
+                    .map(serde_json::to_value)
+                    .transpose()
+                    .change_context(EntityTypeError)?;
+
+                Ok((GetEntityTypeSubgraphResponse::from(response), cursor))
+            }
+        })
+        .boxed())
+    }
+
+    async fn update_entity_type(
+        &self,
+        scope: Session<Account>,
+        request: UpdateEntityTypeRequest,
+    ) -> Result<EntityTypeMetadata, Report<EntityTypeError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let UpdateEntityTypeRequest {
+            schema,
+            mut type_to_update,
+            relationships,
+            provenance,
+        } = request;
+
+        type_to_update.version = OntologyTypeVersion::new(type_to_update.version.inner() + 1);
+
+        let entity_type =
+            patch_id_and_parse(&type_to_update, schema).change_context(EntityTypeError)?;
+
+        let mut store = self.store().await?;
+
+        store
+            .update_entity_type(actor_id, UpdateEntityTypesParams {
+                schema: entity_type,
+                relationships,
+                provenance,
+            })
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not update entity type");
+            })
+            .change_context(EntityTypeError)
+    }
+
+    async fn archive_entity_type(
+        &self,
+        scope: Session<Account>,
+        entity_type_id: VersionedUrl,
+    ) -> Result<OntologyTemporalMetadata, Report<EntityTypeError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let mut store = self.store().await?;
+
+        store
+            .archive_entity_type(actor_id, ArchiveEntityTypeParams {
+                entity_type_id: Cow::Owned(entity_type_id),
+            })
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not archive entity type");
+            })
+            .change_context(EntityTypeError)
+    }
+
+    async fn unarchive_entity_type(
+        &self,
+        scope: Session<Account>,
+        entity_type_id: VersionedUrl,
+        provenance: ProvidedOntologyEditionProvenance,
+    ) -> Result<OntologyTemporalMetadata, Report<EntityTypeError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let mut store = self.store().await?;
+
+        store
+            .unarchive_entity_type(actor_id, UnarchiveEntityTypeParams {
+                entity_type_id: Cow::Owned(entity_type_id),
+                provenance,
+            })
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not unarchive entity type");
+            })
+            .change_context(EntityTypeError)
+    }
+
+    async fn check_entity_type_permission(
+        &self,
+        scope: Session<Account>,
+        entity_type_id: VersionedUrl,
+        permission: EntityTypePermission,
+    ) -> Result<PermissionResponse, Report<EntityTypeError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let check = self
+            .authorization_api()
+            .await?
+            .check_entity_type_permission(
+                actor_id,
+                permission,
+                EntityTypeUuid::from_url(&entity_type_id),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .inspect_err(|error| {
+                tracing::error!(
+                    ?error,
+                    "Could not check if permission on the entity type is granted to the specified \
+                     actor"
+                );
+            })
+            .change_context(EntityTypeError)?;
+
+        Ok(PermissionResponse {
+            has_permission: check.has_permission,
+        })
+    }
+}
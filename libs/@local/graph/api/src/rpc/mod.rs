@@ -1,11 +1,18 @@
 pub mod account;
 pub mod auth;
+pub mod change_feed;
+pub mod data_type;
 pub mod echo;
+pub mod entity;
+pub mod entity_type;
+mod pagination;
+pub mod property_type;
 mod session;
+pub mod web;
 
 use alloc::sync::Arc;
 
-use harpc_codec::{decode::ReportDecoder, encode::ReportEncoder};
+use harpc_codec::{decode::ReportDecoder, encode::ReportEncoder, negotiate::Negotiate};
 use harpc_server::{
     route::Route,
     router::{Router, RouterBuilder},
@@ -20,12 +27,19 @@ use harpc_types::subsystem::SubsystemId;
 use hash_graph_authorization::AuthorizationApiPool;
 use hash_graph_store::pool::StorePool;
 use hash_temporal_client::TemporalClient;
+use type_system::schema::DomainValidator;
 
 use self::{
     account::{AccountDelegate, AccountServer},
     auth::{AuthenticationDelegate, AuthenticationServer},
+    change_feed::{ChangeFeedDelegate, ChangeFeedServer},
+    data_type::{DataTypeDelegate, DataTypeServer},
     echo::{EchoDelegate, EchoServer},
+    entity::{EntityDelegate, EntityServer},
+    entity_type::{EntityTypeDelegate, EntityTypeServer},
+    property_type::{PropertyTypeDelegate, PropertyTypeServer},
     session::Account,
+    web::{WebDelegate, WebServer},
 };
 
 #[derive(Debug, Copy, Clone)]
@@ -33,6 +47,12 @@ pub enum GraphSubsystemId {
     Echo,
     Authentication,
     Account,
+    ChangeFeed,
+    Entity,
+    EntityType,
+    PropertyType,
+    DataType,
+    Web,
 }
 
 impl SubsystemIdentifier for GraphSubsystemId {
@@ -44,6 +64,12 @@ impl SubsystemIdentifier for GraphSubsystemId {
             0x00 => Some(Self::Echo),
             0x01 => Some(Self::Authentication),
             0x02 => Some(Self::Account),
+            0x03 => Some(Self::ChangeFeed),
+            0x04 => Some(Self::Entity),
+            0x05 => Some(Self::EntityType),
+            0x06 => Some(Self::PropertyType),
+            0x07 => Some(Self::DataType),
+            0x08 => Some(Self::Web),
             _ => None,
         }
     }
@@ -53,6 +79,12 @@ impl SubsystemIdentifier for GraphSubsystemId {
             Self::Echo => SubsystemId::new(0x00),
             Self::Authentication => SubsystemId::new(0x01),
             Self::Account => SubsystemId::new(0x02),
+            Self::ChangeFeed => SubsystemId::new(0x03),
+            Self::Entity => SubsystemId::new(0x04),
+            Self::EntityType => SubsystemId::new(0x05),
+            Self::PropertyType => SubsystemId::new(0x06),
+            Self::DataType => SubsystemId::new(0x07),
+            Self::Web => SubsystemId::new(0x08),
         }
     }
 }
@@ -61,6 +93,7 @@ pub struct Dependencies<S, A, C> {
     pub store: Arc<S>,
     pub authorization_api: Arc<A>,
     pub temporal_client: Option<TemporalClient>,
+    pub domain_validator: DomainValidator,
     pub codec: C,
 }
 
@@ -78,8 +111,10 @@ pub fn rpc_router<S, A, C, N>(
 where
     S: StorePool + Send + Sync + 'static,
     A: AuthorizationApiPool + Send + Sync + 'static,
-    C: ReportEncoder + ReportDecoder + Clone + Send + Sync + 'static,
+    C: ReportEncoder + ReportDecoder + Negotiate + Clone + Send + Sync + 'static,
 {
+    let temporal_client = dependencies.temporal_client.map(Arc::new);
+
     let builder = RouterBuilder::new(dependencies.codec)
         .with_builder(|builder| {
             builder
@@ -88,9 +123,42 @@ where
         })
         .register(AuthenticationDelegate::new(AuthenticationServer))
         .register(AccountDelegate::new(AccountServer {
+            store_pool: Arc::clone(&dependencies.store),
+            authorization_api_pool: Arc::clone(&dependencies.authorization_api),
+            temporal_client: temporal_client.clone(),
+        }))
+        .register(ChangeFeedDelegate::new(ChangeFeedServer {
+            store_pool: Arc::clone(&dependencies.store),
+            authorization_api_pool: Arc::clone(&dependencies.authorization_api),
+            temporal_client: temporal_client.clone(),
+        }))
+        .register(EntityDelegate::new(EntityServer {
+            store_pool: Arc::clone(&dependencies.store),
+            authorization_api_pool: Arc::clone(&dependencies.authorization_api),
+            temporal_client: temporal_client.clone(),
+        }))
+        .register(EntityTypeDelegate::new(EntityTypeServer {
+            store_pool: Arc::clone(&dependencies.store),
+            authorization_api_pool: Arc::clone(&dependencies.authorization_api),
+            temporal_client: temporal_client.clone(),
+            domain_validator: dependencies.domain_validator.clone(),
+        }))
+        .register(PropertyTypeDelegate::new(PropertyTypeServer {
+            store_pool: Arc::clone(&dependencies.store),
+            authorization_api_pool: Arc::clone(&dependencies.authorization_api),
+            temporal_client: temporal_client.clone(),
+            domain_validator: dependencies.domain_validator.clone(),
+        }))
+        .register(DataTypeDelegate::new(DataTypeServer {
+            store_pool: Arc::clone(&dependencies.store),
+            authorization_api_pool: Arc::clone(&dependencies.authorization_api),
+            temporal_client: temporal_client.clone(),
+            domain_validator: dependencies.domain_validator,
+        }))
+        .register(WebDelegate::new(WebServer {
             store_pool: dependencies.store,
             authorization_api_pool: dependencies.authorization_api,
-            temporal_client: dependencies.temporal_client.map(Arc::new),
+            temporal_client,
         }))
         .register(EchoDelegate::new(EchoServer));
 
@@ -100,3 +168,220 @@ where
 
     (router, task)
 }
+
+#[cfg(test)]
+mod tests {
+    use harpc_system::{Subsystem, SubsystemIdentifier as _, procedure::ProcedureIdentifier};
+    use harpc_types::{procedure::ProcedureId, subsystem::SubsystemId, version::Version};
+    use hash_graph_authorization::schema::EntityRelationAndSubject;
+    use hash_graph_store::{
+        account::InsertWebIdParams,
+        entity::{CreateEntityParams, PatchEntityParams},
+    };
+    use hash_graph_types::{
+        knowledge::entity::Entity,
+        ontology::{DataTypeMetadata, EntityTypeMetadata, PropertyTypeMetadata},
+        owned_by_id::OwnedById,
+    };
+    use serde::{Serialize, de::DeserializeOwned};
+    use serde_json::json;
+
+    use super::{account, auth, change_feed, echo};
+    use crate::rest::{
+        data_type::UpdateDataTypeRequest, entity_type::UpdateEntityTypeRequest,
+        property_type::UpdatePropertyTypeRequest,
+    };
+
+    fn assert_subsystem<S: Subsystem>(
+        id: u16,
+        procedures: impl IntoIterator<Item = (S::ProcedureId, u16)>,
+    ) {
+        assert_eq!(S::ID.into_id(), SubsystemId::new(id));
+        assert_eq!(S::VERSION, Version {
+            major: 0x00,
+            minor: 0x00
+        });
+
+        for (procedure, id) in procedures {
+            assert_eq!(procedure.into_id(), ProcedureId::new(id));
+            assert_eq!(
+                S::ProcedureId::from_id(ProcedureId::new(id)).map(ProcedureIdentifier::into_id),
+                Some(ProcedureId::new(id))
+            );
+        }
+    }
+
+    // The ids are part of the wire format and must match the ones of the previously hand-written
+    // subsystem definitions.
+    #[test]
+    fn echo_ids() {
+        assert_subsystem::<echo::meta::EchoSystem>(0x00, [(
+            echo::meta::EchoProcedureId::Echo,
+            0x00,
+        )]);
+    }
+
+    #[test]
+    fn authentication_ids() {
+        assert_subsystem::<auth::meta::AuthenticationSystem>(0x01, [(
+            auth::meta::AuthenticationProcedureId::Authenticate,
+            0x00,
+        )]);
+    }
+
+    #[test]
+    fn account_ids() {
+        assert_subsystem::<account::meta::AccountSystem>(0x02, [
+            (account::meta::AccountProcedureId::CreateAccount, 0x00),
+            (account::meta::AccountProcedureId::CreateAccountGroup, 0x01),
+            (
+                account::meta::AccountProcedureId::CheckAccountGroupPermission,
+                0x02,
+            ),
+            (
+                account::meta::AccountProcedureId::AddAccountGroupMember,
+                0x03,
+            ),
+            (
+                account::meta::AccountProcedureId::RemoveAccountGroupMember,
+                0x04,
+            ),
+        ]);
+    }
+
+    #[test]
+    fn change_feed_ids() {
+        assert_subsystem::<change_feed::meta::ChangeFeedSystem>(0x03, [(
+            change_feed::meta::ChangeFeedProcedureId::ReadGraphChanges,
+            0x00,
+        )]);
+    }
+
+    const OWNED_BY_ID: &str = "a2ed6e70-4a4c-4f2a-9a0f-8b9bfb3b3f0e";
+    const ACCOUNT_ID: &str = "0d3d2b33-3c0a-4a94-8d11-8fa3d4e5d6f7";
+    const ENTITY_ID: &str =
+        "a2ed6e70-4a4c-4f2a-9a0f-8b9bfb3b3f0e~5f1e2d3c-4b5a-4978-8695-a4b3c2d1e0f9";
+    const TIMESTAMP: &str = "2024-01-01T00:00:00Z";
+
+    /// Checks that a request or response of a procedure survives being sent over the wire.
+    ///
+    /// Clients encode requests and decode responses the same way as the delegates decode requests
+    /// and encode responses, so the value has to be decodable from its own encoding.
+    fn assert_round_trip<T: Serialize + DeserializeOwned>(value: serde_json::Value) {
+        let decoded: T = serde_json::from_value(value).expect("value should be decodable");
+        let encoded = serde_json::to_value(&decoded).expect("value should be encodable");
+
+        let decoded: T =
+            serde_json::from_value(encoded.clone()).expect("encoded value should be decodable");
+        assert_eq!(
+            serde_json::to_value(&decoded).expect("value should be encodable"),
+            encoded
+        );
+    }
+
+    fn ontology_type_metadata(base_url: &str) -> serde_json::Value {
+        json!({
+            "recordId": { "baseUrl": base_url, "version": 1 },
+            "ownedById": OWNED_BY_ID,
+            "temporalVersioning": {
+                "transactionTime": {
+                    "start": { "kind": "inclusive", "limit": TIMESTAMP },
+                    "end": { "kind": "unbounded" },
+                },
+            },
+            "provenance": { "edition": { "createdById": ACCOUNT_ID } },
+        })
+    }
+
+    #[test]
+    fn data_type_round_trip() {
+        assert_round_trip::<UpdateDataTypeRequest>(json!({
+            "schema": { "title": "Text" },
+            "typeToUpdate": "https://blockprotocol.org/@alice/types/data-type/text/v/1",
+            "relationships": [],
+            "conversions": {},
+        }));
+        assert_round_trip::<DataTypeMetadata>(ontology_type_metadata(
+            "https://blockprotocol.org/@alice/types/data-type/text/",
+        ));
+    }
+
+    #[test]
+    fn property_type_round_trip() {
+        assert_round_trip::<UpdatePropertyTypeRequest>(json!({
+            "schema": { "title": "Name" },
+            "typeToUpdate": "https://blockprotocol.org/@alice/types/property-type/name/v/1",
+            "relationships": [],
+        }));
+        assert_round_trip::<PropertyTypeMetadata>(ontology_type_metadata(
+            "https://blockprotocol.org/@alice/types/property-type/name/",
+        ));
+    }
+
+    #[test]
+    fn entity_type_round_trip() {
+        assert_round_trip::<UpdateEntityTypeRequest>(json!({
+            "schema": { "title": "Person" },
+            "typeToUpdate": "https://blockprotocol.org/@alice/types/entity-type/person/v/1",
+            "relationships": [],
+        }));
+        assert_round_trip::<EntityTypeMetadata>(ontology_type_metadata(
+            "https://blockprotocol.org/@alice/types/entity-type/person/",
+        ));
+    }
+
+    #[test]
+    fn entity_round_trip() {
+        assert_round_trip::<CreateEntityParams<Vec<EntityRelationAndSubject>>>(json!({
+            "ownedById": OWNED_BY_ID,
+            "entityTypeIds": ["https://blockprotocol.org/@alice/types/entity-type/person/v/1"],
+            "properties": {},
+            "draft": false,
+            "relationships": [],
+        }));
+        assert_round_trip::<PatchEntityParams>(json!({
+            "entityId": ENTITY_ID,
+            "properties": [{
+                "op": "remove",
+                "path": ["https://blockprotocol.org/@alice/types/property-type/name/"],
+            }],
+            "archived": true,
+        }));
+        assert_round_trip::<Entity>(json!({
+            "properties": {},
+            "metadata": {
+                "recordId": {
+                    "entityId": ENTITY_ID,
+                    "editionId": "7c9e6679-7425-40de-944b-e07fc1f90ae7",
+                },
+                "temporalVersioning": {
+                    "decisionTime": {
+                        "start": { "kind": "inclusive", "limit": TIMESTAMP },
+                        "end": { "kind": "unbounded" },
+                    },
+                    "transactionTime": {
+                        "start": { "kind": "inclusive", "limit": TIMESTAMP },
+                        "end": { "kind": "unbounded" },
+                    },
+                },
+                "entityTypeIds": ["https://blockprotocol.org/@alice/types/entity-type/person/v/1"],
+                "archived": false,
+                "provenance": {
+                    "createdById": ACCOUNT_ID,
+                    "createdAtTransactionTime": TIMESTAMP,
+                    "createdAtDecisionTime": TIMESTAMP,
+                    "edition": { "createdById": ACCOUNT_ID },
+                },
+            },
+        }));
+    }
+
+    #[test]
+    fn web_round_trip() {
+        assert_round_trip::<InsertWebIdParams>(json!({
+            "ownedById": OWNED_BY_ID,
+            "owner": { "kind": "account", "subjectId": ACCOUNT_ID },
+        }));
+        assert_round_trip::<OwnedById>(json!(OWNED_BY_ID));
+    }
+}
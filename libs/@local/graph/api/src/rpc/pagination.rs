@@ -0,0 +1,211 @@
+use core::error::Error;
+
+use error_stack::{Report, ResultExt as _};
+use futures::{Stream, stream};
+use serde::{Deserialize, Serialize};
+
+/// Deserializes the parameters of a query from its JSON request.
+///
+/// The parameters are deserialized from a JSON value instead of the raw payload to allow borrowed
+/// deserialization, same as the REST endpoints.
+pub(crate) fn deserialize_request<'de, T, C>(
+    request: &'de serde_json::Value,
+    context: C,
+) -> Result<T, Report<C>>
+where
+    T: Deserialize<'de>,
+    C: Error + Send + Sync + 'static,
+{
+    T::deserialize(request).change_context(context)
+}
+
+/// Streams all pages of a paginated query.
+///
+/// The query is called with the request of the current page and returns the page together with
+/// the cursor to continue from. As long as a cursor is returned, it is written to `cursor_field`
+/// of the request and the next page is requested. The `limit` of the request is used as the page
+/// size; if no limit is specified the store returns all records at once, so only a single page is
+/// streamed.
+///
+/// Pages are sent as JSON values in the same format as the response of the REST endpoint, so
+/// clients can decode them without the response types having to be deserializable.
+///
+/// The stream ends after the first error.
+pub(crate) fn paginate<T, C, F, Fut>(
+    request: serde_json::Value,
+    cursor_field: &'static str,
+    context: C,
+    query: F,
+) -> impl Stream<Item = Result<serde_json::Value, Report<C>>> + Send + 'static
+where
+    T: Serialize + Send + 'static,
+    C: Error + Copy + Send + Sync + 'static,
+    F: FnMut(serde_json::Value) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<(T, Option<serde_json::Value>), Report<C>>> + Send + 'static,
+{
+    let paginated = request.get("limit").is_some_and(|limit| !limit.is_null());
+
+    stream::try_unfold(
+        (Some(request), query),
+        move |(request, mut query)| async move {
+            let Some(request) = request else {
+                return Ok(None);
+            };
+
+            let (page, cursor) = query(request.clone()).await?;
+            let page = serde_json::to_value(page).change_context(context)?;
+
+            let next_request = match (request, cursor) {
+                (serde_json::Value::Object(mut fields), Some(cursor)) if paginated => {
+                    fields.insert(cursor_field.to_owned(), cursor);
+                    Some(serde_json::Value::Object(fields))
+                }
+                _ => None,
+            };
+
+            Ok(Some((page, (next_request, query))))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+    use core::error::Error;
+    use std::sync::Mutex;
+
+    use error_stack::Report;
+    use futures::{StreamExt as _, TryStreamExt as _};
+    use serde_json::json;
+
+    use super::{deserialize_request, paginate};
+
+    #[derive(Debug, Copy, Clone, derive_more::Display)]
+    #[display("query failed")]
+    struct QueryError;
+
+    impl Error for QueryError {}
+
+    /// Returns the page of the numbers `0..total` selected by the `limit` and `after` fields of
+    /// the request.
+    fn query_numbers(
+        request: &serde_json::Value,
+        total: u64,
+    ) -> (Vec<u64>, Option<serde_json::Value>) {
+        let start = request["after"].as_u64().map_or(0, |after| after + 1);
+        let end = request["limit"]
+            .as_u64()
+            .map_or(total, |limit| (start + limit).min(total));
+        let cursor = (end < total).then(|| json!(end - 1));
+
+        ((start..end).collect(), cursor)
+    }
+
+    /// Collects all pages of the numbers `0..total` together with the requests of every page.
+    async fn collect_pages(
+        request: serde_json::Value,
+        total: u64,
+    ) -> (Vec<serde_json::Value>, Vec<serde_json::Value>) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let pages = paginate(request, "after", QueryError, {
+            let requests = Arc::clone(&requests);
+            move |request| {
+                let page = query_numbers(&request, total);
+                requests
+                    .lock()
+                    .expect("lock should not be poisoned")
+                    .push(request);
+                async move { Ok(page) }
+            }
+        })
+        .try_collect()
+        .await
+        .expect("query should succeed");
+
+        let requests = requests
+            .lock()
+            .expect("lock should not be poisoned")
+            .clone();
+        (pages, requests)
+    }
+
+    #[tokio::test]
+    async fn single_page_without_limit() {
+        let (pages, requests) = collect_pages(json!({ "filter": {} }), 5).await;
+
+        assert_eq!(pages, [json!([0, 1, 2, 3, 4])]);
+        assert_eq!(requests, [json!({ "filter": {} })]);
+    }
+
+    #[tokio::test]
+    async fn null_limit_is_not_paginated() {
+        let (pages, requests) = collect_pages(json!({ "limit": null }), 3).await;
+
+        assert_eq!(pages, [json!([0, 1, 2])]);
+        assert_eq!(requests.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn streams_all_pages() {
+        let (pages, requests) = collect_pages(json!({ "limit": 2 }), 5).await;
+
+        assert_eq!(pages, [json!([0, 1]), json!([2, 3]), json!([4])]);
+        assert_eq!(requests, [
+            json!({ "limit": 2 }),
+            json!({ "limit": 2, "after": 1 }),
+            json!({ "limit": 2, "after": 3 }),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn last_full_page_ends_stream() {
+        let (pages, requests) = collect_pages(json!({ "limit": 2 }), 4).await;
+
+        assert_eq!(pages, [json!([0, 1]), json!([2, 3])]);
+        assert_eq!(requests.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn stops_after_error() {
+        let mut calls = 0_u32;
+        let pages = paginate(json!({ "limit": 1 }), "after", QueryError, move |_| {
+            calls += 1;
+            let call = calls;
+            async move {
+                if call == 1 {
+                    Ok((call, Some(json!(call))))
+                } else {
+                    Err(Report::new(QueryError))
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+        .await;
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!(
+            *pages[0].as_ref().expect("first page should succeed"),
+            json!(1)
+        );
+        assert!(pages[1].is_err());
+    }
+
+    #[test]
+    fn deserializes_borrowed_request() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Request<'a> {
+            #[serde(borrow)]
+            name: &'a str,
+        }
+
+        let request = json!({ "name": "Alice" });
+        let parsed: Request<'_> =
+            deserialize_request(&request, QueryError).expect("request should be valid");
+        assert_eq!(parsed.name, "Alice");
+
+        let report = deserialize_request::<Request<'_>, _>(&json!({}), QueryError)
+            .expect_err("request should be invalid");
+        assert!(report.contains::<QueryError>());
+    }
+}
@@ -0,0 +1,421 @@
+use alloc::{borrow::Cow, sync::Arc};
+
+use error_stack::{Report, ResultExt as _};
+use futures::{StreamExt as _, stream::BoxStream};
+use harpc_server::{error::Forbidden, session::Session};
+use hash_graph_authorization::{
+    AuthorizationApi as _, AuthorizationApiPool, schema::PropertyTypePermission,
+    zanzibar::Consistency,
+};
+use hash_graph_postgres_store::ontology::patch_id_and_parse;
+use hash_graph_store::{
+    pool::StorePool,
+    property_type::{
+        ArchivePropertyTypeParams, CreatePropertyTypeParams, GetPropertyTypeSubgraphParams,
+        GetPropertyTypesParams, PropertyTypeStore as _, UnarchivePropertyTypeParams,
+        UpdatePropertyTypesParams,
+    },
+    query::ConflictBehavior,
+};
+use hash_graph_types::{
+    account::AccountId,
+    ontology::{
+        OntologyTemporalMetadata, OntologyTypeClassificationMetadata, PropertyTypeMetadata,
+        ProvidedOntologyEditionProvenance,
+    },
+};
+use hash_temporal_client::TemporalClient;
+use type_system::{
+    schema::{DomainValidator, PropertyTypeUuid, ValidateOntologyType as _},
+    url::{OntologyTypeVersion, VersionedUrl},
+};
+
+use super::{
+    GraphSubsystemId,
+    account::PermissionResponse,
+    pagination::{deserialize_request, paginate},
+    session::Account,
+};
+use crate::rest::property_type::{
+    CreatePropertyTypeRequest, GetPropertyTypeSubgraphResponse, UpdatePropertyTypeRequest,
+};
+
+#[must_use]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::Error)]
+#[display("unable to fullfil property type request")]
+pub struct PropertyTypeError;
+
+#[harpc_system::service(
+    subsystem = GraphSubsystemId::PropertyType,
+    version = "0.0"
+)]
+pub trait PropertyTypeSystem {
+    type ExecutionScope;
+
+    #[procedure(id = 0x00)]
+    async fn create_property_types(
+        &self,
+        scope: Self::ExecutionScope,
+        request: CreatePropertyTypeRequest,
+    ) -> Result<Vec<PropertyTypeMetadata>, Report<PropertyTypeError>>;
+
+    /// Queries the property types matching the request.
+    ///
+    /// The request and the pages of the response have the same format as the request and the
+    /// response of the `/property-types/query` endpoint.
+    ///
+    /// If a `limit` is specified, it is used as the page size and every page is sent as its own
+    /// item until all property types have been returned.
+    #[procedure(id = 0x01, stream)]
+    async fn query_property_types(
+        &self,
+        scope: Self::ExecutionScope,
+        request: serde_json::Value,
+    ) -> Result<
+        BoxStream<'static, Result<serde_json::Value, Report<PropertyTypeError>>>,
+        Report<PropertyTypeError>,
+    >;
+
+    /// Queries the subgraphs rooted at the property types matching the request.
+    ///
+    /// The request and the pages of the response have the same format as the request and the
+    /// response of the `/property-types/query/subgraph` endpoint.
+    ///
+    /// Pages are streamed the same way as in [`query_property_types`](Self::query_property_types).
+    #[procedure(id = 0x02, stream)]
+    async fn query_property_type_subgraph(
+        &self,
+        scope: Self::ExecutionScope,
+        request: serde_json::Value,
+    ) -> Result<
+        BoxStream<'static, Result<serde_json::Value, Report<PropertyTypeError>>>,
+        Report<PropertyTypeError>,
+    >;
+
+    #[procedure(id = 0x03)]
+    async fn update_property_type(
+        &self,
+        scope: Self::ExecutionScope,
+        request: UpdatePropertyTypeRequest,
+    ) -> Result<PropertyTypeMetadata, Report<PropertyTypeError>>;
+
+    #[procedure(id = 0x04)]
+    async fn archive_property_type(
+        &self,
+        scope: Self::ExecutionScope,
+        property_type_id: VersionedUrl,
+    ) -> Result<OntologyTemporalMetadata, Report<PropertyTypeError>>;
+
+    #[procedure(id = 0x05)]
+    async fn unarchive_property_type(
+        &self,
+        scope: Self::ExecutionScope,
+        property_type_id: VersionedUrl,
+        provenance: ProvidedOntologyEditionProvenance,
+    ) -> Result<OntologyTemporalMetadata, Report<PropertyTypeError>>;
+
+    #[procedure(id = 0x06)]
+    async fn check_property_type_permission(
+        &self,
+        scope: Self::ExecutionScope,
+        property_type_id: VersionedUrl,
+        permission: PropertyTypePermission,
+    ) -> Result<PermissionResponse, Report<PropertyTypeError>>;
+}
+
+#[derive_where::derive_where(Clone)]
+pub struct PropertyTypeServer<S, A> {
+    pub authorization_api_pool: Arc<A>,
+    pub temporal_client: Option<Arc<TemporalClient>>,
+    pub store_pool: Arc<S>,
+    pub domain_validator: DomainValidator,
+}
+
+impl<S, A> PropertyTypeServer<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    async fn authorization_api(&self) -> Result<A::Api<'_>, Report<PropertyTypeError>> {
+        self.authorization_api_pool
+            .acquire()
+            .await
+            .inspect_err(|error| {
+                tracing::error!(?error, "Could not acquire access to the authorization API");
+            })
+            .change_context(PropertyTypeError)
+    }
+
+    async fn store(&self) -> Result<S::Store<'_, A::Api<'_>>, Report<PropertyTypeError>> {
+        let authorization_api = self.authorization_api().await?;
+
+        self.store_pool
+            .acquire(authorization_api, self.temporal_client.clone())
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not acquire store");
+            })
+            .change_context(PropertyTypeError)
+    }
+
+    fn actor(session: &Session<Account>) -> Result<AccountId, Report<PropertyTypeError>> {
+        let &Account {
+            actor_id: Some(actor_id),
+        } = session.get()
+        else {
+            let request_info = session.request_info();
+
+            return Err(Report::new(Forbidden {
+                subsystem: request_info.subsystem,
+                procedure: request_info.procedure,
+                reason: Cow::Borrowed("user authentication required"),
+            })
+            .change_context(PropertyTypeError));
+        };
+
+        Ok(actor_id)
+    }
+}
+
+impl<S, A> PropertyTypeSystem for PropertyTypeServer<S, A>
+where
+    S: StorePool + Send + Sync + 'static,
+    A: AuthorizationApiPool + Send + Sync + 'static,
+{
+    type ExecutionScope = Session<Account>;
+
+    async fn create_property_types(
+        &self,
+        scope: Session<Account>,
+        request: CreatePropertyTypeRequest,
+    ) -> Result<Vec<PropertyTypeMetadata>, Report<PropertyTypeError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let CreatePropertyTypeRequest {
+            schema,
+            owned_by_id,
+            relationships,
+            provenance,
+        } = request;
+
+        let params = schema
+            .into_iter()
+            .map(|schema| {
+                self.domain_validator
+                    .validate(&schema)
+                    .inspect_err(|report| {
+                        tracing::error!(error=?report, id=%schema.id, "Property type ID failed to validate");
+                    })
+                    .change_context(PropertyTypeError)?;
+
+                Ok(CreatePropertyTypeParams {
+                    schema,
+                    classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+                    relationships: relationships.clone(),
+                    conflict_behavior: ConflictBehavior::Fail,
+                    provenance: provenance.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>, Report<PropertyTypeError>>>()?;
+
+        let mut store = self.store().await?;
+
+        store
+            .create_property_types(actor_id, params)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not create property types");
+            })
+            .change_context(PropertyTypeError)
+    }
+
+    async fn query_property_types(
+        &self,
+        scope: Session<Account>,
+        request: serde_json::Value,
+    ) -> Result<
+        BoxStream<'static, Result<serde_json::Value, Report<PropertyTypeError>>>,
+        Report<PropertyTypeError>,
+    > {
+        let actor_id = Self::actor(&scope)?;
+
+        let server = self.clone();
+        Ok(
+            paginate(request, "after", PropertyTypeError, move |request| {
+                let server = server.clone();
+                async move {
+                    let params: GetPropertyTypesParams =
+                        deserialize_request(&request, PropertyTypeError)?;
+
+                    let store = server.store().await?;
+                    let response = store
+                        .get_property_types(actor_id, params)
+                        .await
+                        .inspect_err(|report| {
+                            tracing::error!(error=?report, "Could not read property types");
+                        })
+                        .change_context(PropertyTypeError)?;
+
+                    let cursor = response
+                        .cursor
+                        .as_ref()
+                        .map(serde_json::to_value)
+                        .transpose()
+                        .change_context(PropertyTypeError)?;
+
+                    Ok((response, cursor))
+                }
+            })
+            .boxed(),
+        )
+    }
+
+    async fn query_property_type_subgraph(
+        &self,
+        scope: Session<Account>,
+        request: serde_json::Value,
+    ) -> Result<
+        BoxStream<'static, Result<serde_json::Value, Report<PropertyTypeError>>>,
+        Report<PropertyTypeError>,
+    > {
+        let actor_id = Self::actor(&scope)?;
+
+        let server = self.clone();
+        Ok(
+            paginate(request, "after", PropertyTypeError, move |request| {
+                let server = server.clone();
+                async move {
+                    let params: GetPropertyTypeSubgraphParams =
+                        deserialize_request(&request, PropertyTypeError)?;
+
+                    let store = server.store().await?;
+                    let response = store
+                        .get_property_type_subgraph(actor_id, params)
+                        .await
+                        .inspect_err(|report| {
+                            tracing::error!(error=?report, "Could not read property type subgraph");
+                        })
+                        .change_context(PropertyTypeError)?;
+
+                    let cursor = response
+                        .cursor
+                        .as_ref()
+                        .map(serde_json::to_value)
+                        .transpose()
+                        .change_context(PropertyTypeError)?;
+
+                    Ok((GetPropertyTypeSubgraphResponse::from(response), cursor))
+                }
+            })
+            .boxed(),
+        )
+    }
+
+    async fn update_property_type(
+        &self,
+        scope: Session<Account>,
+        request: UpdatePropertyTypeRequest,
+    ) -> Result<PropertyTypeMetadata, Report<PropertyTypeError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let UpdatePropertyTypeRequest {
+            schema,
+            mut type_to_update,
+            relationships,
+            provenance,
+        } = request;
+
+        type_to_update.version = OntologyTypeVersion::new(type_to_update.version.inner() + 1);
+
+        let property_type =
+            patch_id_and_parse(&type_to_update, schema).change_context(PropertyTypeError)?;
+
+        let mut store = self.store().await?;
+
+        store
+            .update_property_type(actor_id, UpdatePropertyTypesParams {
+                schema: property_type,
+                relationships,
+                provenance,
+            })
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not update property type");
+            })
+            .change_context(PropertyTypeError)
+    }
+
+    async fn archive_property_type(
+        &self,
+        scope: Session<Account>,
+        property_type_id: VersionedUrl,
+    ) -> Result<OntologyTemporalMetadata, Report<PropertyTypeError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let mut store = self.store().await?;
+
+        store
+            .archive_property_type(actor_id, ArchivePropertyTypeParams {
+                property_type_id: Cow::Owned(property_type_id),
+            })
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not archive property type");
+            })
+            .change_context(PropertyTypeError)
+    }
+
+    async fn unarchive_property_type(
+        &self,
+        scope: Session<Account>,
+        property_type_id: VersionedUrl,
+        provenance: ProvidedOntologyEditionProvenance,
+    ) -> Result<OntologyTemporalMetadata, Report<PropertyTypeError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let mut store = self.store().await?;
+
+        store
+            .unarchive_property_type(actor_id, UnarchivePropertyTypeParams {
+                property_type_id: Cow::Owned(property_type_id),
+                provenance,
+            })
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not unarchive property type");
+            })
+            .change_context(PropertyTypeError)
+    }
+
+    async fn check_property_type_permission(
+        &self,
+        scope: Session<Account>,
+        property_type_id: VersionedUrl,
+        permission: PropertyTypePermission,
+    ) -> Result<PermissionResponse, Report<PropertyTypeError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let check = self
+            .authorization_api()
+            .await?
+            .check_property_type_permission(
+                actor_id,
+                permission,
+                PropertyTypeUuid::from_url(&property_type_id),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .inspect_err(|error| {
+                tracing::error!(
+                    ?error,
+                    "Could not check if permission on the property type is granted to the \
+                     specified actor"
+                );
+            })
+            .change_context(PropertyTypeError)?;
+
+        Ok(PermissionResponse {
+            has_permission: check.has_permission,
+        })
+    }
+}
@@ -0,0 +1,149 @@
+use alloc::{borrow::Cow, sync::Arc};
+
+use error_stack::{Report, ResultExt as _};
+use harpc_server::{error::Forbidden, session::Session};
+use hash_graph_authorization::{
+    AuthorizationApi as _, AuthorizationApiPool, schema::WebPermission, zanzibar::Consistency,
+};
+use hash_graph_store::{
+    account::{AccountStore as _, InsertWebIdParams},
+    pool::StorePool,
+};
+use hash_graph_types::{account::AccountId, owned_by_id::OwnedById};
+use hash_temporal_client::TemporalClient;
+
+use super::{GraphSubsystemId, account::PermissionResponse, session::Account};
+
+#[must_use]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::Error)]
+#[display("unable to fullfil web request")]
+pub struct WebError;
+
+#[harpc_system::service(subsystem = GraphSubsystemId::Web, version = "0.0")]
+pub trait WebSystem {
+    type ExecutionScope;
+
+    #[procedure(id = 0x00)]
+    async fn create_web(
+        &self,
+        scope: Self::ExecutionScope,
+        params: InsertWebIdParams,
+    ) -> Result<OwnedById, Report<WebError>>;
+
+    #[procedure(id = 0x01)]
+    async fn check_web_permission(
+        &self,
+        scope: Self::ExecutionScope,
+        web_id: OwnedById,
+        permission: WebPermission,
+    ) -> Result<PermissionResponse, Report<WebError>>;
+}
+
+#[derive(Debug)]
+#[derive_where::derive_where(Clone)]
+pub struct WebServer<S, A> {
+    pub authorization_api_pool: Arc<A>,
+    pub temporal_client: Option<Arc<TemporalClient>>,
+    pub store_pool: Arc<S>,
+}
+
+impl<S, A> WebServer<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    async fn authorization_api(&self) -> Result<A::Api<'_>, Report<WebError>> {
+        self.authorization_api_pool
+            .acquire()
+            .await
+            .inspect_err(|error| {
+                tracing::error!(?error, "Could not acquire access to the authorization API");
+            })
+            .change_context(WebError)
+    }
+
+    async fn store(&self) -> Result<S::Store<'_, A::Api<'_>>, Report<WebError>> {
+        let authorization_api = self.authorization_api().await?;
+
+        self.store_pool
+            .acquire(authorization_api, self.temporal_client.clone())
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not acquire store");
+            })
+            .change_context(WebError)
+    }
+
+    fn actor(session: &Session<Account>) -> Result<AccountId, Report<WebError>> {
+        let &Account {
+            actor_id: Some(actor_id),
+        } = session.get()
+        else {
+            let request_info = session.request_info();
+
+            return Err(Report::new(Forbidden {
+                subsystem: request_info.subsystem,
+                procedure: request_info.procedure,
+                reason: Cow::Borrowed("user authentication required"),
+            })
+            .change_context(WebError));
+        };
+
+        Ok(actor_id)
+    }
+}
+
+impl<S, A> WebSystem for WebServer<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    type ExecutionScope = Session<Account>;
+
+    async fn create_web(
+        &self,
+        scope: Session<Account>,
+        params: InsertWebIdParams,
+    ) -> Result<OwnedById, Report<WebError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let mut store = self.store().await?;
+
+        let owned_by_id = params.owned_by_id;
+        store
+            .insert_web_id(actor_id, params)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not create web id");
+            })
+            .change_context(WebError)?;
+
+        Ok(owned_by_id)
+    }
+
+    async fn check_web_permission(
+        &self,
+        scope: Session<Account>,
+        web_id: OwnedById,
+        permission: WebPermission,
+    ) -> Result<PermissionResponse, Report<WebError>> {
+        let actor_id = Self::actor(&scope)?;
+
+        let check = self
+            .authorization_api()
+            .await?
+            .check_web_permission(actor_id, permission, web_id, Consistency::FullyConsistent)
+            .await
+            .inspect_err(|error| {
+                tracing::error!(
+                    ?error,
+                    "Could not check if permission on web is granted to the specified actor"
+                );
+            })
+            .change_context(WebError)?;
+
+        Ok(PermissionResponse {
+            has_permission: check.has_permission,
+        })
+    }
+}
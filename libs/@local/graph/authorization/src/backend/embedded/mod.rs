@@ -0,0 +1,313 @@
+mod schema;
+mod state;
+
+use alloc::sync::Arc;
+
+use error_stack::{Report, ResultExt as _};
+use futures::{Stream, stream};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use tokio::sync::RwLock;
+
+use self::{
+    schema::Schema,
+    state::{ObjectReference, RelationTuple, RelationshipState, SubjectReference, TupleFilter},
+};
+use crate::{
+    backend::{
+        BulkCheckItem, BulkCheckResponse, CheckError, CheckResponse, DeleteRelationshipError,
+        DeleteRelationshipResponse, ExportSchemaError, ExportSchemaResponse, ImportSchemaError,
+        ImportSchemaResponse, ModifyRelationshipError, ModifyRelationshipOperation,
+        ModifyRelationshipResponse, ReadError, ZanzibarBackend, spicedb::serde as wire,
+    },
+    zanzibar::{
+        Consistency, Permission,
+        types::{Relationship, RelationshipFilter, Resource, Subject},
+    },
+};
+
+/// An in-process [`ZanzibarBackend`] which keeps all relationships in memory.
+///
+/// The backend evaluates the schema imported by [`ZanzibarBackend::import_schema`] the same way
+/// `SpiceDB` does, so permission checks behave as in production without requiring a running
+/// `SpiceDB` instance. Every write creates a new revision, which is returned as [`Zookie`] and
+/// can be used to read the data at that revision.
+///
+/// Cloning the backend does not copy the relationships, the clone refers to the same data.
+///
+/// [`Zookie`]: crate::zanzibar::Zookie
+#[derive(Debug, Default, Clone)]
+pub struct EmbeddedZanzibar {
+    state: Arc<RwLock<RelationshipState>>,
+}
+
+impl EmbeddedZanzibar {
+    /// Creates a new backend without a schema and without any relationships.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+fn relationship_to_tuple<R>(relationship: &R) -> Result<RelationTuple, serde_json::Error>
+where
+    R: Relationship<
+            Resource: Resource<Kind: Serialize, Id: Serialize>,
+            Relation: Serialize,
+            Subject: Resource<Kind: Serialize, Id: Serialize>,
+            SubjectSet: Serialize,
+        >,
+{
+    serde_json::from_value(wire::relationship::serialize(
+        relationship,
+        serde_json::value::Serializer,
+    )?)
+}
+
+fn tuple_to_relationship<R>(tuple: &RelationTuple) -> Result<R, serde_json::Error>
+where
+    for<'de> R: Relationship<
+            Resource: Resource<Kind: Deserialize<'de>, Id: Deserialize<'de>>,
+            Relation: Deserialize<'de>,
+            Subject: Resource<Kind: Deserialize<'de>, Id: Deserialize<'de>>,
+            SubjectSet: Deserialize<'de>,
+        >,
+{
+    wire::relationship::deserialize(serde_json::to_value(tuple)?)
+}
+
+fn filter_to_tuple_filter(
+    filter: &RelationshipFilter<
+        impl Serialize,
+        impl Serialize,
+        impl Serialize,
+        impl Serialize,
+        impl Serialize,
+        impl Serialize,
+    >,
+) -> Result<TupleFilter, serde_json::Error> {
+    serde_json::from_value(wire::relationship_filter::serialize(
+        filter,
+        serde_json::value::Serializer,
+    )?)
+}
+
+fn check_request<O, R, S>(
+    resource: &O,
+    permission: &R,
+    subject: &S,
+) -> Result<(ObjectReference, String, SubjectReference), Report<CheckError>>
+where
+    O: Resource<Kind: Serialize, Id: Serialize>,
+    R: Serialize,
+    S: Subject<Resource: Resource<Kind: Serialize, Id: Serialize>, Relation: Serialize>,
+{
+    let resource = serde_json::from_value(
+        wire::resource::serialize(resource, serde_json::value::Serializer)
+            .change_context(CheckError)?,
+    )
+    .change_context(CheckError)?;
+    let permission = serde_plain::to_string(permission).change_context(CheckError)?;
+    let subject = serde_json::from_value(
+        wire::subject::serialize(subject, serde_json::value::Serializer)
+            .change_context(CheckError)?,
+    )
+    .change_context(CheckError)?;
+    Ok((resource, permission, subject))
+}
+
+impl ZanzibarBackend for EmbeddedZanzibar {
+    async fn import_schema(
+        &mut self,
+        schema: &str,
+    ) -> Result<ImportSchemaResponse, Report<ImportSchemaError>> {
+        let parsed = Schema::parse(schema).change_context(ImportSchemaError)?;
+        let revision = self
+            .state
+            .write()
+            .await
+            .import_schema(schema.to_owned(), parsed);
+
+        Ok(ImportSchemaResponse {
+            written_at: RelationshipState::zookie(revision),
+        })
+    }
+
+    async fn export_schema(&self) -> Result<ExportSchemaResponse, Report<ExportSchemaError>> {
+        let state = self.state.read().await;
+        let schema = state
+            .schema_text()
+            .ok_or_else(|| Report::new(ExportSchemaError))
+            .attach_printable("no schema has been imported")?
+            .to_owned();
+        let read_at = state
+            .revision(Consistency::FullyConsistent)
+            .change_context(ExportSchemaError)?;
+
+        Ok(ExportSchemaResponse {
+            schema,
+            read_at: RelationshipState::zookie(read_at),
+        })
+    }
+
+    async fn modify_relationships<T>(
+        &mut self,
+        relationships: impl IntoIterator<Item = (ModifyRelationshipOperation, T), IntoIter: Send> + Send,
+    ) -> Result<ModifyRelationshipResponse, Report<ModifyRelationshipError>>
+    where
+        T: Relationship<
+                Resource: Resource<Kind: Serialize, Id: Serialize>,
+                Relation: Serialize,
+                Subject: Resource<Kind: Serialize, Id: Serialize>,
+                SubjectSet: Serialize,
+            > + Send
+            + Sync,
+    {
+        let operations = relationships
+            .into_iter()
+            .map(|(operation, relationship)| Ok((operation, relationship_to_tuple(&relationship)?)))
+            .collect::<Result<Vec<_>, serde_json::Error>>()
+            .change_context(ModifyRelationshipError)?;
+
+        let revision = self
+            .state
+            .write()
+            .await
+            .modify(operations)
+            .change_context(ModifyRelationshipError)?;
+
+        Ok(ModifyRelationshipResponse {
+            written_at: RelationshipState::zookie(revision),
+        })
+    }
+
+    async fn check_permission<O, R, S>(
+        &self,
+        resource: &O,
+        permission: &R,
+        subject: &S,
+        consistency: Consistency<'_>,
+    ) -> Result<CheckResponse, Report<CheckError>>
+    where
+        O: Resource<Kind: Serialize, Id: Serialize> + Sync,
+        R: Serialize + Permission<O> + Sync,
+        S: Subject<Resource: Resource<Kind: Serialize, Id: Serialize>, Relation: Serialize> + Sync,
+    {
+        let (resource, permission, subject) = check_request(resource, permission, subject)?;
+
+        let state = self.state.read().await;
+        let revision = state.revision(consistency).change_context(CheckError)?;
+        let has_permission = state
+            .check(&resource, &permission, &subject, revision)
+            .change_context(CheckError)?;
+
+        Ok(CheckResponse {
+            has_permission,
+            checked_at: RelationshipState::zookie(revision),
+        })
+    }
+
+    async fn check_permissions<O, R, S>(
+        &self,
+        relationships: impl IntoIterator<Item = (O, R, S), IntoIter: Send + Sync> + Send,
+        consistency: Consistency<'_>,
+    ) -> Result<
+        BulkCheckResponse<impl IntoIterator<Item = BulkCheckItem<O, R, S>>>,
+        Report<CheckError>,
+    >
+    where
+        O: Resource<Kind: Serialize + DeserializeOwned, Id: Serialize + DeserializeOwned>
+            + Send
+            + Sync,
+        R: Serialize + DeserializeOwned + Permission<O> + Send + Sync,
+        S: Subject<
+                Resource: Resource<
+                    Kind: Serialize + DeserializeOwned,
+                    Id: Serialize + DeserializeOwned,
+                >,
+                Relation: Serialize + DeserializeOwned,
+            > + Send
+            + Sync,
+    {
+        let state = self.state.read().await;
+        let revision = state.revision(consistency).change_context(CheckError)?;
+
+        let permissions = relationships
+            .into_iter()
+            .map(|(resource, permission, subject)| {
+                let (resource_reference, permission_name, subject_reference) =
+                    check_request(&resource, &permission, &subject)?;
+                let has_permission = state
+                    .check(
+                        &resource_reference,
+                        &permission_name,
+                        &subject_reference,
+                        revision,
+                    )
+                    .change_context(CheckError)?;
+
+                Ok(BulkCheckItem {
+                    resource,
+                    permission,
+                    subject,
+                    has_permission: Ok(has_permission),
+                })
+            })
+            .collect::<Result<Vec<_>, Report<CheckError>>>()?;
+
+        Ok(BulkCheckResponse {
+            permissions,
+            checked_at: RelationshipState::zookie(revision),
+        })
+    }
+
+    async fn read_relations<R>(
+        &self,
+        filter: RelationshipFilter<
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+        >,
+        consistency: Consistency<'_>,
+    ) -> Result<impl Stream<Item = Result<R, Report<ReadError>>> + Send, Report<ReadError>>
+    where
+        for<'de> R: Relationship<
+                Resource: Resource<Kind: Deserialize<'de>, Id: Deserialize<'de>>,
+                Relation: Deserialize<'de>,
+                Subject: Resource<Kind: Deserialize<'de>, Id: Deserialize<'de>>,
+                SubjectSet: Deserialize<'de>,
+            > + Send,
+    {
+        let filter = filter_to_tuple_filter(&filter).change_context(ReadError)?;
+
+        let state = self.state.read().await;
+        let revision = state.revision(consistency).change_context(ReadError)?;
+        let tuples = state.read(&filter, revision);
+        drop(state);
+
+        Ok(stream::iter(tuples.into_iter().map(|tuple| {
+            tuple_to_relationship(&tuple).change_context(ReadError)
+        })))
+    }
+
+    async fn delete_relations(
+        &mut self,
+        filter: RelationshipFilter<
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+        >,
+    ) -> Result<DeleteRelationshipResponse, Report<DeleteRelationshipError>> {
+        let filter = filter_to_tuple_filter(&filter).change_context(DeleteRelationshipError)?;
+        let revision = self.state.write().await.delete(&filter);
+
+        Ok(DeleteRelationshipResponse {
+            deleted_at: RelationshipState::zookie(revision),
+        })
+    }
+}
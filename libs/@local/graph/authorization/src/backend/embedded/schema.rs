@@ -0,0 +1,506 @@
+use alloc::collections::BTreeMap;
+use core::{error::Error, fmt, iter::Peekable, str::CharIndices};
+
+/// An error which occurred while reading a schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum SchemaError {
+    Syntax { line: usize, message: String },
+    UnknownDefinition { definition: String, name: String },
+    UnknownRelation { definition: String, name: String },
+    DuplicateName { definition: String, name: String },
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax { line, message } => write!(fmt, "line {line}: {message}"),
+            Self::UnknownDefinition { definition, name } => {
+                write!(fmt, "`{definition}` references unknown definition `{name}`")
+            }
+            Self::UnknownRelation { definition, name } => {
+                write!(fmt, "`{definition}` references unknown relation `{name}`")
+            }
+            Self::DuplicateName { definition, name } => {
+                write!(fmt, "`{name}` is defined more than once in `{definition}`")
+            }
+        }
+    }
+}
+
+impl Error for SchemaError {}
+
+/// A subject type which is allowed on the right-hand side of a relation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AllowedSubject {
+    kind: String,
+    relation: Option<String>,
+    wildcard: bool,
+}
+
+/// The expression of a permission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Expression {
+    Nil,
+    Reference(String),
+    Arrow { tupleset: String, computed: String },
+    Union(Box<Self>, Box<Self>),
+    Intersection(Box<Self>, Box<Self>),
+    Exclusion(Box<Self>, Box<Self>),
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct Definition {
+    relations: BTreeMap<String, Vec<AllowedSubject>>,
+    permissions: BTreeMap<String, Expression>,
+}
+
+impl AllowedSubject {
+    /// Returns if a subject with the provided kind and relation is allowed.
+    ///
+    /// `wildcard` has to be set if the subject refers to all objects of `kind`.
+    pub(crate) fn allows(&self, kind: &str, relation: Option<&str>, wildcard: bool) -> bool {
+        self.kind == kind && self.relation.as_deref() == relation && self.wildcard == wildcard
+    }
+}
+
+impl Definition {
+    pub(crate) fn contains(&self, name: &str) -> bool {
+        self.relations.contains_key(name) || self.permissions.contains_key(name)
+    }
+
+    pub(crate) fn relation(&self, name: &str) -> Option<&[AllowedSubject]> {
+        self.relations.get(name).map(Vec::as_slice)
+    }
+
+    pub(crate) fn permission(&self, name: &str) -> Option<&Expression> {
+        self.permissions.get(name)
+    }
+}
+
+/// A parsed schema in the `SpiceDB` schema language.
+///
+/// Only the parts of the language are supported which are required to express the graph schema:
+/// definitions, relations, permissions, unions (`+`), intersections (`&`), exclusions (`-`) and
+/// arrows (`->`). Caveats are not supported.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct Schema {
+    definitions: BTreeMap<String, Definition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token<'s> {
+    Identifier(&'s str),
+    Symbol(&'static str),
+}
+
+struct Lexer<'s> {
+    source: &'s str,
+    chars: Peekable<CharIndices<'s>>,
+    line: usize,
+}
+
+impl<'s> Lexer<'s> {
+    fn new(source: &'s str) -> Self {
+        Self {
+            source,
+            chars: source.char_indices().peekable(),
+            line: 1,
+        }
+    }
+
+    fn error(&self, message: impl Into<String>) -> SchemaError {
+        SchemaError::Syntax {
+            line: self.line,
+            message: message.into(),
+        }
+    }
+
+    fn skip_trivia(&mut self) -> Result<(), SchemaError> {
+        loop {
+            match self.chars.peek() {
+                Some((_, '\n')) => {
+                    self.line += 1;
+                    self.chars.next();
+                }
+                Some((_, char)) if char.is_whitespace() => {
+                    self.chars.next();
+                }
+                Some((_, '/')) => match self.chars.clone().nth(1).map(|(_, char)| char) {
+                    Some('/') => while self.chars.next_if(|(_, char)| *char != '\n').is_some() {},
+                    Some('*') => {
+                        self.chars.next();
+                        self.chars.next();
+                        let mut previous = ' ';
+                        loop {
+                            let Some((_, char)) = self.chars.next() else {
+                                return Err(self.error("unterminated block comment"));
+                            };
+                            if char == '\n' {
+                                self.line += 1;
+                            }
+                            if previous == '*' && char == '/' {
+                                break;
+                            }
+                            previous = char;
+                        }
+                    }
+                    _ => return Ok(()),
+                },
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn next_token(&mut self) -> Result<Option<Token<'s>>, SchemaError> {
+        self.skip_trivia()?;
+        let Some((start, char)) = self.chars.next() else {
+            return Ok(None);
+        };
+
+        let symbol = match char {
+            '{' => "{",
+            '}' => "}",
+            '(' => "(",
+            ')' => ")",
+            ':' => ":",
+            '|' => "|",
+            '#' => "#",
+            '*' => "*",
+            '=' => "=",
+            '+' => "+",
+            '&' => "&",
+            '-' if self.chars.next_if(|(_, char)| *char == '>').is_some() => "->",
+            '-' => "-",
+            char if char.is_ascii_alphanumeric() || char == '_' => {
+                let mut end = start + char.len_utf8();
+                while let Some((index, char)) = self.chars.next_if(|(_, char)| {
+                    char.is_ascii_alphanumeric() || *char == '_' || *char == '/'
+                }) {
+                    end = index + char.len_utf8();
+                }
+                return Ok(Some(Token::Identifier(
+                    self.source
+                        .get(start..end)
+                        .expect("identifiers should only consist of ASCII characters"),
+                )));
+            }
+            char => return Err(self.error(format!("unexpected character `{char}`"))),
+        };
+        Ok(Some(Token::Symbol(symbol)))
+    }
+}
+
+struct Parser<'s> {
+    lexer: Lexer<'s>,
+    peeked: Option<Option<Token<'s>>>,
+}
+
+impl<'s> Parser<'s> {
+    fn peek(&mut self) -> Result<Option<&Token<'s>>, SchemaError> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.lexer.next_token()?);
+        }
+        Ok(self.peeked.as_ref().and_then(Option::as_ref))
+    }
+
+    fn next_token(&mut self) -> Result<Option<Token<'s>>, SchemaError> {
+        self.peeked
+            .take()
+            .map_or_else(|| self.lexer.next_token(), Ok)
+    }
+
+    fn next_if_symbol(&mut self, symbol: &'static str) -> Result<bool, SchemaError> {
+        if self.peek()? == Some(&Token::Symbol(symbol)) {
+            self.peeked = None;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: &'static str) -> Result<(), SchemaError> {
+        if self.next_if_symbol(symbol)? {
+            Ok(())
+        } else {
+            Err(self.lexer.error(format!("expected `{symbol}`")))
+        }
+    }
+
+    fn expect_identifier(&mut self) -> Result<&'s str, SchemaError> {
+        match self.next_token()? {
+            Some(Token::Identifier(identifier)) => Ok(identifier),
+            _ => Err(self.lexer.error("expected identifier")),
+        }
+    }
+
+    fn parse_schema(mut self) -> Result<Schema, SchemaError> {
+        let mut schema = Schema::default();
+        while let Some(token) = self.next_token()? {
+            if token != Token::Identifier("definition") {
+                return Err(self.lexer.error("expected `definition`"));
+            }
+            let name = self.expect_identifier()?;
+            let definition = self.parse_definition(name)?;
+            if schema
+                .definitions
+                .insert(name.to_owned(), definition)
+                .is_some()
+            {
+                return Err(SchemaError::DuplicateName {
+                    definition: name.to_owned(),
+                    name: name.to_owned(),
+                });
+            }
+        }
+        Ok(schema)
+    }
+
+    fn parse_definition(&mut self, definition_name: &str) -> Result<Definition, SchemaError> {
+        let mut definition = Definition::default();
+        self.expect_symbol("{")?;
+        while !self.next_if_symbol("}")? {
+            let keyword = self.expect_identifier()?;
+            let name = self.expect_identifier()?;
+            if definition.contains(name) {
+                return Err(SchemaError::DuplicateName {
+                    definition: definition_name.to_owned(),
+                    name: name.to_owned(),
+                });
+            }
+            match keyword {
+                "relation" => {
+                    self.expect_symbol(":")?;
+                    let mut subjects = vec![self.parse_allowed_subject()?];
+                    while self.next_if_symbol("|")? {
+                        subjects.push(self.parse_allowed_subject()?);
+                    }
+                    definition.relations.insert(name.to_owned(), subjects);
+                }
+                "permission" => {
+                    self.expect_symbol("=")?;
+                    let expression = self.parse_union()?;
+                    definition.permissions.insert(name.to_owned(), expression);
+                }
+                _ => return Err(self.lexer.error("expected `relation` or `permission`")),
+            }
+        }
+        Ok(definition)
+    }
+
+    fn parse_allowed_subject(&mut self) -> Result<AllowedSubject, SchemaError> {
+        let kind = self.expect_identifier()?.to_owned();
+        if self.next_if_symbol("#")? {
+            Ok(AllowedSubject {
+                kind,
+                relation: Some(self.expect_identifier()?.to_owned()),
+                wildcard: false,
+            })
+        } else if self.next_if_symbol(":")? {
+            self.expect_symbol("*")?;
+            Ok(AllowedSubject {
+                kind,
+                relation: None,
+                wildcard: true,
+            })
+        } else {
+            Ok(AllowedSubject {
+                kind,
+                relation: None,
+                wildcard: false,
+            })
+        }
+    }
+
+    fn parse_union(&mut self) -> Result<Expression, SchemaError> {
+        let mut expression = self.parse_intersection()?;
+        while self.next_if_symbol("+")? {
+            expression =
+                Expression::Union(Box::new(expression), Box::new(self.parse_intersection()?));
+        }
+        Ok(expression)
+    }
+
+    fn parse_intersection(&mut self) -> Result<Expression, SchemaError> {
+        let mut expression = self.parse_exclusion()?;
+        while self.next_if_symbol("&")? {
+            expression =
+                Expression::Intersection(Box::new(expression), Box::new(self.parse_exclusion()?));
+        }
+        Ok(expression)
+    }
+
+    fn parse_exclusion(&mut self) -> Result<Expression, SchemaError> {
+        let mut expression = self.parse_term()?;
+        while self.next_if_symbol("-")? {
+            expression = Expression::Exclusion(Box::new(expression), Box::new(self.parse_term()?));
+        }
+        Ok(expression)
+    }
+
+    fn parse_term(&mut self) -> Result<Expression, SchemaError> {
+        if self.next_if_symbol("(")? {
+            let expression = self.parse_union()?;
+            self.expect_symbol(")")?;
+            return Ok(expression);
+        }
+
+        let name = self.expect_identifier()?;
+        if name == "nil" {
+            Ok(Expression::Nil)
+        } else if self.next_if_symbol("->")? {
+            Ok(Expression::Arrow {
+                tupleset: name.to_owned(),
+                computed: self.expect_identifier()?.to_owned(),
+            })
+        } else {
+            Ok(Expression::Reference(name.to_owned()))
+        }
+    }
+}
+
+impl Schema {
+    /// Parses a schema written in the `SpiceDB` schema language.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema is syntactically invalid or references unknown definitions
+    /// or relations.
+    pub(crate) fn parse(source: &str) -> Result<Self, SchemaError> {
+        let schema = Parser {
+            lexer: Lexer::new(source),
+            peeked: None,
+        }
+        .parse_schema()?;
+        schema.validate()?;
+        Ok(schema)
+    }
+
+    pub(crate) fn definition(&self, kind: &str) -> Option<&Definition> {
+        self.definitions.get(kind)
+    }
+
+    fn validate(&self) -> Result<(), SchemaError> {
+        for (definition_name, definition) in &self.definitions {
+            for subject in definition.relations.values().flatten() {
+                let Some(subject_definition) = self.definitions.get(&subject.kind) else {
+                    return Err(SchemaError::UnknownDefinition {
+                        definition: definition_name.clone(),
+                        name: subject.kind.clone(),
+                    });
+                };
+                if let Some(relation) = &subject.relation {
+                    if !subject_definition.contains(relation) {
+                        return Err(SchemaError::UnknownRelation {
+                            definition: definition_name.clone(),
+                            name: format!("{}#{relation}", subject.kind),
+                        });
+                    }
+                }
+            }
+
+            for expression in definition.permissions.values() {
+                validate_expression(definition_name, definition, expression)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn validate_expression(
+    definition_name: &str,
+    definition: &Definition,
+    expression: &Expression,
+) -> Result<(), SchemaError> {
+    match expression {
+        Expression::Nil => Ok(()),
+        Expression::Reference(name) if definition.contains(name) => Ok(()),
+        Expression::Arrow { tupleset, .. } if definition.relations.contains_key(tupleset) => Ok(()),
+        Expression::Reference(name) | Expression::Arrow { tupleset: name, .. } => {
+            Err(SchemaError::UnknownRelation {
+                definition: definition_name.to_owned(),
+                name: name.clone(),
+            })
+        }
+        Expression::Union(lhs, rhs)
+        | Expression::Intersection(lhs, rhs)
+        | Expression::Exclusion(lhs, rhs) => {
+            validate_expression(definition_name, definition, lhs)?;
+            validate_expression(definition_name, definition, rhs)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Expression, Schema, SchemaError};
+
+    #[test]
+    fn parse_graph_schema() {
+        let schema = Schema::parse(include_str!("../../../schemas/v1__initial_schema.zed"))
+            .expect("schema should be valid");
+
+        let entity = &schema.definitions["graph/entity"];
+        assert_eq!(entity.relations["level_00_viewer"].len(), 4);
+        assert!(
+            entity.relations["level_00_viewer"]
+                .iter()
+                .any(|subject| subject.kind == "graph/account" && subject.wildcard)
+        );
+        assert!(matches!(
+            entity.permissions["full_access"],
+            Expression::Union(_, ref rhs) if matches!(**rhs, Expression::Intersection(..))
+        ));
+    }
+
+    #[test]
+    fn operator_precedence() {
+        let schema = Schema::parse(
+            "definition user {}
+             definition document {
+                 /* multi-line
+                    comment */
+                 relation a: user
+                 relation b: user
+                 relation c: user
+                 permission p = a + b & c - a // trailing comment
+             }",
+        )
+        .expect("schema should be valid");
+
+        assert_eq!(
+            schema.definitions["document"].permissions["p"],
+            Expression::Union(
+                Box::new(Expression::Reference("a".to_owned())),
+                Box::new(Expression::Intersection(
+                    Box::new(Expression::Reference("b".to_owned())),
+                    Box::new(Expression::Exclusion(
+                        Box::new(Expression::Reference("c".to_owned())),
+                        Box::new(Expression::Reference("a".to_owned())),
+                    )),
+                )),
+            )
+        );
+    }
+
+    #[test]
+    fn unknown_references() {
+        assert!(matches!(
+            Schema::parse("definition document { relation owner: user }"),
+            Err(SchemaError::UnknownDefinition { .. })
+        ));
+        assert!(matches!(
+            Schema::parse("definition document { permission view = owner }"),
+            Err(SchemaError::UnknownRelation { .. })
+        ));
+        assert!(matches!(
+            Schema::parse(
+                "definition document { relation owner: document\n relation owner: document }"
+            ),
+            Err(SchemaError::DuplicateName { .. })
+        ));
+        assert!(matches!(
+            Schema::parse("definition document {\n relation owner document }"),
+            Err(SchemaError::Syntax { line: 2, .. })
+        ));
+    }
+}
@@ -0,0 +1,641 @@
+use alloc::collections::BTreeMap;
+use core::{error::Error, fmt, mem};
+use std::collections::HashMap;
+
+use error_stack::{Report, bail};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    backend::{
+        ModifyRelationshipOperation,
+        embedded::schema::{Expression, Schema},
+    },
+    zanzibar::{Consistency, Zookie},
+};
+
+/// The object ID which grants access to all objects of a kind.
+const WILDCARD: &str = "*";
+
+/// A reference to an object in the `SpiceDB` wire format.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ObjectReference {
+    object_type: String,
+    object_id: String,
+}
+
+/// A reference to a subject in the `SpiceDB` wire format.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SubjectReference {
+    object: ObjectReference,
+    #[serde(default)]
+    optional_relation: Option<String>,
+}
+
+/// A relationship in the `SpiceDB` wire format.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RelationTuple {
+    resource: ObjectReference,
+    relation: String,
+    subject: SubjectReference,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SubjectRelationFilter {
+    relation: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SubjectFilter {
+    subject_type: String,
+    #[serde(default)]
+    optional_subject_id: Option<String>,
+    #[serde(default)]
+    optional_relation: Option<SubjectRelationFilter>,
+}
+
+/// A relationship filter in the `SpiceDB` wire format.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TupleFilter {
+    resource_type: String,
+    #[serde(default)]
+    optional_resource_id: Option<String>,
+    #[serde(default)]
+    optional_relation: Option<String>,
+    #[serde(default)]
+    optional_subject_filter: Option<SubjectFilter>,
+}
+
+impl TupleFilter {
+    fn matches(&self, tuple: &RelationTuple) -> bool {
+        tuple.resource.object_type == self.resource_type
+            && self
+                .optional_resource_id
+                .as_ref()
+                .is_none_or(|id| *id == tuple.resource.object_id)
+            && self
+                .optional_relation
+                .as_ref()
+                .is_none_or(|relation| *relation == tuple.relation)
+            && self.optional_subject_filter.as_ref().is_none_or(|filter| {
+                tuple.subject.object.object_type == filter.subject_type
+                    && filter
+                        .optional_subject_id
+                        .as_ref()
+                        .is_none_or(|id| *id == tuple.subject.object.object_id)
+                    && filter.optional_relation.as_ref().is_none_or(|relation| {
+                        tuple.subject.optional_relation.as_ref() == Some(&relation.relation)
+                    })
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum EvaluationError {
+    MissingSchema,
+    InvalidZookie(String),
+    UnknownDefinition(String),
+    UnknownRelation { definition: String, name: String },
+    InvalidSubject(RelationTuple),
+    AlreadyExists(RelationTuple),
+}
+
+impl fmt::Display for EvaluationError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSchema => fmt.write_str("no schema has been imported"),
+            Self::InvalidZookie(token) => write!(fmt, "`{token}` is not a valid revision"),
+            Self::UnknownDefinition(definition) => {
+                write!(fmt, "definition `{definition}` does not exist")
+            }
+            Self::UnknownRelation { definition, name } => {
+                write!(fmt, "`{name}` does not exist on `{definition}`")
+            }
+            Self::InvalidSubject(tuple) => write!(
+                fmt,
+                "subject `{}` is not allowed for relation `{}#{}`",
+                tuple.subject.object.object_type, tuple.resource.object_type, tuple.relation
+            ),
+            Self::AlreadyExists(tuple) => write!(
+                fmt,
+                "relationship `{}:{}#{}@{}:{}` already exists",
+                tuple.resource.object_type,
+                tuple.resource.object_id,
+                tuple.relation,
+                tuple.subject.object.object_type,
+                tuple.subject.object.object_id
+            ),
+        }
+    }
+}
+
+impl Error for EvaluationError {}
+
+/// A relationship together with the revisions it was visible at.
+#[derive(Debug, Clone)]
+struct VersionedTuple {
+    tuple: RelationTuple,
+    created_at: u64,
+    deleted_at: Option<u64>,
+}
+
+impl VersionedTuple {
+    fn is_visible_at(&self, revision: u64) -> bool {
+        self.created_at <= revision && self.deleted_at.is_none_or(|deleted| deleted > revision)
+    }
+}
+
+/// Relationships and the schema they are evaluated against.
+///
+/// Every write creates a new revision. Deleted relationships are kept, so the data can be read at
+/// any previous revision, which is what the [`Zookie`]s returned by the backend refer to.
+#[derive(Debug, Default)]
+pub(crate) struct RelationshipState {
+    schema: Option<(String, Schema)>,
+    tuples: BTreeMap<ObjectReference, Vec<VersionedTuple>>,
+    revision: u64,
+}
+
+impl RelationshipState {
+    pub(crate) fn zookie(revision: u64) -> Zookie<'static> {
+        Zookie::new(revision.to_string())
+    }
+
+    pub(crate) fn schema_text(&self) -> Option<&str> {
+        self.schema.as_ref().map(|(text, _)| text.as_str())
+    }
+
+    pub(crate) fn import_schema(&mut self, text: String, schema: Schema) -> u64 {
+        self.schema = Some((text, schema));
+        self.revision += 1;
+        self.revision
+    }
+
+    /// Returns the revision data should be read at for the provided consistency.
+    pub(crate) fn revision(
+        &self,
+        consistency: Consistency<'_>,
+    ) -> Result<u64, Report<EvaluationError>> {
+        let parse = |zookie: &Zookie<'_>| {
+            if zookie.as_str().is_empty() {
+                return Ok(0);
+            }
+            zookie
+                .as_str()
+                .parse::<u64>()
+                .ok()
+                .filter(|revision| *revision <= self.revision)
+                .ok_or_else(|| {
+                    Report::new(EvaluationError::InvalidZookie(zookie.as_str().to_owned()))
+                })
+        };
+
+        match consistency {
+            Consistency::MinimalLatency | Consistency::FullyConsistent => Ok(self.revision),
+            Consistency::AtLeastAsFresh(zookie) => parse(zookie).map(|_| self.revision),
+            Consistency::AtExactSnapshot(zookie) => parse(zookie),
+        }
+    }
+
+    fn schema(&self) -> Result<&Schema, Report<EvaluationError>> {
+        self.schema
+            .as_ref()
+            .map(|(_, schema)| schema)
+            .ok_or_else(|| Report::new(EvaluationError::MissingSchema))
+    }
+
+    fn visible_tuples<'s>(
+        &'s self,
+        resource: &ObjectReference,
+        relation: &'s str,
+        revision: u64,
+    ) -> impl Iterator<Item = &'s RelationTuple> + use<'s> {
+        self.tuples
+            .get(resource)
+            .into_iter()
+            .flatten()
+            .filter(move |versioned| {
+                versioned.tuple.relation == relation && versioned.is_visible_at(revision)
+            })
+            .map(|versioned| &versioned.tuple)
+    }
+
+    fn find_visible(&mut self, tuple: &RelationTuple) -> Option<&mut VersionedTuple> {
+        let revision = self.revision;
+        self.tuples
+            .get_mut(&tuple.resource)?
+            .iter_mut()
+            .find(|versioned| versioned.tuple == *tuple && versioned.is_visible_at(revision))
+    }
+
+    fn validate_tuple(&self, tuple: &RelationTuple) -> Result<(), Report<EvaluationError>> {
+        let schema = self.schema()?;
+        let definition = schema
+            .definition(&tuple.resource.object_type)
+            .ok_or_else(|| {
+                Report::new(EvaluationError::UnknownDefinition(
+                    tuple.resource.object_type.clone(),
+                ))
+            })?;
+        let allowed_subjects = definition.relation(&tuple.relation).ok_or_else(|| {
+            Report::new(EvaluationError::UnknownRelation {
+                definition: tuple.resource.object_type.clone(),
+                name: tuple.relation.clone(),
+            })
+        })?;
+
+        let is_wildcard = tuple.subject.object.object_id == WILDCARD;
+        if allowed_subjects.iter().any(|allowed| {
+            allowed.allows(
+                &tuple.subject.object.object_type,
+                tuple.subject.optional_relation.as_deref(),
+                is_wildcard,
+            )
+        }) {
+            Ok(())
+        } else {
+            Err(Report::new(EvaluationError::InvalidSubject(tuple.clone())))
+        }
+    }
+
+    /// Applies all operations in a single revision.
+    ///
+    /// Either all operations are applied or none of them.
+    pub(crate) fn modify(
+        &mut self,
+        operations: Vec<(ModifyRelationshipOperation, RelationTuple)>,
+    ) -> Result<u64, Report<EvaluationError>> {
+        let mut created = Vec::new();
+        for (operation, tuple) in &operations {
+            self.validate_tuple(tuple)?;
+            if *operation == ModifyRelationshipOperation::Create {
+                if created.contains(&tuple)
+                    || self.tuples.get(&tuple.resource).is_some_and(|tuples| {
+                        tuples.iter().any(|versioned| {
+                            versioned.tuple == *tuple && versioned.deleted_at.is_none()
+                        })
+                    })
+                {
+                    bail!(EvaluationError::AlreadyExists(tuple.clone()));
+                }
+                created.push(tuple);
+            }
+        }
+
+        if operations.is_empty() {
+            return Ok(self.revision);
+        }
+
+        self.revision += 1;
+        let revision = self.revision;
+        for (operation, tuple) in operations {
+            match operation {
+                ModifyRelationshipOperation::Create | ModifyRelationshipOperation::Touch => {
+                    if self.find_visible(&tuple).is_none() {
+                        self.tuples.entry(tuple.resource.clone()).or_default().push(
+                            VersionedTuple {
+                                tuple,
+                                created_at: revision,
+                                deleted_at: None,
+                            },
+                        );
+                    }
+                }
+                ModifyRelationshipOperation::Delete => {
+                    if let Some(versioned) = self.find_visible(&tuple) {
+                        versioned.deleted_at = Some(revision);
+                    }
+                }
+            }
+        }
+        Ok(revision)
+    }
+
+    /// Deletes all relationships matching `filter` in a new revision.
+    pub(crate) fn delete(&mut self, filter: &TupleFilter) -> u64 {
+        self.revision += 1;
+        let revision = self.revision;
+        for versioned in self.tuples.values_mut().flatten() {
+            if versioned.deleted_at.is_none() && filter.matches(&versioned.tuple) {
+                versioned.deleted_at = Some(revision);
+            }
+        }
+        revision
+    }
+
+    /// Returns all relationships matching `filter` at the provided revision.
+    pub(crate) fn read(&self, filter: &TupleFilter, revision: u64) -> Vec<RelationTuple> {
+        self.tuples
+            .values()
+            .flatten()
+            .filter(|versioned| {
+                versioned.is_visible_at(revision) && filter.matches(&versioned.tuple)
+            })
+            .map(|versioned| versioned.tuple.clone())
+            .collect()
+    }
+
+    /// Checks if `subject` has the relation or permission `name` on `resource`.
+    pub(crate) fn check(
+        &self,
+        resource: &ObjectReference,
+        name: &str,
+        subject: &SubjectReference,
+        revision: u64,
+    ) -> Result<bool, Report<EvaluationError>> {
+        Evaluator {
+            state: self,
+            schema: self.schema()?,
+            subject,
+            revision,
+            cache: HashMap::new(),
+            depth: 0,
+            lowest_dependency: usize::MAX,
+        }
+        .check(resource, name)
+    }
+}
+
+/// The state of a relation in the cache of the [`Evaluator`].
+#[derive(Debug, Copy, Clone)]
+enum Evaluation {
+    /// The relation is being evaluated at the given depth.
+    InProgress(usize),
+    /// The relation has been evaluated to the given result.
+    Done(bool),
+}
+
+/// Evaluates a single check request.
+///
+/// Results are cached for the duration of the request. A relation which is evaluated again while
+/// it is still being evaluated is considered to not contain the subject, which stops cycles in the
+/// relationship graph. Results depending on such a relation are provisional, so they are only
+/// cached once the evaluation of the relation the cycle started at has finished.
+struct Evaluator<'s> {
+    state: &'s RelationshipState,
+    schema: &'s Schema,
+    subject: &'s SubjectReference,
+    revision: u64,
+    cache: HashMap<(ObjectReference, String), Evaluation>,
+    /// The number of relations currently being evaluated.
+    depth: usize,
+    /// The lowest depth of an in-progress relation the current evaluation depends on.
+    lowest_dependency: usize,
+}
+
+impl Evaluator<'_> {
+    fn check(
+        &mut self,
+        resource: &ObjectReference,
+        name: &str,
+    ) -> Result<bool, Report<EvaluationError>> {
+        if self.subject.object == *resource
+            && self.subject.optional_relation.as_deref() == Some(name)
+        {
+            return Ok(true);
+        }
+
+        let key = (resource.clone(), name.to_owned());
+        match self.cache.get(&key) {
+            Some(Evaluation::Done(result)) => return Ok(*result),
+            Some(Evaluation::InProgress(depth)) => {
+                self.lowest_dependency = self.lowest_dependency.min(*depth);
+                return Ok(false);
+            }
+            None => {}
+        }
+
+        let depth = self.depth;
+        self.cache
+            .insert(key.clone(), Evaluation::InProgress(depth));
+        self.depth += 1;
+        let outer_dependency = mem::replace(&mut self.lowest_dependency, usize::MAX);
+
+        let result = self.evaluate(resource, name);
+
+        self.depth -= 1;
+        let lowest_dependency = mem::replace(&mut self.lowest_dependency, outer_dependency);
+        let result = result?;
+
+        if lowest_dependency < depth {
+            // The result relies on a relation further up which is not evaluated yet, so it has to
+            // be evaluated again when it's requested later on.
+            self.cache.remove(&key);
+            self.lowest_dependency = self.lowest_dependency.min(lowest_dependency);
+        } else {
+            self.cache.insert(key, Evaluation::Done(result));
+        }
+        Ok(result)
+    }
+
+    fn evaluate(
+        &mut self,
+        resource: &ObjectReference,
+        name: &str,
+    ) -> Result<bool, Report<EvaluationError>> {
+        let schema = self.schema;
+        let definition = schema.definition(&resource.object_type).ok_or_else(|| {
+            Report::new(EvaluationError::UnknownDefinition(
+                resource.object_type.clone(),
+            ))
+        })?;
+
+        if let Some(expression) = definition.permission(name) {
+            return self.evaluate_expression(resource, expression);
+        }
+        if definition.relation(name).is_none() {
+            bail!(EvaluationError::UnknownRelation {
+                definition: resource.object_type.clone(),
+                name: name.to_owned(),
+            });
+        }
+
+        let state = self.state;
+        for tuple in state.visible_tuples(resource, name, self.revision) {
+            match &tuple.subject.optional_relation {
+                None => {
+                    if self.subject.optional_relation.is_none()
+                        && tuple.subject.object.object_type == self.subject.object.object_type
+                        && (tuple.subject.object.object_id == self.subject.object.object_id
+                            || tuple.subject.object.object_id == WILDCARD)
+                    {
+                        return Ok(true);
+                    }
+                }
+                Some(relation) => {
+                    if tuple.subject == *self.subject
+                        || self.check(&tuple.subject.object, relation)?
+                    {
+                        return Ok(true);
+                    }
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    fn evaluate_expression(
+        &mut self,
+        resource: &ObjectReference,
+        expression: &Expression,
+    ) -> Result<bool, Report<EvaluationError>> {
+        match expression {
+            Expression::Nil => Ok(false),
+            Expression::Reference(name) => self.check(resource, name),
+            Expression::Arrow { tupleset, computed } => {
+                let (state, schema) = (self.state, self.schema);
+                for tuple in state.visible_tuples(resource, tupleset, self.revision) {
+                    let has_computed = schema
+                        .definition(&tuple.subject.object.object_type)
+                        .is_some_and(|definition| definition.contains(computed));
+                    if has_computed && self.check(&tuple.subject.object, computed)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            Expression::Union(lhs, rhs) => Ok(self.evaluate_expression(resource, lhs)?
+                || self.evaluate_expression(resource, rhs)?),
+            Expression::Intersection(lhs, rhs) => Ok(self.evaluate_expression(resource, lhs)?
+                && self.evaluate_expression(resource, rhs)?),
+            Expression::Exclusion(lhs, rhs) => Ok(self.evaluate_expression(resource, lhs)?
+                && !self.evaluate_expression(resource, rhs)?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ObjectReference, RelationTuple, RelationshipState, SubjectReference};
+    use crate::backend::{ModifyRelationshipOperation, embedded::schema::Schema};
+
+    const SCHEMA: &str = "definition user {}
+         definition group {
+             relation member: user | group#member
+         }
+         definition document {
+             relation first: group#member
+             relation second: group#member
+             permission both = first & second
+             permission view = first - second
+         }";
+
+    fn object(object_type: &str, object_id: &str) -> ObjectReference {
+        ObjectReference {
+            object_type: object_type.to_owned(),
+            object_id: object_id.to_owned(),
+        }
+    }
+
+    fn tuple(
+        resource: ObjectReference,
+        relation: &str,
+        subject: ObjectReference,
+        subject_relation: Option<&str>,
+    ) -> RelationTuple {
+        RelationTuple {
+            resource,
+            relation: relation.to_owned(),
+            subject: SubjectReference {
+                object: subject,
+                optional_relation: subject_relation.map(ToOwned::to_owned),
+            },
+        }
+    }
+
+    /// Creates two groups which are members of each other, where only `group:a` directly contains
+    /// `user:alice`.
+    ///
+    /// `group:a` lists `group:b` first, so evaluating `group:a` evaluates `group:b` while
+    /// `group:a` is still in progress.
+    fn cyclic_groups() -> (RelationshipState, u64) {
+        let mut state = RelationshipState::default();
+        state.import_schema(
+            SCHEMA.to_owned(),
+            Schema::parse(SCHEMA).expect("schema should be valid"),
+        );
+        let revision = state
+            .modify(
+                [
+                    tuple(
+                        object("group", "a"),
+                        "member",
+                        object("group", "b"),
+                        Some("member"),
+                    ),
+                    tuple(
+                        object("group", "a"),
+                        "member",
+                        object("user", "alice"),
+                        None,
+                    ),
+                    tuple(
+                        object("group", "b"),
+                        "member",
+                        object("group", "a"),
+                        Some("member"),
+                    ),
+                    tuple(
+                        object("document", "d"),
+                        "first",
+                        object("group", "a"),
+                        Some("member"),
+                    ),
+                    tuple(
+                        object("document", "d"),
+                        "second",
+                        object("group", "b"),
+                        Some("member"),
+                    ),
+                ]
+                .into_iter()
+                .map(|tuple| (ModifyRelationshipOperation::Create, tuple))
+                .collect(),
+            )
+            .expect("relationships should be valid");
+        (state, revision)
+    }
+
+    fn alice() -> SubjectReference {
+        SubjectReference {
+            object: object("user", "alice"),
+            optional_relation: None,
+        }
+    }
+
+    #[test]
+    fn recursive_relation() {
+        let (state, revision) = cyclic_groups();
+
+        for group in ["a", "b"] {
+            assert!(
+                state
+                    .check(&object("group", group), "member", &alice(), revision)
+                    .expect("check should succeed"),
+                "group:{group}"
+            );
+        }
+        // `group:b` is evaluated while `group:a` is in progress, which must not be cached as final
+        assert!(
+            state
+                .check(&object("document", "d"), "both", &alice(), revision)
+                .expect("check should succeed")
+        );
+    }
+
+    #[test]
+    fn exclusion_through_cycle() {
+        let (state, revision) = cyclic_groups();
+
+        assert!(
+            !state
+                .check(&object("document", "d"), "view", &alice(), revision)
+                .expect("check should succeed")
+        );
+    }
+}
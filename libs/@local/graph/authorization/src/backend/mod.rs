@@ -1,3 +1,4 @@
+mod embedded;
 mod spicedb;
 
 use core::{error::Error, fmt, iter::repeat};
@@ -6,7 +7,10 @@ use error_stack::Report;
 use futures::{Stream, stream};
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
-pub use self::spicedb::{RpcError, SpiceDbOpenApi};
+pub use self::{
+    embedded::EmbeddedZanzibar,
+    spicedb::{RpcError, SpiceDbOpenApi},
+};
 use crate::{
     NoAuthorization,
     zanzibar::{
@@ -0,0 +1,373 @@
+#![expect(clippy::panic_in_result_fn)]
+
+mod schema;
+
+use core::error::Error;
+
+use futures::TryStreamExt as _;
+use hash_graph_authorization::{
+    AuthorizationApi as _,
+    backend::{EmbeddedZanzibar, ModifyRelationshipOperation, ZanzibarBackend as _},
+    schema::{
+        EntityEditorSubject, EntityOwnerSubject, EntityPermission, EntityRelationAndSubject,
+        EntitySetting, EntitySettingSubject, EntityViewerSubject, WebEntityViewerSubject,
+        WebOwnerSubject, WebRelationAndSubject,
+    },
+    zanzibar::{Consistency, ZanzibarClient, types::RelationshipFilter},
+};
+use hash_graph_types::{
+    knowledge::entity::{EntityId, EntityUuid},
+    owned_by_id::OwnedById,
+};
+use uuid::Uuid;
+
+use crate::schema::{ALICE, BOB, ENTITY_A, ENTITY_B};
+
+const WEB: OwnedById = OwnedById::new(Uuid::from_fields(3, 0, 0, &[0; 8]));
+
+async fn backend() -> Result<EmbeddedZanzibar, Box<dyn Error>> {
+    let mut backend = EmbeddedZanzibar::new();
+    backend
+        .import_schema(include_str!("../schemas/v1__initial_schema.zed"))
+        .await?;
+    Ok(backend)
+}
+
+#[tokio::test]
+async fn schema_round_trip() -> Result<(), Box<dyn Error>> {
+    let backend = backend().await?;
+
+    assert_eq!(
+        backend.export_schema().await?.schema,
+        include_str!("../schemas/v1__initial_schema.zed")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn plain_permissions() -> Result<(), Box<dyn Error>> {
+    let mut backend = backend().await?;
+
+    let token = backend
+        .touch_relationships([
+            (ENTITY_A, EntityRelationAndSubject::Editor {
+                subject: EntityEditorSubject::Account { id: ALICE },
+                level: 0,
+            }),
+            (ENTITY_A, EntityRelationAndSubject::Viewer {
+                subject: EntityViewerSubject::Account { id: BOB },
+                level: 0,
+            }),
+        ])
+        .await?
+        .written_at;
+    let consistency = Consistency::AtLeastAsFresh(&token);
+
+    for (permission, account, expected) in [
+        (EntityPermission::View, ALICE, true),
+        (EntityPermission::Update, ALICE, true),
+        (EntityPermission::FullAccess, ALICE, false),
+        (EntityPermission::View, BOB, true),
+        (EntityPermission::Update, BOB, false),
+    ] {
+        assert_eq!(
+            backend
+                .check_permission(&ENTITY_A, &permission, &account, consistency)
+                .await?
+                .has_permission,
+            expected,
+            "{account:?} {permission:?}"
+        );
+    }
+    assert!(
+        !backend
+            .check_permission(&ENTITY_B, &EntityPermission::View, &ALICE, consistency)
+            .await?
+            .has_permission
+    );
+
+    let deleted_at = backend
+        .delete_relationships([(ENTITY_A, EntityRelationAndSubject::Viewer {
+            subject: EntityViewerSubject::Account { id: BOB },
+            level: 0,
+        })])
+        .await?
+        .written_at;
+    assert!(
+        !backend
+            .check_permission(
+                &ENTITY_A,
+                &EntityPermission::View,
+                &BOB,
+                Consistency::AtLeastAsFresh(&deleted_at)
+            )
+            .await?
+            .has_permission
+    );
+
+    // The previous revision still contains the deleted relationship
+    assert!(
+        backend
+            .check_permission(
+                &ENTITY_A,
+                &EntityPermission::View,
+                &BOB,
+                Consistency::AtExactSnapshot(&token)
+            )
+            .await?
+            .has_permission
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn public_access() -> Result<(), Box<dyn Error>> {
+    let mut backend = backend().await?;
+
+    backend
+        .touch_relationships([(ENTITY_A, EntityRelationAndSubject::Viewer {
+            subject: EntityViewerSubject::Public,
+            level: 0,
+        })])
+        .await?;
+
+    for account in [ALICE, BOB] {
+        assert!(
+            backend
+                .check_permission(
+                    &ENTITY_A,
+                    &EntityPermission::View,
+                    &account,
+                    Consistency::FullyConsistent
+                )
+                .await?
+                .has_permission
+        );
+        assert!(
+            !backend
+                .check_permission(
+                    &ENTITY_A,
+                    &EntityPermission::Update,
+                    &account,
+                    Consistency::FullyConsistent
+                )
+                .await?
+                .has_permission
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn web_permissions() -> Result<(), Box<dyn Error>> {
+    let mut client = ZanzibarClient::new(backend().await?);
+    client.seed().await?;
+
+    let entity_a = EntityId {
+        owned_by_id: WEB,
+        entity_uuid: ENTITY_A,
+        draft_id: None,
+    };
+    client
+        .modify_web_relations([
+            (
+                ModifyRelationshipOperation::Create,
+                WEB,
+                WebRelationAndSubject::Owner {
+                    subject: WebOwnerSubject::Account { id: ALICE },
+                    level: 0,
+                },
+            ),
+            (
+                ModifyRelationshipOperation::Create,
+                WEB,
+                WebRelationAndSubject::EntityViewer {
+                    subject: WebEntityViewerSubject::Account { id: BOB },
+                    level: 0,
+                },
+            ),
+        ])
+        .await?;
+    let token = client
+        .modify_entity_relations(
+            [
+                EntityRelationAndSubject::Owner {
+                    subject: EntityOwnerSubject::Web { id: WEB },
+                    level: 0,
+                },
+                EntityRelationAndSubject::Setting {
+                    subject: EntitySettingSubject::Setting {
+                        id: EntitySetting::AdministratorFromWeb,
+                    },
+                    level: 0,
+                },
+                EntityRelationAndSubject::Setting {
+                    subject: EntitySettingSubject::Setting {
+                        id: EntitySetting::UpdateFromWeb,
+                    },
+                    level: 0,
+                },
+                EntityRelationAndSubject::Setting {
+                    subject: EntitySettingSubject::Setting {
+                        id: EntitySetting::ViewFromWeb,
+                    },
+                    level: 0,
+                },
+            ]
+            .map(|relation| (ModifyRelationshipOperation::Create, entity_a, relation)),
+        )
+        .await?;
+    let consistency = Consistency::AtLeastAsFresh(&token);
+
+    for (permission, account, expected) in [
+        (EntityPermission::FullAccess, ALICE, true),
+        (EntityPermission::Update, ALICE, true),
+        (EntityPermission::View, ALICE, true),
+        (EntityPermission::FullAccess, BOB, false),
+        (EntityPermission::Update, BOB, false),
+        (EntityPermission::View, BOB, true),
+    ] {
+        assert_eq!(
+            client
+                .check_entity_permission(account, permission, entity_a, consistency)
+                .await?
+                .has_permission,
+            expected,
+            "{account:?} {permission:?}"
+        );
+    }
+
+    // Without the setting, the web administrator does not have full access to the entity
+    client
+        .modify_entity_relations([(
+            ModifyRelationshipOperation::Delete,
+            entity_a,
+            EntityRelationAndSubject::Setting {
+                subject: EntitySettingSubject::Setting {
+                    id: EntitySetting::AdministratorFromWeb,
+                },
+                level: 0,
+            },
+        )])
+        .await?;
+    assert!(
+        !client
+            .check_entity_permission(
+                ALICE,
+                EntityPermission::FullAccess,
+                entity_a,
+                Consistency::FullyConsistent
+            )
+            .await?
+            .has_permission
+    );
+
+    let relations = client
+        .get_entity_relations(entity_a, Consistency::FullyConsistent)
+        .await?;
+    assert_eq!(relations.len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_and_delete_relations() -> Result<(), Box<dyn Error>> {
+    let mut backend = backend().await?;
+
+    backend
+        .touch_relationships([
+            (ENTITY_A, EntityRelationAndSubject::Viewer {
+                subject: EntityViewerSubject::Account { id: ALICE },
+                level: 0,
+            }),
+            (ENTITY_B, EntityRelationAndSubject::Viewer {
+                subject: EntityViewerSubject::Account { id: BOB },
+                level: 0,
+            }),
+        ])
+        .await?;
+
+    let relations = backend
+        .read_relations::<(EntityUuid, EntityRelationAndSubject)>(
+            RelationshipFilter::from_resource(ENTITY_A),
+            Consistency::FullyConsistent,
+        )
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(relations, [(ENTITY_A, EntityRelationAndSubject::Viewer {
+        subject: EntityViewerSubject::Account { id: ALICE },
+        level: 0,
+    })]);
+
+    backend
+        .delete_relations(RelationshipFilter::from_resource(ENTITY_A))
+        .await?;
+    let relations = backend
+        .read_relations::<(EntityUuid, EntityRelationAndSubject)>(
+            RelationshipFilter::from_resource(ENTITY_B),
+            Consistency::FullyConsistent,
+        )
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(relations.len(), 1);
+    assert!(
+        backend
+            .read_relations::<(EntityUuid, EntityRelationAndSubject)>(
+                RelationshipFilter::from_resource(ENTITY_A),
+                Consistency::FullyConsistent,
+            )
+            .await?
+            .try_collect::<Vec<_>>()
+            .await?
+            .is_empty()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn invalid_writes() -> Result<(), Box<dyn Error>> {
+    let mut backend = backend().await?;
+
+    let relationship = (ENTITY_A, EntityRelationAndSubject::Viewer {
+        subject: EntityViewerSubject::Account { id: ALICE },
+        level: 0,
+    });
+    backend.create_relationships([relationship]).await?;
+
+    // Creating an existing relationship fails and does not apply any other update
+    backend
+        .create_relationships([
+            (ENTITY_B, EntityRelationAndSubject::Viewer {
+                subject: EntityViewerSubject::Account { id: ALICE },
+                level: 0,
+            }),
+            relationship,
+        ])
+        .await
+        .expect_err("relationship already exists");
+    assert!(
+        !backend
+            .check_permission(
+                &ENTITY_B,
+                &EntityPermission::View,
+                &ALICE,
+                Consistency::FullyConsistent
+            )
+            .await?
+            .has_permission
+    );
+
+    // Relationships can only be written after a schema was imported
+    EmbeddedZanzibar::new()
+        .touch_relationships([relationship])
+        .await
+        .expect_err("no schema was imported");
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+//! In-memory implementation of the HASH Graph store
+//!
+//! The store in this crate keeps all data in process memory and implements the same store traits
+//! as the Postgres-backed store. It is intended for tests and local tooling which should not depend
+//! on a running database. Data is lost when the last [`MemoryStorePool`] is dropped.
+//!
+//! [`MemoryStorePool`]: store::MemoryStorePool
+
+#![feature(hash_raw_entry)]
+#![feature(impl_trait_in_assoc_type)]
+#![feature(let_chains)]
+#![expect(
+    unreachable_pub,
+    clippy::significant_drop_tightening,
+    reason = "This should be enabled but it's currently too noisy"
+)]
+
+extern crate alloc;
+
+pub mod store;
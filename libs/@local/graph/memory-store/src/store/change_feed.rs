@@ -0,0 +1,97 @@
+use error_stack::Report;
+use hash_graph_authorization::AuthorizationApi;
+use hash_graph_store::{
+    change_feed::{ChangeFeedStore, GraphChange, ReadGraphChangesParams, ReadGraphChangesResponse},
+    error::QueryError,
+};
+use hash_graph_types::account::AccountId;
+
+use crate::store::MemoryStore;
+
+/// Returns the changes recorded after [`ReadGraphChangesParams::after`].
+///
+/// `changes` is ordered by ID, so the first change to return can be found by a binary search.
+fn read_changes(changes: &[GraphChange], params: ReadGraphChangesParams) -> Vec<GraphChange> {
+    let start = params.after.map_or(0, |after| {
+        changes.partition_point(|change| change.id <= after)
+    });
+    changes[start..]
+        .iter()
+        .take(params.limit)
+        .cloned()
+        .collect()
+}
+
+impl<A> ChangeFeedStore for MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn read_graph_changes(
+        &self,
+        actor_id: AccountId,
+        params: ReadGraphChangesParams,
+    ) -> Result<ReadGraphChangesResponse, Report<QueryError>> {
+        let changes = read_changes(&self.state.read().await.changes, params);
+        ReadGraphChangesResponse::from_permitted_changes(
+            &self.authorization_api,
+            actor_id,
+            changes,
+            params.after,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr as _;
+
+    use hash_graph_store::change_feed::{GraphChangeId, GraphChangeKind, ReadGraphChangesParams};
+    use type_system::url::VersionedUrl;
+
+    use super::read_changes;
+    use crate::store::state::MemoryState;
+
+    #[test]
+    fn read_changes_with_cursor() {
+        let mut state = MemoryState::default();
+        state.publish_changes((1..=5).map(|version| {
+            GraphChangeKind::TypeCreated {
+                ontology_type_id: VersionedUrl::from_str(&format!(
+                    "https://example.com/types/data-type/text/v/{version}"
+                ))
+                .expect("should be a valid URL"),
+            }
+        }));
+
+        let ids = |params| {
+            read_changes(&state.changes, params)
+                .into_iter()
+                .map(|change| change.id.into_inner())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ids(ReadGraphChangesParams {
+                after: None,
+                limit: 2,
+            }),
+            [1, 2]
+        );
+        assert_eq!(
+            ids(ReadGraphChangesParams {
+                after: Some(GraphChangeId::new(2)),
+                limit: 10,
+            }),
+            [3, 4, 5]
+        );
+        assert!(
+            ids(ReadGraphChangesParams {
+                after: Some(GraphChangeId::new(5)),
+                limit: 10,
+            })
+            .is_empty()
+        );
+    }
+}
@@ -0,0 +1,1469 @@
+//! Evaluation of [`Filter`]s against the in-memory state.
+//!
+//! Every query path is resolved to a list of [`Value`]s. Paths which traverse edges may resolve to
+//! more than one value, in which case a comparison matches if any of the resolved values matches.
+//! This mirrors the semantics of the joins emitted by the Postgres query compiler.
+
+use alloc::borrow::Cow;
+use core::cmp::Ordering;
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    sync::{Mutex, PoisonError},
+};
+
+use error_stack::{Report, ResultExt as _};
+use hash_graph_store::{
+    data_type::DataTypeQueryPath,
+    entity::EntityQueryPath,
+    entity_type::EntityTypeQueryPath,
+    error::QueryError,
+    filter::{
+        Filter, FilterExpression, JsonPath, Parameter, ParameterList, PathToken, QueryRecord,
+    },
+    property_type::PropertyTypeQueryPath,
+    query::CursorField,
+    subgraph::{
+        edges::{EdgeDirection, KnowledgeGraphEdgeKind, OntologyEdgeKind, SharedEdgeKind},
+        temporal_axes::QueryTemporalAxes,
+    },
+};
+use hash_graph_temporal_versioning::{TemporalInterval, TemporalTagged as _, Timestamp};
+use hash_graph_types::{
+    knowledge::entity::{Entity, EntityId},
+    ontology::{
+        DataTypeWithMetadata, EntityTypeWithMetadata, OntologyTypeClassificationMetadata,
+        PropertyTypeWithMetadata,
+    },
+};
+use hash_status::StatusCode;
+use regex::Regex;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use type_system::{
+    schema::{
+        DataType, DataTypeUuid, EntityType, EntityTypeUuid, InheritanceDepth, OntologyTypeUuid,
+        PropertyType, PropertyTypeUuid,
+    },
+    url::{OntologyTypeVersion, VersionedUrl},
+};
+use uuid::Uuid;
+
+use crate::store::state::{
+    DataTypeClosure, EntityTypeClosure, MemoryState, OntologyEdition, OntologyRecord,
+    is_entity_visible, is_latest_version,
+};
+
+/// A single value a query path resolves to.
+#[derive(Debug, Clone)]
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    Text(String),
+    Uuid(Uuid),
+    Version {
+        version: OntologyTypeVersion,
+        latest: bool,
+    },
+    Timestamp(Timestamp<()>),
+    Interval(TemporalInterval<()>),
+    Vector(Vec<f32>),
+    Json(JsonValue),
+}
+
+impl Value {
+    pub(crate) fn from_json(value: JsonValue) -> Self {
+        match value {
+            JsonValue::Null => Self::Null,
+            JsonValue::Bool(value) => Self::Bool(value),
+            JsonValue::Number(number) => number.as_f64().map_or(Self::Null, Self::Number),
+            JsonValue::String(text) => Self::Text(text),
+            value @ (JsonValue::Array(_) | JsonValue::Object(_)) => Self::Json(value),
+        }
+    }
+
+    fn serialized(value: &impl Serialize) -> Self {
+        serde_json::to_value(value).map_or(Self::Null, Self::from_json)
+    }
+
+    fn json_path(value: &impl Serialize, path: Option<&JsonPath<'_>>) -> Self {
+        let Ok(mut value) = serde_json::to_value(value) else {
+            return Self::Null;
+        };
+        for token in path.map(JsonPath::path_tokens).unwrap_or_default() {
+            let child = match token {
+                PathToken::Field(field) => value.get_mut(field.as_ref()),
+                PathToken::Index(index) => value.get_mut(*index),
+            };
+            let Some(child) = child else {
+                return Self::Null;
+            };
+            value = child.take();
+        }
+        Self::from_json(value)
+    }
+
+    fn interval<A>(interval: impl Into<TemporalInterval<A>>) -> Self {
+        Self::Interval(interval.into().cast())
+    }
+
+    pub(crate) const fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    /// Returns the lowercase words of all text values, including the ones nested in JSON.
+    fn words(&self) -> Vec<String> {
+        fn collect_json(value: &JsonValue, words: &mut Vec<String>) {
+            match value {
+                JsonValue::String(text) => words.extend(tokenize(text)),
+                JsonValue::Array(values) => {
+                    for value in values {
+                        collect_json(value, words);
+                    }
+                }
+                JsonValue::Object(object) => {
+                    for value in object.values() {
+                        collect_json(value, words);
+                    }
+                }
+                JsonValue::Null | JsonValue::Bool(_) | JsonValue::Number(_) => {}
+            }
+        }
+
+        let mut words = Vec::new();
+        match self {
+            Self::Text(text) => words.extend(tokenize(text)),
+            Self::Json(value) => collect_json(value, &mut words),
+            _ => {}
+        }
+        words
+    }
+
+    fn as_uuid(&self) -> Option<Uuid> {
+        match self {
+            Self::Uuid(uuid) => Some(*uuid),
+            Self::Text(text) => Uuid::parse_str(text).ok(),
+            _ => None,
+        }
+    }
+
+    /// Compares two values.
+    ///
+    /// Returns [`None`] if the values are not comparable, e.g. if one of them is null or if they
+    /// have different types.
+    pub(crate) fn compare(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Self::Null, _) | (_, Self::Null) => None,
+            (Self::Bool(lhs), Self::Bool(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::Number(lhs), Self::Number(rhs)) => lhs.partial_cmp(rhs),
+            (Self::Text(lhs), Self::Text(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::Uuid(_), Self::Text(_)) | (Self::Text(_), Self::Uuid(_)) => {
+                Some(self.as_uuid()?.cmp(&other.as_uuid()?))
+            }
+            (Self::Uuid(lhs), Self::Uuid(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::Version { version: lhs, .. }, Self::Version { version: rhs, .. }) => {
+                Some(lhs.cmp(rhs))
+            }
+            (Self::Version { version, .. }, Self::Number(number)) => {
+                f64::from(version.inner()).partial_cmp(number)
+            }
+            (Self::Number(number), Self::Version { version, .. }) => {
+                number.partial_cmp(&f64::from(version.inner()))
+            }
+            (Self::Version { latest, .. }, Self::Text(text))
+            | (Self::Text(text), Self::Version { latest, .. }) => {
+                (*latest && text == "latest").then_some(Ordering::Equal)
+            }
+            (Self::Timestamp(lhs), Self::Timestamp(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::Interval(lhs), Self::Interval(rhs)) => Some(lhs.cmp(rhs)),
+            (Self::Vector(lhs), Self::Vector(rhs)) => (lhs == rhs).then_some(Ordering::Equal),
+            (Self::Json(lhs), Self::Json(rhs)) => (lhs == rhs).then_some(Ordering::Equal),
+            _ => None,
+        }
+    }
+
+    fn equals(&self, other: &Self) -> bool {
+        self.compare(other) == Some(Ordering::Equal)
+    }
+
+    fn cosine_distance(&self, other: &Self) -> Option<f64> {
+        let (Self::Vector(lhs), Self::Vector(rhs)) = (self, other) else {
+            return None;
+        };
+        if lhs.len() != rhs.len() {
+            return None;
+        }
+
+        let (mut dot, mut lhs_norm, mut rhs_norm) = (0.0_f64, 0.0_f64, 0.0_f64);
+        for (lhs, rhs) in lhs.iter().zip(rhs) {
+            let (lhs, rhs) = (f64::from(*lhs), f64::from(*rhs));
+            dot += lhs * rhs;
+            lhs_norm += lhs * lhs;
+            rhs_norm += rhs * rhs;
+        }
+        let norm = lhs_norm.sqrt() * rhs_norm.sqrt();
+        (norm > 0.0).then(|| 1.0 - dot / norm)
+    }
+}
+
+/// Splits `text` into lowercase words, similar to the `simple` text search configuration.
+fn tokenize(text: &str) -> impl Iterator<Item = String> {
+    text.split(|character: char| !character.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// A single element of a SQL `LIKE` pattern.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum LikeToken {
+    /// `%`, matches any sequence of characters.
+    AnyString,
+    /// `_`, matches exactly one character.
+    AnyCharacter,
+    /// Any other character, or a character escaped with `\`.
+    Character(char),
+}
+
+/// Returns if `text` matches the SQL `LIKE` `pattern`.
+///
+/// The pattern is matched without recursion: when a character does not match, matching continues
+/// after the most recent `%`, which then consumes one more character. This takes at most
+/// `O(text * pattern)` steps.
+///
+/// # Errors
+///
+/// - if `pattern` ends with an escape character, which Postgres rejects as well
+fn like(text: &str, pattern: &str, case_insensitive: bool) -> Result<bool, Report<QueryError>> {
+    let (text, pattern) = if case_insensitive {
+        (
+            Cow::Owned(text.to_lowercase()),
+            Cow::Owned(pattern.to_lowercase()),
+        )
+    } else {
+        (Cow::Borrowed(text), Cow::Borrowed(pattern))
+    };
+
+    let text = text.chars().collect::<Vec<_>>();
+    let mut pattern_characters = pattern.chars();
+    let mut tokens = Vec::new();
+    while let Some(character) = pattern_characters.next() {
+        tokens.push(match character {
+            '%' => LikeToken::AnyString,
+            '_' => LikeToken::AnyCharacter,
+            '\\' => LikeToken::Character(pattern_characters.next().ok_or_else(|| {
+                Report::new(QueryError)
+                    .attach(StatusCode::InvalidArgument)
+                    .attach_printable(format!(
+                        "LIKE pattern must not end with escape character: {pattern}"
+                    ))
+            })?),
+            character => LikeToken::Character(character),
+        });
+    }
+
+    let mut text_index = 0;
+    let mut token_index = 0;
+    // The token after the most recent `%` and the text position this `%` matched up to.
+    let mut backtrack = None;
+    while text_index < text.len() {
+        match tokens.get(token_index) {
+            Some(LikeToken::AnyString) => {
+                token_index += 1;
+                backtrack = Some((token_index, text_index));
+                continue;
+            }
+            Some(LikeToken::AnyCharacter) => {
+                token_index += 1;
+                text_index += 1;
+                continue;
+            }
+            Some(LikeToken::Character(character)) if *character == text[text_index] => {
+                token_index += 1;
+                text_index += 1;
+                continue;
+            }
+            Some(LikeToken::Character(_)) | None => {}
+        }
+
+        let Some((backtrack_token_index, backtrack_text_index)) = backtrack else {
+            return Ok(false);
+        };
+        token_index = backtrack_token_index;
+        text_index = backtrack_text_index + 1;
+        backtrack = Some((backtrack_token_index, text_index));
+    }
+
+    Ok(tokens[token_index..]
+        .iter()
+        .all(|token| *token == LikeToken::AnyString))
+}
+
+/// A simplified web search query as accepted by [`Filter::FullTextMatch`].
+///
+/// Every word has to be contained in the document unless it's prefixed with `-`, in which case it
+/// must not be contained. Phrases and `or` are treated as plain words.
+struct FullTextQuery {
+    included: Vec<String>,
+    excluded: Vec<String>,
+}
+
+impl FullTextQuery {
+    fn parse(query: &str) -> Self {
+        let mut included = Vec::new();
+        let mut excluded = Vec::new();
+        for term in query.split_whitespace() {
+            if let Some(term) = term.strip_prefix('-') {
+                excluded.extend(tokenize(term));
+            } else {
+                included.extend(tokenize(term).filter(|word| word != "or"));
+            }
+        }
+        Self { included, excluded }
+    }
+
+    fn matches(&self, words: &[String]) -> bool {
+        !self.included.is_empty()
+            && self.included.iter().all(|term| words.contains(term))
+            && !self.excluded.iter().any(|term| words.contains(term))
+    }
+
+    /// Returns the number of occurrences of the query words in `words`.
+    fn rank(&self, words: &[String]) -> usize {
+        words
+            .iter()
+            .filter(|word| self.included.contains(word))
+            .count()
+    }
+}
+
+impl Value {
+    /// Converts the value into the field of an [`EntityQueryCursor`].
+    ///
+    /// [`EntityQueryCursor`]: hash_graph_store::entity::EntityQueryCursor
+    pub(crate) fn into_cursor_field(self) -> CursorField<'static> {
+        match self {
+            Self::Null => CursorField::Json(JsonValue::Null),
+            Self::Bool(value) => CursorField::Bool(value),
+            Self::Number(number) => CursorField::F64(number),
+            Self::Text(text) => CursorField::String(Cow::Owned(text)),
+            Self::Uuid(uuid) => CursorField::Uuid(uuid),
+            Self::Version { version, .. } => CursorField::F64(f64::from(version.inner())),
+            Self::Timestamp(timestamp) => CursorField::Timestamp(timestamp),
+            Self::Interval(interval) => CursorField::TimeInterval(interval),
+            Self::Vector(vector) => CursorField::Json(JsonValue::from(vector)),
+            Self::Json(value) => CursorField::Json(value),
+        }
+    }
+
+    /// Converts the value into JSON, e.g. to return it as the key of an aggregation group.
+    pub(crate) fn into_json(self) -> JsonValue {
+        match self {
+            Self::Null => JsonValue::Null,
+            Self::Bool(value) => JsonValue::Bool(value),
+            Self::Number(number) => JsonValue::from(number),
+            Self::Text(text) => JsonValue::String(text),
+            Self::Uuid(uuid) => JsonValue::String(uuid.to_string()),
+            Self::Version { version, .. } => JsonValue::from(version.inner()),
+            Self::Timestamp(timestamp) => {
+                serde_json::to_value(timestamp).unwrap_or(JsonValue::Null)
+            }
+            Self::Interval(interval) => serde_json::to_value(interval).unwrap_or(JsonValue::Null),
+            Self::Vector(vector) => JsonValue::from(vector),
+            Self::Json(value) => value,
+        }
+    }
+}
+
+impl From<&CursorField<'_>> for Value {
+    fn from(field: &CursorField<'_>) -> Self {
+        match field {
+            CursorField::Bool(value) => Self::Bool(*value),
+            CursorField::I32(number) => Self::Number(f64::from(*number)),
+            CursorField::F64(number) => Self::Number(*number),
+            CursorField::String(text) => Self::Text(text.to_string()),
+            CursorField::Timestamp(timestamp) => Self::Timestamp(*timestamp),
+            CursorField::TimeInterval(interval) => Self::Interval(*interval),
+            CursorField::Json(value) => Self::from_json(value.clone()),
+            CursorField::Uuid(uuid) => Self::Uuid(*uuid),
+        }
+    }
+}
+
+impl From<&Parameter<'_>> for Value {
+    fn from(parameter: &Parameter<'_>) -> Self {
+        match parameter {
+            Parameter::Boolean(value) => Self::Bool(*value),
+            Parameter::I32(number) => Self::Number(f64::from(*number)),
+            Parameter::F64(number) => Self::Number(*number),
+            Parameter::Text(text) => Self::Text(text.to_string()),
+            Parameter::Vector(vector) => Self::Vector(vector.iter().collect()),
+            Parameter::Any(value) => Self::from_json(value.clone()),
+            Parameter::Uuid(uuid) => Self::Uuid(*uuid),
+            Parameter::OntologyTypeVersion(version) => Self::Version {
+                version: *version,
+                latest: false,
+            },
+            Parameter::Timestamp(timestamp) => Self::Timestamp(*timestamp),
+        }
+    }
+}
+
+/// A borrowed ontology type together with the edition which is visible to the current query.
+pub(crate) struct OntologyView<'s, S, C> {
+    pub id: &'s VersionedUrl,
+    pub record: &'s OntologyRecord<S, C>,
+    pub edition: &'s OntologyEdition,
+}
+
+impl<S, C> Clone for OntologyView<'_, S, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S, C> Copy for OntologyView<'_, S, C> {}
+
+pub(crate) type DataTypeView<'s> = OntologyView<'s, DataType, DataTypeClosure>;
+pub(crate) type PropertyTypeView<'s> = OntologyView<'s, PropertyType, ()>;
+pub(crate) type EntityTypeView<'s> = OntologyView<'s, EntityType, EntityTypeClosure>;
+
+/// The state and query parameters a [`Filter`] is evaluated against.
+pub(crate) struct FilterContext<'s> {
+    pub state: &'s MemoryState,
+    pub temporal_axes: &'s QueryTemporalAxes,
+    pub include_drafts: bool,
+    /// The regular expressions used by the filters so far, so every pattern is only compiled once.
+    regexes: Mutex<HashMap<String, Regex>>,
+}
+
+impl<'s> FilterContext<'s> {
+    pub(crate) fn new(
+        state: &'s MemoryState,
+        temporal_axes: &'s QueryTemporalAxes,
+        include_drafts: bool,
+    ) -> Self {
+        Self {
+            state,
+            temporal_axes,
+            include_drafts,
+            regexes: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns if the record represented by `view` is matched by `filter`.
+    ///
+    /// # Errors
+    ///
+    /// - if a regular expression or `LIKE` pattern used by `filter` is invalid
+    pub(crate) fn matches<'p, R: FilterRecord>(
+        &self,
+        filter: &Filter<'p, R>,
+        view: R::View<'s>,
+    ) -> Result<bool, Report<QueryError>> {
+        Ok(match filter {
+            Filter::All(filters) => {
+                for filter in filters {
+                    if !self.matches(filter, view)? {
+                        return Ok(false);
+                    }
+                }
+                true
+            }
+            Filter::Any(filters) => {
+                for filter in filters {
+                    if self.matches(filter, view)? {
+                        return Ok(true);
+                    }
+                }
+                false
+            }
+            Filter::Not(filter) => !self.matches(filter, view)?,
+            Filter::Equal(lhs, rhs) => match (lhs, rhs) {
+                (Some(lhs), Some(rhs)) => self.any_pair(lhs, rhs, view, Value::equals),
+                (Some(expression), None) | (None, Some(expression)) => {
+                    self.values(expression, view).iter().all(Value::is_null)
+                }
+                (None, None) => true,
+            },
+            Filter::NotEqual(lhs, rhs) => match (lhs, rhs) {
+                (Some(lhs), Some(rhs)) => self.any_pair(lhs, rhs, view, |lhs, rhs| {
+                    !lhs.is_null() && !rhs.is_null() && !lhs.equals(rhs)
+                }),
+                (Some(expression), None) | (None, Some(expression)) => self
+                    .values(expression, view)
+                    .iter()
+                    .any(|value| !value.is_null()),
+                (None, None) => false,
+            },
+            Filter::Greater(lhs, rhs) => self.any_pair(lhs, rhs, view, |lhs, rhs| {
+                lhs.compare(rhs) == Some(Ordering::Greater)
+            }),
+            Filter::GreaterOrEqual(lhs, rhs) => self.any_pair(lhs, rhs, view, |lhs, rhs| {
+                matches!(lhs.compare(rhs), Some(Ordering::Greater | Ordering::Equal))
+            }),
+            Filter::Less(lhs, rhs) => self.any_pair(lhs, rhs, view, |lhs, rhs| {
+                lhs.compare(rhs) == Some(Ordering::Less)
+            }),
+            Filter::LessOrEqual(lhs, rhs) => self.any_pair(lhs, rhs, view, |lhs, rhs| {
+                matches!(lhs.compare(rhs), Some(Ordering::Less | Ordering::Equal))
+            }),
+            Filter::CosineDistance(lhs, rhs, max) => {
+                let Some(max) = self.values(max, view).iter().find_map(Value::as_number) else {
+                    return Ok(false);
+                };
+                self.any_pair(lhs, rhs, view, |lhs, rhs| {
+                    lhs.cosine_distance(rhs)
+                        .is_some_and(|distance| distance <= max)
+                })
+            }
+            Filter::In(expression, list) => self
+                .values(expression, view)
+                .iter()
+                .filter_map(Value::as_uuid)
+                .any(|uuid| match list {
+                    ParameterList::DataTypeIds(ids) => ids.iter().any(|id| id.into_uuid() == uuid),
+                    ParameterList::PropertyTypeIds(ids) => {
+                        ids.iter().any(|id| id.into_uuid() == uuid)
+                    }
+                    ParameterList::EntityTypeIds(ids) => {
+                        ids.iter().any(|id| id.into_uuid() == uuid)
+                    }
+                    ParameterList::EntityEditionIds(ids) => {
+                        ids.iter().any(|id| id.into_uuid() == uuid)
+                    }
+                }),
+            Filter::StartsWith(lhs, rhs) => self.any_pair(lhs, rhs, view, |lhs, rhs| {
+                lhs.as_text()
+                    .zip(rhs.as_text())
+                    .is_some_and(|(lhs, rhs)| lhs.starts_with(rhs))
+            }),
+            Filter::EndsWith(lhs, rhs) => self.any_pair(lhs, rhs, view, |lhs, rhs| {
+                lhs.as_text()
+                    .zip(rhs.as_text())
+                    .is_some_and(|(lhs, rhs)| lhs.ends_with(rhs))
+            }),
+            Filter::ContainsSegment(lhs, rhs) => self.any_pair(lhs, rhs, view, |lhs, rhs| {
+                lhs.as_text()
+                    .zip(rhs.as_text())
+                    .is_some_and(|(lhs, rhs)| lhs.contains(rhs))
+            }),
+            Filter::Like(lhs, rhs) => self.any_like(lhs, rhs, view, false)?,
+            Filter::ILike(lhs, rhs) => self.any_like(lhs, rhs, view, true)?,
+            Filter::Regex(lhs, rhs) => {
+                let texts = self.values(lhs, view);
+                for pattern in self.values(rhs, view).iter().filter_map(Value::as_text) {
+                    let regex = self.regex(pattern)?;
+                    if texts
+                        .iter()
+                        .filter_map(Value::as_text)
+                        .any(|text| regex.is_match(text))
+                    {
+                        return Ok(true);
+                    }
+                }
+                false
+            }
+            Filter::FullTextMatch(lhs, rhs) => self.any_pair(lhs, rhs, view, |lhs, rhs| {
+                rhs.as_text()
+                    .map(FullTextQuery::parse)
+                    .is_some_and(|query| query.matches(&lhs.words()))
+            }),
+        })
+    }
+
+    /// Returns the records of `views` which are matched by `filter`.
+    ///
+    /// # Errors
+    ///
+    /// - if a regular expression or `LIKE` pattern used by `filter` is invalid
+    pub(crate) fn filter_views<'f, 'p, R: FilterRecord>(
+        &'f self,
+        filter: &'f Filter<'p, R>,
+        views: impl IntoIterator<Item = R::View<'s>> + 'f,
+    ) -> impl Iterator<Item = Result<R::View<'s>, Report<QueryError>>> + 'f {
+        views.into_iter().filter_map(move |view| {
+            self.matches(filter, view)
+                .map(|matches| matches.then_some(view))
+                .transpose()
+        })
+    }
+
+    /// Returns the compiled regular expression for `pattern`.
+    ///
+    /// Like in Postgres, an invalid pattern is an error instead of matching nothing.
+    fn regex(&self, pattern: &str) -> Result<Regex, Report<QueryError>> {
+        let mut regexes = self.regexes.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(regex) = regexes.get(pattern) {
+            return Ok(regex.clone());
+        }
+
+        let regex = Regex::new(pattern)
+            .change_context(QueryError)
+            .attach(StatusCode::InvalidArgument)
+            .attach_printable_lazy(|| format!("invalid regular expression: {pattern}"))?;
+        regexes.insert(pattern.to_owned(), regex.clone());
+        Ok(regex)
+    }
+
+    pub(crate) fn values<'p, R: FilterRecord>(
+        &self,
+        expression: &FilterExpression<'p, R>,
+        view: R::View<'s>,
+    ) -> Vec<Value> {
+        match expression {
+            FilterExpression::Path { path } => R::path_values(self, view, path),
+            FilterExpression::Parameter {
+                parameter,
+                convert: _,
+            } => vec![Value::from(parameter)],
+        }
+    }
+
+    /// Returns if any text of `lhs` matches any `LIKE` pattern of `rhs`.
+    fn any_like<'p, R: FilterRecord>(
+        &self,
+        lhs: &FilterExpression<'p, R>,
+        rhs: &FilterExpression<'p, R>,
+        view: R::View<'s>,
+        case_insensitive: bool,
+    ) -> Result<bool, Report<QueryError>> {
+        let texts = self.values(lhs, view);
+        for pattern in self.values(rhs, view).iter().filter_map(Value::as_text) {
+            for text in texts.iter().filter_map(Value::as_text) {
+                if like(text, pattern, case_insensitive)? {
+                    return Ok(true);
+                }
+            }
+        }
+        Ok(false)
+    }
+
+    fn any_pair<'p, R: FilterRecord>(
+        &self,
+        lhs: &FilterExpression<'p, R>,
+        rhs: &FilterExpression<'p, R>,
+        view: R::View<'s>,
+        predicate: impl Fn(&Value, &Value) -> bool,
+    ) -> bool {
+        let lhs = self.values(lhs, view);
+        let rhs = self.values(rhs, view);
+        lhs.iter()
+            .any(|lhs| rhs.iter().any(|rhs| predicate(lhs, rhs)))
+    }
+
+    fn ontology_view<S, C>(
+        &self,
+        records: &'s std::collections::BTreeMap<VersionedUrl, OntologyRecord<S, C>>,
+        ontology_id: OntologyTypeUuid,
+    ) -> Option<OntologyView<'s, S, C>> {
+        let url = self.state.ontology_ids.get(&ontology_id)?;
+        let (id, record) = records.get_key_value(url)?;
+        let edition = record.edition_at(self.temporal_axes)?;
+        Some(OntologyView {
+            id,
+            record,
+            edition,
+        })
+    }
+
+    fn ontology_views<S, C>(
+        &self,
+        records: &'s std::collections::BTreeMap<VersionedUrl, OntologyRecord<S, C>>,
+    ) -> impl Iterator<Item = OntologyView<'s, S, C>> {
+        let temporal_axes = self.temporal_axes;
+        records.iter().filter_map(move |(id, record)| {
+            Some(OntologyView {
+                id,
+                record,
+                edition: record.edition_at(temporal_axes)?,
+            })
+        })
+    }
+
+    pub(crate) fn data_type_view(&self, id: DataTypeUuid) -> Option<DataTypeView<'s>> {
+        self.ontology_view(&self.state.data_types, id.into())
+    }
+
+    pub(crate) fn property_type_view(&self, id: PropertyTypeUuid) -> Option<PropertyTypeView<'s>> {
+        self.ontology_view(&self.state.property_types, id.into())
+    }
+
+    pub(crate) fn entity_type_view(&self, id: EntityTypeUuid) -> Option<EntityTypeView<'s>> {
+        self.ontology_view(&self.state.entity_types, id.into())
+    }
+
+    pub(crate) fn data_type_views(&self) -> impl Iterator<Item = DataTypeView<'s>> {
+        self.ontology_views(&self.state.data_types)
+    }
+
+    pub(crate) fn property_type_views(&self) -> impl Iterator<Item = PropertyTypeView<'s>> {
+        self.ontology_views(&self.state.property_types)
+    }
+
+    pub(crate) fn entity_type_views(&self) -> impl Iterator<Item = EntityTypeView<'s>> {
+        self.ontology_views(&self.state.entity_types)
+    }
+
+    /// Returns all visible editions of all entities.
+    pub(crate) fn entity_views(&self) -> impl Iterator<Item = &'s Entity> {
+        let (temporal_axes, include_drafts) = (self.temporal_axes, self.include_drafts);
+        self.state
+            .entities
+            .values()
+            .flatten()
+            .filter(move |entity| is_entity_visible(entity, temporal_axes, include_drafts))
+    }
+
+    /// Returns the visible editions of the entity with the provided ID.
+    ///
+    /// Links reference entities without their draft ID, so all visible editions of the entity,
+    /// including drafts if requested, are returned.
+    pub(crate) fn entity_views_by_id(
+        &self,
+        entity_id: EntityId,
+    ) -> impl Iterator<Item = &'s Entity> {
+        let (temporal_axes, include_drafts) = (self.temporal_axes, self.include_drafts);
+        self.state
+            .entities
+            .get(&entity_id.entity_uuid)
+            .into_iter()
+            .flatten()
+            .filter(move |entity| {
+                entity.metadata.record_id.entity_id.owned_by_id == entity_id.owned_by_id
+                    && is_entity_visible(entity, temporal_axes, include_drafts)
+            })
+    }
+
+    /// Returns the entity types of an entity, including the inherited entity types, together with
+    /// their inheritance depth.
+    pub(crate) fn entity_type_depths(
+        &self,
+        entity: &Entity,
+    ) -> HashMap<EntityTypeUuid, InheritanceDepth> {
+        let mut depths = HashMap::new();
+        for entity_type_id in &entity.metadata.entity_type_ids {
+            let Some(record) = self.state.entity_types.get(entity_type_id) else {
+                continue;
+            };
+            for metadata in &record.closure.closed_schema.all_of {
+                let depth = InheritanceDepth::new(metadata.depth);
+                match depths.entry(EntityTypeUuid::from_url(&metadata.id)) {
+                    Entry::Occupied(mut entry) => {
+                        if depth < *entry.get() {
+                            entry.insert(depth);
+                        }
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert(depth);
+                    }
+                }
+            }
+        }
+        depths
+    }
+}
+
+fn within_depth(depth: InheritanceDepth, max_depth: Option<u32>) -> bool {
+    max_depth.is_none_or(|max_depth| u32::from(depth.inner()) <= max_depth)
+}
+
+fn owned_by_id(classification: &OntologyTypeClassificationMetadata) -> Value {
+    match classification {
+        OntologyTypeClassificationMetadata::Owned { owned_by_id } => {
+            Value::Uuid(owned_by_id.into_uuid())
+        }
+        OntologyTypeClassificationMetadata::External { .. } => Value::Null,
+    }
+}
+
+fn embedding<S, C>(record: &OntologyRecord<S, C>) -> Value {
+    record.embedding.as_ref().map_or(Value::Null, |embedding| {
+        Value::Vector(embedding.embedding.iter().collect())
+    })
+}
+
+/// A [`QueryRecord`] which can be filtered by the in-memory store.
+pub(crate) trait FilterRecord: QueryRecord {
+    type View<'s>: Copy;
+
+    fn path_values<'s>(
+        context: &FilterContext<'s>,
+        view: Self::View<'s>,
+        path: &Self::QueryPath<'_>,
+    ) -> Vec<Value>;
+}
+
+impl FilterRecord for DataTypeWithMetadata {
+    type View<'s> = DataTypeView<'s>;
+
+    fn path_values<'s>(
+        context: &FilterContext<'s>,
+        view: Self::View<'s>,
+        path: &DataTypeQueryPath<'_>,
+    ) -> Vec<Value> {
+        let data_type_id = DataTypeUuid::from_url(view.id);
+        match path {
+            DataTypeQueryPath::BaseUrl => vec![Value::Text(view.id.base_url.to_string())],
+            DataTypeQueryPath::Version => vec![Value::Version {
+                version: view.id.version,
+                latest: is_latest_version(
+                    &context.state.data_types,
+                    view.id,
+                    context.temporal_axes,
+                ),
+            }],
+            DataTypeQueryPath::VersionedUrl => vec![Value::Text(view.id.to_string())],
+            DataTypeQueryPath::TransactionTime => {
+                vec![Value::interval(view.edition.transaction_time.convert())]
+            }
+            DataTypeQueryPath::OwnedById => vec![owned_by_id(&view.record.classification)],
+            DataTypeQueryPath::Title => vec![Value::Text(view.record.schema.title.clone())],
+            DataTypeQueryPath::Description => {
+                vec![Value::Text(view.record.schema.description.clone())]
+            }
+            DataTypeQueryPath::Type => vec![Value::json_path(
+                &*view.record.schema,
+                Some(&JsonPath::from_path_tokens(vec![PathToken::Field(
+                    "type".into(),
+                )])),
+            )],
+            DataTypeQueryPath::OntologyId => vec![Value::Uuid(data_type_id.into_uuid())],
+            DataTypeQueryPath::Schema(path) => {
+                vec![Value::json_path(&*view.record.schema, path.as_ref())]
+            }
+            DataTypeQueryPath::AdditionalMetadata => {
+                vec![Value::serialized(&view.record.classification)]
+            }
+            DataTypeQueryPath::Embedding => vec![embedding(view.record)],
+            DataTypeQueryPath::EditionProvenance(path) => vec![Value::json_path(
+                &view.edition.provenance.edition,
+                path.as_ref(),
+            )],
+            DataTypeQueryPath::TargetConversionBaseUrls => view
+                .record
+                .closure
+                .conversions
+                .keys()
+                .map(|base_url| Value::Text(base_url.to_string()))
+                .collect(),
+            DataTypeQueryPath::FromConversions => view
+                .record
+                .closure
+                .conversions
+                .values()
+                .map(|conversions| Value::serialized(&conversions.from))
+                .collect(),
+            DataTypeQueryPath::IntoConversions => view
+                .record
+                .closure
+                .conversions
+                .values()
+                .map(|conversions| Value::serialized(&conversions.to))
+                .collect(),
+            DataTypeQueryPath::DataTypeEdge {
+                edge_kind: OntologyEdgeKind::InheritsFrom,
+                path,
+                direction: EdgeDirection::Outgoing,
+                inheritance_depth,
+            } => view
+                .record
+                .closure
+                .resolve_data
+                .inheritance_depths()
+                .filter(|(_, depth)| within_depth(*depth, *inheritance_depth))
+                .filter_map(|(parent_id, _)| context.data_type_view(parent_id))
+                .flat_map(|parent| Self::path_values(context, parent, path))
+                .collect(),
+            DataTypeQueryPath::DataTypeEdge {
+                edge_kind: OntologyEdgeKind::InheritsFrom,
+                path,
+                direction: EdgeDirection::Incoming,
+                inheritance_depth,
+            } => context
+                .data_type_views()
+                .filter(|child| {
+                    child.record.closure.resolve_data.inheritance_depths().any(
+                        |(parent_id, depth)| {
+                            parent_id == data_type_id && within_depth(depth, *inheritance_depth)
+                        },
+                    )
+                })
+                .flat_map(|child| Self::path_values(context, child, path))
+                .collect(),
+            DataTypeQueryPath::PropertyTypeEdge {
+                edge_kind: OntologyEdgeKind::ConstrainsValuesOn,
+                path,
+            } => context
+                .property_type_views()
+                .filter(|property_type| {
+                    property_type
+                        .record
+                        .schema
+                        .data_type_references()
+                        .iter()
+                        .any(|reference| reference.url == *view.id)
+                })
+                .flat_map(|property_type| {
+                    PropertyTypeWithMetadata::path_values(context, property_type, path)
+                })
+                .collect(),
+            DataTypeQueryPath::DataTypeEdge { .. } | DataTypeQueryPath::PropertyTypeEdge { .. } => {
+                unreachable!("Invalid path: {path}")
+            }
+        }
+    }
+}
+
+impl FilterRecord for PropertyTypeWithMetadata {
+    type View<'s> = PropertyTypeView<'s>;
+
+    fn path_values<'s>(
+        context: &FilterContext<'s>,
+        view: Self::View<'s>,
+        path: &PropertyTypeQueryPath<'_>,
+    ) -> Vec<Value> {
+        let property_type_id = PropertyTypeUuid::from_url(view.id);
+        match path {
+            PropertyTypeQueryPath::BaseUrl => vec![Value::Text(view.id.base_url.to_string())],
+            PropertyTypeQueryPath::Version => vec![Value::Version {
+                version: view.id.version,
+                latest: is_latest_version(
+                    &context.state.property_types,
+                    view.id,
+                    context.temporal_axes,
+                ),
+            }],
+            PropertyTypeQueryPath::VersionedUrl => vec![Value::Text(view.id.to_string())],
+            PropertyTypeQueryPath::TransactionTime => {
+                vec![Value::interval(view.edition.transaction_time.convert())]
+            }
+            PropertyTypeQueryPath::OwnedById => vec![owned_by_id(&view.record.classification)],
+            PropertyTypeQueryPath::Title => vec![Value::Text(view.record.schema.title.clone())],
+            PropertyTypeQueryPath::Description => {
+                vec![Value::Text(view.record.schema.description.clone())]
+            }
+            PropertyTypeQueryPath::OntologyId => vec![Value::Uuid(property_type_id.into_uuid())],
+            PropertyTypeQueryPath::Schema(path) => {
+                vec![Value::json_path(&*view.record.schema, path.as_ref())]
+            }
+            PropertyTypeQueryPath::AdditionalMetadata => {
+                vec![Value::serialized(&view.record.classification)]
+            }
+            PropertyTypeQueryPath::Embedding => vec![embedding(view.record)],
+            PropertyTypeQueryPath::EditionProvenance(path) => vec![Value::json_path(
+                &view.edition.provenance.edition,
+                path.as_ref(),
+            )],
+            PropertyTypeQueryPath::DataTypeEdge {
+                edge_kind: OntologyEdgeKind::ConstrainsValuesOn,
+                path,
+            } => view
+                .record
+                .schema
+                .data_type_references()
+                .into_iter()
+                .filter_map(|reference| {
+                    context.data_type_view(DataTypeUuid::from_url(&reference.url))
+                })
+                .flat_map(|data_type| DataTypeWithMetadata::path_values(context, data_type, path))
+                .collect(),
+            PropertyTypeQueryPath::PropertyTypeEdge {
+                edge_kind: OntologyEdgeKind::ConstrainsPropertiesOn,
+                path,
+                direction: EdgeDirection::Outgoing,
+            } => view
+                .record
+                .schema
+                .property_type_references()
+                .into_iter()
+                .filter_map(|reference| {
+                    context.property_type_view(PropertyTypeUuid::from_url(&reference.url))
+                })
+                .flat_map(|property_type| Self::path_values(context, property_type, path))
+                .collect(),
+            PropertyTypeQueryPath::PropertyTypeEdge {
+                edge_kind: OntologyEdgeKind::ConstrainsPropertiesOn,
+                path,
+                direction: EdgeDirection::Incoming,
+            } => context
+                .property_type_views()
+                .filter(|property_type| {
+                    property_type
+                        .record
+                        .schema
+                        .property_type_references()
+                        .iter()
+                        .any(|reference| reference.url == *view.id)
+                })
+                .flat_map(|property_type| Self::path_values(context, property_type, path))
+                .collect(),
+            PropertyTypeQueryPath::EntityTypeEdge {
+                edge_kind: OntologyEdgeKind::ConstrainsPropertiesOn,
+                path,
+                inheritance_depth,
+            } => context
+                .entity_type_views()
+                .filter(|entity_type| {
+                    entity_type
+                        .record
+                        .closure
+                        .resolve_data
+                        .properties()
+                        .any(|(id, depth)| {
+                            id == property_type_id && within_depth(depth, *inheritance_depth)
+                        })
+                })
+                .flat_map(|entity_type| {
+                    EntityTypeWithMetadata::path_values(context, entity_type, path)
+                })
+                .collect(),
+            PropertyTypeQueryPath::DataTypeEdge { .. }
+            | PropertyTypeQueryPath::PropertyTypeEdge { .. }
+            | PropertyTypeQueryPath::EntityTypeEdge { .. } => {
+                unreachable!("Invalid path: {path}")
+            }
+        }
+    }
+}
+
+impl FilterRecord for EntityTypeWithMetadata {
+    type View<'s> = EntityTypeView<'s>;
+
+    #[expect(clippy::too_many_lines)]
+    fn path_values<'s>(
+        context: &FilterContext<'s>,
+        view: Self::View<'s>,
+        path: &EntityTypeQueryPath<'_>,
+    ) -> Vec<Value> {
+        let entity_type_id = EntityTypeUuid::from_url(view.id);
+        match path {
+            EntityTypeQueryPath::BaseUrl => vec![Value::Text(view.id.base_url.to_string())],
+            EntityTypeQueryPath::Version => vec![Value::Version {
+                version: view.id.version,
+                latest: is_latest_version(
+                    &context.state.entity_types,
+                    view.id,
+                    context.temporal_axes,
+                ),
+            }],
+            EntityTypeQueryPath::VersionedUrl => vec![Value::Text(view.id.to_string())],
+            EntityTypeQueryPath::TransactionTime => {
+                vec![Value::interval(view.edition.transaction_time.convert())]
+            }
+            EntityTypeQueryPath::OwnedById => vec![owned_by_id(&view.record.classification)],
+            EntityTypeQueryPath::Title => vec![Value::Text(view.record.schema.title.clone())],
+            EntityTypeQueryPath::Description => vec![
+                view.record
+                    .schema
+                    .description
+                    .clone()
+                    .map_or(Value::Null, Value::Text),
+            ],
+            EntityTypeQueryPath::Required
+            | EntityTypeQueryPath::LabelProperty
+            | EntityTypeQueryPath::Icon => {
+                let field = match path {
+                    EntityTypeQueryPath::Required => "required",
+                    EntityTypeQueryPath::LabelProperty => "labelProperty",
+                    _ => "icon",
+                };
+                vec![Value::json_path(
+                    &*view.record.schema,
+                    Some(&JsonPath::from_path_tokens(vec![PathToken::Field(
+                        field.into(),
+                    )])),
+                )]
+            }
+            EntityTypeQueryPath::OntologyId => vec![Value::Uuid(entity_type_id.into_uuid())],
+            EntityTypeQueryPath::Schema(path) => {
+                vec![Value::json_path(&*view.record.schema, path.as_ref())]
+            }
+            EntityTypeQueryPath::ClosedSchema(path) => vec![Value::json_path(
+                &*view.record.closure.closed_schema,
+                path.as_ref(),
+            )],
+            EntityTypeQueryPath::AdditionalMetadata => {
+                vec![Value::serialized(&view.record.classification)]
+            }
+            EntityTypeQueryPath::Embedding => vec![embedding(view.record)],
+            EntityTypeQueryPath::EditionProvenance(path) => vec![Value::json_path(
+                &view.edition.provenance.edition,
+                path.as_ref(),
+            )],
+            EntityTypeQueryPath::PropertyTypeEdge {
+                edge_kind: OntologyEdgeKind::ConstrainsPropertiesOn,
+                path,
+                inheritance_depth,
+            } => view
+                .record
+                .closure
+                .resolve_data
+                .properties()
+                .filter(|(_, depth)| within_depth(*depth, *inheritance_depth))
+                .filter_map(|(id, _)| context.property_type_view(id))
+                .flat_map(|property_type| {
+                    PropertyTypeWithMetadata::path_values(context, property_type, path)
+                })
+                .collect(),
+            EntityTypeQueryPath::EntityTypeEdge {
+                edge_kind:
+                    edge_kind @ (OntologyEdgeKind::InheritsFrom
+                    | OntologyEdgeKind::ConstrainsLinksOn
+                    | OntologyEdgeKind::ConstrainsLinkDestinationsOn),
+                path,
+                direction,
+                inheritance_depth,
+            } => {
+                let edge_kind = *edge_kind;
+                let targets = move |entity_type: EntityTypeView<'s>| {
+                    let resolve_data = &*entity_type.record.closure.resolve_data;
+                    let targets: Vec<_> = match edge_kind {
+                        OntologyEdgeKind::InheritsFrom => {
+                            resolve_data.inheritance_depths().collect()
+                        }
+                        OntologyEdgeKind::ConstrainsLinksOn => resolve_data.links().collect(),
+                        _ => resolve_data.link_destinations().collect(),
+                    };
+                    targets
+                        .into_iter()
+                        .filter(|(_, depth)| within_depth(*depth, *inheritance_depth))
+                        .map(|(id, _)| id)
+                };
+
+                match direction {
+                    EdgeDirection::Outgoing => targets(view)
+                        .filter_map(|id| context.entity_type_view(id))
+                        .flat_map(|target| Self::path_values(context, target, path))
+                        .collect(),
+                    EdgeDirection::Incoming => context
+                        .entity_type_views()
+                        .filter(|source| targets(*source).any(|id| id == entity_type_id))
+                        .flat_map(|source| Self::path_values(context, source, path))
+                        .collect(),
+                }
+            }
+            EntityTypeQueryPath::EntityEdge {
+                edge_kind: SharedEdgeKind::IsOfType,
+                path,
+                inheritance_depth,
+            } => context
+                .entity_views()
+                .filter(|entity| {
+                    context
+                        .entity_type_depths(entity)
+                        .get(&entity_type_id)
+                        .is_some_and(|depth| within_depth(*depth, *inheritance_depth))
+                })
+                .flat_map(|entity| Entity::path_values(context, entity, path))
+                .collect(),
+            EntityTypeQueryPath::PropertyTypeEdge { .. }
+            | EntityTypeQueryPath::EntityTypeEdge { .. } => {
+                unreachable!("Invalid path: {path}")
+            }
+        }
+    }
+}
+
+impl FilterRecord for Entity {
+    type View<'s> = &'s Entity;
+
+    #[expect(clippy::too_many_lines)]
+    fn path_values<'s>(
+        context: &FilterContext<'s>,
+        entity: Self::View<'s>,
+        path: &EntityQueryPath<'_>,
+    ) -> Vec<Value> {
+        let entity_id = entity.metadata.record_id.entity_id;
+        match path {
+            EntityQueryPath::Uuid => vec![Value::Uuid(entity_id.entity_uuid.into_uuid())],
+            EntityQueryPath::OwnedById => vec![Value::Uuid(entity_id.owned_by_id.into_uuid())],
+            EntityQueryPath::DraftId => vec![
+                entity_id
+                    .draft_id
+                    .map_or(Value::Null, |draft_id| Value::Uuid(draft_id.into_uuid())),
+            ],
+            EntityQueryPath::EditionId => vec![Value::Uuid(
+                entity.metadata.record_id.edition_id.into_uuid(),
+            )],
+            EntityQueryPath::DecisionTime => vec![Value::interval(
+                entity.metadata.temporal_versioning.decision_time.convert(),
+            )],
+            EntityQueryPath::TransactionTime => vec![Value::interval(
+                entity
+                    .metadata
+                    .temporal_versioning
+                    .transaction_time
+                    .convert(),
+            )],
+            EntityQueryPath::TypeBaseUrls => entity
+                .metadata
+                .entity_type_ids
+                .iter()
+                .map(|entity_type_id| Value::Text(entity_type_id.base_url.to_string()))
+                .collect(),
+            EntityQueryPath::TypeVersions => entity
+                .metadata
+                .entity_type_ids
+                .iter()
+                .map(|entity_type_id| Value::Version {
+                    version: entity_type_id.version,
+                    latest: false,
+                })
+                .collect(),
+            EntityQueryPath::EntityConfidence => vec![
+                entity
+                    .metadata
+                    .confidence
+                    .map_or(Value::Null, |confidence| Value::Number(confidence.value())),
+            ],
+            EntityQueryPath::LeftEntityConfidence => vec![
+                entity
+                    .link_data
+                    .as_ref()
+                    .and_then(|link_data| link_data.left_entity_confidence)
+                    .map_or(Value::Null, |confidence| Value::Number(confidence.value())),
+            ],
+            EntityQueryPath::RightEntityConfidence => vec![
+                entity
+                    .link_data
+                    .as_ref()
+                    .and_then(|link_data| link_data.right_entity_confidence)
+                    .map_or(Value::Null, |confidence| Value::Number(confidence.value())),
+            ],
+            EntityQueryPath::LeftEntityProvenance => {
+                vec![entity.link_data.as_ref().map_or(Value::Null, |link_data| {
+                    Value::serialized(&link_data.left_entity_provenance)
+                })]
+            }
+            EntityQueryPath::RightEntityProvenance => {
+                vec![entity.link_data.as_ref().map_or(Value::Null, |link_data| {
+                    Value::serialized(&link_data.right_entity_provenance)
+                })]
+            }
+            EntityQueryPath::Archived => vec![Value::Bool(entity.metadata.archived)],
+            EntityQueryPath::Properties(path) => {
+                vec![Value::json_path(&entity.properties, path.as_ref())]
+            }
+            EntityQueryPath::Label { inheritance_depth } => context
+                .entity_type_depths(entity)
+                .into_iter()
+                .filter(|(_, depth)| within_depth(*depth, *inheritance_depth))
+                .filter_map(|(id, _)| context.entity_type_view(id))
+                .filter_map(|entity_type| entity_type.record.schema.label_property.as_ref())
+                .map(|label_property| {
+                    entity
+                        .properties
+                        .properties()
+                        .get(label_property)
+                        .map_or(Value::Null, Value::serialized)
+                })
+                .collect(),
+            EntityQueryPath::Provenance(path) => vec![Value::json_path(
+                &entity.metadata.provenance.inferred,
+                path.as_ref(),
+            )],
+            EntityQueryPath::EditionProvenance(path) => vec![Value::json_path(
+                &entity.metadata.provenance.edition,
+                path.as_ref(),
+            )],
+            EntityQueryPath::PropertyMetadata(path) => {
+                vec![Value::json_path(&entity.metadata.properties, path.as_ref())]
+            }
+            EntityQueryPath::Relevance(query) => {
+                let words = Value::serialized(&entity.properties).words();
+                #[expect(
+                    clippy::cast_precision_loss,
+                    reason = "The number of words will never exceed the precision of `f64`"
+                )]
+                let rank = FullTextQuery::parse(query).rank(&words) as f64;
+                vec![Value::Number(rank)]
+            }
+            EntityQueryPath::Embedding => context
+                .state
+                .entity_embeddings
+                .iter()
+                .filter(|embedding| embedding.entity_id == entity_id)
+                .map(|embedding| Value::Vector(embedding.embedding.iter().collect()))
+                .collect(),
+            EntityQueryPath::EntityTypeEdge {
+                edge_kind: SharedEdgeKind::IsOfType,
+                path,
+                inheritance_depth,
+            } => context
+                .entity_type_depths(entity)
+                .into_iter()
+                .filter(|(_, depth)| within_depth(*depth, *inheritance_depth))
+                .filter_map(|(id, _)| context.entity_type_view(id))
+                .flat_map(|entity_type| {
+                    EntityTypeWithMetadata::path_values(context, entity_type, path)
+                })
+                .collect(),
+            EntityQueryPath::EntityEdge {
+                edge_kind,
+                path,
+                direction,
+            } => {
+                let endpoint = |entity: &Entity| {
+                    entity.link_data.as_ref().map(|link_data| match edge_kind {
+                        KnowledgeGraphEdgeKind::HasLeftEntity => link_data.left_entity_id,
+                        KnowledgeGraphEdgeKind::HasRightEntity => link_data.right_entity_id,
+                    })
+                };
+
+                match direction {
+                    EdgeDirection::Outgoing => endpoint(entity)
+                        .into_iter()
+                        .flat_map(|target_id| context.entity_views_by_id(target_id))
+                        .flat_map(|target| Self::path_values(context, target, path))
+                        .collect(),
+                    EdgeDirection::Incoming => context
+                        .entity_views()
+                        .filter(|source| {
+                            endpoint(source).is_some_and(|target_id| {
+                                target_id.owned_by_id == entity_id.owned_by_id
+                                    && target_id.entity_uuid == entity_id.entity_uuid
+                            })
+                        })
+                        .flat_map(|source| Self::path_values(context, source, path))
+                        .collect(),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+
+    use hash_graph_store::subgraph::temporal_axes::QueryTemporalAxesUnresolved;
+    use serde_json::json;
+
+    use super::{FilterContext, FullTextQuery, Value, like};
+    use crate::store::state::MemoryState;
+
+    #[test]
+    fn compare_values() {
+        assert_eq!(
+            Value::Number(1.0).compare(&Value::Number(2.0)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            Value::Text("b".to_owned()).compare(&Value::Text("a".to_owned())),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(Value::Null.compare(&Value::Number(1.0)), None);
+        assert_eq!(
+            Value::Bool(true).compare(&Value::Text("true".to_owned())),
+            None
+        );
+    }
+
+    #[test]
+    fn json_values_are_flattened() {
+        assert!(Value::from_json(json!(null)).is_null());
+        assert!(matches!(
+            Value::from_json(json!(5)),
+            Value::Number(number) if (number - 5.0).abs() < f64::EPSILON
+        ));
+        assert!(matches!(
+            Value::from_json(json!({ "a": 1 })),
+            Value::Json(_)
+        ));
+    }
+
+    #[test]
+    fn cursor_round_trip() {
+        for value in [
+            Value::Bool(true),
+            Value::Number(1.5),
+            Value::Text("text".to_owned()),
+        ] {
+            let cursor = value.clone().into_cursor_field();
+            assert_eq!(Value::from(&cursor).compare(&value), Some(Ordering::Equal));
+        }
+        assert!(Value::from(&Value::Null.into_cursor_field()).is_null());
+    }
+
+    #[test]
+    fn like_patterns() {
+        assert!(like("hello world", "hello%", false).expect("pattern should be valid"));
+        assert!(like("hello world", "%o w%", false).expect("pattern should be valid"));
+        assert!(like("hello", "h_llo", false).expect("pattern should be valid"));
+        assert!(!like("hello", "h_lo", false).expect("pattern should be valid"));
+        assert!(!like("Hello", "hello", false).expect("pattern should be valid"));
+        assert!(like("Hello", "hello", true).expect("pattern should be valid"));
+        assert!(like("100%", "100\\%", false).expect("pattern should be valid"));
+        assert!(!like("1000", "100\\%", false).expect("pattern should be valid"));
+        assert!(like("", "%", false).expect("pattern should be valid"));
+        assert!(!like("", "_", false).expect("pattern should be valid"));
+        assert!(like("abc", "%%c", false).expect("pattern should be valid"));
+        assert!(like("abcbc", "%bc", false).expect("pattern should be valid"));
+        assert!(!like("abcb", "%bc", false).expect("pattern should be valid"));
+        assert!(like("a\\", "a\\\\", false).expect("pattern should be valid"));
+        like("a\\", "a\\", false).expect_err("trailing escape character should be rejected");
+    }
+
+    #[test]
+    fn like_with_many_wildcards() {
+        let text = "a".repeat(1_000);
+        assert!(
+            !like(&text, &format!("{}b", "%a".repeat(100)), false)
+                .expect("pattern should be valid")
+        );
+        assert!(like(&text, &"%a".repeat(100), false).expect("pattern should be valid"));
+    }
+
+    #[test]
+    fn regexes_are_compiled_once() {
+        let state = MemoryState::default();
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let context = FilterContext::new(&state, &temporal_axes, false);
+
+        let regex = context.regex("^a+$").expect("could not compile regex");
+        assert!(regex.is_match("aaa"));
+        context.regex("^a+$").expect("could not compile regex");
+        assert_eq!(
+            context
+                .regexes
+                .lock()
+                .expect("regexes should not be poisoned")
+                .len(),
+            1
+        );
+
+        context
+            .regex("(")
+            .expect_err("invalid regex should not compile");
+    }
+
+    #[test]
+    fn full_text_query() {
+        let words = Value::from_json(json!({
+            "name": "The quick brown fox",
+            "tags": ["Animal", "forest"],
+        }))
+        .words();
+
+        assert!(FullTextQuery::parse("quick fox").matches(&words));
+        assert!(FullTextQuery::parse("QUICK animal").matches(&words));
+        assert!(!FullTextQuery::parse("quick dog").matches(&words));
+        assert!(!FullTextQuery::parse("quick -fox").matches(&words));
+        assert!(!FullTextQuery::parse("").matches(&words));
+        assert_eq!(FullTextQuery::parse("fox forest").rank(&words), 2);
+    }
+}
@@ -0,0 +1,2135 @@
+use alloc::{borrow::Cow, collections::BTreeSet};
+use core::{
+    borrow::Borrow as _,
+    cmp::Ordering,
+    iter::{self, once},
+    mem,
+};
+use std::collections::{HashMap, HashSet};
+
+use error_stack::{FutureExt as _, Report, ResultExt as _, TryReportStreamExt as _, bail, ensure};
+use futures::{StreamExt as _, TryStreamExt as _, stream};
+use hash_graph_authorization::{
+    AuthorizationApi,
+    backend::ModifyRelationshipOperation,
+    schema::{
+        EntityOwnerSubject, EntityPermission, EntityRelationAndSubject, EntityTypePermission,
+        WebPermission,
+    },
+    zanzibar::Consistency,
+};
+use hash_graph_store::{
+    change_feed::GraphChangeKind,
+    entity::{
+        AggregateEntitiesParams, AggregateEntitiesResponse, ClosedMultiEntityTypeMap,
+        CountEntitiesParams, CreateEntityParams, EmptyEntityTypes, EntityAggregationFunction,
+        EntityAggregationGroup, EntityHistoryEntry, EntityQueryCursor, EntityQueryPath,
+        EntityQuerySorting, EntityQuerySortingRecord, EntityStore, EntityTypeRetrieval,
+        EntityTypesError, EntityValidationReport, EntityValidationType, GetEntitiesParams,
+        GetEntitiesResponse, GetEntityHistoryParams, GetEntityHistoryResponse,
+        GetEntitySubgraphParams, GetEntitySubgraphResponse, ImportEntitiesParams,
+        ImportEntitiesResponse, MergeEntitiesParams, MergeEntitiesResponse,
+        MigrateEntityTypeParams, MigrateEntityTypeResponse, PatchEntityParams, QueryConversion,
+        UpdateEntityEmbeddingsParams, ValidateEntityComponents, ValidateEntityParams,
+    },
+    entity_type::IncludeEntityTypeOption,
+    error::{
+        EntityEditionMismatch, EntityHistoryCursorNotFound, InsertionError, LinkedEntitiesMismatch,
+        QueryError, UpdateError,
+    },
+    filter::Filter,
+    query::{NullOrdering, Ordering as SortingOrdering},
+    subgraph::{
+        Subgraph, SubgraphRecord as _,
+        identifier::GraphElementVertexId,
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxes, QueryTemporalAxesUnresolved,
+            VariableTemporalAxisUnresolved,
+        },
+    },
+};
+use hash_graph_temporal_versioning::{
+    ClosedTemporalBound, DecisionTime, LeftClosedTemporalInterval, LimitedTemporalBound,
+    OpenTemporalBound, TemporalBound, TemporalTagged as _, Timestamp, TransactionTime,
+};
+use hash_graph_types::{
+    account::{AccountId, CreatedById, EditionArchivedById, EditionCreatedById},
+    knowledge::{
+        entity::{
+            DraftId, Entity, EntityEditionId, EntityEditionProvenance, EntityId, EntityMetadata,
+            EntityProvenance, EntityRecordId, EntityTemporalMetadata, EntityUuid,
+            InferredEntityProvenance,
+        },
+        property::{
+            Property, PropertyMetadata, PropertyPatchOperation, PropertyPath, PropertyPathError,
+            PropertyWithMetadata, PropertyWithMetadataObject, PropertyWithMetadataValue,
+            visitor::EntityVisitor as _,
+        },
+    },
+    ontology::{DataTypeLookup, OntologyTypeProvider},
+};
+use hash_graph_validation::{EntityPreprocessor, Validate as _};
+use hash_status::StatusCode;
+use serde_json::Value as JsonValue;
+use type_system::{
+    schema::{ClosedEntityType, ClosedMultiEntityType, DataTypeReference, EntityTypeUuid},
+    url::VersionedUrl,
+};
+use uuid::Uuid;
+
+use crate::store::{
+    EntityDoesNotExist, MemoryStore, ResponseCountMap,
+    filter::{FilterContext, FilterRecord as _, Value},
+    ontology::entity_type_resolve_definitions,
+    state::{EntityEmbeddingRecord, MemoryState, open_interval},
+    traversal::{TraversalItem, traverse_subgraph},
+    validation::StoreProvider,
+};
+
+#[derive(Debug)]
+#[expect(clippy::struct_excessive_bools, reason = "Parameter struct")]
+struct GetEntitiesImplParams<'a> {
+    filter: Filter<'a, Entity>,
+    sorting: EntityQuerySorting<'static>,
+    limit: Option<usize>,
+    include_drafts: bool,
+    include_count: bool,
+    include_entity_types: Option<IncludeEntityTypeOption>,
+    include_web_ids: bool,
+    include_created_by_ids: bool,
+    include_edition_created_by_ids: bool,
+    include_type_ids: bool,
+}
+
+/// Returns the values `entity` is sorted by, one for each path in `sorting`.
+///
+/// If a path resolves to more than one value, the first non-null value is used.
+fn sorting_values<'s>(
+    context: &FilterContext<'s>,
+    entity: &'s Entity,
+    sorting: &EntityQuerySorting<'_>,
+) -> Vec<Value> {
+    sorting
+        .paths
+        .iter()
+        .map(|record| first_value(context, entity, &record.path))
+        .collect()
+}
+
+/// Returns the first non-null value `path` resolves to for `entity`.
+fn first_value<'s>(
+    context: &FilterContext<'s>,
+    entity: &'s Entity,
+    path: &EntityQueryPath<'_>,
+) -> Value {
+    Entity::path_values(context, entity, path)
+        .into_iter()
+        .find(|value| !value.is_null())
+        .unwrap_or(Value::Null)
+}
+
+/// Returns the keys of the aggregation groups `entity` belongs to.
+///
+/// A path resolving to more than one value, e.g. the types of an entity, places the entity in one
+/// group per value. This mirrors the rows produced by the joins in Postgres.
+fn aggregation_keys<'s>(
+    context: &FilterContext<'s>,
+    entity: &'s Entity,
+    group_by: &[EntityQueryPath<'_>],
+) -> Vec<Vec<JsonValue>> {
+    group_by.iter().fold(vec![Vec::new()], |keys, path| {
+        let mut values = Entity::path_values(context, entity, path);
+        if values.is_empty() {
+            values.push(Value::Null);
+        }
+        let values = values.into_iter().map(Value::into_json).collect::<Vec<_>>();
+
+        keys.into_iter()
+            .flat_map(|key| {
+                values.iter().map(move |value| {
+                    let mut key = key.clone();
+                    key.push(value.clone());
+                    key
+                })
+            })
+            .collect()
+    })
+}
+
+/// Collects the values of a single aggregation within a group.
+#[derive(Debug, Default)]
+struct AggregationAccumulator {
+    count: usize,
+    numbers: Vec<f64>,
+}
+
+impl AggregationAccumulator {
+    fn push(&mut self, value: &Value) {
+        if value.is_null() {
+            return;
+        }
+        self.count += 1;
+        if let Some(number) = value.as_number() {
+            self.numbers.push(number);
+        }
+    }
+
+    #[expect(
+        clippy::float_arithmetic,
+        clippy::cast_precision_loss,
+        reason = "Aggregations are computed as `f64`, the same as in Postgres"
+    )]
+    fn finish(&self, function: EntityAggregationFunction) -> Option<f64> {
+        match function {
+            EntityAggregationFunction::Count => Some(self.count as f64),
+            EntityAggregationFunction::Sum => {
+                (!self.numbers.is_empty()).then(|| self.numbers.iter().sum())
+            }
+            EntityAggregationFunction::Avg => (!self.numbers.is_empty())
+                .then(|| self.numbers.iter().sum::<f64>() / self.numbers.len() as f64),
+            EntityAggregationFunction::Min => self.numbers.iter().copied().reduce(f64::min),
+            EntityAggregationFunction::Max => self.numbers.iter().copied().reduce(f64::max),
+        }
+    }
+}
+
+/// Compares two lists of values returned by [`sorting_values`].
+///
+/// Nulls are sorted last in ascending and first in descending order unless specified otherwise,
+/// which is the default in Postgres.
+fn compare_sorting_values(
+    paths: &[EntityQuerySortingRecord<'_>],
+    lhs: &[Value],
+    rhs: &[Value],
+) -> Ordering {
+    for ((record, lhs), rhs) in paths.iter().zip(lhs).zip(rhs) {
+        let nulls = record.nulls.unwrap_or(match record.ordering {
+            SortingOrdering::Ascending => NullOrdering::Last,
+            SortingOrdering::Descending => NullOrdering::First,
+        });
+        let null_ordering = match nulls {
+            NullOrdering::First => Ordering::Less,
+            NullOrdering::Last => Ordering::Greater,
+        };
+
+        let ordering = match (lhs.is_null(), rhs.is_null()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => null_ordering,
+            (false, true) => null_ordering.reverse(),
+            (false, false) => {
+                let ordering = lhs.compare(rhs).unwrap_or(Ordering::Equal);
+                match record.ordering {
+                    SortingOrdering::Ascending => ordering,
+                    SortingOrdering::Descending => ordering.reverse(),
+                }
+            }
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+async fn convert_entity_properties<P: DataTypeLookup + Sync>(
+    provider: &P,
+    entity: &mut PropertyWithMetadata,
+    path: &PropertyPath<'_>,
+    target_data_type_id: &VersionedUrl,
+) {
+    let Ok(PropertyWithMetadata::Value(PropertyWithMetadataValue { value, metadata })) =
+        entity.get_mut(path.as_ref())
+    else {
+        // If the property does not exist or is not a value, we can ignore it.
+        return;
+    };
+
+    let Some(source_data_type_id) = &mut metadata.data_type_id else {
+        // If the property does not have a data type, we can ignore it.
+        return;
+    };
+
+    let Ok(conversions) = provider
+        .find_conversion(
+            <&DataTypeReference>::from(&*source_data_type_id),
+            <&DataTypeReference>::from(target_data_type_id),
+        )
+        .await
+    else {
+        // If no conversion is found, we can ignore the property.
+        return;
+    };
+
+    let Some(mut value_number) = value.as_f64() else {
+        // If the value is not a number, we can ignore the property.
+        return;
+    };
+
+    for conversion in conversions.borrow() {
+        value_number = conversion.evaluate(value_number);
+    }
+    drop(conversions);
+
+    *value = JsonValue::from(value_number);
+
+    metadata.data_type_id = Some(target_data_type_id.clone());
+}
+
+async fn convert_entity<P: DataTypeLookup + Sync>(
+    provider: &P,
+    entity: &mut Entity,
+    conversions: &[QueryConversion<'_>],
+) -> Result<(), Report<PropertyPathError>> {
+    let mut property = PropertyWithMetadata::Object(PropertyWithMetadataObject::from_parts(
+        mem::take(&mut entity.properties),
+        Some(mem::take(&mut entity.metadata.properties)),
+    )?);
+    for conversion in conversions {
+        convert_entity_properties(
+            provider,
+            &mut property,
+            &conversion.path,
+            &conversion.data_type_id,
+        )
+        .await;
+    }
+    let PropertyWithMetadata::Object(property) = property else {
+        unreachable!("The property was just converted to an object");
+    };
+    let (properties, metadata) = property.into_parts();
+    entity.properties = properties;
+    entity.metadata.properties = metadata;
+    Ok(())
+}
+
+fn resolve_closed_multi_entity_types<'e>(
+    state: &MemoryState,
+    entities: impl IntoIterator<Item = &'e Entity>,
+) -> Result<HashMap<VersionedUrl, ClosedMultiEntityTypeMap>, Report<QueryError>> {
+    let mut closed_types = HashMap::<&VersionedUrl, ClosedEntityType>::new();
+    let all_multi_entity_type_ids = entities
+        .into_iter()
+        .map(|entity| {
+            for entity_type_id in &entity.metadata.entity_type_ids {
+                if !closed_types.contains_key(entity_type_id) {
+                    let record = state
+                        .entity_types
+                        .get(entity_type_id)
+                        .ok_or(QueryError)
+                        .attach_printable_lazy(|| entity_type_id.clone())?;
+                    closed_types.insert(
+                        entity_type_id,
+                        ClosedEntityType::clone(&record.closure.closed_schema),
+                    );
+                }
+            }
+            Ok(entity
+                .metadata
+                .entity_type_ids
+                .iter()
+                .collect::<BTreeSet<_>>())
+        })
+        .collect::<Result<Vec<_>, Report<QueryError>>>()?;
+
+    let mut resolved_entity_types = HashMap::<VersionedUrl, ClosedMultiEntityTypeMap>::new();
+    for entity_multi_type_ids in &all_multi_entity_type_ids {
+        let mut entity_type_id_iter = entity_multi_type_ids.iter();
+        let Some(first_entity_type_id) = entity_type_id_iter.next() else {
+            continue;
+        };
+        let (_, ref mut map) = resolved_entity_types
+            .raw_entry_mut()
+            .from_key(*first_entity_type_id)
+            .or_insert_with(|| {
+                ((*first_entity_type_id).clone(), ClosedMultiEntityTypeMap {
+                    schema: ClosedMultiEntityType::from_closed_schema(
+                        closed_types
+                            .get(*first_entity_type_id)
+                            .expect(
+                                "The entity type was already resolved, so it should be present in \
+                                 the closed types",
+                            )
+                            .clone(),
+                    ),
+                    inner: HashMap::new(),
+                })
+            });
+
+        for entity_type_id in entity_type_id_iter {
+            let (_, new_map) = map
+                .inner
+                .raw_entry_mut()
+                .from_key(*entity_type_id)
+                .or_insert_with(|| {
+                    let mut closed_parent = map.schema.clone();
+                    closed_parent
+                        .add_closed_entity_type(
+                            closed_types
+                                .get(*entity_type_id)
+                                .expect(
+                                    "The entity type was already resolved, so it should be \
+                                     present in the closed types",
+                                )
+                                .clone(),
+                        )
+                        .expect("The entity type was constructed before so it has to be valid");
+                    ((*entity_type_id).clone(), ClosedMultiEntityTypeMap {
+                        schema: closed_parent,
+                        inner: HashMap::new(),
+                    })
+                });
+            *map = new_map;
+        }
+    }
+
+    Ok(resolved_entity_types)
+}
+
+/// Returns the position of the edition of `entity_id` which is current at the provided times.
+///
+/// Only editions with the same draft ID as `entity_id` are considered.
+fn current_edition_position(
+    editions: &[Entity],
+    entity_id: EntityId,
+    transaction_time: Timestamp<TransactionTime>,
+    decision_time: Timestamp<DecisionTime>,
+) -> Option<usize> {
+    editions.iter().position(|entity| {
+        let temporal_versioning = &entity.metadata.temporal_versioning;
+        entity.metadata.record_id.entity_id == entity_id
+            && temporal_versioning
+                .transaction_time
+                .contains_point(&transaction_time)
+            && temporal_versioning
+                .decision_time
+                .contains_point(&decision_time)
+    })
+}
+
+/// Splits the edition at `position` at the provided times.
+///
+/// The edition is closed in transaction time. Its decision time up to `decision_time` is
+/// re-inserted as current, so the history before `decision_time` is kept. The returned temporal
+/// metadata covers the remaining decision time, starting at `decision_time`.
+fn split_edition(
+    editions: &mut Vec<Entity>,
+    position: usize,
+    transaction_time: Timestamp<TransactionTime>,
+    decision_time: Timestamp<DecisionTime>,
+) -> (Entity, EntityTemporalMetadata) {
+    let mut previous = editions.swap_remove(position);
+    let previous_versioning = previous.metadata.temporal_versioning.clone();
+    let ClosedTemporalBound::Inclusive(previous_transaction_time) =
+        *previous_versioning.transaction_time.start();
+    let ClosedTemporalBound::Inclusive(previous_decision_time) =
+        *previous_versioning.decision_time.start();
+
+    if previous_decision_time < decision_time {
+        let mut retained = previous.clone();
+        retained.metadata.temporal_versioning = EntityTemporalMetadata {
+            decision_time: LeftClosedTemporalInterval::new(
+                ClosedTemporalBound::Inclusive(previous_decision_time),
+                OpenTemporalBound::Exclusive(decision_time),
+            ),
+            transaction_time: open_interval(transaction_time),
+        };
+        editions.push(retained);
+    }
+
+    let remaining = EntityTemporalMetadata {
+        decision_time: LeftClosedTemporalInterval::new(
+            ClosedTemporalBound::Inclusive(decision_time),
+            *previous_versioning.decision_time.end(),
+        ),
+        transaction_time: open_interval(transaction_time),
+    };
+
+    if previous_transaction_time < transaction_time {
+        previous.metadata.temporal_versioning.transaction_time = LeftClosedTemporalInterval::new(
+            ClosedTemporalBound::Inclusive(previous_transaction_time),
+            OpenTemporalBound::Exclusive(transaction_time),
+        );
+        editions.push(previous.clone());
+    }
+
+    (previous, remaining)
+}
+
+/// Returns the time the edition was created, which is the start of its earliest row.
+fn edition_start(entity: &Entity) -> (Timestamp<TransactionTime>, Timestamp<DecisionTime>) {
+    let ClosedTemporalBound::Inclusive(transaction_time) =
+        *entity.metadata.temporal_versioning.transaction_time.start();
+    let ClosedTemporalBound::Inclusive(decision_time) =
+        *entity.metadata.temporal_versioning.decision_time.start();
+    (transaction_time, decision_time)
+}
+
+/// Returns the edition the edition at `index` of the ordered `editions` is based on.
+///
+/// This is the previous edition of the same draft or, for the first edition of a draft, the live
+/// edition the draft was created from.
+fn previous_edition<'e>(editions: &[&'e Entity], index: usize) -> Option<&'e Entity> {
+    let draft_id = editions[index].metadata.record_id.entity_id.draft_id;
+    let mut earlier_editions = editions[..index].iter().rev().copied();
+
+    earlier_editions
+        .clone()
+        .find(|edition| edition.metadata.record_id.entity_id.draft_id == draft_id)
+        .or_else(|| {
+            draft_id?;
+            earlier_editions.find(|edition| edition.metadata.record_id.entity_id.draft_id.is_none())
+        })
+}
+
+impl<A> MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    /// Removes all entities and their embeddings from the store.
+    pub async fn delete_entities(&mut self) {
+        tracing::debug!("Deleting all entities");
+        let mut state = self.state.write().await;
+        state.entities.clear();
+        state.entity_embeddings.clear();
+    }
+
+    #[tracing::instrument(level = "info", skip(self, state, params))]
+    async fn get_entities_impl(
+        &self,
+        state: &MemoryState,
+        actor_id: AccountId,
+        params: GetEntitiesImplParams<'_>,
+        temporal_axes: &QueryTemporalAxes,
+    ) -> Result<GetEntitiesResponse<'static>, Report<QueryError>> {
+        let context = FilterContext::new(state, temporal_axes, params.include_drafts);
+
+        let entities = context
+            .filter_views(&params.filter, context.entity_views())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut web_ids = params.include_web_ids.then(ResponseCountMap::default);
+        let mut created_by_ids = params
+            .include_created_by_ids
+            .then(ResponseCountMap::default);
+        let mut edition_created_by_ids = params
+            .include_edition_created_by_ids
+            .then(ResponseCountMap::default);
+        let mut type_ids = params.include_type_ids.then(ResponseCountMap::default);
+        for entity in &entities {
+            if let Some(web_ids) = &mut web_ids {
+                web_ids.increment(&entity.metadata.record_id.entity_id.owned_by_id);
+            }
+            if let Some(created_by_ids) = &mut created_by_ids {
+                created_by_ids.increment(&entity.metadata.provenance.inferred.created_by_id);
+            }
+            if let Some(edition_created_by_ids) = &mut edition_created_by_ids {
+                edition_created_by_ids.increment(&entity.metadata.provenance.edition.created_by_id);
+            }
+            if let Some(type_ids) = &mut type_ids {
+                for entity_type_id in &entity.metadata.entity_type_ids {
+                    type_ids.increment(entity_type_id);
+                }
+            }
+        }
+
+        let (permissions, _) = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                entities
+                    .iter()
+                    .map(|entity| entity.metadata.record_id.entity_id)
+                    .collect::<Vec<_>>(),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let mut entities = entities
+            .into_iter()
+            .filter(|entity| {
+                permissions
+                    .get(&entity.metadata.record_id.entity_id.entity_uuid)
+                    .copied()
+                    .unwrap_or(false)
+            })
+            .map(|entity| (sorting_values(&context, entity, &params.sorting), entity))
+            .collect::<Vec<_>>();
+        let count = params.include_count.then_some(entities.len());
+
+        entities
+            .sort_by(|(lhs, _), (rhs, _)| compare_sorting_values(&params.sorting.paths, lhs, rhs));
+        if let Some(cursor) = &params.sorting.cursor {
+            let cursor = cursor.values.iter().map(Value::from).collect::<Vec<_>>();
+            entities.retain(|(values, _)| {
+                compare_sorting_values(&params.sorting.paths, values, &cursor) == Ordering::Greater
+            });
+        }
+
+        let cursor = match params.limit {
+            Some(limit) if entities.len() >= limit => {
+                entities.truncate(limit);
+                entities.last().map(|(values, _)| EntityQueryCursor {
+                    values: values
+                        .iter()
+                        .cloned()
+                        .map(Value::into_cursor_field)
+                        .collect(),
+                })
+            }
+            _ => None,
+        };
+        let entities = entities
+            .into_iter()
+            .map(|(_, entity)| entity.clone())
+            .collect::<Vec<_>>();
+
+        Ok(GetEntitiesResponse {
+            closed_multi_entity_types: params
+                .include_entity_types
+                .map(|_| resolve_closed_multi_entity_types(state, &entities))
+                .transpose()?,
+            definitions: match params.include_entity_types {
+                Some(
+                    IncludeEntityTypeOption::Resolved
+                    | IncludeEntityTypeOption::ResolvedWithDataTypeChildren,
+                ) => {
+                    let entity_type_uuids = entities
+                        .iter()
+                        .flat_map(|entity| {
+                            entity
+                                .metadata
+                                .entity_type_ids
+                                .iter()
+                                .map(EntityTypeUuid::from_url)
+                        })
+                        .collect::<HashSet<_>>()
+                        .into_iter()
+                        .collect::<Vec<_>>();
+                    Some(
+                        entity_type_resolve_definitions(
+                            state,
+                            &self.authorization_api,
+                            actor_id,
+                            &entity_type_uuids,
+                            params.include_entity_types
+                                == Some(IncludeEntityTypeOption::ResolvedWithDataTypeChildren),
+                        )
+                        .await?,
+                    )
+                }
+                None | Some(IncludeEntityTypeOption::Closed) => None,
+            },
+            entities,
+            cursor,
+            count,
+            web_ids: web_ids.map(HashMap::from),
+            created_by_ids: created_by_ids.map(HashMap::from),
+            edition_created_by_ids: edition_created_by_ids.map(HashMap::from),
+            type_ids: type_ids.map(HashMap::from),
+        })
+    }
+}
+
+impl<A> EntityStore for MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn create_entities<R>(
+        &mut self,
+        actor_id: AccountId,
+        params: Vec<CreateEntityParams<R>>,
+    ) -> Result<Vec<Entity>, Report<InsertionError>>
+    where
+        R: IntoIterator<Item = EntityRelationAndSubject> + Send,
+    {
+        let transaction_time = Timestamp::<TransactionTime>::now().remove_nanosecond();
+        let mut relationships = Vec::with_capacity(params.len());
+        let mut entity_type_ids = HashMap::new();
+        let mut checked_web_ids = HashSet::new();
+        let mut entity_uuids = HashSet::new();
+
+        let mut entities = Vec::with_capacity(params.len());
+        let mut validation_params = Vec::with_capacity(params.len());
+
+        let mut state = self.state.write().await;
+        let validator_provider = StoreProvider {
+            state: &state,
+            authorization_api: &self.authorization_api,
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+
+        let mut validation_reports = HashMap::<usize, EntityValidationReport>::new();
+        for (index, mut params) in params.into_iter().enumerate() {
+            let entity_type = ClosedMultiEntityType::from_multi_type_closed_schema(
+                stream::iter(&params.entity_type_ids)
+                    .then(|entity_type_url| async {
+                        OntologyTypeProvider::<ClosedEntityType>::provide_type(
+                            &validator_provider,
+                            entity_type_url,
+                        )
+                        .await
+                        .map(|entity_type| (*entity_type).clone())
+                    })
+                    .try_collect::<Vec<ClosedEntityType>>()
+                    .await
+                    .change_context(InsertionError)?,
+            )
+            .change_context(InsertionError)?;
+
+            let mut preprocessor = EntityPreprocessor {
+                components: if params.draft {
+                    ValidateEntityComponents::draft()
+                } else {
+                    ValidateEntityComponents::full()
+                },
+            };
+            preprocessor.components.link_validation = self.settings.validate_links;
+
+            if let Err(property_validation) = preprocessor
+                .visit_object(&entity_type, &mut params.properties, &validator_provider)
+                .await
+            {
+                validation_reports.entry(index).or_default().properties =
+                    property_validation.properties;
+            }
+
+            let (properties, property_metadata) = params.properties.into_parts();
+
+            let decision_time = params
+                .decision_time
+                .map_or_else(|| transaction_time.cast(), Timestamp::remove_nanosecond);
+            let entity_id = EntityId {
+                owned_by_id: params.owned_by_id,
+                entity_uuid: params
+                    .entity_uuid
+                    .unwrap_or_else(|| EntityUuid::new(Uuid::new_v4())),
+                draft_id: params.draft.then(|| DraftId::new(Uuid::new_v4())),
+            };
+
+            if state.entities.contains_key(&entity_id.entity_uuid)
+                || !entity_uuids.insert(entity_id.entity_uuid)
+            {
+                bail!(
+                    Report::new(InsertionError)
+                        .attach(StatusCode::AlreadyExists)
+                        .attach_printable("The entity already exists")
+                        .attach_printable(entity_id.entity_uuid)
+                );
+            }
+
+            if entity_id.entity_uuid.as_uuid() != entity_id.owned_by_id.as_uuid() {
+                checked_web_ids.insert(entity_id.owned_by_id);
+            }
+
+            let entity_provenance = EntityProvenance {
+                inferred: InferredEntityProvenance {
+                    created_by_id: CreatedById::new(actor_id),
+                    created_at_transaction_time: transaction_time,
+                    created_at_decision_time: decision_time,
+                    first_non_draft_created_at_transaction_time: entity_id
+                        .draft_id
+                        .is_none()
+                        .then_some(transaction_time),
+                    first_non_draft_created_at_decision_time: entity_id
+                        .draft_id
+                        .is_none()
+                        .then_some(decision_time),
+                },
+                edition: EntityEditionProvenance {
+                    created_by_id: EditionCreatedById::new(actor_id),
+                    archived_by_id: None,
+                    provided: params.provenance,
+                },
+            };
+
+            for entity_type in &entity_type.all_of {
+                entity_type_ids.insert(
+                    EntityTypeUuid::from_url(&entity_type.id),
+                    entity_type.id.clone(),
+                );
+            }
+
+            entities.push(Entity {
+                properties,
+                link_data: params.link_data,
+                metadata: EntityMetadata {
+                    record_id: EntityRecordId {
+                        entity_id,
+                        edition_id: EntityEditionId::new(Uuid::new_v4()),
+                    },
+                    temporal_versioning: EntityTemporalMetadata {
+                        decision_time: open_interval(decision_time),
+                        transaction_time: open_interval(transaction_time),
+                    },
+                    entity_type_ids: params.entity_type_ids,
+                    archived: false,
+                    provenance: entity_provenance,
+                    confidence: params.confidence,
+                    properties: property_metadata,
+                },
+            });
+
+            validation_params.push((entity_type, preprocessor.components));
+
+            relationships.extend(
+                params
+                    .relationships
+                    .into_iter()
+                    .chain(once(EntityRelationAndSubject::Owner {
+                        subject: EntityOwnerSubject::Web {
+                            id: params.owned_by_id,
+                        },
+                        level: 0,
+                    }))
+                    .map(|relation_and_subject| (entity_id, relation_and_subject)),
+            );
+        }
+
+        let (instantiate_permissions, zookie) = self
+            .authorization_api
+            .check_entity_types_permission(
+                actor_id,
+                EntityTypePermission::Instantiate,
+                entity_type_ids.keys().copied(),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(InsertionError)?;
+        let forbidden_instantiations = instantiate_permissions
+            .iter()
+            .filter_map(|(entity_type_id, permission)| {
+                if *permission {
+                    None
+                } else {
+                    entity_type_ids.get(entity_type_id)
+                }
+            })
+            .collect::<Vec<_>>();
+        if !forbidden_instantiations.is_empty() {
+            return Err(Report::new(InsertionError)
+                .attach(StatusCode::PermissionDenied)
+                .attach_printable(
+                    "The actor does not have permission to instantiate one or more entity types",
+                )
+                .attach_printable(
+                    forbidden_instantiations
+                        .into_iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ));
+        }
+
+        if !checked_web_ids.is_empty() {
+            let (create_entity_permissions, _zookie) = self
+                .authorization_api
+                .check_webs_permission(
+                    actor_id,
+                    WebPermission::CreateEntity,
+                    checked_web_ids,
+                    Consistency::AtLeastAsFresh(&zookie),
+                )
+                .await
+                .change_context(InsertionError)?;
+            let forbidden_webs = create_entity_permissions
+                .iter()
+                .filter_map(
+                    |(web_id, permission)| {
+                        if *permission { None } else { Some(web_id) }
+                    },
+                )
+                .collect::<Vec<_>>();
+            if !forbidden_webs.is_empty() {
+                return Err(Report::new(InsertionError)
+                    .attach(StatusCode::PermissionDenied)
+                    .attach_printable(
+                        "The actor does not have permission to create entities for one or more \
+                         web ids",
+                    )
+                    .attach_printable(
+                        forbidden_webs
+                            .into_iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ));
+            }
+        }
+
+        // The entities are inserted before the link validation, so links between the created
+        // entities can be resolved. They are removed again if the validation fails.
+        for entity in &entities {
+            state
+                .entities
+                .insert(entity.metadata.record_id.entity_id.entity_uuid, vec![
+                    entity.clone(),
+                ]);
+        }
+
+        let validator_provider = StoreProvider {
+            state: &state,
+            authorization_api: &self.authorization_api,
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+        for (index, (entity, (schema, components))) in
+            entities.iter().zip(validation_params).enumerate()
+        {
+            let validation_report = entity
+                .validate(&schema, components, &validator_provider)
+                .await;
+            if !validation_report.is_valid() {
+                let report = validation_reports.entry(index).or_default();
+                report.link = validation_report.link;
+                report.metadata.properties = validation_report.property_metadata;
+            }
+        }
+
+        let modify_result = if validation_reports.is_empty() {
+            self.authorization_api
+                .modify_entity_relations(relationships.into_iter().map(
+                    |(entity_id, relation_and_subject)| {
+                        (
+                            ModifyRelationshipOperation::Create,
+                            entity_id,
+                            relation_and_subject,
+                        )
+                    },
+                ))
+                .await
+                .change_context(InsertionError)
+        } else {
+            Err(Report::new(InsertionError).attach(validation_reports))
+        };
+        if let Err(error) = modify_result {
+            for entity_uuid in entity_uuids {
+                state.entities.remove(&entity_uuid);
+            }
+            return Err(error);
+        }
+        state.publish_changes(entities.iter().flat_map(|entity| {
+            once(GraphChangeKind::EntityCreated {
+                entity_id: entity.metadata.record_id.entity_id,
+                edition_id: entity.metadata.record_id.edition_id,
+            })
+            .chain(GraphChangeKind::relationship_modified(entity))
+        }));
+        drop(state);
+
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_entity_embeddings_workflow(actor_id, &entities)
+                .await
+                .change_context(InsertionError)?;
+        }
+
+        Ok(entities)
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn validate_entities(
+        &self,
+        actor_id: AccountId,
+        _: Consistency<'_>,
+        params: Vec<ValidateEntityParams<'_>>,
+    ) -> HashMap<usize, EntityValidationReport> {
+        let mut validation_reports = HashMap::<usize, EntityValidationReport>::new();
+
+        let state = self.state.read().await;
+        let validator_provider = StoreProvider {
+            state: &state,
+            authorization_api: &self.authorization_api,
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+
+        for (index, mut params) in params.into_iter().enumerate() {
+            let mut validation_report = EntityValidationReport::default();
+
+            let schema = match params.entity_types {
+                EntityValidationType::ClosedSchema(schema) => schema,
+                EntityValidationType::Id(entity_type_urls) => {
+                    let entity_type = stream::iter(entity_type_urls.as_ref())
+                        .then(|entity_type_url| {
+                            OntologyTypeProvider::<ClosedEntityType>::provide_type(
+                                &validator_provider,
+                                entity_type_url,
+                            )
+                            .change_context_lazy(|| {
+                                EntityTypeRetrieval {
+                                    entity_type_url: entity_type_url.clone(),
+                                }
+                            })
+                        })
+                        .map_ok(|entity_type| (*entity_type).clone())
+                        .try_collect_reports::<Vec<ClosedEntityType>>()
+                        .await
+                        .map_err(EntityTypesError::EntityTypeRetrieval)
+                        .and_then(|entity_types| {
+                            ClosedMultiEntityType::from_multi_type_closed_schema(entity_types)
+                                .map_err(EntityTypesError::ResolveClosedEntityType)
+                        });
+                    match entity_type {
+                        Ok(entity_type) => Cow::Owned(entity_type),
+                        Err(error) => {
+                            validation_report.metadata.entity_types = Some(error);
+                            validation_reports.insert(index, validation_report);
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            if schema.all_of.is_empty() {
+                validation_report.metadata.entity_types =
+                    Some(EntityTypesError::Empty(Report::new(EmptyEntityTypes)));
+            }
+
+            let mut preprocessor = EntityPreprocessor {
+                components: params.components,
+            };
+
+            if let Err(property_validation) = preprocessor
+                .visit_object(
+                    schema.as_ref(),
+                    params.properties.to_mut(),
+                    &validator_provider,
+                )
+                .await
+            {
+                validation_report.properties = property_validation.properties;
+            }
+
+            validation_report.link = params
+                .link_data
+                .as_deref()
+                .validate(&schema, params.components, &validator_provider)
+                .await;
+
+            if !validation_report.is_valid() {
+                validation_reports.insert(index, validation_report);
+            }
+        }
+
+        validation_reports
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn import_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: ImportEntitiesParams,
+    ) -> Result<ImportEntitiesResponse, Report<InsertionError>> {
+        let rows = {
+            let state = self.state.read().await;
+            params
+                .read_rows(&StoreProvider {
+                    state: &state,
+                    authorization_api: &self.authorization_api,
+                    authorization: Some((actor_id, Consistency::FullyConsistent)),
+                })
+                .await?
+        };
+
+        params.import_rows(self, actor_id, rows).await
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn get_entities(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntitiesParams<'_>,
+    ) -> Result<GetEntitiesResponse<'static>, Report<QueryError>> {
+        let state = self.state.read().await;
+        let provider = StoreProvider {
+            state: &state,
+            authorization_api: &self.authorization_api,
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+        params
+            .filter
+            .convert_parameters(&provider)
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.resolve();
+
+        let mut response = self
+            .get_entities_impl(
+                &state,
+                actor_id,
+                GetEntitiesImplParams {
+                    filter: params.filter,
+                    sorting: params.sorting,
+                    limit: params.limit,
+                    include_drafts: params.include_drafts,
+                    include_count: params.include_count,
+                    include_entity_types: params.include_entity_types,
+                    include_web_ids: params.include_web_ids,
+                    include_created_by_ids: params.include_created_by_ids,
+                    include_edition_created_by_ids: params.include_edition_created_by_ids,
+                    include_type_ids: params.include_type_ids,
+                },
+                &temporal_axes,
+            )
+            .await?;
+
+        for entity in &mut response.entities {
+            convert_entity(&provider, entity, &params.conversions)
+                .await
+                .change_context(QueryError)?;
+        }
+
+        Ok(response)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn get_entity_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntitySubgraphParams<'_>,
+    ) -> Result<GetEntitySubgraphResponse<'static>, Report<QueryError>> {
+        let state = self.state.read().await;
+        let provider = StoreProvider {
+            state: &state,
+            authorization_api: &self.authorization_api,
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+        params
+            .filter
+            .convert_parameters(&provider)
+            .await
+            .change_context(QueryError)?;
+
+        let unresolved_temporal_axes = params.temporal_axes;
+        let temporal_axes = unresolved_temporal_axes.clone().resolve();
+        let time_axis = temporal_axes.variable_time_axis();
+
+        let GetEntitiesResponse {
+            entities: root_entities,
+            cursor,
+            count,
+            closed_multi_entity_types: _,
+            definitions: _,
+            web_ids,
+            created_by_ids,
+            edition_created_by_ids,
+            type_ids,
+        } = self
+            .get_entities_impl(
+                &state,
+                actor_id,
+                GetEntitiesImplParams {
+                    filter: params.filter,
+                    sorting: params.sorting,
+                    limit: params.limit,
+                    include_drafts: params.include_drafts,
+                    include_count: params.include_count,
+                    include_entity_types: None,
+                    include_web_ids: params.include_web_ids,
+                    include_created_by_ids: params.include_created_by_ids,
+                    include_edition_created_by_ids: params.include_edition_created_by_ids,
+                    include_type_ids: params.include_type_ids,
+                },
+                &temporal_axes,
+            )
+            .await?;
+
+        let mut subgraph = Subgraph::new(
+            params.graph_resolve_depths,
+            unresolved_temporal_axes,
+            temporal_axes.clone(),
+        );
+
+        let traversal_queue = root_entities
+            .iter()
+            .map(|entity| {
+                (
+                    TraversalItem::Entity(entity.vertex_id(time_axis)),
+                    subgraph.depths,
+                    subgraph.temporal_axes.resolved.variable_interval(),
+                )
+            })
+            .collect();
+        subgraph.roots.extend(
+            root_entities
+                .iter()
+                .map(|entity| GraphElementVertexId::from(entity.vertex_id(time_axis))),
+        );
+        for entity in root_entities {
+            subgraph.insert_vertex(entity.vertex_id(time_axis), entity);
+        }
+
+        traverse_subgraph(
+            &FilterContext::new(&state, &temporal_axes, params.include_drafts),
+            &self.authorization_api,
+            actor_id,
+            traversal_queue,
+            &mut subgraph,
+        )
+        .await?;
+
+        for entity in subgraph.vertices.entities.values_mut() {
+            convert_entity(&provider, entity, &params.conversions)
+                .await
+                .change_context(QueryError)?;
+        }
+
+        Ok(GetEntitySubgraphResponse {
+            closed_multi_entity_types: params
+                .include_entity_types
+                .map(|_| {
+                    resolve_closed_multi_entity_types(&state, subgraph.vertices.entities.values())
+                })
+                .transpose()?,
+            definitions: match params.include_entity_types {
+                Some(
+                    IncludeEntityTypeOption::Resolved
+                    | IncludeEntityTypeOption::ResolvedWithDataTypeChildren,
+                ) => {
+                    let entity_type_uuids = subgraph
+                        .vertices
+                        .entities
+                        .values()
+                        .flat_map(|entity| {
+                            entity
+                                .metadata
+                                .entity_type_ids
+                                .iter()
+                                .map(EntityTypeUuid::from_url)
+                        })
+                        .collect::<HashSet<_>>()
+                        .into_iter()
+                        .collect::<Vec<_>>();
+                    Some(
+                        entity_type_resolve_definitions(
+                            &state,
+                            &self.authorization_api,
+                            actor_id,
+                            &entity_type_uuids,
+                            params.include_entity_types
+                                == Some(IncludeEntityTypeOption::ResolvedWithDataTypeChildren),
+                        )
+                        .await?,
+                    )
+                }
+                None | Some(IncludeEntityTypeOption::Closed) => None,
+            },
+            subgraph,
+            cursor,
+            count,
+            web_ids,
+            created_by_ids,
+            edition_created_by_ids,
+            type_ids,
+        })
+    }
+
+    async fn count_entities(
+        &self,
+        actor_id: AccountId,
+        mut params: CountEntitiesParams<'_>,
+    ) -> Result<usize, Report<QueryError>> {
+        let state = self.state.read().await;
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                state: &state,
+                authorization_api: &self.authorization_api,
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.resolve();
+        let context = FilterContext::new(&state, &temporal_axes, params.include_drafts);
+        let entity_ids = context
+            .filter_views(&params.filter, context.entity_views())
+            .map(|entity| entity.map(|entity| entity.metadata.record_id.entity_id))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let permitted_ids = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                entity_ids.iter().copied(),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .0
+            .into_iter()
+            .filter_map(|(entity_id, has_permission)| has_permission.then_some(entity_id))
+            .collect::<HashSet<_>>();
+
+        Ok(entity_ids
+            .into_iter()
+            .filter(|id| permitted_ids.contains(&id.entity_uuid))
+            .count())
+    }
+
+    async fn aggregate_entities(
+        &self,
+        actor_id: AccountId,
+        mut params: AggregateEntitiesParams<'_>,
+    ) -> Result<AggregateEntitiesResponse, Report<QueryError>> {
+        let state = self.state.read().await;
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                state: &state,
+                authorization_api: &self.authorization_api,
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.resolve();
+        let context = FilterContext::new(&state, &temporal_axes, params.include_drafts);
+        let entities = context
+            .filter_views(&params.filter, context.entity_views())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let permitted_ids = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                entities
+                    .iter()
+                    .map(|entity| entity.metadata.record_id.entity_id),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .0
+            .into_iter()
+            .filter_map(|(entity_id, has_permission)| has_permission.then_some(entity_id))
+            .collect::<HashSet<_>>();
+
+        let new_accumulators = || {
+            iter::repeat_with(AggregationAccumulator::default)
+                .take(params.aggregations.len())
+                .collect::<Vec<_>>()
+        };
+        let mut groups = Vec::<(Vec<JsonValue>, usize, Vec<AggregationAccumulator>)>::new();
+        if params.group_by.is_empty() {
+            // Without grouping, a single group is returned even if no entity matches.
+            groups.push((Vec::new(), 0, new_accumulators()));
+        }
+
+        for entity in entities.into_iter().filter(|entity| {
+            permitted_ids.contains(&entity.metadata.record_id.entity_id.entity_uuid)
+        }) {
+            for keys in aggregation_keys(&context, entity, &params.group_by) {
+                let position = groups
+                    .iter()
+                    .position(|(group_keys, ..)| *group_keys == keys)
+                    .unwrap_or_else(|| {
+                        groups.push((keys, 0, new_accumulators()));
+                        groups.len() - 1
+                    });
+                let (_, count, accumulators) = &mut groups[position];
+                *count += 1;
+                for (accumulator, aggregation) in accumulators.iter_mut().zip(&params.aggregations)
+                {
+                    accumulator.push(&first_value(&context, entity, &aggregation.path));
+                }
+            }
+        }
+
+        Ok(AggregateEntitiesResponse {
+            groups: groups
+                .into_iter()
+                .map(|(keys, count, accumulators)| EntityAggregationGroup {
+                    keys,
+                    count,
+                    values: accumulators
+                        .iter()
+                        .zip(&params.aggregations)
+                        .map(|(accumulator, aggregation)| accumulator.finish(aggregation.function))
+                        .collect(),
+                })
+                .collect(),
+        })
+    }
+
+    async fn get_entity_by_id(
+        &self,
+        actor_id: AccountId,
+        entity_id: EntityId,
+        transaction_time: Option<Timestamp<TransactionTime>>,
+        decision_time: Option<Timestamp<DecisionTime>>,
+    ) -> Result<Entity, Report<QueryError>> {
+        self.authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::View,
+                entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .assert_permission()
+            .change_context(QueryError)?;
+
+        let temporal_axes = QueryTemporalAxesUnresolved::TransactionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(decision_time),
+            variable: VariableTemporalAxisUnresolved::new(
+                transaction_time.map(TemporalBound::Inclusive),
+                transaction_time.map(LimitedTemporalBound::Inclusive),
+            ),
+        }
+        .resolve();
+
+        let state = self.state.read().await;
+        let context = FilterContext::new(&state, &temporal_axes, entity_id.draft_id.is_some());
+        let filter = Filter::for_entity_by_entity_id(entity_id);
+        let mut entities = context.filter_views(&filter, context.entity_views());
+
+        match (entities.next().transpose()?, entities.next().transpose()?) {
+            (Some(entity), None) => Ok(entity.clone()),
+            (None, _) => Err(Report::new(EntityDoesNotExist)
+                .attach(StatusCode::NotFound)
+                .attach_printable(entity_id)
+                .change_context(QueryError)),
+            (Some(_), Some(_)) => Err(Report::new(QueryError)
+                .attach_printable("Expected exactly one entity")
+                .attach_printable(entity_id)),
+        }
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn patch_entity(
+        &mut self,
+        actor_id: AccountId,
+        mut params: PatchEntityParams,
+    ) -> Result<Entity, Report<UpdateError>> {
+        let transaction_time = Timestamp::now().remove_nanosecond();
+        let decision_time = params
+            .decision_time
+            .map_or_else(|| transaction_time.cast(), Timestamp::remove_nanosecond);
+        let entity_type_ids = params
+            .entity_type_ids
+            .iter()
+            .map(EntityTypeUuid::from_url)
+            .collect::<Vec<_>>();
+
+        if !self
+            .authorization_api
+            .check_entity_types_permission(
+                actor_id,
+                EntityTypePermission::Instantiate,
+                entity_type_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .0
+            .into_iter()
+            .all(|(_, permission)| permission)
+        {
+            bail!(Report::new(UpdateError).attach(StatusCode::PermissionDenied));
+        }
+
+        self.authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::Update,
+                params.entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)?;
+
+        let mut state = self.state.write().await;
+
+        let entity_does_not_exist = || {
+            Report::new(EntityDoesNotExist)
+                .attach(StatusCode::NotFound)
+                .attach_printable(params.entity_id)
+                .change_context(UpdateError)
+        };
+        let mut editions = state
+            .entities
+            .get(&params.entity_id.entity_uuid)
+            .cloned()
+            .ok_or_else(entity_does_not_exist)?;
+        let locked_position =
+            current_edition_position(&editions, params.entity_id, transaction_time, decision_time)
+                .ok_or_else(entity_does_not_exist)?;
+        let previous_entity = editions[locked_position].clone();
+
+        if let Some(expected_edition_id) = params.expected_edition_id {
+            let edition_id = previous_entity.metadata.record_id.edition_id;
+            if edition_id != expected_edition_id {
+                bail!(
+                    Report::new(EntityEditionMismatch {
+                        expected: expected_edition_id,
+                        actual: edition_id,
+                    })
+                    .attach(StatusCode::Aborted)
+                    .change_context(UpdateError)
+                );
+            }
+        }
+
+        let mut link_data = previous_entity.link_data.clone();
+        if let Some(patched_link_data) = params.link_data {
+            ensure!(
+                link_data.as_ref().is_some_and(|link_data| {
+                    link_data.left_entity_id == patched_link_data.left_entity_id
+                        && link_data.right_entity_id == patched_link_data.right_entity_id
+                }),
+                Report::new(LinkedEntitiesMismatch)
+                    .attach(StatusCode::InvalidArgument)
+                    .change_context(UpdateError)
+            );
+            link_data = Some(patched_link_data);
+        }
+
+        let mut first_non_draft_created_at_decision_time = previous_entity
+            .metadata
+            .provenance
+            .inferred
+            .first_non_draft_created_at_decision_time;
+        let mut first_non_draft_created_at_transaction_time = previous_entity
+            .metadata
+            .provenance
+            .inferred
+            .first_non_draft_created_at_transaction_time;
+
+        let was_draft_before = previous_entity
+            .metadata
+            .record_id
+            .entity_id
+            .draft_id
+            .is_some();
+        let draft = params.draft.unwrap_or(was_draft_before);
+        let archived = params.archived.unwrap_or(previous_entity.metadata.archived);
+        let (entity_type_ids, entity_types_updated) = if params.entity_type_ids.is_empty() {
+            (previous_entity.metadata.entity_type_ids, false)
+        } else {
+            let added_types = previous_entity
+                .metadata
+                .entity_type_ids
+                .difference(&params.entity_type_ids);
+            let removed_types = params
+                .entity_type_ids
+                .difference(&previous_entity.metadata.entity_type_ids);
+
+            let mut has_changed = false;
+            for entity_type_id in added_types.chain(removed_types) {
+                has_changed = true;
+
+                self.authorization_api
+                    .check_entity_type_permission(
+                        actor_id,
+                        EntityTypePermission::Instantiate,
+                        EntityTypeUuid::from_url(entity_type_id),
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(UpdateError)?
+                    .assert_permission()
+                    .change_context(UpdateError)
+                    .attach(StatusCode::PermissionDenied)?;
+            }
+
+            (params.entity_type_ids, has_changed)
+        };
+
+        let previous_properties = previous_entity.properties.clone();
+        let previous_property_metadata = previous_entity.metadata.properties.clone();
+
+        let mut properties_with_metadata = PropertyWithMetadata::from_parts(
+            Property::Object(previous_entity.properties),
+            Some(PropertyMetadata::Object {
+                value: previous_entity.metadata.properties.value,
+                metadata: previous_entity.metadata.properties.metadata,
+            }),
+        )
+        .change_context(UpdateError)?;
+        properties_with_metadata
+            .patch(params.properties)
+            .change_context(UpdateError)?;
+
+        let validator_provider = StoreProvider {
+            state: &state,
+            authorization_api: &self.authorization_api,
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+        let entity_type = ClosedMultiEntityType::from_multi_type_closed_schema(
+            stream::iter(&entity_type_ids)
+                .then(|entity_type_url| async {
+                    OntologyTypeProvider::<ClosedEntityType>::provide_type(
+                        &validator_provider,
+                        entity_type_url,
+                    )
+                    .await
+                    .map(|entity_type| (*entity_type).clone())
+                })
+                .try_collect::<Vec<ClosedEntityType>>()
+                .await
+                .change_context(UpdateError)?,
+        )
+        .change_context(UpdateError)?;
+
+        let mut validation_components = if draft {
+            ValidateEntityComponents::draft()
+        } else {
+            ValidateEntityComponents::full()
+        };
+        validation_components.link_validation = self.settings.validate_links;
+
+        let mut validation_report = EntityValidationReport::default();
+        let (properties, property_metadata) =
+            if let PropertyWithMetadata::Object(mut object) = properties_with_metadata {
+                let mut preprocessor = EntityPreprocessor {
+                    components: validation_components,
+                };
+                if let Err(property_validation) = preprocessor
+                    .visit_object(&entity_type, &mut object, &validator_provider)
+                    .await
+                {
+                    validation_report.properties = property_validation.properties;
+                }
+
+                object.into_parts()
+            } else {
+                unreachable!("patching should not change the property type");
+            };
+
+        let has_changed_properties = previous_properties
+            .diff(&properties, &mut PropertyPath::default())
+            .next()
+            .is_some();
+
+        if !has_changed_properties
+            && was_draft_before == draft
+            && archived == previous_entity.metadata.archived
+            && !entity_types_updated
+            && previous_property_metadata == property_metadata
+            && params.confidence == previous_entity.metadata.confidence
+            && link_data == previous_entity.link_data
+            && params.provenance.merged_entity_ids.is_empty()
+        {
+            // No changes were made to the entity. Merges are always recorded in a new edition.
+            return Ok(Entity {
+                properties: previous_properties,
+                link_data,
+                metadata: EntityMetadata {
+                    record_id: previous_entity.metadata.record_id,
+                    temporal_versioning: previous_entity.metadata.temporal_versioning,
+                    entity_type_ids,
+                    provenance: previous_entity.metadata.provenance,
+                    archived,
+                    confidence: previous_entity.metadata.confidence,
+                    properties: property_metadata,
+                },
+            });
+        }
+
+        let edition_provenance = EntityEditionProvenance {
+            created_by_id: EditionCreatedById::new(actor_id),
+            archived_by_id: None,
+            provided: params.provenance,
+        };
+
+        let temporal_versioning = match (was_draft_before, draft) {
+            (true, true) | (false, false) => {
+                // regular update
+                split_edition(
+                    &mut editions,
+                    locked_position,
+                    transaction_time,
+                    decision_time,
+                )
+                .1
+            }
+            (false, true) => {
+                // A new draft is created, the live entity stays untouched.
+                params.entity_id.draft_id = Some(DraftId::new(Uuid::new_v4()));
+                EntityTemporalMetadata {
+                    decision_time: open_interval(decision_time),
+                    transaction_time: open_interval(transaction_time),
+                }
+            }
+            (true, false) => {
+                // Publish a draft
+                let draft_id = params.entity_id;
+                params.entity_id.draft_id = None;
+
+                if first_non_draft_created_at_decision_time.is_none() {
+                    first_non_draft_created_at_transaction_time = Some(transaction_time);
+                    first_non_draft_created_at_decision_time = Some(decision_time);
+                    for edition in &mut editions {
+                        let inferred = &mut edition.metadata.provenance.inferred;
+                        inferred.first_non_draft_created_at_transaction_time =
+                            first_non_draft_created_at_transaction_time;
+                        inferred.first_non_draft_created_at_decision_time =
+                            first_non_draft_created_at_decision_time;
+                    }
+                }
+
+                if let Some(live_position) = current_edition_position(
+                    &editions,
+                    params.entity_id,
+                    transaction_time,
+                    decision_time,
+                ) {
+                    // The live entity is superseded by the published draft, so it ends at the
+                    // decision time of the publication.
+                    let (previous_live_entity, _) = split_edition(
+                        &mut editions,
+                        live_position,
+                        transaction_time,
+                        decision_time,
+                    );
+                    let archived_by_id = EditionArchivedById::new(actor_id);
+                    for edition in &mut editions {
+                        if edition.metadata.record_id.edition_id
+                            == previous_live_entity.metadata.record_id.edition_id
+                        {
+                            edition.metadata.provenance.edition.archived_by_id =
+                                Some(archived_by_id);
+                        }
+                    }
+                }
+
+                let draft_position =
+                    current_edition_position(&editions, draft_id, transaction_time, decision_time)
+                        .ok_or_else(entity_does_not_exist)?;
+                split_edition(
+                    &mut editions,
+                    draft_position,
+                    transaction_time,
+                    decision_time,
+                )
+                .1
+            }
+        };
+
+        let entity = Entity {
+            properties,
+            link_data,
+            metadata: EntityMetadata {
+                record_id: EntityRecordId {
+                    entity_id: params.entity_id,
+                    edition_id: EntityEditionId::new(Uuid::new_v4()),
+                },
+                temporal_versioning,
+                entity_type_ids,
+                provenance: EntityProvenance {
+                    inferred: InferredEntityProvenance {
+                        first_non_draft_created_at_transaction_time,
+                        first_non_draft_created_at_decision_time,
+                        ..previous_entity.metadata.provenance.inferred
+                    },
+                    edition: edition_provenance,
+                },
+                confidence: params.confidence,
+                properties: property_metadata,
+                archived,
+            },
+        };
+        editions.push(entity.clone());
+
+        // The new editions are stored before the link validation and restored if the validation
+        // fails.
+        let previous_editions = state
+            .entities
+            .insert(params.entity_id.entity_uuid, editions);
+
+        let validator_provider = StoreProvider {
+            state: &state,
+            authorization_api: &self.authorization_api,
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+        let post_validation_report = entity
+            .validate(&entity_type, validation_components, &validator_provider)
+            .await;
+        validation_report.link = post_validation_report.link;
+        validation_report.metadata.properties = post_validation_report.property_metadata;
+
+        if !validation_report.is_valid() {
+            if let Some(previous_editions) = previous_editions {
+                state
+                    .entities
+                    .insert(params.entity_id.entity_uuid, previous_editions);
+            }
+            bail!(Report::new(UpdateError).attach(HashMap::from([(
+                entity.metadata.record_id.entity_id,
+                validation_report
+            )])));
+        }
+        let record_id = entity.metadata.record_id;
+        let change = if archived && !previous_entity.metadata.archived {
+            GraphChangeKind::EntityArchived {
+                entity_id: record_id.entity_id,
+                edition_id: record_id.edition_id,
+            }
+        } else {
+            GraphChangeKind::EntityUpdated {
+                entity_id: record_id.entity_id,
+                edition_id: record_id.edition_id,
+            }
+        };
+        state.publish_changes(once(change).chain(GraphChangeKind::relationship_modified(&entity)));
+        drop(state);
+
+        let entities = [entity];
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_entity_embeddings_workflow(actor_id, &entities)
+                .await
+                .change_context(UpdateError)?;
+        }
+        let [entity] = entities;
+        Ok(entity)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn merge_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: MergeEntitiesParams,
+    ) -> Result<MergeEntitiesResponse, Report<UpdateError>> {
+        let surviving_entity_id = params.surviving_entity_id;
+        if params
+            .merged_entity_ids
+            .iter()
+            .any(|entity_id| entity_id.entity_uuid == surviving_entity_id.entity_uuid)
+        {
+            bail!(
+                Report::new(UpdateError)
+                    .attach(StatusCode::InvalidArgument)
+                    .attach_printable("The surviving entity cannot be merged into itself")
+            );
+        }
+
+        let survivor = self
+            .get_entity_by_id(actor_id, surviving_entity_id, None, None)
+            .await
+            .change_context(UpdateError)?;
+        let mut properties = PropertyWithMetadataObject::from_parts(
+            survivor.properties.clone(),
+            Some(survivor.metadata.properties.clone()),
+        )
+        .change_context(UpdateError)?;
+
+        let mut merged_entities = Vec::with_capacity(params.merged_entity_ids.len());
+        for &entity_id in &params.merged_entity_ids {
+            let entity = self
+                .get_entity_by_id(actor_id, entity_id, None, None)
+                .await
+                .change_context(UpdateError)?;
+            params.merge_properties(
+                &mut properties,
+                PropertyWithMetadataObject::from_parts(
+                    entity.properties.clone(),
+                    Some(entity.metadata.properties.clone()),
+                )
+                .change_context(UpdateError)?,
+            );
+            merged_entities.push(entity);
+        }
+
+        let is_merged = |entity_uuid: EntityUuid| {
+            params
+                .merged_entity_ids
+                .iter()
+                .any(|merged| merged.entity_uuid == entity_uuid)
+        };
+        let (entities_snapshot, changes_len, link_entity_ids) = {
+            let state = self.state.read().await;
+            let mut link_entity_ids = Vec::new();
+            for edition in state.entities.values().flatten() {
+                let Some(link_data) = &edition.link_data else {
+                    continue;
+                };
+                let link_entity_id = EntityId {
+                    draft_id: None,
+                    ..edition.metadata.record_id.entity_id
+                };
+                if (is_merged(link_data.left_entity_id.entity_uuid)
+                    || is_merged(link_data.right_entity_id.entity_uuid))
+                    && !is_merged(link_entity_id.entity_uuid)
+                    && link_entity_id.entity_uuid != surviving_entity_id.entity_uuid
+                    && !link_entity_ids.contains(&link_entity_id)
+                {
+                    link_entity_ids.push(link_entity_id);
+                }
+            }
+            (state.entities.clone(), state.changes.len(), link_entity_ids)
+        };
+
+        let mut relinked_entity_ids = Vec::new();
+        let mut created_link_entity_ids = Vec::new();
+        let result = async {
+            let entity = self
+                .patch_entity(actor_id, PatchEntityParams {
+                    entity_id: surviving_entity_id,
+                    expected_edition_id: Some(survivor.metadata.record_id.edition_id),
+                    decision_time: None,
+                    entity_type_ids: HashSet::new(),
+                    properties: vec![PropertyPatchOperation::Replace {
+                        path: PropertyPath::default(),
+                        property: PropertyWithMetadata::Object(properties),
+                    }],
+                    draft: None,
+                    archived: None,
+                    confidence: survivor.metadata.confidence,
+                    link_data: None,
+                    provenance: params.merged_provenance(once(&survivor).chain(&merged_entities)),
+                })
+                .await?;
+
+            // The endpoints of a link entity are not versioned, so instead of re-pointing the
+            // links the existing link entities are archived and replaced by new link entities.
+            for link_entity_id in link_entity_ids {
+                let link_entity = self
+                    .get_entity_by_id(actor_id, link_entity_id, None, None)
+                    .await
+                    .change_context(UpdateError)?;
+                if link_entity.metadata.archived {
+                    continue;
+                }
+
+                let relationships = self
+                    .authorization_api
+                    .get_entity_relations(link_entity_id, Consistency::FullyConsistent)
+                    .await
+                    .change_context(UpdateError)?;
+                let Some(replacement) = params.relink(&link_entity, relationships)? else {
+                    continue;
+                };
+
+                let created_link_entity = self
+                    .create_entity(actor_id, replacement)
+                    .await
+                    .change_context(UpdateError)?;
+
+                self.patch_entity(actor_id, PatchEntityParams {
+                    entity_id: link_entity_id,
+                    expected_edition_id: Some(link_entity.metadata.record_id.edition_id),
+                    decision_time: None,
+                    entity_type_ids: HashSet::new(),
+                    properties: Vec::new(),
+                    draft: None,
+                    archived: Some(true),
+                    confidence: link_entity.metadata.confidence,
+                    link_data: None,
+                    provenance: params.provenance.clone(),
+                })
+                .await?;
+
+                relinked_entity_ids.push(link_entity_id);
+                created_link_entity_ids.push(created_link_entity.metadata.record_id.entity_id);
+            }
+
+            for merged_entity in merged_entities {
+                self.patch_entity(actor_id, PatchEntityParams {
+                    entity_id: merged_entity.metadata.record_id.entity_id,
+                    expected_edition_id: Some(merged_entity.metadata.record_id.edition_id),
+                    decision_time: None,
+                    entity_type_ids: HashSet::new(),
+                    properties: Vec::new(),
+                    draft: None,
+                    archived: Some(true),
+                    confidence: merged_entity.metadata.confidence,
+                    link_data: None,
+                    provenance: params.provenance.clone(),
+                })
+                .await?;
+            }
+
+            Ok::<_, Report<UpdateError>>(entity)
+        }
+        .await;
+
+        match result {
+            Ok(entity) => Ok(MergeEntitiesResponse {
+                entity,
+                relinked_entity_ids,
+                created_link_entity_ids,
+            }),
+            Err(error) => {
+                // Restore the state so a failed merge does not leave partially merged entities.
+                let mut state = self.state.write().await;
+                state.entities = entities_snapshot;
+                state.changes.truncate(changes_len);
+                Err(error)
+            }
+        }
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn migrate_entity_type(
+        &mut self,
+        actor_id: AccountId,
+        params: MigrateEntityTypeParams,
+    ) -> Result<MigrateEntityTypeResponse, Report<UpdateError>> {
+        let (entities_snapshot, changes_len) = {
+            let state = self.state.read().await;
+            (state.entities.clone(), state.changes.len())
+        };
+
+        let result = params.migrate_entities(self, actor_id).await;
+        if result.is_err() {
+            // Restore the state so a failed migration does not leave partially migrated entities.
+            let mut state = self.state.write().await;
+            state.entities = entities_snapshot;
+            state.changes.truncate(changes_len);
+        }
+        result
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn get_entity_history(
+        &self,
+        actor_id: AccountId,
+        params: GetEntityHistoryParams,
+    ) -> Result<GetEntityHistoryResponse, Report<QueryError>> {
+        self.authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::View,
+                params.entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .assert_permission()
+            .change_context(QueryError)?;
+
+        let state = self.state.read().await;
+
+        // An edition may be split into multiple rows, its earliest row is used.
+        let mut earliest_rows = HashMap::<EntityEditionId, &Entity>::new();
+        for row in state
+            .entities
+            .get(&params.entity_id.entity_uuid)
+            .into_iter()
+            .flatten()
+            .filter(|row| {
+                row.metadata.record_id.entity_id.owned_by_id == params.entity_id.owned_by_id
+            })
+        {
+            earliest_rows
+                .entry(row.metadata.record_id.edition_id)
+                .and_modify(|earliest_row| {
+                    if edition_start(row) < edition_start(earliest_row) {
+                        *earliest_row = row;
+                    }
+                })
+                .or_insert(row);
+        }
+        let mut editions = earliest_rows.into_values().collect::<Vec<_>>();
+        editions.sort_by_key(|edition| {
+            (
+                edition_start(edition),
+                edition.metadata.record_id.edition_id,
+            )
+        });
+
+        let mut positions = (0..editions.len())
+            .filter(|&index| {
+                params.entity_id.draft_id.is_none_or(|draft_id| {
+                    editions[index].metadata.record_id.entity_id.draft_id == Some(draft_id)
+                })
+            })
+            .collect::<Vec<_>>();
+        if let Some(after) = params.after {
+            let cursor_position = positions
+                .iter()
+                .position(|&index| editions[index].metadata.record_id.edition_id == after)
+                .ok_or_else(|| {
+                    Report::new(EntityHistoryCursorNotFound)
+                        .attach(StatusCode::NotFound)
+                        .attach_printable(after.into_uuid())
+                        .change_context(QueryError)
+                })?;
+            positions.drain(..=cursor_position);
+        }
+        let has_more = params.limit.is_some_and(|limit| positions.len() > limit);
+        if let Some(limit) = params.limit {
+            positions.truncate(limit);
+        }
+
+        let history = positions
+            .into_iter()
+            .map(|index| {
+                EntityHistoryEntry::new(previous_edition(&editions, index), editions[index])
+            })
+            .collect::<Vec<_>>();
+        let cursor = history
+            .last()
+            .filter(|_| has_more)
+            .map(|entry| entry.edition_id);
+
+        Ok(GetEntityHistoryResponse {
+            editions: history,
+            cursor,
+        })
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_entity_embeddings(
+        &mut self,
+        _: AccountId,
+        params: UpdateEntityEmbeddingsParams<'_>,
+    ) -> Result<(), Report<UpdateError>> {
+        // TODO: Add permission to allow updating embeddings
+        //   see https://linear.app/hash/issue/H-1870
+        let mut state = self.state.write().await;
+
+        let is_outdated = |record: &EntityEmbeddingRecord| {
+            record.updated_at_transaction_time <= params.updated_at_transaction_time
+                && record.updated_at_decision_time <= params.updated_at_decision_time
+        };
+
+        if params.reset {
+            state
+                .entity_embeddings
+                .retain(|record| record.entity_id != params.entity_id || !is_outdated(record));
+        }
+
+        for embedding in params.embeddings {
+            let existing = state.entity_embeddings.iter_mut().find(|record| {
+                record.entity_id.owned_by_id == params.entity_id.owned_by_id
+                    && record.entity_id.entity_uuid == params.entity_id.entity_uuid
+                    && record.property == embedding.property
+            });
+            let record = EntityEmbeddingRecord {
+                entity_id: params.entity_id,
+                property: embedding.property,
+                embedding: embedding.embedding.into_owned(),
+                updated_at_transaction_time: params.updated_at_transaction_time,
+                updated_at_decision_time: params.updated_at_decision_time,
+            };
+            match existing {
+                Some(existing) => {
+                    if is_outdated(existing) {
+                        *existing = record;
+                    }
+                }
+                None => state.entity_embeddings.push(record),
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn reindex_entity_cache(&mut self) -> Result<(), Report<UpdateError>> {
+        // The inherited entity types of an entity are resolved from the closed entity types on
+        // every query, so there is no cache which could become stale.
+        tracing::info!("Reindexing entity cache");
+        Ok(())
+    }
+}
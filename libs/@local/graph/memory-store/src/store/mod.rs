@@ -0,0 +1,271 @@
+mod change_feed;
+mod error;
+mod filter;
+mod knowledge;
+mod ontology;
+mod pool;
+mod state;
+mod traversal;
+mod validation;
+
+use alloc::sync::Arc;
+use core::hash::Hash;
+use std::collections::HashMap;
+
+use error_stack::{Report, ResultExt as _};
+use hash_graph_authorization::{
+    AuthorizationApi,
+    backend::ModifyRelationshipOperation,
+    schema::{
+        AccountGroupAdministratorSubject, AccountGroupRelationAndSubject, WebDataTypeViewerSubject,
+        WebEntityCreatorSubject, WebEntityEditorSubject, WebEntityTypeViewerSubject,
+        WebOwnerSubject, WebPropertyTypeViewerSubject, WebRelationAndSubject, WebSubjectSet,
+    },
+};
+use hash_graph_store::account::{
+    AccountGroupInsertionError, AccountInsertionError, AccountStore, InsertAccountGroupIdParams,
+    InsertAccountIdParams, InsertWebIdParams, QueryWebError, WebInsertionError,
+};
+use hash_graph_types::{
+    account::{AccountGroupId, AccountId},
+    owned_by_id::OwnedById,
+};
+use hash_temporal_client::TemporalClient;
+use tokio::sync::RwLock;
+
+use self::state::MemoryState;
+pub use self::{
+    error::{
+        BaseUrlAlreadyExists, EntityDoesNotExist, OntologyTypeIsNotOwned,
+        OntologyVersionDoesNotExist, StoreError, VersionedUrlAlreadyExists,
+    },
+    pool::MemoryStorePool,
+};
+
+#[derive(Debug, Clone)]
+pub struct MemoryStoreSettings {
+    pub validate_links: bool,
+}
+
+impl Default for MemoryStoreSettings {
+    fn default() -> Self {
+        Self {
+            validate_links: true,
+        }
+    }
+}
+
+/// An in-memory store
+///
+/// All stores acquired from the same [`MemoryStorePool`] share their data. Every operation takes
+/// the lock on the shared state for its whole duration, so operations are serialized and a failed
+/// operation never leaves partially written data behind.
+pub struct MemoryStore<A> {
+    state: Arc<RwLock<MemoryState>>,
+    pub authorization_api: A,
+    pub temporal_client: Option<Arc<TemporalClient>>,
+    pub settings: MemoryStoreSettings,
+}
+
+#[derive(Debug)]
+pub struct ResponseCountMap<T> {
+    map: HashMap<T, usize>,
+}
+
+impl<T> Default for ResponseCountMap<T> {
+    fn default() -> Self {
+        Self {
+            map: HashMap::new(),
+        }
+    }
+}
+
+impl<T> ResponseCountMap<T>
+where
+    T: Eq + Hash + Clone,
+{
+    pub fn increment(&mut self, key: &T) {
+        *self
+            .map
+            .raw_entry_mut()
+            .from_key(key)
+            .or_insert(key.clone(), 0)
+            .1 += 1;
+    }
+}
+
+#[expect(
+    clippy::implicit_hasher,
+    reason = "The hasher should not be exposed from `ResponseCountMap`"
+)]
+impl<T> From<ResponseCountMap<T>> for HashMap<T, usize> {
+    fn from(map: ResponseCountMap<T>) -> Self {
+        map.map
+    }
+}
+
+impl<A> MemoryStore<A>
+where
+    A: Send + Sync,
+{
+    /// Creates a new `MemoryStore` operating on the shared `state`.
+    #[must_use]
+    const fn new(
+        state: Arc<RwLock<MemoryState>>,
+        authorization_api: A,
+        temporal_client: Option<Arc<TemporalClient>>,
+        settings: MemoryStoreSettings,
+    ) -> Self {
+        Self {
+            state,
+            authorization_api,
+            temporal_client,
+            settings,
+        }
+    }
+
+    /// Removes all accounts, account groups, and webs from the store.
+    pub async fn delete_accounts(&mut self) {
+        let mut state = self.state.write().await;
+        state.webs.clear();
+        state.accounts.clear();
+        state.account_groups.clear();
+    }
+}
+
+impl<A: AuthorizationApi> AccountStore for MemoryStore<A> {
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn insert_account_id(
+        &mut self,
+        actor_id: AccountId,
+        params: InsertAccountIdParams,
+    ) -> Result<(), Report<AccountInsertionError>> {
+        if !self.state.write().await.accounts.insert(params.account_id) {
+            return Err(Report::new(AccountInsertionError)
+                .attach_printable("Account already exists")
+                .attach_printable(params.account_id));
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn insert_account_group_id(
+        &mut self,
+        actor_id: AccountId,
+        params: InsertAccountGroupIdParams,
+    ) -> Result<(), Report<AccountGroupInsertionError>> {
+        let mut state = self.state.write().await;
+        if state.account_groups.contains(&params.account_group_id) {
+            return Err(Report::new(AccountGroupInsertionError)
+                .attach_printable("Account group already exists")
+                .attach_printable(params.account_group_id));
+        }
+
+        self.authorization_api
+            .modify_account_group_relations([(
+                ModifyRelationshipOperation::Create,
+                params.account_group_id,
+                AccountGroupRelationAndSubject::Administrator {
+                    subject: AccountGroupAdministratorSubject::Account { id: actor_id },
+                    level: 0,
+                },
+            )])
+            .await
+            .change_context(AccountGroupInsertionError)?;
+
+        state.account_groups.insert(params.account_group_id);
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn insert_web_id(
+        &mut self,
+        actor_id: AccountId,
+        params: InsertWebIdParams,
+    ) -> Result<(), Report<WebInsertionError>> {
+        let mut state = self.state.write().await;
+        if state.webs.contains(&params.owned_by_id) {
+            return Err(Report::new(WebInsertionError)
+                .attach_printable("Web already exists")
+                .attach_printable(params.owned_by_id));
+        }
+
+        let mut relationships = vec![
+            WebRelationAndSubject::Owner {
+                subject: params.owner,
+                level: 0,
+            },
+            WebRelationAndSubject::EntityTypeViewer {
+                subject: WebEntityTypeViewerSubject::Public,
+                level: 0,
+            },
+            WebRelationAndSubject::PropertyTypeViewer {
+                subject: WebPropertyTypeViewerSubject::Public,
+                level: 0,
+            },
+            WebRelationAndSubject::DataTypeViewer {
+                subject: WebDataTypeViewerSubject::Public,
+                level: 0,
+            },
+        ];
+        if let WebOwnerSubject::AccountGroup { id } = params.owner {
+            relationships.extend([
+                WebRelationAndSubject::EntityCreator {
+                    subject: WebEntityCreatorSubject::AccountGroup {
+                        id,
+                        set: WebSubjectSet::Member,
+                    },
+                    level: 0,
+                },
+                WebRelationAndSubject::EntityEditor {
+                    subject: WebEntityEditorSubject::AccountGroup {
+                        id,
+                        set: WebSubjectSet::Member,
+                    },
+                    level: 0,
+                },
+                // TODO: Add ontology type creators
+            ]);
+        }
+
+        self.authorization_api
+            .modify_web_relations(relationships.into_iter().map(|relation_and_subject| {
+                (
+                    ModifyRelationshipOperation::Create,
+                    params.owned_by_id,
+                    relation_and_subject,
+                )
+            }))
+            .await
+            .change_context(WebInsertionError)?;
+
+        state.webs.insert(params.owned_by_id);
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn identify_owned_by_id(
+        &self,
+        owned_by_id: OwnedById,
+    ) -> Result<WebOwnerSubject, Report<QueryWebError>> {
+        let state = self.state.read().await;
+        let account_id = AccountId::new(owned_by_id.into_uuid());
+        let account_group_id = AccountGroupId::new(owned_by_id.into_uuid());
+
+        match (
+            state.accounts.contains(&account_id),
+            state.account_groups.contains(&account_group_id),
+        ) {
+            (false, false) => Err(Report::new(QueryWebError)
+                .attach_printable("Record does not exist")
+                .attach_printable(owned_by_id)),
+            (true, false) => Ok(WebOwnerSubject::Account { id: account_id }),
+            (false, true) => Ok(WebOwnerSubject::AccountGroup {
+                id: account_group_id,
+            }),
+            (true, true) => Err(Report::new(QueryWebError)
+                .attach_printable("Record exists in both accounts and account_groups")
+                .attach_printable(owned_by_id)),
+        }
+    }
+}
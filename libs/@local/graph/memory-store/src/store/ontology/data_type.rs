@@ -0,0 +1,692 @@
+use alloc::sync::Arc;
+use core::iter::once;
+use std::collections::HashSet;
+
+use error_stack::{Report, ResultExt as _};
+use hash_graph_authorization::{
+    AuthorizationApi,
+    backend::ModifyRelationshipOperation,
+    schema::{DataTypeOwnerSubject, DataTypePermission, DataTypeRelationAndSubject, WebPermission},
+    zanzibar::Consistency,
+};
+use hash_graph_store::{
+    change_feed::GraphChangeKind,
+    data_type::{
+        ArchiveDataTypeParams, CountDataTypesParams, CreateDataTypeParams, DataTypeStore,
+        GetDataTypeSubgraphParams, GetDataTypeSubgraphResponse, GetDataTypesParams,
+        GetDataTypesResponse, UnarchiveDataTypeParams, UpdateDataTypeEmbeddingParams,
+        UpdateDataTypesParams,
+    },
+    error::{InsertionError, QueryError, UpdateError},
+    subgraph::{
+        Subgraph, SubgraphRecord as _, identifier::GraphElementVertexId,
+        temporal_axes::QueryTemporalAxes,
+    },
+};
+use hash_graph_types::{
+    account::{AccountId, EditionArchivedById, EditionCreatedById},
+    ontology::{
+        DataTypeMetadata, DataTypeWithMetadata, OntologyEditionProvenance,
+        OntologyTemporalMetadata, OntologyTypeClassificationMetadata, OntologyTypeRecordId,
+    },
+};
+use hash_status::StatusCode;
+use type_system::{
+    Validator as _,
+    schema::{ClosedDataType, DataType, DataTypeUuid, DataTypeValidator, OntologyTypeResolver},
+    url::{OntologyTypeVersion, VersionedUrl},
+};
+
+use crate::store::{
+    MemoryStore,
+    filter::FilterContext,
+    ontology::{
+        PendingOntologyIds, archive_ontology_type, data_type_with_metadata, initial_edition,
+        read_ontology_types, unarchive_ontology_type, update_ontology_embedding,
+    },
+    state::{DataTypeClosure, DataTypeRecord, MemoryState},
+    traversal::{TraversalItem, permitted_data_types, traverse_subgraph},
+    validation::{StoreProvider, current_temporal_axes},
+};
+
+/// Adds the data types `data_type_ids` which are visible to `actor_id` to `resolver`.
+async fn add_parent_data_types<A: AuthorizationApi>(
+    state: &MemoryState,
+    authorization_api: &A,
+    actor_id: AccountId,
+    resolver: &mut OntologyTypeResolver,
+    data_type_ids: Vec<DataTypeUuid>,
+) -> Result<(), Report<QueryError>> {
+    let temporal_axes = current_temporal_axes();
+    let context = FilterContext::new(state, &temporal_axes, false);
+
+    for data_type_id in permitted_data_types(authorization_api, actor_id, data_type_ids).await? {
+        if let Some(parent) = context.data_type_view(data_type_id) {
+            resolver.add_closed_data_type(
+                data_type_id,
+                Arc::clone(&parent.record.schema),
+                Arc::clone(&parent.record.closure.resolve_data),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+impl<A> MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    /// Removes all data types from the store.
+    pub async fn delete_data_types(&mut self) {
+        let mut state = self.state.write().await;
+        let records = core::mem::take(&mut state.data_types);
+        state.remove_ontology_ids(records);
+    }
+
+    async fn get_data_types_impl(
+        &self,
+        state: &MemoryState,
+        actor_id: AccountId,
+        params: GetDataTypesParams<'_>,
+        temporal_axes: &QueryTemporalAxes,
+    ) -> Result<GetDataTypesResponse, Report<QueryError>> {
+        let context = FilterContext::new(state, temporal_axes, params.include_drafts);
+
+        let count = params
+            .include_count
+            .then(|| {
+                read_ontology_types(&context, &state.data_types, &params.filter, None, None)
+                    .map(|types| types.len())
+            })
+            .transpose()?;
+
+        let data_types = read_ontology_types(
+            &context,
+            &state.data_types,
+            &params.filter,
+            params.after.as_ref(),
+            params.limit,
+        )?;
+
+        let (permissions, _) = self
+            .authorization_api
+            .check_data_types_permission(
+                actor_id,
+                DataTypePermission::View,
+                data_types
+                    .iter()
+                    .map(|view| DataTypeUuid::from_url(view.id))
+                    .collect::<Vec<_>>(),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let data_types = data_types
+            .into_iter()
+            .filter(|view| {
+                permissions
+                    .get(&DataTypeUuid::from_url(view.id))
+                    .copied()
+                    .unwrap_or(false)
+            })
+            .map(|view| data_type_with_metadata(view.id, view.record, view.edition))
+            .collect::<Vec<_>>();
+
+        Ok(GetDataTypesResponse {
+            cursor: if params.limit.is_some() {
+                data_types
+                    .last()
+                    .map(|data_type| data_type.schema.id.clone())
+            } else {
+                None
+            },
+            data_types,
+            count,
+        })
+    }
+}
+
+impl<A> DataTypeStore for MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn create_data_types<P, R>(
+        &mut self,
+        actor_id: AccountId,
+        params: P,
+    ) -> Result<Vec<DataTypeMetadata>, Report<InsertionError>>
+    where
+        P: IntoIterator<Item = CreateDataTypeParams<R>, IntoIter: Send> + Send,
+        R: IntoIterator<Item = DataTypeRelationAndSubject> + Send + Sync,
+    {
+        let mut state = self.state.write().await;
+        let mut pending = PendingOntologyIds::default();
+
+        let mut relationships = HashSet::new();
+        let mut inserted_data_types = Vec::new();
+
+        for parameters in params {
+            let data_type_id = DataTypeUuid::from_url(&parameters.schema.id);
+            if let OntologyTypeClassificationMetadata::Owned { owned_by_id } =
+                &parameters.classification
+            {
+                self.authorization_api
+                    .check_web_permission(
+                        actor_id,
+                        WebPermission::CreateDataType,
+                        *owned_by_id,
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(InsertionError)?
+                    .assert_permission()
+                    .change_context(InsertionError)?;
+
+                relationships.insert((data_type_id, DataTypeRelationAndSubject::Owner {
+                    subject: DataTypeOwnerSubject::Web { id: *owned_by_id },
+                    level: 0,
+                }));
+            }
+
+            relationships.extend(
+                parameters
+                    .relationships
+                    .into_iter()
+                    .map(|relation_and_subject| (data_type_id, relation_and_subject)),
+            );
+
+            if state.reserve_ontology_id(
+                &mut pending,
+                &parameters.schema.id,
+                &parameters.classification,
+                parameters.conflict_behavior,
+            )? {
+                let edition = initial_edition(OntologyEditionProvenance {
+                    created_by_id: EditionCreatedById::new(actor_id),
+                    archived_by_id: None,
+                    user_defined: parameters.provenance,
+                });
+                let metadata = DataTypeMetadata {
+                    record_id: OntologyTypeRecordId::from(parameters.schema.id.clone()),
+                    classification: parameters.classification,
+                    temporal_versioning: edition.temporal_metadata(),
+                    provenance: edition.provenance.clone(),
+                    conversions: parameters.conversions,
+                };
+                inserted_data_types.push((
+                    data_type_id,
+                    Arc::new(parameters.schema),
+                    edition,
+                    metadata,
+                ));
+            }
+        }
+
+        let mut ontology_type_resolver = OntologyTypeResolver::default();
+        for (data_type_id, schema, ..) in &inserted_data_types {
+            ontology_type_resolver.add_unresolved_data_type(*data_type_id, Arc::clone(schema));
+        }
+
+        let parent_ids = inserted_data_types
+            .iter()
+            .flat_map(|(_, schema, ..)| {
+                schema
+                    .data_type_references()
+                    .map(|(reference, _)| DataTypeUuid::from_url(&reference.url))
+            })
+            .filter(|parent_id| {
+                !inserted_data_types
+                    .iter()
+                    .any(|(data_type_id, ..)| data_type_id == parent_id)
+            })
+            .collect::<HashSet<_>>();
+        add_parent_data_types(
+            &state,
+            &self.authorization_api,
+            actor_id,
+            &mut ontology_type_resolver,
+            parent_ids.into_iter().collect(),
+        )
+        .await
+        .change_context(InsertionError)
+        .attach_printable("Could not read parent data types")?;
+
+        let data_type_validator = DataTypeValidator;
+        let mut records = Vec::with_capacity(inserted_data_types.len());
+        for (data_type_id, schema, edition, metadata) in inserted_data_types {
+            let resolve_data = ontology_type_resolver
+                .resolve_data_type_metadata(data_type_id)
+                .change_context(InsertionError)?;
+            let closed_schema = ClosedDataType::from_resolve_data((*schema).clone(), &resolve_data)
+                .change_context(InsertionError)?;
+
+            data_type_validator
+                .validate_ref(&*schema)
+                .attach(StatusCode::InvalidArgument)
+                .change_context(InsertionError)?;
+            data_type_validator
+                .validate_ref(&closed_schema)
+                .attach(StatusCode::InvalidArgument)
+                .change_context(InsertionError)?;
+
+            records.push((
+                DataTypeRecord {
+                    schema,
+                    closure: DataTypeClosure {
+                        closed_schema: Arc::new(closed_schema),
+                        resolve_data,
+                        conversions: metadata.conversions.clone(),
+                    },
+                    classification: metadata.classification.clone(),
+                    editions: vec![edition],
+                    embedding: None,
+                },
+                metadata,
+            ));
+        }
+
+        self.authorization_api
+            .modify_data_type_relations(relationships.into_iter().map(
+                |(resource, relation_and_subject)| {
+                    (
+                        ModifyRelationshipOperation::Create,
+                        resource,
+                        relation_and_subject,
+                    )
+                },
+            ))
+            .await
+            .change_context(InsertionError)?;
+
+        state.commit_ontology_ids(pending);
+        let mut inserted_data_types = Vec::with_capacity(records.len());
+        let mut inserted_data_type_metadata = Vec::with_capacity(records.len());
+        for (record, metadata) in records {
+            inserted_data_types.push(DataTypeWithMetadata {
+                schema: DataType::clone(&record.schema),
+                metadata: metadata.clone(),
+            });
+            state.data_types.insert(record.schema.id.clone(), record);
+            inserted_data_type_metadata.push(metadata);
+        }
+        drop(state);
+
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_data_type_embeddings_workflow(actor_id, &inserted_data_types)
+                .await
+                .change_context(InsertionError)?;
+        }
+
+        Ok(inserted_data_type_metadata)
+    }
+
+    async fn get_data_types(
+        &self,
+        actor_id: AccountId,
+        mut params: GetDataTypesParams<'_>,
+    ) -> Result<GetDataTypesResponse, Report<QueryError>> {
+        let state = self.state.read().await;
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                state: &state,
+                authorization_api: &self.authorization_api,
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.clone().resolve();
+        self.get_data_types_impl(&state, actor_id, params, &temporal_axes)
+            .await
+    }
+
+    async fn count_data_types(
+        &self,
+        actor_id: AccountId,
+        mut params: CountDataTypesParams<'_>,
+    ) -> Result<usize, Report<QueryError>> {
+        let state = self.state.read().await;
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                state: &state,
+                authorization_api: &self.authorization_api,
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.resolve();
+        let context = FilterContext::new(&state, &temporal_axes, params.include_drafts);
+        Ok(read_ontology_types(&context, &state.data_types, &params.filter, None, None)?.len())
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn get_data_type_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetDataTypeSubgraphParams<'_>,
+    ) -> Result<GetDataTypeSubgraphResponse, Report<QueryError>> {
+        let state = self.state.read().await;
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                state: &state,
+                authorization_api: &self.authorization_api,
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.clone().resolve();
+        let time_axis = temporal_axes.variable_time_axis();
+
+        let GetDataTypesResponse {
+            data_types,
+            cursor,
+            count,
+        } = self
+            .get_data_types_impl(
+                &state,
+                actor_id,
+                GetDataTypesParams {
+                    filter: params.filter,
+                    temporal_axes: params.temporal_axes.clone(),
+                    after: params.after,
+                    limit: params.limit,
+                    include_drafts: params.include_drafts,
+                    include_count: params.include_count,
+                },
+                &temporal_axes,
+            )
+            .await?;
+
+        let mut subgraph = Subgraph::new(
+            params.graph_resolve_depths,
+            params.temporal_axes,
+            temporal_axes.clone(),
+        );
+
+        let traversal_queue = data_types
+            .iter()
+            .map(|data_type| {
+                (
+                    TraversalItem::DataType(DataTypeUuid::from_url(&data_type.schema.id)),
+                    subgraph.depths,
+                    subgraph.temporal_axes.resolved.variable_interval(),
+                )
+            })
+            .collect();
+        subgraph.roots.extend(
+            data_types
+                .iter()
+                .map(|data_type| GraphElementVertexId::from(data_type.vertex_id(time_axis))),
+        );
+        for data_type in data_types {
+            subgraph.insert_vertex(data_type.vertex_id(time_axis), data_type);
+        }
+
+        traverse_subgraph(
+            &FilterContext::new(&state, &temporal_axes, params.include_drafts),
+            &self.authorization_api,
+            actor_id,
+            traversal_queue,
+            &mut subgraph,
+        )
+        .await?;
+
+        Ok(GetDataTypeSubgraphResponse {
+            subgraph,
+            cursor,
+            count,
+        })
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_data_type<R>(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdateDataTypesParams<R>,
+    ) -> Result<DataTypeMetadata, Report<UpdateError>>
+    where
+        R: IntoIterator<Item = DataTypeRelationAndSubject> + Send + Sync,
+    {
+        let data_type_validator = DataTypeValidator;
+
+        let old_ontology_id = DataTypeUuid::from_url(&VersionedUrl {
+            base_url: params.schema.id.base_url.clone(),
+            version: OntologyTypeVersion::new(
+                params
+                    .schema
+                    .id
+                    .version
+                    .inner()
+                    .checked_sub(1)
+                    .ok_or(UpdateError)
+                    .attach_printable(
+                        "The version of the data type is already at the lowest possible value",
+                    )?,
+            ),
+        });
+        let new_ontology_id = DataTypeUuid::from_url(&params.schema.id);
+        self.authorization_api
+            .check_data_type_permission(
+                actor_id,
+                DataTypePermission::Update,
+                old_ontology_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)?;
+
+        let mut state = self.state.write().await;
+        let mut pending = PendingOntologyIds::default();
+
+        let schema = data_type_validator
+            .validate(params.schema)
+            .change_context(UpdateError)?
+            .into_inner();
+        let owned_by_id =
+            state.reserve_updated_ontology_id(&mut pending, &state.data_types, &schema.id)?;
+
+        let mut ontology_type_resolver = OntologyTypeResolver::default();
+        add_parent_data_types(
+            &state,
+            &self.authorization_api,
+            actor_id,
+            &mut ontology_type_resolver,
+            schema
+                .data_type_references()
+                .map(|(reference, _)| DataTypeUuid::from_url(&reference.url))
+                .collect(),
+        )
+        .await
+        .change_context(UpdateError)
+        .attach_printable("Could not read parent data types")?;
+
+        let schema = Arc::new(schema);
+        ontology_type_resolver.add_unresolved_data_type(new_ontology_id, Arc::clone(&schema));
+        let resolve_data = ontology_type_resolver
+            .resolve_data_type_metadata(new_ontology_id)
+            .change_context(UpdateError)?;
+        let closed_schema = data_type_validator
+            .validate(
+                ClosedDataType::from_resolve_data((*schema).clone(), &resolve_data)
+                    .change_context(UpdateError)?,
+            )
+            .change_context(UpdateError)?
+            .into_inner();
+
+        self.authorization_api
+            .modify_data_type_relations(
+                params
+                    .relationships
+                    .into_iter()
+                    .chain(once(DataTypeRelationAndSubject::Owner {
+                        subject: DataTypeOwnerSubject::Web { id: owned_by_id },
+                        level: 0,
+                    }))
+                    .map(|relation_and_subject| {
+                        (
+                            ModifyRelationshipOperation::Create,
+                            new_ontology_id,
+                            relation_and_subject,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        let edition = initial_edition(OntologyEditionProvenance {
+            created_by_id: EditionCreatedById::new(actor_id),
+            archived_by_id: None,
+            user_defined: params.provenance,
+        });
+        let metadata = DataTypeMetadata {
+            record_id: OntologyTypeRecordId::from(schema.id.clone()),
+            classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+            temporal_versioning: edition.temporal_metadata(),
+            provenance: edition.provenance.clone(),
+            conversions: params.conversions,
+        };
+
+        state.commit_ontology_ids(pending);
+        state.data_types.insert(schema.id.clone(), DataTypeRecord {
+            schema: Arc::clone(&schema),
+            closure: DataTypeClosure {
+                closed_schema: Arc::new(closed_schema),
+                resolve_data,
+                conversions: metadata.conversions.clone(),
+            },
+            classification: metadata.classification.clone(),
+            editions: vec![edition],
+            embedding: None,
+        });
+        drop(state);
+
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_data_type_embeddings_workflow(actor_id, &[DataTypeWithMetadata {
+                    schema: DataType::clone(&schema),
+                    metadata: metadata.clone(),
+                }])
+                .await
+                .change_context(UpdateError)?;
+        }
+
+        Ok(metadata)
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn archive_data_type(
+        &mut self,
+        actor_id: AccountId,
+        params: ArchiveDataTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
+        let mut state = self.state.write().await;
+        let metadata = archive_ontology_type(
+            &mut state.data_types,
+            &params.data_type_id,
+            EditionArchivedById::new(actor_id),
+        )?;
+        state.publish_changes([GraphChangeKind::TypeArchived {
+            ontology_type_id: params.data_type_id.into_owned(),
+        }]);
+
+        Ok(metadata)
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn unarchive_data_type(
+        &mut self,
+        actor_id: AccountId,
+        params: UnarchiveDataTypeParams,
+    ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
+        let mut state = self.state.write().await;
+        let metadata = unarchive_ontology_type(
+            &mut state.data_types,
+            &params.data_type_id,
+            OntologyEditionProvenance {
+                created_by_id: EditionCreatedById::new(actor_id),
+                archived_by_id: None,
+                user_defined: params.provenance,
+            },
+        )?;
+        state.publish_changes([GraphChangeKind::TypeUnarchived {
+            ontology_type_id: params.data_type_id,
+        }]);
+
+        Ok(metadata)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_data_type_embeddings(
+        &mut self,
+        _: AccountId,
+        params: UpdateDataTypeEmbeddingParams<'_>,
+    ) -> Result<(), Report<UpdateError>> {
+        // TODO: Add permission to allow updating embeddings
+        //   see https://linear.app/hash/issue/H-1870
+        update_ontology_embedding(
+            &mut self.state.write().await.data_types,
+            &params.data_type_id,
+            params.embedding,
+            params.updated_at_transaction_time,
+        );
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn reindex_data_type_cache(&mut self) -> Result<(), Report<UpdateError>> {
+        tracing::info!("Reindexing data type cache");
+        let mut state = self.state.write().await;
+
+        let mut ontology_type_resolver = OntologyTypeResolver::default();
+        for (data_type_id, record) in &state.data_types {
+            ontology_type_resolver.add_unresolved_data_type(
+                DataTypeUuid::from_url(data_type_id),
+                Arc::clone(&record.schema),
+            );
+        }
+
+        let data_type_validator = DataTypeValidator;
+        let closures = state
+            .data_types
+            .iter()
+            .map(|(data_type_id, record)| {
+                let resolve_data = ontology_type_resolver
+                    .resolve_data_type_metadata(DataTypeUuid::from_url(data_type_id))
+                    .change_context(UpdateError)?;
+                let closed_schema = data_type_validator
+                    .validate(
+                        ClosedDataType::from_resolve_data((*record.schema).clone(), &resolve_data)
+                            .change_context(UpdateError)?,
+                    )
+                    .change_context(UpdateError)?
+                    .into_inner();
+                Ok((data_type_id.clone(), resolve_data, closed_schema))
+            })
+            .collect::<Result<Vec<_>, Report<UpdateError>>>()?;
+
+        for (data_type_id, resolve_data, closed_schema) in closures {
+            let closure = &mut state
+                .data_types
+                .get_mut(&data_type_id)
+                .expect("data type should exist")
+                .closure;
+            closure.resolve_data = resolve_data;
+            closure.closed_schema = Arc::new(closed_schema);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,944 @@
+use alloc::sync::Arc;
+use core::iter::once;
+use std::collections::{HashMap, HashSet};
+
+use error_stack::{Report, ResultExt as _};
+use hash_graph_authorization::{
+    AuthorizationApi,
+    backend::ModifyRelationshipOperation,
+    schema::{
+        EntityTypeOwnerSubject, EntityTypePermission, EntityTypeRelationAndSubject, WebPermission,
+    },
+    zanzibar::Consistency,
+};
+use hash_graph_store::{
+    change_feed::GraphChangeKind,
+    entity_type::{
+        ArchiveEntityTypeParams, ClosedDataTypeDefinition, CountEntityTypesParams,
+        CreateEntityTypeParams, EntityTypeQueryPath, EntityTypeResolveDefinitions, EntityTypeStore,
+        GetClosedMultiEntityTypeParams, GetClosedMultiEntityTypeResponse,
+        GetEntityTypeSubgraphParams, GetEntityTypeSubgraphResponse, GetEntityTypesParams,
+        GetEntityTypesResponse, IncludeEntityTypeOption, UnarchiveEntityTypeParams,
+        UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams,
+    },
+    error::{InsertionError, QueryError, UpdateError},
+    filter::{Filter, FilterExpression, ParameterList},
+    subgraph::{
+        Subgraph, SubgraphRecord as _, identifier::GraphElementVertexId,
+        temporal_axes::QueryTemporalAxes,
+    },
+};
+use hash_graph_types::{
+    account::{AccountId, EditionArchivedById, EditionCreatedById},
+    ontology::{
+        EntityTypeMetadata, EntityTypeWithMetadata, OntologyEditionProvenance,
+        OntologyTemporalMetadata, OntologyTypeClassificationMetadata, OntologyTypeRecordId,
+    },
+};
+use hash_status::StatusCode;
+use type_system::{
+    Validator as _,
+    schema::{
+        ClosedDataType, ClosedEntityType, ClosedMultiEntityType, DataTypeUuid, EntityType,
+        EntityTypeUuid, EntityTypeValidator, OntologyTypeResolver, PartialEntityType, PropertyType,
+        PropertyTypeUuid,
+    },
+    url::{OntologyTypeVersion, VersionedUrl},
+};
+
+use crate::store::{
+    MemoryStore, ResponseCountMap,
+    filter::FilterContext,
+    ontology::{
+        PendingOntologyIds, archive_ontology_type, entity_type_with_metadata, initial_edition,
+        read_ontology_types, unarchive_ontology_type, update_ontology_embedding,
+    },
+    state::{EntityTypeClosure, EntityTypeRecord, MemoryState},
+    traversal::{
+        TraversalItem, permitted_entity_types, permitted_property_types, traverse_subgraph,
+    },
+    validation::{StoreProvider, current_temporal_axes},
+};
+
+/// Adds the entity types `entity_type_ids` which are visible to `actor_id` to `resolver`.
+async fn add_referenced_entity_types<A: AuthorizationApi>(
+    state: &MemoryState,
+    authorization_api: &A,
+    actor_id: AccountId,
+    resolver: &mut OntologyTypeResolver,
+    entity_type_ids: Vec<EntityTypeUuid>,
+) -> Result<(), Report<QueryError>> {
+    let temporal_axes = current_temporal_axes();
+    let context = FilterContext::new(state, &temporal_axes, false);
+
+    for entity_type_id in
+        permitted_entity_types(authorization_api, actor_id, entity_type_ids).await?
+    {
+        if let Some(entity_type) = context.entity_type_view(entity_type_id) {
+            resolver.add_closed_entity_type(
+                entity_type_id,
+                Arc::clone(&entity_type.record.schema),
+                Arc::clone(&entity_type.record.closure.resolve_data),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Collects the types required to resolve the closed schemas of `entity_types`.
+///
+/// This includes the link and link destination entity types, all property types reachable from
+/// the entity types, and the data types used by these property types. If
+/// `include_data_type_children` is set, the data types inheriting from these data types are
+/// included as well.
+pub(crate) async fn entity_type_resolve_definitions<A: AuthorizationApi>(
+    state: &MemoryState,
+    authorization_api: &A,
+    actor_id: AccountId,
+    entity_types: &[EntityTypeUuid],
+    include_data_type_children: bool,
+) -> Result<EntityTypeResolveDefinitions, Report<QueryError>> {
+    let mut definitions = EntityTypeResolveDefinitions::default();
+
+    let mut property_type_uuids = Vec::<PropertyTypeUuid>::new();
+    for entity_type_id in entity_types {
+        let Some(record) = state
+            .ontology_ids
+            .get(&(*entity_type_id).into())
+            .and_then(|url| state.entity_types.get(url))
+        else {
+            continue;
+        };
+
+        let resolve_data = &record.closure.resolve_data;
+        for (target_id, _) in resolve_data.links().chain(resolve_data.link_destinations()) {
+            if let Some((target_url, target)) = state
+                .ontology_ids
+                .get(&target_id.into())
+                .and_then(|url| state.entity_types.get_key_value(url))
+            {
+                definitions.entity_types.insert(
+                    target_url.clone(),
+                    PartialEntityType::from(EntityType::clone(&target.schema)),
+                );
+            }
+        }
+        property_type_uuids.extend(resolve_data.properties().map(|(id, _)| id));
+    }
+
+    let temporal_axes = current_temporal_axes();
+    let context = FilterContext::new(state, &temporal_axes, false);
+
+    let mut data_type_uuids = HashSet::new();
+    let mut property_type_queue =
+        permitted_property_types(authorization_api, actor_id, property_type_uuids).await?;
+    while let Some(property_type_id) = property_type_queue.pop() {
+        let Some(property_type) = context.property_type_view(property_type_id) else {
+            continue;
+        };
+        if definitions.property_types.contains_key(property_type.id) {
+            continue;
+        }
+
+        let schema = &property_type.record.schema;
+        data_type_uuids.extend(
+            schema
+                .data_type_references()
+                .into_iter()
+                .map(|reference| DataTypeUuid::from_url(&reference.url)),
+        );
+        property_type_queue.extend(
+            permitted_property_types(
+                authorization_api,
+                actor_id,
+                schema
+                    .property_type_references()
+                    .into_iter()
+                    .map(|reference| PropertyTypeUuid::from_url(&reference.url))
+                    .collect(),
+            )
+            .await?,
+        );
+        definitions
+            .property_types
+            .insert(property_type.id.clone(), PropertyType::clone(schema));
+    }
+
+    for (data_type_url, record) in &state.data_types {
+        let is_requested = data_type_uuids.contains(&DataTypeUuid::from_url(data_type_url))
+            || (include_data_type_children
+                && record
+                    .closure
+                    .resolve_data
+                    .inheritance_depths()
+                    .any(|(parent_id, _)| data_type_uuids.contains(&parent_id)));
+        if is_requested {
+            definitions
+                .data_types
+                .insert(data_type_url.clone(), ClosedDataTypeDefinition {
+                    schema: ClosedDataType::clone(&record.closure.closed_schema),
+                    parents: record
+                        .schema
+                        .all_of
+                        .iter()
+                        .map(|reference| reference.url.clone())
+                        .collect(),
+                });
+        }
+    }
+
+    Ok(definitions)
+}
+
+impl<A> MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    /// Removes all entity types from the store.
+    pub async fn delete_entity_types(&mut self) {
+        let mut state = self.state.write().await;
+        let records = core::mem::take(&mut state.entity_types);
+        state.remove_ontology_ids(records);
+    }
+
+    async fn get_entity_types_impl(
+        &self,
+        state: &MemoryState,
+        actor_id: AccountId,
+        params: GetEntityTypesParams<'_>,
+        temporal_axes: &QueryTemporalAxes,
+    ) -> Result<GetEntityTypesResponse, Report<QueryError>> {
+        let context = FilterContext::new(state, temporal_axes, params.include_drafts);
+
+        let (count, web_ids, edition_created_by_ids) = if params.include_count
+            || params.include_web_ids
+            || params.include_edition_created_by_ids
+        {
+            let mut web_ids = params.include_web_ids.then(ResponseCountMap::default);
+            let mut edition_created_by_ids = params
+                .include_edition_created_by_ids
+                .then(ResponseCountMap::default);
+
+            let entity_type_ids =
+                read_ontology_types(&context, &state.entity_types, &params.filter, None, None)?
+                    .into_iter()
+                    .map(|view| {
+                        if let (
+                            Some(web_ids),
+                            OntologyTypeClassificationMetadata::Owned { owned_by_id },
+                        ) = (&mut web_ids, &view.record.classification)
+                        {
+                            web_ids.increment(owned_by_id);
+                        }
+                        if let Some(edition_created_by_ids) = &mut edition_created_by_ids {
+                            edition_created_by_ids
+                                .increment(&view.edition.provenance.edition.created_by_id);
+                        }
+                        EntityTypeUuid::from_url(view.id)
+                    })
+                    .collect::<Vec<_>>();
+
+            let count = permitted_entity_types(&self.authorization_api, actor_id, entity_type_ids)
+                .await?
+                .len();
+            (
+                Some(count),
+                web_ids.map(HashMap::from),
+                edition_created_by_ids.map(HashMap::from),
+            )
+        } else {
+            (None, None, None)
+        };
+
+        let entity_types = read_ontology_types(
+            &context,
+            &state.entity_types,
+            &params.filter,
+            params.after.as_ref(),
+            params.limit,
+        )?;
+
+        let (permissions, _) = self
+            .authorization_api
+            .check_entity_types_permission(
+                actor_id,
+                EntityTypePermission::View,
+                entity_types
+                    .iter()
+                    .map(|view| EntityTypeUuid::from_url(view.id))
+                    .collect::<Vec<_>>(),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let entity_types = entity_types
+            .into_iter()
+            .filter(|view| {
+                permissions
+                    .get(&EntityTypeUuid::from_url(view.id))
+                    .copied()
+                    .unwrap_or(false)
+            })
+            .collect::<Vec<_>>();
+
+        let closed_entity_types = params.include_entity_types.map(|_| {
+            entity_types
+                .iter()
+                .map(|view| ClosedEntityType::clone(&view.record.closure.closed_schema))
+                .collect()
+        });
+        let definitions = match params.include_entity_types {
+            None | Some(IncludeEntityTypeOption::Closed) => None,
+            Some(IncludeEntityTypeOption::Resolved) => Some(
+                entity_type_resolve_definitions(
+                    state,
+                    &self.authorization_api,
+                    actor_id,
+                    &entity_types
+                        .iter()
+                        .map(|view| EntityTypeUuid::from_url(view.id))
+                        .collect::<Vec<_>>(),
+                    false,
+                )
+                .await?,
+            ),
+            Some(IncludeEntityTypeOption::ResolvedWithDataTypeChildren) => Some(
+                entity_type_resolve_definitions(
+                    state,
+                    &self.authorization_api,
+                    actor_id,
+                    &entity_types
+                        .iter()
+                        .map(|view| EntityTypeUuid::from_url(view.id))
+                        .collect::<Vec<_>>(),
+                    true,
+                )
+                .await?,
+            ),
+        };
+
+        let entity_types = entity_types
+            .into_iter()
+            .map(|view| entity_type_with_metadata(view.id, view.record, view.edition))
+            .collect::<Vec<_>>();
+
+        Ok(GetEntityTypesResponse {
+            cursor: if params.limit.is_some() {
+                entity_types
+                    .last()
+                    .map(|entity_type| entity_type.schema.id.clone())
+            } else {
+                None
+            },
+            entity_types,
+            closed_entity_types,
+            definitions,
+            count,
+            web_ids,
+            edition_created_by_ids,
+        })
+    }
+}
+
+impl<A> EntityTypeStore for MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn create_entity_types<P, R>(
+        &mut self,
+        actor_id: AccountId,
+        params: P,
+    ) -> Result<Vec<EntityTypeMetadata>, Report<InsertionError>>
+    where
+        P: IntoIterator<Item = CreateEntityTypeParams<R>, IntoIter: Send> + Send,
+        R: IntoIterator<Item = EntityTypeRelationAndSubject> + Send + Sync,
+    {
+        let mut state = self.state.write().await;
+        let mut pending = PendingOntologyIds::default();
+
+        let mut relationships = HashSet::new();
+        let mut inserted_entity_types = Vec::new();
+
+        for parameters in params {
+            let entity_type_id = EntityTypeUuid::from_url(&parameters.schema.id);
+
+            if let OntologyTypeClassificationMetadata::Owned { owned_by_id } =
+                &parameters.classification
+            {
+                self.authorization_api
+                    .check_web_permission(
+                        actor_id,
+                        WebPermission::CreateEntityType,
+                        *owned_by_id,
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(InsertionError)?
+                    .assert_permission()
+                    .change_context(InsertionError)?;
+
+                relationships.insert((entity_type_id, EntityTypeRelationAndSubject::Owner {
+                    subject: EntityTypeOwnerSubject::Web { id: *owned_by_id },
+                    level: 0,
+                }));
+            }
+
+            relationships.extend(
+                parameters
+                    .relationships
+                    .into_iter()
+                    .map(|relation_and_subject| (entity_type_id, relation_and_subject)),
+            );
+
+            if state.reserve_ontology_id(
+                &mut pending,
+                &parameters.schema.id,
+                &parameters.classification,
+                parameters.conflict_behavior,
+            )? {
+                let edition = initial_edition(OntologyEditionProvenance {
+                    created_by_id: EditionCreatedById::new(actor_id),
+                    archived_by_id: None,
+                    user_defined: parameters.provenance,
+                });
+                let metadata = EntityTypeMetadata {
+                    record_id: OntologyTypeRecordId::from(parameters.schema.id.clone()),
+                    classification: parameters.classification,
+                    temporal_versioning: edition.temporal_metadata(),
+                    provenance: edition.provenance.clone(),
+                };
+                inserted_entity_types.push((
+                    entity_type_id,
+                    Arc::new(parameters.schema),
+                    edition,
+                    metadata,
+                ));
+            }
+        }
+
+        let mut ontology_type_resolver = OntologyTypeResolver::default();
+        for (entity_type_id, schema, ..) in &inserted_entity_types {
+            ontology_type_resolver.add_unresolved_entity_type(*entity_type_id, Arc::clone(schema));
+        }
+
+        let reference_ids = inserted_entity_types
+            .iter()
+            .flat_map(|(_, schema, ..)| {
+                schema
+                    .entity_type_references()
+                    .map(|(reference, _)| EntityTypeUuid::from_url(&reference.url))
+            })
+            .filter(|reference_id| {
+                !inserted_entity_types
+                    .iter()
+                    .any(|(entity_type_id, ..)| entity_type_id == reference_id)
+            })
+            .collect::<HashSet<_>>();
+        add_referenced_entity_types(
+            &state,
+            &self.authorization_api,
+            actor_id,
+            &mut ontology_type_resolver,
+            reference_ids.into_iter().collect(),
+        )
+        .await
+        .change_context(InsertionError)
+        .attach_printable("Could not read parent entity types")?;
+
+        let entity_type_validator = EntityTypeValidator;
+        let mut records = Vec::with_capacity(inserted_entity_types.len());
+        for (entity_type_id, schema, edition, metadata) in inserted_entity_types {
+            let resolve_data = ontology_type_resolver
+                .resolve_entity_type_metadata(entity_type_id)
+                .change_context(InsertionError)?;
+            let closed_schema =
+                ClosedEntityType::from_resolve_data((*schema).clone(), &resolve_data)
+                    .change_context(InsertionError)?;
+
+            entity_type_validator
+                .validate_ref(&*schema)
+                .attach(StatusCode::InvalidArgument)
+                .change_context(InsertionError)?;
+            entity_type_validator
+                .validate_ref(&closed_schema)
+                .attach(StatusCode::InvalidArgument)
+                .change_context(InsertionError)?;
+
+            records.push((
+                EntityTypeRecord {
+                    schema,
+                    closure: EntityTypeClosure {
+                        closed_schema: Arc::new(closed_schema),
+                        resolve_data,
+                    },
+                    classification: metadata.classification.clone(),
+                    editions: vec![edition],
+                    embedding: None,
+                },
+                metadata,
+            ));
+        }
+
+        self.authorization_api
+            .modify_entity_type_relations(relationships.into_iter().map(
+                |(resource, relation_and_subject)| {
+                    (
+                        ModifyRelationshipOperation::Create,
+                        resource,
+                        relation_and_subject,
+                    )
+                },
+            ))
+            .await
+            .change_context(InsertionError)?;
+
+        state.commit_ontology_ids(pending);
+        let mut inserted_entity_types = Vec::with_capacity(records.len());
+        let mut inserted_entity_type_metadata = Vec::with_capacity(records.len());
+        for (record, metadata) in records {
+            inserted_entity_types.push(EntityTypeWithMetadata {
+                schema: EntityType::clone(&record.schema),
+                metadata: metadata.clone(),
+            });
+            state.entity_types.insert(record.schema.id.clone(), record);
+            inserted_entity_type_metadata.push(metadata);
+        }
+        drop(state);
+
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_entity_type_embeddings_workflow(actor_id, &inserted_entity_types)
+                .await
+                .change_context(InsertionError)?;
+        }
+
+        Ok(inserted_entity_type_metadata)
+    }
+
+    async fn count_entity_types(
+        &self,
+        actor_id: AccountId,
+        mut params: CountEntityTypesParams<'_>,
+    ) -> Result<usize, Report<QueryError>> {
+        let state = self.state.read().await;
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                state: &state,
+                authorization_api: &self.authorization_api,
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.resolve();
+        let context = FilterContext::new(&state, &temporal_axes, params.include_drafts);
+        Ok(read_ontology_types(&context, &state.entity_types, &params.filter, None, None)?.len())
+    }
+
+    async fn get_entity_types(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntityTypesParams<'_>,
+    ) -> Result<GetEntityTypesResponse, Report<QueryError>> {
+        let state = self.state.read().await;
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                state: &state,
+                authorization_api: &self.authorization_api,
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.clone().resolve();
+        self.get_entity_types_impl(&state, actor_id, params, &temporal_axes)
+            .await
+    }
+
+    async fn get_closed_multi_entity_types(
+        &self,
+        actor_id: AccountId,
+        params: GetClosedMultiEntityTypeParams,
+    ) -> Result<GetClosedMultiEntityTypeResponse, Report<QueryError>> {
+        let entity_type_ids = params
+            .entity_type_ids
+            .iter()
+            .map(EntityTypeUuid::from_url)
+            .collect::<Vec<_>>();
+        let response = self
+            .get_entity_types(actor_id, GetEntityTypesParams {
+                filter: Filter::In(
+                    FilterExpression::Path {
+                        path: EntityTypeQueryPath::OntologyId,
+                    },
+                    ParameterList::EntityTypeIds(&entity_type_ids),
+                ),
+                temporal_axes: params.temporal_axes,
+                include_drafts: params.include_drafts,
+                after: None,
+                limit: None,
+                include_count: false,
+                include_entity_types: Some(params.include_resolved.map_or(
+                    IncludeEntityTypeOption::Closed,
+                    IncludeEntityTypeOption::from,
+                )),
+                include_web_ids: false,
+                include_edition_created_by_ids: false,
+            })
+            .await?;
+
+        Ok(GetClosedMultiEntityTypeResponse {
+            entity_type: ClosedMultiEntityType::from_multi_type_closed_schema(
+                response
+                    .closed_entity_types
+                    .expect("Response should include closed entity types"),
+            )
+            .change_context(QueryError)?,
+            definitions: response.definitions,
+        })
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn get_entity_type_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntityTypeSubgraphParams<'_>,
+    ) -> Result<GetEntityTypeSubgraphResponse, Report<QueryError>> {
+        let state = self.state.read().await;
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                state: &state,
+                authorization_api: &self.authorization_api,
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.clone().resolve();
+        let time_axis = temporal_axes.variable_time_axis();
+
+        let GetEntityTypesResponse {
+            entity_types,
+            closed_entity_types: _,
+            definitions: _,
+            cursor,
+            count,
+            web_ids,
+            edition_created_by_ids,
+        } = self
+            .get_entity_types_impl(
+                &state,
+                actor_id,
+                GetEntityTypesParams {
+                    filter: params.filter,
+                    temporal_axes: params.temporal_axes.clone(),
+                    after: params.after,
+                    limit: params.limit,
+                    include_drafts: params.include_drafts,
+                    include_entity_types: None,
+                    include_count: params.include_count,
+                    include_web_ids: params.include_web_ids,
+                    include_edition_created_by_ids: params.include_edition_created_by_ids,
+                },
+                &temporal_axes,
+            )
+            .await?;
+
+        let mut subgraph = Subgraph::new(
+            params.graph_resolve_depths,
+            params.temporal_axes,
+            temporal_axes.clone(),
+        );
+
+        let traversal_queue = entity_types
+            .iter()
+            .map(|entity_type| {
+                (
+                    TraversalItem::EntityType(EntityTypeUuid::from_url(&entity_type.schema.id)),
+                    subgraph.depths,
+                    subgraph.temporal_axes.resolved.variable_interval(),
+                )
+            })
+            .collect();
+        subgraph.roots.extend(
+            entity_types
+                .iter()
+                .map(|entity_type| GraphElementVertexId::from(entity_type.vertex_id(time_axis))),
+        );
+        for entity_type in entity_types {
+            subgraph.insert_vertex(entity_type.vertex_id(time_axis), entity_type);
+        }
+
+        traverse_subgraph(
+            &FilterContext::new(&state, &temporal_axes, params.include_drafts),
+            &self.authorization_api,
+            actor_id,
+            traversal_queue,
+            &mut subgraph,
+        )
+        .await?;
+
+        Ok(GetEntityTypeSubgraphResponse {
+            subgraph,
+            cursor,
+            count,
+            web_ids,
+            edition_created_by_ids,
+        })
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_entity_type<R>(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdateEntityTypesParams<R>,
+    ) -> Result<EntityTypeMetadata, Report<UpdateError>>
+    where
+        R: IntoIterator<Item = EntityTypeRelationAndSubject> + Send + Sync,
+    {
+        let entity_type_validator = EntityTypeValidator;
+
+        let old_ontology_id = EntityTypeUuid::from_url(&VersionedUrl {
+            base_url: params.schema.id.base_url.clone(),
+            version: OntologyTypeVersion::new(
+                params
+                    .schema
+                    .id
+                    .version
+                    .inner()
+                    .checked_sub(1)
+                    .ok_or(UpdateError)
+                    .attach_printable(
+                        "The version of the data type is already at the lowest possible value",
+                    )?,
+            ),
+        });
+        let new_ontology_id = EntityTypeUuid::from_url(&params.schema.id);
+        self.authorization_api
+            .check_entity_type_permission(
+                actor_id,
+                EntityTypePermission::Update,
+                old_ontology_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)?;
+
+        let mut state = self.state.write().await;
+        let mut pending = PendingOntologyIds::default();
+
+        let schema = entity_type_validator
+            .validate(params.schema)
+            .change_context(UpdateError)?
+            .into_inner();
+        let owned_by_id =
+            state.reserve_updated_ontology_id(&mut pending, &state.entity_types, &schema.id)?;
+
+        let mut ontology_type_resolver = OntologyTypeResolver::default();
+        add_referenced_entity_types(
+            &state,
+            &self.authorization_api,
+            actor_id,
+            &mut ontology_type_resolver,
+            schema
+                .entity_type_references()
+                .map(|(reference, _)| EntityTypeUuid::from_url(&reference.url))
+                .filter(|reference_id| *reference_id != new_ontology_id)
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect(),
+        )
+        .await
+        .change_context(UpdateError)
+        .attach_printable("Could not read parent entity types")?;
+
+        let schema = Arc::new(schema);
+        ontology_type_resolver.add_unresolved_entity_type(new_ontology_id, Arc::clone(&schema));
+        let resolve_data = ontology_type_resolver
+            .resolve_entity_type_metadata(new_ontology_id)
+            .change_context(UpdateError)?;
+        let closed_schema = entity_type_validator
+            .validate(
+                ClosedEntityType::from_resolve_data((*schema).clone(), &resolve_data)
+                    .change_context(UpdateError)?,
+            )
+            .change_context(UpdateError)?
+            .into_inner();
+
+        self.authorization_api
+            .modify_entity_type_relations(
+                params
+                    .relationships
+                    .into_iter()
+                    .chain(once(EntityTypeRelationAndSubject::Owner {
+                        subject: EntityTypeOwnerSubject::Web { id: owned_by_id },
+                        level: 0,
+                    }))
+                    .map(|relation_and_subject| {
+                        (
+                            ModifyRelationshipOperation::Create,
+                            new_ontology_id,
+                            relation_and_subject,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        let edition = initial_edition(OntologyEditionProvenance {
+            created_by_id: EditionCreatedById::new(actor_id),
+            archived_by_id: None,
+            user_defined: params.provenance,
+        });
+        let metadata = EntityTypeMetadata {
+            record_id: OntologyTypeRecordId::from(schema.id.clone()),
+            classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+            temporal_versioning: edition.temporal_metadata(),
+            provenance: edition.provenance.clone(),
+        };
+
+        state.commit_ontology_ids(pending);
+        state
+            .entity_types
+            .insert(schema.id.clone(), EntityTypeRecord {
+                schema: Arc::clone(&schema),
+                closure: EntityTypeClosure {
+                    closed_schema: Arc::new(closed_schema),
+                    resolve_data,
+                },
+                classification: metadata.classification.clone(),
+                editions: vec![edition],
+                embedding: None,
+            });
+        drop(state);
+
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_entity_type_embeddings_workflow(actor_id, &[EntityTypeWithMetadata {
+                    schema: EntityType::clone(&schema),
+                    metadata: metadata.clone(),
+                }])
+                .await
+                .change_context(UpdateError)?;
+        }
+
+        Ok(metadata)
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn archive_entity_type(
+        &mut self,
+        actor_id: AccountId,
+        params: ArchiveEntityTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
+        let mut state = self.state.write().await;
+        let metadata = archive_ontology_type(
+            &mut state.entity_types,
+            &params.entity_type_id,
+            EditionArchivedById::new(actor_id),
+        )?;
+        state.publish_changes([GraphChangeKind::TypeArchived {
+            ontology_type_id: params.entity_type_id.into_owned(),
+        }]);
+
+        Ok(metadata)
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn unarchive_entity_type(
+        &mut self,
+        actor_id: AccountId,
+        params: UnarchiveEntityTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
+        let mut state = self.state.write().await;
+        let metadata = unarchive_ontology_type(
+            &mut state.entity_types,
+            &params.entity_type_id,
+            OntologyEditionProvenance {
+                created_by_id: EditionCreatedById::new(actor_id),
+                archived_by_id: None,
+                user_defined: params.provenance,
+            },
+        )?;
+        state.publish_changes([GraphChangeKind::TypeUnarchived {
+            ontology_type_id: params.entity_type_id.into_owned(),
+        }]);
+
+        Ok(metadata)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_entity_type_embeddings(
+        &mut self,
+        _: AccountId,
+        params: UpdateEntityTypeEmbeddingParams<'_>,
+    ) -> Result<(), Report<UpdateError>> {
+        // TODO: Add permission to allow updating embeddings
+        //   see https://linear.app/hash/issue/H-1870
+        update_ontology_embedding(
+            &mut self.state.write().await.entity_types,
+            &params.entity_type_id,
+            params.embedding,
+            params.updated_at_transaction_time,
+        );
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn reindex_entity_type_cache(&mut self) -> Result<(), Report<UpdateError>> {
+        tracing::info!("Reindexing entity type cache");
+        let mut state = self.state.write().await;
+
+        let mut ontology_type_resolver = OntologyTypeResolver::default();
+        for (entity_type_id, record) in &state.entity_types {
+            ontology_type_resolver.add_unresolved_entity_type(
+                EntityTypeUuid::from_url(entity_type_id),
+                Arc::clone(&record.schema),
+            );
+        }
+
+        let entity_type_validator = EntityTypeValidator;
+        let closures = state
+            .entity_types
+            .iter()
+            .map(|(entity_type_id, record)| {
+                let resolve_data = ontology_type_resolver
+                    .resolve_entity_type_metadata(EntityTypeUuid::from_url(entity_type_id))
+                    .change_context(UpdateError)?;
+                let closed_schema = entity_type_validator
+                    .validate(
+                        ClosedEntityType::from_resolve_data(
+                            (*record.schema).clone(),
+                            &resolve_data,
+                        )
+                        .change_context(UpdateError)?,
+                    )
+                    .change_context(UpdateError)?
+                    .into_inner();
+                Ok((entity_type_id.clone(), resolve_data, closed_schema))
+            })
+            .collect::<Result<Vec<_>, Report<UpdateError>>>()?;
+
+        for (entity_type_id, resolve_data, closed_schema) in closures {
+            let closure = &mut state
+                .entity_types
+                .get_mut(&entity_type_id)
+                .expect("entity type should exist")
+                .closure;
+            closure.resolve_data = resolve_data;
+            closure.closed_schema = Arc::new(closed_schema);
+        }
+
+        Ok(())
+    }
+}
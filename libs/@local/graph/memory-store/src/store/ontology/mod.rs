@@ -0,0 +1,416 @@
+mod data_type;
+mod entity_type;
+mod property_type;
+
+use core::ops::Bound;
+use std::collections::{BTreeMap, HashMap};
+
+use error_stack::{Report, ResultExt as _};
+use hash_graph_store::{
+    change_feed::GraphChangeKind,
+    error::{InsertionError, QueryError, UpdateError},
+    filter::Filter,
+    query::ConflictBehavior,
+};
+use hash_graph_temporal_versioning::{
+    ClosedTemporalBound, LeftClosedTemporalInterval, OpenTemporalBound, Timestamp, TransactionTime,
+};
+use hash_graph_types::{
+    Embedding,
+    account::EditionArchivedById,
+    ontology::{
+        DataTypeMetadata, DataTypeWithMetadata, EntityTypeMetadata, EntityTypeWithMetadata,
+        OntologyEditionProvenance, OntologyProvenance, OntologyTemporalMetadata,
+        OntologyTypeClassificationMetadata, OntologyTypeRecordId, PropertyTypeMetadata,
+        PropertyTypeWithMetadata,
+    },
+    owned_by_id::OwnedById,
+};
+use type_system::{
+    schema::{DataType, EntityType, OntologyTypeUuid, PropertyType},
+    url::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+};
+
+pub(crate) use self::entity_type::entity_type_resolve_definitions;
+use crate::store::{
+    error::{
+        BaseUrlAlreadyExists, OntologyTypeIsNotOwned, OntologyVersionDoesNotExist,
+        VersionedUrlAlreadyExists,
+    },
+    filter::{FilterContext, FilterRecord, OntologyView},
+    state::{
+        DataTypeClosure, EntityTypeClosure, MemoryState, OntologyEdition, OntologyEmbedding,
+        OntologyLocation, OntologyRecord, open_interval,
+    },
+};
+
+/// Ontology IDs which are reserved by an operation but not yet written to the [`MemoryState`].
+///
+/// Operations which insert multiple ontology types first validate all of them before any data is
+/// written, so a failing operation does not leave partially inserted types behind. Types which are
+/// inserted by the same operation must still conflict with each other, which is what this is
+/// tracking.
+#[derive(Debug, Default)]
+pub(crate) struct PendingOntologyIds {
+    base_urls: HashMap<BaseUrl, OntologyLocation>,
+    ontology_ids: HashMap<OntologyTypeUuid, VersionedUrl>,
+}
+
+impl MemoryState {
+    fn base_url_location(
+        &self,
+        pending: &PendingOntologyIds,
+        base_url: &BaseUrl,
+    ) -> Option<OntologyLocation> {
+        self.base_urls
+            .get(base_url)
+            .or_else(|| pending.base_urls.get(base_url))
+            .copied()
+    }
+
+    fn contains_ontology_id(&self, pending: &PendingOntologyIds, id: OntologyTypeUuid) -> bool {
+        self.ontology_ids.contains_key(&id) || pending.ontology_ids.contains_key(&id)
+    }
+
+    /// Reserves the base URL and the ontology ID of `ontology_id`.
+    ///
+    /// Returns `false` if the ontology type already exists and `on_conflict` is
+    /// [`ConflictBehavior::Skip`].
+    ///
+    /// # Errors
+    ///
+    /// - [`BaseUrlAlreadyExists`] if the [`BaseUrl`] already exists and `on_conflict` is
+    ///   [`ConflictBehavior::Fail`], or if it exists for a different location
+    /// - [`VersionedUrlAlreadyExists`] if the [`VersionedUrl`] already exists and `on_conflict` is
+    ///   [`ConflictBehavior::Fail`]
+    pub(crate) fn reserve_ontology_id(
+        &self,
+        pending: &mut PendingOntologyIds,
+        ontology_id: &VersionedUrl,
+        classification: &OntologyTypeClassificationMetadata,
+        on_conflict: ConflictBehavior,
+    ) -> Result<bool, Report<InsertionError>> {
+        let (location, base_url_conflict) = match classification {
+            OntologyTypeClassificationMetadata::Owned { .. } => {
+                (OntologyLocation::Owned, on_conflict)
+            }
+            OntologyTypeClassificationMetadata::External { .. } => {
+                (OntologyLocation::External, ConflictBehavior::Skip)
+            }
+        };
+
+        match (
+            self.base_url_location(pending, &ontology_id.base_url),
+            base_url_conflict,
+        ) {
+            (None, _) => {}
+            (Some(_), ConflictBehavior::Fail) => {
+                return Err(Report::new(BaseUrlAlreadyExists)
+                    .attach_printable(ontology_id.base_url.clone())
+                    .change_context(InsertionError));
+            }
+            (Some(existing_location), ConflictBehavior::Skip) => {
+                if existing_location != location {
+                    return Err(Report::new(BaseUrlAlreadyExists)
+                        .attach_printable(ontology_id.base_url.clone())
+                        .change_context(InsertionError));
+                }
+            }
+        }
+
+        let ontology_uuid = OntologyTypeUuid::from_url(ontology_id);
+        if self.contains_ontology_id(pending, ontology_uuid) {
+            return match on_conflict {
+                ConflictBehavior::Fail => Err(Report::new(VersionedUrlAlreadyExists)
+                    .attach_printable(ontology_id.clone())
+                    .change_context(InsertionError)),
+                ConflictBehavior::Skip => Ok(false),
+            };
+        }
+
+        pending
+            .base_urls
+            .entry(ontology_id.base_url.clone())
+            .or_insert(location);
+        pending
+            .ontology_ids
+            .insert(ontology_uuid, ontology_id.clone());
+        Ok(true)
+    }
+
+    /// Reserves the ontology ID for a new version of an owned ontology type.
+    ///
+    /// Returns the web the previous version is owned by.
+    ///
+    /// # Errors
+    ///
+    /// - [`VersionedUrlAlreadyExists`] if [`VersionedUrl`] does already exist
+    /// - [`OntologyVersionDoesNotExist`] if the previous version does not exist
+    /// - [`OntologyTypeIsNotOwned`] if ontology type is an external ontology type
+    pub(crate) fn reserve_updated_ontology_id<S, C>(
+        &self,
+        pending: &mut PendingOntologyIds,
+        records: &BTreeMap<VersionedUrl, OntologyRecord<S, C>>,
+        url: &VersionedUrl,
+    ) -> Result<OwnedById, Report<UpdateError>> {
+        let previous_version = OntologyTypeVersion::new(
+            url.version
+                .inner()
+                .checked_sub(1)
+                .ok_or(UpdateError)
+                .attach_printable(
+                    "The version of the data type is already at the lowest possible value",
+                )?,
+        );
+        let previous_url = VersionedUrl {
+            base_url: url.base_url.clone(),
+            version: previous_version,
+        };
+
+        let owned_by_id = match records
+            .get(&previous_url)
+            .map(|record| &record.classification)
+        {
+            Some(OntologyTypeClassificationMetadata::Owned { owned_by_id }) => *owned_by_id,
+            Some(OntologyTypeClassificationMetadata::External { .. }) => {
+                return Err(Report::new(OntologyTypeIsNotOwned)
+                    .attach_printable(url.clone())
+                    .change_context(UpdateError));
+            }
+            None => {
+                return Err(Report::new(OntologyVersionDoesNotExist)
+                    .attach_printable(url.clone())
+                    .change_context(UpdateError));
+            }
+        };
+
+        let ontology_uuid = OntologyTypeUuid::from_url(url);
+        if self.contains_ontology_id(pending, ontology_uuid) {
+            return Err(Report::new(VersionedUrlAlreadyExists)
+                .attach_printable(url.clone())
+                .change_context(InsertionError)
+                .change_context(UpdateError));
+        }
+        pending.ontology_ids.insert(ontology_uuid, url.clone());
+
+        Ok(owned_by_id)
+    }
+
+    /// Removes the base URLs and ontology IDs of the removed `records`.
+    pub(crate) fn remove_ontology_ids<S, C>(
+        &mut self,
+        records: BTreeMap<VersionedUrl, OntologyRecord<S, C>>,
+    ) {
+        for url in records.into_keys() {
+            self.ontology_ids.remove(&OntologyTypeUuid::from_url(&url));
+            self.base_urls.remove(&url.base_url);
+        }
+    }
+
+    /// Writes the reserved IDs to the state.
+    pub(crate) fn commit_ontology_ids(&mut self, pending: PendingOntologyIds) {
+        for (base_url, location) in pending.base_urls {
+            self.base_urls.entry(base_url).or_insert(location);
+        }
+        self.publish_changes(pending.ontology_ids.values().map(|ontology_type_id| {
+            GraphChangeKind::TypeCreated {
+                ontology_type_id: ontology_type_id.clone(),
+            }
+        }));
+        self.ontology_ids.extend(pending.ontology_ids);
+    }
+}
+
+/// Creates the first edition of a new ontology type.
+pub(crate) fn initial_edition(provenance: OntologyEditionProvenance) -> OntologyEdition {
+    OntologyEdition {
+        transaction_time: open_interval(Timestamp::now()),
+        provenance: OntologyProvenance {
+            edition: provenance,
+        },
+    }
+}
+
+/// Closes the transaction time of the current edition of the ontology type `id`.
+///
+/// # Errors
+///
+/// - [`VersionedUrlAlreadyExists`] if the ontology type is already archived
+/// - [`OntologyVersionDoesNotExist`] if the ontology type does not exist
+pub(crate) fn archive_ontology_type<S, C>(
+    records: &mut BTreeMap<VersionedUrl, OntologyRecord<S, C>>,
+    id: &VersionedUrl,
+    archived_by_id: EditionArchivedById,
+) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
+    let Some(record) = records.get_mut(id) else {
+        return Err(Report::new(OntologyVersionDoesNotExist)
+            .attach_printable(id.clone())
+            .change_context(UpdateError));
+    };
+    let Some(edition) = record.current_edition_mut() else {
+        return Err(Report::new(VersionedUrlAlreadyExists)
+            .attach_printable(id.clone())
+            .change_context(UpdateError));
+    };
+
+    let ClosedTemporalBound::Inclusive(start) = *edition.transaction_time.start();
+    edition.transaction_time = LeftClosedTemporalInterval::new(
+        ClosedTemporalBound::Inclusive(start),
+        OpenTemporalBound::Exclusive(Timestamp::now().max(start)),
+    );
+    edition.provenance.edition.archived_by_id = Some(archived_by_id);
+
+    Ok(edition.temporal_metadata())
+}
+
+/// Appends a new edition to the ontology type `id`.
+///
+/// # Errors
+///
+/// - [`VersionedUrlAlreadyExists`] if the ontology type is not archived
+/// - [`OntologyVersionDoesNotExist`] if the ontology type does not exist
+pub(crate) fn unarchive_ontology_type<S, C>(
+    records: &mut BTreeMap<VersionedUrl, OntologyRecord<S, C>>,
+    id: &VersionedUrl,
+    provenance: OntologyEditionProvenance,
+) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
+    let Some(record) = records.get_mut(id) else {
+        return Err(Report::new(OntologyVersionDoesNotExist)
+            .attach_printable(id.clone())
+            .change_context(UpdateError));
+    };
+    if record.current_edition().is_some() {
+        return Err(Report::new(VersionedUrlAlreadyExists)
+            .attach_printable(id.clone())
+            .change_context(UpdateError));
+    }
+
+    let edition = initial_edition(provenance);
+    let temporal_metadata = edition.temporal_metadata();
+    record.editions.push(edition);
+    Ok(temporal_metadata)
+}
+
+pub(crate) fn data_type_with_metadata(
+    id: &VersionedUrl,
+    record: &OntologyRecord<DataType, DataTypeClosure>,
+    edition: &OntologyEdition,
+) -> DataTypeWithMetadata {
+    DataTypeWithMetadata {
+        schema: DataType::clone(&record.schema),
+        metadata: DataTypeMetadata {
+            record_id: OntologyTypeRecordId::from(id.clone()),
+            classification: record.classification.clone(),
+            temporal_versioning: edition.temporal_metadata(),
+            provenance: edition.provenance.clone(),
+            conversions: record.closure.conversions.clone(),
+        },
+    }
+}
+
+pub(crate) fn property_type_with_metadata(
+    id: &VersionedUrl,
+    record: &OntologyRecord<PropertyType, ()>,
+    edition: &OntologyEdition,
+) -> PropertyTypeWithMetadata {
+    PropertyTypeWithMetadata {
+        schema: PropertyType::clone(&record.schema),
+        metadata: PropertyTypeMetadata {
+            record_id: OntologyTypeRecordId::from(id.clone()),
+            classification: record.classification.clone(),
+            temporal_versioning: edition.temporal_metadata(),
+            provenance: edition.provenance.clone(),
+        },
+    }
+}
+
+pub(crate) fn entity_type_with_metadata(
+    id: &VersionedUrl,
+    record: &OntologyRecord<EntityType, EntityTypeClosure>,
+    edition: &OntologyEdition,
+) -> EntityTypeWithMetadata {
+    EntityTypeWithMetadata {
+        schema: EntityType::clone(&record.schema),
+        metadata: EntityTypeMetadata {
+            record_id: OntologyTypeRecordId::from(id.clone()),
+            classification: record.classification.clone(),
+            temporal_versioning: edition.temporal_metadata(),
+            provenance: edition.provenance.clone(),
+        },
+    }
+}
+
+/// Returns the ontology types matching `filter` ordered by their [`VersionedUrl`].
+///
+/// Only types after `after` are returned and at most `limit` types are returned.
+///
+/// # Errors
+///
+/// - if a regular expression or `LIKE` pattern used by `filter` is invalid
+pub(crate) fn read_ontology_types<'s, S, C, R>(
+    context: &FilterContext<'s>,
+    records: &'s BTreeMap<VersionedUrl, OntologyRecord<S, C>>,
+    filter: &Filter<'_, R>,
+    after: Option<&VersionedUrl>,
+    limit: Option<usize>,
+) -> Result<Vec<OntologyView<'s, S, C>>, Report<QueryError>>
+where
+    R: for<'v> FilterRecord<View<'v> = OntologyView<'v, S, C>>,
+{
+    let lower_bound = after.map_or(Bound::Unbounded, Bound::Excluded);
+    let views = records
+        .range::<VersionedUrl, _>((lower_bound, Bound::Unbounded))
+        .filter_map(|(id, record)| {
+            Some(OntologyView {
+                id,
+                record,
+                edition: record.edition_at(context.temporal_axes)?,
+            })
+        });
+    context
+        .filter_views(filter, views)
+        .take(limit.unwrap_or(usize::MAX))
+        .collect()
+}
+
+/// Stores the embedding of the ontology type `id`.
+///
+/// Only the latest version of an ontology type keeps an embedding, the embeddings of earlier
+/// versions are removed. An embedding is not replaced by an embedding which was calculated
+/// earlier. If `id` is not the latest version, the embedding is discarded.
+pub(crate) fn update_ontology_embedding<S, C>(
+    records: &mut BTreeMap<VersionedUrl, OntologyRecord<S, C>>,
+    id: &VersionedUrl,
+    embedding: Embedding<'_>,
+    updated_at_transaction_time: Timestamp<TransactionTime>,
+) {
+    let is_latest_version = records
+        .range::<VersionedUrl, _>((Bound::Excluded(id), Bound::Unbounded))
+        .next()
+        .is_none_or(|(next, _)| next.base_url != id.base_url);
+    if !is_latest_version || !records.contains_key(id) {
+        return;
+    }
+
+    for (_, record) in records.range_mut::<VersionedUrl, _>((
+        Bound::Included(&VersionedUrl {
+            base_url: id.base_url.clone(),
+            version: OntologyTypeVersion::new(0),
+        }),
+        Bound::Excluded(id),
+    )) {
+        record.embedding = None;
+    }
+
+    let record = records.get_mut(id).expect("the ontology type should exist");
+    if record
+        .embedding
+        .as_ref()
+        .is_none_or(|existing| existing.updated_at_transaction_time <= updated_at_transaction_time)
+    {
+        record.embedding = Some(OntologyEmbedding {
+            embedding: embedding.into_owned(),
+            updated_at_transaction_time,
+        });
+    }
+}
@@ -0,0 +1,590 @@
+use alloc::sync::Arc;
+use core::iter::once;
+use std::collections::HashSet;
+
+use error_stack::{Report, ResultExt as _};
+use hash_graph_authorization::{
+    AuthorizationApi,
+    backend::ModifyRelationshipOperation,
+    schema::{
+        PropertyTypeOwnerSubject, PropertyTypePermission, PropertyTypeRelationAndSubject,
+        WebPermission,
+    },
+    zanzibar::Consistency,
+};
+use hash_graph_store::{
+    change_feed::GraphChangeKind,
+    error::{InsertionError, QueryError, UpdateError},
+    property_type::{
+        ArchivePropertyTypeParams, CountPropertyTypesParams, CreatePropertyTypeParams,
+        GetPropertyTypeSubgraphParams, GetPropertyTypeSubgraphResponse, GetPropertyTypesParams,
+        GetPropertyTypesResponse, PropertyTypeStore, UnarchivePropertyTypeParams,
+        UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams,
+    },
+    subgraph::{
+        Subgraph, SubgraphRecord as _, identifier::GraphElementVertexId,
+        temporal_axes::QueryTemporalAxes,
+    },
+};
+use hash_graph_types::{
+    account::{AccountId, EditionArchivedById, EditionCreatedById},
+    ontology::{
+        OntologyEditionProvenance, OntologyTemporalMetadata, OntologyTypeClassificationMetadata,
+        OntologyTypeRecordId, PropertyTypeMetadata, PropertyTypeWithMetadata,
+    },
+};
+use hash_status::StatusCode;
+use type_system::{
+    Validator as _,
+    schema::{OntologyTypeUuid, PropertyType, PropertyTypeUuid, PropertyTypeValidator},
+    url::{OntologyTypeVersion, VersionedUrl},
+};
+
+use crate::store::{
+    MemoryStore,
+    filter::FilterContext,
+    ontology::{
+        PendingOntologyIds, archive_ontology_type, initial_edition, property_type_with_metadata,
+        read_ontology_types, unarchive_ontology_type, update_ontology_embedding,
+    },
+    state::{MemoryState, PropertyTypeRecord},
+    traversal::{TraversalItem, traverse_subgraph},
+    validation::StoreProvider,
+};
+
+impl MemoryState {
+    /// Checks that all types referenced by `property_type` exist or are about to be inserted.
+    fn ensure_property_type_references(
+        &self,
+        pending: &PendingOntologyIds,
+        property_type: &PropertyType,
+    ) -> Result<(), Report<InsertionError>> {
+        let references = property_type
+            .data_type_references()
+            .into_iter()
+            .map(|reference| &reference.url)
+            .chain(
+                property_type
+                    .property_type_references()
+                    .into_iter()
+                    .map(|reference| &reference.url),
+            );
+
+        for reference in references {
+            if !self.contains_ontology_id(pending, OntologyTypeUuid::from_url(reference)) {
+                return Err(Report::new(InsertionError).attach_printable(format!(
+                    "could not insert references for property type `{}`: `{reference}` does not \
+                     exist",
+                    property_type.id
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<A> MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    /// Removes all property types from the store.
+    pub async fn delete_property_types(&mut self) {
+        let mut state = self.state.write().await;
+        let records = core::mem::take(&mut state.property_types);
+        state.remove_ontology_ids(records);
+    }
+
+    async fn get_property_types_impl(
+        &self,
+        state: &MemoryState,
+        actor_id: AccountId,
+        params: GetPropertyTypesParams<'_>,
+        temporal_axes: &QueryTemporalAxes,
+    ) -> Result<GetPropertyTypesResponse, Report<QueryError>> {
+        let context = FilterContext::new(state, temporal_axes, params.include_drafts);
+
+        let count = params
+            .include_count
+            .then(|| {
+                read_ontology_types(&context, &state.property_types, &params.filter, None, None)
+                    .map(|types| types.len())
+            })
+            .transpose()?;
+
+        let property_types = read_ontology_types(
+            &context,
+            &state.property_types,
+            &params.filter,
+            params.after.as_ref(),
+            params.limit,
+        )?;
+
+        let (permissions, _) = self
+            .authorization_api
+            .check_property_types_permission(
+                actor_id,
+                PropertyTypePermission::View,
+                property_types
+                    .iter()
+                    .map(|view| PropertyTypeUuid::from_url(view.id))
+                    .collect::<Vec<_>>(),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let property_types = property_types
+            .into_iter()
+            .filter(|view| {
+                permissions
+                    .get(&PropertyTypeUuid::from_url(view.id))
+                    .copied()
+                    .unwrap_or(false)
+            })
+            .map(|view| property_type_with_metadata(view.id, view.record, view.edition))
+            .collect::<Vec<_>>();
+
+        Ok(GetPropertyTypesResponse {
+            cursor: if params.limit.is_some() {
+                property_types
+                    .last()
+                    .map(|property_type| property_type.schema.id.clone())
+            } else {
+                None
+            },
+            property_types,
+            count,
+        })
+    }
+}
+
+impl<A> PropertyTypeStore for MemoryStore<A>
+where
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn create_property_types<P, R>(
+        &mut self,
+        actor_id: AccountId,
+        params: P,
+    ) -> Result<Vec<PropertyTypeMetadata>, Report<InsertionError>>
+    where
+        P: IntoIterator<Item = CreatePropertyTypeParams<R>, IntoIter: Send> + Send,
+        R: IntoIterator<Item = PropertyTypeRelationAndSubject> + Send + Sync,
+    {
+        let mut state = self.state.write().await;
+        let mut pending = PendingOntologyIds::default();
+
+        let property_type_validator = PropertyTypeValidator;
+        let mut relationships = HashSet::new();
+        let mut inserted_property_types = Vec::new();
+
+        for parameters in params {
+            let property_type_id = PropertyTypeUuid::from_url(&parameters.schema.id);
+
+            if let OntologyTypeClassificationMetadata::Owned { owned_by_id } =
+                &parameters.classification
+            {
+                self.authorization_api
+                    .check_web_permission(
+                        actor_id,
+                        WebPermission::CreatePropertyType,
+                        *owned_by_id,
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(InsertionError)?
+                    .assert_permission()
+                    .change_context(InsertionError)?;
+
+                relationships.insert((property_type_id, PropertyTypeRelationAndSubject::Owner {
+                    subject: PropertyTypeOwnerSubject::Web { id: *owned_by_id },
+                    level: 0,
+                }));
+            }
+
+            relationships.extend(
+                parameters
+                    .relationships
+                    .into_iter()
+                    .map(|relation_and_subject| (property_type_id, relation_and_subject)),
+            );
+
+            if state.reserve_ontology_id(
+                &mut pending,
+                &parameters.schema.id,
+                &parameters.classification,
+                parameters.conflict_behavior,
+            )? {
+                property_type_validator
+                    .validate_ref(&parameters.schema)
+                    .attach(StatusCode::InvalidArgument)
+                    .change_context(InsertionError)?;
+
+                let edition = initial_edition(OntologyEditionProvenance {
+                    created_by_id: EditionCreatedById::new(actor_id),
+                    archived_by_id: None,
+                    user_defined: parameters.provenance,
+                });
+                let metadata = PropertyTypeMetadata {
+                    record_id: OntologyTypeRecordId::from(parameters.schema.id.clone()),
+                    classification: parameters.classification,
+                    temporal_versioning: edition.temporal_metadata(),
+                    provenance: edition.provenance.clone(),
+                };
+                inserted_property_types.push((parameters.schema, edition, metadata));
+            }
+        }
+
+        for (schema, ..) in &inserted_property_types {
+            state.ensure_property_type_references(&pending, schema)?;
+        }
+
+        self.authorization_api
+            .modify_property_type_relations(relationships.into_iter().map(
+                |(resource, relation_and_subject)| {
+                    (
+                        ModifyRelationshipOperation::Create,
+                        resource,
+                        relation_and_subject,
+                    )
+                },
+            ))
+            .await
+            .change_context(InsertionError)?;
+
+        state.commit_ontology_ids(pending);
+        let mut inserted_property_types_with_metadata =
+            Vec::with_capacity(inserted_property_types.len());
+        for (schema, edition, metadata) in inserted_property_types {
+            state
+                .property_types
+                .insert(schema.id.clone(), PropertyTypeRecord {
+                    schema: Arc::new(schema.clone()),
+                    closure: (),
+                    classification: metadata.classification.clone(),
+                    editions: vec![edition],
+                    embedding: None,
+                });
+            inserted_property_types_with_metadata
+                .push(PropertyTypeWithMetadata { schema, metadata });
+        }
+        drop(state);
+
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_property_type_embeddings_workflow(
+                    actor_id,
+                    &inserted_property_types_with_metadata,
+                )
+                .await
+                .change_context(InsertionError)?;
+        }
+
+        Ok(inserted_property_types_with_metadata
+            .into_iter()
+            .map(|property_type| property_type.metadata)
+            .collect())
+    }
+
+    async fn count_property_types(
+        &self,
+        actor_id: AccountId,
+        mut params: CountPropertyTypesParams<'_>,
+    ) -> Result<usize, Report<QueryError>> {
+        let state = self.state.read().await;
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                state: &state,
+                authorization_api: &self.authorization_api,
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.resolve();
+        let context = FilterContext::new(&state, &temporal_axes, params.include_drafts);
+        Ok(read_ontology_types(&context, &state.property_types, &params.filter, None, None)?.len())
+    }
+
+    async fn get_property_types(
+        &self,
+        actor_id: AccountId,
+        mut params: GetPropertyTypesParams<'_>,
+    ) -> Result<GetPropertyTypesResponse, Report<QueryError>> {
+        let state = self.state.read().await;
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                state: &state,
+                authorization_api: &self.authorization_api,
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.clone().resolve();
+        self.get_property_types_impl(&state, actor_id, params, &temporal_axes)
+            .await
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn get_property_type_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetPropertyTypeSubgraphParams<'_>,
+    ) -> Result<GetPropertyTypeSubgraphResponse, Report<QueryError>> {
+        let state = self.state.read().await;
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                state: &state,
+                authorization_api: &self.authorization_api,
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.clone().resolve();
+        let time_axis = temporal_axes.variable_time_axis();
+
+        let GetPropertyTypesResponse {
+            property_types,
+            cursor,
+            count,
+        } = self
+            .get_property_types_impl(
+                &state,
+                actor_id,
+                GetPropertyTypesParams {
+                    filter: params.filter,
+                    temporal_axes: params.temporal_axes.clone(),
+                    after: params.after,
+                    limit: params.limit,
+                    include_drafts: params.include_drafts,
+                    include_count: params.include_count,
+                },
+                &temporal_axes,
+            )
+            .await?;
+
+        let mut subgraph = Subgraph::new(
+            params.graph_resolve_depths,
+            params.temporal_axes,
+            temporal_axes.clone(),
+        );
+
+        let traversal_queue = property_types
+            .iter()
+            .map(|property_type| {
+                (
+                    TraversalItem::PropertyType(PropertyTypeUuid::from_url(
+                        &property_type.schema.id,
+                    )),
+                    subgraph.depths,
+                    subgraph.temporal_axes.resolved.variable_interval(),
+                )
+            })
+            .collect();
+        subgraph.roots.extend(
+            property_types.iter().map(|property_type| {
+                GraphElementVertexId::from(property_type.vertex_id(time_axis))
+            }),
+        );
+        for property_type in property_types {
+            subgraph.insert_vertex(property_type.vertex_id(time_axis), property_type);
+        }
+
+        traverse_subgraph(
+            &FilterContext::new(&state, &temporal_axes, params.include_drafts),
+            &self.authorization_api,
+            actor_id,
+            traversal_queue,
+            &mut subgraph,
+        )
+        .await?;
+
+        Ok(GetPropertyTypeSubgraphResponse {
+            subgraph,
+            cursor,
+            count,
+        })
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_property_type<R>(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdatePropertyTypesParams<R>,
+    ) -> Result<PropertyTypeMetadata, Report<UpdateError>>
+    where
+        R: IntoIterator<Item = PropertyTypeRelationAndSubject> + Send + Sync,
+    {
+        let property_type_validator = PropertyTypeValidator;
+
+        let old_ontology_id = PropertyTypeUuid::from_url(&VersionedUrl {
+            base_url: params.schema.id.base_url.clone(),
+            version: OntologyTypeVersion::new(
+                params
+                    .schema
+                    .id
+                    .version
+                    .inner()
+                    .checked_sub(1)
+                    .ok_or(UpdateError)
+                    .attach_printable(
+                        "The version of the data type is already at the lowest possible value",
+                    )?,
+            ),
+        });
+        self.authorization_api
+            .check_property_type_permission(
+                actor_id,
+                PropertyTypePermission::Update,
+                old_ontology_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)
+            .attach_printable(old_ontology_id.into_uuid())?;
+
+        let mut state = self.state.write().await;
+        let mut pending = PendingOntologyIds::default();
+
+        property_type_validator
+            .validate_ref(&params.schema)
+            .change_context(UpdateError)?;
+        let owned_by_id = state.reserve_updated_ontology_id(
+            &mut pending,
+            &state.property_types,
+            &params.schema.id,
+        )?;
+        state
+            .ensure_property_type_references(&pending, &params.schema)
+            .change_context(UpdateError)?;
+
+        let property_type_id = PropertyTypeUuid::from_url(&params.schema.id);
+        self.authorization_api
+            .modify_property_type_relations(
+                params
+                    .relationships
+                    .into_iter()
+                    .chain(once(PropertyTypeRelationAndSubject::Owner {
+                        subject: PropertyTypeOwnerSubject::Web { id: owned_by_id },
+                        level: 0,
+                    }))
+                    .map(|relation_and_subject| {
+                        (
+                            ModifyRelationshipOperation::Create,
+                            property_type_id,
+                            relation_and_subject,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        let edition = initial_edition(OntologyEditionProvenance {
+            created_by_id: EditionCreatedById::new(actor_id),
+            archived_by_id: None,
+            user_defined: params.provenance,
+        });
+        let metadata = PropertyTypeMetadata {
+            record_id: OntologyTypeRecordId::from(params.schema.id.clone()),
+            classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+            temporal_versioning: edition.temporal_metadata(),
+            provenance: edition.provenance.clone(),
+        };
+
+        state.commit_ontology_ids(pending);
+        state
+            .property_types
+            .insert(params.schema.id.clone(), PropertyTypeRecord {
+                schema: Arc::new(params.schema.clone()),
+                closure: (),
+                classification: metadata.classification.clone(),
+                editions: vec![edition],
+                embedding: None,
+            });
+        drop(state);
+
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_property_type_embeddings_workflow(actor_id, &[
+                    PropertyTypeWithMetadata {
+                        schema: params.schema,
+                        metadata: metadata.clone(),
+                    },
+                ])
+                .await
+                .change_context(UpdateError)?;
+        }
+
+        Ok(metadata)
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn archive_property_type(
+        &mut self,
+        actor_id: AccountId,
+        params: ArchivePropertyTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
+        let mut state = self.state.write().await;
+        let metadata = archive_ontology_type(
+            &mut state.property_types,
+            &params.property_type_id,
+            EditionArchivedById::new(actor_id),
+        )?;
+        state.publish_changes([GraphChangeKind::TypeArchived {
+            ontology_type_id: params.property_type_id.into_owned(),
+        }]);
+
+        Ok(metadata)
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn unarchive_property_type(
+        &mut self,
+        actor_id: AccountId,
+        params: UnarchivePropertyTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
+        let mut state = self.state.write().await;
+        let metadata = unarchive_ontology_type(
+            &mut state.property_types,
+            &params.property_type_id,
+            OntologyEditionProvenance {
+                created_by_id: EditionCreatedById::new(actor_id),
+                archived_by_id: None,
+                user_defined: params.provenance,
+            },
+        )?;
+        state.publish_changes([GraphChangeKind::TypeUnarchived {
+            ontology_type_id: params.property_type_id.into_owned(),
+        }]);
+
+        Ok(metadata)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_property_type_embeddings(
+        &mut self,
+        _: AccountId,
+        params: UpdatePropertyTypeEmbeddingParams<'_>,
+    ) -> Result<(), Report<UpdateError>> {
+        // TODO: Add permission to allow updating embeddings
+        //   see https://linear.app/hash/issue/H-1870
+        update_ontology_embedding(
+            &mut self.state.write().await.property_types,
+            &params.property_type_id,
+            params.embedding,
+            params.updated_at_transaction_time,
+        );
+
+        Ok(())
+    }
+}
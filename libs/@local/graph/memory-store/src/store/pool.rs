@@ -0,0 +1,55 @@
+use alloc::sync::Arc;
+
+use error_stack::Report;
+use hash_graph_authorization::AuthorizationApi;
+use hash_graph_store::pool::StorePool;
+use hash_temporal_client::TemporalClient;
+use tokio::sync::RwLock;
+
+use crate::store::{MemoryStore, MemoryStoreSettings, StoreError, state::MemoryState};
+
+/// A pool of [`MemoryStore`]s sharing the same in-memory data.
+///
+/// Cloning the pool does not copy the data, the clone refers to the same state.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryStorePool {
+    state: Arc<RwLock<MemoryState>>,
+    pub settings: MemoryStoreSettings,
+}
+
+impl MemoryStorePool {
+    /// Creates a new, empty `MemoryStorePool`.
+    #[must_use]
+    pub fn new(settings: MemoryStoreSettings) -> Self {
+        Self {
+            state: Arc::default(),
+            settings,
+        }
+    }
+}
+
+impl StorePool for MemoryStorePool {
+    type Error = StoreError;
+    type Store<'pool, A: AuthorizationApi> = MemoryStore<A>;
+
+    async fn acquire<A: AuthorizationApi>(
+        &self,
+        authorization_api: A,
+        temporal_client: Option<Arc<TemporalClient>>,
+    ) -> Result<Self::Store<'_, A>, Report<Self::Error>> {
+        self.acquire_owned(authorization_api, temporal_client).await
+    }
+
+    async fn acquire_owned<A: AuthorizationApi>(
+        &self,
+        authorization_api: A,
+        temporal_client: Option<Arc<TemporalClient>>,
+    ) -> Result<Self::Store<'static, A>, Report<Self::Error>> {
+        Ok(MemoryStore::new(
+            Arc::clone(&self.state),
+            authorization_api,
+            temporal_client,
+            self.settings.clone(),
+        ))
+    }
+}
@@ -0,0 +1,225 @@
+use alloc::sync::Arc;
+use core::ops::Bound;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use hash_graph_store::{
+    change_feed::{GraphChange, GraphChangeId, GraphChangeKind},
+    subgraph::temporal_axes::QueryTemporalAxes,
+};
+use hash_graph_temporal_versioning::{
+    ClosedTemporalBound, DecisionTime, LeftClosedTemporalInterval, OpenTemporalBound, Timestamp,
+    TransactionTime,
+};
+use hash_graph_types::{
+    Embedding,
+    account::{AccountGroupId, AccountId},
+    knowledge::entity::{Entity, EntityId, EntityUuid},
+    ontology::{OntologyProvenance, OntologyTemporalMetadata, OntologyTypeClassificationMetadata},
+    owned_by_id::OwnedById,
+};
+use type_system::{
+    schema::{
+        ClosedDataType, ClosedEntityType, Conversions, DataType, DataTypeResolveData, EntityType,
+        EntityTypeResolveData, OntologyTypeUuid, PropertyType,
+    },
+    url::{BaseUrl, VersionedUrl},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum OntologyLocation {
+    Owned,
+    External,
+}
+
+/// A single edition of an ontology type.
+///
+/// Archiving an ontology type closes the transaction time of the current edition, unarchiving it
+/// appends a new edition.
+#[derive(Debug, Clone)]
+pub(crate) struct OntologyEdition {
+    pub transaction_time: LeftClosedTemporalInterval<TransactionTime>,
+    pub provenance: OntologyProvenance,
+}
+
+impl OntologyEdition {
+    pub(crate) fn is_current(&self) -> bool {
+        matches!(self.transaction_time.end(), OpenTemporalBound::Unbounded)
+    }
+
+    pub(crate) fn is_visible(&self, temporal_axes: &QueryTemporalAxes) -> bool {
+        match temporal_axes {
+            QueryTemporalAxes::DecisionTime { pinned, .. } => {
+                self.transaction_time.contains_point(&pinned.timestamp)
+            }
+            QueryTemporalAxes::TransactionTime { variable, .. } => {
+                self.transaction_time.overlaps(&variable.interval)
+            }
+        }
+    }
+
+    pub(crate) fn temporal_metadata(&self) -> OntologyTemporalMetadata {
+        OntologyTemporalMetadata {
+            transaction_time: self.transaction_time,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct OntologyEmbedding {
+    pub embedding: Embedding<'static>,
+    pub updated_at_transaction_time: Timestamp<TransactionTime>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct OntologyRecord<S, C> {
+    pub schema: Arc<S>,
+    pub closure: C,
+    pub classification: OntologyTypeClassificationMetadata,
+    pub editions: Vec<OntologyEdition>,
+    pub embedding: Option<OntologyEmbedding>,
+}
+
+impl<S, C> OntologyRecord<S, C> {
+    /// Returns the most recent edition which is visible at the provided temporal axes.
+    pub(crate) fn edition_at(&self, temporal_axes: &QueryTemporalAxes) -> Option<&OntologyEdition> {
+        self.editions
+            .iter()
+            .rev()
+            .find(|edition| edition.is_visible(temporal_axes))
+    }
+
+    pub(crate) fn current_edition(&self) -> Option<&OntologyEdition> {
+        self.editions.last().filter(|edition| edition.is_current())
+    }
+
+    pub(crate) fn current_edition_mut(&mut self) -> Option<&mut OntologyEdition> {
+        self.editions
+            .last_mut()
+            .filter(|edition| edition.is_current())
+    }
+
+    pub(crate) fn latest_edition(&self) -> &OntologyEdition {
+        self.editions
+            .last()
+            .expect("an ontology type should have at least one edition")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct DataTypeClosure {
+    pub closed_schema: Arc<ClosedDataType>,
+    pub resolve_data: Arc<DataTypeResolveData>,
+    pub conversions: HashMap<BaseUrl, Conversions>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct EntityTypeClosure {
+    pub closed_schema: Arc<ClosedEntityType>,
+    pub resolve_data: Arc<EntityTypeResolveData>,
+}
+
+pub(crate) type DataTypeRecord = OntologyRecord<DataType, DataTypeClosure>;
+pub(crate) type PropertyTypeRecord = OntologyRecord<PropertyType, ()>;
+pub(crate) type EntityTypeRecord = OntologyRecord<EntityType, EntityTypeClosure>;
+
+#[derive(Debug, Clone)]
+pub(crate) struct EntityEmbeddingRecord {
+    pub entity_id: EntityId,
+    pub property: Option<BaseUrl>,
+    pub embedding: Embedding<'static>,
+    pub updated_at_transaction_time: Timestamp<TransactionTime>,
+    pub updated_at_decision_time: Timestamp<DecisionTime>,
+}
+
+/// The complete data held by a [`MemoryStorePool`].
+///
+/// Entities are stored as a list of editions per [`EntityUuid`], where every edition covers a
+/// distinct region of the bitemporal plane, mirroring the rows of `entity_temporal_metadata` in
+/// the Postgres store.
+///
+/// [`MemoryStorePool`]: crate::store::MemoryStorePool
+#[derive(Debug, Default)]
+pub(crate) struct MemoryState {
+    pub accounts: HashSet<AccountId>,
+    pub account_groups: HashSet<AccountGroupId>,
+    pub webs: HashSet<OwnedById>,
+
+    pub base_urls: HashMap<BaseUrl, OntologyLocation>,
+    pub ontology_ids: HashMap<OntologyTypeUuid, VersionedUrl>,
+    pub data_types: BTreeMap<VersionedUrl, DataTypeRecord>,
+    pub property_types: BTreeMap<VersionedUrl, PropertyTypeRecord>,
+    pub entity_types: BTreeMap<VersionedUrl, EntityTypeRecord>,
+
+    pub entities: HashMap<EntityUuid, Vec<Entity>>,
+    pub entity_embeddings: Vec<EntityEmbeddingRecord>,
+
+    pub changes: Vec<GraphChange>,
+}
+
+impl MemoryState {
+    /// Appends `changes` to the change feed.
+    pub(crate) fn publish_changes(&mut self, changes: impl IntoIterator<Item = GraphChangeKind>) {
+        let transaction_time = Timestamp::now();
+        for change in changes {
+            let id = self
+                .changes
+                .last()
+                .map_or(1, |previous| previous.id.into_inner() + 1);
+            self.changes.push(GraphChange {
+                id: GraphChangeId::new(id),
+                transaction_time,
+                change,
+            });
+        }
+    }
+}
+
+/// Returns if there is no later version of `id` visible at the provided temporal axes.
+pub(crate) fn is_latest_version<S, C>(
+    records: &BTreeMap<VersionedUrl, OntologyRecord<S, C>>,
+    id: &VersionedUrl,
+    temporal_axes: &QueryTemporalAxes,
+) -> bool {
+    records
+        .range::<VersionedUrl, _>((Bound::Excluded(id), Bound::Unbounded))
+        .take_while(|(other, _)| other.base_url == id.base_url)
+        .all(|(_, record)| record.edition_at(temporal_axes).is_none())
+}
+
+/// Returns if the entity edition is visible at the provided temporal axes.
+pub(crate) fn is_entity_visible(
+    entity: &Entity,
+    temporal_axes: &QueryTemporalAxes,
+    include_drafts: bool,
+) -> bool {
+    if !include_drafts && entity.metadata.record_id.entity_id.draft_id.is_some() {
+        return false;
+    }
+
+    let temporal_versioning = &entity.metadata.temporal_versioning;
+    match temporal_axes {
+        QueryTemporalAxes::DecisionTime { pinned, variable } => {
+            temporal_versioning
+                .transaction_time
+                .contains_point(&pinned.timestamp)
+                && temporal_versioning
+                    .decision_time
+                    .overlaps(&variable.interval)
+        }
+        QueryTemporalAxes::TransactionTime { pinned, variable } => {
+            temporal_versioning
+                .decision_time
+                .contains_point(&pinned.timestamp)
+                && temporal_versioning
+                    .transaction_time
+                    .overlaps(&variable.interval)
+        }
+    }
+}
+
+pub(crate) fn open_interval<A>(start: Timestamp<A>) -> LeftClosedTemporalInterval<A> {
+    LeftClosedTemporalInterval::new(
+        ClosedTemporalBound::Inclusive(start),
+        OpenTemporalBound::Unbounded,
+    )
+}
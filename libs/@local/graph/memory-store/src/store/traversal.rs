@@ -0,0 +1,542 @@
+use std::collections::HashMap;
+
+use error_stack::{Report, ResultExt as _};
+use hash_graph_authorization::{
+    AuthorizationApi,
+    schema::{DataTypePermission, EntityPermission, EntityTypePermission, PropertyTypePermission},
+    zanzibar::Consistency,
+};
+use hash_graph_store::{
+    error::QueryError,
+    subgraph::{
+        Subgraph, SubgraphRecord as _,
+        edges::{
+            EdgeDirection, GraphResolveDepths, KnowledgeGraphEdgeKind, OntologyEdgeKind,
+            SharedEdgeKind,
+        },
+        identifier::{
+            DataTypeVertexId, EntityIdWithInterval, EntityTypeVertexId, EntityVertexId,
+            PropertyTypeVertexId,
+        },
+        temporal_axes::VariableAxis,
+    },
+};
+use hash_graph_temporal_versioning::{
+    LeftClosedTemporalInterval, RightBoundedTemporalInterval, TemporalInterval,
+    TemporalTagged as _, TimeAxis,
+};
+use hash_graph_types::{
+    account::AccountId,
+    knowledge::entity::{Entity, EntityId},
+};
+use type_system::{
+    schema::{DataTypeUuid, EntityTypeToEntityTypeEdge, EntityTypeUuid, PropertyTypeUuid},
+    url::VersionedUrl,
+};
+
+use crate::store::{
+    filter::FilterContext,
+    ontology::{data_type_with_metadata, entity_type_with_metadata, property_type_with_metadata},
+};
+
+/// A vertex which is reached while resolving a [`Subgraph`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum TraversalItem {
+    DataType(DataTypeUuid),
+    PropertyType(PropertyTypeUuid),
+    EntityType(EntityTypeUuid),
+    Entity(EntityVertexId),
+}
+
+type TraversalQueue = Vec<(
+    TraversalItem,
+    GraphResolveDepths,
+    RightBoundedTemporalInterval<VariableAxis>,
+)>;
+
+fn data_type_vertex_id(id: &VersionedUrl) -> DataTypeVertexId {
+    DataTypeVertexId {
+        base_id: id.base_url.clone(),
+        revision_id: id.version,
+    }
+}
+
+fn property_type_vertex_id(id: &VersionedUrl) -> PropertyTypeVertexId {
+    PropertyTypeVertexId {
+        base_id: id.base_url.clone(),
+        revision_id: id.version,
+    }
+}
+
+fn entity_type_vertex_id(id: &VersionedUrl) -> EntityTypeVertexId {
+    EntityTypeVertexId {
+        base_id: id.base_url.clone(),
+        revision_id: id.version,
+    }
+}
+
+/// Returns the interval of the entity edition on the variable axis of `context`.
+fn entity_interval(
+    context: &FilterContext<'_>,
+    entity: &Entity,
+) -> LeftClosedTemporalInterval<VariableAxis> {
+    let temporal_versioning = &entity.metadata.temporal_versioning;
+    match context.temporal_axes.variable_time_axis() {
+        TimeAxis::DecisionTime => temporal_versioning.decision_time.cast(),
+        TimeAxis::TransactionTime => temporal_versioning.transaction_time.cast(),
+    }
+}
+
+fn intersect_interval(
+    interval: RightBoundedTemporalInterval<VariableAxis>,
+    other: LeftClosedTemporalInterval<VariableAxis>,
+) -> Option<RightBoundedTemporalInterval<VariableAxis>> {
+    let interval: TemporalInterval<VariableAxis> = interval.convert();
+    interval
+        .intersect(other.convert())
+        .map(TemporalInterval::convert)
+}
+
+pub(crate) async fn permitted_data_types<A: AuthorizationApi>(
+    authorization_api: &A,
+    actor_id: AccountId,
+    ids: Vec<DataTypeUuid>,
+) -> Result<Vec<DataTypeUuid>, Report<QueryError>> {
+    if ids.is_empty() {
+        return Ok(ids);
+    }
+
+    let (permissions, _) = authorization_api
+        .check_data_types_permission(
+            actor_id,
+            DataTypePermission::View,
+            ids.iter().copied(),
+            Consistency::FullyConsistent,
+        )
+        .await
+        .change_context(QueryError)?;
+    Ok(ids
+        .into_iter()
+        .filter(|id| permissions.get(id).copied().unwrap_or(false))
+        .collect())
+}
+
+pub(crate) async fn permitted_property_types<A: AuthorizationApi>(
+    authorization_api: &A,
+    actor_id: AccountId,
+    ids: Vec<PropertyTypeUuid>,
+) -> Result<Vec<PropertyTypeUuid>, Report<QueryError>> {
+    if ids.is_empty() {
+        return Ok(ids);
+    }
+
+    let (permissions, _) = authorization_api
+        .check_property_types_permission(
+            actor_id,
+            PropertyTypePermission::View,
+            ids.iter().copied(),
+            Consistency::FullyConsistent,
+        )
+        .await
+        .change_context(QueryError)?;
+    Ok(ids
+        .into_iter()
+        .filter(|id| permissions.get(id).copied().unwrap_or(false))
+        .collect())
+}
+
+pub(crate) async fn permitted_entity_types<A: AuthorizationApi>(
+    authorization_api: &A,
+    actor_id: AccountId,
+    ids: Vec<EntityTypeUuid>,
+) -> Result<Vec<EntityTypeUuid>, Report<QueryError>> {
+    if ids.is_empty() {
+        return Ok(ids);
+    }
+
+    let (permissions, _) = authorization_api
+        .check_entity_types_permission(
+            actor_id,
+            EntityTypePermission::View,
+            ids.iter().copied(),
+            Consistency::FullyConsistent,
+        )
+        .await
+        .change_context(QueryError)?;
+    Ok(ids
+        .into_iter()
+        .filter(|id| permissions.get(id).copied().unwrap_or(false))
+        .collect())
+}
+
+/// Returns the visible editions of the entities connected to `entity` by `edge_kind`.
+fn linked_entities<'s>(
+    context: &FilterContext<'s>,
+    entity: &Entity,
+    edge_kind: KnowledgeGraphEdgeKind,
+    direction: EdgeDirection,
+) -> Vec<&'s Entity> {
+    let endpoint = |entity: &Entity| {
+        entity.link_data.as_ref().map(|link_data| match edge_kind {
+            KnowledgeGraphEdgeKind::HasLeftEntity => link_data.left_entity_id,
+            KnowledgeGraphEdgeKind::HasRightEntity => link_data.right_entity_id,
+        })
+    };
+
+    match direction {
+        EdgeDirection::Outgoing => endpoint(entity)
+            .map(|target_id| context.entity_views_by_id(target_id).collect())
+            .unwrap_or_default(),
+        EdgeDirection::Incoming => {
+            let source_id = entity.metadata.record_id.entity_id;
+            context
+                .entity_views()
+                .filter(|link| {
+                    endpoint(link).is_some_and(|target_id| {
+                        target_id.owned_by_id == source_id.owned_by_id
+                            && target_id.entity_uuid == source_id.entity_uuid
+                    })
+                })
+                .collect()
+        }
+    }
+}
+
+/// Resolves the edges of the items in `queue` and adds the reached vertices to `subgraph`.
+///
+/// This mirrors the traversal of the Postgres store: ontology types only follow their outgoing
+/// edges to directly referenced types, entities follow their type edges and their link edges in
+/// both directions. Every reached vertex is only added if the actor is permitted to view it.
+pub(crate) async fn traverse_subgraph<A: AuthorizationApi>(
+    context: &FilterContext<'_>,
+    authorization_api: &A,
+    actor_id: AccountId,
+    mut queue: TraversalQueue,
+    subgraph: &mut Subgraph,
+) -> Result<(), Report<QueryError>> {
+    let time_axis = context.temporal_axes.variable_time_axis();
+    let mut visited = HashMap::<
+        TraversalItem,
+        Vec<(
+            GraphResolveDepths,
+            RightBoundedTemporalInterval<VariableAxis>,
+        )>,
+    >::new();
+
+    while let Some((item, depths, interval)) = queue.pop() {
+        let visits = visited.entry(item.clone()).or_default();
+        if visits.iter().any(|(visited_depths, visited_interval)| {
+            visited_depths.contains(depths) && visited_interval.contains_interval(&interval)
+        }) {
+            continue;
+        }
+        visits.push((depths, interval));
+
+        match item {
+            TraversalItem::DataType(data_type_id) => {
+                let Some(view) = context.data_type_view(data_type_id) else {
+                    continue;
+                };
+                let vertex_id = data_type_vertex_id(view.id);
+                subgraph.insert_vertex(
+                    vertex_id.clone(),
+                    data_type_with_metadata(view.id, view.record, view.edition),
+                );
+
+                if let Some(depths) = depths.decrement_depth_for_edge(
+                    OntologyEdgeKind::InheritsFrom,
+                    EdgeDirection::Outgoing,
+                ) {
+                    let parents = view
+                        .record
+                        .schema
+                        .data_type_references()
+                        .map(|(reference, _)| DataTypeUuid::from_url(&reference.url))
+                        .collect();
+                    for parent_id in
+                        permitted_data_types(authorization_api, actor_id, parents).await?
+                    {
+                        let Some(parent) = context.data_type_view(parent_id) else {
+                            continue;
+                        };
+                        subgraph.insert_edge(
+                            &vertex_id,
+                            OntologyEdgeKind::InheritsFrom,
+                            EdgeDirection::Outgoing,
+                            data_type_vertex_id(parent.id),
+                        );
+                        queue.push((TraversalItem::DataType(parent_id), depths, interval));
+                    }
+                }
+            }
+            TraversalItem::PropertyType(property_type_id) => {
+                let Some(view) = context.property_type_view(property_type_id) else {
+                    continue;
+                };
+                let vertex_id = property_type_vertex_id(view.id);
+                subgraph.insert_vertex(
+                    vertex_id.clone(),
+                    property_type_with_metadata(view.id, view.record, view.edition),
+                );
+
+                if let Some(depths) = depths.decrement_depth_for_edge(
+                    OntologyEdgeKind::ConstrainsValuesOn,
+                    EdgeDirection::Outgoing,
+                ) {
+                    let data_types = view
+                        .record
+                        .schema
+                        .data_type_references()
+                        .into_iter()
+                        .map(|reference| DataTypeUuid::from_url(&reference.url))
+                        .collect();
+                    for data_type_id in
+                        permitted_data_types(authorization_api, actor_id, data_types).await?
+                    {
+                        let Some(data_type) = context.data_type_view(data_type_id) else {
+                            continue;
+                        };
+                        subgraph.insert_edge(
+                            &vertex_id,
+                            OntologyEdgeKind::ConstrainsValuesOn,
+                            EdgeDirection::Outgoing,
+                            data_type_vertex_id(data_type.id),
+                        );
+                        queue.push((TraversalItem::DataType(data_type_id), depths, interval));
+                    }
+                }
+
+                if let Some(depths) = depths.decrement_depth_for_edge(
+                    OntologyEdgeKind::ConstrainsPropertiesOn,
+                    EdgeDirection::Outgoing,
+                ) {
+                    let property_types = view
+                        .record
+                        .schema
+                        .property_type_references()
+                        .into_iter()
+                        .map(|reference| PropertyTypeUuid::from_url(&reference.url))
+                        .collect();
+                    for target_id in
+                        permitted_property_types(authorization_api, actor_id, property_types)
+                            .await?
+                    {
+                        let Some(property_type) = context.property_type_view(target_id) else {
+                            continue;
+                        };
+                        subgraph.insert_edge(
+                            &vertex_id,
+                            OntologyEdgeKind::ConstrainsPropertiesOn,
+                            EdgeDirection::Outgoing,
+                            property_type_vertex_id(property_type.id),
+                        );
+                        queue.push((TraversalItem::PropertyType(target_id), depths, interval));
+                    }
+                }
+            }
+            TraversalItem::EntityType(entity_type_id) => {
+                let Some(view) = context.entity_type_view(entity_type_id) else {
+                    continue;
+                };
+                let vertex_id = entity_type_vertex_id(view.id);
+                subgraph.insert_vertex(
+                    vertex_id.clone(),
+                    entity_type_with_metadata(view.id, view.record, view.edition),
+                );
+
+                if let Some(depths) = depths.decrement_depth_for_edge(
+                    OntologyEdgeKind::ConstrainsPropertiesOn,
+                    EdgeDirection::Outgoing,
+                ) {
+                    let property_types = view
+                        .record
+                        .schema
+                        .property_type_references()
+                        .map(|(reference, _)| PropertyTypeUuid::from_url(&reference.url))
+                        .collect();
+                    for property_type_id in
+                        permitted_property_types(authorization_api, actor_id, property_types)
+                            .await?
+                    {
+                        let Some(property_type) = context.property_type_view(property_type_id)
+                        else {
+                            continue;
+                        };
+                        subgraph.insert_edge(
+                            &vertex_id,
+                            OntologyEdgeKind::ConstrainsPropertiesOn,
+                            EdgeDirection::Outgoing,
+                            property_type_vertex_id(property_type.id),
+                        );
+                        queue.push((
+                            TraversalItem::PropertyType(property_type_id),
+                            depths,
+                            interval,
+                        ));
+                    }
+                }
+
+                for (edge_kind, reference_kind) in [
+                    (
+                        OntologyEdgeKind::InheritsFrom,
+                        EntityTypeToEntityTypeEdge::Inheritance,
+                    ),
+                    (
+                        OntologyEdgeKind::ConstrainsLinksOn,
+                        EntityTypeToEntityTypeEdge::Link,
+                    ),
+                    (
+                        OntologyEdgeKind::ConstrainsLinkDestinationsOn,
+                        EntityTypeToEntityTypeEdge::LinkDestination,
+                    ),
+                ] {
+                    let Some(depths) =
+                        depths.decrement_depth_for_edge(edge_kind, EdgeDirection::Outgoing)
+                    else {
+                        continue;
+                    };
+
+                    let mut entity_types = view
+                        .record
+                        .schema
+                        .entity_type_references()
+                        .filter(|(_, kind)| *kind == reference_kind)
+                        .map(|(reference, _)| EntityTypeUuid::from_url(&reference.url))
+                        .collect::<Vec<_>>();
+                    entity_types.sort_unstable_by_key(|id| id.into_uuid());
+                    entity_types.dedup();
+
+                    for target_id in
+                        permitted_entity_types(authorization_api, actor_id, entity_types).await?
+                    {
+                        let Some(entity_type) = context.entity_type_view(target_id) else {
+                            continue;
+                        };
+                        subgraph.insert_edge(
+                            &vertex_id,
+                            edge_kind,
+                            EdgeDirection::Outgoing,
+                            entity_type_vertex_id(entity_type.id),
+                        );
+                        queue.push((TraversalItem::EntityType(target_id), depths, interval));
+                    }
+                }
+            }
+            TraversalItem::Entity(vertex_id) => {
+                let Some(entity) = context
+                    .entity_views_by_id(vertex_id.base_id)
+                    .find(|entity| entity.vertex_id(time_axis) == vertex_id)
+                else {
+                    continue;
+                };
+                subgraph.insert_vertex(vertex_id, entity.clone());
+
+                if let Some(depths) = depths
+                    .decrement_depth_for_edge(SharedEdgeKind::IsOfType, EdgeDirection::Outgoing)
+                {
+                    let entity_types = entity
+                        .metadata
+                        .entity_type_ids
+                        .iter()
+                        .map(EntityTypeUuid::from_url)
+                        .collect();
+                    for entity_type_id in
+                        permitted_entity_types(authorization_api, actor_id, entity_types).await?
+                    {
+                        let Some(entity_type) = context.entity_type_view(entity_type_id) else {
+                            continue;
+                        };
+                        subgraph.insert_edge(
+                            &vertex_id,
+                            SharedEdgeKind::IsOfType,
+                            EdgeDirection::Outgoing,
+                            entity_type_vertex_id(entity_type.id),
+                        );
+                        queue.push((TraversalItem::EntityType(entity_type_id), depths, interval));
+                    }
+                }
+
+                let source_interval = entity_interval(context, entity);
+                for (edge_kind, direction) in [
+                    (
+                        KnowledgeGraphEdgeKind::HasLeftEntity,
+                        EdgeDirection::Incoming,
+                    ),
+                    (
+                        KnowledgeGraphEdgeKind::HasRightEntity,
+                        EdgeDirection::Incoming,
+                    ),
+                    (
+                        KnowledgeGraphEdgeKind::HasLeftEntity,
+                        EdgeDirection::Outgoing,
+                    ),
+                    (
+                        KnowledgeGraphEdgeKind::HasRightEntity,
+                        EdgeDirection::Outgoing,
+                    ),
+                ] {
+                    let Some(depths) = depths.decrement_depth_for_edge(edge_kind, direction) else {
+                        continue;
+                    };
+
+                    let edges = linked_entities(context, entity, edge_kind, direction)
+                        .into_iter()
+                        .filter_map(|target| {
+                            let edge_interval =
+                                source_interval.intersect(entity_interval(context, target))?;
+                            let traversal_interval = intersect_interval(interval, edge_interval)?;
+                            Some((target, edge_interval, traversal_interval))
+                        })
+                        .collect::<Vec<_>>();
+                    if edges.is_empty() {
+                        continue;
+                    }
+
+                    let (permissions, _) = authorization_api
+                        .check_entities_permission(
+                            actor_id,
+                            EntityPermission::View,
+                            edges
+                                .iter()
+                                .map(|(target, ..)| target.metadata.record_id.entity_id)
+                                .collect::<Vec<_>>(),
+                            Consistency::FullyConsistent,
+                        )
+                        .await
+                        .change_context(QueryError)?;
+
+                    for (target, edge_interval, traversal_interval) in edges {
+                        let target_id = target.metadata.record_id.entity_id;
+                        if !permissions
+                            .get(&target_id.entity_uuid)
+                            .copied()
+                            .unwrap_or(true)
+                        {
+                            continue;
+                        }
+
+                        subgraph.insert_edge(
+                            &vertex_id,
+                            edge_kind,
+                            direction,
+                            EntityIdWithInterval {
+                                entity_id: EntityId {
+                                    draft_id: None,
+                                    ..target_id
+                                },
+                                interval: edge_interval,
+                            },
+                        );
+                        queue.push((
+                            TraversalItem::Entity(target.vertex_id(time_axis)),
+                            depths,
+                            traversal_interval,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
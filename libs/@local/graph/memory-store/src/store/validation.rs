@@ -0,0 +1,323 @@
+use alloc::sync::Arc;
+use core::borrow::Borrow;
+
+use error_stack::{Report, ResultExt as _};
+use hash_graph_authorization::{
+    AuthorizationApi,
+    schema::{DataTypePermission, EntityPermission, EntityTypePermission, PropertyTypePermission},
+    zanzibar::Consistency,
+};
+use hash_graph_store::{
+    error::QueryError,
+    subgraph::temporal_axes::{
+        PinnedTemporalAxisUnresolved, QueryTemporalAxes, QueryTemporalAxesUnresolved,
+        VariableTemporalAxisUnresolved,
+    },
+};
+use hash_graph_types::{
+    account::AccountId,
+    knowledge::entity::{Entity, EntityId},
+    ontology::{DataTypeLookup, DataTypeWithMetadata, OntologyTypeProvider},
+};
+use hash_graph_validation::EntityProvider;
+use type_system::{
+    schema::{
+        ClosedDataType, ClosedEntityType, ConversionExpression, DataTypeReference, DataTypeUuid,
+        EntityTypeUuid, PropertyType, PropertyTypeUuid,
+    },
+    url::{BaseUrl, VersionedUrl},
+};
+
+use crate::store::{
+    ontology::data_type_with_metadata,
+    state::{MemoryState, is_entity_visible},
+};
+
+/// Provides the types and entities of a [`MemoryState`] to the validation and conversion logic.
+///
+/// Unlike the Postgres provider, no cache is required as all lookups are served from memory.
+#[derive(Debug)]
+pub(crate) struct StoreProvider<'a, A> {
+    pub state: &'a MemoryState,
+    pub authorization_api: &'a A,
+    pub authorization: Option<(AccountId, Consistency<'static>)>,
+}
+
+pub(crate) fn current_temporal_axes() -> QueryTemporalAxes {
+    QueryTemporalAxesUnresolved::DecisionTime {
+        pinned: PinnedTemporalAxisUnresolved::new(None),
+        variable: VariableTemporalAxisUnresolved::new(None, None),
+    }
+    .resolve()
+}
+
+impl<A> StoreProvider<'_, A>
+where
+    A: AuthorizationApi,
+{
+    async fn authorize_data_type(&self, type_id: DataTypeUuid) -> Result<(), Report<QueryError>> {
+        if let Some((actor_id, consistency)) = self.authorization {
+            self.authorization_api
+                .check_data_type_permission(
+                    actor_id,
+                    DataTypePermission::View,
+                    type_id,
+                    consistency,
+                )
+                .await
+                .change_context(QueryError)?
+                .assert_permission()
+                .change_context(QueryError)?;
+        }
+
+        Ok(())
+    }
+
+    async fn authorize_property_type(
+        &self,
+        type_id: PropertyTypeUuid,
+    ) -> Result<(), Report<QueryError>> {
+        if let Some((actor_id, consistency)) = self.authorization {
+            self.authorization_api
+                .check_property_type_permission(
+                    actor_id,
+                    PropertyTypePermission::View,
+                    type_id,
+                    consistency,
+                )
+                .await
+                .change_context(QueryError)?
+                .assert_permission()
+                .change_context(QueryError)?;
+        }
+
+        Ok(())
+    }
+
+    async fn authorize_entity_type(
+        &self,
+        type_id: EntityTypeUuid,
+    ) -> Result<(), Report<QueryError>> {
+        if let Some((actor_id, consistency)) = self.authorization {
+            self.authorization_api
+                .check_entity_type_permission(
+                    actor_id,
+                    EntityTypePermission::View,
+                    type_id,
+                    consistency,
+                )
+                .await
+                .change_context(QueryError)?
+                .assert_permission()
+                .change_context(QueryError)?;
+        }
+
+        Ok(())
+    }
+
+    fn data_type_url(
+        &self,
+        data_type_uuid: DataTypeUuid,
+    ) -> Result<&VersionedUrl, Report<QueryError>> {
+        self.state
+            .ontology_ids
+            .get(&data_type_uuid.into())
+            .filter(|url| self.state.data_types.contains_key(*url))
+            .ok_or_else(|| {
+                Report::new(QueryError).attach_printable(format!(
+                    "Data type `{}` does not exist",
+                    data_type_uuid.into_uuid()
+                ))
+            })
+    }
+}
+
+impl<A> DataTypeLookup for StoreProvider<'_, A>
+where
+    A: AuthorizationApi,
+{
+    type ClosedDataType = Arc<ClosedDataType>;
+    type DataTypeWithMetadata = Arc<DataTypeWithMetadata>;
+    type Error = QueryError;
+
+    async fn lookup_data_type_by_uuid(
+        &self,
+        data_type_uuid: DataTypeUuid,
+    ) -> Result<Arc<DataTypeWithMetadata>, Report<QueryError>> {
+        self.authorize_data_type(data_type_uuid).await?;
+
+        let url = self.data_type_url(data_type_uuid)?;
+        let record = &self.state.data_types[url];
+        let edition = record.edition_at(&current_temporal_axes()).ok_or_else(|| {
+            Report::new(QueryError).attach_printable(format!("Data type `{url}` is archived"))
+        })?;
+
+        Ok(Arc::new(data_type_with_metadata(url, record, edition)))
+    }
+
+    async fn lookup_closed_data_type_by_uuid(
+        &self,
+        data_type_uuid: DataTypeUuid,
+    ) -> Result<Arc<ClosedDataType>, Report<QueryError>> {
+        self.authorize_data_type(data_type_uuid).await?;
+
+        let url = self.data_type_url(data_type_uuid)?;
+        Ok(Arc::clone(
+            &self.state.data_types[url].closure.closed_schema,
+        ))
+    }
+
+    async fn is_parent_of(
+        &self,
+        child: &DataTypeReference,
+        parent: &BaseUrl,
+    ) -> Result<bool, Report<QueryError>> {
+        let Some(record) = self.state.data_types.get(&child.url) else {
+            return Ok(false);
+        };
+
+        Ok(record
+            .closure
+            .resolve_data
+            .inheritance_depths()
+            .filter_map(|(parent_id, _)| self.state.ontology_ids.get(&parent_id.into()))
+            .any(|parent_url| parent_url.base_url == *parent))
+    }
+
+    async fn find_conversion(
+        &self,
+        source: &DataTypeReference,
+        target: &DataTypeReference,
+    ) -> Result<impl Borrow<Vec<ConversionExpression>>, Report<QueryError>> {
+        let source_conversions = self
+            .state
+            .data_types
+            .get(&source.url)
+            .map(|record| &record.closure.conversions);
+        let target_conversions = self
+            .state
+            .data_types
+            .get(&target.url)
+            .map(|record| &record.closure.conversions);
+
+        let mut candidates = Vec::new();
+        if let (Some(source_conversions), Some(target_conversions)) =
+            (source_conversions, target_conversions)
+        {
+            for (base_url, source_conversion) in source_conversions {
+                if let Some(target_conversion) = target_conversions.get(base_url) {
+                    candidates.push(vec![
+                        source_conversion.to.expression.clone(),
+                        target_conversion.from.expression.clone(),
+                    ]);
+                }
+            }
+        }
+        if let Some(conversion) =
+            source_conversions.and_then(|conversions| conversions.get(&target.url.base_url))
+        {
+            candidates.push(vec![conversion.to.expression.clone()]);
+        }
+        if let Some(conversion) =
+            target_conversions.and_then(|conversions| conversions.get(&source.url.base_url))
+        {
+            candidates.push(vec![conversion.from.expression.clone()]);
+        }
+
+        if candidates.len() != 1 {
+            return Err(Report::new(QueryError).attach_printable(format!(
+                "Found none or more than one conversions between `{}` and `{}`",
+                source.url, target.url
+            )));
+        }
+
+        Ok(candidates
+            .pop()
+            .expect("there should be exactly one candidate"))
+    }
+}
+
+impl<A> OntologyTypeProvider<PropertyType> for StoreProvider<'_, A>
+where
+    A: AuthorizationApi,
+{
+    type Value = Arc<PropertyType>;
+
+    #[expect(refining_impl_trait)]
+    async fn provide_type(
+        &self,
+        type_id: &VersionedUrl,
+    ) -> Result<Arc<PropertyType>, Report<QueryError>> {
+        self.authorize_property_type(PropertyTypeUuid::from_url(type_id))
+            .await?;
+
+        self.state
+            .property_types
+            .get(type_id)
+            .filter(|record| record.edition_at(&current_temporal_axes()).is_some())
+            .map(|record| Arc::clone(&record.schema))
+            .ok_or_else(|| {
+                Report::new(QueryError)
+                    .attach_printable(format!("Property type `{type_id}` does not exist"))
+            })
+    }
+}
+
+impl<A> OntologyTypeProvider<ClosedEntityType> for StoreProvider<'_, A>
+where
+    A: AuthorizationApi,
+{
+    type Value = Arc<ClosedEntityType>;
+
+    #[expect(refining_impl_trait)]
+    async fn provide_type(
+        &self,
+        type_id: &VersionedUrl,
+    ) -> Result<Arc<ClosedEntityType>, Report<QueryError>> {
+        self.authorize_entity_type(EntityTypeUuid::from_url(type_id))
+            .await?;
+
+        self.state
+            .entity_types
+            .get(type_id)
+            .filter(|record| record.edition_at(&current_temporal_axes()).is_some())
+            .map(|record| Arc::clone(&record.closure.closed_schema))
+            .ok_or_else(|| {
+                Report::new(QueryError)
+                    .attach_printable(format!("Entity type `{type_id}` does not exist"))
+            })
+    }
+}
+
+impl<A> EntityProvider for StoreProvider<'_, A>
+where
+    A: AuthorizationApi,
+{
+    #[expect(refining_impl_trait)]
+    async fn provide_entity(&self, entity_id: EntityId) -> Result<Entity, Report<QueryError>> {
+        if let Some((actor_id, consistency)) = self.authorization {
+            self.authorization_api
+                .check_entity_permission(actor_id, EntityPermission::View, entity_id, consistency)
+                .await
+                .change_context(QueryError)?
+                .assert_permission()
+                .change_context(QueryError)?;
+        }
+
+        let temporal_axes = current_temporal_axes();
+        self.state
+            .entities
+            .get(&entity_id.entity_uuid)
+            .into_iter()
+            .flatten()
+            .find(|entity| {
+                entity.metadata.record_id.entity_id == entity_id
+                    && is_entity_visible(entity, &temporal_axes, entity_id.draft_id.is_some())
+            })
+            .cloned()
+            .ok_or_else(|| {
+                Report::new(QueryError)
+                    .attach_printable(format!("Entity `{entity_id}` does not exist"))
+            })
+    }
+}
@@ -0,0 +1 @@
+DROP TABLE graph_changes;
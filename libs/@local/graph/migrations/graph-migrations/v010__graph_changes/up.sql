@@ -0,0 +1,5 @@
+CREATE TABLE graph_changes (
+    change_id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    transaction_time TIMESTAMPTZ NOT NULL DEFAULT now(),
+    change JSONB NOT NULL
+);
@@ -0,0 +1 @@
+DROP INDEX entity_editions_full_text_idx;
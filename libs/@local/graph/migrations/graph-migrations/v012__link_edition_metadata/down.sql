@@ -0,0 +1,46 @@
+ALTER TABLE entity_has_left_entity
+    ADD COLUMN provenance JSONB,
+    ADD COLUMN confidence DOUBLE PRECISION;
+
+ALTER TABLE entity_has_right_entity
+    ADD COLUMN provenance JSONB,
+    ADD COLUMN confidence DOUBLE PRECISION;
+
+-- Only the latest edition of the link metadata can be kept.
+UPDATE entity_has_left_entity
+SET confidence = latest.left_entity_confidence,
+    provenance = latest.left_entity_provenance
+FROM (
+    SELECT DISTINCT ON (web_id, entity_uuid)
+        web_id,
+        entity_uuid,
+        left_entity_confidence,
+        left_entity_provenance
+    FROM entity_temporal_metadata
+    JOIN entity_editions USING (entity_edition_id)
+    ORDER BY web_id, entity_uuid, upper(transaction_time) DESC NULLS FIRST
+) AS latest
+WHERE entity_has_left_entity.web_id = latest.web_id
+  AND entity_has_left_entity.entity_uuid = latest.entity_uuid;
+
+UPDATE entity_has_right_entity
+SET confidence = latest.right_entity_confidence,
+    provenance = latest.right_entity_provenance
+FROM (
+    SELECT DISTINCT ON (web_id, entity_uuid)
+        web_id,
+        entity_uuid,
+        right_entity_confidence,
+        right_entity_provenance
+    FROM entity_temporal_metadata
+    JOIN entity_editions USING (entity_edition_id)
+    ORDER BY web_id, entity_uuid, upper(transaction_time) DESC NULLS FIRST
+) AS latest
+WHERE entity_has_right_entity.web_id = latest.web_id
+  AND entity_has_right_entity.entity_uuid = latest.entity_uuid;
+
+ALTER TABLE entity_editions
+    DROP COLUMN left_entity_confidence,
+    DROP COLUMN left_entity_provenance,
+    DROP COLUMN right_entity_confidence,
+    DROP COLUMN right_entity_provenance;
@@ -0,0 +1,33 @@
+-- The confidence and provenance of the links of a link entity are stored per edition, so they are
+-- versioned together with the rest of the entity.
+ALTER TABLE entity_editions
+    ADD COLUMN left_entity_confidence DOUBLE PRECISION,
+    ADD COLUMN left_entity_provenance JSONB,
+    ADD COLUMN right_entity_confidence DOUBLE PRECISION,
+    ADD COLUMN right_entity_provenance JSONB;
+
+UPDATE entity_editions
+SET left_entity_confidence = entity_has_left_entity.confidence,
+    left_entity_provenance = entity_has_left_entity.provenance
+FROM entity_temporal_metadata
+JOIN entity_has_left_entity
+  ON entity_has_left_entity.web_id = entity_temporal_metadata.web_id
+ AND entity_has_left_entity.entity_uuid = entity_temporal_metadata.entity_uuid
+WHERE entity_editions.entity_edition_id = entity_temporal_metadata.entity_edition_id;
+
+UPDATE entity_editions
+SET right_entity_confidence = entity_has_right_entity.confidence,
+    right_entity_provenance = entity_has_right_entity.provenance
+FROM entity_temporal_metadata
+JOIN entity_has_right_entity
+  ON entity_has_right_entity.web_id = entity_temporal_metadata.web_id
+ AND entity_has_right_entity.entity_uuid = entity_temporal_metadata.entity_uuid
+WHERE entity_editions.entity_edition_id = entity_temporal_metadata.entity_edition_id;
+
+ALTER TABLE entity_has_left_entity
+    DROP COLUMN confidence,
+    DROP COLUMN provenance;
+
+ALTER TABLE entity_has_right_entity
+    DROP COLUMN confidence,
+    DROP COLUMN provenance;
@@ -0,0 +1,6 @@
+-- Only full-text matches on the whole `properties` object are able to use this index. Matches on
+-- a single property or on the label of an entity (which is resolved through its entity types) are
+-- evaluated without an index.
+CREATE INDEX entity_editions_full_text_idx
+ON entity_editions
+USING gin (jsonb_to_tsvector('simple', properties, '["string"]'));
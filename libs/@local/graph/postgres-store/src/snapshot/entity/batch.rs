@@ -61,42 +61,34 @@ where
             .client()
             .simple_query(
                 "
-                    CREATE TEMPORARY TABLE entity_ids_tmp
-                        (LIKE entity_ids INCLUDING ALL)
-                        ON COMMIT DROP;
+                    CREATE TABLE entity_ids_tmp
+                        (LIKE entity_ids INCLUDING ALL);
 
-                    CREATE TEMPORARY TABLE entity_drafts_tmp
-                        (LIKE entity_drafts INCLUDING ALL)
-                        ON COMMIT DROP;
+                    CREATE TABLE entity_drafts_tmp
+                        (LIKE entity_drafts INCLUDING ALL);
 
-                    CREATE TEMPORARY TABLE entity_editions_tmp
-                        (LIKE entity_editions INCLUDING ALL)
-                        ON COMMIT DROP;
+                    CREATE TABLE entity_editions_tmp
+                        (LIKE entity_editions INCLUDING ALL);
 
-                    CREATE TEMPORARY TABLE entity_is_of_type_tmp
-                        (LIKE entity_is_of_type INCLUDING ALL)
-                        ON COMMIT DROP;
+                    CREATE TABLE entity_is_of_type_tmp
+                        (LIKE entity_is_of_type INCLUDING ALL);
 
-                    CREATE TEMPORARY TABLE entity_temporal_metadata_tmp
-                        (LIKE entity_temporal_metadata INCLUDING ALL)
-                        ON COMMIT DROP;
+                    CREATE TABLE entity_temporal_metadata_tmp
+                        (LIKE entity_temporal_metadata INCLUDING ALL);
 
-                    CREATE TEMPORARY TABLE entity_has_left_entity_tmp
-                        (LIKE entity_has_left_entity INCLUDING ALL)
-                        ON COMMIT DROP;
+                    CREATE TABLE entity_has_left_entity_tmp
+                        (LIKE entity_has_left_entity INCLUDING ALL);
 
-                    CREATE TEMPORARY TABLE entity_has_right_entity_tmp
-                        (LIKE entity_has_right_entity INCLUDING ALL)
-                        ON COMMIT DROP;
+                    CREATE TABLE entity_has_right_entity_tmp
+                        (LIKE entity_has_right_entity INCLUDING ALL);
 
-                    CREATE TEMPORARY TABLE entity_embeddings_tmp
-                        (LIKE entity_embeddings INCLUDING ALL)
-                        ON COMMIT DROP;
+                    CREATE TABLE entity_embeddings_tmp
+                        (LIKE entity_embeddings INCLUDING ALL);
                 ",
             )
             .await
             .change_context(InsertionError)
-            .attach_printable("could not create temporary tables")?;
+            .attach_printable("could not create staging tables")?;
         Ok(())
     }
 
@@ -100,6 +100,22 @@ impl Sink<EntitySnapshotRecord> for EntitySender {
                 confidence: entity.metadata.confidence,
                 provenance: entity.metadata.provenance.edition,
                 property_metadata: entity.metadata.properties,
+                left_entity_confidence: entity
+                    .link_data
+                    .as_ref()
+                    .and_then(|link_data| link_data.left_entity_confidence),
+                left_entity_provenance: entity
+                    .link_data
+                    .as_ref()
+                    .map(|link_data| link_data.left_entity_provenance.clone()),
+                right_entity_confidence: entity
+                    .link_data
+                    .as_ref()
+                    .and_then(|link_data| link_data.right_entity_confidence),
+                right_entity_provenance: entity
+                    .link_data
+                    .as_ref()
+                    .map(|link_data| link_data.right_entity_provenance.clone()),
             })
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not send entity edition")?;
@@ -134,8 +150,6 @@ impl Sink<EntitySnapshotRecord> for EntitySender {
                     entity_uuid: entity.metadata.record_id.entity_id.entity_uuid,
                     left_web_id: link_data.left_entity_id.owned_by_id,
                     left_entity_uuid: link_data.left_entity_id.entity_uuid,
-                    confidence: link_data.left_entity_confidence,
-                    provenance: link_data.left_entity_provenance,
                 })
                 .change_context(SnapshotRestoreError::Read)
                 .attach_printable("could not send entity link edges")?;
@@ -145,8 +159,6 @@ impl Sink<EntitySnapshotRecord> for EntitySender {
                     entity_uuid: entity.metadata.record_id.entity_id.entity_uuid,
                     right_web_id: link_data.right_entity_id.owned_by_id,
                     right_entity_uuid: link_data.right_entity_id.entity_uuid,
-                    confidence: link_data.right_entity_confidence,
-                    provenance: link_data.right_entity_provenance,
                 })
                 .change_context(SnapshotRestoreError::Read)
                 .attach_printable("could not send entity link edges")?;
@@ -23,6 +23,9 @@ impl Error for SnapshotDumpError {}
 pub enum SnapshotRestoreError {
     Unsupported,
     MissingMetadata,
+    MissingCheckpoint,
+    CheckpointExists,
+    Locked,
     Read,
     Buffer,
     Write,
@@ -34,6 +37,14 @@ impl fmt::Display for SnapshotRestoreError {
         match self {
             Self::Unsupported => write!(fmt, "The snapshot contains unsupported entries"),
             Self::MissingMetadata => write!(fmt, "The snapshot does not contain metadata"),
+            Self::MissingCheckpoint => {
+                write!(fmt, "There is no interrupted snapshot restore to resume")
+            }
+            Self::CheckpointExists => write!(
+                fmt,
+                "There is an interrupted snapshot restore, it has to be resumed or restarted"
+            ),
+            Self::Locked => write!(fmt, "Another snapshot restore is currently running"),
             Self::Read => write!(fmt, "could not read a snapshot entry"),
             Self::Buffer => write!(fmt, "could not buffer a snapshot entry"),
             Self::Write => write!(fmt, "could not write a snapshot entry into the store"),
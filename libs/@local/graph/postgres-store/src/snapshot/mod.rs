@@ -8,6 +8,7 @@ pub use self::{
         DataTypeSnapshotRecord, EntityTypeSnapshotRecord, OntologyTypeSnapshotRecord,
         PropertyTypeSnapshotRecord,
     },
+    restore::SnapshotCheckpoint,
 };
 pub use crate::snapshot::metadata::SnapshotMetadata;
 
@@ -17,13 +18,15 @@ mod ontology;
 mod restore;
 mod web;
 
-use core::{error::Error, future::ready};
+use core::{error::Error, fmt, future::ready, pin::pin};
 
 use async_scoped::TokioScope;
 use error_stack::{Report, ResultExt as _, ensure};
 use futures::{
     Sink, SinkExt as _, Stream, StreamExt as _, TryFutureExt as _, TryStreamExt as _,
-    channel::mpsc, stream,
+    channel::mpsc,
+    future,
+    stream::{self, TryChunksError},
 };
 use hash_graph_authorization::{
     AuthorizationApi, NoAuthorization,
@@ -116,7 +119,92 @@ pub enum SnapshotEntry {
     Relation(AuthorizationRelation),
 }
 
+/// The kind of a [`SnapshotEntry`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SnapshotEntryKind {
+    Snapshot,
+    Account,
+    AccountGroup,
+    Web,
+    DataType,
+    DataTypeEmbedding,
+    PropertyType,
+    PropertyTypeEmbedding,
+    EntityType,
+    EntityTypeEmbedding,
+    Entity,
+    EntityEmbedding,
+    Relation,
+}
+
+impl SnapshotEntryKind {
+    const ALL: [Self; 13] = [
+        Self::Snapshot,
+        Self::Account,
+        Self::AccountGroup,
+        Self::Web,
+        Self::DataType,
+        Self::DataTypeEmbedding,
+        Self::PropertyType,
+        Self::PropertyTypeEmbedding,
+        Self::EntityType,
+        Self::EntityTypeEmbedding,
+        Self::Entity,
+        Self::EntityEmbedding,
+        Self::Relation,
+    ];
+
+    /// Returns the name of the kind as used in the `type` field of the serialized entry.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Snapshot => "snapshot",
+            Self::Account => "account",
+            Self::AccountGroup => "accountGroup",
+            Self::Web => "web",
+            Self::DataType => "dataType",
+            Self::DataTypeEmbedding => "dataTypeEmbedding",
+            Self::PropertyType => "propertyType",
+            Self::PropertyTypeEmbedding => "propertyTypeEmbedding",
+            Self::EntityType => "entityType",
+            Self::EntityTypeEmbedding => "entityTypeEmbedding",
+            Self::Entity => "entity",
+            Self::EntityEmbedding => "entityEmbedding",
+            Self::Relation => "relation",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == name)
+    }
+}
+
+impl fmt::Display for SnapshotEntryKind {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(self.as_str())
+    }
+}
+
 impl SnapshotEntry {
+    #[must_use]
+    pub const fn kind(&self) -> SnapshotEntryKind {
+        match self {
+            Self::Snapshot(_) => SnapshotEntryKind::Snapshot,
+            Self::Account(_) => SnapshotEntryKind::Account,
+            Self::AccountGroup(_) => SnapshotEntryKind::AccountGroup,
+            Self::Web(_) => SnapshotEntryKind::Web,
+            Self::DataType(_) => SnapshotEntryKind::DataType,
+            Self::DataTypeEmbedding(_) => SnapshotEntryKind::DataTypeEmbedding,
+            Self::PropertyType(_) => SnapshotEntryKind::PropertyType,
+            Self::PropertyTypeEmbedding(_) => SnapshotEntryKind::PropertyTypeEmbedding,
+            Self::EntityType(_) => SnapshotEntryKind::EntityType,
+            Self::EntityTypeEmbedding(_) => SnapshotEntryKind::EntityTypeEmbedding,
+            Self::Entity(_) => SnapshotEntryKind::Entity,
+            Self::EntityEmbedding(_) => SnapshotEntryKind::EntityEmbedding,
+            Self::Relation(_) => SnapshotEntryKind::Relation,
+        }
+    }
+
     #[expect(clippy::too_many_lines)]
     pub fn install_error_stack_hook() {
         error_stack::Report::install_debug_hook::<Self>(|entry, context| match entry {
@@ -248,6 +336,19 @@ trait WriteBatch<C, A> {
     ) -> impl Future<Output = Result<(), Report<InsertionError>>> + Send;
 }
 
+#[derive(Debug, Copy, Clone)]
+pub struct SnapshotRestoreSettings {
+    /// The number of entries, which are written into the store per transaction.
+    pub chunk_size: usize,
+    pub ignore_validation_errors: bool,
+    /// Continue a previously interrupted restore from its last checkpoint.
+    pub resume: bool,
+    /// Discard the checkpoint of a previously interrupted restore and start from the beginning.
+    ///
+    /// Without this setting, a restore which is not resumed fails if a checkpoint exists.
+    pub restart: bool,
+}
+
 pub struct SnapshotStore<C, A>(PostgresStore<C, A>);
 
 impl<C, A> SnapshotStore<C, A> {
@@ -777,28 +878,33 @@ where
 {
     /// Reads the snapshot from the stream into the store.
     ///
-    /// The data emitted by the stream is read in a separate thread and is sent to different
-    /// channels for each record type. Each channel holds a buffer of `chunk_size` entries. The
-    /// receivers of the channels are then used to insert the records into the store. When a write
-    /// operation to the store succeeds, the next entry is read from the channel, even if the
-    /// buffer of the channel is not full yet. This ensures, that the store is continuously writing
-    /// to the database and does not wait for the buffer to be full.
+    /// The stream is read in segments of `chunk_size` entries. The entries of a segment are sent
+    /// to different channels for each record type, the receivers of the channels are then used to
+    /// insert the records into the store.
     ///
     /// Writing to the store happens in three stages:
     ///   1. The first stage is the `begin` stage. This stage is executed before any records are
-    ///      read from the stream. It is used to create a transaction, so a possible rollback is
-    ///      possible. For each data, which is inserted, a temporary table is created. This table is
-    ///      used to insert the data into the store without locking the store and avoiding yet
+    ///      read from the stream. It creates the `snapshot_restore` staging schema, which contains
+    ///      a table for each data, which is inserted, and the restore checkpoint. The staging
+    ///      tables are used to insert the data without locking the store and avoiding yet
     ///      unfulfilled foreign key constraints.
-    ///   2. The second stage is the `write` stage. This stage is executed for each record type. It
-    ///      reads the batch of records from the channels and inserts them into the temporary
-    ///      tables, which were created above.
+    ///   2. The second stage is the `write` stage. This stage is executed for each segment of the
+    ///      stream. It reads the batch of records from the channels and inserts them into the
+    ///      staging tables. Each segment is committed in its own transaction together with the
+    ///      number of entries per [`SnapshotEntryKind`] read so far.
     ///   3. The third stage is the `commit` stage. This stage is executed after all records have
-    ///      been read from the stream. It is used to insert the data from the temporary tables into
-    ///      the store and to drop the temporary tables. As foreign key constraints are now enabled,
+    ///      been read from the stream. It is used to insert the data from the staging tables into
+    ///      the store and to drop the staging schema. As foreign key constraints are now enabled,
     ///      this stage might fail. In this case, the transaction is rolled back and the error is
     ///      returned.
     ///
+    /// If the restore is interrupted, it can be continued by providing the same snapshot again
+    /// with [`SnapshotRestoreSettings::resume`] enabled. All entries, which were already committed
+    /// according to the checkpoint, are skipped. To start over instead, the existing checkpoint has
+    /// to be discarded explicitly by enabling [`SnapshotRestoreSettings::restart`].
+    ///
+    /// Only one restore can run at a time, which is guarded by a Postgres advisory lock.
+    ///
     /// If the input stream contains an `Err` value, the snapshot restore is aborted and the error
     /// is returned.
     ///
@@ -806,58 +912,131 @@ where
     ///
     /// - If reading a record from the provided stream fails
     /// - If writing a record into the datastore fails
+    /// - If the restore should be resumed but no checkpoint exists
+    /// - If a checkpoint exists but the restore should neither be resumed nor restarted
+    /// - If another restore is currently running
     pub async fn restore_snapshot(
         &mut self,
         snapshot: impl Stream<Item = Result<SnapshotEntry, Report<impl Error + Send + Sync + 'static>>>
         + Send
         + 'static,
-        chunk_size: usize,
-        ignore_validation_errors: bool,
+        settings: SnapshotRestoreSettings,
+    ) -> Result<(), Report<SnapshotRestoreError>> {
+        restore::lock_restore(&self.0).await?;
+        let result = self.restore_snapshot_locked(snapshot, settings).await;
+        let unlocked = restore::unlock_restore(&self.0).await;
+        result.and(unlocked)
+    }
+
+    async fn restore_snapshot_locked(
+        &mut self,
+        snapshot: impl Stream<Item = Result<SnapshotEntry, Report<impl Error + Send + Sync + 'static>>>
+        + Send
+        + 'static,
+        settings: SnapshotRestoreSettings,
     ) -> Result<(), Report<SnapshotRestoreError>> {
         tracing::info!("snapshot restore started");
 
-        let (snapshot_record_tx, snapshot_record_rx, metadata_rx) = restore::channel(chunk_size);
+        let mut checkpoint = if settings.resume {
+            let checkpoint = restore::load_checkpoint(&self.0).await?;
+            tracing::info!(
+                entries = checkpoint.total(),
+                "resuming snapshot restore from checkpoint"
+            );
+            checkpoint
+        } else {
+            if restore::checkpoint_exists(&self.0).await? {
+                ensure!(settings.restart, SnapshotRestoreError::CheckpointExists);
+                tracing::warn!("discarding checkpoint of an interrupted snapshot restore");
+            }
 
-        let read_thread = tokio::spawn(
+            let mut client = self
+                .0
+                .transaction()
+                .await
+                .change_context(SnapshotRestoreError::Write)?;
+            restore::prepare_staging(&mut client).await?;
+            client
+                .commit()
+                .await
+                .change_context(SnapshotRestoreError::Write)?;
+            SnapshotCheckpoint::default()
+        };
+
+        let mut remaining = checkpoint.clone();
+        let mut segments = pin!(
             snapshot
                 .map_err(|report| report.change_context(SnapshotRestoreError::Read))
-                .forward(
-                    snapshot_record_tx
-                        .sink_map_err(|report| report.change_context(SnapshotRestoreError::Buffer)),
-                ),
+                .try_filter(move |entry| ready(!remaining.take(entry.kind())))
+                .try_chunks(settings.chunk_size)
+                .map_err(|TryChunksError(_, report)| report)
         );
 
-        let mut client = self
-            .0
-            .transaction()
-            .await
-            .change_context(SnapshotRestoreError::Write)?;
+        while let Some(entries) = segments.try_next().await? {
+            for entry in &entries {
+                checkpoint.record(entry.kind());
+            }
 
-        SnapshotRecordBatch::begin(&mut client)
-            .await
-            .change_context(SnapshotRestoreError::Write)?;
+            let client = self
+                .0
+                .transaction()
+                .await
+                .change_context(SnapshotRestoreError::Write)?;
+            restore::enter_staging(&client).await?;
 
-        let mut client = snapshot_record_rx
-            .map(Ok::<_, Report<SnapshotRestoreError>>)
-            .try_fold(
-                client,
-                |mut client, records: SnapshotRecordBatch| async move {
-                    records
-                        .write(&mut client)
-                        .await
-                        .change_context(SnapshotRestoreError::Write)?;
-                    Ok(client)
-                },
+            let (snapshot_record_tx, snapshot_record_rx, metadata_rx) =
+                restore::channel(settings.chunk_size);
+
+            let ((), client) = future::try_join(
+                stream::iter(entries).map(Ok).forward(snapshot_record_tx),
+                snapshot_record_rx
+                    .map(Ok::<_, Report<SnapshotRestoreError>>)
+                    .try_fold(
+                        client,
+                        |mut client, records: SnapshotRecordBatch| async move {
+                            records
+                                .write(&mut client)
+                                .await
+                                .change_context(SnapshotRestoreError::Write)?;
+                            Ok(client)
+                        },
+                    ),
             )
             .await?;
 
+            for metadata in metadata_rx.collect::<Vec<SnapshotMetadata>>().await {
+                validate_metadata(&metadata)?;
+            }
+
+            restore::store_checkpoint(&client, &checkpoint).await?;
+            client
+                .commit()
+                .await
+                .change_context(SnapshotRestoreError::Write)
+                .attach_printable("unable to commit snapshot segment to the staging schema")?;
+
+            tracing::info!(
+                entries = checkpoint.total(),
+                "snapshot restore checkpoint saved"
+            );
+        }
+
+        match checkpoint.entries(SnapshotEntryKind::Snapshot) {
+            0 => return Err(Report::new(SnapshotRestoreError::MissingMetadata)),
+            1 => {}
+            _ => tracing::warn!("found more than one metadata record in the snapshot"),
+        }
+
         tracing::info!("snapshot reading finished, committing...");
 
-        read_thread
+        let mut client = self
+            .0
+            .transaction()
             .await
-            .change_context(SnapshotRestoreError::Read)??;
+            .change_context(SnapshotRestoreError::Write)?;
+        restore::enter_staging(&client).await?;
 
-        SnapshotRecordBatch::commit(&mut client, ignore_validation_errors)
+        SnapshotRecordBatch::commit(&mut client, settings.ignore_validation_errors)
             .await
             .change_context(SnapshotRestoreError::Write)
             .map_err(|report| {
@@ -879,29 +1058,154 @@ where
                 }
             })?;
 
+        restore::drop_staging(&client).await?;
+
         client
             .commit()
             .await
             .change_context(SnapshotRestoreError::Write)
             .attach_printable("unable to commit snapshot to the store")?;
 
-        let mut found_metadata = false;
-        for metadata in metadata_rx.collect::<Vec<SnapshotMetadata>>().await {
-            if found_metadata {
-                tracing::warn!("found more than one metadata record in the snapshot");
-            }
-            found_metadata = true;
+        tracing::info!("snapshot restore finished");
 
-            ensure!(
-                metadata.block_protocol_module_versions.graph == semver::Version::new(0, 3, 0),
-                SnapshotRestoreError::Unsupported
-            );
+        Ok(())
+    }
+}
+
+fn validate_metadata(metadata: &SnapshotMetadata) -> Result<(), Report<SnapshotRestoreError>> {
+    ensure!(
+        metadata.block_protocol_module_versions.graph == semver::Version::new(0, 3, 0),
+        SnapshotRestoreError::Unsupported
+    );
+    Ok(())
+}
+
+/// Reads the snapshot from the stream without writing it into a store.
+///
+/// Every entry is deserialized and the [`SnapshotMetadata`] is checked to be supported. The
+/// returned [`SnapshotCheckpoint`] contains the number of entries per [`SnapshotEntryKind`].
+///
+/// # Errors
+///
+/// - If reading a record from the provided stream fails
+/// - If the snapshot does not contain metadata or the metadata is not supported
+pub async fn validate_snapshot(
+    snapshot: impl Stream<Item = Result<SnapshotEntry, Report<impl Error + Send + Sync + 'static>>>
+    + Send,
+) -> Result<SnapshotCheckpoint, Report<SnapshotRestoreError>> {
+    let checkpoint = snapshot
+        .map_err(|report| report.change_context(SnapshotRestoreError::Read))
+        .try_fold(
+            SnapshotCheckpoint::default(),
+            |mut checkpoint, entry| async move {
+                if let SnapshotEntry::Snapshot(metadata) = &entry {
+                    validate_metadata(metadata)?;
+                }
+                checkpoint.record(entry.kind());
+                Ok(checkpoint)
+            },
+        )
+        .await?;
+
+    match checkpoint.entries(SnapshotEntryKind::Snapshot) {
+        0 => return Err(Report::new(SnapshotRestoreError::MissingMetadata)),
+        1 => {}
+        _ => tracing::warn!("found more than one metadata record in the snapshot"),
+    }
+
+    Ok(checkpoint)
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct StreamError;
+
+    impl fmt::Display for StreamError {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt.write_str("could not read from stream")
         }
+    }
 
-        ensure!(found_metadata, SnapshotRestoreError::MissingMetadata);
+    impl Error for StreamError {}
 
-        tracing::info!("snapshot restore finished");
+    fn metadata(graph: semver::Version) -> SnapshotEntry {
+        SnapshotEntry::Snapshot(SnapshotMetadata {
+            block_protocol_module_versions: BlockProtocolModuleVersions { graph },
+            custom: CustomGlobalMetadata,
+        })
+    }
 
-        Ok(())
+    fn account() -> SnapshotEntry {
+        SnapshotEntry::Account(Account {
+            id: AccountId::new(Uuid::new_v4()),
+        })
+    }
+
+    async fn validate(
+        entries: Vec<Result<SnapshotEntry, Report<StreamError>>>,
+    ) -> Result<SnapshotCheckpoint, Report<SnapshotRestoreError>> {
+        validate_snapshot(stream::iter(entries)).await
+    }
+
+    #[tokio::test]
+    async fn counts_entries() {
+        let checkpoint = validate(vec![
+            Ok(metadata(semver::Version::new(0, 3, 0))),
+            Ok(account()),
+            Ok(account()),
+        ])
+        .await
+        .expect("snapshot should be valid");
+
+        assert_eq!(checkpoint.entries(SnapshotEntryKind::Snapshot), 1);
+        assert_eq!(checkpoint.entries(SnapshotEntryKind::Account), 2);
+        assert_eq!(checkpoint.total(), 3);
+    }
+
+    #[tokio::test]
+    async fn missing_metadata() {
+        let report = validate(vec![Ok(account())])
+            .await
+            .expect_err("snapshot without metadata should be rejected");
+
+        assert!(matches!(
+            report.current_context(),
+            SnapshotRestoreError::MissingMetadata
+        ));
+    }
+
+    #[tokio::test]
+    async fn unsupported_metadata() {
+        let report = validate(vec![
+            Ok(metadata(semver::Version::new(0, 2, 0))),
+            Ok(account()),
+        ])
+        .await
+        .expect_err("snapshot with unsupported metadata should be rejected");
+
+        assert!(matches!(
+            report.current_context(),
+            SnapshotRestoreError::Unsupported
+        ));
+    }
+
+    #[tokio::test]
+    async fn read_error() {
+        let report = validate(vec![
+            Ok(metadata(semver::Version::new(0, 3, 0))),
+            Err(Report::new(StreamError)),
+        ])
+        .await
+        .expect_err("snapshot with read errors should be rejected");
+
+        assert!(matches!(
+            report.current_context(),
+            SnapshotRestoreError::Read
+        ));
     }
 }
@@ -36,22 +36,19 @@ where
             .client()
             .simple_query(
                 "
-                    CREATE TEMPORARY TABLE data_types_tmp
-                        (LIKE data_types INCLUDING ALL)
-                        ON COMMIT DROP;
+                    CREATE TABLE data_types_tmp
+                        (LIKE data_types INCLUDING ALL);
 
-                    CREATE TEMPORARY TABLE data_type_conversions_tmp
-                        (LIKE data_type_conversions INCLUDING ALL)
-                        ON COMMIT DROP;
+                    CREATE TABLE data_type_conversions_tmp
+                        (LIKE data_type_conversions INCLUDING ALL);
 
-                    CREATE TEMPORARY TABLE data_type_embeddings_tmp
-                        (LIKE data_type_embeddings INCLUDING ALL)
-                        ON COMMIT DROP;
+                    CREATE TABLE data_type_embeddings_tmp
+                        (LIKE data_type_embeddings INCLUDING ALL);
                 ",
             )
             .await
             .change_context(InsertionError)
-            .attach_printable("could not create temporary tables")?;
+            .attach_printable("could not create staging tables")?;
         Ok(())
     }
 
@@ -35,18 +35,18 @@ where
             .client()
             .simple_query(
                 "
-                    CREATE TEMPORARY TABLE entity_types_tmp (
+                    CREATE TABLE entity_types_tmp (
                         LIKE entity_types INCLUDING ALL
-                    ) ON COMMIT DROP;
+                    );
 
-                    CREATE TEMPORARY TABLE entity_type_embeddings_tmp (
+                    CREATE TABLE entity_type_embeddings_tmp (
                         LIKE entity_type_embeddings INCLUDING ALL
-                    ) ON COMMIT DROP;
+                    );
                 ",
             )
             .await
             .change_context(InsertionError)
-            .attach_printable("could not create temporary tables")?;
+            .attach_printable("could not create staging tables")?;
         Ok(())
     }
 
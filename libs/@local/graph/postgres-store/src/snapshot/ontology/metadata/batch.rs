@@ -33,26 +33,22 @@ where
             .client()
             .simple_query(
                 "
-                    CREATE TEMPORARY TABLE ontology_ids_tmp
-                        (LIKE ontology_ids INCLUDING ALL)
-                        ON COMMIT DROP;
+                    CREATE TABLE ontology_ids_tmp
+                        (LIKE ontology_ids INCLUDING ALL);
 
-                    CREATE TEMPORARY TABLE ontology_temporal_metadata_tmp
-                        (LIKE ontology_temporal_metadata INCLUDING ALL)
-                        ON COMMIT DROP;
+                    CREATE TABLE ontology_temporal_metadata_tmp
+                        (LIKE ontology_temporal_metadata INCLUDING ALL);
 
-                    CREATE TEMPORARY TABLE ontology_owned_metadata_tmp
-                        (LIKE ontology_owned_metadata INCLUDING ALL)
-                        ON COMMIT DROP;
+                    CREATE TABLE ontology_owned_metadata_tmp
+                        (LIKE ontology_owned_metadata INCLUDING ALL);
 
-                    CREATE TEMPORARY TABLE ontology_external_metadata_tmp
-                        (LIKE ontology_external_metadata INCLUDING ALL)
-                        ON COMMIT DROP;
+                    CREATE TABLE ontology_external_metadata_tmp
+                        (LIKE ontology_external_metadata INCLUDING ALL);
                 ",
             )
             .await
             .change_context(InsertionError)
-            .attach_printable("could not create temporary tables")?;
+            .attach_printable("could not create staging tables")?;
         Ok(())
     }
 
@@ -38,26 +38,26 @@ where
             .client()
             .simple_query(
                 "
-                    CREATE TEMPORARY TABLE property_types_tmp (
+                    CREATE TABLE property_types_tmp (
                         LIKE property_types INCLUDING ALL
-                    ) ON COMMIT DROP;
+                    );
 
-                    CREATE TEMPORARY TABLE property_type_constrains_values_on_tmp (
+                    CREATE TABLE property_type_constrains_values_on_tmp (
                         LIKE property_type_constrains_values_on INCLUDING ALL
-                    ) ON COMMIT DROP;
+                    );
 
-                    CREATE TEMPORARY TABLE property_type_constrains_properties_on_tmp (
+                    CREATE TABLE property_type_constrains_properties_on_tmp (
                         LIKE property_type_constrains_properties_on INCLUDING ALL
-                    ) ON COMMIT DROP;
+                    );
 
-                    CREATE TEMPORARY TABLE property_type_embeddings_tmp (
+                    CREATE TABLE property_type_embeddings_tmp (
                         LIKE property_type_embeddings INCLUDING ALL
-                    ) ON COMMIT DROP;
+                    );
                 ",
             )
             .await
             .change_context(InsertionError)
-            .attach_printable("could not create temporary tables")?;
+            .attach_printable("could not create staging tables")?;
         Ok(())
     }
 
@@ -31,12 +31,12 @@ where
             .client()
             .simple_query(
                 "
-                    CREATE TEMPORARY TABLE accounts_tmp (
+                    CREATE TABLE accounts_tmp (
                         LIKE accounts INCLUDING ALL
-                    ) ON COMMIT DROP;
-                    CREATE TEMPORARY TABLE account_groups_tmp (
+                    );
+                    CREATE TABLE account_groups_tmp (
                         LIKE account_groups INCLUDING ALL
-                    ) ON COMMIT DROP;
+                    );
                 ",
             )
             .await
@@ -0,0 +1,328 @@
+use std::collections::HashMap;
+
+use error_stack::{Report, ResultExt as _};
+use hash_graph_authorization::{AuthorizationApi, backend::ZanzibarBackend};
+use tokio_postgres::GenericClient as _;
+
+use crate::{
+    snapshot::{
+        SnapshotEntryKind, SnapshotRestoreError, WriteBatch as _, restore::SnapshotRecordBatch,
+    },
+    store::{AsClient, PostgresStore},
+};
+
+/// The number of snapshot entries per [`SnapshotEntryKind`], which were committed into the store.
+///
+/// When a snapshot restore is resumed, the first entries of each kind are skipped according to
+/// the checkpoint, so the snapshot has to be provided in the same order as before.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SnapshotCheckpoint {
+    entries: HashMap<SnapshotEntryKind, usize>,
+}
+
+impl SnapshotCheckpoint {
+    /// Returns the number of entries of the given kind.
+    #[must_use]
+    pub fn entries(&self, kind: SnapshotEntryKind) -> usize {
+        self.entries.get(&kind).copied().unwrap_or(0)
+    }
+
+    /// Returns the total number of entries.
+    #[must_use]
+    pub fn total(&self) -> usize {
+        self.entries.values().sum()
+    }
+
+    /// Returns an iterator over the number of entries of each kind.
+    pub fn iter(&self) -> impl Iterator<Item = (SnapshotEntryKind, usize)> + '_ {
+        self.entries.iter().map(|(kind, entries)| (*kind, *entries))
+    }
+
+    pub(crate) fn record(&mut self, kind: SnapshotEntryKind) {
+        *self.entries.entry(kind).or_default() += 1;
+    }
+
+    /// Removes one entry of the given kind and returns `true` if there was one left.
+    pub(crate) fn take(&mut self, kind: SnapshotEntryKind) -> bool {
+        match self.entries.get_mut(&kind) {
+            Some(entries) if *entries > 0 => {
+                *entries -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Key of the advisory lock, which is held for the duration of a snapshot restore.
+///
+/// The value is the ASCII representation of `snapshot`.
+const RESTORE_LOCK_KEY: i64 = 0x736E_6170_7368_6F74;
+
+/// Takes the session level advisory lock, which guards the staging schema against concurrent
+/// restores.
+///
+/// The lock is released by [`unlock_restore`] or when the connection is closed.
+///
+/// # Errors
+///
+/// Returns [`SnapshotRestoreError::Locked`] if another restore is currently running.
+pub(crate) async fn lock_restore<C, A>(
+    postgres_client: &PostgresStore<C, A>,
+) -> Result<(), Report<SnapshotRestoreError>>
+where
+    C: AsClient,
+    A: Send + Sync,
+{
+    let locked: bool = postgres_client
+        .as_client()
+        .client()
+        .query_one("SELECT pg_try_advisory_lock($1);", &[&RESTORE_LOCK_KEY])
+        .await
+        .change_context(SnapshotRestoreError::Write)
+        .attach_printable("could not acquire snapshot restore lock")?
+        .get(0);
+
+    if locked {
+        Ok(())
+    } else {
+        Err(Report::new(SnapshotRestoreError::Locked))
+    }
+}
+
+/// Releases the advisory lock taken by [`lock_restore`].
+pub(crate) async fn unlock_restore<C, A>(
+    postgres_client: &PostgresStore<C, A>,
+) -> Result<(), Report<SnapshotRestoreError>>
+where
+    C: AsClient,
+    A: Send + Sync,
+{
+    postgres_client
+        .as_client()
+        .client()
+        .execute("SELECT pg_advisory_unlock($1);", &[&RESTORE_LOCK_KEY])
+        .await
+        .change_context(SnapshotRestoreError::Write)
+        .attach_printable("could not release snapshot restore lock")?;
+
+    Ok(())
+}
+
+/// Returns if a checkpoint of a previously interrupted restore exists.
+pub(crate) async fn checkpoint_exists<C, A>(
+    postgres_client: &PostgresStore<C, A>,
+) -> Result<bool, Report<SnapshotRestoreError>>
+where
+    C: AsClient,
+    A: Send + Sync,
+{
+    Ok(postgres_client
+        .as_client()
+        .client()
+        .query_one(
+            "SELECT to_regclass('snapshot_restore.checkpoint') IS NOT NULL;",
+            &[],
+        )
+        .await
+        .change_context(SnapshotRestoreError::Read)?
+        .get(0))
+}
+
+/// Creates the staging schema, in which the snapshot is written before it's committed into the
+/// store.
+///
+/// A possibly existing staging schema of a previous restore is dropped, so callers have to make
+/// sure that an existing checkpoint should be discarded.
+pub(crate) async fn prepare_staging<C, A>(
+    postgres_client: &mut PostgresStore<C, A>,
+) -> Result<(), Report<SnapshotRestoreError>>
+where
+    C: AsClient,
+    A: AuthorizationApi + ZanzibarBackend,
+{
+    postgres_client
+        .as_client()
+        .client()
+        .simple_query(
+            "
+                DROP SCHEMA IF EXISTS snapshot_restore CASCADE;
+                CREATE SCHEMA snapshot_restore;
+                CREATE TABLE snapshot_restore.checkpoint (
+                    kind TEXT PRIMARY KEY,
+                    entries BIGINT NOT NULL
+                );
+            ",
+        )
+        .await
+        .change_context(SnapshotRestoreError::Write)
+        .attach_printable("could not create staging schema")?;
+
+    enter_staging(postgres_client).await?;
+
+    SnapshotRecordBatch::begin(postgres_client)
+        .await
+        .change_context(SnapshotRestoreError::Write)
+}
+
+/// Resolves unqualified table names in the staging schema first for the current transaction.
+pub(crate) async fn enter_staging<C, A>(
+    postgres_client: &PostgresStore<C, A>,
+) -> Result<(), Report<SnapshotRestoreError>>
+where
+    C: AsClient,
+    A: Send + Sync,
+{
+    postgres_client
+        .as_client()
+        .client()
+        .execute(
+            "SELECT set_config('search_path', 'snapshot_restore, ' || \
+             current_setting('search_path'), true);",
+            &[],
+        )
+        .await
+        .change_context(SnapshotRestoreError::Write)
+        .attach_printable("could not enter staging schema")?;
+
+    Ok(())
+}
+
+/// Drops the staging schema including the checkpoint.
+pub(crate) async fn drop_staging<C, A>(
+    postgres_client: &PostgresStore<C, A>,
+) -> Result<(), Report<SnapshotRestoreError>>
+where
+    C: AsClient,
+    A: Send + Sync,
+{
+    postgres_client
+        .as_client()
+        .client()
+        .simple_query("DROP SCHEMA snapshot_restore CASCADE;")
+        .await
+        .change_context(SnapshotRestoreError::Write)
+        .attach_printable("could not drop staging schema")?;
+
+    Ok(())
+}
+
+/// Reads the checkpoint of a previously interrupted restore.
+///
+/// # Errors
+///
+/// Returns [`SnapshotRestoreError::MissingCheckpoint`] if there is no restore to resume.
+pub(crate) async fn load_checkpoint<C, A>(
+    postgres_client: &PostgresStore<C, A>,
+) -> Result<SnapshotCheckpoint, Report<SnapshotRestoreError>>
+where
+    C: AsClient,
+    A: Send + Sync,
+{
+    if !checkpoint_exists(postgres_client).await? {
+        return Err(Report::new(SnapshotRestoreError::MissingCheckpoint));
+    }
+
+    let client = postgres_client.as_client().client();
+
+    let mut checkpoint = SnapshotCheckpoint::default();
+    for row in client
+        .query("SELECT kind, entries FROM snapshot_restore.checkpoint;", &[
+        ])
+        .await
+        .change_context(SnapshotRestoreError::Read)?
+    {
+        let kind: String = row.get(0);
+        let entries: i64 = row.get(1);
+
+        let kind = SnapshotEntryKind::from_name(&kind)
+            .ok_or(SnapshotRestoreError::Read)
+            .attach_printable_lazy(|| format!("unknown snapshot entry kind `{kind}`"))?;
+        let entries = usize::try_from(entries).change_context(SnapshotRestoreError::Read)?;
+
+        checkpoint.entries.insert(kind, entries);
+    }
+
+    Ok(checkpoint)
+}
+
+/// Writes the checkpoint into the staging schema.
+pub(crate) async fn store_checkpoint<C, A>(
+    postgres_client: &PostgresStore<C, A>,
+    checkpoint: &SnapshotCheckpoint,
+) -> Result<(), Report<SnapshotRestoreError>>
+where
+    C: AsClient,
+    A: Send + Sync,
+{
+    let (kinds, entries): (Vec<_>, Vec<_>) = checkpoint
+        .iter()
+        .map(|(kind, entries)| i64::try_from(entries).map(|entries| (kind.as_str(), entries)))
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(SnapshotRestoreError::Write)?
+        .into_iter()
+        .unzip();
+
+    postgres_client
+        .as_client()
+        .client()
+        .execute(
+            "
+                INSERT INTO snapshot_restore.checkpoint (kind, entries)
+                SELECT * FROM UNNEST($1::text[], $2::bigint[])
+                ON CONFLICT (kind) DO UPDATE SET entries = EXCLUDED.entries;
+            ",
+            &[&kinds, &entries],
+        )
+        .await
+        .change_context(SnapshotRestoreError::Write)
+        .attach_printable("could not store snapshot restore checkpoint")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_counts_per_kind() {
+        let mut checkpoint = SnapshotCheckpoint::default();
+        checkpoint.record(SnapshotEntryKind::Snapshot);
+        checkpoint.record(SnapshotEntryKind::Entity);
+        checkpoint.record(SnapshotEntryKind::Entity);
+
+        assert_eq!(checkpoint.entries(SnapshotEntryKind::Snapshot), 1);
+        assert_eq!(checkpoint.entries(SnapshotEntryKind::Entity), 2);
+        assert_eq!(checkpoint.entries(SnapshotEntryKind::DataType), 0);
+        assert_eq!(checkpoint.total(), 3);
+    }
+
+    #[test]
+    fn take_skips_recorded_entries_only() {
+        let mut checkpoint = SnapshotCheckpoint::default();
+        checkpoint.record(SnapshotEntryKind::Entity);
+        checkpoint.record(SnapshotEntryKind::Entity);
+
+        assert!(checkpoint.take(SnapshotEntryKind::Entity));
+        assert!(checkpoint.take(SnapshotEntryKind::Entity));
+        assert!(!checkpoint.take(SnapshotEntryKind::Entity));
+        assert!(!checkpoint.take(SnapshotEntryKind::DataType));
+
+        assert_eq!(checkpoint.entries(SnapshotEntryKind::Entity), 0);
+        assert_eq!(checkpoint.total(), 0);
+    }
+
+    #[test]
+    fn take_does_not_affect_other_kinds() {
+        let mut checkpoint = SnapshotCheckpoint::default();
+        checkpoint.record(SnapshotEntryKind::Snapshot);
+        checkpoint.record(SnapshotEntryKind::Entity);
+
+        assert!(checkpoint.take(SnapshotEntryKind::Snapshot));
+        assert!(!checkpoint.take(SnapshotEntryKind::Snapshot));
+
+        assert_eq!(checkpoint.entries(SnapshotEntryKind::Entity), 1);
+        assert_eq!(checkpoint.total(), 1);
+    }
+}
@@ -1,5 +1,12 @@
 mod batch;
 mod channel;
+mod checkpoint;
 
-pub use self::batch::SnapshotRecordBatch;
-pub(crate) use self::channel::channel;
+pub use self::{batch::SnapshotRecordBatch, checkpoint::SnapshotCheckpoint};
+pub(crate) use self::{
+    channel::channel,
+    checkpoint::{
+        checkpoint_exists, drop_staging, enter_staging, load_checkpoint, lock_restore,
+        prepare_staging, store_checkpoint, unlock_restore,
+    },
+};
@@ -27,9 +27,8 @@ where
             .client()
             .simple_query(
                 "
-                    CREATE TEMPORARY TABLE webs_tmp
-                        (LIKE webs INCLUDING ALL)
-                        ON COMMIT DROP;
+                    CREATE TABLE webs_tmp
+                        (LIKE webs INCLUDING ALL);
                 ",
             )
             .await
@@ -0,0 +1,108 @@
+use error_stack::{Report, ResultExt as _};
+use hash_graph_authorization::AuthorizationApi;
+use hash_graph_store::{
+    change_feed::{
+        ChangeFeedStore, GraphChange, GraphChangeId, GraphChangeKind, ReadGraphChangesParams,
+        ReadGraphChangesResponse,
+    },
+    error::{InsertionError, QueryError},
+};
+use hash_graph_types::account::AccountId;
+use postgres_types::Json;
+use tokio_postgres::Transaction;
+
+use crate::store::{AsClient, PostgresStore};
+
+impl<A> PostgresStore<Transaction<'_>, A>
+where
+    A: Send + Sync,
+{
+    /// Records the `changes` in the change feed as part of the current transaction.
+    ///
+    /// Writers are serialized by a transaction-scoped advisory lock, which is held until the
+    /// transaction is committed or rolled back. This guarantees that changes become visible in the
+    /// order of their [`GraphChangeId`], so a reader never skips a change which is committed later
+    /// with a lower ID. To keep the lock short, this should be called right before the
+    /// transaction is committed.
+    ///
+    /// # Errors
+    ///
+    /// - if recording the changes failed
+    #[tracing::instrument(level = "debug", skip(self, changes))]
+    pub(crate) async fn publish_graph_changes(
+        &self,
+        changes: impl IntoIterator<Item = GraphChangeKind> + Send,
+    ) -> Result<(), Report<InsertionError>> {
+        let changes = changes.into_iter().map(Json).collect::<Vec<_>>();
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        self.as_client()
+            .simple_query("SELECT pg_advisory_xact_lock(hashtext('graph_changes'));")
+            .await
+            .change_context(InsertionError)?;
+        self.as_client()
+            .query(
+                "
+                    INSERT INTO graph_changes (change)
+                    SELECT * FROM UNNEST($1::JSONB[]);
+                ",
+                &[&changes],
+            )
+            .await
+            .change_context(InsertionError)?;
+
+        Ok(())
+    }
+}
+
+impl<C, A> ChangeFeedStore for PostgresStore<C, A>
+where
+    C: AsClient,
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn read_graph_changes(
+        &self,
+        actor_id: AccountId,
+        params: ReadGraphChangesParams,
+    ) -> Result<ReadGraphChangesResponse, Report<QueryError>> {
+        let limit = i64::try_from(params.limit)
+            .change_context(QueryError)
+            .attach_printable("The limit is too large")?;
+
+        let changes = self
+            .as_client()
+            .query(
+                "
+                    SELECT change_id, transaction_time, change
+                    FROM graph_changes
+                    WHERE change_id > $1
+                    ORDER BY change_id
+                    LIMIT $2;
+                ",
+                &[&params.after.unwrap_or(GraphChangeId::new(0)), &limit],
+            )
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| {
+                let Json(change) = row.get(2);
+                GraphChange {
+                    id: row.get(0),
+                    transaction_time: row.get(1),
+                    change,
+                }
+            })
+            .collect();
+
+        ReadGraphChangesResponse::from_permitted_changes(
+            &self.authorization_api,
+            actor_id,
+            changes,
+            params.after,
+        )
+        .await
+    }
+}
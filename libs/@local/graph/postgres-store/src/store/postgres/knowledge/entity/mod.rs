@@ -1,7 +1,7 @@
 mod query;
 mod read;
 use alloc::{borrow::Cow, collections::BTreeSet};
-use core::{borrow::Borrow as _, iter::once, mem};
+use core::{borrow::Borrow as _, iter::once, mem, slice};
 use std::collections::{HashMap, HashSet};
 
 use error_stack::{FutureExt as _, Report, ResultExt as _, TryReportStreamExt as _, bail, ensure};
@@ -16,16 +16,24 @@ use hash_graph_authorization::{
     zanzibar::{Consistency, Zookie},
 };
 use hash_graph_store::{
+    change_feed::GraphChangeKind,
     entity::{
-        ClosedMultiEntityTypeMap, CountEntitiesParams, CreateEntityParams, EmptyEntityTypes,
-        EntityQueryPath, EntityQuerySorting, EntityStore, EntityTypeRetrieval, EntityTypesError,
-        EntityValidationReport, EntityValidationType, GetEntitiesParams, GetEntitiesResponse,
-        GetEntitySubgraphParams, GetEntitySubgraphResponse, PatchEntityParams, QueryConversion,
+        AggregateEntitiesParams, AggregateEntitiesResponse, ClosedMultiEntityTypeMap,
+        CountEntitiesParams, CreateEntityParams, EmptyEntityTypes, EntityAggregationFunction,
+        EntityAggregationGroup, EntityHistoryEntry, EntityQueryPath, EntityQuerySorting,
+        EntityStore, EntityTypeRetrieval, EntityTypesError, EntityValidationReport,
+        EntityValidationType, GetEntitiesParams, GetEntitiesResponse, GetEntityHistoryParams,
+        GetEntityHistoryResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse,
+        ImportEntitiesParams, ImportEntitiesResponse, MergeEntitiesParams, MergeEntitiesResponse,
+        MigrateEntityTypeParams, MigrateEntityTypeResponse, PatchEntityParams, QueryConversion,
         UpdateEntityEmbeddingsParams, ValidateEntityComponents, ValidateEntityParams,
     },
     entity_type::IncludeEntityTypeOption,
-    error::{InsertionError, QueryError, UpdateError},
-    filter::{Filter, FilterExpression, Parameter},
+    error::{
+        EntityEditionMismatch, EntityHistoryCursorNotFound, InsertionError, LinkedEntitiesMismatch,
+        QueryError, UpdateError,
+    },
+    filter::{Filter, FilterExpression, Parameter, ParameterList},
     query::{QueryResult as _, Read, ReadPaginated, Sorting as _},
     subgraph::{
         Subgraph, SubgraphRecord as _,
@@ -53,10 +61,11 @@ use hash_graph_types::{
             EntityMetadata, EntityProvenance, EntityRecordId, EntityTemporalMetadata, EntityUuid,
             InferredEntityProvenance,
         },
+        link::LinkData,
         property::{
-            Property, PropertyMetadata, PropertyMetadataObject, PropertyObject, PropertyPath,
-            PropertyPathError, PropertyWithMetadata, PropertyWithMetadataObject,
-            PropertyWithMetadataValue, visitor::EntityVisitor as _,
+            Property, PropertyMetadata, PropertyMetadataObject, PropertyObject,
+            PropertyPatchOperation, PropertyPath, PropertyPathError, PropertyWithMetadata,
+            PropertyWithMetadataObject, PropertyWithMetadataValue, visitor::EntityVisitor as _,
         },
     },
     ontology::{DataTypeLookup, OntologyTypeProvider},
@@ -84,7 +93,8 @@ use crate::store::{
         ResponseCountMap, TraversalContext,
         knowledge::entity::read::EntityEdgeTraversalData,
         query::{
-            InsertStatementBuilder, ReferenceTable, Table,
+            Constant, Expression, Function, InsertStatementBuilder, PostgresType, ReferenceTable,
+            SelectCompiler, Table,
             rows::{
                 EntityDraftRow, EntityEditionRow, EntityHasLeftEntityRow, EntityHasRightEntityRow,
                 EntityIdRow, EntityIsOfTypeRow, EntityTemporalMetadataRow,
@@ -734,6 +744,39 @@ where
     }
 }
 
+/// Compiles `expression` into a `double precision` if it resolves to a JSON number and to `NULL`
+/// otherwise.
+fn compile_json_number(expression: Expression) -> Box<Expression> {
+    Box::new(Expression::Cast(
+        Box::new(Expression::Function(Function::JsonPathQueryFirst(
+            Box::new(Expression::Function(Function::ToJson(Box::new(expression)))),
+            Box::new(Expression::Constant(Constant::String(
+                r#"$ ? (@.type() == "number")"#,
+            ))),
+        ))),
+        PostgresType::DoublePrecision,
+    ))
+}
+
+/// Compiles the aggregate of the values `expression` resolves to.
+///
+/// Except for counting, only numeric values are aggregated. The result is always returned as
+/// `double precision`.
+fn compile_aggregation(function: EntityAggregationFunction, expression: Expression) -> Expression {
+    let aggregation = match function {
+        EntityAggregationFunction::Count => Function::Count(Box::new(expression)),
+        EntityAggregationFunction::Sum => Function::Sum(compile_json_number(expression)),
+        EntityAggregationFunction::Avg => Function::Avg(compile_json_number(expression)),
+        EntityAggregationFunction::Min => Function::Min(compile_json_number(expression)),
+        EntityAggregationFunction::Max => Function::Max(compile_json_number(expression)),
+    };
+
+    Expression::Cast(
+        Box::new(Expression::Function(aggregation)),
+        PostgresType::DoublePrecision,
+    )
+}
+
 impl<C, A> EntityStore for PostgresStore<C, A>
 where
     C: AsClient,
@@ -748,24 +791,77 @@ where
     where
         R: IntoIterator<Item = EntityRelationAndSubject> + Send,
     {
-        let transaction_time = Timestamp::<TransactionTime>::now().remove_nanosecond();
-        let mut relationships = Vec::with_capacity(params.len());
-        let mut entity_type_ids = HashMap::new();
-        let mut checked_web_ids = HashSet::new();
-        let mut entity_edition_ids = Vec::with_capacity(params.len());
+        let transaction = self.transaction().await.change_context(InsertionError)?;
 
-        let mut entity_id_rows = Vec::with_capacity(params.len());
-        let mut entity_draft_rows = Vec::new();
-        let mut entity_edition_rows = Vec::with_capacity(params.len());
-        let mut entity_temporal_metadata_rows = Vec::with_capacity(params.len());
-        let mut entity_is_of_type_rows = Vec::with_capacity(params.len());
-        let mut entity_has_left_entity_rows = Vec::new();
-        let mut entity_has_right_entity_rows = Vec::new();
+        let mut inserted_entities = transaction.insert_entities(actor_id, params).await?;
+        let relationships = mem::take(&mut inserted_entities.relationships);
 
-        let mut entities = Vec::with_capacity(params.len());
-        // TODO: There are expected to be duplicates but we currently don't have a way to identify
-        //       multi-type entity types. We need a way to speed this up.
-        let mut validation_params = Vec::with_capacity(params.len());
+        // Links between entities created in the same call are validated with the permissions of
+        // the actor, so the relationships have to exist before the entities are validated.
+        transaction
+            .authorization_api
+            .modify_entity_relations(relationships.iter().copied().map(
+                |(entity_id, relation_and_subject)| {
+                    (
+                        ModifyRelationshipOperation::Create,
+                        entity_id,
+                        relation_and_subject,
+                    )
+                },
+            ))
+            .await
+            .change_context(InsertionError)?;
+
+        let entities = transaction
+            .validate_inserted_entities(actor_id, inserted_entities)
+            .await?;
+
+        let commit_result = transaction.commit().await.change_context(InsertionError);
+        if let Err(error) = commit_result {
+            let mut error = error.expand();
+
+            if let Err(auth_error) = self
+                .authorization_api
+                .modify_entity_relations(relationships.into_iter().map(
+                    |(entity_id, relation_and_subject)| {
+                        (
+                            ModifyRelationshipOperation::Delete,
+                            entity_id,
+                            relation_and_subject,
+                        )
+                    },
+                ))
+                .await
+                .change_context(InsertionError)
+            {
+                error.push(auth_error);
+            }
+
+            Err(error.change_context(InsertionError))
+        } else {
+            if let Some(temporal_client) = &self.temporal_client {
+                temporal_client
+                    .start_update_entity_embeddings_workflow(actor_id, &entities)
+                    .await
+                    .change_context(InsertionError)?;
+            }
+
+            Ok(entities)
+        }
+    }
+
+    // TODO: Relax constraints on entity validation for draft entities
+    //   see https://linear.app/hash/issue/H-1449
+    // TODO: Restrict non-draft links to non-draft entities
+    //   see https://linear.app/hash/issue/H-1450
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn validate_entities(
+        &self,
+        actor_id: AccountId,
+        consistency: Consistency<'_>,
+        params: Vec<ValidateEntityParams<'_>>,
+    ) -> HashMap<usize, EntityValidationReport> {
+        let mut validation_reports = HashMap::<usize, EntityValidationReport>::new();
 
         let validator_provider = StoreProvider {
             store: self,
@@ -773,547 +869,169 @@ where
             authorization: Some((actor_id, Consistency::FullyConsistent)),
         };
 
-        let mut validation_reports = HashMap::<usize, EntityValidationReport>::new();
         for (index, mut params) in params.into_iter().enumerate() {
-            let entity_type = ClosedMultiEntityType::from_multi_type_closed_schema(
-                stream::iter(&params.entity_type_ids)
-                    .then(|entity_type_url| async {
-                        OntologyTypeProvider::<ClosedEntityType>::provide_type(
-                            &validator_provider,
-                            entity_type_url,
-                        )
+            let mut validation_report = EntityValidationReport::default();
+
+            let schema = match params.entity_types {
+                EntityValidationType::ClosedSchema(schema) => schema,
+                EntityValidationType::Id(entity_type_urls) => {
+                    let entity_type = stream::iter(entity_type_urls.as_ref())
+                        .then(|entity_type_url| {
+                            OntologyTypeProvider::<ClosedEntityType>::provide_type(
+                                &validator_provider,
+                                entity_type_url,
+                            )
+                            .change_context_lazy(|| {
+                                EntityTypeRetrieval {
+                                    entity_type_url: entity_type_url.clone(),
+                                }
+                            })
+                        })
+                        .map_ok(|entity_type| (*entity_type).clone())
+                        .try_collect_reports::<Vec<ClosedEntityType>>()
                         .await
-                        .map(|entity_type| (*entity_type).clone())
-                    })
-                    .try_collect::<Vec<ClosedEntityType>>()
-                    .await
-                    .change_context(InsertionError)?,
-            )
-            .change_context(InsertionError)?;
+                        .map_err(EntityTypesError::EntityTypeRetrieval)
+                        .and_then(|entity_types| {
+                            ClosedMultiEntityType::from_multi_type_closed_schema(entity_types)
+                                .map_err(EntityTypesError::ResolveClosedEntityType)
+                        });
+                    match entity_type {
+                        Ok(entity_type) => Cow::Owned(entity_type),
+                        Err(error) => {
+                            validation_report.metadata.entity_types = Some(error);
+                            validation_reports.insert(index, validation_report);
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            if schema.all_of.is_empty() {
+                validation_report.metadata.entity_types =
+                    Some(EntityTypesError::Empty(Report::new(EmptyEntityTypes)));
+            };
 
             let mut preprocessor = EntityPreprocessor {
-                components: if params.draft {
-                    ValidateEntityComponents::draft()
-                } else {
-                    ValidateEntityComponents::full()
-                },
+                components: params.components,
             };
-            preprocessor.components.link_validation = self.settings.validate_links;
 
             if let Err(property_validation) = preprocessor
-                .visit_object(&entity_type, &mut params.properties, &validator_provider)
+                .visit_object(
+                    schema.as_ref(),
+                    params.properties.to_mut(),
+                    &validator_provider,
+                )
                 .await
             {
                 validation_reports.entry(index).or_default().properties =
                     property_validation.properties;
             }
 
-            let (properties, property_metadata) = params.properties.into_parts();
-
-            let decision_time = params
-                .decision_time
-                .map_or_else(|| transaction_time.cast(), Timestamp::remove_nanosecond);
-            let entity_id = EntityId {
-                owned_by_id: params.owned_by_id,
-                entity_uuid: params
-                    .entity_uuid
-                    .unwrap_or_else(|| EntityUuid::new(Uuid::new_v4())),
-                draft_id: params.draft.then(|| DraftId::new(Uuid::new_v4())),
-            };
-
-            if entity_id.entity_uuid.as_uuid() != entity_id.owned_by_id.as_uuid() {
-                checked_web_ids.insert(entity_id.owned_by_id);
-            }
+            validation_report.link = params
+                .link_data
+                .as_deref()
+                .validate(&schema, params.components, &validator_provider)
+                .await;
 
-            let entity_provenance = EntityProvenance {
-                inferred: InferredEntityProvenance {
-                    created_by_id: CreatedById::new(actor_id),
-                    created_at_transaction_time: transaction_time,
-                    created_at_decision_time: decision_time,
-                    first_non_draft_created_at_transaction_time: entity_id
-                        .draft_id
-                        .is_none()
-                        .then_some(transaction_time),
-                    first_non_draft_created_at_decision_time: entity_id
-                        .draft_id
-                        .is_none()
-                        .then_some(decision_time),
-                },
-                edition: EntityEditionProvenance {
-                    created_by_id: EditionCreatedById::new(actor_id),
-                    archived_by_id: None,
-                    provided: params.provenance,
-                },
-            };
-            entity_id_rows.push(EntityIdRow {
-                web_id: entity_id.owned_by_id,
-                entity_uuid: entity_id.entity_uuid,
-                provenance: entity_provenance.inferred.clone(),
-            });
-            if let Some(draft_id) = entity_id.draft_id {
-                entity_draft_rows.push(EntityDraftRow {
-                    web_id: entity_id.owned_by_id,
-                    entity_uuid: entity_id.entity_uuid,
-                    draft_id,
-                });
+            if !validation_report.is_valid() {
+                validation_reports.insert(index, validation_report);
             }
+        }
 
-            let entity_edition_id = EntityEditionId::new(Uuid::new_v4());
-            entity_edition_rows.push(EntityEditionRow {
-                entity_edition_id,
-                properties: properties.clone(),
-                archived: false,
-                confidence: params.confidence,
-                provenance: entity_provenance.edition.clone(),
-                property_metadata: property_metadata.clone(),
-            });
-            entity_edition_ids.push(entity_edition_id);
+        validation_reports
+    }
 
-            let temporal_versioning = EntityTemporalMetadata {
-                decision_time: LeftClosedTemporalInterval::new(
-                    ClosedTemporalBound::Inclusive(decision_time),
-                    OpenTemporalBound::Unbounded,
-                ),
-                transaction_time: LeftClosedTemporalInterval::new(
-                    ClosedTemporalBound::Inclusive(transaction_time),
-                    OpenTemporalBound::Unbounded,
-                ),
-            };
-            entity_temporal_metadata_rows.push(EntityTemporalMetadataRow {
-                web_id: entity_id.owned_by_id,
-                entity_uuid: entity_id.entity_uuid,
-                draft_id: entity_id.draft_id,
-                entity_edition_id,
-                decision_time: temporal_versioning.decision_time,
-                transaction_time: temporal_versioning.transaction_time,
-            });
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn import_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: ImportEntitiesParams,
+    ) -> Result<ImportEntitiesResponse, Report<InsertionError>> {
+        let rows = params
+            .read_rows(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await?;
 
-            for entity_type in &entity_type.all_of {
-                let entity_type_id = EntityTypeUuid::from_url(&entity_type.id);
-                entity_type_ids.insert(entity_type_id, entity_type.id.clone());
-                entity_is_of_type_rows.push(EntityIsOfTypeRow {
-                    entity_edition_id,
-                    entity_type_ontology_id: entity_type_id,
-                    inheritance_depth: InheritanceDepth::new(0),
-                });
-            }
+        params.import_rows(self, actor_id, rows).await
+    }
 
-            let link_data = params.link_data.inspect(|link_data| {
-                entity_has_left_entity_rows.push(EntityHasLeftEntityRow {
-                    web_id: entity_id.owned_by_id,
-                    entity_uuid: entity_id.entity_uuid,
-                    left_web_id: link_data.left_entity_id.owned_by_id,
-                    left_entity_uuid: link_data.left_entity_id.entity_uuid,
-                    confidence: link_data.left_entity_confidence,
-                    provenance: link_data.left_entity_provenance.clone(),
-                });
-                entity_has_right_entity_rows.push(EntityHasRightEntityRow {
-                    web_id: entity_id.owned_by_id,
-                    entity_uuid: entity_id.entity_uuid,
-                    right_web_id: link_data.right_entity_id.owned_by_id,
-                    right_entity_uuid: link_data.right_entity_id.entity_uuid,
-                    confidence: link_data.right_entity_confidence,
-                    provenance: link_data.right_entity_provenance.clone(),
-                });
-            });
-
-            entities.push(Entity {
-                properties,
-                link_data,
-                metadata: EntityMetadata {
-                    record_id: EntityRecordId {
-                        entity_id,
-                        edition_id: entity_edition_id,
-                    },
-                    temporal_versioning,
-                    entity_type_ids: params.entity_type_ids,
-                    archived: false,
-                    provenance: entity_provenance,
-                    confidence: params.confidence,
-                    properties: property_metadata,
-                },
-            });
-
-            validation_params.push((entity_type, preprocessor.components));
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn get_entities(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntitiesParams<'_>,
+    ) -> Result<GetEntitiesResponse<'static>, Report<QueryError>> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
 
-            let current_num_relationships = relationships.len();
-            relationships.extend(
-                params
-                    .relationships
-                    .into_iter()
-                    .chain(once(EntityRelationAndSubject::Owner {
-                        subject: EntityOwnerSubject::Web {
-                            id: params.owned_by_id,
-                        },
-                        level: 0,
-                    }))
-                    .map(|relation_and_subject| (entity_id, relation_and_subject)),
-            );
-            if relationships.len() == current_num_relationships {
-                return Err(Report::new(InsertionError)
-                    .attach_printable("At least one relationship must be provided"));
-            }
-        }
-        // We move out the cache, so we can re-use `&mut self` later.
-        let store_cache = validator_provider.cache;
+        let temporal_axes = params.temporal_axes.resolve();
 
-        let (instantiate_permissions, zookie) = self
-            .authorization_api
-            .check_entity_types_permission(
+        let mut response = self
+            .get_entities_impl(
                 actor_id,
-                EntityTypePermission::Instantiate,
-                entity_type_ids.keys().copied(),
-                Consistency::FullyConsistent,
+                GetEntitiesImplParams {
+                    filter: params.filter,
+                    sorting: params.sorting,
+                    limit: params.limit,
+                    include_drafts: params.include_drafts,
+                    include_count: params.include_count,
+                    include_entity_types: params.include_entity_types,
+                    include_web_ids: params.include_web_ids,
+                    include_created_by_ids: params.include_created_by_ids,
+                    include_edition_created_by_ids: params.include_edition_created_by_ids,
+                    include_type_ids: params.include_type_ids,
+                },
+                &temporal_axes,
             )
             .await
-            .change_context(InsertionError)?;
-        let forbidden_instantiations = instantiate_permissions
-            .iter()
-            .filter_map(|(entity_type_id, permission)| {
-                if *permission {
-                    None
-                } else {
-                    entity_type_ids.get(entity_type_id)
-                }
-            })
-            .collect::<Vec<_>>();
-        if !forbidden_instantiations.is_empty() {
-            return Err(Report::new(InsertionError)
-                .attach(StatusCode::PermissionDenied)
-                .attach_printable(
-                    "The actor does not have permission to instantiate one or more entity types",
-                )
-                .attach_printable(
-                    forbidden_instantiations
-                        .into_iter()
-                        .map(ToString::to_string)
-                        .collect::<Vec<_>>()
-                        .join(", "),
-                ));
-        }
+            .map(|(response, _)| response)?;
 
-        if !checked_web_ids.is_empty() {
-            let (create_entity_permissions, _zookie) = self
-                .authorization_api
-                .check_webs_permission(
-                    actor_id,
-                    WebPermission::CreateEntity,
-                    checked_web_ids,
-                    Consistency::AtLeastAsFresh(&zookie),
-                )
-                .await
-                .change_context(InsertionError)?;
-            let forbidden_webs = create_entity_permissions
-                .iter()
-                .filter_map(
-                    |(web_id, permission)| {
-                        if *permission { None } else { Some(web_id) }
-                    },
-                )
-                .collect::<Vec<_>>();
-            if !forbidden_webs.is_empty() {
-                return Err(Report::new(InsertionError)
-                    .attach(StatusCode::PermissionDenied)
-                    .attach_printable(
-                        "The actor does not have permission to create entities for one or more \
-                         web ids",
-                    )
-                    .attach_printable(
-                        forbidden_webs
-                            .into_iter()
-                            .map(ToString::to_string)
-                            .collect::<Vec<_>>()
-                            .join(", "),
-                    ));
+        if !params.conversions.is_empty() {
+            let provider = StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            };
+            for entity in &mut response.entities {
+                self.convert_entity(&provider, entity, &params.conversions)
+                    .await
+                    .change_context(QueryError)?;
             }
         }
 
-        let transaction = self.transaction().await.change_context(InsertionError)?;
-
-        let insertions = [
-            InsertStatementBuilder::from_rows(Table::EntityIds, &entity_id_rows),
-            InsertStatementBuilder::from_rows(Table::EntityDrafts, &entity_draft_rows),
-            InsertStatementBuilder::from_rows(Table::EntityEditions, &entity_edition_rows),
-            InsertStatementBuilder::from_rows(
-                Table::EntityTemporalMetadata,
-                &entity_temporal_metadata_rows,
-            ),
-            InsertStatementBuilder::from_rows(Table::EntityIsOfType, &entity_is_of_type_rows),
-            InsertStatementBuilder::from_rows(
-                Table::EntityHasLeftEntity,
-                &entity_has_left_entity_rows,
-            ),
-            InsertStatementBuilder::from_rows(
-                Table::EntityHasRightEntity,
-                &entity_has_right_entity_rows,
-            ),
-        ];
-
-        for statement in insertions {
-            let (statement, parameters) = statement.compile();
-            transaction
-                .as_client()
-                .query(&statement, &parameters)
-                .await
-                .change_context(InsertionError)?;
-        }
-
-        transaction
-            .as_client()
-            .query(
-                "
-                    INSERT INTO entity_is_of_type
-                    SELECT entity_edition_id,
-                        target_entity_type_ontology_id AS entity_type_ontology_id,
-                        MIN(entity_type_inherits_from.depth + 1) AS inheritance_depth
-                    FROM entity_is_of_type
-                    JOIN entity_type_inherits_from
-                        ON entity_type_ontology_id = source_entity_type_ontology_id
-                    WHERE entity_edition_id = ANY($1)
-                    GROUP BY entity_edition_id, target_entity_type_ontology_id;
-                ",
-                &[&entity_edition_ids],
-            )
-            .await
-            .change_context(InsertionError)?;
+        Ok(response)
+    }
 
-        transaction
-            .authorization_api
-            .modify_entity_relations(relationships.iter().copied().map(
-                |(entity_id, relation_and_subject)| {
-                    (
-                        ModifyRelationshipOperation::Create,
-                        entity_id,
-                        relation_and_subject,
-                    )
-                },
-            ))
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn get_entity_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntitySubgraphParams<'_>,
+    ) -> Result<GetEntitySubgraphResponse<'static>, Report<QueryError>> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
             .await
-            .change_context(InsertionError)?;
-
-        let validator_provider = StoreProvider {
-            store: &transaction,
-            cache: store_cache,
-            authorization: Some((actor_id, Consistency::FullyConsistent)),
-        };
+            .change_context(QueryError)?;
 
-        for (index, (entity, (schema, components))) in
-            entities.iter().zip(validation_params).enumerate()
-        {
-            let validation_report = entity
-                .validate(&schema, components, &validator_provider)
-                .await;
-            if !validation_report.is_valid() {
-                let report = validation_reports.entry(index).or_default();
-                report.link = validation_report.link;
-                report.metadata.properties = validation_report.property_metadata;
-            }
-        }
+        let unresolved_temporal_axes = params.temporal_axes;
+        let temporal_axes = unresolved_temporal_axes.clone().resolve();
 
-        ensure!(
-            validation_reports.is_empty(),
-            Report::new(InsertionError).attach(validation_reports)
-        );
-
-        let commit_result = transaction.commit().await.change_context(InsertionError);
-        if let Err(error) = commit_result {
-            let mut error = error.expand();
-
-            if let Err(auth_error) = self
-                .authorization_api
-                .modify_entity_relations(relationships.into_iter().map(
-                    |(entity_id, relation_and_subject)| {
-                        (
-                            ModifyRelationshipOperation::Delete,
-                            entity_id,
-                            relation_and_subject,
-                        )
-                    },
-                ))
-                .await
-                .change_context(InsertionError)
-            {
-                error.push(auth_error);
-            }
-
-            Err(error.change_context(InsertionError))
-        } else {
-            if let Some(temporal_client) = &self.temporal_client {
-                temporal_client
-                    .start_update_entity_embeddings_workflow(actor_id, &entities)
-                    .await
-                    .change_context(InsertionError)?;
-            }
-
-            Ok(entities)
-        }
-    }
-
-    // TODO: Relax constraints on entity validation for draft entities
-    //   see https://linear.app/hash/issue/H-1449
-    // TODO: Restrict non-draft links to non-draft entities
-    //   see https://linear.app/hash/issue/H-1450
-    #[tracing::instrument(level = "info", skip(self))]
-    async fn validate_entities(
-        &self,
-        actor_id: AccountId,
-        consistency: Consistency<'_>,
-        params: Vec<ValidateEntityParams<'_>>,
-    ) -> HashMap<usize, EntityValidationReport> {
-        let mut validation_reports = HashMap::<usize, EntityValidationReport>::new();
-
-        let validator_provider = StoreProvider {
-            store: self,
-            cache: StoreCache::default(),
-            authorization: Some((actor_id, Consistency::FullyConsistent)),
-        };
-
-        for (index, mut params) in params.into_iter().enumerate() {
-            let mut validation_report = EntityValidationReport::default();
-
-            let schema = match params.entity_types {
-                EntityValidationType::ClosedSchema(schema) => schema,
-                EntityValidationType::Id(entity_type_urls) => {
-                    let entity_type = stream::iter(entity_type_urls.as_ref())
-                        .then(|entity_type_url| {
-                            OntologyTypeProvider::<ClosedEntityType>::provide_type(
-                                &validator_provider,
-                                entity_type_url,
-                            )
-                            .change_context_lazy(|| {
-                                EntityTypeRetrieval {
-                                    entity_type_url: entity_type_url.clone(),
-                                }
-                            })
-                        })
-                        .map_ok(|entity_type| (*entity_type).clone())
-                        .try_collect_reports::<Vec<ClosedEntityType>>()
-                        .await
-                        .map_err(EntityTypesError::EntityTypeRetrieval)
-                        .and_then(|entity_types| {
-                            ClosedMultiEntityType::from_multi_type_closed_schema(entity_types)
-                                .map_err(EntityTypesError::ResolveClosedEntityType)
-                        });
-                    match entity_type {
-                        Ok(entity_type) => Cow::Owned(entity_type),
-                        Err(error) => {
-                            validation_report.metadata.entity_types = Some(error);
-                            validation_reports.insert(index, validation_report);
-                            continue;
-                        }
-                    }
-                }
-            };
-
-            if schema.all_of.is_empty() {
-                validation_report.metadata.entity_types =
-                    Some(EntityTypesError::Empty(Report::new(EmptyEntityTypes)));
-            };
-
-            let mut preprocessor = EntityPreprocessor {
-                components: params.components,
-            };
-
-            if let Err(property_validation) = preprocessor
-                .visit_object(
-                    schema.as_ref(),
-                    params.properties.to_mut(),
-                    &validator_provider,
-                )
-                .await
-            {
-                validation_reports.entry(index).or_default().properties =
-                    property_validation.properties;
-            }
-
-            validation_report.link = params
-                .link_data
-                .as_deref()
-                .validate(&schema, params.components, &validator_provider)
-                .await;
-
-            if !validation_report.is_valid() {
-                validation_reports.insert(index, validation_report);
-            }
-        }
-
-        validation_reports
-    }
-
-    #[tracing::instrument(level = "info", skip(self, params))]
-    async fn get_entities(
-        &self,
-        actor_id: AccountId,
-        mut params: GetEntitiesParams<'_>,
-    ) -> Result<GetEntitiesResponse<'static>, Report<QueryError>> {
-        params
-            .filter
-            .convert_parameters(&StoreProvider {
-                store: self,
-                cache: StoreCache::default(),
-                authorization: Some((actor_id, Consistency::FullyConsistent)),
-            })
-            .await
-            .change_context(QueryError)?;
-
-        let temporal_axes = params.temporal_axes.resolve();
-
-        let mut response = self
-            .get_entities_impl(
-                actor_id,
-                GetEntitiesImplParams {
-                    filter: params.filter,
-                    sorting: params.sorting,
-                    limit: params.limit,
-                    include_drafts: params.include_drafts,
-                    include_count: params.include_count,
-                    include_entity_types: params.include_entity_types,
-                    include_web_ids: params.include_web_ids,
-                    include_created_by_ids: params.include_created_by_ids,
-                    include_edition_created_by_ids: params.include_edition_created_by_ids,
-                    include_type_ids: params.include_type_ids,
-                },
-                &temporal_axes,
-            )
-            .await
-            .map(|(response, _)| response)?;
-
-        if !params.conversions.is_empty() {
-            let provider = StoreProvider {
-                store: self,
-                cache: StoreCache::default(),
-                authorization: Some((actor_id, Consistency::FullyConsistent)),
-            };
-            for entity in &mut response.entities {
-                self.convert_entity(&provider, entity, &params.conversions)
-                    .await
-                    .change_context(QueryError)?;
-            }
-        }
-
-        Ok(response)
-    }
-
-    #[tracing::instrument(level = "info", skip(self, params))]
-    async fn get_entity_subgraph(
-        &self,
-        actor_id: AccountId,
-        mut params: GetEntitySubgraphParams<'_>,
-    ) -> Result<GetEntitySubgraphResponse<'static>, Report<QueryError>> {
-        params
-            .filter
-            .convert_parameters(&StoreProvider {
-                store: self,
-                cache: StoreCache::default(),
-                authorization: Some((actor_id, Consistency::FullyConsistent)),
-            })
-            .await
-            .change_context(QueryError)?;
-
-        let unresolved_temporal_axes = params.temporal_axes;
-        let temporal_axes = unresolved_temporal_axes.clone().resolve();
-
-        let time_axis = temporal_axes.variable_time_axis();
+        let time_axis = temporal_axes.variable_time_axis();
 
         let (
             GetEntitiesResponse {
@@ -1512,449 +1230,601 @@ where
             .count())
     }
 
-    async fn get_entity_by_id(
+    async fn aggregate_entities(
         &self,
         actor_id: AccountId,
-        entity_id: EntityId,
-        transaction_time: Option<Timestamp<TransactionTime>>,
-        decision_time: Option<Timestamp<DecisionTime>>,
-    ) -> Result<Entity, Report<QueryError>> {
-        self.authorization_api
-            .check_entity_permission(
+        mut params: AggregateEntitiesParams<'_>,
+    ) -> Result<AggregateEntitiesResponse, Report<QueryError>> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.resolve();
+
+        let record_ids = Read::<Entity>::read(
+            self,
+            &params.filter,
+            Some(&temporal_axes),
+            params.include_drafts,
+        )
+        .await?
+        .map_ok(|entity| entity.metadata.record_id)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+        let permitted_ids = self
+            .authorization_api
+            .check_entities_permission(
                 actor_id,
                 EntityPermission::View,
-                entity_id,
+                record_ids.iter().map(|record_id| record_id.entity_id),
                 Consistency::FullyConsistent,
             )
+            .instrument(tracing::trace_span!("post_filter_entities"))
             .await
             .change_context(QueryError)?
-            .assert_permission()
-            .change_context(QueryError)?;
+            .0
+            .into_iter()
+            .filter_map(|(entity_id, has_permission)| has_permission.then_some(entity_id))
+            .collect::<HashSet<_>>();
 
-        let temporal_axes = QueryTemporalAxesUnresolved::TransactionTime {
-            pinned: PinnedTemporalAxisUnresolved::new(decision_time),
-            variable: VariableTemporalAxisUnresolved::new(
-                transaction_time.map(TemporalBound::Inclusive),
-                transaction_time.map(LimitedTemporalBound::Inclusive),
+        let edition_ids = record_ids
+            .into_iter()
+            .filter(|record_id| permitted_ids.contains(&record_id.entity_id.entity_uuid))
+            .map(|record_id| record_id.edition_id)
+            .collect::<Vec<_>>();
+
+        let filter = Filter::All(vec![
+            params.filter,
+            Filter::In(
+                FilterExpression::Path {
+                    path: EntityQueryPath::EditionId,
+                },
+                ParameterList::EntityEditionIds(&edition_ids),
             ),
-        }
-        .resolve();
+        ]);
 
-        Read::<Entity>::read_one(
-            self,
-            &Filter::for_entity_by_entity_id(entity_id),
-            Some(&temporal_axes),
-            entity_id.draft_id.is_some(),
-        )
-        .await
-    }
-
-    #[expect(
-        clippy::significant_drop_tightening,
-        reason = "The connection is required to borrow the client"
-    )]
-    #[tracing::instrument(level = "info", skip(self, params))]
-    async fn patch_entity(
-        &mut self,
-        actor_id: AccountId,
-        mut params: PatchEntityParams,
-    ) -> Result<Entity, Report<UpdateError>> {
-        let transaction_time = Timestamp::now().remove_nanosecond();
-        let decision_time = params
-            .decision_time
-            .map_or_else(|| transaction_time.cast(), Timestamp::remove_nanosecond);
-        let entity_type_ids = params
-            .entity_type_ids
+        let mut compiler = SelectCompiler::new(Some(&temporal_axes), params.include_drafts);
+        compiler.add_filter(&filter);
+        let key_indices = params
+            .group_by
             .iter()
-            .map(EntityTypeUuid::from_url)
+            .map(|path| compiler.add_grouping_path(path))
+            .collect::<Vec<_>>();
+        let count_index = compiler.add_aggregation(Expression::Function(Function::Count(
+            Box::new(Expression::Asterisk),
+        )));
+        let value_indices = params
+            .aggregations
+            .iter()
+            .map(|aggregation| {
+                let expression = compiler.compile_path_column(&aggregation.path);
+                compiler.add_aggregation(compile_aggregation(aggregation.function, expression))
+            })
             .collect::<Vec<_>>();
 
-        if !self
-            .authorization_api
-            .check_entity_types_permission(
-                actor_id,
-                EntityTypePermission::Instantiate,
-                entity_type_ids,
-                Consistency::FullyConsistent,
-            )
+        let (statement, parameters) = compiler.compile();
+        let groups = self
+            .as_client()
+            .query(&statement, parameters)
+            .instrument(tracing::trace_span!("query"))
             .await
-            .change_context(UpdateError)?
-            .0
+            .change_context(QueryError)?
             .into_iter()
-            .all(|(_, permission)| permission)
-        {
-            bail!(Report::new(UpdateError).attach(StatusCode::PermissionDenied));
-        }
+            .map(|row| {
+                Ok(EntityAggregationGroup {
+                    keys: key_indices
+                        .iter()
+                        .map(|&index| {
+                            row.get::<_, Option<JsonValue>>(index)
+                                .unwrap_or(JsonValue::Null)
+                        })
+                        .collect(),
+                    count: usize::try_from(row.get::<_, i64>(count_index))
+                        .change_context(QueryError)?,
+                    values: value_indices
+                        .iter()
+                        .map(|&index| row.get::<_, Option<f64>>(index))
+                        .collect(),
+                })
+            })
+            .collect::<Result<_, Report<QueryError>>>()?;
+
+        Ok(AggregateEntitiesResponse { groups })
+    }
 
+    async fn get_entity_by_id(
+        &self,
+        actor_id: AccountId,
+        entity_id: EntityId,
+        transaction_time: Option<Timestamp<TransactionTime>>,
+        decision_time: Option<Timestamp<DecisionTime>>,
+    ) -> Result<Entity, Report<QueryError>> {
         self.authorization_api
             .check_entity_permission(
                 actor_id,
-                EntityPermission::Update,
-                params.entity_id,
+                EntityPermission::View,
+                entity_id,
                 Consistency::FullyConsistent,
             )
             .await
-            .change_context(UpdateError)?
+            .change_context(QueryError)?
             .assert_permission()
-            .change_context(UpdateError)?;
-
-        let transaction = self.transaction().await.change_context(UpdateError)?;
+            .change_context(QueryError)?;
 
-        let locked_row = transaction
-            .lock_entity_edition(params.entity_id, transaction_time, decision_time)
-            .await?
-            .ok_or_else(|| {
-                Report::new(EntityDoesNotExist)
-                    .attach(StatusCode::NotFound)
-                    .attach_printable(params.entity_id)
-                    .change_context(UpdateError)
-            })?;
-        let ClosedTemporalBound::Inclusive(locked_transaction_time) =
-            *locked_row.transaction_time.start();
-        let ClosedTemporalBound::Inclusive(locked_decision_time) =
-            *locked_row.decision_time.start();
-        let previous_entity = Read::<Entity>::read_one(
-            &transaction,
-            &Filter::Equal(
-                Some(FilterExpression::Path {
-                    path: EntityQueryPath::EditionId,
-                }),
-                Some(FilterExpression::Parameter {
-                    parameter: Parameter::Uuid(locked_row.entity_edition_id.into_uuid()),
-                    convert: None,
-                }),
+        let temporal_axes = QueryTemporalAxesUnresolved::TransactionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(decision_time),
+            variable: VariableTemporalAxisUnresolved::new(
+                transaction_time.map(TemporalBound::Inclusive),
+                transaction_time.map(LimitedTemporalBound::Inclusive),
             ),
-            Some(&QueryTemporalAxes::DecisionTime {
-                pinned: PinnedTemporalAxis::new(locked_transaction_time),
-                variable: VariableTemporalAxis::new(
-                    TemporalBound::Inclusive(locked_decision_time),
-                    LimitedTemporalBound::Inclusive(locked_decision_time),
-                ),
-            }),
-            true,
+        }
+        .resolve();
+
+        Read::<Entity>::read_one(
+            self,
+            &Filter::for_entity_by_entity_id(entity_id),
+            Some(&temporal_axes),
+            entity_id.draft_id.is_some(),
         )
         .await
-        .change_context(EntityDoesNotExist)
-        .attach(params.entity_id)
-        .change_context(UpdateError)?;
+    }
 
-        let mut first_non_draft_created_at_decision_time = previous_entity
-            .metadata
-            .provenance
-            .inferred
-            .first_non_draft_created_at_decision_time;
-        let mut first_non_draft_created_at_transaction_time = previous_entity
-            .metadata
-            .provenance
-            .inferred
-            .first_non_draft_created_at_transaction_time;
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn patch_entity(
+        &mut self,
+        actor_id: AccountId,
+        params: PatchEntityParams,
+    ) -> Result<Entity, Report<UpdateError>> {
+        let transaction = self.transaction().await.change_context(UpdateError)?;
 
-        let was_draft_before = previous_entity
-            .metadata
-            .record_id
-            .entity_id
-            .draft_id
-            .is_some();
-        let draft = params.draft.unwrap_or(was_draft_before);
-        let archived = params.archived.unwrap_or(previous_entity.metadata.archived);
-        let (entity_type_ids, entity_types_updated) = if params.entity_type_ids.is_empty() {
-            (previous_entity.metadata.entity_type_ids, false)
-        } else {
-            let added_types = previous_entity
-                .metadata
-                .entity_type_ids
-                .difference(&params.entity_type_ids);
-            let removed_types = params
-                .entity_type_ids
-                .difference(&previous_entity.metadata.entity_type_ids);
+        let (entity, updated) = transaction.update_entity(actor_id, params).await?;
 
-            let mut has_changed = false;
-            for entity_type_id in added_types.chain(removed_types) {
-                has_changed = true;
+        transaction.commit().await.change_context(UpdateError)?;
 
-                let entity_type_id = EntityTypeUuid::from_url(entity_type_id);
-                transaction
-                    .authorization_api
-                    .check_entity_type_permission(
-                        actor_id,
-                        EntityTypePermission::Instantiate,
-                        entity_type_id,
-                        Consistency::FullyConsistent,
-                    )
+        if updated {
+            if let Some(temporal_client) = &self.temporal_client {
+                temporal_client
+                    .start_update_entity_embeddings_workflow(actor_id, slice::from_ref(&entity))
                     .await
-                    .change_context(UpdateError)?
-                    .assert_permission()
-                    .change_context(UpdateError)
-                    .attach(StatusCode::PermissionDenied)?;
+                    .change_context(UpdateError)?;
             }
+        }
+        Ok(entity)
+    }
 
-            (params.entity_type_ids, has_changed)
-        };
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn merge_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: MergeEntitiesParams,
+    ) -> Result<MergeEntitiesResponse, Report<UpdateError>> {
+        ensure!(
+            params
+                .merged_entity_ids
+                .iter()
+                .all(|entity_id| entity_id.entity_uuid != params.surviving_entity_id.entity_uuid),
+            Report::new(UpdateError)
+                .attach(StatusCode::InvalidArgument)
+                .attach_printable("The surviving entity cannot be merged into itself")
+        );
 
-        let previous_properties = previous_entity.properties.clone();
-        let previous_property_metadata = previous_entity.metadata.properties.clone();
+        let transaction = self.transaction().await.change_context(UpdateError)?;
 
-        let mut properties_with_metadata = PropertyWithMetadata::from_parts(
-            Property::Object(previous_entity.properties),
-            Some(PropertyMetadata::Object {
-                value: previous_entity.metadata.properties.value,
-                metadata: previous_entity.metadata.properties.metadata,
-            }),
+        let survivor = transaction
+            .get_entity_by_id(actor_id, params.surviving_entity_id, None, None)
+            .await
+            .change_context(UpdateError)?;
+        let mut properties = PropertyWithMetadataObject::from_parts(
+            survivor.properties.clone(),
+            Some(survivor.metadata.properties.clone()),
         )
         .change_context(UpdateError)?;
-        properties_with_metadata
-            .patch(params.properties)
-            .change_context(UpdateError)?;
 
-        let validator_provider = StoreProvider {
-            store: &transaction,
-            cache: StoreCache::default(),
-            authorization: Some((actor_id, Consistency::FullyConsistent)),
-        };
-        let entity_type = ClosedMultiEntityType::from_multi_type_closed_schema(
-            stream::iter(&entity_type_ids)
-                .then(|entity_type_url| async {
-                    OntologyTypeProvider::<ClosedEntityType>::provide_type(
-                        &validator_provider,
-                        entity_type_url,
-                    )
-                    .await
-                    .map(|entity_type| (*entity_type).clone())
-                })
-                .try_collect::<Vec<ClosedEntityType>>()
+        let mut merged_entities = Vec::with_capacity(params.merged_entity_ids.len());
+        for &entity_id in &params.merged_entity_ids {
+            let entity = transaction
+                .get_entity_by_id(actor_id, entity_id, None, None)
                 .await
+                .change_context(UpdateError)?;
+            params.merge_properties(
+                &mut properties,
+                PropertyWithMetadataObject::from_parts(
+                    entity.properties.clone(),
+                    Some(entity.metadata.properties.clone()),
+                )
                 .change_context(UpdateError)?,
-        )
-        .change_context(UpdateError)?;
+            );
+            merged_entities.push(entity);
+        }
 
-        let mut validation_components = if draft {
-            ValidateEntityComponents::draft()
-        } else {
-            ValidateEntityComponents::full()
-        };
-        validation_components.link_validation = transaction.settings.validate_links;
+        // Relationships and embeddings are not covered by the transaction, so they are only
+        // written after the transaction was committed.
+        let mut updated_entities = Vec::new();
+
+        let (entity, updated) = transaction
+            .update_entity(actor_id, PatchEntityParams {
+                entity_id: params.surviving_entity_id,
+                expected_edition_id: Some(survivor.metadata.record_id.edition_id),
+                decision_time: None,
+                entity_type_ids: HashSet::new(),
+                properties: vec![PropertyPatchOperation::Replace {
+                    path: PropertyPath::default(),
+                    property: PropertyWithMetadata::Object(properties),
+                }],
+                draft: None,
+                archived: None,
+                confidence: survivor.metadata.confidence,
+                link_data: None,
+                provenance: params.merged_provenance(once(&survivor).chain(&merged_entities)),
+            })
+            .await?;
+        if updated {
+            updated_entities.push(entity.clone());
+        }
 
-        let mut validation_report = EntityValidationReport::default();
-        let (properties, property_metadata) =
-            if let PropertyWithMetadata::Object(mut object) = properties_with_metadata {
-                let mut preprocessor = EntityPreprocessor {
-                    components: validation_components,
-                };
-                if let Err(property_validation) = preprocessor
-                    .visit_object(&entity_type, &mut object, &validator_provider)
-                    .await
-                {
-                    validation_report.properties = property_validation.properties;
-                }
+        let (merged_web_ids, merged_entity_uuids): (Vec<_>, Vec<_>) = params
+            .merged_entity_ids
+            .iter()
+            .map(|entity_id| (entity_id.owned_by_id, entity_id.entity_uuid))
+            .unzip();
+        let link_entity_ids = transaction
+            .as_client()
+            .query(
+                "
+                    SELECT web_id, entity_uuid FROM entity_has_left_entity
+                    WHERE (left_web_id, left_entity_uuid) IN (
+                        SELECT * FROM UNNEST($1::UUID[], $2::UUID[])
+                    )
+                    UNION
+                    SELECT web_id, entity_uuid FROM entity_has_right_entity
+                    WHERE (right_web_id, right_entity_uuid) IN (
+                        SELECT * FROM UNNEST($1::UUID[], $2::UUID[])
+                    );
+                ",
+                &[&merged_web_ids, &merged_entity_uuids],
+            )
+            .await
+            .change_context(UpdateError)?
+            .into_iter()
+            .map(|row| EntityId {
+                owned_by_id: row.get(0),
+                entity_uuid: row.get(1),
+                draft_id: None,
+            })
+            .filter(|link_entity_id| {
+                link_entity_id.entity_uuid != params.surviving_entity_id.entity_uuid
+                    && !merged_entity_uuids.contains(&link_entity_id.entity_uuid)
+            })
+            .collect::<Vec<_>>();
 
-                let (properties, property_metadata) = object.into_parts();
-                (properties, property_metadata)
-            } else {
-                unreachable!("patching should not change the property type");
-            };
-        // We move out the cache, so we can re-use `&mut self` later.
-        let store_cache = validator_provider.cache;
+        // The endpoints of a link entity are not versioned, so instead of re-pointing the links
+        // the existing link entities are archived and replaced by new link entities.
+        let mut relinked_entity_ids = Vec::new();
+        let mut replacements = Vec::new();
+        for link_entity_id in link_entity_ids {
+            let link_entity = transaction
+                .get_entity_by_id(actor_id, link_entity_id, None, None)
+                .await
+                .change_context(UpdateError)?;
+            if link_entity.metadata.archived {
+                continue;
+            }
 
-        #[expect(clippy::needless_collect, reason = "Will be used later")]
-        let diff = previous_properties
-            .diff(&properties, &mut PropertyPath::default())
-            .collect::<Vec<_>>();
+            let relationships = transaction
+                .authorization_api
+                .get_entity_relations(link_entity_id, Consistency::FullyConsistent)
+                .await
+                .change_context(UpdateError)?;
+            let Some(replacement) = params.relink(&link_entity, relationships)? else {
+                continue;
+            };
 
-        if diff.is_empty()
-            && was_draft_before == draft
-            && archived == previous_entity.metadata.archived
-            && !entity_types_updated
-            && previous_property_metadata == property_metadata
-            && params.confidence == previous_entity.metadata.confidence
-        {
-            // No changes were made to the entity.
-            return Ok(Entity {
-                properties: previous_properties,
-                link_data: previous_entity.link_data,
-                metadata: EntityMetadata {
-                    record_id: previous_entity.metadata.record_id,
-                    temporal_versioning: previous_entity.metadata.temporal_versioning,
-                    entity_type_ids,
-                    provenance: previous_entity.metadata.provenance,
-                    archived,
-                    confidence: previous_entity.metadata.confidence,
-                    properties: property_metadata,
-                },
-            });
-        }
+            let (archived_link_entity, _) = transaction
+                .update_entity(actor_id, PatchEntityParams {
+                    entity_id: link_entity_id,
+                    expected_edition_id: Some(link_entity.metadata.record_id.edition_id),
+                    decision_time: None,
+                    entity_type_ids: HashSet::new(),
+                    properties: Vec::new(),
+                    draft: None,
+                    archived: Some(true),
+                    confidence: link_entity.metadata.confidence,
+                    link_data: None,
+                    provenance: params.provenance.clone(),
+                })
+                .await?;
+            updated_entities.push(archived_link_entity);
 
-        let link_data = previous_entity.link_data;
+            relinked_entity_ids.push(link_entity_id);
+            replacements.push(replacement);
+        }
 
-        let edition_provenance = EntityEditionProvenance {
-            created_by_id: EditionCreatedById::new(actor_id),
-            archived_by_id: None,
-            provided: params.provenance,
+        let mut created_link_entity_ids = Vec::with_capacity(replacements.len());
+        let relationships = if replacements.is_empty() {
+            Vec::new()
+        } else {
+            let mut inserted_entities = transaction
+                .insert_entities(actor_id, replacements)
+                .await
+                .change_context(UpdateError)?;
+            let relationships = mem::take(&mut inserted_entities.relationships);
+            let created_link_entities = transaction
+                .validate_inserted_entities(actor_id, inserted_entities)
+                .await
+                .change_context(UpdateError)?;
+            created_link_entity_ids.extend(
+                created_link_entities
+                    .iter()
+                    .map(|entity| entity.metadata.record_id.entity_id),
+            );
+            updated_entities.extend(created_link_entities);
+            relationships
         };
-        let edition_id = transaction
-            .insert_entity_edition(
-                archived,
-                &entity_type_ids,
-                &properties,
-                params.confidence,
-                &edition_provenance,
-                &property_metadata,
-            )
-            .await
-            .change_context(UpdateError)?;
 
-        let temporal_versioning = match (was_draft_before, draft) {
-            (true, true) | (false, false) => {
-                // regular update
-                transaction
-                    .update_temporal_metadata(
-                        locked_row,
-                        transaction_time,
-                        decision_time,
-                        edition_id,
-                        false,
-                    )
-                    .await?
-            }
-            (false, true) => {
-                let draft_id = DraftId::new(Uuid::new_v4());
-                transaction
-                    .as_client()
-                    .query(
-                        "
-                        INSERT INTO entity_drafts (
-                            web_id,
-                            entity_uuid,
-                            draft_id
-                        ) VALUES ($1, $2, $3);",
-                        &[
-                            &params.entity_id.owned_by_id,
-                            &params.entity_id.entity_uuid,
-                            &draft_id,
-                        ],
-                    )
-                    .await
-                    .change_context(UpdateError)?;
-                params.entity_id.draft_id = Some(draft_id);
-                transaction
-                    .insert_temporal_metadata(
-                        params.entity_id,
-                        edition_id,
-                        transaction_time,
-                        decision_time,
-                    )
-                    .await
-                    .change_context(UpdateError)?
+        for merged_entity in merged_entities {
+            let (archived_entity, updated) = transaction
+                .update_entity(actor_id, PatchEntityParams {
+                    entity_id: merged_entity.metadata.record_id.entity_id,
+                    expected_edition_id: Some(merged_entity.metadata.record_id.edition_id),
+                    decision_time: None,
+                    entity_type_ids: HashSet::new(),
+                    properties: Vec::new(),
+                    draft: None,
+                    archived: Some(true),
+                    confidence: merged_entity.metadata.confidence,
+                    link_data: None,
+                    provenance: params.provenance.clone(),
+                })
+                .await?;
+            if updated {
+                updated_entities.push(archived_entity);
             }
-            (true, false) => {
-                // Publish a draft
-                params.entity_id.draft_id = None;
-
-                if first_non_draft_created_at_decision_time.is_none() {
-                    transaction
-                        .as_client()
-                        .query(
-                            "
-                            UPDATE entity_ids
-                            SET provenance = provenance || JSONB_BUILD_OBJECT(
-                                'firstNonDraftCreatedAtTransactionTime', $1::TIMESTAMPTZ,
-                                'firstNonDraftCreatedAtDecisionTime', $2::TIMESTAMPTZ
-                            )
-                            WHERE web_id = $3
-                              AND entity_uuid = $4;
-                            ",
-                            &[
-                                &transaction_time,
-                                &decision_time,
-                                &params.entity_id.owned_by_id,
-                                &params.entity_id.entity_uuid,
-                            ],
-                        )
-                        .await
-                        .change_context(UpdateError)?;
+        }
 
-                    first_non_draft_created_at_transaction_time = Some(transaction_time);
-                    first_non_draft_created_at_decision_time = Some(decision_time);
-                }
+        transaction.commit().await.change_context(UpdateError)?;
 
-                if let Some(previous_live_entity) = transaction
-                    .lock_entity_edition(params.entity_id, transaction_time, decision_time)
-                    .await?
-                {
-                    transaction
-                        .archive_entity(
-                            actor_id,
-                            previous_live_entity,
-                            transaction_time,
-                            decision_time,
+        if !relationships.is_empty() {
+            self.authorization_api
+                .modify_entity_relations(relationships.into_iter().map(
+                    |(entity_id, relation_and_subject)| {
+                        (
+                            ModifyRelationshipOperation::Create,
+                            entity_id,
+                            relation_and_subject,
                         )
-                        .await?;
-                }
-                transaction
-                    .update_temporal_metadata(
-                        locked_row,
-                        transaction_time,
-                        decision_time,
-                        edition_id,
-                        true,
-                    )
-                    .await?
-            }
-        };
+                    },
+                ))
+                .await
+                .change_context(UpdateError)?;
+        }
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_entity_embeddings_workflow(actor_id, &updated_entities)
+                .await
+                .change_context(UpdateError)?;
+        }
 
-        let entity_metadata = EntityMetadata {
-            record_id: EntityRecordId {
-                entity_id: params.entity_id,
-                edition_id,
-            },
-            temporal_versioning,
-            entity_type_ids,
-            provenance: EntityProvenance {
-                inferred: InferredEntityProvenance {
-                    first_non_draft_created_at_transaction_time,
-                    first_non_draft_created_at_decision_time,
-                    ..previous_entity.metadata.provenance.inferred
-                },
-                edition: edition_provenance,
-            },
-            confidence: params.confidence,
-            properties: property_metadata,
-            archived,
-        };
-        let entities = [Entity {
-            properties,
-            link_data,
-            metadata: entity_metadata.clone(),
-        }];
+        Ok(MergeEntitiesResponse {
+            entity,
+            relinked_entity_ids,
+            created_link_entity_ids,
+        })
+    }
 
-        let validator_provider = StoreProvider {
-            store: &transaction,
-            cache: store_cache,
-            authorization: Some((actor_id, Consistency::FullyConsistent)),
-        };
-        let post_validation_report = entities[0]
-            .validate(&entity_type, validation_components, &validator_provider)
-            .await;
-        validation_report.link = post_validation_report.link;
-        validation_report.metadata.properties = post_validation_report.property_metadata;
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn migrate_entity_type(
+        &mut self,
+        actor_id: AccountId,
+        params: MigrateEntityTypeParams,
+    ) -> Result<MigrateEntityTypeResponse, Report<UpdateError>> {
+        let mut transaction = self.transaction().await.change_context(UpdateError)?;
 
-        ensure!(
-            validation_report.is_valid(),
-            Report::new(UpdateError).attach(HashMap::from([(
-                entities[0].metadata.record_id.entity_id,
-                validation_report
-            )]))
-        );
+        let response = params.migrate_entities(&mut transaction, actor_id).await?;
 
         transaction.commit().await.change_context(UpdateError)?;
 
-        if let Some(temporal_client) = &self.temporal_client {
-            temporal_client
-                .start_update_entity_embeddings_workflow(actor_id, &entities)
+        Ok(response)
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn get_entity_history(
+        &self,
+        actor_id: AccountId,
+        params: GetEntityHistoryParams,
+    ) -> Result<GetEntityHistoryResponse, Report<QueryError>> {
+        self.authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::View,
+                params.entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .assert_permission()
+            .change_context(QueryError)?;
+
+        let draft_id = params.entity_id.draft_id;
+        if let Some(after) = params.after {
+            let is_edition = self
+                .as_client()
+                .query_one(
+                    "
+                        SELECT EXISTS (
+                            SELECT 1 FROM entity_temporal_metadata
+                            WHERE web_id = $1
+                              AND entity_uuid = $2
+                              AND entity_edition_id = $3
+                              AND ($4::UUID IS NULL OR draft_id = $4)
+                        );
+                    ",
+                    &[
+                        &params.entity_id.owned_by_id,
+                        &params.entity_id.entity_uuid,
+                        &after,
+                        &draft_id,
+                    ],
+                )
                 .await
-                .change_context(UpdateError)?;
+                .change_context(QueryError)?
+                .get::<_, bool>(0);
+            ensure!(
+                is_edition,
+                Report::new(EntityHistoryCursorNotFound)
+                    .attach(StatusCode::NotFound)
+                    .attach_printable(after.into_uuid())
+                    .change_context(QueryError)
+            );
         }
-        let [entity] = entities;
-        Ok(entity)
+
+        // One more edition than requested is read to know if there are more editions.
+        let limit = params
+            .limit
+            .map(|limit| i64::try_from(limit.saturating_add(1)).unwrap_or(i64::MAX));
+
+        // Every edition is identified by the time it was created, which is the start of its
+        // earliest row. The previous edition is the previous edition of the same draft, or, for
+        // the first edition of a draft, the live edition the draft was created from.
+        let rows = self
+            .as_client()
+            .query(
+                "
+                    WITH editions AS (
+                        SELECT DISTINCT ON (entity_edition_id)
+                            entity_edition_id,
+                            draft_id,
+                            lower(transaction_time) AS transaction_time,
+                            lower(decision_time) AS decision_time
+                        FROM entity_temporal_metadata
+                        WHERE web_id = $1 AND entity_uuid = $2
+                        ORDER BY entity_edition_id, lower(transaction_time), lower(decision_time)
+                    ), lineage AS (
+                        SELECT
+                            editions.*,
+                            LAG(entity_edition_id) OVER previous_edition AS previous_edition_id,
+                            LAG(transaction_time) OVER previous_edition
+                                AS previous_transaction_time,
+                            LAG(decision_time) OVER previous_edition AS previous_decision_time
+                        FROM editions
+                        WINDOW previous_edition AS (
+                            PARTITION BY draft_id
+                            ORDER BY transaction_time, decision_time, entity_edition_id
+                        )
+                    )
+                    SELECT
+                        lineage.entity_edition_id,
+                        lineage.transaction_time,
+                        lineage.decision_time,
+                        COALESCE(lineage.previous_edition_id, live.entity_edition_id),
+                        COALESCE(lineage.previous_transaction_time, live.transaction_time),
+                        COALESCE(lineage.previous_decision_time, live.decision_time)
+                    FROM lineage
+                    LEFT JOIN LATERAL (
+                        SELECT entity_edition_id, transaction_time, decision_time
+                        FROM editions
+                        WHERE lineage.previous_edition_id IS NULL
+                          AND lineage.draft_id IS NOT NULL
+                          AND editions.draft_id IS NULL
+                          AND (editions.transaction_time, editions.decision_time,
+                               editions.entity_edition_id)
+                            < (lineage.transaction_time, lineage.decision_time,
+                               lineage.entity_edition_id)
+                        ORDER BY editions.transaction_time DESC,
+                                 editions.decision_time DESC,
+                                 editions.entity_edition_id DESC
+                        LIMIT 1
+                    ) AS live ON TRUE
+                    WHERE ($3::UUID IS NULL OR lineage.draft_id = $3)
+                      AND ($4::UUID IS NULL
+                        OR (lineage.transaction_time, lineage.decision_time,
+                            lineage.entity_edition_id)
+                         > (SELECT transaction_time, decision_time, entity_edition_id
+                            FROM editions WHERE entity_edition_id = $4))
+                    ORDER BY lineage.transaction_time,
+                             lineage.decision_time,
+                             lineage.entity_edition_id
+                    LIMIT $5;
+                ",
+                &[
+                    &params.entity_id.owned_by_id,
+                    &params.entity_id.entity_uuid,
+                    &draft_id,
+                    &params.after,
+                    &limit,
+                ],
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let page = &rows[..rows.len().min(params.limit.unwrap_or(usize::MAX))];
+
+        let mut edition_times = HashMap::<
+            EntityEditionId,
+            (Timestamp<TransactionTime>, Timestamp<DecisionTime>),
+        >::with_capacity(page.len());
+        for row in page {
+            edition_times.insert(row.get(0), (row.get(1), row.get(2)));
+            if let Some(previous_edition_id) = row.get::<_, Option<EntityEditionId>>(3) {
+                edition_times.insert(previous_edition_id, (row.get(4), row.get(5)));
+            }
+        }
+
+        // The editions are read at the time they were created, so they are found even if they
+        // are not visible at the current time anymore.
+        let mut editions = HashMap::with_capacity(edition_times.len());
+        for (edition_id, (transaction_time, decision_time)) in edition_times {
+            let edition = Read::<Entity>::read_one(
+                self,
+                &Filter::Equal(
+                    Some(FilterExpression::Path {
+                        path: EntityQueryPath::EditionId,
+                    }),
+                    Some(FilterExpression::Parameter {
+                        parameter: Parameter::Uuid(edition_id.into_uuid()),
+                        convert: None,
+                    }),
+                ),
+                Some(&QueryTemporalAxes::DecisionTime {
+                    pinned: PinnedTemporalAxis::new(transaction_time),
+                    variable: VariableTemporalAxis::new(
+                        TemporalBound::Inclusive(decision_time),
+                        LimitedTemporalBound::Inclusive(decision_time),
+                    ),
+                }),
+                true,
+            )
+            .await?;
+            editions.insert(edition_id, edition);
+        }
+
+        let history = page
+            .iter()
+            .map(|row| {
+                let previous_edition = row
+                    .get::<_, Option<EntityEditionId>>(3)
+                    .map(|previous_edition_id| &editions[&previous_edition_id]);
+                EntityHistoryEntry::new(
+                    previous_edition,
+                    &editions[&row.get::<_, EntityEditionId>(0)],
+                )
+            })
+            .collect::<Vec<_>>();
+        let cursor = history
+            .last()
+            .filter(|_| rows.len() > page.len())
+            .map(|entry| entry.edition_id);
+
+        Ok(GetEntityHistoryResponse {
+            editions: history,
+            cursor,
+        })
     }
 
     #[tracing::instrument(level = "info", skip(self, params))]
@@ -2131,6 +2001,7 @@ where
         confidence: Option<Confidence>,
         provenance: &EntityEditionProvenance,
         metadata: &PropertyMetadataObject,
+        link_data: Option<&LinkData>,
     ) -> Result<EntityEditionId, Report<InsertionError>> {
         let edition_id: EntityEditionId = self
             .as_client()
@@ -2142,11 +2013,25 @@ where
                         properties,
                         confidence,
                         provenance,
-                        property_metadata
-                    ) VALUES (gen_random_uuid(), $1, $2, $3, $4, $5)
+                        property_metadata,
+                        left_entity_confidence,
+                        left_entity_provenance,
+                        right_entity_confidence,
+                        right_entity_provenance
+                    ) VALUES (gen_random_uuid(), $1, $2, $3, $4, $5, $6, $7, $8, $9)
                     RETURNING entity_edition_id;
                 ",
-                &[&archived, &properties, &confidence, provenance, metadata],
+                &[
+                    &archived,
+                    &properties,
+                    &confidence,
+                    provenance,
+                    metadata,
+                    &link_data.and_then(|link_data| link_data.left_entity_confidence),
+                    &link_data.map(|link_data| &link_data.left_entity_provenance),
+                    &link_data.and_then(|link_data| link_data.right_entity_confidence),
+                    &link_data.map(|link_data| &link_data.right_entity_provenance),
+                ],
             )
             .await
             .change_context(InsertionError)?
@@ -2581,3 +2466,1003 @@ where
         })
     }
 }
+
+/// Entities written by [`PostgresStore::insert_entities`] which are not validated yet.
+#[derive(Debug)]
+struct InsertedEntities {
+    entities: Vec<Entity>,
+    relationships: Vec<(EntityId, EntityRelationAndSubject)>,
+    validation_params: Vec<(ClosedMultiEntityType, ValidateEntityComponents)>,
+    validation_reports: HashMap<usize, EntityValidationReport>,
+    store_cache: StoreCache,
+}
+
+impl<A> PostgresStore<tokio_postgres::Transaction<'_>, A>
+where
+    A: AuthorizationApi,
+{
+    /// Writes the rows of the entities to the database.
+    ///
+    /// The returned relationships are not written and no workflows are started, so this can be
+    /// used as part of a larger transaction. The entities have to be validated by
+    /// [`validate_inserted_entities`] afterwards.
+    ///
+    /// [`validate_inserted_entities`]: Self::validate_inserted_entities
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn insert_entities<R>(
+        &self,
+        actor_id: AccountId,
+        params: Vec<CreateEntityParams<R>>,
+    ) -> Result<InsertedEntities, Report<InsertionError>>
+    where
+        R: IntoIterator<Item = EntityRelationAndSubject> + Send,
+    {
+        let transaction_time = Timestamp::<TransactionTime>::now().remove_nanosecond();
+        let mut relationships = Vec::with_capacity(params.len());
+        let mut entity_type_ids = HashMap::new();
+        let mut checked_web_ids = HashSet::new();
+        let mut entity_edition_ids = Vec::with_capacity(params.len());
+
+        let mut entity_id_rows = Vec::with_capacity(params.len());
+        let mut entity_draft_rows = Vec::new();
+        let mut entity_edition_rows = Vec::with_capacity(params.len());
+        let mut entity_temporal_metadata_rows = Vec::with_capacity(params.len());
+        let mut entity_is_of_type_rows = Vec::with_capacity(params.len());
+        let mut entity_has_left_entity_rows = Vec::new();
+        let mut entity_has_right_entity_rows = Vec::new();
+
+        let mut entities = Vec::with_capacity(params.len());
+        // TODO: There are expected to be duplicates but we currently don't have a way to identify
+        //       multi-type entity types. We need a way to speed this up.
+        let mut validation_params = Vec::with_capacity(params.len());
+
+        let validator_provider = StoreProvider {
+            store: self,
+            cache: StoreCache::default(),
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+
+        let mut validation_reports = HashMap::<usize, EntityValidationReport>::new();
+        for (index, mut params) in params.into_iter().enumerate() {
+            let entity_type = ClosedMultiEntityType::from_multi_type_closed_schema(
+                stream::iter(&params.entity_type_ids)
+                    .then(|entity_type_url| async {
+                        OntologyTypeProvider::<ClosedEntityType>::provide_type(
+                            &validator_provider,
+                            entity_type_url,
+                        )
+                        .await
+                        .map(|entity_type| (*entity_type).clone())
+                    })
+                    .try_collect::<Vec<ClosedEntityType>>()
+                    .await
+                    .change_context(InsertionError)?,
+            )
+            .change_context(InsertionError)?;
+
+            let mut preprocessor = EntityPreprocessor {
+                components: if params.draft {
+                    ValidateEntityComponents::draft()
+                } else {
+                    ValidateEntityComponents::full()
+                },
+            };
+            preprocessor.components.link_validation = self.settings.validate_links;
+
+            if let Err(property_validation) = preprocessor
+                .visit_object(&entity_type, &mut params.properties, &validator_provider)
+                .await
+            {
+                validation_reports.entry(index).or_default().properties =
+                    property_validation.properties;
+            }
+
+            let (properties, property_metadata) = params.properties.into_parts();
+
+            let decision_time = params
+                .decision_time
+                .map_or_else(|| transaction_time.cast(), Timestamp::remove_nanosecond);
+            let entity_id = EntityId {
+                owned_by_id: params.owned_by_id,
+                entity_uuid: params
+                    .entity_uuid
+                    .unwrap_or_else(|| EntityUuid::new(Uuid::new_v4())),
+                draft_id: params.draft.then(|| DraftId::new(Uuid::new_v4())),
+            };
+
+            if entity_id.entity_uuid.as_uuid() != entity_id.owned_by_id.as_uuid() {
+                checked_web_ids.insert(entity_id.owned_by_id);
+            }
+
+            let entity_provenance = EntityProvenance {
+                inferred: InferredEntityProvenance {
+                    created_by_id: CreatedById::new(actor_id),
+                    created_at_transaction_time: transaction_time,
+                    created_at_decision_time: decision_time,
+                    first_non_draft_created_at_transaction_time: entity_id
+                        .draft_id
+                        .is_none()
+                        .then_some(transaction_time),
+                    first_non_draft_created_at_decision_time: entity_id
+                        .draft_id
+                        .is_none()
+                        .then_some(decision_time),
+                },
+                edition: EntityEditionProvenance {
+                    created_by_id: EditionCreatedById::new(actor_id),
+                    archived_by_id: None,
+                    provided: params.provenance,
+                },
+            };
+            entity_id_rows.push(EntityIdRow {
+                web_id: entity_id.owned_by_id,
+                entity_uuid: entity_id.entity_uuid,
+                provenance: entity_provenance.inferred.clone(),
+            });
+            if let Some(draft_id) = entity_id.draft_id {
+                entity_draft_rows.push(EntityDraftRow {
+                    web_id: entity_id.owned_by_id,
+                    entity_uuid: entity_id.entity_uuid,
+                    draft_id,
+                });
+            }
+
+            let entity_edition_id = EntityEditionId::new(Uuid::new_v4());
+            entity_edition_rows.push(EntityEditionRow {
+                entity_edition_id,
+                properties: properties.clone(),
+                archived: false,
+                confidence: params.confidence,
+                provenance: entity_provenance.edition.clone(),
+                property_metadata: property_metadata.clone(),
+                left_entity_confidence: params
+                    .link_data
+                    .as_ref()
+                    .and_then(|link_data| link_data.left_entity_confidence),
+                left_entity_provenance: params
+                    .link_data
+                    .as_ref()
+                    .map(|link_data| link_data.left_entity_provenance.clone()),
+                right_entity_confidence: params
+                    .link_data
+                    .as_ref()
+                    .and_then(|link_data| link_data.right_entity_confidence),
+                right_entity_provenance: params
+                    .link_data
+                    .as_ref()
+                    .map(|link_data| link_data.right_entity_provenance.clone()),
+            });
+            entity_edition_ids.push(entity_edition_id);
+
+            let temporal_versioning = EntityTemporalMetadata {
+                decision_time: LeftClosedTemporalInterval::new(
+                    ClosedTemporalBound::Inclusive(decision_time),
+                    OpenTemporalBound::Unbounded,
+                ),
+                transaction_time: LeftClosedTemporalInterval::new(
+                    ClosedTemporalBound::Inclusive(transaction_time),
+                    OpenTemporalBound::Unbounded,
+                ),
+            };
+            entity_temporal_metadata_rows.push(EntityTemporalMetadataRow {
+                web_id: entity_id.owned_by_id,
+                entity_uuid: entity_id.entity_uuid,
+                draft_id: entity_id.draft_id,
+                entity_edition_id,
+                decision_time: temporal_versioning.decision_time,
+                transaction_time: temporal_versioning.transaction_time,
+            });
+
+            for entity_type in &entity_type.all_of {
+                let entity_type_id = EntityTypeUuid::from_url(&entity_type.id);
+                entity_type_ids.insert(entity_type_id, entity_type.id.clone());
+                entity_is_of_type_rows.push(EntityIsOfTypeRow {
+                    entity_edition_id,
+                    entity_type_ontology_id: entity_type_id,
+                    inheritance_depth: InheritanceDepth::new(0),
+                });
+            }
+
+            let link_data = params.link_data.inspect(|link_data| {
+                entity_has_left_entity_rows.push(EntityHasLeftEntityRow {
+                    web_id: entity_id.owned_by_id,
+                    entity_uuid: entity_id.entity_uuid,
+                    left_web_id: link_data.left_entity_id.owned_by_id,
+                    left_entity_uuid: link_data.left_entity_id.entity_uuid,
+                });
+                entity_has_right_entity_rows.push(EntityHasRightEntityRow {
+                    web_id: entity_id.owned_by_id,
+                    entity_uuid: entity_id.entity_uuid,
+                    right_web_id: link_data.right_entity_id.owned_by_id,
+                    right_entity_uuid: link_data.right_entity_id.entity_uuid,
+                });
+            });
+
+            entities.push(Entity {
+                properties,
+                link_data,
+                metadata: EntityMetadata {
+                    record_id: EntityRecordId {
+                        entity_id,
+                        edition_id: entity_edition_id,
+                    },
+                    temporal_versioning,
+                    entity_type_ids: params.entity_type_ids,
+                    archived: false,
+                    provenance: entity_provenance,
+                    confidence: params.confidence,
+                    properties: property_metadata,
+                },
+            });
+
+            validation_params.push((entity_type, preprocessor.components));
+
+            let current_num_relationships = relationships.len();
+            relationships.extend(
+                params
+                    .relationships
+                    .into_iter()
+                    .chain(once(EntityRelationAndSubject::Owner {
+                        subject: EntityOwnerSubject::Web {
+                            id: params.owned_by_id,
+                        },
+                        level: 0,
+                    }))
+                    .map(|relation_and_subject| (entity_id, relation_and_subject)),
+            );
+            if relationships.len() == current_num_relationships {
+                return Err(Report::new(InsertionError)
+                    .attach_printable("At least one relationship must be provided"));
+            }
+        }
+        let store_cache = validator_provider.cache;
+
+        let (instantiate_permissions, zookie) = self
+            .authorization_api
+            .check_entity_types_permission(
+                actor_id,
+                EntityTypePermission::Instantiate,
+                entity_type_ids.keys().copied(),
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(InsertionError)?;
+        let forbidden_instantiations = instantiate_permissions
+            .iter()
+            .filter_map(|(entity_type_id, permission)| {
+                if *permission {
+                    None
+                } else {
+                    entity_type_ids.get(entity_type_id)
+                }
+            })
+            .collect::<Vec<_>>();
+        if !forbidden_instantiations.is_empty() {
+            return Err(Report::new(InsertionError)
+                .attach(StatusCode::PermissionDenied)
+                .attach_printable(
+                    "The actor does not have permission to instantiate one or more entity types",
+                )
+                .attach_printable(
+                    forbidden_instantiations
+                        .into_iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ));
+        }
+
+        if !checked_web_ids.is_empty() {
+            let (create_entity_permissions, _zookie) = self
+                .authorization_api
+                .check_webs_permission(
+                    actor_id,
+                    WebPermission::CreateEntity,
+                    checked_web_ids,
+                    Consistency::AtLeastAsFresh(&zookie),
+                )
+                .await
+                .change_context(InsertionError)?;
+            let forbidden_webs = create_entity_permissions
+                .iter()
+                .filter_map(
+                    |(web_id, permission)| {
+                        if *permission { None } else { Some(web_id) }
+                    },
+                )
+                .collect::<Vec<_>>();
+            if !forbidden_webs.is_empty() {
+                return Err(Report::new(InsertionError)
+                    .attach(StatusCode::PermissionDenied)
+                    .attach_printable(
+                        "The actor does not have permission to create entities for one or more \
+                         web ids",
+                    )
+                    .attach_printable(
+                        forbidden_webs
+                            .into_iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ));
+            }
+        }
+
+        let insertions = [
+            InsertStatementBuilder::from_rows(Table::EntityIds, &entity_id_rows),
+            InsertStatementBuilder::from_rows(Table::EntityDrafts, &entity_draft_rows),
+            InsertStatementBuilder::from_rows(Table::EntityEditions, &entity_edition_rows),
+            InsertStatementBuilder::from_rows(
+                Table::EntityTemporalMetadata,
+                &entity_temporal_metadata_rows,
+            ),
+            InsertStatementBuilder::from_rows(Table::EntityIsOfType, &entity_is_of_type_rows),
+            InsertStatementBuilder::from_rows(
+                Table::EntityHasLeftEntity,
+                &entity_has_left_entity_rows,
+            ),
+            InsertStatementBuilder::from_rows(
+                Table::EntityHasRightEntity,
+                &entity_has_right_entity_rows,
+            ),
+        ];
+
+        for statement in insertions {
+            let (statement, parameters) = statement.compile();
+            self.as_client()
+                .query(&statement, &parameters)
+                .await
+                .change_context(InsertionError)?;
+        }
+
+        self.as_client()
+            .query(
+                "
+                    INSERT INTO entity_is_of_type
+                    SELECT entity_edition_id,
+                        target_entity_type_ontology_id AS entity_type_ontology_id,
+                        MIN(entity_type_inherits_from.depth + 1) AS inheritance_depth
+                    FROM entity_is_of_type
+                    JOIN entity_type_inherits_from
+                        ON entity_type_ontology_id = source_entity_type_ontology_id
+                    WHERE entity_edition_id = ANY($1)
+                    GROUP BY entity_edition_id, target_entity_type_ontology_id;
+                ",
+                &[&entity_edition_ids],
+            )
+            .await
+            .change_context(InsertionError)?;
+
+        Ok(InsertedEntities {
+            entities,
+            relationships,
+            validation_params,
+            validation_reports,
+            store_cache,
+        })
+    }
+
+    /// Validates the entities written by [`insert_entities`] and publishes their creation.
+    ///
+    /// [`insert_entities`]: Self::insert_entities
+    #[tracing::instrument(level = "info", skip(self, inserted_entities))]
+    async fn validate_inserted_entities(
+        &self,
+        actor_id: AccountId,
+        inserted_entities: InsertedEntities,
+    ) -> Result<Vec<Entity>, Report<InsertionError>> {
+        let InsertedEntities {
+            entities,
+            relationships: _,
+            validation_params,
+            mut validation_reports,
+            store_cache,
+        } = inserted_entities;
+
+        let validator_provider = StoreProvider {
+            store: self,
+            cache: store_cache,
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+
+        for (index, (entity, (schema, components))) in
+            entities.iter().zip(validation_params).enumerate()
+        {
+            let validation_report = entity
+                .validate(&schema, components, &validator_provider)
+                .await;
+            if !validation_report.is_valid() {
+                let report = validation_reports.entry(index).or_default();
+                report.link = validation_report.link;
+                report.metadata.properties = validation_report.property_metadata;
+            }
+        }
+
+        ensure!(
+            validation_reports.is_empty(),
+            Report::new(InsertionError).attach(validation_reports)
+        );
+
+        self.publish_graph_changes(entities.iter().flat_map(|entity| {
+            once(GraphChangeKind::EntityCreated {
+                entity_id: entity.metadata.record_id.entity_id,
+                edition_id: entity.metadata.record_id.edition_id,
+            })
+            .chain(GraphChangeKind::relationship_modified(entity))
+        }))
+        .await?;
+
+        Ok(entities)
+    }
+
+    /// Writes a new edition of the entity without starting any workflows.
+    ///
+    /// Returns the patched entity and whether a new edition was written.
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn update_entity(
+        &self,
+        actor_id: AccountId,
+        mut params: PatchEntityParams,
+    ) -> Result<(Entity, bool), Report<UpdateError>> {
+        let transaction_time = Timestamp::now().remove_nanosecond();
+        let decision_time = params
+            .decision_time
+            .map_or_else(|| transaction_time.cast(), Timestamp::remove_nanosecond);
+        let entity_type_ids = params
+            .entity_type_ids
+            .iter()
+            .map(EntityTypeUuid::from_url)
+            .collect::<Vec<_>>();
+
+        if !self
+            .authorization_api
+            .check_entity_types_permission(
+                actor_id,
+                EntityTypePermission::Instantiate,
+                entity_type_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .0
+            .into_iter()
+            .all(|(_, permission)| permission)
+        {
+            bail!(Report::new(UpdateError).attach(StatusCode::PermissionDenied));
+        }
+
+        self.authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::Update,
+                params.entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)?;
+
+        let locked_row = self
+            .lock_entity_edition(params.entity_id, transaction_time, decision_time)
+            .await?
+            .ok_or_else(|| {
+                Report::new(EntityDoesNotExist)
+                    .attach(StatusCode::NotFound)
+                    .attach_printable(params.entity_id)
+                    .change_context(UpdateError)
+            })?;
+        let ClosedTemporalBound::Inclusive(locked_transaction_time) =
+            *locked_row.transaction_time.start();
+        let ClosedTemporalBound::Inclusive(locked_decision_time) =
+            *locked_row.decision_time.start();
+        let previous_entity = Read::<Entity>::read_one(
+            self,
+            &Filter::Equal(
+                Some(FilterExpression::Path {
+                    path: EntityQueryPath::EditionId,
+                }),
+                Some(FilterExpression::Parameter {
+                    parameter: Parameter::Uuid(locked_row.entity_edition_id.into_uuid()),
+                    convert: None,
+                }),
+            ),
+            Some(&QueryTemporalAxes::DecisionTime {
+                pinned: PinnedTemporalAxis::new(locked_transaction_time),
+                variable: VariableTemporalAxis::new(
+                    TemporalBound::Inclusive(locked_decision_time),
+                    LimitedTemporalBound::Inclusive(locked_decision_time),
+                ),
+            }),
+            true,
+        )
+        .await
+        .change_context(EntityDoesNotExist)
+        .attach(params.entity_id)
+        .change_context(UpdateError)?;
+
+        if let Some(expected_edition_id) = params.expected_edition_id {
+            let edition_id = previous_entity.metadata.record_id.edition_id;
+            if edition_id != expected_edition_id {
+                bail!(
+                    Report::new(EntityEditionMismatch {
+                        expected: expected_edition_id,
+                        actual: edition_id,
+                    })
+                    .attach(StatusCode::Aborted)
+                    .change_context(UpdateError)
+                );
+            }
+        }
+
+        let mut link_data = previous_entity.link_data.clone();
+        if let Some(patched_link_data) = params.link_data {
+            ensure!(
+                link_data.as_ref().is_some_and(|link_data| {
+                    link_data.left_entity_id == patched_link_data.left_entity_id
+                        && link_data.right_entity_id == patched_link_data.right_entity_id
+                }),
+                Report::new(LinkedEntitiesMismatch)
+                    .attach(StatusCode::InvalidArgument)
+                    .change_context(UpdateError)
+            );
+            link_data = Some(patched_link_data);
+        }
+
+        let mut first_non_draft_created_at_decision_time = previous_entity
+            .metadata
+            .provenance
+            .inferred
+            .first_non_draft_created_at_decision_time;
+        let mut first_non_draft_created_at_transaction_time = previous_entity
+            .metadata
+            .provenance
+            .inferred
+            .first_non_draft_created_at_transaction_time;
+
+        let was_draft_before = previous_entity
+            .metadata
+            .record_id
+            .entity_id
+            .draft_id
+            .is_some();
+        let draft = params.draft.unwrap_or(was_draft_before);
+        let archived = params.archived.unwrap_or(previous_entity.metadata.archived);
+        let (entity_type_ids, entity_types_updated) = if params.entity_type_ids.is_empty() {
+            (previous_entity.metadata.entity_type_ids, false)
+        } else {
+            let added_types = previous_entity
+                .metadata
+                .entity_type_ids
+                .difference(&params.entity_type_ids);
+            let removed_types = params
+                .entity_type_ids
+                .difference(&previous_entity.metadata.entity_type_ids);
+
+            let mut has_changed = false;
+            for entity_type_id in added_types.chain(removed_types) {
+                has_changed = true;
+
+                let entity_type_id = EntityTypeUuid::from_url(entity_type_id);
+                self.authorization_api
+                    .check_entity_type_permission(
+                        actor_id,
+                        EntityTypePermission::Instantiate,
+                        entity_type_id,
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(UpdateError)?
+                    .assert_permission()
+                    .change_context(UpdateError)
+                    .attach(StatusCode::PermissionDenied)?;
+            }
+
+            (params.entity_type_ids, has_changed)
+        };
+
+        let previous_properties = previous_entity.properties.clone();
+        let previous_property_metadata = previous_entity.metadata.properties.clone();
+
+        let mut properties_with_metadata = PropertyWithMetadata::from_parts(
+            Property::Object(previous_entity.properties),
+            Some(PropertyMetadata::Object {
+                value: previous_entity.metadata.properties.value,
+                metadata: previous_entity.metadata.properties.metadata,
+            }),
+        )
+        .change_context(UpdateError)?;
+        properties_with_metadata
+            .patch(params.properties)
+            .change_context(UpdateError)?;
+
+        let validator_provider = StoreProvider {
+            store: self,
+            cache: StoreCache::default(),
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+        let entity_type = ClosedMultiEntityType::from_multi_type_closed_schema(
+            stream::iter(&entity_type_ids)
+                .then(|entity_type_url| async {
+                    OntologyTypeProvider::<ClosedEntityType>::provide_type(
+                        &validator_provider,
+                        entity_type_url,
+                    )
+                    .await
+                    .map(|entity_type| (*entity_type).clone())
+                })
+                .try_collect::<Vec<ClosedEntityType>>()
+                .await
+                .change_context(UpdateError)?,
+        )
+        .change_context(UpdateError)?;
+
+        let mut validation_components = if draft {
+            ValidateEntityComponents::draft()
+        } else {
+            ValidateEntityComponents::full()
+        };
+        validation_components.link_validation = self.settings.validate_links;
+
+        let mut validation_report = EntityValidationReport::default();
+        let (properties, property_metadata) =
+            if let PropertyWithMetadata::Object(mut object) = properties_with_metadata {
+                let mut preprocessor = EntityPreprocessor {
+                    components: validation_components,
+                };
+                if let Err(property_validation) = preprocessor
+                    .visit_object(&entity_type, &mut object, &validator_provider)
+                    .await
+                {
+                    validation_report.properties = property_validation.properties;
+                }
+
+                let (properties, property_metadata) = object.into_parts();
+                (properties, property_metadata)
+            } else {
+                unreachable!("patching should not change the property type");
+            };
+        let store_cache = validator_provider.cache;
+
+        #[expect(clippy::needless_collect, reason = "Will be used later")]
+        let diff = previous_properties
+            .diff(&properties, &mut PropertyPath::default())
+            .collect::<Vec<_>>();
+
+        if diff.is_empty()
+            && was_draft_before == draft
+            && archived == previous_entity.metadata.archived
+            && !entity_types_updated
+            && previous_property_metadata == property_metadata
+            && params.confidence == previous_entity.metadata.confidence
+            && link_data == previous_entity.link_data
+            && params.provenance.merged_entity_ids.is_empty()
+        {
+            // No changes were made to the entity. Merges are always recorded in a new edition.
+            return Ok((
+                Entity {
+                    properties: previous_properties,
+                    link_data,
+                    metadata: EntityMetadata {
+                        record_id: previous_entity.metadata.record_id,
+                        temporal_versioning: previous_entity.metadata.temporal_versioning,
+                        entity_type_ids,
+                        provenance: previous_entity.metadata.provenance,
+                        archived,
+                        confidence: previous_entity.metadata.confidence,
+                        properties: property_metadata,
+                    },
+                },
+                false,
+            ));
+        }
+
+        let edition_provenance = EntityEditionProvenance {
+            created_by_id: EditionCreatedById::new(actor_id),
+            archived_by_id: None,
+            provided: params.provenance,
+        };
+        let edition_id = self
+            .insert_entity_edition(
+                archived,
+                &entity_type_ids,
+                &properties,
+                params.confidence,
+                &edition_provenance,
+                &property_metadata,
+                link_data.as_ref(),
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        let temporal_versioning = match (was_draft_before, draft) {
+            (true, true) | (false, false) => {
+                // regular update
+                self.update_temporal_metadata(
+                    locked_row,
+                    transaction_time,
+                    decision_time,
+                    edition_id,
+                    false,
+                )
+                .await?
+            }
+            (false, true) => {
+                let draft_id = DraftId::new(Uuid::new_v4());
+                self.as_client()
+                    .query(
+                        "
+                        INSERT INTO entity_drafts (
+                            web_id,
+                            entity_uuid,
+                            draft_id
+                        ) VALUES ($1, $2, $3);",
+                        &[
+                            &params.entity_id.owned_by_id,
+                            &params.entity_id.entity_uuid,
+                            &draft_id,
+                        ],
+                    )
+                    .await
+                    .change_context(UpdateError)?;
+                params.entity_id.draft_id = Some(draft_id);
+                self.insert_temporal_metadata(
+                    params.entity_id,
+                    edition_id,
+                    transaction_time,
+                    decision_time,
+                )
+                .await
+                .change_context(UpdateError)?
+            }
+            (true, false) => {
+                // Publish a draft
+                params.entity_id.draft_id = None;
+
+                if first_non_draft_created_at_decision_time.is_none() {
+                    self.as_client()
+                        .query(
+                            "
+                            UPDATE entity_ids
+                            SET provenance = provenance || JSONB_BUILD_OBJECT(
+                                'firstNonDraftCreatedAtTransactionTime', $1::TIMESTAMPTZ,
+                                'firstNonDraftCreatedAtDecisionTime', $2::TIMESTAMPTZ
+                            )
+                            WHERE web_id = $3
+                              AND entity_uuid = $4;
+                            ",
+                            &[
+                                &transaction_time,
+                                &decision_time,
+                                &params.entity_id.owned_by_id,
+                                &params.entity_id.entity_uuid,
+                            ],
+                        )
+                        .await
+                        .change_context(UpdateError)?;
+
+                    first_non_draft_created_at_transaction_time = Some(transaction_time);
+                    first_non_draft_created_at_decision_time = Some(decision_time);
+                }
+
+                if let Some(previous_live_entity) = self
+                    .lock_entity_edition(params.entity_id, transaction_time, decision_time)
+                    .await?
+                {
+                    self.archive_entity(
+                        actor_id,
+                        previous_live_entity,
+                        transaction_time,
+                        decision_time,
+                    )
+                    .await?;
+                }
+                self.update_temporal_metadata(
+                    locked_row,
+                    transaction_time,
+                    decision_time,
+                    edition_id,
+                    true,
+                )
+                .await?
+            }
+        };
+
+        let entity_metadata = EntityMetadata {
+            record_id: EntityRecordId {
+                entity_id: params.entity_id,
+                edition_id,
+            },
+            temporal_versioning,
+            entity_type_ids,
+            provenance: EntityProvenance {
+                inferred: InferredEntityProvenance {
+                    first_non_draft_created_at_transaction_time,
+                    first_non_draft_created_at_decision_time,
+                    ..previous_entity.metadata.provenance.inferred
+                },
+                edition: edition_provenance,
+            },
+            confidence: params.confidence,
+            properties: property_metadata,
+            archived,
+        };
+        let entity = Entity {
+            properties,
+            link_data,
+            metadata: entity_metadata,
+        };
+
+        let validator_provider = StoreProvider {
+            store: self,
+            cache: store_cache,
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+        let post_validation_report = entity
+            .validate(&entity_type, validation_components, &validator_provider)
+            .await;
+        validation_report.link = post_validation_report.link;
+        validation_report.metadata.properties = post_validation_report.property_metadata;
+
+        ensure!(
+            validation_report.is_valid(),
+            Report::new(UpdateError).attach(HashMap::from([(
+                entity.metadata.record_id.entity_id,
+                validation_report
+            )]))
+        );
+
+        let record_id = entity.metadata.record_id;
+        let change = if archived && !previous_entity.metadata.archived {
+            GraphChangeKind::EntityArchived {
+                entity_id: record_id.entity_id,
+                edition_id: record_id.edition_id,
+            }
+        } else {
+            GraphChangeKind::EntityUpdated {
+                entity_id: record_id.entity_id,
+                edition_id: record_id.edition_id,
+            }
+        };
+        self.publish_graph_changes(
+            once(change).chain(GraphChangeKind::relationship_modified(&entity)),
+        )
+        .await
+        .change_context(UpdateError)?;
+
+        Ok((entity, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::borrow::Cow;
+
+    use hash_graph_store::{
+        entity::{EntityAggregationFunction, EntityQueryPath},
+        filter::{JsonPath, PathToken},
+        subgraph::temporal_axes::QueryTemporalAxesUnresolved,
+    };
+    use hash_graph_types::knowledge::entity::Entity;
+    use postgres_types::ToSql;
+
+    use super::compile_aggregation;
+    use crate::store::postgres::query::{
+        Expression, Function, SelectCompiler, test_helper::trim_whitespace,
+    };
+
+    fn property_json_path(base_url: &'static str) -> JsonPath<'static> {
+        JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(base_url))])
+    }
+
+    fn assert_compilation(
+        compiler: &SelectCompiler<'_, '_, Entity>,
+        expected_statement: &str,
+        expected_parameters: &[&dyn ToSql],
+    ) {
+        let (compiled_statement, compiled_parameters) = compiler.compile();
+
+        assert_eq!(
+            trim_whitespace(&compiled_statement),
+            trim_whitespace(expected_statement),
+            "actual:\n{compiled_statement}\nexpected: {expected_statement}"
+        );
+        assert_eq!(
+            compiled_parameters
+                .iter()
+                .map(|parameter| format!("{parameter:?}"))
+                .collect::<Vec<_>>(),
+            expected_parameters
+                .iter()
+                .map(|parameter| format!("{parameter:?}"))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn aggregation_of_json_property() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let age_json_path =
+            property_json_path("https://blockprotocol.org/@alice/types/property-type/age/");
+        let age_path = EntityQueryPath::Properties(Some(age_json_path.clone()));
+
+        let mut compiler = SelectCompiler::<Entity>::new(Some(&temporal_axes), false);
+        let count = compiler.compile_path_column(&age_path);
+        compiler.add_aggregation(compile_aggregation(EntityAggregationFunction::Count, count));
+        let sum = compiler.compile_path_column(&age_path);
+        compiler.add_aggregation(compile_aggregation(EntityAggregationFunction::Sum, sum));
+
+        assert_compilation(
+            &compiler,
+            r#"
+            SELECT
+                (COUNT(jsonb_path_query_first("entity_editions_0_1_0"."properties", (($3::text)::jsonpath)))::double precision),
+                (SUM((jsonb_path_query_first(to_jsonb(jsonb_path_query_first("entity_editions_0_1_0"."properties", (($4::text)::jsonpath))), '$ ? (@.type() == "number")')::double precision))::double precision)
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+              AND "entity_temporal_metadata_0_0_0"."transaction_time" @> $1::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $2
+            "#,
+            &[
+                &temporal_axes.pinned_timestamp(),
+                &temporal_axes.variable_interval(),
+                &age_json_path,
+                &age_json_path,
+            ],
+        );
+    }
+
+    #[test]
+    fn grouped_aggregation_of_json_property() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let name_json_path =
+            property_json_path("https://blockprotocol.org/@alice/types/property-type/name/");
+        let name_path = EntityQueryPath::Properties(Some(name_json_path.clone()));
+        let age_json_path =
+            property_json_path("https://blockprotocol.org/@alice/types/property-type/age/");
+        let age_path = EntityQueryPath::Properties(Some(age_json_path.clone()));
+
+        let mut compiler = SelectCompiler::<Entity>::new(Some(&temporal_axes), false);
+        compiler.add_grouping_path(&name_path);
+        compiler.add_aggregation(Expression::Function(Function::Count(Box::new(
+            Expression::Asterisk,
+        ))));
+        for function in [
+            EntityAggregationFunction::Avg,
+            EntityAggregationFunction::Min,
+            EntityAggregationFunction::Max,
+        ] {
+            let expression = compiler.compile_path_column(&age_path);
+            compiler.add_aggregation(compile_aggregation(function, expression));
+        }
+
+        assert_compilation(
+            &compiler,
+            r#"
+            SELECT
+                to_jsonb(jsonb_path_query_first("entity_editions_0_1_0"."properties", (($3::text)::jsonpath))),
+                COUNT(*),
+                (AVG((jsonb_path_query_first(to_jsonb(jsonb_path_query_first("entity_editions_0_1_0"."properties", (($4::text)::jsonpath))), '$ ? (@.type() == "number")')::double precision))::double precision),
+                (MIN((jsonb_path_query_first(to_jsonb(jsonb_path_query_first("entity_editions_0_1_0"."properties", (($5::text)::jsonpath))), '$ ? (@.type() == "number")')::double precision))::double precision),
+                (MAX((jsonb_path_query_first(to_jsonb(jsonb_path_query_first("entity_editions_0_1_0"."properties", (($6::text)::jsonpath))), '$ ? (@.type() == "number")')::double precision))::double precision)
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+              AND "entity_temporal_metadata_0_0_0"."transaction_time" @> $1::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $2
+            GROUP BY jsonb_path_query_first("entity_editions_0_1_0"."properties", (($3::text)::jsonpath))
+            "#,
+            &[
+                &temporal_axes.pinned_timestamp(),
+                &temporal_axes.variable_interval(),
+                &name_json_path,
+                &age_json_path,
+                &age_json_path,
+                &age_json_path,
+            ],
+        );
+    }
+}
@@ -1,3 +1,4 @@
+mod change_feed;
 mod crud;
 mod knowledge;
 mod migration;
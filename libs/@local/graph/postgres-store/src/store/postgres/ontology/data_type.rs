@@ -11,6 +11,7 @@ use hash_graph_authorization::{
     zanzibar::{Consistency, Zookie},
 };
 use hash_graph_store::{
+    change_feed::GraphChangeKind,
     data_type::{
         ArchiveDataTypeParams, CountDataTypesParams, CreateDataTypeParams, DataTypeQueryPath,
         DataTypeStore, GetDataTypeSubgraphParams, GetDataTypeSubgraphResponse, GetDataTypesParams,
@@ -588,6 +589,14 @@ where
             .await
             .change_context(InsertionError)?;
 
+        transaction
+            .publish_graph_changes(inserted_data_type_metadata.iter().map(|metadata| {
+                GraphChangeKind::TypeCreated {
+                    ontology_type_id: VersionedUrl::from(metadata.record_id.clone()),
+                }
+            }))
+            .await?;
+
         if let Err(error) = transaction.commit().await.change_context(InsertionError) {
             let mut error = error.expand();
 
@@ -950,6 +959,13 @@ where
             .await
             .change_context(UpdateError)?;
 
+        transaction
+            .publish_graph_changes([GraphChangeKind::TypeCreated {
+                ontology_type_id: schema.id.clone(),
+            }])
+            .await
+            .change_context(UpdateError)?;
+
         if let Err(error) = transaction.commit().await.change_context(UpdateError) {
             let mut error = error.expand();
 
@@ -998,8 +1014,21 @@ where
         actor_id: AccountId,
         params: ArchiveDataTypeParams<'_>,
     ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
-        self.archive_ontology_type(&params.data_type_id, EditionArchivedById::new(actor_id))
+        let transaction = self.transaction().await.change_context(UpdateError)?;
+
+        let metadata = transaction
+            .archive_ontology_type(&params.data_type_id, EditionArchivedById::new(actor_id))
+            .await?;
+        transaction
+            .publish_graph_changes([GraphChangeKind::TypeArchived {
+                ontology_type_id: params.data_type_id.into_owned(),
+            }])
             .await
+            .change_context(UpdateError)?;
+
+        transaction.commit().await.change_context(UpdateError)?;
+
+        Ok(metadata)
     }
 
     #[tracing::instrument(level = "info", skip(self))]
@@ -1008,12 +1037,25 @@ where
         actor_id: AccountId,
         params: UnarchiveDataTypeParams,
     ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
-        self.unarchive_ontology_type(&params.data_type_id, &OntologyEditionProvenance {
-            created_by_id: EditionCreatedById::new(actor_id),
-            archived_by_id: None,
-            user_defined: params.provenance,
-        })
-        .await
+        let transaction = self.transaction().await.change_context(UpdateError)?;
+
+        let metadata = transaction
+            .unarchive_ontology_type(&params.data_type_id, &OntologyEditionProvenance {
+                created_by_id: EditionCreatedById::new(actor_id),
+                archived_by_id: None,
+                user_defined: params.provenance,
+            })
+            .await?;
+        transaction
+            .publish_graph_changes([GraphChangeKind::TypeUnarchived {
+                ontology_type_id: params.data_type_id,
+            }])
+            .await
+            .change_context(UpdateError)?;
+
+        transaction.commit().await.change_context(UpdateError)?;
+
+        Ok(metadata)
     }
 
     #[tracing::instrument(level = "info", skip(self, params))]
@@ -13,6 +13,7 @@ use hash_graph_authorization::{
     zanzibar::{Consistency, Zookie},
 };
 use hash_graph_store::{
+    change_feed::GraphChangeKind,
     entity_type::{
         ArchiveEntityTypeParams, ClosedDataTypeDefinition, CountEntityTypesParams,
         CreateEntityTypeParams, EntityTypeQueryPath, EntityTypeResolveDefinitions, EntityTypeStore,
@@ -921,6 +922,14 @@ where
             .await
             .change_context(InsertionError)?;
 
+        transaction
+            .publish_graph_changes(inserted_entity_type_metadata.iter().map(|metadata| {
+                GraphChangeKind::TypeCreated {
+                    ontology_type_id: VersionedUrl::from(metadata.record_id.clone()),
+                }
+            }))
+            .await?;
+
         if let Err(error) = transaction.commit().await.change_context(InsertionError) {
             let mut error = error.expand();
 
@@ -1346,6 +1355,13 @@ where
             .await
             .change_context(UpdateError)?;
 
+        transaction
+            .publish_graph_changes([GraphChangeKind::TypeCreated {
+                ontology_type_id: schema.id.clone(),
+            }])
+            .await
+            .change_context(UpdateError)?;
+
         if let Err(error) = transaction.commit().await.change_context(UpdateError) {
             let mut error = error.expand();
 
@@ -1397,8 +1413,21 @@ where
         actor_id: AccountId,
         params: ArchiveEntityTypeParams<'_>,
     ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
-        self.archive_ontology_type(&params.entity_type_id, EditionArchivedById::new(actor_id))
+        let transaction = self.transaction().await.change_context(UpdateError)?;
+
+        let metadata = transaction
+            .archive_ontology_type(&params.entity_type_id, EditionArchivedById::new(actor_id))
+            .await?;
+        transaction
+            .publish_graph_changes([GraphChangeKind::TypeArchived {
+                ontology_type_id: params.entity_type_id.into_owned(),
+            }])
             .await
+            .change_context(UpdateError)?;
+
+        transaction.commit().await.change_context(UpdateError)?;
+
+        Ok(metadata)
     }
 
     #[tracing::instrument(level = "info", skip(self))]
@@ -1407,12 +1436,25 @@ where
         actor_id: AccountId,
         params: UnarchiveEntityTypeParams<'_>,
     ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
-        self.unarchive_ontology_type(&params.entity_type_id, &OntologyEditionProvenance {
-            created_by_id: EditionCreatedById::new(actor_id),
-            archived_by_id: None,
-            user_defined: params.provenance,
-        })
-        .await
+        let transaction = self.transaction().await.change_context(UpdateError)?;
+
+        let metadata = transaction
+            .unarchive_ontology_type(&params.entity_type_id, &OntologyEditionProvenance {
+                created_by_id: EditionCreatedById::new(actor_id),
+                archived_by_id: None,
+                user_defined: params.provenance,
+            })
+            .await?;
+        transaction
+            .publish_graph_changes([GraphChangeKind::TypeUnarchived {
+                ontology_type_id: params.entity_type_id.into_owned(),
+            }])
+            .await
+            .change_context(UpdateError)?;
+
+        transaction.commit().await.change_context(UpdateError)?;
+
+        Ok(metadata)
     }
 
     #[tracing::instrument(level = "info", skip(self, params))]
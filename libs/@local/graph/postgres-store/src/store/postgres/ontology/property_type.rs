@@ -13,6 +13,7 @@ use hash_graph_authorization::{
     zanzibar::{Consistency, Zookie},
 };
 use hash_graph_store::{
+    change_feed::GraphChangeKind,
     error::{InsertionError, QueryError, UpdateError},
     property_type::{
         ArchivePropertyTypeParams, CountPropertyTypesParams, CreatePropertyTypeParams,
@@ -480,6 +481,14 @@ where
             .await
             .change_context(InsertionError)?;
 
+        transaction
+            .publish_graph_changes(inserted_property_type_metadata.iter().map(|metadata| {
+                GraphChangeKind::TypeCreated {
+                    ontology_type_id: VersionedUrl::from(metadata.record_id.clone()),
+                }
+            }))
+            .await?;
+
         if let Err(error) = transaction.commit().await.change_context(InsertionError) {
             let mut error = error.expand();
 
@@ -756,6 +765,13 @@ where
             .await
             .change_context(UpdateError)?;
 
+        transaction
+            .publish_graph_changes([GraphChangeKind::TypeCreated {
+                ontology_type_id: params.schema.id.clone(),
+            }])
+            .await
+            .change_context(UpdateError)?;
+
         if let Err(error) = transaction.commit().await.change_context(UpdateError) {
             let mut error = error.expand();
 
@@ -807,8 +823,21 @@ where
         actor_id: AccountId,
         params: ArchivePropertyTypeParams<'_>,
     ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
-        self.archive_ontology_type(&params.property_type_id, EditionArchivedById::new(actor_id))
+        let transaction = self.transaction().await.change_context(UpdateError)?;
+
+        let metadata = transaction
+            .archive_ontology_type(&params.property_type_id, EditionArchivedById::new(actor_id))
+            .await?;
+        transaction
+            .publish_graph_changes([GraphChangeKind::TypeArchived {
+                ontology_type_id: params.property_type_id.into_owned(),
+            }])
             .await
+            .change_context(UpdateError)?;
+
+        transaction.commit().await.change_context(UpdateError)?;
+
+        Ok(metadata)
     }
 
     #[tracing::instrument(level = "info", skip(self))]
@@ -817,12 +846,25 @@ where
         actor_id: AccountId,
         params: UnarchivePropertyTypeParams<'_>,
     ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
-        self.unarchive_ontology_type(&params.property_type_id, &OntologyEditionProvenance {
-            created_by_id: EditionCreatedById::new(actor_id),
-            archived_by_id: None,
-            user_defined: params.provenance,
-        })
-        .await
+        let transaction = self.transaction().await.change_context(UpdateError)?;
+
+        let metadata = transaction
+            .unarchive_ontology_type(&params.property_type_id, &OntologyEditionProvenance {
+                created_by_id: EditionCreatedById::new(actor_id),
+                archived_by_id: None,
+                user_defined: params.provenance,
+            })
+            .await?;
+        transaction
+            .publish_graph_changes([GraphChangeKind::TypeUnarchived {
+                ontology_type_id: params.property_type_id.into_owned(),
+            }])
+            .await
+            .change_context(UpdateError)?;
+
+        transaction.commit().await.change_context(UpdateError)?;
+
+        Ok(metadata)
     }
 
     #[tracing::instrument(level = "info", skip(self, params))]
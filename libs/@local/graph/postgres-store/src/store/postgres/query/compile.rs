@@ -335,6 +335,39 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
         }
     }
 
+    /// Adds a new path to the selection and groups the result by it.
+    ///
+    /// The selected value is converted to JSON, so it can be decoded independently of the type
+    /// of the underlying column.
+    pub fn add_grouping_path(&mut self, path: &'p R::QueryPath<'q>) -> usize
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let expression = self.compile_path_column(path);
+        self.statement
+            .group_by_expression
+            .expressions
+            .push(expression.clone());
+        self.statement.selects.push(SelectExpression::new(
+            Expression::Function(Function::ToJson(Box::new(expression))),
+            None,
+        ));
+        self.statement.selects.len() - 1
+    }
+
+    /// Adds an aggregate expression to the selection.
+    ///
+    /// Aggregates are computed per group added by [`add_grouping_path`] or over all rows if no
+    /// grouping was added.
+    ///
+    /// [`add_grouping_path`]: Self::add_grouping_path
+    pub fn add_aggregation(&mut self, aggregation: Expression) -> usize {
+        self.statement
+            .selects
+            .push(SelectExpression::new(aggregation, None));
+        self.statement.selects.len() - 1
+    }
+
     /// Adds a new path to the selection which can be used as cursor.
     pub fn add_cursor_selection(
         &mut self,
@@ -583,56 +616,44 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
                 self.compile_filter_expression(lhs).0,
                 self.compile_parameter_list(rhs).0,
             ),
-            Filter::StartsWith(lhs, rhs) => {
-                let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
-                let left_filter = if left_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(left_filter)))
-                } else {
-                    left_filter
-                };
-
-                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
-                let right_filter = if right_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
-                } else {
-                    right_filter
-                };
-
-                Condition::StartsWith(left_filter, right_filter)
-            }
-            Filter::EndsWith(lhs, rhs) => {
-                let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
-                let left_filter = if left_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(left_filter)))
-                } else {
-                    left_filter
-                };
-
-                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
-                let right_filter = if right_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
-                } else {
-                    right_filter
-                };
-
-                Condition::EndsWith(left_filter, right_filter)
-            }
-            Filter::ContainsSegment(lhs, rhs) => {
-                let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
-                let left_filter = if left_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(left_filter)))
-                } else {
-                    left_filter
-                };
-
-                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
-                let right_filter = if right_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
+            Filter::StartsWith(lhs, rhs) => Condition::StartsWith(
+                self.compile_text_filter_expression(lhs),
+                self.compile_text_filter_expression(rhs),
+            ),
+            Filter::EndsWith(lhs, rhs) => Condition::EndsWith(
+                self.compile_text_filter_expression(lhs),
+                self.compile_text_filter_expression(rhs),
+            ),
+            Filter::ContainsSegment(lhs, rhs) => Condition::ContainsSegment(
+                self.compile_text_filter_expression(lhs),
+                self.compile_text_filter_expression(rhs),
+            ),
+            Filter::Like(lhs, rhs) => Condition::Like(
+                self.compile_text_filter_expression(lhs),
+                self.compile_text_filter_expression(rhs),
+            ),
+            Filter::ILike(lhs, rhs) => Condition::ILike(
+                self.compile_text_filter_expression(lhs),
+                self.compile_text_filter_expression(rhs),
+            ),
+            Filter::Regex(lhs, rhs) => Condition::Regex(
+                self.compile_text_filter_expression(lhs),
+                self.compile_text_filter_expression(rhs),
+            ),
+            Filter::FullTextMatch(lhs, rhs) => {
+                let (document, document_type) = self.compile_filter_expression(lhs);
+                let document = if document_type == ParameterType::Any {
+                    Function::JsonToTsVector(Box::new(document))
                 } else {
-                    right_filter
+                    Function::ToTsVector(Box::new(document))
                 };
 
-                Condition::ContainsSegment(left_filter, right_filter)
+                Condition::FullTextMatch(
+                    Expression::Function(document),
+                    Expression::Function(Function::WebSearchToTsQuery(Box::new(
+                        self.compile_text_filter_expression(rhs),
+                    ))),
+                )
             }
         }
     }
@@ -811,6 +832,19 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
                     )),
                 ))
             }
+            Some(JsonField::FullTextQuery(query)) => {
+                unreachable!("Full-text query `{query}` should be handled by now")
+            }
+            Some(JsonField::FullTextQueryParameter(index)) => {
+                Expression::Function(Function::TsRank(
+                    Box::new(Expression::Function(Function::JsonToTsVector(Box::new(
+                        column_expression,
+                    )))),
+                    Box::new(Expression::Function(Function::WebSearchToTsQuery(
+                        Box::new(Expression::Parameter(index)),
+                    ))),
+                ))
+            }
             Some(JsonField::StaticText(field)) => {
                 Expression::Function(Function::JsonExtractAsText(
                     Box::new(column_expression),
@@ -902,10 +936,10 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
         match expression {
             FilterExpression::Path { path } => {
                 let (column, json_field) = path.terminating_column();
-                let parameter_type = if let Some(JsonField::StaticText(_)) = json_field {
-                    ParameterType::Text
-                } else {
-                    column.parameter_type()
+                let parameter_type = match json_field {
+                    Some(JsonField::StaticText(_)) => ParameterType::Text,
+                    Some(JsonField::FullTextQuery(_)) => ParameterType::F64,
+                    _ => column.parameter_type(),
                 };
                 (self.compile_path_column(path), parameter_type)
             }
@@ -918,6 +952,22 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
         }
     }
 
+    /// Compiles the `expression` and extracts the text value if it refers to a JSON value.
+    fn compile_text_filter_expression(
+        &mut self,
+        expression: &'p FilterExpression<'q, R>,
+    ) -> Expression
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let (expression, parameter_type) = self.compile_filter_expression(expression);
+        if parameter_type == ParameterType::Any {
+            Expression::Function(Function::JsonExtractText(Box::new(expression)))
+        } else {
+            expression
+        }
+    }
+
     pub fn compile_parameter_list<'f: 'p>(
         &mut self,
         parameters: &'p ParameterList<'f>,
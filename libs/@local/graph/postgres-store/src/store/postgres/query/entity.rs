@@ -27,15 +27,16 @@ impl PostgresQueryPath for EntityQueryPath<'_> {
                 vec![Relation::EntityIds]
             }
             Self::Embedding => vec![Relation::EntityEmbeddings],
-            Self::LeftEntityConfidence | Self::LeftEntityProvenance => vec![Relation::LeftEntity],
-            Self::RightEntityConfidence | Self::RightEntityProvenance => {
-                vec![Relation::RightEntity]
-            }
             Self::Properties(_)
             | Self::Label { .. }
+            | Self::Relevance(_)
             | Self::EditionProvenance(_)
             | Self::Archived
             | Self::EntityConfidence
+            | Self::LeftEntityConfidence
+            | Self::LeftEntityProvenance
+            | Self::RightEntityConfidence
+            | Self::RightEntityProvenance
             | Self::PropertyMetadata(_) => {
                 vec![Relation::EntityEditions]
             }
@@ -164,6 +165,10 @@ impl PostgresQueryPath for EntityQueryPath<'_> {
                     inheritance_depth: *inheritance_depth,
                 }),
             ),
+            Self::Relevance(query) => (
+                Column::EntityEditions(EntityEditions::Properties),
+                Some(JsonField::FullTextQuery(query)),
+            ),
             Self::Provenance(path) => (
                 Column::EntityIds(EntityIds::Provenance),
                 path.as_ref().map(JsonField::JsonPath),
@@ -178,19 +183,19 @@ impl PostgresQueryPath for EntityQueryPath<'_> {
             ),
             Self::EntityConfidence => (Column::EntityEditions(EntityEditions::Confidence), None),
             Self::LeftEntityConfidence => (
-                Column::EntityHasLeftEntity(EntityHasLeftEntity::Confidence),
+                Column::EntityEditions(EntityEditions::LeftEntityConfidence),
                 None,
             ),
             Self::LeftEntityProvenance => (
-                Column::EntityHasLeftEntity(EntityHasLeftEntity::Provenance),
+                Column::EntityEditions(EntityEditions::LeftEntityProvenance),
                 None,
             ),
             Self::RightEntityConfidence => (
-                Column::EntityHasRightEntity(EntityHasRightEntity::Confidence),
+                Column::EntityEditions(EntityEditions::RightEntityConfidence),
                 None,
             ),
             Self::RightEntityProvenance => (
-                Column::EntityHasRightEntity(EntityHasRightEntity::Provenance),
+                Column::EntityEditions(EntityEditions::RightEntityProvenance),
                 None,
             ),
         }
@@ -33,8 +33,8 @@ pub use self::{
     compile::SelectCompiler,
     condition::{Condition, EqualityOperator},
     expression::{
-        Constant, Expression, Function, JoinExpression, OrderByExpression, SelectExpression,
-        WhereExpression, WithExpression,
+        Constant, Expression, Function, JoinExpression, OrderByExpression, PostgresType,
+        SelectExpression, WhereExpression, WithExpression,
     },
     statement::{
         Distinctness, InsertStatementBuilder, SelectStatement, Statement, WindowStatement,
@@ -173,7 +173,7 @@ where
 }
 
 #[cfg(test)]
-mod test_helper {
+pub(crate) mod test_helper {
     use hash_graph_store::data_type::DataTypeQueryPath;
 
     use crate::store::postgres::query::{
@@ -91,6 +91,10 @@ pub struct EntityEditionRow {
     pub confidence: Option<Confidence>,
     pub provenance: EntityEditionProvenance,
     pub property_metadata: PropertyMetadataObject,
+    pub left_entity_confidence: Option<Confidence>,
+    pub left_entity_provenance: Option<PropertyProvenance>,
+    pub right_entity_confidence: Option<Confidence>,
+    pub right_entity_provenance: Option<PropertyProvenance>,
 }
 
 #[derive(Debug, ToSql)]
@@ -112,8 +116,6 @@ pub struct EntityHasLeftEntityRow {
     pub entity_uuid: EntityUuid,
     pub left_web_id: OwnedById,
     pub left_entity_uuid: EntityUuid,
-    pub confidence: Option<Confidence>,
-    pub provenance: PropertyProvenance,
 }
 
 impl PostgresRow for EntityHasLeftEntityRow {
@@ -129,8 +131,6 @@ pub struct EntityHasRightEntityRow {
     pub entity_uuid: EntityUuid,
     pub right_web_id: OwnedById,
     pub right_entity_uuid: EntityUuid,
-    pub confidence: Option<Confidence>,
-    pub provenance: PropertyProvenance,
 }
 
 impl PostgresRow for EntityHasRightEntityRow {
@@ -92,14 +92,14 @@ impl Transpile for SelectStatement {
             self.where_expression.transpile(fmt)?;
         }
 
-        if !self.order_by_expression.is_empty() {
+        if !self.group_by_expression.expressions.is_empty() {
             fmt.write_char('\n')?;
-            self.order_by_expression.transpile(fmt)?;
+            self.group_by_expression.transpile(fmt)?;
         }
 
-        if !self.group_by_expression.expressions.is_empty() {
+        if !self.order_by_expression.is_empty() {
             fmt.write_char('\n')?;
-            self.group_by_expression.transpile(fmt)?;
+            self.order_by_expression.transpile(fmt)?;
         }
 
         if let Some(limit) = self.limit {
@@ -136,7 +136,8 @@ mod tests {
     use uuid::Uuid;
 
     use crate::store::postgres::query::{
-        Distinctness, PostgresRecord, SelectCompiler, test_helper::trim_whitespace,
+        Distinctness, Expression, Function, PostgresRecord, PostgresType, SelectCompiler,
+        test_helper::trim_whitespace,
     };
 
     fn test_compilation<'p, 'q: 'p, T: PostgresRecord + 'static>(
@@ -830,6 +831,95 @@ mod tests {
         );
     }
 
+    #[test]
+    fn entity_aggregation_query() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let mut compiler = SelectCompiler::<Entity>::new(Some(&temporal_axes), false);
+        let json_path = JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+            r#"$."https://blockprotocol.org/@alice/types/property-type/age/""#,
+        ))]);
+        let property_path = EntityQueryPath::Properties(Some(json_path.clone()));
+
+        compiler.add_grouping_path(&EntityQueryPath::OwnedById);
+        compiler.add_aggregation(Expression::Function(Function::Count(Box::new(
+            Expression::Asterisk,
+        ))));
+        let property = compiler.compile_path_column(&property_path);
+        compiler.add_aggregation(Expression::Function(Function::Sum(Box::new(
+            Expression::Cast(Box::new(property), PostgresType::DoublePrecision),
+        ))));
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT
+                to_jsonb("entity_temporal_metadata_0_0_0"."web_id"),
+                COUNT(*),
+                SUM((jsonb_path_query_first("entity_editions_0_1_0"."properties", (($3::text)::jsonpath))::double precision))
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+              AND "entity_temporal_metadata_0_0_0"."transaction_time" @> $1::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $2
+            GROUP BY "entity_temporal_metadata_0_0_0"."web_id"
+            "#,
+            &[
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &json_path,
+            ],
+        );
+    }
+
+    #[test]
+    fn entity_full_text_search_query() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let mut compiler = SelectCompiler::<Entity>::new(Some(&temporal_axes), false);
+
+        let relevance_path = EntityQueryPath::Relevance(Cow::Borrowed("quick fox"));
+        compiler.add_distinct_selection_with_ordering(
+            &relevance_path,
+            Distinctness::Indistinct,
+            Some((Ordering::Descending, None)),
+        );
+
+        let filter = Filter::FullTextMatch(
+            FilterExpression::Path {
+                path: EntityQueryPath::Properties(None),
+            },
+            FilterExpression::Parameter {
+                parameter: Parameter::Text(Cow::Borrowed("quick fox")),
+                convert: None,
+            },
+        );
+        compiler.add_filter(&filter);
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT
+                ts_rank(jsonb_to_tsvector('simple', "entity_editions_0_1_0"."properties", '["string"]'), websearch_to_tsquery('simple', $3))
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+              AND "entity_temporal_metadata_0_0_0"."transaction_time" @> $1::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $2
+              AND jsonb_to_tsvector('simple', "entity_editions_0_1_0"."properties", '["string"]') @@ websearch_to_tsquery('simple', $4)
+            ORDER BY ts_rank(jsonb_to_tsvector('simple', "entity_editions_0_1_0"."properties", '["string"]'), websearch_to_tsquery('simple', $3)) DESC
+            "#,
+            &[
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &"quick fox",
+                &"quick fox",
+            ],
+        );
+    }
+
     #[test]
     fn entity_outgoing_link_query() {
         let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
@@ -1,3 +1,4 @@
+use alloc::borrow::Cow;
 use core::{
     fmt::{self, Debug, Formatter},
     hash::Hash,
@@ -353,6 +354,8 @@ pub enum JsonField<'p> {
     JsonPathParameter(usize),
     StaticText(&'static str),
     Label { inheritance_depth: Option<u32> },
+    FullTextQuery(&'p Cow<'p, str>),
+    FullTextQueryParameter(usize),
 }
 
 impl<'p> JsonField<'p> {
@@ -368,6 +371,11 @@ impl<'p> JsonField<'p> {
             Self::JsonPathParameter(index) => (JsonField::JsonPathParameter(index), None),
             Self::StaticText(text) => (JsonField::StaticText(text), None),
             Self::Label { inheritance_depth } => (JsonField::Label { inheritance_depth }, None),
+            Self::FullTextQuery(query) => (
+                JsonField::FullTextQueryParameter(current_parameter_index),
+                Some(query),
+            ),
+            Self::FullTextQueryParameter(index) => (JsonField::FullTextQueryParameter(index), None),
         }
     }
 }
@@ -975,22 +983,38 @@ pub enum EntityEditions {
     Confidence,
     Provenance,
     PropertyMetadata,
+    LeftEntityConfidence,
+    LeftEntityProvenance,
+    RightEntityConfidence,
+    RightEntityProvenance,
 }
 
 impl DatabaseColumn for EntityEditions {
     fn parameter_type(self) -> ParameterType {
         match self {
             Self::EditionId => ParameterType::Uuid,
-            Self::Properties | Self::Provenance | Self::PropertyMetadata => ParameterType::Any,
+            Self::Properties
+            | Self::Provenance
+            | Self::PropertyMetadata
+            | Self::LeftEntityProvenance
+            | Self::RightEntityProvenance => ParameterType::Any,
             Self::Archived => ParameterType::Boolean,
-            Self::Confidence => ParameterType::F64,
+            Self::Confidence | Self::LeftEntityConfidence | Self::RightEntityConfidence => {
+                ParameterType::F64
+            }
         }
     }
 
     fn nullable(self) -> bool {
         match self {
             Self::EditionId | Self::Archived | Self::Provenance => false,
-            Self::Properties | Self::Confidence | Self::PropertyMetadata => true,
+            Self::Properties
+            | Self::Confidence
+            | Self::PropertyMetadata
+            | Self::LeftEntityConfidence
+            | Self::LeftEntityProvenance
+            | Self::RightEntityConfidence
+            | Self::RightEntityProvenance => true,
         }
     }
 
@@ -1002,6 +1026,10 @@ impl DatabaseColumn for EntityEditions {
             Self::Archived => "archived",
             Self::Confidence => "confidence",
             Self::PropertyMetadata => "property_metadata",
+            Self::LeftEntityConfidence => "left_entity_confidence",
+            Self::LeftEntityProvenance => "left_entity_provenance",
+            Self::RightEntityConfidence => "right_entity_confidence",
+            Self::RightEntityProvenance => "right_entity_provenance",
         }
     }
 }
@@ -1069,26 +1097,15 @@ pub enum EntityHasLeftEntity {
     EntityUuid,
     LeftEntityWebId,
     LeftEntityUuid,
-    Confidence,
-    Provenance,
 }
 
 impl DatabaseColumn for EntityHasLeftEntity {
     fn parameter_type(self) -> ParameterType {
-        match self {
-            Self::WebId | Self::EntityUuid | Self::LeftEntityWebId | Self::LeftEntityUuid => {
-                ParameterType::Uuid
-            }
-            Self::Provenance => ParameterType::Any,
-            Self::Confidence => ParameterType::F64,
-        }
+        ParameterType::Uuid
     }
 
     fn nullable(self) -> bool {
-        match self {
-            Self::WebId | Self::EntityUuid | Self::LeftEntityWebId | Self::LeftEntityUuid => false,
-            Self::Provenance | Self::Confidence => true,
-        }
+        false
     }
 
     fn as_str(self) -> &'static str {
@@ -1097,8 +1114,6 @@ impl DatabaseColumn for EntityHasLeftEntity {
             Self::EntityUuid => "entity_uuid",
             Self::LeftEntityWebId => "left_web_id",
             Self::LeftEntityUuid => "left_entity_uuid",
-            Self::Confidence => "confidence",
-            Self::Provenance => "provenance",
         }
     }
 }
@@ -1109,28 +1124,15 @@ pub enum EntityHasRightEntity {
     EntityUuid,
     RightEntityWebId,
     RightEntityUuid,
-    Confidence,
-    Provenance,
 }
 
 impl DatabaseColumn for EntityHasRightEntity {
     fn parameter_type(self) -> ParameterType {
-        match self {
-            Self::WebId | Self::EntityUuid | Self::RightEntityWebId | Self::RightEntityUuid => {
-                ParameterType::Uuid
-            }
-            Self::Provenance => ParameterType::Any,
-            Self::Confidence => ParameterType::F64,
-        }
+        ParameterType::Uuid
     }
 
     fn nullable(self) -> bool {
-        match self {
-            Self::WebId | Self::EntityUuid | Self::RightEntityWebId | Self::RightEntityUuid => {
-                false
-            }
-            Self::Provenance | Self::Confidence => true,
-        }
+        false
     }
 
     fn as_str(self) -> &'static str {
@@ -1139,8 +1141,6 @@ impl DatabaseColumn for EntityHasRightEntity {
             Self::EntityUuid => "entity_uuid",
             Self::RightEntityWebId => "right_web_id",
             Self::RightEntityUuid => "right_entity_uuid",
-            Self::Confidence => "confidence",
-            Self::Provenance => "provenance",
         }
     }
 }
@@ -43,7 +43,7 @@ pub struct InsertAccountGroupIdParams {
 #[error("Could not insert web")]
 pub struct WebInsertionError;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct InsertWebIdParams {
@@ -0,0 +1,230 @@
+//! Ordered feed of the mutations applied to the graph.
+//!
+//! Every mutation of an entity or an ontology type is recorded as a [`GraphChange`] in the same
+//! transaction as the mutation itself. Changes are identified by a monotonically increasing
+//! [`GraphChangeId`], which can be used as a cursor to resume reading the feed.
+
+use core::fmt;
+use std::collections::HashMap;
+
+use error_stack::{Report, ResultExt as _};
+use hash_graph_authorization::{AuthorizationApi, schema::EntityPermission, zanzibar::Consistency};
+use hash_graph_temporal_versioning::{Timestamp, TransactionTime};
+use hash_graph_types::{
+    account::AccountId,
+    knowledge::entity::{Entity, EntityEditionId, EntityId},
+};
+#[cfg(feature = "postgres")]
+use postgres_types::{FromSql, ToSql};
+use serde::{Deserialize, Serialize};
+use type_system::url::VersionedUrl;
+
+use crate::error::QueryError;
+
+/// The position of a [`GraphChange`] in the change feed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "postgres", derive(FromSql, ToSql), postgres(transparent))]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(transparent)]
+pub struct GraphChangeId(i64);
+
+impl GraphChangeId {
+    #[must_use]
+    pub const fn new(id: i64) -> Self {
+        Self(id)
+    }
+
+    #[must_use]
+    pub const fn into_inner(self) -> i64 {
+        self.0
+    }
+}
+
+impl fmt::Display for GraphChangeId {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+/// The kind of mutation recorded in a [`GraphChange`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum GraphChangeKind {
+    #[serde(rename_all = "camelCase")]
+    EntityCreated {
+        entity_id: EntityId,
+        edition_id: EntityEditionId,
+    },
+    #[serde(rename_all = "camelCase")]
+    EntityUpdated {
+        entity_id: EntityId,
+        edition_id: EntityEditionId,
+    },
+    #[serde(rename_all = "camelCase")]
+    EntityArchived {
+        entity_id: EntityId,
+        edition_id: EntityEditionId,
+    },
+    /// A new ontology type or a new version of an existing ontology type was created.
+    #[serde(rename_all = "camelCase")]
+    TypeCreated { ontology_type_id: VersionedUrl },
+    #[serde(rename_all = "camelCase")]
+    TypeArchived { ontology_type_id: VersionedUrl },
+    #[serde(rename_all = "camelCase")]
+    TypeUnarchived { ontology_type_id: VersionedUrl },
+    /// A link entity between two entities was created, updated, or archived.
+    ///
+    /// This is recorded in addition to the entity change of the link entity.
+    #[serde(rename_all = "camelCase")]
+    RelationshipModified {
+        link_entity_id: EntityId,
+        left_entity_id: EntityId,
+        right_entity_id: EntityId,
+    },
+}
+
+impl GraphChangeKind {
+    /// Returns the entity affected by this change.
+    ///
+    /// For [`RelationshipModified`] this is the link entity.
+    ///
+    /// [`RelationshipModified`]: Self::RelationshipModified
+    #[must_use]
+    pub const fn entity_id(&self) -> Option<EntityId> {
+        match self {
+            Self::EntityCreated { entity_id, .. }
+            | Self::EntityUpdated { entity_id, .. }
+            | Self::EntityArchived { entity_id, .. }
+            | Self::RelationshipModified {
+                link_entity_id: entity_id,
+                ..
+            } => Some(*entity_id),
+            Self::TypeCreated { .. } | Self::TypeArchived { .. } | Self::TypeUnarchived { .. } => {
+                None
+            }
+        }
+    }
+
+    /// Returns the [`RelationshipModified`] change for `entity` if it is a link entity.
+    ///
+    /// [`RelationshipModified`]: Self::RelationshipModified
+    #[must_use]
+    pub fn relationship_modified(entity: &Entity) -> Option<Self> {
+        entity
+            .link_data
+            .as_ref()
+            .map(|link_data| Self::RelationshipModified {
+                link_entity_id: entity.metadata.record_id.entity_id,
+                left_entity_id: link_data.left_entity_id,
+                right_entity_id: link_data.right_entity_id,
+            })
+    }
+}
+
+/// A single mutation of the graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GraphChange {
+    pub id: GraphChangeId,
+    pub transaction_time: Timestamp<TransactionTime>,
+    pub change: GraphChangeKind,
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ReadGraphChangesParams {
+    /// Only changes recorded after this change are returned.
+    ///
+    /// If not specified, the feed is read from the beginning.
+    #[serde(default)]
+    pub after: Option<GraphChangeId>,
+    /// The maximum number of changes to return.
+    pub limit: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ReadGraphChangesResponse {
+    pub changes: Vec<GraphChange>,
+    /// The cursor to pass as `after` to continue reading the feed.
+    ///
+    /// This is the ID of the last change which was read or, if no change was read, the cursor
+    /// which was passed in. Changes of entities the actor is not permitted to view are omitted
+    /// from [`changes`] but still advance the cursor.
+    ///
+    /// [`changes`]: Self::changes
+    pub cursor: Option<GraphChangeId>,
+}
+
+impl ReadGraphChangesResponse {
+    /// Creates a response from the `changes` which were read from the feed.
+    ///
+    /// Changes of entities `actor_id` is not permitted to view are removed.
+    ///
+    /// # Errors
+    ///
+    /// - if checking the permissions failed
+    pub async fn from_permitted_changes<A: AuthorizationApi>(
+        authorization_api: &A,
+        actor_id: AccountId,
+        changes: Vec<GraphChange>,
+        after: Option<GraphChangeId>,
+    ) -> Result<Self, Report<QueryError>> {
+        let cursor = changes.last().map(|change| change.id).or(after);
+
+        let entity_ids = changes
+            .iter()
+            .filter_map(|change| change.change.entity_id())
+            .collect::<Vec<_>>();
+        let permissions = if entity_ids.is_empty() {
+            HashMap::new()
+        } else {
+            authorization_api
+                .check_entities_permission(
+                    actor_id,
+                    EntityPermission::View,
+                    entity_ids,
+                    Consistency::FullyConsistent,
+                )
+                .await
+                .change_context(QueryError)?
+                .0
+        };
+
+        Ok(Self {
+            changes: changes
+                .into_iter()
+                .filter(|change| {
+                    change.change.entity_id().is_none_or(|entity_id| {
+                        permissions
+                            .get(&entity_id.entity_uuid)
+                            .copied()
+                            .unwrap_or(false)
+                    })
+                })
+                .collect(),
+            cursor,
+        })
+    }
+}
+
+/// Describes the API of a store implementation for the change feed.
+pub trait ChangeFeedStore {
+    /// Reads the changes recorded after [`ReadGraphChangesParams::after`] in the order they were
+    /// recorded.
+    ///
+    /// Changes of entities the actor is not permitted to view are skipped.
+    ///
+    /// # Errors
+    ///
+    /// - if reading the changes from the store failed
+    fn read_graph_changes(
+        &self,
+        actor_id: AccountId,
+        params: ReadGraphChangesParams,
+    ) -> impl Future<Output = Result<ReadGraphChangesResponse, Report<QueryError>>> + Send;
+}
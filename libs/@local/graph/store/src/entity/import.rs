@@ -0,0 +1,586 @@
+use alloc::borrow::Cow;
+use core::{borrow::Borrow as _, iter::Peekable, mem, str::Chars};
+use std::collections::{HashMap, HashSet};
+
+use error_stack::{Report, ResultExt as _, bail};
+use hash_graph_authorization::{schema::EntityRelationAndSubject, zanzibar::Consistency};
+use hash_graph_types::{
+    account::AccountId,
+    knowledge::{
+        entity::{Entity, ProvidedEntityEditionProvenance},
+        property::{
+            ObjectMetadata, PropertyProvenance, PropertyWithMetadata, PropertyWithMetadataObject,
+            PropertyWithMetadataValue, ValueMetadata,
+        },
+    },
+    ontology::DataTypeLookup,
+    owned_by_id::OwnedById,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map as JsonMap, Number as JsonNumber, Value as JsonValue};
+use type_system::{
+    schema::{DataTypeReference, JsonSchemaValueType},
+    url::{BaseUrl, VersionedUrl},
+};
+
+use crate::{
+    entity::{
+        CreateEntityParams, EntityStore, EntityValidationReport, EntityValidationType,
+        ValidateEntityComponents, ValidateEntityParams,
+    },
+    error::InsertionError,
+};
+
+/// The format of the data passed to [`EntityStore::import_entities`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum EntityImportFormat {
+    /// Comma-separated values with a header row naming the columns.
+    Csv,
+    /// One JSON object per line, keyed by the column names.
+    JsonLines,
+}
+
+/// Describes how the values of a column are stored as a property.
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityImportColumn {
+    /// The property the values of the column are stored in.
+    pub property_type_base_url: BaseUrl,
+    /// The data type of the values in the column.
+    ///
+    /// String values, e.g. the cells of CSV data, are parsed according to the JSON type of the
+    /// data type. If it is not specified, they are imported as strings and the data type is
+    /// inferred when the entity is validated.
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub data_type_id: Option<VersionedUrl>,
+    /// The data type the values are converted to before they are stored.
+    ///
+    /// Requires `dataTypeId` to be specified.
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub convert_to: Option<VersionedUrl>,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ImportEntitiesParams {
+    pub owned_by_id: OwnedById,
+    #[cfg_attr(feature = "utoipa", schema(value_type = Vec<VersionedUrl>))]
+    pub entity_type_ids: HashSet<VersionedUrl>,
+    pub format: EntityImportFormat,
+    pub data: String,
+    /// Maps the column names to the properties they are imported into.
+    ///
+    /// Columns which are not part of the mapping are ignored.
+    pub columns: HashMap<String, EntityImportColumn>,
+    pub draft: bool,
+    /// Only validates the rows without creating any entities.
+    #[serde(default)]
+    pub dry_run: bool,
+    pub relationships: Vec<EntityRelationAndSubject>,
+    #[serde(default)]
+    pub provenance: ProvidedEntityEditionProvenance,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct MalformedImportRow {
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct InvalidImportValue {
+    pub column: String,
+    pub value: String,
+    pub expected: JsonSchemaValueType,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(tag = "type", rename_all = "camelCase")]
+#[must_use]
+pub enum ImportRowError {
+    Malformed { data: MalformedImportRow },
+    InvalidValue { data: InvalidImportValue },
+}
+
+#[derive(Debug, Default, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+#[must_use]
+pub struct ImportRowReport {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ImportRowError>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation: Option<EntityValidationReport>,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ImportEntitiesResponse {
+    pub row_count: usize,
+    /// The reports of the rows which failed to import, keyed by the zero-based row index.
+    ///
+    /// The header row of CSV data and empty lines are not counted.
+    pub reports: HashMap<usize, ImportRowReport>,
+    /// The created entities.
+    ///
+    /// Entities are only created if all rows are valid and `dryRun` was not requested.
+    pub entities: Vec<Entity>,
+}
+
+/// The properties read from a single row or the errors which occurred while reading it.
+pub type ImportRow = Result<PropertyWithMetadataObject, Vec<ImportRowError>>;
+
+enum Cell {
+    Text(String),
+    Json(JsonValue),
+}
+
+/// Splits CSV `data` into records of fields.
+///
+/// Fields may be quoted, in which case they may contain separators, line breaks and quotes escaped
+/// as `""`. Lines are terminated by `\n` or `\r\n` and empty lines are skipped. A record with text
+/// after the closing quote of a field is malformed, reading continues with the next line. An
+/// unterminated quoted field is malformed and ends the data.
+fn read_csv_records(data: &str) -> Vec<Result<Vec<String>, ImportRowError>> {
+    fn read_quoted(chars: &mut Peekable<Chars<'_>>, field: &mut String) -> bool {
+        while let Some(char) = chars.next() {
+            if char == '"' {
+                if chars.next_if_eq(&'"').is_none() {
+                    return true;
+                }
+                field.push('"');
+            } else {
+                field.push(char);
+            }
+        }
+        false
+    }
+
+    fn malformed(message: &str) -> ImportRowError {
+        ImportRowError::Malformed {
+            data: MalformedImportRow {
+                message: message.to_owned(),
+            },
+        }
+    }
+
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    // Distinguishes a quoted empty field from an empty line.
+    let mut quoted = false;
+    let mut chars = data.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if field.is_empty() && !quoted => {
+                quoted = true;
+                if !read_quoted(&mut chars, &mut field) {
+                    records.push(Err(malformed("quoted field is not terminated")));
+                    return records;
+                }
+                if chars
+                    .peek()
+                    .is_some_and(|next| !matches!(next, ',' | '\r' | '\n'))
+                {
+                    while chars.next_if(|next| *next != '\n').is_some() {}
+                    record.clear();
+                    field.clear();
+                    quoted = false;
+                    records.push(Err(malformed("unexpected text after quoted field")));
+                }
+            }
+            ',' => {
+                record.push(mem::take(&mut field));
+                quoted = false;
+            }
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                if record.is_empty() && field.is_empty() && !quoted {
+                    // Empty lines are skipped
+                    continue;
+                }
+                record.push(mem::take(&mut field));
+                records.push(Ok(mem::take(&mut record)));
+                quoted = false;
+            }
+            _ => field.push(char),
+        }
+    }
+
+    if !record.is_empty() || !field.is_empty() || quoted {
+        record.push(field);
+        records.push(Ok(record));
+    }
+
+    records
+}
+
+fn parse_cell(value: &str, value_type: Option<JsonSchemaValueType>) -> Option<JsonValue> {
+    match value_type {
+        None | Some(JsonSchemaValueType::String) => Some(JsonValue::String(value.to_owned())),
+        Some(JsonSchemaValueType::Number) => value
+            .trim()
+            .parse::<JsonNumber>()
+            .ok()
+            .map(JsonValue::Number),
+        Some(JsonSchemaValueType::Boolean) => match value.trim() {
+            "true" => Some(JsonValue::Bool(true)),
+            "false" => Some(JsonValue::Bool(false)),
+            _ => None,
+        },
+        Some(JsonSchemaValueType::Null) => (value.trim() == "null").then_some(JsonValue::Null),
+        Some(expected @ (JsonSchemaValueType::Array | JsonSchemaValueType::Object)) => {
+            serde_json::from_str::<JsonValue>(value)
+                .ok()
+                .filter(|value| JsonSchemaValueType::from(value) == expected)
+        }
+    }
+}
+
+impl ImportEntitiesParams {
+    fn records(
+        &self,
+    ) -> Result<Vec<Result<HashMap<&str, Cell>, ImportRowError>>, Report<InsertionError>> {
+        match self.format {
+            EntityImportFormat::Csv => {
+                let mut records = read_csv_records(&self.data).into_iter();
+                let header = match records.next() {
+                    Some(Ok(header)) => header,
+                    Some(Err(_)) | None => {
+                        bail!(
+                            Report::new(InsertionError)
+                                .attach_printable("CSV data does not contain a valid header row")
+                        )
+                    }
+                };
+
+                Ok(records
+                    .map(|record| {
+                        let record = record?;
+                        if record.len() != header.len() {
+                            return Err(ImportRowError::Malformed {
+                                data: MalformedImportRow {
+                                    message: format!(
+                                        "expected {} fields but found {}",
+                                        header.len(),
+                                        record.len()
+                                    ),
+                                },
+                            });
+                        }
+
+                        Ok(header
+                            .iter()
+                            .zip(record)
+                            .filter_map(|(column, value)| {
+                                let (column, _) = self.columns.get_key_value(column)?;
+                                Some((column.as_str(), Cell::Text(value)))
+                            })
+                            .collect())
+                    })
+                    .collect())
+            }
+            EntityImportFormat::JsonLines => Ok(self
+                .data
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| {
+                    let object = serde_json::from_str::<JsonMap<String, JsonValue>>(line).map_err(
+                        |error| ImportRowError::Malformed {
+                            data: MalformedImportRow {
+                                message: error.to_string(),
+                            },
+                        },
+                    )?;
+
+                    Ok(object
+                        .into_iter()
+                        .filter_map(|(column, value)| {
+                            let (column, _) = self.columns.get_key_value(&column)?;
+                            Some((column.as_str(), match value {
+                                JsonValue::String(value) => Cell::Text(value),
+                                value => Cell::Json(value),
+                            }))
+                        })
+                        .collect())
+                })
+                .collect()),
+        }
+    }
+
+    /// Reads the properties of each row of the imported data.
+    ///
+    /// The data types of the columns are resolved using the `provider` to parse string values.
+    /// Empty strings and `null` values are skipped.
+    ///
+    /// # Errors
+    ///
+    /// - if a data type of a column could not be resolved
+    /// - if a column requests a conversion without specifying its data type
+    /// - if CSV data does not contain a header row
+    pub async fn read_rows<P>(&self, provider: &P) -> Result<Vec<ImportRow>, Report<InsertionError>>
+    where
+        P: DataTypeLookup + Sync,
+    {
+        let mut value_types = HashMap::new();
+        for (column, mapping) in &self.columns {
+            let Some(data_type_id) = &mapping.data_type_id else {
+                if mapping.convert_to.is_some() {
+                    bail!(Report::new(InsertionError).attach_printable(format!(
+                        "column `{column}` requests a conversion but does not specify its data \
+                         type"
+                    )));
+                }
+                continue;
+            };
+
+            let data_type = provider
+                .lookup_closed_data_type_by_ref(<&DataTypeReference>::from(data_type_id))
+                .await
+                .change_context(InsertionError)
+                .attach_printable_lazy(|| format!("could not resolve data type of `{column}`"))?;
+            if let Some(value_type) = data_type.borrow().value_type() {
+                value_types.insert(column.as_str(), value_type);
+            }
+        }
+
+        Ok(self
+            .records()?
+            .into_iter()
+            .map(|record| {
+                let record = record.map_err(|error| vec![error])?;
+
+                let mut errors = Vec::new();
+                let mut properties = HashMap::new();
+                for (column, cell) in record {
+                    let mapping = &self.columns[column];
+                    let value_type = value_types.get(column).copied();
+                    let value = match cell {
+                        Cell::Text(value) if value.is_empty() => continue,
+                        Cell::Text(value) => {
+                            let Some(parsed) = parse_cell(&value, value_type) else {
+                                errors.push(ImportRowError::InvalidValue {
+                                    data: InvalidImportValue {
+                                        column: column.to_owned(),
+                                        value,
+                                        expected: value_type.unwrap_or(JsonSchemaValueType::String),
+                                    },
+                                });
+                                continue;
+                            };
+                            parsed
+                        }
+                        Cell::Json(JsonValue::Null) => continue,
+                        Cell::Json(value) => value,
+                    };
+
+                    properties.insert(
+                        mapping.property_type_base_url.clone(),
+                        PropertyWithMetadata::Value(PropertyWithMetadataValue {
+                            value,
+                            metadata: ValueMetadata {
+                                provenance: PropertyProvenance::default(),
+                                confidence: None,
+                                data_type_id: mapping
+                                    .convert_to
+                                    .clone()
+                                    .or_else(|| mapping.data_type_id.clone()),
+                                original_data_type_id: mapping.data_type_id.clone(),
+                                canonical: HashMap::new(),
+                            },
+                        }),
+                    );
+                }
+
+                if errors.is_empty() {
+                    Ok(PropertyWithMetadataObject {
+                        value: properties,
+                        metadata: ObjectMetadata::default(),
+                    })
+                } else {
+                    Err(errors)
+                }
+            })
+            .collect())
+    }
+
+    /// Validates the rows read by [`read_rows`] and creates an entity for each of them.
+    ///
+    /// No entity is created if any row failed to be read or validated, or if a dry run was
+    /// requested.
+    ///
+    /// # Errors
+    ///
+    /// - if creating the entities failed
+    ///
+    /// [`read_rows`]: Self::read_rows
+    pub async fn import_rows<S>(
+        self,
+        store: &mut S,
+        actor_id: AccountId,
+        rows: Vec<ImportRow>,
+    ) -> Result<ImportEntitiesResponse, Report<InsertionError>>
+    where
+        S: EntityStore + Send + Sync,
+    {
+        let row_count = rows.len();
+        let mut reports = HashMap::<usize, ImportRowReport>::new();
+        let mut valid_rows = Vec::with_capacity(row_count);
+        for (index, row) in rows.into_iter().enumerate() {
+            match row {
+                Ok(properties) => valid_rows.push((index, properties)),
+                Err(errors) => {
+                    reports.insert(index, ImportRowReport {
+                        errors,
+                        validation: None,
+                    });
+                }
+            }
+        }
+
+        let components = if self.draft {
+            ValidateEntityComponents::draft()
+        } else {
+            ValidateEntityComponents::full()
+        };
+        let validation_reports = store
+            .validate_entities(
+                actor_id,
+                Consistency::FullyConsistent,
+                valid_rows
+                    .iter()
+                    .map(|(_, properties)| ValidateEntityParams {
+                        entity_types: EntityValidationType::Id(Cow::Borrowed(
+                            &self.entity_type_ids,
+                        )),
+                        properties: Cow::Borrowed(properties),
+                        link_data: None,
+                        components,
+                    })
+                    .collect(),
+            )
+            .await;
+        for (index, validation_report) in validation_reports {
+            reports.entry(valid_rows[index].0).or_default().validation = Some(validation_report);
+        }
+
+        if self.dry_run || !reports.is_empty() {
+            return Ok(ImportEntitiesResponse {
+                row_count,
+                reports,
+                entities: Vec::new(),
+            });
+        }
+
+        let entities = store
+            .create_entities(
+                actor_id,
+                valid_rows
+                    .into_iter()
+                    .map(|(_, properties)| CreateEntityParams {
+                        owned_by_id: self.owned_by_id,
+                        entity_uuid: None,
+                        decision_time: None,
+                        entity_type_ids: self.entity_type_ids.clone(),
+                        properties,
+                        confidence: None,
+                        link_data: None,
+                        draft: self.draft,
+                        relationships: self.relationships.clone(),
+                        provenance: self.provenance.clone(),
+                    })
+                    .collect(),
+            )
+            .await?;
+
+        Ok(ImportEntitiesResponse {
+            row_count,
+            reports,
+            entities,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn assert_records(data: &str, expected: &[Result<Vec<&str>, &str>]) {
+        let records = read_csv_records(data);
+        let records = records
+            .iter()
+            .map(|record| match record {
+                Ok(fields) => Ok(fields.iter().map(String::as_str).collect::<Vec<_>>()),
+                Err(ImportRowError::Malformed { data }) => Err(data.message.as_str()),
+                Err(error) => panic!("unexpected error: {error:?}"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(records, expected);
+    }
+
+    #[test]
+    fn plain_records() {
+        assert_records("a,b\nc,d", &[Ok(vec!["a", "b"]), Ok(vec!["c", "d"])]);
+        assert_records("a,,b", &[Ok(vec!["a", "", "b"])]);
+    }
+
+    #[test]
+    fn trailing_newline() {
+        assert_records("a,b\n", &[Ok(vec!["a", "b"])]);
+        assert_records("a,b\n\n\nc,d\n", &[Ok(vec!["a", "b"]), Ok(vec!["c", "d"])]);
+    }
+
+    #[test]
+    fn crlf_line_endings() {
+        assert_records("a,b\r\nc,d\r\n", &[Ok(vec!["a", "b"]), Ok(vec!["c", "d"])]);
+        assert_records("\"a\"\r\n", &[Ok(vec!["a"])]);
+    }
+
+    #[test]
+    fn quoted_commas_and_line_breaks() {
+        assert_records("\"a,b\",c", &[Ok(vec!["a,b", "c"])]);
+        assert_records("\"a\nb\",c\n", &[Ok(vec!["a\nb", "c"])]);
+    }
+
+    #[test]
+    fn escaped_quotes() {
+        assert_records("\"say \"\"hi\"\"\",b", &[Ok(vec!["say \"hi\"", "b"])]);
+        assert_records("\"\"\"\"", &[Ok(vec!["\""])]);
+    }
+
+    #[test]
+    fn quoted_empty_fields() {
+        assert_records("\"\",a", &[Ok(vec!["", "a"])]);
+        assert_records("\"\"\na", &[Ok(vec![""]), Ok(vec!["a"])]);
+    }
+
+    #[test]
+    fn unterminated_quotes() {
+        assert_records("a,b\n\"c,d\ne,f", &[
+            Ok(vec!["a", "b"]),
+            Err("quoted field is not terminated"),
+        ]);
+    }
+
+    #[test]
+    fn text_after_closing_quote() {
+        assert_records("\"a\"b,c\nd,e", &[
+            Err("unexpected text after quoted field"),
+            Ok(vec!["d", "e"]),
+        ]);
+        assert_records("x,\"a\" ,c", &[Err("unexpected text after quoted field")]);
+    }
+}
@@ -1,16 +1,25 @@
 #[cfg(feature = "utoipa")]
 pub use self::store::CreateEntityRequest;
 pub use self::{
+    import::{
+        EntityImportColumn, EntityImportFormat, ImportEntitiesParams, ImportEntitiesResponse,
+        ImportRow, ImportRowError, ImportRowReport, InvalidImportValue, MalformedImportRow,
+    },
     query::{
         EntityQueryCursor, EntityQueryPath, EntityQuerySorting, EntityQuerySortingRecord,
         EntityQuerySortingToken, EntityQueryToken,
     },
     store::{
-        ClosedMultiEntityTypeMap, CountEntitiesParams, CreateEntityParams, DiffEntityParams,
-        DiffEntityResult, EntityStore, EntityValidationType, GetEntitiesParams,
-        GetEntitiesResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse, PatchEntityParams,
-        QueryConversion, UpdateEntityEmbeddingsParams, ValidateEntityComponents,
-        ValidateEntityError, ValidateEntityParams,
+        AggregateEntitiesParams, AggregateEntitiesResponse, ClosedMultiEntityTypeMap,
+        CountEntitiesParams, CreateEntityParams, DiffEntityParams, DiffEntityResult,
+        EntityAggregation, EntityAggregationFunction, EntityAggregationGroup, EntityHistoryEntry,
+        EntityRevertTarget, EntityStore, EntityValidationType, GetEntitiesParams,
+        GetEntitiesResponse, GetEntityHistoryParams, GetEntityHistoryResponse,
+        GetEntitySubgraphParams, GetEntitySubgraphResponse, MergeEntitiesParams,
+        MergeEntitiesResponse, MigrateEntityTypeParams, MigrateEntityTypeResponse,
+        PatchEntityParams, PropertyMergeStrategy, QueryConversion, RevertEntityParams,
+        UpdateEntityEmbeddingsParams, ValidateEntityComponents, ValidateEntityError,
+        ValidateEntityParams,
     },
     validation_report::{
         EmptyEntityTypes, EntityRetrieval, EntityTypeRetrieval, EntityTypesError,
@@ -21,6 +30,7 @@ pub use self::{
     },
 };
 
+mod import;
 mod query;
 mod store;
 mod validation_report;
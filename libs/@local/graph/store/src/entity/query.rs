@@ -435,6 +435,14 @@ pub enum EntityQueryPath<'p> {
     /// # Ok::<(), serde_json::Error>(())
     /// ```
     Embedding,
+    /// The full-text search relevance of the [`Entity`] for the provided query.
+    ///
+    /// This path is only available for sorting and deserializes from `["relevance", query]`. The
+    /// query uses the same syntax as [`Filter::FullTextMatch`].
+    ///
+    /// [`Entity`]: hash_graph_types::knowledge::entity::Entity
+    /// [`Filter::FullTextMatch`]: crate::filter::Filter::FullTextMatch
+    Relevance(Cow<'p, str>),
 }
 
 impl fmt::Display for EntityQueryPath<'_> {
@@ -459,6 +467,7 @@ impl fmt::Display for EntityQueryPath<'_> {
             Self::PropertyMetadata(Some(path)) => write!(fmt, "propertyMetadata.{path}"),
             Self::PropertyMetadata(None) => fmt.write_str("propertyMetadata"),
             Self::Embedding => fmt.write_str("embedding"),
+            Self::Relevance(_) => fmt.write_str("relevance"),
             Self::EntityTypeEdge {
                 edge_kind: SharedEdgeKind::IsOfType,
                 path,
@@ -514,9 +523,10 @@ impl QueryPath for EntityQueryPath<'_> {
             | Self::PropertyMetadata(_)
             | Self::LeftEntityProvenance
             | Self::RightEntityProvenance => ParameterType::Any,
-            Self::EntityConfidence | Self::LeftEntityConfidence | Self::RightEntityConfidence => {
-                ParameterType::F64
-            }
+            Self::EntityConfidence
+            | Self::LeftEntityConfidence
+            | Self::RightEntityConfidence
+            | Self::Relevance(_) => ParameterType::F64,
             Self::Embedding => ParameterType::Vector(Box::new(ParameterType::F64)),
             Self::Archived => ParameterType::Boolean,
             Self::EntityTypeEdge { path, .. } => path.expected_type(),
@@ -773,6 +783,7 @@ pub enum EntityQuerySortingToken {
     CreatedAtTransactionTime,
     CreatedAtDecisionTime,
     TypeTitle,
+    Relevance,
 }
 
 /// Deserializes an [`EntityQueryPath`] from a string sequence.
@@ -785,7 +796,7 @@ impl EntityQuerySortingVisitor {
     pub(crate) const EXPECTING: &'static str =
         "one of `uuid`, `archived`, `properties`, `label`, `recordCreatedAtTransactionTime`, \
          `recordCreatedAtDecisionTime`, `createdAtTransactionTime`, `createdAtDecisionTime`, \
-         `typeTitle`";
+         `typeTitle`, `relevance`";
 
     #[must_use]
     pub(crate) const fn new(position: usize) -> Self {
@@ -838,6 +849,10 @@ impl<'de> Visitor<'de> for EntityQuerySortingVisitor {
                     .transpose()
                     .map_err(de::Error::custom)?,
             },
+            EntityQuerySortingToken::Relevance => EntityQueryPath::Relevance(Cow::Owned(
+                seq.next_element::<String>()?
+                    .ok_or_else(|| de::Error::invalid_length(self.position, &self))?,
+            )),
             EntityQuerySortingToken::Properties => EntityPropertiesPathVisitor {
                 position: self.position,
             }
@@ -892,6 +907,7 @@ impl<'de: 'p, 'p> EntityQueryPath<'p> {
             Self::Properties(path) => EntityQueryPath::Properties(path.map(JsonPath::into_owned)),
             Self::Label { inheritance_depth } => EntityQueryPath::Label { inheritance_depth },
             Self::Embedding => EntityQueryPath::Embedding,
+            Self::Relevance(query) => EntityQueryPath::Relevance(Cow::Owned(query.into_owned())),
             Self::EntityConfidence => EntityQueryPath::EntityConfidence,
             Self::LeftEntityConfidence => EntityQueryPath::LeftEntityConfidence,
             Self::LeftEntityProvenance => EntityQueryPath::LeftEntityProvenance,
@@ -1021,6 +1037,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn relevance_sorting_path() {
+        assert_eq!(
+            EntityQueryPath::deserialize_from_sorting_tokens(de::value::SeqDeserializer::<
+                _,
+                de::value::Error,
+            >::new(
+                ["relevance", "quick brown fox"].into_iter()
+            ))
+            .expect("could not deserialize sorting path"),
+            EntityQueryPath::Relevance(Cow::Borrowed("quick brown fox"))
+        );
+    }
+
     fn deserialize<'p>(segments: impl IntoIterator<Item = &'p str>) -> EntityQueryPath<'p> {
         EntityQueryPath::deserialize(de::value::SeqDeserializer::<_, de::value::Error>::new(
             segments.into_iter(),
@@ -1,24 +1,34 @@
 use alloc::borrow::Cow;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, hash_map::Entry};
 
-use error_stack::Report;
+use error_stack::{Report, ResultExt as _};
 use futures::TryFutureExt as _;
 use hash_graph_authorization::{schema::EntityRelationAndSubject, zanzibar::Consistency};
-use hash_graph_temporal_versioning::{DecisionTime, Timestamp, TransactionTime};
+use hash_graph_temporal_versioning::{
+    DecisionTime, LimitedTemporalBound, TemporalBound, Timestamp, TransactionTime,
+};
 use hash_graph_types::{
     account::{AccountId, CreatedById, EditionCreatedById},
     knowledge::{
         Confidence, EntityTypeIdDiff,
-        entity::{Entity, EntityEmbedding, EntityId, EntityUuid, ProvidedEntityEditionProvenance},
+        entity::{
+            Entity, EntityEditionId, EntityEditionProvenance, EntityEmbedding, EntityId,
+            EntityTemporalMetadata, EntityUuid, ProvidedEntityEditionProvenance,
+        },
         link::LinkData,
         property::{
-            PropertyDiff, PropertyPatchOperation, PropertyPath, PropertyWithMetadataObject,
+            PropertyDiff, PropertyObject, PropertyPatchOperation, PropertyPath, PropertyPathError,
+            PropertyWithMetadata, PropertyWithMetadataObject,
         },
     },
     owned_by_id::OwnedById,
 };
 use serde::{Deserialize, Serialize};
-use type_system::{schema::ClosedMultiEntityType, url::VersionedUrl};
+use serde_json::Value as JsonValue;
+use type_system::{
+    schema::ClosedMultiEntityType,
+    url::{BaseUrl, VersionedUrl},
+};
 #[cfg(feature = "utoipa")]
 use utoipa::{
     ToSchema,
@@ -26,11 +36,21 @@ use utoipa::{
 };
 
 use crate::{
-    entity::{EntityQueryCursor, EntityQuerySorting, EntityValidationReport},
+    entity::{
+        EntityQueryCursor, EntityQueryPath, EntityQuerySorting, EntityValidationReport,
+        ImportEntitiesParams, ImportEntitiesResponse,
+    },
     entity_type::{EntityTypeResolveDefinitions, IncludeEntityTypeOption},
-    error::{InsertionError, QueryError, UpdateError},
-    filter::Filter,
-    subgraph::{Subgraph, edges::GraphResolveDepths, temporal_axes::QueryTemporalAxesUnresolved},
+    error::{EntityRevertTargetNotFound, InsertionError, QueryError, UpdateError},
+    filter::{Filter, FilterExpression, Parameter},
+    subgraph::{
+        Subgraph,
+        edges::GraphResolveDepths,
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
+            VariableTemporalAxisUnresolved,
+        },
+    },
 };
 
 #[derive(Debug, Clone, Deserialize)]
@@ -111,7 +131,7 @@ impl Default for ValidateEntityComponents {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(
     feature = "utoipa",
     derive(utoipa::ToSchema),
@@ -120,7 +140,7 @@ impl Default for ValidateEntityComponents {
 #[serde(
     rename_all = "camelCase",
     deny_unknown_fields,
-    bound(deserialize = "R: Deserialize<'de>")
+    bound(serialize = "R: Serialize", deserialize = "R: Deserialize<'de>")
 )]
 pub struct CreateEntityParams<R> {
     pub owned_by_id: OwnedById,
@@ -141,7 +161,7 @@ pub struct CreateEntityParams<R> {
     pub link_data: Option<LinkData>,
     pub draft: bool,
     pub relationships: R,
-    #[serde(default, skip_serializing_if = "UserDefinedProvenanceData::is_empty")]
+    #[serde(default)]
     pub provenance: ProvidedEntityEditionProvenance,
 }
 
@@ -263,15 +283,139 @@ pub struct CountEntitiesParams<'a> {
     pub include_drafts: bool,
 }
 
+/// The function used to aggregate the values of an [`EntityQueryPath`] within a group.
+///
+/// Except for [`Count`], only numeric values are taken into account. Values of any other type
+/// are ignored.
+///
+/// [`Count`]: Self::Count
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum EntityAggregationFunction {
+    /// The number of entities in the group for which the path resolves to a value.
+    Count,
+    Sum,
+    Avg,
+    Min,
+    Max,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityAggregation<'a> {
+    pub function: EntityAggregationFunction,
+    #[serde(borrow)]
+    pub path: EntityQueryPath<'a>,
+}
+
+#[cfg(feature = "utoipa")]
+impl ToSchema<'_> for EntityAggregation<'_> {
+    fn schema() -> (&'static str, openapi::RefOr<openapi::Schema>) {
+        (
+            "EntityAggregation",
+            openapi::Schema::Object(
+                openapi::ObjectBuilder::new()
+                    .property(
+                        "function",
+                        Ref::from_schema_name("EntityAggregationFunction"),
+                    )
+                    .required("function")
+                    .property("path", Ref::from_schema_name("EntityQueryPath"))
+                    .required("path")
+                    .build(),
+            )
+            .into(),
+        )
+    }
+}
+
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AggregateEntitiesParams<'a> {
+    #[serde(borrow)]
+    pub filter: Filter<'a, Entity>,
+    pub temporal_axes: QueryTemporalAxesUnresolved,
+    pub include_drafts: bool,
+    /// The paths the matching entities are grouped by.
+    ///
+    /// If empty, all matching entities form a single group.
+    #[serde(borrow, default)]
+    pub group_by: Vec<EntityQueryPath<'a>>,
+    #[serde(borrow, default)]
+    pub aggregations: Vec<EntityAggregation<'a>>,
+}
+
+#[cfg(feature = "utoipa")]
+impl ToSchema<'_> for AggregateEntitiesParams<'_> {
+    fn schema() -> (&'static str, openapi::RefOr<openapi::Schema>) {
+        (
+            "AggregateEntitiesParams",
+            openapi::Schema::Object(
+                openapi::ObjectBuilder::new()
+                    .property("filter", Ref::from_schema_name("Filter"))
+                    .required("filter")
+                    .property(
+                        "temporalAxes",
+                        Ref::from_schema_name("QueryTemporalAxesUnresolved"),
+                    )
+                    .required("temporalAxes")
+                    .property(
+                        "includeDrafts",
+                        openapi::ObjectBuilder::new().schema_type(openapi::SchemaType::Boolean),
+                    )
+                    .required("includeDrafts")
+                    .property(
+                        "groupBy",
+                        Ref::from_schema_name("EntityQueryPath").to_array_builder(),
+                    )
+                    .property(
+                        "aggregations",
+                        Ref::from_schema_name("EntityAggregation").to_array_builder(),
+                    )
+                    .build(),
+            )
+            .into(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityAggregationGroup {
+    /// The values of the requested `groupBy` paths, in the same order.
+    pub keys: Vec<JsonValue>,
+    /// The number of entities in this group.
+    pub count: usize,
+    /// The results of the requested aggregations, in the same order.
+    ///
+    /// A value is `null` if the group does not contain any value the aggregation applies to.
+    pub values: Vec<Option<f64>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct AggregateEntitiesResponse {
+    pub groups: Vec<EntityAggregationGroup>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct PatchEntityParams {
     pub entity_id: EntityId,
+    /// The edition of the entity the patch is based on.
+    ///
+    /// If provided, the patch is rejected if the entity was updated since this edition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub expected_edition_id: Option<EntityEditionId>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     pub decision_time: Option<Timestamp<DecisionTime>>,
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
     #[cfg_attr(feature = "utoipa", schema(value_type = Vec<VersionedUrl>))]
     pub entity_type_ids: HashSet<VersionedUrl>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -285,10 +429,472 @@ pub struct PatchEntityParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     pub confidence: Option<Confidence>,
-    #[serde(default, skip_serializing_if = "UserDefinedProvenanceData::is_empty")]
+    /// Replaces the confidence and provenance of the links of a link entity.
+    ///
+    /// The linked entities cannot be changed, so they have to match the current link data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub link_data: Option<LinkData>,
+    #[serde(default)]
+    pub provenance: ProvidedEntityEditionProvenance,
+}
+
+/// Determines which value is kept if multiple merged entities specify the same property.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum PropertyMergeStrategy {
+    /// The value of the surviving entity is kept.
+    #[default]
+    KeepSurvivor,
+    /// The value of the merged entities is taken. If multiple merged entities specify the
+    /// property, the last one wins.
+    PreferMerged,
+    /// The value with the highest confidence is taken. Ties are resolved in favor of the
+    /// surviving entity.
+    HighestConfidence,
+    /// Array values are concatenated while skipping duplicates. Other values are handled like
+    /// [`KeepSurvivor`].
+    ///
+    /// [`KeepSurvivor`]: Self::KeepSurvivor
+    Combine,
+}
+
+impl PropertyMergeStrategy {
+    fn merge(self, survivor: &mut PropertyWithMetadata, merged: PropertyWithMetadata) {
+        match (self, survivor, merged) {
+            (Self::PreferMerged, survivor, merged) => *survivor = merged,
+            (Self::HighestConfidence, survivor, merged) => {
+                if merged.confidence() > survivor.confidence() {
+                    *survivor = merged;
+                }
+            }
+            (
+                Self::Combine,
+                PropertyWithMetadata::Array(survivor),
+                PropertyWithMetadata::Array(merged),
+            ) => {
+                for element in merged.value {
+                    let value = element.clone().into_parts().0;
+                    if !survivor
+                        .value
+                        .iter()
+                        .any(|existing| existing.clone().into_parts().0 == value)
+                    {
+                        survivor.value.push(element);
+                    }
+                }
+            }
+            (Self::KeepSurvivor | Self::Combine, _, _) => {}
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MergeEntitiesParams {
+    /// The entity which is kept. All other entities are merged into it.
+    pub surviving_entity_id: EntityId,
+    /// The entities which are merged into the surviving entity and archived afterwards.
+    pub merged_entity_ids: Vec<EntityId>,
+    /// The strategy used for properties which don't have an entry in `propertyStrategies`.
+    #[serde(default)]
+    pub default_strategy: PropertyMergeStrategy,
+    #[serde(default)]
+    pub property_strategies: HashMap<BaseUrl, PropertyMergeStrategy>,
+    #[serde(default)]
+    pub provenance: ProvidedEntityEditionProvenance,
+}
+
+impl MergeEntitiesParams {
+    /// Merges the `merged` properties into the `survivor` properties.
+    ///
+    /// Properties which are only present on the merged entity are always added.
+    pub fn merge_properties(
+        &self,
+        survivor: &mut PropertyWithMetadataObject,
+        merged: PropertyWithMetadataObject,
+    ) {
+        for (key, property) in merged.value {
+            let strategy = self
+                .property_strategies
+                .get(&key)
+                .copied()
+                .unwrap_or(self.default_strategy);
+            match survivor.value.entry(key) {
+                Entry::Occupied(mut entry) => strategy.merge(entry.get_mut(), property),
+                Entry::Vacant(entry) => {
+                    entry.insert(property);
+                }
+            }
+        }
+    }
+
+    /// Creates the provenance of the edition of the surviving entity.
+    ///
+    /// The sources of all involved entities are combined and the merged entities are recorded.
+    #[must_use]
+    pub fn merged_provenance<'e>(
+        &self,
+        entities: impl IntoIterator<Item = &'e Entity>,
+    ) -> ProvidedEntityEditionProvenance {
+        let mut provenance = self.provenance.clone();
+        for source in entities
+            .into_iter()
+            .flat_map(|entity| &entity.metadata.provenance.edition.provided.sources)
+        {
+            if !provenance.sources.contains(source) {
+                provenance.sources.push(source.clone());
+            }
+        }
+        provenance
+            .merged_entity_ids
+            .clone_from(&self.merged_entity_ids);
+        provenance
+    }
+
+    /// Creates the parameters of the link entity which replaces `link_entity`.
+    ///
+    /// The endpoints of a link entity cannot be changed without rewriting its history, so links
+    /// from or to a merged entity are replaced by a new link entity pointing to the surviving
+    /// entity instead. Returns `None` if `link_entity` is not linked to any merged entity.
+    ///
+    /// # Errors
+    ///
+    /// - if the properties of `link_entity` cannot be combined with their metadata
+    pub fn relink(
+        &self,
+        link_entity: &Entity,
+        relationships: Vec<EntityRelationAndSubject>,
+    ) -> Result<Option<CreateEntityParams<Vec<EntityRelationAndSubject>>>, Report<UpdateError>>
+    {
+        let is_merged = |entity_id: EntityId| {
+            self.merged_entity_ids
+                .iter()
+                .any(|merged| merged.entity_uuid == entity_id.entity_uuid)
+        };
+
+        let Some(link_data) = &link_entity.link_data else {
+            return Ok(None);
+        };
+        if !is_merged(link_data.left_entity_id) && !is_merged(link_data.right_entity_id) {
+            return Ok(None);
+        }
+
+        let mut link_data = link_data.clone();
+        if is_merged(link_data.left_entity_id) {
+            link_data.left_entity_id = self.surviving_entity_id;
+        }
+        if is_merged(link_data.right_entity_id) {
+            link_data.right_entity_id = self.surviving_entity_id;
+        }
+
+        Ok(Some(CreateEntityParams {
+            owned_by_id: link_entity.metadata.record_id.entity_id.owned_by_id,
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: link_entity.metadata.entity_type_ids.clone(),
+            properties: PropertyWithMetadataObject::from_parts(
+                link_entity.properties.clone(),
+                Some(link_entity.metadata.properties.clone()),
+            )
+            .change_context(UpdateError)?,
+            confidence: link_entity.metadata.confidence,
+            link_data: Some(link_data),
+            draft: false,
+            // The owner is always added when creating an entity
+            relationships: relationships
+                .into_iter()
+                .filter(|relationship| {
+                    !matches!(relationship, EntityRelationAndSubject::Owner { .. })
+                })
+                .collect(),
+            provenance: self.provenance.clone(),
+        }))
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct MergeEntitiesResponse {
+    /// The surviving entity after the merge.
+    pub entity: Entity,
+    /// The link entities from or to a merged entity, which were archived.
+    pub relinked_entity_ids: Vec<EntityId>,
+    /// The link entities which replace the archived link entities, in the same order.
+    pub created_link_entity_ids: Vec<EntityId>,
+}
+
+/// The state of an entity to revert to.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub enum EntityRevertTarget {
+    /// The entity is reverted to the specified edition.
+    EditionId(EntityEditionId),
+    /// The entity is reverted to the state which was known at the specified transaction time.
+    TransactionTime(Timestamp<TransactionTime>),
+    /// The entity is reverted to the state which was valid at the specified decision time.
+    DecisionTime(Timestamp<DecisionTime>),
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RevertEntityParams {
+    pub entity_id: EntityId,
+    pub target: EntityRevertTarget,
+    #[serde(default)]
+    pub provenance: ProvidedEntityEditionProvenance,
+}
+
+impl RevertEntityParams {
+    /// Returns the filter and temporal axes to read the entity in the state of the target.
+    fn target_query(&self) -> (Filter<'static, Entity>, QueryTemporalAxesUnresolved) {
+        let filter = Filter::for_entity_by_entity_id(self.entity_id);
+        match self.target {
+            EntityRevertTarget::EditionId(edition_id) => (
+                Filter::All(vec![
+                    filter,
+                    Filter::Equal(
+                        Some(FilterExpression::Path {
+                            path: EntityQueryPath::EditionId,
+                        }),
+                        Some(FilterExpression::Parameter {
+                            parameter: Parameter::Uuid(edition_id.into_uuid()),
+                            convert: None,
+                        }),
+                    ),
+                ]),
+                QueryTemporalAxesUnresolved::TransactionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(
+                        Some(TemporalBound::Unbounded),
+                        None,
+                    ),
+                },
+            ),
+            EntityRevertTarget::TransactionTime(transaction_time) => {
+                (filter, QueryTemporalAxesUnresolved::TransactionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(
+                        Some(TemporalBound::Inclusive(transaction_time)),
+                        Some(LimitedTemporalBound::Inclusive(transaction_time)),
+                    ),
+                })
+            }
+            EntityRevertTarget::DecisionTime(decision_time) => {
+                (filter, QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(
+                        Some(TemporalBound::Inclusive(decision_time)),
+                        Some(LimitedTemporalBound::Inclusive(decision_time)),
+                    ),
+                })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MigrateEntityTypeParams {
+    /// The entity type version the entities are migrated from.
+    pub old_entity_type_id: VersionedUrl,
+    /// The entity type version the entities are migrated to.
+    pub new_entity_type_id: VersionedUrl,
+    /// Moves the values of the top-level properties keyed by the old base URL to the new one.
+    ///
+    /// A moved value replaces an existing value of the target property. Properties which are not
+    /// part of the mapping are kept as they are.
+    #[serde(default)]
+    pub property_mapping: HashMap<BaseUrl, BaseUrl>,
+    /// The top-level properties which are removed from the entities.
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Vec<BaseUrl>))]
+    pub removed_properties: HashSet<BaseUrl>,
+    /// Only reports the entities which would become invalid without migrating them.
+    #[serde(default)]
+    pub dry_run: bool,
+    #[serde(default)]
     pub provenance: ProvidedEntityEditionProvenance,
 }
 
+/// The state of an entity after it was migrated to a new entity type.
+struct EntityTypeMigration {
+    entity_id: EntityId,
+    edition_id: EntityEditionId,
+    entity_type_ids: HashSet<VersionedUrl>,
+    properties: PropertyWithMetadataObject,
+    link_data: Option<LinkData>,
+    confidence: Option<Confidence>,
+}
+
+impl MigrateEntityTypeParams {
+    /// Migrates the entities of the old entity type in `store`.
+    ///
+    /// This is the shared implementation of [`EntityStore::migrate_entity_type`]. The entities are
+    /// updated one by one, so the store has to ensure that the updates are reverted if one of them
+    /// fails.
+    ///
+    /// # Errors
+    ///
+    /// - if the entities of the old entity type cannot be retrieved
+    /// - if the actor is not allowed to update one of the entities
+    /// - if one of the entities was updated while the migration was in progress
+    pub async fn migrate_entities<S>(
+        &self,
+        store: &mut S,
+        actor_id: AccountId,
+    ) -> Result<MigrateEntityTypeResponse, Report<UpdateError>>
+    where
+        S: EntityStore + Send + Sync,
+    {
+        let entities = store
+            .get_entities(actor_id, GetEntitiesParams {
+                filter: Filter::for_entity_by_type_id(&self.old_entity_type_id),
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                sorting: EntityQuerySorting {
+                    paths: Vec::new(),
+                    cursor: None,
+                },
+                conversions: Vec::new(),
+                limit: None,
+                include_drafts: false,
+                include_count: false,
+                include_entity_types: None,
+                include_web_ids: false,
+                include_created_by_ids: false,
+                include_edition_created_by_ids: false,
+                include_type_ids: false,
+            })
+            .await
+            .change_context(UpdateError)?
+            .entities;
+
+        let migrations = entities
+            .into_iter()
+            .filter(|entity| !entity.metadata.archived)
+            .map(|entity| self.migrate_entity(entity))
+            .collect::<Result<Vec<_>, _>>()
+            .change_context(UpdateError)?;
+
+        let reports = store
+            .validate_entities(
+                actor_id,
+                Consistency::FullyConsistent,
+                migrations
+                    .iter()
+                    .map(|migration| ValidateEntityParams {
+                        entity_types: EntityValidationType::Id(Cow::Borrowed(
+                            &migration.entity_type_ids,
+                        )),
+                        properties: Cow::Borrowed(&migration.properties),
+                        link_data: migration.link_data.as_ref().map(Cow::Borrowed),
+                        components: ValidateEntityComponents::full(),
+                    })
+                    .collect(),
+            )
+            .await
+            .into_iter()
+            .map(|(index, report)| (migrations[index].entity_id, report))
+            .collect::<HashMap<_, _>>();
+
+        let entity_count = migrations.len();
+        if self.dry_run || !reports.is_empty() {
+            return Ok(MigrateEntityTypeResponse {
+                entity_count,
+                reports,
+                migrated_entities: Vec::new(),
+            });
+        }
+
+        let mut migrated_entities = Vec::with_capacity(entity_count);
+        for migration in migrations {
+            migrated_entities.push(
+                store
+                    .patch_entity(actor_id, PatchEntityParams {
+                        entity_id: migration.entity_id,
+                        expected_edition_id: Some(migration.edition_id),
+                        decision_time: None,
+                        entity_type_ids: migration.entity_type_ids,
+                        properties: vec![PropertyPatchOperation::Replace {
+                            path: PropertyPath::default(),
+                            property: PropertyWithMetadata::Object(migration.properties),
+                        }],
+                        draft: None,
+                        archived: None,
+                        confidence: migration.confidence,
+                        link_data: None,
+                        provenance: self.provenance.clone(),
+                    })
+                    .await?,
+            );
+        }
+
+        Ok(MigrateEntityTypeResponse {
+            entity_count,
+            reports,
+            migrated_entities,
+        })
+    }
+
+    fn migrate_entity(
+        &self,
+        entity: Entity,
+    ) -> Result<EntityTypeMigration, Report<PropertyPathError>> {
+        let mut entity_type_ids = entity.metadata.entity_type_ids;
+        entity_type_ids.remove(&self.old_entity_type_id);
+        entity_type_ids.insert(self.new_entity_type_id.clone());
+
+        let mut properties = PropertyWithMetadataObject::from_parts(
+            entity.properties,
+            Some(entity.metadata.properties),
+        )?;
+        for base_url in &self.removed_properties {
+            properties.value.remove(base_url);
+        }
+        let moved_properties = self
+            .property_mapping
+            .iter()
+            .filter_map(|(old_base_url, new_base_url)| {
+                Some((new_base_url.clone(), properties.value.remove(old_base_url)?))
+            })
+            .collect::<Vec<_>>();
+        properties.value.extend(moved_properties);
+
+        Ok(EntityTypeMigration {
+            entity_id: entity.metadata.record_id.entity_id,
+            edition_id: entity.metadata.record_id.edition_id,
+            entity_type_ids,
+            properties,
+            link_data: entity.link_data,
+            confidence: entity.metadata.confidence,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct MigrateEntityTypeResponse {
+    /// The number of entities of the old entity type which were checked.
+    pub entity_count: usize,
+    /// The validation reports of the entities which are invalid under the new entity type.
+    pub reports: HashMap<EntityId, EntityValidationReport>,
+    /// The migrated entities.
+    ///
+    /// Entities are only migrated if all of them are valid under the new entity type and
+    /// `dryRun` was not requested.
+    pub migrated_entities: Vec<Entity>,
+}
+
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -333,6 +939,109 @@ pub struct DiffEntityResult<'e> {
     pub draft_state: Option<bool>,
 }
 
+impl DiffEntityResult<'static> {
+    /// Computes the changes from the `previous` entity to `entity`.
+    ///
+    /// If no `previous` entity is provided, `entity` is compared against an empty, non-draft
+    /// entity.
+    #[must_use]
+    pub fn between(previous: Option<&Entity>, entity: &Entity) -> Self {
+        let empty_properties = PropertyObject::empty();
+        let empty_entity_type_ids = HashSet::new();
+        let (previous_properties, previous_entity_type_ids, previous_is_draft) = previous.map_or(
+            (&empty_properties, &empty_entity_type_ids, false),
+            |previous| {
+                (
+                    &previous.properties,
+                    &previous.metadata.entity_type_ids,
+                    previous.metadata.record_id.entity_id.draft_id.is_some(),
+                )
+            },
+        );
+        let is_draft = entity.metadata.record_id.entity_id.draft_id.is_some();
+
+        let removed_types = previous_entity_type_ids
+            .difference(&entity.metadata.entity_type_ids)
+            .map(|removed| EntityTypeIdDiff::Removed {
+                removed: Cow::Borrowed(removed),
+            });
+        let added_types = entity
+            .metadata
+            .entity_type_ids
+            .difference(previous_entity_type_ids)
+            .map(|added| EntityTypeIdDiff::Added {
+                added: Cow::Borrowed(added),
+            });
+
+        Self {
+            properties: previous_properties
+                .diff(&entity.properties, &mut PropertyPath::default())
+                .map(PropertyDiff::into_owned)
+                .collect(),
+            entity_type_ids: removed_types
+                .chain(added_types)
+                .map(EntityTypeIdDiff::into_owned)
+                .collect(),
+            draft_state: (previous_is_draft != is_draft).then_some(is_draft),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct GetEntityHistoryParams {
+    pub entity_id: EntityId,
+    /// Only editions after this edition are returned.
+    pub after: Option<EntityEditionId>,
+    pub limit: Option<usize>,
+}
+
+/// A single edition in the history of an entity.
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityHistoryEntry {
+    pub entity_id: EntityId,
+    pub edition_id: EntityEditionId,
+    pub temporal_versioning: EntityTemporalMetadata,
+    /// The changes compared to the previous edition of the same lineage.
+    pub changes: DiffEntityResult<'static>,
+    /// The archival state of the entity if it was changed by this edition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub archived_state: Option<bool>,
+    pub provenance: EntityEditionProvenance,
+}
+
+impl EntityHistoryEntry {
+    /// Creates the history entry of `entity` compared to the `previous` edition of its lineage.
+    #[must_use]
+    pub fn new(previous: Option<&Entity>, entity: &Entity) -> Self {
+        let was_archived = previous.is_some_and(|previous| previous.metadata.archived);
+        Self {
+            entity_id: entity.metadata.record_id.entity_id,
+            edition_id: entity.metadata.record_id.edition_id,
+            temporal_versioning: entity.metadata.temporal_versioning.clone(),
+            changes: DiffEntityResult::between(previous, entity),
+            archived_state: (was_archived != entity.metadata.archived)
+                .then_some(entity.metadata.archived),
+            provenance: entity.metadata.provenance.edition.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetEntityHistoryResponse {
+    pub editions: Vec<EntityHistoryEntry>,
+    /// The edition to pass as `after` to retrieve the next page.
+    ///
+    /// If this is `None`, all remaining editions were returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub cursor: Option<EntityEditionId>,
+}
+
 /// Describes the API of a store implementation for [Entities].
 ///
 /// [Entities]: Entity
@@ -399,6 +1108,23 @@ pub trait EntityStore {
         params: Vec<ValidateEntityParams<'_>>,
     ) -> impl Future<Output = HashMap<usize, EntityValidationReport>> + Send;
 
+    /// Imports [`Entities`][Entity] from CSV or JSON Lines data.
+    ///
+    /// Each row is converted into the properties described by the column mapping and validated
+    /// against the requested entity types. The entities are only created if all rows are valid
+    /// and no dry run was requested, otherwise the per-row reports are returned.
+    ///
+    /// # Errors
+    ///
+    /// - if the data types of the column mapping cannot be resolved
+    /// - if the data cannot be read
+    /// - if creating the entities failed
+    fn import_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: ImportEntitiesParams,
+    ) -> impl Future<Output = Result<ImportEntitiesResponse, Report<InsertionError>>> + Send;
+
     /// Get a list of entities specified by the [`GetEntitiesParams`].
     ///
     /// # Errors
@@ -434,6 +1160,19 @@ pub trait EntityStore {
         params: CountEntitiesParams<'_>,
     ) -> impl Future<Output = Result<usize, Report<QueryError>>> + Send;
 
+    /// Groups the entities matching the [`Filter`] and computes aggregates for each group.
+    ///
+    /// Only entities the actor is permitted to view are taken into account.
+    ///
+    /// # Errors
+    ///
+    /// - if the request to the database fails
+    fn aggregate_entities(
+        &self,
+        actor_id: AccountId,
+        params: AggregateEntitiesParams<'_>,
+    ) -> impl Future<Output = Result<AggregateEntitiesResponse, Report<QueryError>>> + Send;
+
     fn get_entity_by_id(
         &self,
         actor_id: AccountId,
@@ -448,6 +1187,138 @@ pub trait EntityStore {
         params: PatchEntityParams,
     ) -> impl Future<Output = Result<Entity, Report<UpdateError>>> + Send;
 
+    /// Merges the given entities into a single surviving entity.
+    ///
+    /// The properties of the merged entities are combined into the surviving entity according
+    /// to the property strategies, link entities pointing to or from the merged entities are
+    /// archived and replaced by link entities pointing to the surviving entity, and the merged
+    /// entities are archived. The merge is recorded in the provenance of the new edition of the
+    /// surviving entity. The history of the involved entities is not modified.
+    ///
+    /// # Errors
+    ///
+    /// - if any of the entities does not exist
+    /// - if the [`EntityId`] of the surviving entity is part of the merged entities
+    /// - if the actor is not allowed to update the involved entities
+    fn merge_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: MergeEntitiesParams,
+    ) -> impl Future<Output = Result<MergeEntitiesResponse, Report<UpdateError>>> + Send;
+
+    /// Reverts an [`Entity`] to a previous state.
+    ///
+    /// A new edition is created which equals the state of the entity at the requested target,
+    /// including its entity types, property metadata, confidence, link data, and archival state.
+    /// If the entity is already in that state, no edition is created.
+    ///
+    /// # Errors
+    ///
+    /// - if the entity does not exist at the requested target
+    /// - if the actor is not allowed to update the entity
+    fn revert_entity(
+        &mut self,
+        actor_id: AccountId,
+        params: RevertEntityParams,
+    ) -> impl Future<Output = Result<Entity, Report<UpdateError>>> + Send
+    where
+        Self: Send + Sync,
+    {
+        async move {
+            let (filter, temporal_axes) = params.target_query();
+            let target_entity = self
+                .get_entities(actor_id, GetEntitiesParams {
+                    filter,
+                    temporal_axes,
+                    sorting: EntityQuerySorting {
+                        paths: Vec::new(),
+                        cursor: None,
+                    },
+                    conversions: Vec::new(),
+                    limit: Some(1),
+                    include_drafts: params.entity_id.draft_id.is_some(),
+                    include_count: false,
+                    include_entity_types: None,
+                    include_web_ids: false,
+                    include_created_by_ids: false,
+                    include_edition_created_by_ids: false,
+                    include_type_ids: false,
+                })
+                .await
+                .change_context(UpdateError)?
+                .entities
+                .pop()
+                .ok_or_else(|| {
+                    Report::new(EntityRevertTargetNotFound)
+                        .attach_printable(params.entity_id)
+                        .change_context(UpdateError)
+                })?;
+
+            let properties = PropertyWithMetadataObject::from_parts(
+                target_entity.properties,
+                Some(target_entity.metadata.properties),
+            )
+            .change_context(UpdateError)?;
+
+            self.patch_entity(actor_id, PatchEntityParams {
+                entity_id: params.entity_id,
+                expected_edition_id: None,
+                decision_time: None,
+                entity_type_ids: target_entity.metadata.entity_type_ids,
+                properties: vec![PropertyPatchOperation::Replace {
+                    path: PropertyPath::default(),
+                    property: PropertyWithMetadata::Object(properties),
+                }],
+                draft: None,
+                archived: Some(target_entity.metadata.archived),
+                confidence: target_entity.metadata.confidence,
+                link_data: target_entity.link_data,
+                provenance: params.provenance,
+            })
+            .await
+        }
+    }
+
+    /// Migrates the entities of an entity type version to a new entity type version.
+    ///
+    /// The current, non-draft editions of all entities of the old entity type are transformed
+    /// according to the property mapping and validated against the new entity type. The
+    /// entities are only updated if all of them are valid and no dry run was requested, otherwise
+    /// the validation reports of the invalid entities are returned.
+    ///
+    /// Either all entities are updated or none of them.
+    ///
+    /// # Errors
+    ///
+    /// - if the entities of the old entity type cannot be retrieved
+    /// - if the actor is not allowed to update one of the entities
+    /// - if one of the entities was updated while the migration was in progress
+    fn migrate_entity_type(
+        &mut self,
+        actor_id: AccountId,
+        params: MigrateEntityTypeParams,
+    ) -> impl Future<Output = Result<MigrateEntityTypeResponse, Report<UpdateError>>> + Send;
+
+    /// Returns the editions of an [`Entity`] together with the changes each edition introduced.
+    ///
+    /// The editions, including draft editions, are ordered by the time they were created. Only
+    /// the editions of the requested page are loaded.
+    ///
+    /// Every edition is compared to the previous edition of its lineage: a live edition to the
+    /// previous live edition, a draft edition to the previous edition of the same draft, and the
+    /// first edition of a draft to the live edition it was created from.
+    ///
+    /// # Errors
+    ///
+    /// - if the actor is not allowed to view the entity
+    /// - if `after` is not an edition of the entity
+    /// - if the editions of the entity cannot be retrieved
+    fn get_entity_history(
+        &self,
+        actor_id: AccountId,
+        params: GetEntityHistoryParams,
+    ) -> impl Future<Output = Result<GetEntityHistoryResponse, Report<QueryError>>> + Send;
+
     fn diff_entity(
         &self,
         actor_id: AccountId,
@@ -474,42 +1345,10 @@ pub trait EntityStore {
                 )
                 .await?;
 
-            let property_diff = first_entity
-                .properties
-                .diff(&second_entity.properties, &mut PropertyPath::default())
-                .map(PropertyDiff::into_owned)
-                .collect();
-
-            let removed_types = first_entity
-                .metadata
-                .entity_type_ids
-                .difference(&second_entity.metadata.entity_type_ids)
-                .map(|removed| EntityTypeIdDiff::Removed {
-                    removed: Cow::Borrowed(removed),
-                });
-            let added_types = second_entity
-                .metadata
-                .entity_type_ids
-                .difference(&first_entity.metadata.entity_type_ids)
-                .map(|added| EntityTypeIdDiff::Added {
-                    added: Cow::Borrowed(added),
-                });
-            let first_is_draft = first_entity.metadata.record_id.entity_id.draft_id.is_some();
-            let second_is_draft = second_entity
-                .metadata
-                .record_id
-                .entity_id
-                .draft_id
-                .is_some();
-
-            Ok(DiffEntityResult {
-                properties: property_diff,
-                entity_type_ids: removed_types
-                    .chain(added_types)
-                    .map(EntityTypeIdDiff::into_owned)
-                    .collect(),
-                draft_state: (first_is_draft != second_is_draft).then_some(second_is_draft),
-            })
+            Ok(DiffEntityResult::between(
+                Some(&first_entity),
+                &second_entity,
+            ))
         }
     }
 
@@ -1,5 +1,7 @@
 use core::{error::Error, fmt};
 
+use hash_graph_types::knowledge::entity::EntityEditionId;
+
 #[derive(Debug)]
 #[must_use]
 pub struct InsertionError;
@@ -36,6 +38,64 @@ impl fmt::Display for UpdateError {
 
 impl Error for UpdateError {}
 
+/// The entity was updated after the edition an update was based on.
+#[derive(Debug)]
+#[must_use]
+pub struct EntityEditionMismatch {
+    pub expected: EntityEditionId,
+    pub actual: EntityEditionId,
+}
+
+impl fmt::Display for EntityEditionMismatch {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fmt,
+            "The entity was updated since edition `{}`, the current edition is `{}`",
+            self.expected.as_uuid(),
+            self.actual.as_uuid()
+        )
+    }
+}
+
+impl Error for EntityEditionMismatch {}
+
+#[derive(Debug)]
+#[must_use]
+pub struct EntityRevertTargetNotFound;
+
+impl fmt::Display for EntityRevertTargetNotFound {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("The entity does not exist at the requested revert target")
+    }
+}
+
+impl Error for EntityRevertTargetNotFound {}
+
+#[derive(Debug)]
+#[must_use]
+pub struct EntityHistoryCursorNotFound;
+
+impl fmt::Display for EntityHistoryCursorNotFound {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("The edition to continue the history after is not an edition of the entity")
+    }
+}
+
+impl Error for EntityHistoryCursorNotFound {}
+
+/// A patch tried to change the entities a link entity is pointing to.
+#[derive(Debug)]
+#[must_use]
+pub struct LinkedEntitiesMismatch;
+
+impl fmt::Display for LinkedEntitiesMismatch {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("The linked entities of a link entity cannot be changed")
+    }
+}
+
+impl Error for LinkedEntitiesMismatch {}
+
 #[derive(Debug)]
 #[must_use]
 pub struct DeletionError;
@@ -126,6 +126,25 @@ pub enum Filter<'p, R: QueryRecord> {
     StartsWith(FilterExpression<'p, R>, FilterExpression<'p, R>),
     EndsWith(FilterExpression<'p, R>, FilterExpression<'p, R>),
     ContainsSegment(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches if the left-hand side matches the SQL `LIKE` pattern on the right-hand side.
+    ///
+    /// `%` matches any sequence of characters, `_` matches a single character, and `\` escapes
+    /// the next character.
+    Like(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Same as [`Filter::Like`] but compares case-insensitively.
+    #[serde(rename = "iLike")]
+    ILike(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches if the left-hand side matches the POSIX regular expression on the right-hand side.
+    Regex(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches if the text values of the left-hand side match the full-text search query on the
+    /// right-hand side.
+    ///
+    /// The query uses the web search syntax, i.e. quoted phrases, `or` and `-` for negation are
+    /// supported.
+    ///
+    /// Only matches against all properties of an entity are backed by an index, matching a single
+    /// property or the label of an entity requires a scan.
+    FullTextMatch(FilterExpression<'p, R>, FilterExpression<'p, R>),
 }
 
 impl<'p, R> Filter<'p, R>
@@ -435,7 +454,11 @@ where
             }
             Self::StartsWith(lhs, rhs)
             | Self::EndsWith(lhs, rhs)
-            | Self::ContainsSegment(lhs, rhs) => {
+            | Self::ContainsSegment(lhs, rhs)
+            | Self::Like(lhs, rhs)
+            | Self::ILike(lhs, rhs)
+            | Self::Regex(lhs, rhs)
+            | Self::FullTextMatch(lhs, rhs) => {
                 lhs.apply_parameter_conversion(data_type_provider).await?;
                 rhs.apply_parameter_conversion(data_type_provider).await?;
 
@@ -703,4 +726,28 @@ mod tests {
         )
         .await;
     }
+
+    #[tokio::test]
+    async fn case_insensitive_like() {
+        let expected = json!({
+          "iLike": [
+            { "path": ["title"] },
+            { "parameter": "%number%" }
+          ]
+        });
+
+        test_filter_representation(
+            &Filter::<DataTypeWithMetadata>::ILike(
+                FilterExpression::Path {
+                    path: DataTypeQueryPath::Title,
+                },
+                FilterExpression::Parameter {
+                    parameter: Parameter::Text(Cow::Borrowed("%number%")),
+                    convert: None,
+                },
+            ),
+            &expected,
+        )
+        .await;
+    }
 }
@@ -27,6 +27,11 @@ impl<'p> JsonPath<'p> {
         Self { path }
     }
 
+    #[must_use]
+    pub fn path_tokens(&self) -> &[PathToken<'p>] {
+        &self.path
+    }
+
     fn write(&self, writer: &mut impl Write) -> Result<(), fmt::Error> {
         writer.write_char('$')?;
         for token in &self.path {
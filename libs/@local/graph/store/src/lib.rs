@@ -7,6 +7,7 @@
 extern crate alloc;
 
 pub mod account;
+pub mod change_feed;
 pub mod data_type;
 pub mod entity;
 pub mod entity_type;
@@ -6,8 +6,8 @@ use hash_graph_authorization::AuthorizationApi;
 use hash_temporal_client::TemporalClient;
 
 use crate::{
-    account::AccountStore, data_type::DataTypeStore, entity::EntityStore,
-    entity_type::EntityTypeStore, property_type::PropertyTypeStore,
+    account::AccountStore, change_feed::ChangeFeedStore, data_type::DataTypeStore,
+    entity::EntityStore, entity_type::EntityTypeStore, property_type::PropertyTypeStore,
 };
 
 /// Managed pool to keep track about [`Store`]s.
@@ -25,6 +25,7 @@ pub trait StorePool {
         + PropertyTypeStore
         + EntityTypeStore
         + EntityStore
+        + ChangeFeedStore
         + Send
         + Sync;
 
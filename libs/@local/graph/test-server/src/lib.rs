@@ -1,5 +1,7 @@
 extern crate alloc;
 
+mod memory_store;
+
 use alloc::sync::Arc;
 use core::error::Error;
 use std::collections::HashMap;
@@ -23,7 +25,10 @@ use hash_graph_authorization::{
     },
     zanzibar::types::{RelationshipFilter, ResourceFilter},
 };
-use hash_graph_postgres_store::{snapshot::SnapshotStore, store::PostgresStorePool};
+use hash_graph_postgres_store::{
+    snapshot::{SnapshotRestoreSettings, SnapshotStore},
+    store::PostgresStorePool,
+};
 use hash_graph_store::pool::StorePool as _;
 use hash_graph_type_defs::error::{ErrorInfo, StatusPayloads};
 use hash_graph_types::account::AccountId;
@@ -32,6 +37,8 @@ use tokio::io;
 use tokio_util::{codec::FramedRead, io::StreamReader};
 use uuid::Uuid;
 
+pub use self::memory_store::memory_store_routes;
+
 /// Create routes for interacting with entities.
 pub fn routes<A>(store_pool: PostgresStorePool, authorization_api: A) -> Router
 where
@@ -111,8 +118,12 @@ where
                 ),
                 JsonLinesDecoder::default(),
             ),
-            10_000,
-            false,
+            SnapshotRestoreSettings {
+                chunk_size: 10_000,
+                ignore_validation_errors: false,
+                resume: false,
+                restart: true,
+            },
         )
         .await
         .map_err(|report| {
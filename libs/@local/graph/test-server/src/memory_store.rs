@@ -0,0 +1,220 @@
+use alloc::sync::Arc;
+
+use axum::{
+    Extension, Router,
+    response::Response,
+    routing::{delete, post},
+};
+use hash_graph_api::rest::{middleware::span_trace_layer, status::status_to_response};
+use hash_graph_authorization::{
+    AuthorizationApi,
+    backend::ZanzibarBackend,
+    schema::{
+        AccountGroupNamespace, DataTypeNamespace, EntityNamespace, EntityTypeNamespace,
+        PropertyTypeNamespace, WebNamespace,
+    },
+    zanzibar::types::{RelationshipFilter, ResourceFilter},
+};
+use hash_graph_memory_store::store::MemoryStorePool;
+use hash_graph_store::pool::StorePool as _;
+use hash_status::{Status, StatusCode};
+
+use crate::{report_to_response, store_acquisition_error};
+
+/// Create routes for resetting a [`MemoryStorePool`].
+///
+/// Restoring a snapshot is not supported by the in-memory store, the `/snapshot` endpoint always
+/// responds with [`StatusCode::Unimplemented`].
+pub fn memory_store_routes<A>(store_pool: MemoryStorePool, authorization_api: A) -> Router
+where
+    A: AuthorizationApi + ZanzibarBackend + Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/snapshot", post(restore_snapshot))
+        .route("/accounts", delete(delete_accounts::<A>))
+        .route("/data-types", delete(delete_data_types::<A>))
+        .route("/property-types", delete(delete_property_types::<A>))
+        .route("/entity-types", delete(delete_entity_types::<A>))
+        .route("/entities", delete(delete_entities::<A>))
+        .layer(Extension(Arc::new(store_pool)))
+        .layer(Extension(Arc::new(authorization_api)))
+        .layer(span_trace_layer())
+}
+
+async fn restore_snapshot() -> Response {
+    status_to_response(Status::<()>::new(
+        StatusCode::Unimplemented,
+        Some("Snapshots cannot be restored into the in-memory store".to_owned()),
+        vec![],
+    ))
+}
+
+async fn delete_accounts<A>(
+    pool: Extension<Arc<MemoryStorePool>>,
+    authorization_api: Extension<Arc<A>>,
+) -> Result<Response, Response>
+where
+    A: AuthorizationApi + ZanzibarBackend + Clone,
+{
+    let mut authorization_api = (**authorization_api).clone();
+
+    authorization_api
+        .delete_relations(RelationshipFilter::from_resource(
+            ResourceFilter::from_kind(WebNamespace::Web),
+        ))
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not delete web relationships");
+            report_to_response(&report, "ACCOUNT_DELETION_FAILURE")
+        })?;
+
+    authorization_api
+        .delete_relations(RelationshipFilter::from_resource(
+            ResourceFilter::from_kind(AccountGroupNamespace::AccountGroup),
+        ))
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not delete account group relationships");
+            report_to_response(&report, "ACCOUNT_DELETION_FAILURE")
+        })?;
+
+    pool.acquire(authorization_api, None)
+        .await
+        .map_err(store_acquisition_error)?
+        .delete_accounts()
+        .await;
+
+    Ok(status_to_response(Status::<()>::new(
+        StatusCode::Ok,
+        Some("Accounts deleted successfully".to_owned()),
+        vec![],
+    )))
+}
+
+async fn delete_data_types<A>(
+    pool: Extension<Arc<MemoryStorePool>>,
+    authorization_api: Extension<Arc<A>>,
+) -> Result<Response, Response>
+where
+    A: AuthorizationApi + ZanzibarBackend + Clone,
+{
+    let mut authorization_api = (**authorization_api).clone();
+
+    authorization_api
+        .delete_relations(RelationshipFilter::from_resource(
+            ResourceFilter::from_kind(DataTypeNamespace::DataType),
+        ))
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not delete data type relationships");
+            report_to_response(&report, "DATA_TYPE_DELETION_FAILURE")
+        })?;
+
+    pool.acquire(authorization_api, None)
+        .await
+        .map_err(store_acquisition_error)?
+        .delete_data_types()
+        .await;
+
+    Ok(status_to_response(Status::<()>::new(
+        StatusCode::Ok,
+        Some("Data types deleted successfully".to_owned()),
+        vec![],
+    )))
+}
+
+async fn delete_property_types<A>(
+    pool: Extension<Arc<MemoryStorePool>>,
+    authorization_api: Extension<Arc<A>>,
+) -> Result<Response, Response>
+where
+    A: AuthorizationApi + ZanzibarBackend + Clone,
+{
+    let mut authorization_api = (**authorization_api).clone();
+
+    authorization_api
+        .delete_relations(RelationshipFilter::from_resource(
+            ResourceFilter::from_kind(PropertyTypeNamespace::PropertyType),
+        ))
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not delete property type relationships");
+            report_to_response(&report, "PROPERTY_TYPE_DELETION_FAILURE")
+        })?;
+
+    pool.acquire(authorization_api, None)
+        .await
+        .map_err(store_acquisition_error)?
+        .delete_property_types()
+        .await;
+
+    Ok(status_to_response(Status::<()>::new(
+        StatusCode::Ok,
+        Some("Property types deleted successfully".to_owned()),
+        vec![],
+    )))
+}
+
+async fn delete_entity_types<A>(
+    pool: Extension<Arc<MemoryStorePool>>,
+    authorization_api: Extension<Arc<A>>,
+) -> Result<Response, Response>
+where
+    A: AuthorizationApi + ZanzibarBackend + Clone,
+{
+    let mut authorization_api = (**authorization_api).clone();
+
+    authorization_api
+        .delete_relations(RelationshipFilter::from_resource(
+            ResourceFilter::from_kind(EntityTypeNamespace::EntityType),
+        ))
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not delete entity type relationships");
+            report_to_response(&report, "ENTITY_TYPE_DELETION_FAILURE")
+        })?;
+
+    pool.acquire(authorization_api, None)
+        .await
+        .map_err(store_acquisition_error)?
+        .delete_entity_types()
+        .await;
+
+    Ok(status_to_response(Status::<()>::new(
+        StatusCode::Ok,
+        Some("Entity types deleted successfully".to_owned()),
+        vec![],
+    )))
+}
+
+async fn delete_entities<A>(
+    pool: Extension<Arc<MemoryStorePool>>,
+    authorization_api: Extension<Arc<A>>,
+) -> Result<Response, Response>
+where
+    A: AuthorizationApi + ZanzibarBackend + Clone,
+{
+    let mut authorization_api = (**authorization_api).clone();
+
+    authorization_api
+        .delete_relations(RelationshipFilter::from_resource(
+            ResourceFilter::from_kind(EntityNamespace::Entity),
+        ))
+        .await
+        .map_err(|report| {
+            tracing::error!(error=?report, "Could not delete entity relationships");
+            report_to_response(&report, "ENTITY_DELETION_FAILURE")
+        })?;
+
+    pool.acquire(authorization_api, None)
+        .await
+        .map_err(store_acquisition_error)?
+        .delete_entities()
+        .await;
+
+    Ok(status_to_response(Status::<()>::new(
+        StatusCode::Ok,
+        Some("Entities deleted successfully".to_owned()),
+        vec![],
+    )))
+}
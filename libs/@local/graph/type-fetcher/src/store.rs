@@ -18,6 +18,7 @@ use hash_graph_store::{
         InsertAccountGroupIdParams, InsertAccountIdParams, InsertWebIdParams, QueryWebError,
         WebInsertionError,
     },
+    change_feed::{ChangeFeedStore, ReadGraphChangesParams, ReadGraphChangesResponse},
     data_type::{
         ArchiveDataTypeParams, CountDataTypesParams, CreateDataTypeParams, DataTypeStore,
         GetDataTypeSubgraphParams, GetDataTypeSubgraphResponse, GetDataTypesParams,
@@ -25,9 +26,13 @@ use hash_graph_store::{
         UpdateDataTypesParams,
     },
     entity::{
-        CountEntitiesParams, CreateEntityParams, EntityStore, EntityValidationReport,
-        GetEntitiesParams, GetEntitiesResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse,
-        PatchEntityParams, UpdateEntityEmbeddingsParams, ValidateEntityParams,
+        AggregateEntitiesParams, AggregateEntitiesResponse, CountEntitiesParams,
+        CreateEntityParams, EntityStore, EntityValidationReport, GetEntitiesParams,
+        GetEntitiesResponse, GetEntityHistoryParams, GetEntityHistoryResponse,
+        GetEntitySubgraphParams, GetEntitySubgraphResponse, ImportEntitiesParams,
+        ImportEntitiesResponse, MergeEntitiesParams, MergeEntitiesResponse,
+        MigrateEntityTypeParams, MigrateEntityTypeResponse, PatchEntityParams,
+        UpdateEntityEmbeddingsParams, ValidateEntityParams,
     },
     entity_type::{
         ArchiveEntityTypeParams, CountEntityTypesParams, CreateEntityTypeParams, EntityTypeStore,
@@ -802,6 +807,20 @@ where
     }
 }
 
+impl<S, A> ChangeFeedStore for FetchingStore<S, A>
+where
+    S: ChangeFeedStore + Send + Sync,
+    A: Send + Sync,
+{
+    async fn read_graph_changes(
+        &self,
+        actor_id: AccountId,
+        params: ReadGraphChangesParams,
+    ) -> Result<ReadGraphChangesResponse, Report<QueryError>> {
+        self.store.read_graph_changes(actor_id, params).await
+    }
+}
+
 impl<S, A> DataTypeStore for FetchingStore<S, A>
 where
     S: DataTypeStore + PropertyTypeStore + EntityTypeStore + Send + Sync,
@@ -1210,6 +1229,28 @@ where
             .await
     }
 
+    async fn import_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: ImportEntitiesParams,
+    ) -> Result<ImportEntitiesResponse, Report<InsertionError>> {
+        for entity_type_id in &params.entity_type_ids {
+            let entity_type_reference = EntityTypeReference {
+                url: entity_type_id.clone(),
+            };
+            self.insert_external_types_by_reference(
+                actor_id,
+                OntologyTypeReference::EntityTypeReference(&entity_type_reference),
+                ConflictBehavior::Skip,
+                FetchBehavior::ExcludeProvidedReferences,
+                &HashSet::new(),
+            )
+            .await?;
+        }
+
+        self.store.import_entities(actor_id, params).await
+    }
+
     async fn get_entities(
         &self,
         actor_id: AccountId,
@@ -1246,6 +1287,14 @@ where
         self.store.count_entities(actor_id, params).await
     }
 
+    async fn aggregate_entities(
+        &self,
+        actor_id: AccountId,
+        params: AggregateEntitiesParams<'_>,
+    ) -> Result<AggregateEntitiesResponse, Report<QueryError>> {
+        self.store.aggregate_entities(actor_id, params).await
+    }
+
     async fn patch_entity(
         &mut self,
         actor_id: AccountId,
@@ -1268,6 +1317,30 @@ where
         self.store.patch_entity(actor_id, params).await
     }
 
+    async fn merge_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: MergeEntitiesParams,
+    ) -> Result<MergeEntitiesResponse, Report<UpdateError>> {
+        self.store.merge_entities(actor_id, params).await
+    }
+
+    async fn migrate_entity_type(
+        &mut self,
+        actor_id: AccountId,
+        params: MigrateEntityTypeParams,
+    ) -> Result<MigrateEntityTypeResponse, Report<UpdateError>> {
+        self.store.migrate_entity_type(actor_id, params).await
+    }
+
+    async fn get_entity_history(
+        &self,
+        actor_id: AccountId,
+        params: GetEntityHistoryParams,
+    ) -> Result<GetEntityHistoryResponse, Report<QueryError>> {
+        self.store.get_entity_history(actor_id, params).await
+    }
+
     async fn update_entity_embeddings(
         &mut self,
         actor_id: AccountId,
@@ -113,7 +113,7 @@ pub struct EntityMetadata {
 
 /// A record of an [`Entity`] that has been persisted in the datastore, with its associated
 /// metadata.
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(ToSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Entity {
@@ -143,25 +143,7 @@ impl Entity {
         )
         .change_context(PatchError)?;
 
-        for operation in operations {
-            match operation {
-                PropertyPatchOperation::Add { path, property } => {
-                    properties_with_metadata
-                        .add(path, property)
-                        .change_context(PatchError)?;
-                }
-                PropertyPatchOperation::Remove { path } => {
-                    properties_with_metadata
-                        .remove(&path)
-                        .change_context(PatchError)?;
-                }
-                PropertyPatchOperation::Replace { path, property } => {
-                    properties_with_metadata
-                        .replace(&path, property)
-                        .change_context(PatchError)?;
-                }
-            }
-        }
+        properties_with_metadata.patch(operations)?;
 
         let (
             Property::Object(properties),
@@ -771,4 +753,107 @@ mod tests {
             ]);
         }
     }
+
+    mod patch {
+        use error_stack::Report;
+        use type_system::url::BaseUrl;
+
+        use crate::knowledge::property::{
+            PatchError, PatchTestFailed, Property, PropertyPatchOperation, PropertyPath,
+            PropertyPathElement, PropertyWithMetadata,
+        };
+
+        macro_rules! property {
+            ($($json:tt)+) => {
+                serde_json::from_value::<Property>(serde_json::json!($($json)+)).expect("invalid JSON")
+            };
+        }
+
+        fn create_base_url(property: usize) -> BaseUrl {
+            BaseUrl::new(format!("http://example.com/property-{property}/")).expect("invalid URL")
+        }
+
+        fn path(property: usize, index: Option<usize>) -> PropertyPath<'static> {
+            [PropertyPathElement::from(create_base_url(property))]
+                .into_iter()
+                .chain(index.map(PropertyPathElement::Index))
+                .collect()
+        }
+
+        fn patch(
+            property: Property,
+            operations: impl IntoIterator<Item = PropertyPatchOperation>,
+        ) -> Result<Property, Report<PatchError>> {
+            let mut property = PropertyWithMetadata::from_parts(property, None)
+                .expect("could not create property with metadata");
+            property.patch(operations)?;
+            Ok(property.into_parts().0)
+        }
+
+        #[test]
+        fn move_property() {
+            let patched = patch(
+                property!({ create_base_url(1): ["foo", "bar"], create_base_url(2): "baz" }),
+                [
+                    PropertyPatchOperation::Move {
+                        from: path(1, Some(0)),
+                        path: path(1, Some(1)),
+                    },
+                    PropertyPatchOperation::Move {
+                        from: path(2, None),
+                        path: path(3, None),
+                    },
+                ],
+            )
+            .expect("could not patch property");
+
+            assert_eq!(
+                patched,
+                property!({ create_base_url(1): ["bar", "foo"], create_base_url(3): "baz" })
+            );
+        }
+
+        #[test]
+        fn copy_property() {
+            let patched = patch(property!({ create_base_url(1): ["foo", "bar"] }), [
+                PropertyPatchOperation::Copy {
+                    from: path(1, Some(1)),
+                    path: path(1, Some(0)),
+                },
+            ])
+            .expect("could not patch property");
+
+            assert_eq!(
+                patched,
+                property!({ create_base_url(1): ["bar", "foo", "bar"] })
+            );
+        }
+
+        #[test]
+        fn test_property() {
+            let property = property!({ create_base_url(1): "foo" });
+
+            patch(property.clone(), [PropertyPatchOperation::Test {
+                path: path(1, None),
+                value: property!("foo"),
+                metadata: None,
+                confidence: None,
+            }])
+            .expect("test operation should succeed");
+
+            for (path, value) in [
+                (path(1, None), property!("bar")),
+                (path(2, None), property!("foo")),
+            ] {
+                let report = patch(property.clone(), [PropertyPatchOperation::Test {
+                    path,
+                    value,
+                    metadata: None,
+                    confidence: None,
+                }])
+                .expect_err("test operation should fail");
+                assert!(report.contains::<PatchTestFailed>(), "{report:?}");
+            }
+        }
+    }
 }
@@ -308,6 +308,9 @@ pub struct ProvidedEntityEditionProvenance {
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub origin: Option<OriginProvenance>,
+    /// The entities which were merged into this entity when this edition was created.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub merged_entity_ids: Vec<EntityId>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -15,6 +15,8 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use type_system::url::BaseUrl;
 
+use crate::knowledge::Confidence;
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
@@ -36,3 +38,16 @@ pub enum PropertyMetadata {
     #[cfg_attr(feature = "utoipa", schema(title = "PropertyMetadataValue"))]
     Value { metadata: ValueMetadata },
 }
+
+impl PropertyMetadata {
+    /// Returns the confidence of the property itself, excluding the confidence of nested
+    /// properties.
+    #[must_use]
+    pub const fn confidence(&self) -> Option<Confidence> {
+        match self {
+            Self::Array { metadata, .. } => metadata.confidence,
+            Self::Object { metadata, .. } => metadata.confidence,
+            Self::Value { metadata } => metadata.confidence,
+        }
+    }
+}
@@ -11,7 +11,7 @@ pub use self::{
         PropertyProvenance, PropertyWithMetadataValue, ValueMetadata,
     },
     object::{PropertyObject, PropertyWithMetadataObject},
-    patch::{PatchError, PropertyPatchOperation},
+    patch::{PatchError, PatchTestFailed, PropertyPatchOperation},
     path::{PropertyPath, PropertyPathElement},
 };
 
@@ -34,6 +34,8 @@ use type_system::{
     url::{BaseUrl, VersionedUrl},
 };
 
+use crate::knowledge::Confidence;
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(untagged)]
@@ -100,6 +102,15 @@ impl PropertyWithMetadata {
         }
     }
 
+    #[must_use]
+    pub const fn confidence(&self) -> Option<Confidence> {
+        match self {
+            Self::Array(array) => array.metadata.confidence,
+            Self::Object(object) => object.metadata.confidence,
+            Self::Value(property) => property.metadata.confidence,
+        }
+    }
+
     /// Modify the properties and confidence values of the entity.
     ///
     /// # Errors
@@ -120,17 +131,154 @@ impl PropertyWithMetadata {
                 PropertyPatchOperation::Replace { path, property } => {
                     self.replace(&path, property).change_context(PatchError)?;
                 }
+                PropertyPatchOperation::Move { from, path } => {
+                    let property = self.get(from.as_ref()).change_context(PatchError)?.clone();
+                    self.remove(&from).change_context(PatchError)?;
+                    self.add(path, property).change_context(PatchError)?;
+                }
+                PropertyPatchOperation::Copy { from, path } => {
+                    let property = self.get(from.as_ref()).change_context(PatchError)?.clone();
+                    self.add(path, property).change_context(PatchError)?;
+                }
+                PropertyPatchOperation::Test {
+                    path,
+                    value,
+                    metadata,
+                    confidence,
+                } => {
+                    self.test(&path, &value, metadata.as_ref(), confidence)
+                        .change_context(PatchError)?;
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Checks that the property at the given path has the expected value.
+    ///
+    /// The metadata and the confidence are only compared if they are provided.
+    ///
+    /// # Errors
+    ///
+    /// - [`PatchTestFailed`] if the path does not point to a property or the property does not
+    ///   match the expectation.
+    pub fn test(
+        &self,
+        path: &PropertyPath<'_>,
+        value: &Property,
+        metadata: Option<&PropertyMetadata>,
+        confidence: Option<Confidence>,
+    ) -> Result<(), Report<PatchTestFailed>> {
+        let actual = self.get(path.as_ref()).change_context(PatchTestFailed)?;
+
+        if !actual.value_eq(value) {
+            return Err(Report::new(PatchTestFailed).attach_printable("value does not match"));
+        }
+        if metadata.is_some_and(|metadata| !actual.metadata_eq(metadata)) {
+            return Err(Report::new(PatchTestFailed).attach_printable("metadata does not match"));
+        }
+        if confidence.is_some_and(|confidence| Some(confidence) != actual.confidence()) {
+            return Err(Report::new(PatchTestFailed).attach_printable("confidence does not match"));
+        }
+
+        Ok(())
+    }
+
+    /// Returns if the value of the property is equal to `value`, ignoring any metadata.
+    fn value_eq(&self, value: &Property) -> bool {
+        match (self, value) {
+            (Self::Array(array), Property::Array(values)) => {
+                array.value.len() == values.len()
+                    && iter::zip(&array.value, values).all(|(lhs, rhs)| lhs.value_eq(rhs))
+            }
+            (Self::Object(object), Property::Object(values)) => {
+                object.value.len() == values.len()
+                    && object.value.iter().all(|(base_url, lhs)| {
+                        values
+                            .properties()
+                            .get(base_url)
+                            .is_some_and(|rhs| lhs.value_eq(rhs))
+                    })
+            }
+            (Self::Value(property), Property::Value(value)) => property.value == *value,
+            _ => false,
+        }
+    }
+
+    /// Returns if the metadata of the property, including the metadata of nested properties, is
+    /// equal to `metadata`.
+    fn metadata_eq(&self, metadata: &PropertyMetadata) -> bool {
+        match (self, metadata) {
+            (Self::Array(array), PropertyMetadata::Array { value, metadata }) => {
+                array.metadata == *metadata
+                    && array.value.len() == value.len()
+                    && iter::zip(&array.value, value).all(|(lhs, rhs)| lhs.metadata_eq(rhs))
+            }
+            (Self::Object(object), PropertyMetadata::Object { value, metadata }) => {
+                object.metadata == *metadata
+                    && object.value.len() == value.len()
+                    && object.value.iter().all(|(base_url, lhs)| {
+                        value.get(base_url).is_some_and(|rhs| lhs.metadata_eq(rhs))
+                    })
+            }
+            (Self::Value(property), PropertyMetadata::Value { metadata }) => {
+                property.metadata == *metadata
+            }
+            _ => false,
+        }
+    }
+
     /// Returns the property at the given path.
     ///
     /// # Errors
     ///
     /// - If the path does not point to a property.
+    pub fn get(
+        &self,
+        path: &[PropertyPathElement<'_>],
+    ) -> Result<&Self, Report<PropertyPathError>> {
+        let mut value = self;
+        for path_element in path {
+            match (value, path_element) {
+                (Self::Array(array), PropertyPathElement::Index(index)) => {
+                    let len = array.value.len();
+                    value = array
+                        .value
+                        .get(*index)
+                        .ok_or(PropertyPathError::IndexOutOfBounds { index: *index, len })?;
+                }
+                (Self::Array(_), PropertyPathElement::Property(key)) => {
+                    return Err(Report::new(PropertyPathError::UnexpectedKey {
+                        key: key.clone().into_owned(),
+                    }));
+                }
+                (Self::Object(object), PropertyPathElement::Property(key)) => {
+                    value = object.value.get(key.as_ref()).ok_or_else(|| {
+                        PropertyPathError::InvalidKey {
+                            key: key.clone().into_owned(),
+                        }
+                    })?;
+                }
+                (Self::Object(_), PropertyPathElement::Index(index)) => {
+                    return Err(Report::new(PropertyPathError::UnexpectedIndex {
+                        index: *index,
+                    }));
+                }
+                (Self::Value(_), _) => {
+                    return Err(Report::new(PropertyPathError::UnexpectedValue));
+                }
+            }
+        }
+
+        Ok(value)
+    }
+
+    /// Returns the property at the given path mutably.
+    ///
+    /// # Errors
+    ///
+    /// - If the path does not point to a property.
     pub fn get_mut(
         &mut self,
         path: &[PropertyPathElement<'_>],
@@ -1,9 +1,12 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::knowledge::property::{PropertyPath, PropertyWithMetadata};
+use crate::knowledge::{
+    Confidence,
+    property::{Property, PropertyMetadata, PropertyPath, PropertyWithMetadata},
+};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", tag = "op")]
 pub enum PropertyPatchOperation {
@@ -18,8 +21,36 @@ pub enum PropertyPatchOperation {
         path: PropertyPath<'static>,
         property: PropertyWithMetadata,
     },
+    /// Removes the property at `from` and adds it at `path`.
+    Move {
+        from: PropertyPath<'static>,
+        path: PropertyPath<'static>,
+    },
+    /// Adds a copy of the property at `from` at `path`.
+    Copy {
+        from: PropertyPath<'static>,
+        path: PropertyPath<'static>,
+    },
+    /// Checks that the property at `path` has the expected value.
+    ///
+    /// If `metadata` or `confidence` are provided, they are compared as well. If the check fails,
+    /// the whole patch is rejected with [`PatchTestFailed`].
+    Test {
+        path: PropertyPath<'static>,
+        value: Property,
+        #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+        #[serde(default)]
+        metadata: Option<PropertyMetadata>,
+        #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+        #[serde(default)]
+        confidence: Option<Confidence>,
+    },
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Error)]
 #[error("Failed to apply patch")]
 pub struct PatchError;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Error)]
+#[error("The property does not match the expected value of the test operation")]
+pub struct PatchTestFailed;
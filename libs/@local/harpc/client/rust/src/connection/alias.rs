@@ -6,7 +6,7 @@ use core::error::Error;
 use bytes::Buf;
 use error_stack::Report;
 use futures::stream;
-use harpc_codec::{decode::Decoder, encode::Encoder};
+use harpc_codec::{decode::Decoder, encode::Encoder, negotiate::Negotiate};
 use harpc_tower::{request::Request, response::Response};
 
 pub type ConnectionRequestStream<C> = stream::Iter<vec::IntoIter<<C as Encoder>::Buf>>;
@@ -56,6 +56,7 @@ where
 pub trait ConnectionCodec:
     Encoder<Error = Report<Self::EncoderError>, Buf: Send>
     + Decoder<Error = Report<Self::DecoderError>>
+    + Negotiate
     + Clone
     + Send
     + Sync
@@ -68,6 +69,7 @@ impl<C, EncoderError, DecoderError> ConnectionCodec for C
 where
     C: Encoder<Error = Report<EncoderError>, Buf: Send>
         + Decoder<Error = Report<DecoderError>>
+        + Negotiate
         + Clone
         + Send
         + Sync,
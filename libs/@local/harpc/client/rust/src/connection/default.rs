@@ -5,7 +5,8 @@ use error_stack::Report;
 use futures::{Stream, StreamExt as _, TryFutureExt as _, future, stream};
 use harpc_net::session::error::ConnectionPartiallyClosedError;
 use harpc_tower::{
-    body::{Frame, stream::StreamBody},
+    body::{Frame, compression::DecompressResponse, stream::StreamBody},
+    layer::compression::ClientCompressionService,
     net::{pack_error::PackError, unpack::Unpack},
     request::Request,
     response::Response,
@@ -74,11 +75,13 @@ where
 
 #[derive(Debug, Clone)]
 pub struct Default {
-    inner: DefaultService<ConnectionService>,
+    inner: DefaultService<ClientCompressionService<ConnectionService>>,
 }
 
 impl Default {
-    pub(crate) const fn new(inner: DefaultService<ConnectionService>) -> Self {
+    pub(crate) const fn new(
+        inner: DefaultService<ClientCompressionService<ConnectionService>>,
+    ) -> Self {
         Self { inner }
     }
 }
@@ -88,12 +91,14 @@ where
     St: Stream<Item: Buf + 'static> + Send + 'static,
 {
     type Error = Report<ConnectionPartiallyClosedError>;
-    type Response = Response<PackError<Unpack>>;
+    type Response = Response<PackError<DecompressResponse<Unpack>>>;
 
     type Future = impl Future<Output = Result<Self::Response, Self::Error>>;
 
     fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
-        <DefaultService<ConnectionService> as Service<harpc_tower::request::Request<St>>>::poll_ready(&mut self.inner, cx)
+        <DefaultService<ClientCompressionService<ConnectionService>> as Service<
+            harpc_tower::request::Request<St>,
+        >>::poll_ready(&mut self.inner, cx)
     }
 
     fn call(&mut self, req: Request<St>) -> Self::Future {
@@ -53,6 +53,8 @@ where
         async move {
             let service = req.subsystem();
             let procedure = req.procedure();
+            let content_type = req.content_type();
+            let compression = req.compression();
             let session = req.session();
 
             let body = req
@@ -65,7 +67,9 @@ where
                     data.copy_to_bytes(remaining)
                 });
 
-            let value = connection.call(service, procedure, body).await?;
+            let value = connection
+                .call(service, procedure, content_type, compression, body)
+                .await?;
 
             let body = Unpack::new(value);
 
@@ -13,9 +13,10 @@ use harpc_net::{
     session::client::{SessionConfig, SessionLayer},
     transport::{TransportConfig, TransportLayer},
 };
+use harpc_tower::{body::compression::Algorithm, layer::compression::ClientCompressionLayer};
 use multiaddr::Multiaddr;
 use tokio_util::sync::{CancellationToken, DropGuard};
-use tower::Layer;
+use tower::{Layer, layer::util::Stack};
 
 use self::connection::{
     Connection,
@@ -27,6 +28,9 @@ use self::connection::{
 pub struct ClientConfig {
     pub transport: TransportConfig,
     pub session: SessionConfig,
+    /// The algorithm used to compress the payload of every request made through
+    /// [`Client::connect`], if `None` payloads are sent uncompressed.
+    pub compression: Option<Algorithm>,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
@@ -51,6 +55,7 @@ pub(crate) struct TransportLayerGuard(
 pub struct Client<C> {
     session: Arc<SessionLayer>,
     codec: C,
+    compression: Option<Algorithm>,
 
     guard: TransportLayerGuard,
 }
@@ -74,6 +79,7 @@ impl<C> Client<C> {
         Ok(Self {
             session: Arc::new(session),
             codec,
+            compression: config.compression,
             guard: TransportLayerGuard(guard),
         })
     }
@@ -90,9 +96,12 @@ impl<C> Client<C> {
     where
         C: Clone + Sync,
     {
-        let connection = self
-            .connect_with_service(DefaultLayer::new(), target)
-            .await?;
+        let layer = Stack::new(
+            ClientCompressionLayer::new(self.compression),
+            DefaultLayer::new(),
+        );
+
+        let connection = self.connect_with_service(layer, target).await?;
 
         Ok(Connection::new(
             default::Default::new(connection),
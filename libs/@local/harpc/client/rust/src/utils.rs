@@ -3,8 +3,8 @@
 use core::error::Error;
 
 use error_stack::{Report, ResultExt as _, TryReportStreamExt as _};
-use futures::{StreamExt as _, stream};
-use harpc_codec::encode::Encoder;
+use futures::{Stream, StreamExt as _, stream};
+use harpc_codec::{encode::Encoder, negotiate::Negotiate};
 use harpc_net::session::server::SessionId;
 use harpc_system::{Subsystem, procedure::ProcedureIdentifier};
 use harpc_tower::{
@@ -20,6 +20,9 @@ use crate::{
 };
 
 /// Encode a request of an iterator of items.
+///
+/// The request announces the content type of the codec, so that the server decodes the request and
+/// encodes the response using the same format.
 pub async fn encode_request_iter<P, E, C>(
     codec: E,
     procedure: P,
@@ -27,9 +30,11 @@ pub async fn encode_request_iter<P, E, C>(
 ) -> Result<Request<ConnectionRequestStream<E>>, Report<[C]>>
 where
     P: ProcedureIdentifier + Send,
-    E: Encoder<Error = Report<C>, Buf: Send> + Send,
+    E: Encoder<Error = Report<C>, Buf: Send> + Negotiate + Send,
     C: Error + Send + Sync + 'static,
 {
+    let content_type = codec.content_type();
+
     let items: Vec<_> = codec
         .encode(stream::iter(items))
         .try_collect_reports()
@@ -41,6 +46,8 @@ where
             procedure: ProcedureDescriptor {
                 id: procedure.into_id(),
             },
+            content_type: Some(content_type),
+            compression: None,
             session: SessionId::CLIENT,
             extensions: Extensions::new(),
         },
@@ -94,3 +101,50 @@ where
     data.map_err(RemoteError::new)
         .change_context(RemoteInvocationError)
 }
+
+/// Invokes a call with a predetermined amount of inputs, which responds with a stream of items.
+///
+/// Every item of the response is decoded as soon as it is received, errors which occur while
+/// decoding an item, or which are returned by the remote server for an item, are returned as part
+/// of the stream.
+///
+/// # Errors
+///
+/// This function returns a `Report<RemoteInvocationError>` in the following cases:
+/// - If encoding the request fails
+/// - If the service call fails
+pub async fn invoke_call_stream<Svc, C, O>(
+    connection: Connection<Svc, C>,
+    procedure: impl ProcedureIdentifier + Send,
+    request: impl IntoIterator<Item: serde::Serialize, IntoIter: Send> + Send,
+) -> Result<
+    impl Stream<Item = Result<O, Report<RemoteInvocationError>>> + Send,
+    Report<RemoteInvocationError>,
+>
+where
+    Svc: ConnectionService<C>,
+    C: ConnectionCodec,
+    O: serde::de::DeserializeOwned,
+{
+    let (service, codec) = connection.into_parts();
+
+    let request = encode_request_iter(codec.clone(), procedure, request)
+        .await
+        .change_context(RemoteInvocationError)?;
+
+    let response = service
+        .oneshot(request)
+        .await
+        .change_context(RemoteInvocationError)?;
+
+    let (_, body) = response.into_parts();
+
+    Ok(codec
+        .decode::<Result<O, serde_value::Value>, _>(body)
+        .map(|item| {
+            item.change_context(RemoteInvocationError).and_then(|data| {
+                data.map_err(RemoteError::new)
+                    .change_context(RemoteInvocationError)
+            })
+        }))
+}
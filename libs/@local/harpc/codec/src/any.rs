@@ -0,0 +1,168 @@
+use bytes::{Buf, Bytes};
+use error_stack::{Report, ResultExt as _};
+use futures_core::{Stream, TryStream};
+use futures_util::{
+    future::Either,
+    stream::{self, StreamExt as _},
+};
+use harpc_types::content_type::ContentType;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    decode::Decoder,
+    encode::Encoder,
+    json::{JsonCodec, JsonDecoderStream, JsonError},
+    msgpack::{MessagePackCodec, MessagePackDecoderStream, MessagePackError},
+    negotiate::Negotiate,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, thiserror::Error)]
+pub enum AnyCodecError {
+    #[error("unable to encode value")]
+    Encode,
+    #[error("unable to decode value")]
+    Decode,
+}
+
+type MapEncodeError<S, C> =
+    stream::Map<S, fn(Result<Bytes, Report<C>>) -> Result<Bytes, Report<AnyCodecError>>>;
+type MapDecodeError<S, T, C> =
+    stream::Map<S, fn(Result<T, Report<C>>) -> Result<T, Report<AnyCodecError>>>;
+
+fn encode_error<C>(result: Result<Bytes, Report<C>>) -> Result<Bytes, Report<AnyCodecError>> {
+    result.change_context(AnyCodecError::Encode)
+}
+
+fn decode_error<T, C>(result: Result<T, Report<C>>) -> Result<T, Report<AnyCodecError>> {
+    result.change_context(AnyCodecError::Decode)
+}
+
+/// Codec that supports every content type implemented in this crate.
+///
+/// Used by servers to serve every client regardless of the codec it has chosen, the variant is
+/// selected per transaction through [`Negotiate`]. If a client doesn't request a content type, the
+/// default variant is used, which is JSON.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum AnyCodec {
+    #[default]
+    Json,
+    MessagePack,
+}
+
+impl Encoder for AnyCodec {
+    type Buf = Bytes;
+    type Error = Report<AnyCodecError>;
+    type Output<Input>
+        = Either<
+        MapEncodeError<<JsonCodec as Encoder>::Output<Input>, JsonError>,
+        MapEncodeError<<MessagePackCodec as Encoder>::Output<Input>, MessagePackError>,
+    >
+    where
+        Input: Stream + Send;
+
+    fn encode<T, S>(self, input: S) -> Self::Output<S>
+    where
+        T: serde::Serialize,
+        S: Stream<Item = T> + Send,
+    {
+        match self {
+            Self::Json => Either::Left(JsonCodec.encode(input).map(encode_error as fn(_) -> _)),
+            Self::MessagePack => Either::Right(
+                MessagePackCodec
+                    .encode(input)
+                    .map(encode_error as fn(_) -> _),
+            ),
+        }
+    }
+}
+
+impl Decoder for AnyCodec {
+    type Error = Report<AnyCodecError>;
+    type Output<T, Input>
+        = Either<
+        MapDecodeError<JsonDecoderStream<T, Input>, T, JsonError>,
+        MapDecodeError<MessagePackDecoderStream<T, Input>, T, MessagePackError>,
+    >
+    where
+        T: DeserializeOwned,
+        Input: TryStream<Ok: Buf> + Send;
+
+    fn decode<T, S>(self, items: S) -> Self::Output<T, S>
+    where
+        T: DeserializeOwned,
+        S: TryStream<Ok: Buf> + Send,
+    {
+        match self {
+            Self::Json => Either::Left(JsonCodec.decode(items).map(decode_error as fn(_) -> _)),
+            Self::MessagePack => Either::Right(
+                MessagePackCodec
+                    .decode(items)
+                    .map(decode_error as fn(_) -> _),
+            ),
+        }
+    }
+}
+
+impl Negotiate for AnyCodec {
+    fn content_type(&self) -> ContentType {
+        match self {
+            Self::Json => ContentType::JSON,
+            Self::MessagePack => ContentType::MESSAGE_PACK,
+        }
+    }
+
+    fn negotiate(&self, content_type: ContentType) -> Option<Self> {
+        if content_type == ContentType::JSON {
+            Some(Self::Json)
+        } else if content_type == ContentType::MESSAGE_PACK {
+            Some(Self::MessagePack)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZero;
+
+    use futures_util::{StreamExt as _, stream};
+    use harpc_types::content_type::ContentType;
+    use serde_json::{Value, json};
+
+    use crate::{
+        any::AnyCodec, decode::Decoder as _, encode::Encoder as _, negotiate::Negotiate as _,
+    };
+
+    #[test]
+    fn negotiate() {
+        assert_eq!(
+            AnyCodec::default().negotiate(ContentType::MESSAGE_PACK),
+            Some(AnyCodec::MessagePack)
+        );
+        assert_eq!(
+            AnyCodec::MessagePack.negotiate(ContentType::JSON),
+            Some(AnyCodec::Json)
+        );
+        assert_eq!(
+            AnyCodec::default().negotiate(ContentType::new(NonZero::<u8>::MAX)),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn encode_decode() {
+        let values = [json!({"key": "value"}), json!([1, 2, 3]), json!(null)];
+
+        for codec in [AnyCodec::Json, AnyCodec::MessagePack] {
+            let encoded = codec.encode(stream::iter(values.iter()));
+            let decoded: Vec<_> = codec
+                .decode::<Value, _>(encoded)
+                .map(|value| value.expect("should be Ok"))
+                .collect()
+                .await;
+
+            assert_eq!(decoded, values, "roundtrip with {codec:?}");
+        }
+    }
+}
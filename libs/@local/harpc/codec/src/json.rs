@@ -7,9 +7,10 @@ use bytes::{Buf, BufMut as _, Bytes, BytesMut};
 use error_stack::{Report, ResultExt as _};
 use futures_core::{Stream, TryStream};
 use futures_util::stream::{self, StreamExt as _};
+use harpc_types::content_type::ContentType;
 use serde::de::DeserializeOwned;
 
-use crate::{decode::Decoder, encode::Encoder};
+use crate::{decode::Decoder, encode::Encoder, negotiate::Negotiate};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, thiserror::Error)]
 pub enum JsonError {
@@ -72,6 +73,16 @@ impl Decoder for JsonCodec {
     }
 }
 
+impl Negotiate for JsonCodec {
+    fn content_type(&self) -> ContentType {
+        ContentType::JSON
+    }
+
+    fn negotiate(&self, content_type: ContentType) -> Option<Self> {
+        (content_type == ContentType::JSON).then_some(*self)
+    }
+}
+
 pin_project_lite::pin_project! {
     pub struct JsonDecoderStream<T, S> {
         #[pin]
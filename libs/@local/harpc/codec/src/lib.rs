@@ -6,14 +6,20 @@
 
 use bytes::Buf;
 use futures_core::{Stream, TryStream};
+use harpc_types::content_type::ContentType;
 
-use crate::{decode::Decoder, encode::Encoder};
+use crate::{decode::Decoder, encode::Encoder, negotiate::Negotiate};
 
+#[cfg(all(feature = "json", feature = "msgpack"))]
+pub mod any;
 pub mod decode;
 pub mod encode;
 pub mod error;
 #[cfg(feature = "json")]
 pub mod json;
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
+pub mod negotiate;
 
 pub trait Codec: Encoder + Decoder {}
 
@@ -65,3 +71,20 @@ where
         self.decoder.decode(items)
     }
 }
+
+impl<E, D> Negotiate for SplitCodec<E, D>
+where
+    E: Negotiate,
+    D: Negotiate,
+{
+    fn content_type(&self) -> ContentType {
+        self.encoder.content_type()
+    }
+
+    fn negotiate(&self, content_type: ContentType) -> Option<Self> {
+        Some(Self {
+            encoder: self.encoder.negotiate(content_type)?,
+            decoder: self.decoder.negotiate(content_type)?,
+        })
+    }
+}
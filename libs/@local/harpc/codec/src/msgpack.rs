@@ -0,0 +1,324 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use bytes::{Buf, BufMut as _, Bytes, BytesMut};
+use error_stack::{Report, ResultExt as _};
+use futures_core::{Stream, TryStream};
+use futures_util::stream::{self, StreamExt as _};
+use harpc_types::content_type::ContentType;
+use serde::de::DeserializeOwned;
+
+use crate::{decode::Decoder, encode::Encoder, negotiate::Negotiate};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, thiserror::Error)]
+pub enum MessagePackError {
+    #[error("unable to encode MessagePack value")]
+    Encode,
+    #[error("unable to decode MessagePack value")]
+    Decode,
+}
+
+/// Codec encoding every item as a MessagePack value.
+///
+/// Unlike JSON, MessagePack has no byte that is guaranteed to never appear inside of a value, so
+/// instead of a separator every value is prefixed by its length as a big-endian `u32`.
+///
+/// Structs are encoded as maps instead of arrays, which is slightly larger, but keeps the format
+/// compatible with `#[serde(flatten)]`, skipped fields and clients written in other languages.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MessagePackCodec;
+
+impl MessagePackCodec {
+    const LENGTH_PREFIX: usize = size_of::<u32>();
+}
+
+impl Encoder for MessagePackCodec {
+    type Buf = Bytes;
+    type Error = Report<MessagePackError>;
+    type Output<Input>
+        = stream::Map<Input, fn(Input::Item) -> Result<Bytes, Report<MessagePackError>>>
+    where
+        Input: Stream + Send;
+
+    fn encode<T, S>(self, input: S) -> Self::Output<S>
+    where
+        T: serde::Serialize,
+        S: Stream<Item = T> + Send,
+    {
+        input.map(|item| {
+            let value = rmp_serde::to_vec_named(&item).change_context(MessagePackError::Encode)?;
+            let length = u32::try_from(value.len()).change_context(MessagePackError::Encode)?;
+
+            let mut buf = BytesMut::with_capacity(Self::LENGTH_PREFIX + value.len());
+            buf.put_u32(length);
+            buf.put_slice(&value);
+
+            Ok(buf.freeze())
+        })
+    }
+}
+
+impl Decoder for MessagePackCodec {
+    type Error = Report<MessagePackError>;
+    type Output<T, Input>
+        = MessagePackDecoderStream<T, Input>
+    where
+        T: DeserializeOwned,
+        Input: TryStream<Ok: Buf> + Send;
+
+    fn decode<T, S>(self, items: S) -> Self::Output<T, S>
+    where
+        T: serde::de::DeserializeOwned,
+        S: TryStream<Ok: Buf> + Send,
+    {
+        MessagePackDecoderStream::new(items)
+    }
+}
+
+impl Negotiate for MessagePackCodec {
+    fn content_type(&self) -> ContentType {
+        ContentType::MESSAGE_PACK
+    }
+
+    fn negotiate(&self, content_type: ContentType) -> Option<Self> {
+        (content_type == ContentType::MESSAGE_PACK).then_some(*self)
+    }
+}
+
+pin_project_lite::pin_project! {
+    pub struct MessagePackDecoderStream<T, S> {
+        #[pin]
+        inner: Option<S>,
+        buffer: BytesMut,
+        _marker: core::marker::PhantomData<fn() -> *const T>,
+    }
+}
+
+impl<T, S> MessagePackDecoderStream<T, S> {
+    pub fn new(inner: S) -> Self {
+        Self {
+            inner: Some(inner),
+            buffer: BytesMut::new(),
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    fn poll_item(self: Pin<&mut Self>) -> Option<Result<T, Report<MessagePackError>>>
+    where
+        T: DeserializeOwned,
+    {
+        let this = self.project();
+
+        let mut prefix = this.buffer.get(..MessagePackCodec::LENGTH_PREFIX)?;
+        let length = prefix.get_u32() as usize;
+
+        if this.buffer.len() < MessagePackCodec::LENGTH_PREFIX + length {
+            return None;
+        }
+
+        this.buffer.advance(MessagePackCodec::LENGTH_PREFIX);
+        let message = this.buffer.split_to(length);
+
+        Some(rmp_serde::from_slice(&message).change_context(MessagePackError::Decode))
+    }
+}
+
+impl<T, S> Stream for MessagePackDecoderStream<T, S>
+where
+    S: TryStream<Ok: Buf>,
+    T: DeserializeOwned,
+{
+    type Item = Result<T, Report<MessagePackError>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            // multiple messages could be in the buffer at the same time, so we always check the
+            // buffer before polling the inner stream.
+            if let Some(value) = Self::poll_item(self.as_mut()) {
+                return Poll::Ready(Some(value));
+            }
+
+            let mut this = self.as_mut().project();
+            // We use an option here to avoid repeated polling of the inner stream once it has
+            // returned `None`. A partial message that remains in the buffer is discarded, same as
+            // in the JSON codec.
+            let Some(inner) = this.inner.as_mut().as_pin_mut() else {
+                return Poll::Ready(None);
+            };
+
+            match ready!(inner.try_poll_next(cx)) {
+                Some(Ok(buf)) => this.buffer.put(buf),
+                // Same as in the JSON codec, we're unable to retain the underlying error, as we
+                // don't know if it is a report or a plain error.
+                Some(Err(_error)) => {
+                    return Poll::Ready(Some(Err(Report::new(MessagePackError::Decode))));
+                }
+                None => this.inner.set(None),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::future::ready;
+    use std::io;
+
+    use bytes::Bytes;
+    use futures_util::{StreamExt as _, stream};
+
+    use crate::{decode::Decoder as _, encode::Encoder as _, msgpack::MessagePackCodec};
+
+    #[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    struct Record {
+        key: String,
+    }
+
+    fn record(key: &str) -> Record {
+        Record {
+            key: key.to_owned(),
+        }
+    }
+
+    // `{"key": "value1"}` prefixed by its length
+    const RECORD_VALUE1: &[u8] = b"\x00\x00\x00\x0C\x81\xA3key\xA6value1";
+    const RECORD_VALUE2: &[u8] = b"\x00\x00\x00\x0C\x81\xA3key\xA6value2";
+
+    #[tokio::test]
+    async fn encode_single_value() {
+        let input = stream::once(ready(record("value1")));
+        let mut encoder = MessagePackCodec.encode(input);
+
+        let encoded = encoder
+            .next()
+            .await
+            .expect("should have a value")
+            .expect("should be Ok");
+        assert_eq!(encoded, Bytes::from_static(RECORD_VALUE1));
+
+        assert!(encoder.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn decode_multiple_records_in_single_chunk() {
+        let input = stream::once(ready(Result::<_, io::Error>::Ok(Bytes::from(
+            [RECORD_VALUE1, RECORD_VALUE2].concat(),
+        ))));
+        let mut decoder = MessagePackCodec.decode::<Record, _>(input);
+
+        assert_eq!(
+            decoder
+                .next()
+                .await
+                .expect("should have a value")
+                .expect("should be Ok"),
+            record("value1")
+        );
+        assert_eq!(
+            decoder
+                .next()
+                .await
+                .expect("should have a value")
+                .expect("should be Ok"),
+            record("value2")
+        );
+        assert!(decoder.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn decode_record_split_across_chunks() {
+        // split inside of the length prefix as well as inside of the value
+        let input = stream::iter([
+            Result::<_, io::Error>::Ok(Bytes::from_static(&RECORD_VALUE1[..2])),
+            Ok(Bytes::from_static(&RECORD_VALUE1[2..9])),
+            Ok(Bytes::from_static(&RECORD_VALUE1[9..])),
+        ]);
+        let mut decoder = MessagePackCodec.decode::<Record, _>(input);
+
+        assert_eq!(
+            decoder
+                .next()
+                .await
+                .expect("should have a value")
+                .expect("should be Ok"),
+            record("value1")
+        );
+        assert!(decoder.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn decode_stream_ends_with_partial_record() {
+        let input = stream::once(ready(Result::<_, io::Error>::Ok(Bytes::from(
+            [RECORD_VALUE1, &RECORD_VALUE2[..6]].concat(),
+        ))));
+        let mut decoder = MessagePackCodec.decode::<Record, _>(input);
+
+        assert_eq!(
+            decoder
+                .next()
+                .await
+                .expect("should have a value")
+                .expect("should be Ok"),
+            record("value1")
+        );
+        assert!(decoder.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn decode_error_in_underlying_stream() {
+        let input = stream::iter([
+            Ok(Bytes::from_static(RECORD_VALUE1)),
+            Err(io::Error::other("o no!")),
+        ]);
+        let mut decoder = MessagePackCodec.decode::<Record, _>(input);
+
+        assert_eq!(
+            decoder
+                .next()
+                .await
+                .expect("should have a value")
+                .expect("should be Ok"),
+            record("value1")
+        );
+
+        let error = decoder
+            .next()
+            .await
+            .expect("should have a value")
+            .expect_err("should be an error");
+        assert_eq!(error.to_string(), "unable to decode MessagePack value");
+
+        assert!(decoder.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn decode_invalid_value() {
+        let input = stream::once(ready(Result::<_, io::Error>::Ok(Bytes::from_static(
+            b"\x00\x00\x00\x01\xC1",
+        ))));
+        let mut decoder = MessagePackCodec.decode::<Record, _>(input);
+
+        let _report = decoder
+            .next()
+            .await
+            .expect("should have a value")
+            .expect_err("should be an error");
+        assert!(decoder.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn encode_decode() {
+        let records = [record("value1"), record("value2"), record("")];
+
+        let encoded = MessagePackCodec.encode(stream::iter(records.iter()));
+        let decoded: Vec<_> = MessagePackCodec
+            .decode::<Record, _>(encoded)
+            .map(|record| record.expect("should be Ok"))
+            .collect()
+            .await;
+
+        assert_eq!(decoded, records);
+    }
+}
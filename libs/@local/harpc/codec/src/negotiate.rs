@@ -0,0 +1,19 @@
+use harpc_types::content_type::ContentType;
+
+/// A codec that knows the content type it produces and is able to switch to another one.
+///
+/// The client announces the [`content_type`] of its codec at the beginning of every transaction,
+/// the server then uses [`negotiate`] to select the codec for the transaction. If the client does
+/// not announce a content type, the server uses its codec as-is.
+///
+/// [`content_type`]: Negotiate::content_type
+/// [`negotiate`]: Negotiate::negotiate
+pub trait Negotiate: Sized {
+    /// The content type of the payloads produced and consumed by the codec.
+    fn content_type(&self) -> ContentType;
+
+    /// Returns the codec to use for the given content type.
+    ///
+    /// Returns `None` if the content type is not supported.
+    fn negotiate(&self, content_type: ContentType) -> Option<Self>;
+}
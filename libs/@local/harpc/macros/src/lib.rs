@@ -0,0 +1,91 @@
+//! Procedural macros for defining harpc subsystems.
+//!
+//! The macros are re-exported by `harpc-system`, which is the crate that should be depended on.
+
+extern crate alloc;
+
+mod service;
+
+use syn::{ItemTrait, parse_macro_input};
+
+use self::service::ServiceArgs;
+
+/// Generates the harpc plumbing for a subsystem trait.
+///
+/// The trait has to declare an associated `type ExecutionScope;` and one `async fn` per procedure.
+/// Every procedure takes `&self` and the execution scope as the first two parameters, followed by
+/// the procedure arguments, and returns `Result<T, Report<E>>`, where `E` is a unit struct.
+///
+/// Next to the trait (which is emitted unchanged) the macro generates:
+///
+/// - a `meta` module containing the subsystem marker type, which has the same name as the trait,
+///   the `{Name}ProcedureId` enum and a `Procedure{Procedure}` marker type per procedure,
+/// - the `Subsystem`, `ProcedureIdentifier` and `Procedure` implementations for them,
+/// - a `{Name}Delegate<T>`, which implements `SubsystemDelegate` for any implementation of the
+///   trait and is registered with the server router, and
+/// - a `{Name}Client<S, C>`, which implements the trait by calling the procedures over a
+///   `Connection`, unless disabled with `client = false`.
+///
+/// `{Name}` is the name of the trait without a trailing `System`. As the module is always called
+/// `meta`, at most one service can be declared per module.
+///
+/// # Arguments
+///
+/// - `subsystem` (required): The variant of the `SubsystemIdentifier` enum used for this subsystem.
+/// - `version` (required): The current version of the subsystem as `"major.minor"`.
+/// - `client`: Whether to generate the client, defaults to `true`. The client requires every
+///   procedure output to implement `DeserializeOwned`, which is not the case for services that only
+///   expose serialize-only types to non-Rust clients.
+///
+/// Every procedure has to be annotated with `#[procedure(...)]`:
+///
+/// - `id` (required): The procedure id. Ids are part of the wire format, so they are always spelled
+///   out and must not be changed once a procedure has been released.
+/// - `since`: The version of the subsystem in which the procedure was introduced as
+///   `"major.minor"`. Defaults to the initial version of the subsystem.
+/// - `stream`: The procedure responds with a stream of items instead of a single value. Streaming
+///   procedures return `Result<BoxStream<'static, Result<T, Report<E>>>, Report<E>>`, every item is
+///   sent to the client as soon as it is produced.
+///
+/// The generated code refers to `harpc-client`, `harpc-codec`, `harpc-server`, `harpc-system`,
+/// `harpc-tower`, `harpc-types` and `error-stack`, which therefore need to be dependencies of the
+/// crate using the macro. Clients of streaming procedures additionally refer to `futures`.
+///
+/// # Example
+///
+/// ```ignore
+/// #[harpc_system::service(subsystem = GraphSubsystemId::Echo, version = "0.1")]
+/// pub trait EchoSystem {
+///     type ExecutionScope;
+///
+///     #[procedure(id = 0x00)]
+///     async fn echo(
+///         &self,
+///         scope: Self::ExecutionScope,
+///         payload: Box<str>,
+///     ) -> Result<Box<str>, Report<EchoError>>;
+///
+///     #[procedure(id = 0x10, since = "0.1")]
+///     async fn echo_twice(
+///         &self,
+///         scope: Self::ExecutionScope,
+///         first: Box<str>,
+///         second: Box<str>,
+///     ) -> Result<Box<str>, Report<EchoError>>;
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn service(
+    args: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let mut service_args = ServiceArgs::default();
+    let parser = syn::meta::parser(|meta| service_args.parse(&meta));
+    parse_macro_input!(args with parser);
+
+    let item = parse_macro_input!(item as ItemTrait);
+
+    service::expand(service_args, item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
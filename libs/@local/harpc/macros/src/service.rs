@@ -0,0 +1,716 @@
+use alloc::collections::{BTreeMap, btree_map::Entry};
+use core::iter;
+
+use convert_case::{Case, Casing as _};
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    FnArg, GenericArgument, Ident, ItemTrait, LitBool, LitInt, LitStr, Pat, Path, PathArguments,
+    ReturnType, TraitItem, TraitItemFn, Type, Visibility, meta::ParseNestedMeta,
+    punctuated::Punctuated, spanned::Spanned as _,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Version {
+    major: u8,
+    minor: u8,
+}
+
+impl Version {
+    fn parse(literal: &LitStr) -> syn::Result<Self> {
+        let value = literal.value();
+        let (major, minor) = value.split_once('.').ok_or_else(|| {
+            syn::Error::new(
+                literal.span(),
+                "expected a version in the format `major.minor`",
+            )
+        })?;
+
+        let parse_component = |component: &str| {
+            component.parse::<u8>().map_err(|error| {
+                syn::Error::new(
+                    literal.span(),
+                    format!("invalid version component `{component}`: {error}"),
+                )
+            })
+        };
+
+        Ok(Self {
+            major: parse_component(major)?,
+            minor: parse_component(minor)?,
+        })
+    }
+
+    fn expand(self) -> TokenStream {
+        let major = Literal::u8_unsuffixed(self.major);
+        let minor = Literal::u8_unsuffixed(self.minor);
+
+        quote! {
+            ::harpc_types::version::Version {
+                major: #major,
+                minor: #minor,
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct ServiceArgs {
+    subsystem: Option<Path>,
+    version: Option<Version>,
+    client: Option<bool>,
+}
+
+impl ServiceArgs {
+    pub(crate) fn parse(&mut self, meta: &ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("subsystem") {
+            self.subsystem = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("version") {
+            self.version = Some(Version::parse(&meta.value()?.parse()?)?);
+        } else if meta.path.is_ident("client") {
+            self.client = Some(meta.value()?.parse::<LitBool>()?.value);
+        } else {
+            return Err(meta.error(
+                "unsupported service argument, expected `subsystem`, `version` or `client`",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+struct Procedure {
+    id: u16,
+    since: Option<Version>,
+    stream: bool,
+    method: Ident,
+    variant: Ident,
+    marker: Ident,
+    scope: Ident,
+    arguments: Vec<(Ident, Type)>,
+    output: Type,
+    error: Type,
+}
+
+/// Extracts `E` from a return type of the form `Result<T, Report<E>>`.
+fn error_type(output: &Type) -> Option<&Type> {
+    fn generic_arguments<'t>(ty: &'t Type, name: &str) -> Option<Vec<&'t Type>> {
+        let Type::Path(path) = ty else {
+            return None;
+        };
+        let segment = path.path.segments.last()?;
+        if segment.ident != name {
+            return None;
+        }
+        let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+            return None;
+        };
+
+        Some(
+            arguments
+                .args
+                .iter()
+                .filter_map(|argument| match argument {
+                    GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                })
+                .collect(),
+        )
+    }
+
+    let &[_, report] = generic_arguments(output, "Result")?.as_slice() else {
+        return None;
+    };
+    let &[error] = generic_arguments(report, "Report")?.as_slice() else {
+        return None;
+    };
+
+    Some(error)
+}
+
+struct ProcedureAttributes {
+    id: Option<LitInt>,
+    since: Option<LitStr>,
+    stream: bool,
+}
+
+/// Parses the `#[procedure(...)]` attributes and removes them from the trait item.
+fn parse_procedure_attributes(item: &mut TraitItemFn) -> syn::Result<ProcedureAttributes> {
+    let mut id = None;
+    let mut since = None;
+    let mut stream = false;
+
+    let mut result = Ok(());
+    item.attrs.retain(|attribute| {
+        if !attribute.path().is_ident("procedure") {
+            return true;
+        }
+
+        if let Err(error) = attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("id") {
+                id = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("since") {
+                since = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("stream") {
+                stream = true;
+            } else {
+                return Err(meta
+                    .error("unsupported procedure argument, expected `id`, `since` or `stream`"));
+            }
+
+            Ok(())
+        }) {
+            result = Err(error);
+        }
+
+        false
+    });
+
+    result.map(|()| ProcedureAttributes { id, since, stream })
+}
+
+fn parse_procedure(item: &mut TraitItemFn, version: Version) -> syn::Result<Procedure> {
+    let ProcedureAttributes { id, since, stream } = parse_procedure_attributes(item)?;
+
+    let id = id
+        .ok_or_else(|| {
+            syn::Error::new(
+                item.sig.ident.span(),
+                "missing procedure id, specify it with `#[procedure(id = ...)]`",
+            )
+        })?
+        .base10_parse::<u16>()?;
+    if id & 0xF000 == 0xF000 {
+        return Err(syn::Error::new(
+            item.sig.ident.span(),
+            format!(
+                "procedure id {id:#06x} is reserved, ids `0xF000` and above are used internally"
+            ),
+        ));
+    }
+
+    let since = since
+        .map(|literal| {
+            let since = Version::parse(&literal)?;
+            if since > version {
+                return Err(syn::Error::new(
+                    literal.span(),
+                    "procedure cannot be introduced after the current version of the subsystem",
+                ));
+            }
+
+            Ok(since)
+        })
+        .transpose()?;
+
+    let signature = &item.sig;
+    if signature.asyncness.is_none() {
+        return Err(syn::Error::new(
+            signature.fn_token.span,
+            "procedures have to be `async`",
+        ));
+    }
+    if !signature.generics.params.is_empty() || signature.generics.where_clause.is_some() {
+        return Err(syn::Error::new(
+            signature.generics.span(),
+            "procedures cannot be generic",
+        ));
+    }
+
+    let mut inputs = signature.inputs.iter();
+    match inputs.next() {
+        Some(FnArg::Receiver(receiver))
+            if receiver.reference.is_some() && receiver.mutability.is_none() => {}
+        _ => {
+            return Err(syn::Error::new(
+                signature.ident.span(),
+                "procedures have to take `&self` as the first parameter",
+            ));
+        }
+    }
+
+    let scope = match inputs.next() {
+        Some(FnArg::Typed(scope)) => match &*scope.pat {
+            Pat::Ident(pattern) => pattern.ident.clone(),
+            _ => format_ident!("scope"),
+        },
+        _ => {
+            return Err(syn::Error::new(
+                signature.ident.span(),
+                "procedures have to take the execution scope as the second parameter",
+            ));
+        }
+    };
+
+    let arguments = inputs
+        .enumerate()
+        .map(|(index, argument)| match argument {
+            FnArg::Typed(argument) => {
+                let ident = match &*argument.pat {
+                    Pat::Ident(pattern) => pattern.ident.clone(),
+                    _ => format_ident!("argument_{index}"),
+                };
+
+                Ok((ident, (*argument.ty).clone()))
+            }
+            FnArg::Receiver(receiver) => {
+                Err(syn::Error::new(receiver.span(), "unexpected receiver"))
+            }
+        })
+        .collect::<syn::Result<_>>()?;
+
+    let ReturnType::Type(_, output) = &signature.output else {
+        return Err(syn::Error::new(
+            signature.ident.span(),
+            "procedures have to return `Result<T, Report<E>>`",
+        ));
+    };
+    let error = error_type(output).cloned().ok_or_else(|| {
+        syn::Error::new(
+            output.span(),
+            "procedures have to return `Result<T, Report<E>>`",
+        )
+    })?;
+
+    let variant = format_ident!("{}", signature.ident.to_string().to_case(Case::Pascal));
+
+    Ok(Procedure {
+        id,
+        since,
+        stream,
+        method: signature.ident.clone(),
+        marker: format_ident!("Procedure{variant}"),
+        variant,
+        scope,
+        arguments,
+        output: (**output).clone(),
+        error,
+    })
+}
+
+/// Returns the visibility of the items inside of the `meta` module.
+///
+/// The module itself uses the visibility of the trait, so its items need to be visible one level
+/// further up.
+fn meta_item_visibility(visibility: &Visibility) -> TokenStream {
+    match visibility {
+        Visibility::Public(_) => quote!(pub),
+        Visibility::Inherited => quote!(pub(super)),
+        Visibility::Restricted(_) => quote!(pub(crate)),
+    }
+}
+
+/// Maps the response body of the procedure at `index` into a chain of `Either`s, so that every
+/// procedure returns the same body type.
+fn either_chain(index: usize, count: usize) -> TokenStream {
+    if count == 1 {
+        return TokenStream::new();
+    }
+
+    let left = (index + 1 < count).then(|| quote!(.map_body(::harpc_tower::either::Either::Left)));
+    let rights = iter::repeat_n(
+        quote!(.map_body(::harpc_tower::either::Either::Right)),
+        index,
+    );
+
+    quote!(.map(|response| response #left #(#rights)*))
+}
+
+#[expect(clippy::too_many_lines)]
+pub(crate) fn expand(args: ServiceArgs, mut item: ItemTrait) -> syn::Result<TokenStream> {
+    let subsystem = args
+        .subsystem
+        .ok_or_else(|| syn::Error::new(Span::call_site(), "missing `subsystem` argument"))?;
+    let version = args
+        .version
+        .ok_or_else(|| syn::Error::new(Span::call_site(), "missing `version` argument"))?;
+
+    if subsystem.segments.len() < 2 {
+        return Err(syn::Error::new(
+            subsystem.span(),
+            "expected the variant of a subsystem identifier, e.g. `SubsystemId::Variant`",
+        ));
+    }
+    let subsystem_type = Path {
+        leading_colon: subsystem.leading_colon,
+        segments: subsystem
+            .segments
+            .iter()
+            .take(subsystem.segments.len() - 1)
+            .cloned()
+            .collect::<Punctuated<_, _>>(),
+    };
+
+    if !item.generics.params.is_empty() || item.generics.where_clause.is_some() {
+        return Err(syn::Error::new(
+            item.generics.span(),
+            "service traits cannot be generic",
+        ));
+    }
+
+    let mut has_execution_scope = false;
+    let mut procedures = Vec::<Procedure>::new();
+    let mut used_ids = BTreeMap::new();
+    for trait_item in &mut item.items {
+        match trait_item {
+            TraitItem::Type(ty) if ty.ident == "ExecutionScope" => {
+                has_execution_scope = true;
+            }
+            TraitItem::Fn(function) => {
+                let procedure = parse_procedure(function, version)?;
+
+                match used_ids.entry(procedure.id) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(procedure.method.clone());
+                    }
+                    Entry::Occupied(entry) => {
+                        return Err(syn::Error::new(
+                            procedure.method.span(),
+                            format!(
+                                "procedure id {:#06x} is already used by `{}`",
+                                procedure.id,
+                                entry.get()
+                            ),
+                        ));
+                    }
+                }
+
+                procedures.push(procedure);
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    trait_item.span(),
+                    "service traits may only contain `type ExecutionScope;` and procedures",
+                ));
+            }
+        }
+    }
+
+    if !has_execution_scope {
+        return Err(syn::Error::new(
+            item.ident.span(),
+            "service traits have to declare `type ExecutionScope;`",
+        ));
+    }
+    if procedures.is_empty() {
+        return Err(syn::Error::new(
+            item.ident.span(),
+            "service traits have to declare at least one procedure",
+        ));
+    }
+
+    let visibility = &item.vis;
+    let meta_visibility = meta_item_visibility(visibility);
+    let trait_ident = &item.ident;
+    let trait_name = trait_ident.to_string();
+    let name = trait_name
+        .strip_suffix("System")
+        .filter(|name| !name.is_empty())
+        .unwrap_or(&trait_name);
+
+    let procedure_id = format_ident!("{name}ProcedureId");
+    let delegate = format_ident!("{name}Delegate");
+    let client = format_ident!("{name}Client");
+
+    let meta_doc = format!("Metadata of the `{trait_name}` subsystem.");
+    let variants = procedures
+        .iter()
+        .map(|procedure| &procedure.variant)
+        .collect::<Vec<_>>();
+    let markers = procedures
+        .iter()
+        .map(|procedure| &procedure.marker)
+        .collect::<Vec<_>>();
+    let methods = procedures
+        .iter()
+        .map(|procedure| &procedure.method)
+        .collect::<Vec<_>>();
+    let ids = procedures
+        .iter()
+        .map(|procedure| Literal::u16_unsuffixed(procedure.id))
+        .collect::<Vec<_>>();
+
+    let procedure_list = markers.iter().rev().fold(
+        quote!(::harpc_system::__private::HNil),
+        |tail, marker| quote!(::harpc_system::__private::HCons<meta::#marker, #tail>),
+    );
+    let version = version.expand();
+
+    let procedure_impls = procedures.iter().map(|procedure| {
+        let Procedure {
+            marker, variant, ..
+        } = procedure;
+        let since = procedure.since.map(|since| {
+            let since = since.expand();
+            quote! {
+                fn since() -> ::harpc_types::version::Version {
+                    #since
+                }
+            }
+        });
+
+        quote! {
+            impl ::harpc_system::procedure::Procedure for meta::#marker {
+                type Subsystem = meta::#trait_ident;
+
+                const ID: <Self::Subsystem as ::harpc_system::Subsystem>::ProcedureId =
+                    meta::#procedure_id::#variant;
+
+                #since
+            }
+        }
+    });
+
+    let delegate_arms = procedures.iter().enumerate().map(|(index, procedure)| {
+        let Procedure {
+            method, variant, ..
+        } = procedure;
+        let arguments = (0..procedure.arguments.len())
+            .map(|index| format_ident!("argument_{index}"))
+            .collect::<Vec<_>>();
+        let pattern = match arguments.as_slice() {
+            [argument] => quote!(#argument),
+            arguments => quote!((#(#arguments),*)),
+        };
+        let either = either_chain(index, procedures.len());
+        let delegate_call = if procedure.stream {
+            quote!(delegate_call_stream)
+        } else {
+            quote!(delegate_call_discrete)
+        };
+
+        quote! {
+            meta::#procedure_id::#variant => {
+                ::harpc_server::utils::#delegate_call(request, codec, |#pattern| async move {
+                    self.inner.#method(scope, #(#arguments),*).await
+                })
+                .await
+                #either
+            }
+        }
+    });
+
+    let client_methods = procedures.iter().map(|procedure| {
+        let Procedure {
+            method,
+            variant,
+            scope,
+            output,
+            error,
+            ..
+        } = procedure;
+        let (arguments, types): (Vec<_>, Vec<_>) = procedure
+            .arguments
+            .iter()
+            .map(|(argument, ty)| (argument, ty))
+            .unzip();
+        let payload = match arguments.as_slice() {
+            [argument] => quote!(#argument),
+            arguments => quote!((#(#arguments),*)),
+        };
+
+        let body = if procedure.stream {
+            quote! {
+                let items = ::error_stack::ResultExt::change_context(
+                    ::harpc_client::utils::invoke_call_stream(
+                        #scope,
+                        meta::#procedure_id::#variant,
+                        [#payload],
+                    )
+                    .await,
+                    #error,
+                )?;
+
+                Ok(::futures::StreamExt::boxed(::futures::StreamExt::map(
+                    items,
+                    |item| ::error_stack::ResultExt::change_context(item, #error),
+                )))
+            }
+        } else {
+            quote! {
+                ::error_stack::ResultExt::change_context(
+                    ::harpc_client::utils::invoke_call_discrete(
+                        #scope,
+                        meta::#procedure_id::#variant,
+                        [#payload],
+                    )
+                    .await,
+                    #error,
+                )
+            }
+        };
+
+        quote! {
+            async fn #method(
+                &self,
+                #scope: ::harpc_client::connection::Connection<S, C>,
+                #(#arguments: #types),*
+            ) -> #output {
+                #body
+            }
+        }
+    });
+
+    // Streams returned by the client own the connection, so the connection cannot borrow anything.
+    let static_bound = procedures
+        .iter()
+        .any(|procedure| procedure.stream)
+        .then(|| quote!(+ 'static));
+
+    let client_impl = args.client.unwrap_or(true).then(|| {
+        quote! {
+            #visibility struct #client<S, C> {
+                _service: ::core::marker::PhantomData<fn() -> *const S>,
+                _codec: ::core::marker::PhantomData<fn() -> *const C>,
+            }
+
+            impl<S, C> #client<S, C> {
+                #[must_use]
+                #visibility const fn new() -> Self {
+                    Self {
+                        _service: ::core::marker::PhantomData,
+                        _codec: ::core::marker::PhantomData,
+                    }
+                }
+            }
+
+            impl<S, C> ::core::fmt::Debug for #client<S, C> {
+                fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    fmt.debug_struct(stringify!(#client)).finish_non_exhaustive()
+                }
+            }
+
+            impl<S, C> Clone for #client<S, C> {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl<S, C> Copy for #client<S, C> {}
+
+            impl<S, C> PartialEq for #client<S, C> {
+                fn eq(&self, _: &Self) -> bool {
+                    true
+                }
+            }
+
+            impl<S, C> Eq for #client<S, C> {}
+
+            impl<S, C> ::core::hash::Hash for #client<S, C> {
+                fn hash<H: ::core::hash::Hasher>(&self, _: &mut H) {}
+            }
+
+            impl<S, C> Default for #client<S, C> {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
+            impl<S, C> #trait_ident for #client<S, C>
+            where
+                S: ::harpc_client::connection::ConnectionService<C> #static_bound,
+                C: ::harpc_client::connection::ConnectionCodec #static_bound,
+            {
+                type ExecutionScope = ::harpc_client::connection::Connection<S, C>;
+
+                #(#client_methods)*
+            }
+        }
+    });
+
+    Ok(quote! {
+        #item
+
+        #visibility mod meta {
+            #![doc = #meta_doc]
+
+            #meta_visibility enum #procedure_id {
+                #(#variants,)*
+            }
+
+            #meta_visibility struct #trait_ident;
+
+            #(#meta_visibility struct #markers;)*
+        }
+
+        impl ::harpc_system::procedure::ProcedureIdentifier for meta::#procedure_id {
+            type Subsystem = meta::#trait_ident;
+
+            fn from_id(id: ::harpc_types::procedure::ProcedureId) -> Option<Self> {
+                match id.value() {
+                    #(#ids => Some(Self::#variants),)*
+                    _ => None,
+                }
+            }
+
+            fn into_id(self) -> ::harpc_types::procedure::ProcedureId {
+                match self {
+                    #(Self::#variants => ::harpc_types::procedure::ProcedureId::new(#ids),)*
+                }
+            }
+        }
+
+        impl ::harpc_system::Subsystem for meta::#trait_ident {
+            type ProcedureId = meta::#procedure_id;
+            type Procedures = #procedure_list;
+            type SubsystemId = #subsystem_type;
+
+            const ID: #subsystem_type = #subsystem;
+            const VERSION: ::harpc_types::version::Version = #version;
+        }
+
+        #(#procedure_impls)*
+
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        #visibility struct #delegate<T> {
+            inner: T,
+        }
+
+        impl<T> #delegate<T> {
+            #[must_use]
+            #visibility const fn new(inner: T) -> Self {
+                Self { inner }
+            }
+        }
+
+        impl<T, C> ::harpc_system::delegate::SubsystemDelegate<C> for #delegate<T>
+        where
+            T: #trait_ident<#(#methods(..): Send,)* ExecutionScope: Send> + Send,
+            C: ::harpc_codec::encode::Encoder
+                + ::harpc_codec::decode::ReportDecoder
+                + ::harpc_codec::negotiate::Negotiate
+                + Clone
+                + Send,
+        {
+            type Error = ::error_stack::Report<::harpc_server::error::DelegationError>;
+            type ExecutionScope = T::ExecutionScope;
+            type Subsystem = meta::#trait_ident;
+
+            type Body<Source>
+                = impl ::harpc_tower::body::Body<
+                    Control: AsRef<::harpc_types::response_kind::ResponseKind>,
+                    Error = <C as ::harpc_codec::encode::Encoder>::Error,
+                >
+            where
+                Source: ::harpc_tower::body::Body<Control = !, Error: Send + Sync> + Send;
+
+            async fn call<B>(
+                self,
+                request: ::harpc_tower::request::Request<B>,
+                scope: T::ExecutionScope,
+                codec: C,
+            ) -> Result<::harpc_tower::response::Response<Self::Body<B>>, Self::Error>
+            where
+                B: ::harpc_tower::body::Body<Control = !, Error: Send + Sync> + Send,
+            {
+                let id = ::harpc_server::utils::parse_procedure_id(&request)?;
+
+                match id {
+                    #(#delegate_arms)*
+                }
+            }
+        }
+
+        #client_impl
+    })
+}
@@ -0,0 +1,125 @@
+#![feature(impl_trait_in_assoc_type, never_type, return_type_notation)]
+
+use core::{error::Error, fmt};
+
+use error_stack::Report;
+use futures::stream::BoxStream;
+use harpc_system::{
+    Subsystem as _, SubsystemIdentifier,
+    procedure::{Procedure as _, ProcedureIdentifier as _},
+};
+use harpc_types::{procedure::ProcedureId, subsystem::SubsystemId, version::Version};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum System {
+    Greeting,
+}
+
+impl SubsystemIdentifier for System {
+    fn from_id(id: SubsystemId) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match id.value() {
+            0x00 => Some(Self::Greeting),
+            _ => None,
+        }
+    }
+
+    fn into_id(self) -> SubsystemId {
+        match self {
+            Self::Greeting => SubsystemId::new(0x00),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GreetingError;
+
+impl fmt::Display for GreetingError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("unable to greet")
+    }
+}
+
+impl Error for GreetingError {}
+
+#[harpc_system::service(subsystem = System::Greeting, version = "1.2")]
+pub trait GreetingSystem {
+    type ExecutionScope;
+
+    #[procedure(id = 0x00)]
+    async fn greet(
+        &self,
+        scope: Self::ExecutionScope,
+        name: Box<str>,
+    ) -> Result<Box<str>, Report<GreetingError>>;
+
+    #[procedure(id = 0x10, since = "1.1")]
+    async fn greet_both(
+        &self,
+        scope: Self::ExecutionScope,
+        first: Box<str>,
+        second: Box<str>,
+    ) -> Result<Box<str>, Report<GreetingError>>;
+
+    #[procedure(id = 0x03, stream)]
+    async fn greet_all(
+        &self,
+        scope: Self::ExecutionScope,
+        names: Vec<Box<str>>,
+    ) -> Result<BoxStream<'static, Result<Box<str>, Report<GreetingError>>>, Report<GreetingError>>;
+}
+
+#[test]
+fn subsystem() {
+    assert_eq!(meta::GreetingSystem::ID, System::Greeting);
+    assert_eq!(meta::GreetingSystem::VERSION, Version {
+        major: 1,
+        minor: 2
+    });
+}
+
+#[test]
+fn procedure_ids() {
+    let ids = [
+        (meta::GreetingProcedureId::Greet, 0x00),
+        (meta::GreetingProcedureId::GreetBoth, 0x10),
+        (meta::GreetingProcedureId::GreetAll, 0x03),
+    ];
+
+    for (procedure, id) in ids {
+        assert_eq!(procedure.into_id(), ProcedureId::new(id));
+        assert_eq!(
+            meta::GreetingProcedureId::from_id(ProcedureId::new(id)).map(|id| id.into_id()),
+            Some(ProcedureId::new(id))
+        );
+    }
+
+    assert!(meta::GreetingProcedureId::from_id(ProcedureId::new(0x01)).is_none());
+}
+
+#[test]
+fn procedures() {
+    assert_eq!(
+        meta::ProcedureGreet::descriptor().id,
+        ProcedureId::new(0x00)
+    );
+    assert_eq!(
+        meta::ProcedureGreetBoth::descriptor().id,
+        ProcedureId::new(0x10)
+    );
+    assert_eq!(
+        meta::ProcedureGreetAll::descriptor().id,
+        ProcedureId::new(0x03)
+    );
+
+    assert_eq!(
+        meta::ProcedureGreet::since(),
+        meta::GreetingSystem::initial_version()
+    );
+    assert_eq!(meta::ProcedureGreetBoth::since(), Version {
+        major: 1,
+        minor: 1
+    });
+}
@@ -0,0 +1,12 @@
+#[harpc_macros::service(subsystem = System::Echo, version = "0.0")]
+pub trait EchoSystem {
+    type ExecutionScope;
+
+    #[procedure(id = 0x00)]
+    async fn echo(&self, scope: Self::ExecutionScope) -> Result<(), Report<EchoError>>;
+
+    #[procedure(id = 0x00)]
+    async fn echo_twice(&self, scope: Self::ExecutionScope) -> Result<(), Report<EchoError>>;
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[harpc_macros::service(subsystem = System::Echo, version = "1")]
+pub trait EchoSystem {
+    type ExecutionScope;
+
+    #[procedure(id = 0x00)]
+    async fn echo(&self, scope: Self::ExecutionScope) -> Result<(), Report<EchoError>>;
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[harpc_macros::service(subsystem = System::Echo, version = "0.0")]
+pub trait EchoSystem {
+    #[procedure(id = 0x00)]
+    async fn echo(&self, scope: Self::ExecutionScope) -> Result<(), Report<EchoError>>;
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[harpc_macros::service(subsystem = System::Echo, version = "0.0")]
+pub trait EchoSystem {
+    type ExecutionScope;
+
+    async fn echo(&self, scope: Self::ExecutionScope) -> Result<(), Report<EchoError>>;
+}
+
+fn main() {}
@@ -8,7 +8,10 @@ use alloc::sync::Arc;
 use bytes::Bytes;
 use error_stack::Report;
 use futures::{Sink, Stream, StreamExt as _, prelude::future::FutureExt as _};
-use harpc_types::{procedure::ProcedureDescriptor, subsystem::SubsystemDescriptor};
+use harpc_types::{
+    compression::Compression, content_type::ContentType, procedure::ProcedureDescriptor,
+    subsystem::SubsystemDescriptor,
+};
 use harpc_wire_protocol::{request::Request, response::Response};
 use scc::ebr::Guard;
 use tachyonix::SendTimeoutError;
@@ -329,6 +332,15 @@ impl Connection {
 
     /// Call a service procedure
     ///
+    /// The `content_type` is transmitted to the server, which uses it to select the codec of the
+    /// transaction. If `None`, the server will use its default codec.
+    ///
+    /// The `compression` is transmitted to the server as well, the payload must already be
+    /// compressed using it. Successful responses are compressed by the server using the same
+    /// compression, which is indicated through [`ValueStream::is_compressed`].
+    ///
+    /// [`ValueStream::is_compressed`]: crate::session::client::ValueStream::is_compressed
+    ///
     /// # Errors
     ///
     /// This will return an error if the connection is unhealthy, meaning that the underlying
@@ -337,6 +349,8 @@ impl Connection {
         &self,
         subsystem: SubsystemDescriptor,
         procedure: ProcedureDescriptor,
+        content_type: Option<ContentType>,
+        compression: Option<Compression>,
         payload: impl Stream<Item = Bytes> + Send + 'static,
     ) -> Result<ResponseStream, Report<ConnectionPartiallyClosedError>> {
         // While not strictly necessary (as the transaction will immediately terminate if the
@@ -364,6 +378,8 @@ impl Connection {
             permit,
             subsystem,
             procedure,
+            content_type,
+            compression,
             response_rx,
             response_tx: stream_tx,
             request_rx: payload,
@@ -601,6 +601,8 @@ impl EchoSystem {
                 RequestBody::Begin(RequestBegin {
                     subsystem,
                     procedure,
+                    content_type: _,
+                    compression: _,
                     payload,
                 }) => {
                     let mut bytes = BytesMut::new();
@@ -685,6 +687,8 @@ async fn call() {
         .call(
             descriptor.subsystem,
             descriptor.procedure,
+            None,
+            None,
             stream::iter(payload.clone()),
         )
         .await
@@ -722,6 +726,8 @@ async fn call_do_not_admit_if_partially_closed_read() {
             .call(
                 descriptor.subsystem,
                 descriptor.procedure,
+                None,
+                None,
                 stream::iter([Bytes::from_static(b"hello")]),
             )
             .await
@@ -747,7 +753,13 @@ async fn call_do_not_admit_if_partially_closed_write() {
 
     let descriptor = Descriptor::default();
     connection
-        .call(descriptor.subsystem, descriptor.procedure, stream::empty())
+        .call(
+            descriptor.subsystem,
+            descriptor.procedure,
+            None,
+            None,
+            stream::empty(),
+        )
         .await
         .expect("should be open");
 
@@ -756,7 +768,13 @@ async fn call_do_not_admit_if_partially_closed_write() {
     // now we have sent a packet, so the connection should be registered as closed
     assert_eq!(
         *connection
-            .call(descriptor.subsystem, descriptor.procedure, stream::empty())
+            .call(
+                descriptor.subsystem,
+                descriptor.procedure,
+                None,
+                None,
+                stream::empty(),
+            )
             .await
             .expect_err("should be closed")
             .current_context(),
@@ -798,6 +816,8 @@ async fn call_finished_removes_stale_entry() {
         .call(
             descriptor.subsystem,
             descriptor.procedure,
+            None,
+            None,
             stream::iter(payload.clone()),
         )
         .await
@@ -834,6 +854,8 @@ async fn call_input_output_independent() {
         .call(
             descriptor.subsystem,
             descriptor.procedure,
+            None,
+            None,
             stream::iter(payload.clone()),
         )
         .await
@@ -869,6 +891,8 @@ async fn call_cancel() {
         .call(
             descriptor.subsystem,
             descriptor.procedure,
+            None,
+            None,
             stream::iter(payload.clone()),
         )
         .await
@@ -917,6 +941,8 @@ async fn connection_idle_does_not_terminate() {
             .call(
                 descriptor.subsystem,
                 descriptor.procedure,
+                None,
+                None,
                 stream::iter(payload.clone()),
             )
             .await
@@ -959,6 +985,8 @@ async fn connection_drop_waits_for_transaction_to_finish() {
         .call(
             descriptor.subsystem,
             descriptor.procedure,
+            None,
+            None,
             stream::iter(payload.clone()),
         )
         .await
@@ -1014,6 +1042,8 @@ async fn call_release_on_deadline_missed() {
         .call(
             descriptor.subsystem,
             descriptor.procedure,
+            None,
+            None,
             stream::iter(payload.clone()),
         )
         .await
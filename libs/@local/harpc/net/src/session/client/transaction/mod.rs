@@ -8,7 +8,8 @@ use core::ops::ControlFlow;
 use bytes::Bytes;
 use futures::{Stream, StreamExt as _, prelude::future::FutureExt as _};
 use harpc_types::{
-    procedure::ProcedureDescriptor, response_kind::ResponseKind, subsystem::SubsystemDescriptor,
+    compression::Compression, content_type::ContentType, procedure::ProcedureDescriptor,
+    response_kind::ResponseKind, subsystem::SubsystemDescriptor,
 };
 use harpc_wire_protocol::{
     flags::BitFlagsOp as _,
@@ -55,6 +56,7 @@ where
         &self,
         state: &mut Option<ResponseState>,
         ResponseBegin { kind, payload }: ResponseBegin,
+        compressed: bool,
     ) -> ControlFlow<(), Bytes> {
         let (tx, rx) = tachyonix::channel(
             self.config
@@ -70,7 +72,11 @@ where
         });
 
         let stream = match kind {
-            ResponseKind::Ok => Ok(ValueStream::new(TerminatedChannelStream::new(rx), internal)),
+            ResponseKind::Ok => Ok(ValueStream::new(
+                TerminatedChannelStream::new(rx),
+                internal,
+                compressed,
+            )),
             ResponseKind::Err(code) => Err(ErrorStream::new(
                 code,
                 TerminatedChannelStream::new(rx),
@@ -128,9 +134,12 @@ where
             }
 
             let end_of_response = response.header.flags.contains(ResponseFlag::EndOfResponse);
+            let compressed = response.header.flags.contains(ResponseFlag::Compressed);
 
             let bytes = match response.body {
-                ResponseBody::Begin(begin) => self.handle_begin(&mut state, begin).await,
+                ResponseBody::Begin(begin) => {
+                    self.handle_begin(&mut state, begin, compressed).await
+                }
                 ResponseBody::Frame(ResponseFrame { payload }) => {
                     ControlFlow::Continue(payload.into_bytes())
                 }
@@ -185,6 +194,8 @@ pub(crate) struct TransactionSendTask<S, P> {
 
     subsystem: SubsystemDescriptor,
     procedure: ProcedureDescriptor,
+    content_type: Option<ContentType>,
+    compression: Option<Compression>,
 
     rx: S,
     tx: mpsc::Sender<Request>,
@@ -211,6 +222,8 @@ where
                 id: self.permit.id(),
                 subsystem: self.subsystem,
                 procedure: self.procedure,
+                content_type: self.content_type,
+                compression: self.compression,
             },
             &self.tx,
         );
@@ -252,6 +265,8 @@ pub(crate) struct TransactionTask<S, P> {
 
     pub subsystem: SubsystemDescriptor,
     pub procedure: ProcedureDescriptor,
+    pub content_type: Option<ContentType>,
+    pub compression: Option<Compression>,
 
     pub response_rx: tachyonix::Receiver<Response>,
     pub response_tx: mpsc::Sender<Result<ValueStream, ErrorStream>>,
@@ -284,6 +299,8 @@ where
 
                 subsystem: self.subsystem,
                 procedure: self.procedure,
+                content_type: self.content_type,
+                compression: self.compression,
 
                 rx: self.request_rx,
                 tx: self.request_tx,
@@ -114,12 +114,29 @@ pub struct ValueStream {
     inner: TerminatedChannelStream<Bytes>,
 
     state: StreamState,
+    compressed: bool,
 }
 
 impl ValueStream {
     #[must_use]
-    pub(crate) const fn new(inner: TerminatedChannelStream<Bytes>, state: StreamState) -> Self {
-        Self { inner, state }
+    pub(crate) const fn new(
+        inner: TerminatedChannelStream<Bytes>,
+        state: StreamState,
+        compressed: bool,
+    ) -> Self {
+        Self {
+            inner,
+            state,
+            compressed,
+        }
+    }
+
+    /// Whether the payload has been compressed by the server.
+    ///
+    /// The compression used is the one requested by the client at the start of the transaction.
+    #[must_use]
+    pub const fn is_compressed(&self) -> bool {
+        self.compressed
     }
 }
 
@@ -747,6 +747,8 @@ fn setup_send_mapped<T>(
         config,
         subsystem: descriptor.subsystem,
         procedure: descriptor.procedure,
+        content_type: None,
+        compression: None,
         rx: ReceiverStream::new(bytes_rx),
         tx: request_tx,
         permit: Arc::new(permit),
@@ -792,6 +794,8 @@ async fn send_drop_sender() {
         RequestBody::Begin(RequestBegin {
             subsystem,
             procedure,
+            content_type: None,
+            compression: None,
             payload
         }) if subsystem == descriptor.subsystem
             && procedure == descriptor.procedure
@@ -881,6 +885,8 @@ async fn send_no_delay() {
         RequestBody::Begin(RequestBegin {
             subsystem,
             procedure,
+            content_type: None,
+            compression: None,
             payload
         }) if subsystem == descriptor.subsystem
             && procedure == descriptor.procedure
@@ -924,6 +930,8 @@ async fn send_no_delay_flush_empty() {
         RequestBody::Begin(RequestBegin {
             subsystem,
             procedure,
+            content_type: None,
+            compression: None,
             payload
         }) if subsystem == descriptor.subsystem
             && procedure == descriptor.procedure
@@ -970,6 +978,8 @@ async fn send_delay() {
         RequestBody::Begin(RequestBegin {
             subsystem,
             procedure,
+            content_type: None,
+            compression: None,
             payload
         }) if subsystem == descriptor.subsystem
             && procedure == descriptor.procedure
@@ -1022,6 +1032,8 @@ async fn send_delay_flush_partial() {
         RequestBody::Begin(RequestBegin {
             subsystem,
             procedure,
+            content_type: None,
+            compression: None,
             payload
         }) if subsystem == descriptor.subsystem
             && procedure == descriptor.procedure
@@ -1055,6 +1067,8 @@ async fn send_delay_flush_empty() {
         RequestBody::Begin(RequestBegin {
             subsystem,
             procedure,
+            content_type: None,
+            compression: None,
             payload
         }) if subsystem == descriptor.subsystem
             && procedure == descriptor.procedure
@@ -75,6 +75,8 @@ pub(crate) fn make_request_begin(
             procedure: ProcedureDescriptor {
                 id: ProcedureId::new(0x01),
             },
+            content_type: None,
+            compression: None,
             payload: Payload::new(payload),
         }),
     }
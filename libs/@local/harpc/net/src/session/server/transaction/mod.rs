@@ -11,11 +11,17 @@ use bytes::Bytes;
 use futures::{Sink, Stream, StreamExt as _, stream::FusedStream};
 use harpc_codec::error::NetworkError;
 use harpc_types::{
-    procedure::ProcedureDescriptor, response_kind::ResponseKind, subsystem::SubsystemDescriptor,
+    compression::Compression, content_type::ContentType, procedure::ProcedureDescriptor,
+    response_kind::ResponseKind, subsystem::SubsystemDescriptor,
 };
 use harpc_wire_protocol::{
     flags::BitFlagsOp as _,
-    request::{Request, begin::RequestBegin, flags::RequestFlag, id::RequestId},
+    request::{
+        Request,
+        begin::RequestBegin,
+        flags::{RequestFlag, RequestFlags},
+        id::RequestId,
+    },
     response::Response,
 };
 use libp2p::PeerId;
@@ -42,6 +48,8 @@ struct TransactionSendDelegateTask<P> {
     rx: mpsc::Receiver<core::result::Result<Bytes, NetworkError>>,
     tx: mpsc::Sender<Response>,
 
+    compressed: bool,
+
     permit: Arc<P>,
 }
 
@@ -66,6 +74,7 @@ where
             ResponseContext {
                 id: self.permit.id(),
                 kind: ResponseKind::Ok,
+                compressed: self.compressed,
             },
             &self.tx,
         );
@@ -116,6 +125,8 @@ where
                         ResponseContext {
                             id: self.permit.id(),
                             kind: ResponseKind::Err(code),
+                            // error responses are never compressed
+                            compressed: false,
                         },
                         &self.tx,
                     );
@@ -137,6 +148,8 @@ pub(crate) struct TransactionTask<P> {
     response_rx: mpsc::Receiver<Result<Bytes, NetworkError>>,
     response_tx: mpsc::Sender<Response>,
 
+    compression: Option<Compression>,
+
     permit: Arc<P>,
 }
 
@@ -151,6 +164,8 @@ where
             rx: self.response_rx,
             tx: self.response_tx,
 
+            compressed: self.compression.is_some(),
+
             permit: self.permit,
         };
 
@@ -181,6 +196,8 @@ pub struct TransactionContext {
 
     subsystem: SubsystemDescriptor,
     procedure: ProcedureDescriptor,
+    content_type: Option<ContentType>,
+    compression: Option<Compression>,
 }
 
 impl TransactionContext {
@@ -208,6 +225,20 @@ impl TransactionContext {
     pub const fn procedure(&self) -> ProcedureDescriptor {
         self.procedure
     }
+
+    /// The content type requested by the client, if any.
+    #[must_use]
+    pub const fn content_type(&self) -> Option<ContentType> {
+        self.content_type
+    }
+
+    /// The compression of the request payload, if any.
+    ///
+    /// Successful responses of the transaction must be compressed using the same compression.
+    #[must_use]
+    pub const fn compression(&self) -> Option<Compression> {
+        self.compression
+    }
 }
 
 pub struct Transaction {
@@ -226,6 +257,7 @@ impl Transaction {
     )]
     pub(crate) fn from_request(
         body: &RequestBegin,
+        flags: RequestFlags,
         TransactionParts {
             peer,
             session,
@@ -237,6 +269,11 @@ impl Transaction {
     ) -> (Self, TransactionTask<TransactionPermit>) {
         let permit = Arc::new(permit);
 
+        // the compression only takes effect if the payload has actually been flagged as compressed
+        let compression = body
+            .compression
+            .filter(|_| flags.contains(RequestFlag::Compressed));
+
         let (response_tx, response_rx) = mpsc::channel(
             config
                 .per_transaction_response_byte_stream_buffer_size
@@ -250,6 +287,8 @@ impl Transaction {
                 session,
                 subsystem: body.subsystem,
                 procedure: body.procedure,
+                content_type: body.content_type,
+                compression,
             },
 
             request: rx,
@@ -264,6 +303,8 @@ impl Transaction {
             response_rx,
             response_tx: tx,
 
+            compression,
+
             permit,
         };
 
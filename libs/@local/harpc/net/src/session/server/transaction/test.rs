@@ -82,6 +82,17 @@ fn setup_send(
     mpsc::Sender<Result<Bytes, NetworkError>>,
     mpsc::Receiver<Response>,
     JoinHandle<()>,
+) {
+    setup_send_compressed(no_delay, false)
+}
+
+fn setup_send_compressed(
+    no_delay: bool,
+    compressed: bool,
+) -> (
+    mpsc::Sender<Result<Bytes, NetworkError>>,
+    mpsc::Receiver<Response>,
+    JoinHandle<()>,
 ) {
     // we choose 8 here, so that we can buffer all replies easily and not spawn an extra task
     let (bytes_tx, bytes_rx) = mpsc::channel(8);
@@ -95,6 +106,7 @@ fn setup_send(
         },
         rx: bytes_rx,
         tx: response_tx,
+        compressed,
         permit: Arc::new(StaticTransactionPermit {
             id: mock_request_id(0),
             cancel: CancellationToken::new(),
@@ -744,6 +756,55 @@ async fn send_delay_error_split_large() {
     });
 }
 
+#[tokio::test]
+async fn send_no_delay_compressed_error_uncompressed() {
+    let (bytes_tx, mut response_rx, handle) = setup_send_compressed(true, true);
+
+    let code = ErrorCode::new(NonZero::new(0xFF_FF).expect("infallible"));
+
+    let error = NetworkError::capture_error(&ExampleError {
+        message: Cow::Borrowed("compressed error"),
+        code,
+    });
+
+    let payload_ok = Bytes::from_static(b"compressed");
+    let payload_err = error.bytes().clone();
+
+    bytes_tx
+        .send(Ok(payload_ok.clone()))
+        .await
+        .expect("should not be closed");
+
+    bytes_tx
+        .send(Err(error))
+        .await
+        .expect("should not be closed");
+
+    drop(bytes_tx);
+
+    tokio::time::timeout(Duration::from_secs(1), handle)
+        .await
+        .expect("should finish within timeout")
+        .expect("should not panic");
+
+    let mut responses = Vec::with_capacity(4);
+    let available = response_rx.recv_many(&mut responses, 4).await;
+    assert_eq!(available, 2);
+
+    // successful responses carry the compressed payload, errors are never compressed
+    assert_begin(&responses[0], ExpectedBegin {
+        flags: ResponseFlags::from(ResponseFlag::Compressed),
+        kind: ResponseKind::Ok,
+        payload: &payload_ok,
+    });
+
+    assert_begin(&responses[1], ExpectedBegin {
+        flags: ResponseFlags::from(ResponseFlag::EndOfResponse),
+        kind: ResponseKind::Err(code),
+        payload: &payload_err,
+    });
+}
+
 #[tokio::test]
 async fn send_no_delay_split_large() {
     let (bytes_tx, mut response_rx, handle) = setup_send(true);
@@ -1281,6 +1342,8 @@ fn make_begin(flags: impl Into<RequestFlags>, payload: impl Into<Bytes>) -> Requ
             procedure: ProcedureDescriptor {
                 id: ProcedureId::new(0x00),
             },
+            content_type: None,
+            compression: None,
             payload: Payload::new(payload),
         }),
     }
@@ -180,6 +180,8 @@ where
         .call(
             descriptor.subsystem,
             descriptor.procedure,
+            None,
+            None,
             stream::iter(iter::once(payload)),
         )
         .await
@@ -254,6 +256,8 @@ async fn echo_client<const VERIFY: bool>(
         .call(
             descriptor.subsystem,
             descriptor.procedure,
+            None,
+            None,
             stream::iter(iter::once(payload)),
         )
         .await
@@ -4,7 +4,8 @@ mod test;
 use bytes::{Buf as _, Bytes};
 use bytes_utils::SegmentedBuf;
 use harpc_types::{
-    procedure::ProcedureDescriptor, response_kind::ResponseKind, subsystem::SubsystemDescriptor,
+    compression::Compression, content_type::ContentType, procedure::ProcedureDescriptor,
+    response_kind::ResponseKind, subsystem::SubsystemDescriptor,
 };
 use harpc_wire_protocol::{
     flags::BitFlagsOp as _,
@@ -64,6 +65,8 @@ pub(crate) struct RequestContext {
 
     pub subsystem: SubsystemDescriptor,
     pub procedure: ProcedureDescriptor,
+    pub content_type: Option<ContentType>,
+    pub compression: Option<Compression>,
 }
 
 fn new_request_header(context: RequestContext) -> RequestHeader {
@@ -72,7 +75,7 @@ fn new_request_header(context: RequestContext) -> RequestHeader {
             version: ProtocolVersion::V1,
         },
         request_id: context.id,
-        flags: RequestFlags::empty(),
+        flags: RequestFlags::empty().set(RequestFlag::Compressed, context.compression.is_some()),
     }
 }
 
@@ -85,6 +88,8 @@ impl NetworkPacket for Request {
             body: RequestBody::Begin(RequestBegin {
                 subsystem: context.subsystem,
                 procedure: context.procedure,
+                content_type: context.content_type,
+                compression: context.compression,
                 payload: Payload::new(bytes),
             }),
         }
@@ -108,6 +113,8 @@ impl NetworkPacket for Request {
 pub(crate) struct ResponseContext {
     pub id: RequestId,
     pub kind: ResponseKind,
+    /// Whether the payload has been compressed, using the compression requested by the client.
+    pub compressed: bool,
 }
 
 fn new_response_header(context: ResponseContext) -> ResponseHeader {
@@ -116,7 +123,7 @@ fn new_response_header(context: ResponseContext) -> ResponseHeader {
             version: ProtocolVersion::V1,
         },
         request_id: context.id,
-        flags: ResponseFlags::empty(),
+        flags: ResponseFlags::empty().set(ResponseFlag::Compressed, context.compressed),
     }
 }
 
@@ -20,6 +20,7 @@ fn push() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compressed: false,
         },
         &tx,
     );
@@ -44,6 +45,7 @@ async fn write_remaining_on_empty() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compressed: false,
         },
         &tx,
     );
@@ -72,6 +74,7 @@ async fn write_remaining_no_delay_increments_index() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compressed: false,
         },
         &tx,
     );
@@ -98,6 +101,7 @@ async fn flush_calls_write() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compressed: false,
         },
         &tx,
     );
@@ -119,6 +123,7 @@ async fn write_calls_write_remaining_on_no_delay() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compressed: false,
         },
         &tx,
     );
@@ -144,6 +149,7 @@ async fn split_single() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compressed: false,
         },
         &tx,
     );
@@ -179,6 +185,7 @@ async fn split_multiple() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compressed: false,
         },
         &tx,
     );
@@ -210,6 +217,32 @@ async fn split_multiple() {
     );
 }
 
+#[tokio::test]
+async fn compressed_flag_on_every_packet() {
+    let (tx, mut rx) = mpsc::channel(8);
+
+    let mut writer = ResponseWriter::new(
+        WriterOptions { no_delay: false },
+        ResponseContext {
+            id: mock_request_id(0x01),
+            kind: ResponseKind::Ok,
+            compressed: true,
+        },
+        &tx,
+    );
+
+    writer.push(Bytes::from(vec![0; Payload::MAX_SIZE + 8]));
+    writer.flush().await.expect("able to flush");
+
+    let mut responses = Vec::with_capacity(8);
+    let available = rx.recv_many(&mut responses, 8).await;
+    assert_eq!(available, 2);
+
+    for response in &responses {
+        assert!(response.header.flags.contains(ResponseFlag::Compressed));
+    }
+}
+
 #[tokio::test]
 #[expect(
     clippy::integer_division_remainder_used,
@@ -224,6 +257,7 @@ async fn delay_push_merge_on_write() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compressed: false,
         },
         &tx,
     );
@@ -254,6 +288,7 @@ async fn delay_write_checks_closed_state_even_if_not_writing() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compressed: false,
         },
         &tx,
     );
@@ -278,6 +313,7 @@ async fn delay_write_checks_closed_state_not_if_empty_buffer() {
         ResponseContext {
             id: mock_request_id(0x01),
             kind: ResponseKind::Ok,
+            compressed: false,
         },
         &tx,
     );
@@ -5,6 +5,7 @@ use core::{
 };
 
 use harpc_types::{
+    content_type::ContentType,
     error_code::ErrorCode,
     procedure::{ProcedureDescriptor, ProcedureId},
     subsystem::SubsystemDescriptor,
@@ -36,6 +37,18 @@ impl Error for ProcedureNotFound {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
+#[display("content type {content_type} is not supported")]
+pub struct UnsupportedContentType {
+    pub content_type: ContentType,
+}
+
+impl Error for UnsupportedContentType {
+    fn provide<'a>(&'a self, request: &mut core::error::Request<'a>) {
+        request.provide_value(ErrorCode::UNSUPPORTED_CONTENT_TYPE);
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Forbidden {
     pub subsystem: SubsystemDescriptor,
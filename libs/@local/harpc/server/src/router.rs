@@ -9,6 +9,7 @@ use futures::FutureExt as _;
 use harpc_system::{Subsystem, delegate::SubsystemDelegate};
 use harpc_tower::{
     body::Body,
+    layer::compression::{CompressionLayer, CompressionService},
     net::pack::{PackLayer, PackService},
     request::Request,
     response::Response,
@@ -57,7 +58,7 @@ impl<S, C> RouterBuilder<HNil, Identity, S, C> {
 }
 
 type ServiceHandler<D, L, S, C> = Handler<
-    <L as Layer<SubsystemDelegateService<D, S, C>>>::Service,
+    CompressionService<<L as Layer<SubsystemDelegateService<D, S, C>>>::Service>,
     <<D as SubsystemDelegate<C>>::Subsystem as Subsystem>::SubsystemId,
 >;
 
@@ -94,7 +95,9 @@ impl<R, L, S, C> RouterBuilder<R, L, S, C> {
     {
         let service =
             SubsystemDelegateService::new(delegate, Arc::clone(&self.session), self.codec.clone());
-        let service = self.builder.service(service);
+        // compression is handled transparently for every subsystem, so that delegates (and the
+        // layers of the builder) always operate on the uncompressed payload.
+        let service = CompressionLayer::new().layer(self.builder.service(service));
 
         RouterBuilder {
             routes: HCons {
@@ -3,8 +3,8 @@
 use core::{array, pin::pin};
 
 use error_stack::{Report, ResultExt as _};
-use futures::{StreamExt as _, stream};
-use harpc_codec::{decode::ReportDecoder, encode::Encoder};
+use futures::{Stream, StreamExt as _, future, stream};
+use harpc_codec::{decode::ReportDecoder, encode::Encoder, negotiate::Negotiate};
 use harpc_system::{Subsystem, procedure::ProcedureIdentifier};
 use harpc_tower::{
     body::{Body, BodyExt as _, Frame, controlled::Controlled, stream::StreamBody},
@@ -13,7 +13,9 @@ use harpc_tower::{
 };
 use harpc_types::{procedure::ProcedureDescriptor, response_kind::ResponseKind};
 
-use crate::error::{DelegationError, ProcedureNotFound, RequestExpectedItemCountMismatch};
+use crate::error::{
+    DelegationError, ProcedureNotFound, RequestExpectedItemCountMismatch, UnsupportedContentType,
+};
 
 /// Parses the procedure identifier from the given request.
 ///
@@ -38,6 +40,27 @@ where
         .change_context(DelegationError)
 }
 
+/// Selects the codec for the content type requested by the client.
+///
+/// If the client didn't request a specific content type, the given codec is used as-is.
+///
+/// # Errors
+///
+/// Returns a `DelegationError` if the codec does not support the requested content type.
+pub fn negotiate_codec<C, B>(request: &Request<B>, codec: C) -> Result<C, Report<DelegationError>>
+where
+    C: Negotiate,
+{
+    let Some(content_type) = request.content_type() else {
+        return Ok(codec);
+    };
+
+    codec
+        .negotiate(content_type)
+        .ok_or(UnsupportedContentType { content_type })
+        .change_context(DelegationError)
+}
+
 /// Delegates a call to a closure with a single input and output.
 pub async fn delegate_call_discrete<B, I, O, C, Fut>(
     request: Request<B>,
@@ -63,9 +86,10 @@ where
     B: Body<Control = !, Error: Send + Sync> + Send,
     I: serde::de::DeserializeOwned,
     O: serde::Serialize + Send,
-    C: Encoder + ReportDecoder + Clone + Send,
+    C: Encoder + ReportDecoder + Negotiate + Clone + Send,
     Fut: Future<Output = O> + Send,
 {
+    let codec = negotiate_codec(&request, codec)?;
     let session_id = request.session();
 
     let body = request.into_body();
@@ -88,3 +112,64 @@ where
     // In theory we could also box this, or use `Either` if we have multiple responses
     Ok(Response::from_ok(response::Parts::new(session_id), data))
 }
+
+/// Delegates a call to a closure with a single input, which responds with a stream of items.
+///
+/// Items are encoded as they are produced by the stream, so that large responses don't have to be
+/// buffered. If the closure fails before a stream has been created, the error is sent as the only
+/// item of the response.
+pub async fn delegate_call_stream<B, I, T, E, C, St, Fut>(
+    request: Request<B>,
+    codec: C,
+    closure: impl FnOnce(I) -> Fut + Send,
+) -> Result<
+    Response<
+        // See `delegate_call_discrete` for why the type is spelled out.
+        Controlled<
+            ResponseKind,
+            StreamBody<
+                stream::MapOk<
+                    <C as Encoder>::Output<
+                        future::Either<St, stream::Iter<array::IntoIter<Result<T, E>, 1>>>,
+                    >,
+                    fn(<C as Encoder>::Buf) -> Frame<<C as Encoder>::Buf, !>,
+                >,
+            >,
+        >,
+    >,
+    Report<DelegationError>,
+>
+where
+    B: Body<Control = !, Error: Send + Sync> + Send,
+    I: serde::de::DeserializeOwned,
+    T: serde::Serialize + Send,
+    E: serde::Serialize + Send,
+    C: Encoder + ReportDecoder + Negotiate + Clone + Send,
+    St: Stream<Item = Result<T, E>> + Send,
+    Fut: Future<Output = Result<St, E>> + Send,
+{
+    let codec = negotiate_codec(&request, codec)?;
+    let session_id = request.session();
+
+    let body = request.into_body();
+    let data = body.into_stream().into_data_stream();
+
+    let stream = codec.clone().decode(data);
+    let mut stream = pin!(stream);
+
+    let payload = stream
+        .next()
+        .await
+        .ok_or_else(|| RequestExpectedItemCountMismatch::exactly(1))
+        .change_context(DelegationError)?
+        .change_context(DelegationError)?;
+
+    let items = match closure(payload).await {
+        Ok(items) => future::Either::Left(items),
+        Err(error) => future::Either::Right(stream::iter([Err(error)])),
+    };
+
+    let data = codec.encode(items);
+
+    Ok(Response::from_ok(response::Parts::new(session_id), data))
+}
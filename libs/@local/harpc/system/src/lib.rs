@@ -1,5 +1,6 @@
 #![feature(never_type, marker_trait_attr)]
 
+pub use harpc_macros::service;
 use harpc_types::{
     subsystem::{SubsystemDescriptor, SubsystemId},
     version::Version,
@@ -11,6 +12,13 @@ pub mod delegate;
 pub mod metadata;
 pub mod procedure;
 
+#[doc(hidden)]
+pub mod __private {
+    //! Re-exports used by the code generated by [`service`](crate::service).
+
+    pub use frunk::{HCons, HNil};
+}
+
 pub trait SubsystemIdentifier: Copy {
     fn from_id(id: SubsystemId) -> Option<Self>
     where
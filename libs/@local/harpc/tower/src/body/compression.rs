@@ -0,0 +1,697 @@
+use alloc::collections::VecDeque;
+use core::{
+    error::Error,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use bytes::{Buf, BufMut as _, Bytes, BytesMut};
+use error_stack::{Report, ResultExt as _};
+use harpc_codec::error::NetworkError;
+use harpc_types::{compression::Compression, error_code::ErrorCode, response_kind::ResponseKind};
+
+use super::{Body, BodyState, Frame};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
+#[non_exhaustive]
+pub enum DecompressionError {
+    #[error("the compressed block is malformed")]
+    Malformed,
+    #[error("the block exceeds the maximum size of {limit} bytes")]
+    BlockTooLarge { limit: usize },
+    #[error("the payload ends with an incomplete block")]
+    Incomplete,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display)]
+#[display("unable to decompress response payload: {reason}")]
+struct MalformedCompressedPayload {
+    reason: DecompressionError,
+}
+
+impl Error for MalformedCompressedPayload {
+    fn provide<'a>(&'a self, request: &mut core::error::Request<'a>) {
+        request.provide_value(ErrorCode::MALFORMED_COMPRESSED_PAYLOAD);
+    }
+}
+
+/// Compression algorithm supported by the bodies in this module.
+///
+/// A payload is compressed as a sequence of self-contained blocks, every block is prefixed by its
+/// compressed and uncompressed length, each as a big-endian `u32`. Blocks are independent of the
+/// packets they are transmitted in, a single block may span multiple packets and vice versa.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Algorithm {
+    Zstd,
+    Lz4,
+}
+
+impl Algorithm {
+    const HEADER_SIZE: usize = size_of::<u32>() * 2;
+    /// The maximum amount of uncompressed bytes in a single block.
+    ///
+    /// Larger data frames are split into multiple blocks. This bounds the memory required to
+    /// decompress a block, regardless of the length announced by the peer.
+    pub const MAX_BLOCK_SIZE: usize = 1024 * 1024;
+
+    /// Returns the algorithm for the given compression.
+    ///
+    /// Returns `None` if the compression is not supported.
+    #[must_use]
+    pub fn from_compression(compression: Compression) -> Option<Self> {
+        if compression == Compression::ZSTD {
+            Some(Self::Zstd)
+        } else if compression == Compression::LZ4 {
+            Some(Self::Lz4)
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub const fn compression(self) -> Compression {
+        match self {
+            Self::Zstd => Compression::ZSTD,
+            Self::Lz4 => Compression::LZ4,
+        }
+    }
+
+    /// Compresses the data into one or more blocks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if zstd is unable to allocate its compression context.
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "blocks are limited to `MAX_BLOCK_SIZE` (plus a small compression overhead), \
+                  which fits into a `u32`"
+    )]
+    #[must_use]
+    pub fn compress(self, mut data: impl Buf) -> Bytes {
+        let mut output = BytesMut::new();
+
+        while data.has_remaining() {
+            let chunk = data.copy_to_bytes(data.remaining().min(Self::MAX_BLOCK_SIZE));
+
+            let block = match self {
+                Self::Zstd => zstd::bulk::compress(&chunk, zstd::DEFAULT_COMPRESSION_LEVEL)
+                    .expect("compressing an in-memory buffer should not fail"),
+                Self::Lz4 => lz4_flex::block::compress(&chunk),
+            };
+
+            output.reserve(Self::HEADER_SIZE + block.len());
+            output.put_u32(block.len() as u32);
+            output.put_u32(chunk.len() as u32);
+            output.put_slice(&block);
+        }
+
+        output.freeze()
+    }
+
+    fn decompress(self, block: &[u8], length: usize) -> Result<Bytes, Report<DecompressionError>> {
+        let output = match self {
+            Self::Zstd => zstd::bulk::decompress(block, length)
+                .change_context(DecompressionError::Malformed)?,
+            Self::Lz4 => lz4_flex::block::decompress(block, length)
+                .change_context(DecompressionError::Malformed)?,
+        };
+
+        if output.len() != length {
+            return Err(Report::new(DecompressionError::Malformed));
+        }
+
+        Ok(Bytes::from(output))
+    }
+}
+
+/// Reassembles blocks from arbitrarily split data and decompresses them.
+#[derive(Debug)]
+struct BlockDecoder {
+    algorithm: Algorithm,
+    buffer: BytesMut,
+}
+
+impl BlockDecoder {
+    fn new(algorithm: Algorithm) -> Self {
+        Self {
+            algorithm,
+            buffer: BytesMut::new(),
+        }
+    }
+
+    fn push(&mut self, data: impl Buf) {
+        self.buffer.put(data);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    fn next_block(&mut self) -> Option<Result<Bytes, Report<DecompressionError>>> {
+        let mut header = self.buffer.get(..Algorithm::HEADER_SIZE)?;
+        let compressed = header.get_u32() as usize;
+        let length = header.get_u32() as usize;
+
+        if length > Algorithm::MAX_BLOCK_SIZE {
+            self.buffer.clear();
+
+            return Some(Err(Report::new(DecompressionError::BlockTooLarge {
+                limit: Algorithm::MAX_BLOCK_SIZE,
+            })));
+        }
+
+        if self.buffer.len() < Algorithm::HEADER_SIZE + compressed {
+            return None;
+        }
+
+        self.buffer.advance(Algorithm::HEADER_SIZE);
+        let block = self.buffer.split_to(compressed);
+
+        Some(self.algorithm.decompress(&block, length))
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// A request body that compresses every data frame.
+    ///
+    /// If no algorithm is given, the data frames are passed through as-is.
+    #[derive(Debug)]
+    pub struct CompressRequest<B> {
+        #[pin]
+        inner: B,
+        algorithm: Option<Algorithm>,
+    }
+}
+
+impl<B> CompressRequest<B> {
+    pub const fn new(inner: B, algorithm: Option<Algorithm>) -> Self {
+        Self { inner, algorithm }
+    }
+}
+
+impl<B> Body for CompressRequest<B>
+where
+    B: Body<Control = !>,
+{
+    type Control = !;
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+    ) -> Poll<Option<Result<Frame<Self::Data, Self::Control>, Self::Error>>> {
+        let this = self.project();
+        let algorithm = *this.algorithm;
+
+        this.inner.poll_frame(cx).map_ok(|frame| {
+            frame.map_data(|mut data| match algorithm {
+                Some(algorithm) => algorithm.compress(data),
+                None => data.copy_to_bytes(data.remaining()),
+            })
+        })
+    }
+
+    fn state(&self) -> Option<BodyState> {
+        self.inner.state()
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// A request body that decompresses the payload compressed by [`CompressRequest`].
+    ///
+    /// If no algorithm is given, the data frames are passed through as-is.
+    #[derive(Debug)]
+    pub struct DecompressRequest<B> {
+        #[pin]
+        inner: B,
+        decoder: Option<BlockDecoder>,
+    }
+}
+
+impl<B> DecompressRequest<B> {
+    pub fn new(inner: B, algorithm: Option<Algorithm>) -> Self {
+        Self {
+            inner,
+            decoder: algorithm.map(BlockDecoder::new),
+        }
+    }
+}
+
+impl<B> Body for DecompressRequest<B>
+where
+    B: Body<Control = !, Error = !>,
+{
+    type Control = !;
+    type Data = Bytes;
+    type Error = Report<DecompressionError>;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+    ) -> Poll<Option<Result<Frame<Self::Data, Self::Control>, Self::Error>>> {
+        let mut this = self.project();
+
+        let Some(decoder) = this.decoder.as_mut() else {
+            return this.inner.poll_frame(cx).map(|frame| {
+                frame.map(|Ok(frame)| {
+                    Ok(frame.map_data(|mut data| data.copy_to_bytes(data.remaining())))
+                })
+            });
+        };
+
+        loop {
+            // multiple blocks could be in the buffer at the same time, so we always check the
+            // buffer before polling the inner body.
+            if let Some(block) = decoder.next_block() {
+                return Poll::Ready(Some(block.map(Frame::new_data)));
+            }
+
+            match ready!(this.inner.as_mut().poll_frame(cx)) {
+                Some(Ok(Frame::Data(data))) => decoder.push(data),
+                None if decoder.is_empty() => return Poll::Ready(None),
+                None => {
+                    decoder.clear();
+
+                    return Poll::Ready(Some(Err(Report::new(DecompressionError::Incomplete))));
+                }
+            }
+        }
+    }
+
+    fn state(&self) -> Option<BodyState> {
+        self.inner.state()
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// A response body that compresses the payload of successful responses.
+    ///
+    /// Data frames of error responses are passed through as-is, as errors are never compressed.
+    /// If no algorithm is given, all data frames are passed through as-is.
+    #[derive(Debug)]
+    pub struct CompressResponse<B> {
+        #[pin]
+        inner: B,
+        algorithm: Option<Algorithm>,
+        // data before the first control frame is considered part of a successful response
+        ok: bool,
+    }
+}
+
+impl<B> CompressResponse<B> {
+    pub const fn new(inner: B, algorithm: Option<Algorithm>) -> Self {
+        Self {
+            inner,
+            algorithm,
+            ok: true,
+        }
+    }
+}
+
+impl<B> Body for CompressResponse<B>
+where
+    B: Body<Control: AsRef<ResponseKind>>,
+{
+    type Control = B::Control;
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+    ) -> Poll<Option<Result<Frame<Self::Data, Self::Control>, Self::Error>>> {
+        let this = self.project();
+
+        let Some(result) = ready!(this.inner.poll_frame(cx)) else {
+            return Poll::Ready(None);
+        };
+
+        let frame = match result {
+            Ok(Frame::Control(control)) => {
+                *this.ok = matches!(control.as_ref(), ResponseKind::Ok);
+
+                Frame::new_control(control)
+            }
+            Ok(Frame::Data(mut data)) => match *this.algorithm {
+                Some(algorithm) if *this.ok => Frame::new_data(algorithm.compress(data)),
+                _ => Frame::new_data(data.copy_to_bytes(data.remaining())),
+            },
+            Err(error) => return Poll::Ready(Some(Err(error))),
+        };
+
+        Poll::Ready(Some(Ok(frame)))
+    }
+
+    fn state(&self) -> Option<BodyState> {
+        self.inner.state()
+    }
+}
+
+#[derive(Debug)]
+enum DecompressResponseState {
+    /// The current response is successful and its payload is decompressed.
+    Decompress,
+    /// The current response is an error, its payload is passed through as-is.
+    Passthrough,
+    /// Decompressing the current response failed, the rest of the payload is discarded.
+    Discard,
+}
+
+pin_project_lite::pin_project! {
+    /// A response body that decompresses the payload compressed by [`CompressResponse`].
+    ///
+    /// If the payload of a successful response cannot be decompressed, the response is replaced
+    /// by an error response, the remaining payload of the response is discarded.
+    /// If no algorithm is given, all data frames are passed through as-is.
+    #[derive(Debug)]
+    pub struct DecompressResponse<B> {
+        #[pin]
+        inner: B,
+        decoder: Option<BlockDecoder>,
+
+        state: DecompressResponseState,
+        queue: VecDeque<Frame<Bytes, ResponseKind>>,
+    }
+}
+
+impl<B> DecompressResponse<B> {
+    pub fn new(inner: B, algorithm: Option<Algorithm>) -> Self {
+        Self {
+            inner,
+            decoder: algorithm.map(BlockDecoder::new),
+
+            state: DecompressResponseState::Decompress,
+            queue: VecDeque::new(),
+        }
+    }
+}
+
+fn fail(queue: &mut VecDeque<Frame<Bytes, ResponseKind>>, reason: DecompressionError) {
+    let error = NetworkError::capture_error(&MalformedCompressedPayload { reason });
+    let (code, bytes) = error.into_parts();
+
+    queue.push_back(Frame::new_control(ResponseKind::Err(code)));
+    queue.push_back(Frame::new_data(bytes));
+}
+
+impl<B> Body for DecompressResponse<B>
+where
+    B: Body<Control: AsRef<ResponseKind>, Error = !>,
+{
+    type Control = ResponseKind;
+    type Data = Bytes;
+    type Error = !;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+    ) -> Poll<Option<Result<Frame<Self::Data, Self::Control>, Self::Error>>> {
+        let mut this = self.project();
+
+        let Some(decoder) = this.decoder.as_mut() else {
+            return this.inner.poll_frame(cx).map(|frame| {
+                frame.map(|Ok(frame)| {
+                    Ok(frame
+                        .map_data(|mut data| data.copy_to_bytes(data.remaining()))
+                        .map_control(|control| *control.as_ref()))
+                })
+            });
+        };
+
+        loop {
+            if let Some(frame) = this.queue.pop_front() {
+                return Poll::Ready(Some(Ok(frame)));
+            }
+
+            if matches!(this.state, DecompressResponseState::Decompress) {
+                match decoder.next_block() {
+                    Some(Ok(data)) => return Poll::Ready(Some(Ok(Frame::new_data(data)))),
+                    Some(Err(report)) => {
+                        decoder.clear();
+                        *this.state = DecompressResponseState::Discard;
+
+                        fail(this.queue, *report.current_context());
+                        continue;
+                    }
+                    None => {}
+                }
+            }
+
+            let Some(Ok(frame)) = ready!(this.inner.as_mut().poll_frame(cx)) else {
+                if matches!(this.state, DecompressResponseState::Decompress) && !decoder.is_empty()
+                {
+                    decoder.clear();
+                    *this.state = DecompressResponseState::Discard;
+
+                    fail(this.queue, DecompressionError::Incomplete);
+                    continue;
+                }
+
+                return Poll::Ready(None);
+            };
+
+            match frame {
+                Frame::Data(mut data) => match this.state {
+                    DecompressResponseState::Decompress => decoder.push(data),
+                    DecompressResponseState::Passthrough => {
+                        let data = data.copy_to_bytes(data.remaining());
+
+                        return Poll::Ready(Some(Ok(Frame::new_data(data))));
+                    }
+                    DecompressResponseState::Discard => {}
+                },
+                Frame::Control(control) => {
+                    if matches!(this.state, DecompressResponseState::Decompress)
+                        && !decoder.is_empty()
+                    {
+                        decoder.clear();
+                        fail(this.queue, DecompressionError::Incomplete);
+                    }
+
+                    let kind = *control.as_ref();
+                    *this.state = match kind {
+                        ResponseKind::Ok => DecompressResponseState::Decompress,
+                        ResponseKind::Err(_) => DecompressResponseState::Passthrough,
+                    };
+
+                    this.queue.push_back(Frame::new_control(kind));
+                }
+            }
+        }
+    }
+
+    fn state(&self) -> Option<BodyState> {
+        if !self.queue.is_empty() {
+            return None;
+        }
+
+        self.inner.state()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::{assert_matches::assert_matches, task::Poll};
+
+    use bytes::{Buf as _, BufMut as _, Bytes, BytesMut};
+    use harpc_types::{error_code::ErrorCode, response_kind::ResponseKind};
+
+    use super::{
+        Algorithm, CompressRequest, CompressResponse, DecompressRequest, DecompressResponse,
+        DecompressionError,
+    };
+    use crate::{
+        body::{Body, BodyState, Frame, test::poll_frame_unpin},
+        test::StaticBody,
+    };
+
+    const PAYLOAD: &[u8] = b"hello, world! hello, world! hello, world!";
+
+    fn collect_data<B>(body: &mut B) -> Bytes
+    where
+        B: Body<Data = Bytes, Error: core::fmt::Debug> + Unpin,
+    {
+        let mut output = BytesMut::new();
+
+        while let Poll::Ready(Some(frame)) = poll_frame_unpin(body) {
+            if let Frame::Data(data) = frame.expect("frame should be Ok") {
+                output.extend_from_slice(&data);
+            }
+        }
+
+        output.freeze()
+    }
+
+    fn request_roundtrip(algorithm: Algorithm) {
+        let body = StaticBody::<_, !, !>::new([
+            Ok(Frame::Data(Bytes::from_static(PAYLOAD))),
+            Ok(Frame::Data(Bytes::from_static(PAYLOAD))),
+        ]);
+
+        let mut compressed = CompressRequest::new(body, Some(algorithm));
+
+        // split the compressed payload at arbitrary boundaries, to ensure that blocks are
+        // reassembled correctly
+        let mut compressed = collect_data(&mut compressed);
+        let mut frames = Vec::new();
+        while compressed.has_remaining() {
+            let length = compressed.remaining().min(7);
+            frames.push(Ok(Frame::Data(compressed.split_to(length))));
+        }
+
+        let mut body = DecompressRequest::new(StaticBody::<_, !, !>::new(frames), Some(algorithm));
+        let output = collect_data(&mut body);
+
+        assert_eq!(output, [PAYLOAD, PAYLOAD].concat());
+        assert_eq!(body.state(), Some(BodyState::Complete));
+    }
+
+    #[test]
+    fn request_roundtrip_zstd() {
+        request_roundtrip(Algorithm::Zstd);
+    }
+
+    #[test]
+    fn request_roundtrip_lz4() {
+        request_roundtrip(Algorithm::Lz4);
+    }
+
+    #[test]
+    fn compress_splits_large_frames() {
+        let data = Bytes::from(vec![0_u8; Algorithm::MAX_BLOCK_SIZE + 8]);
+        let compressed = Algorithm::Lz4.compress(data.clone());
+
+        let mut body = DecompressRequest::new(
+            StaticBody::<_, !, !>::new([Ok(Frame::Data(compressed))]),
+            Some(Algorithm::Lz4),
+        );
+
+        let frame = poll_frame_unpin(&mut body);
+        assert_matches!(frame, Poll::Ready(Some(Ok(Frame::Data(data)))) if data.len() == Algorithm::MAX_BLOCK_SIZE);
+
+        let frame = poll_frame_unpin(&mut body);
+        assert_matches!(frame, Poll::Ready(Some(Ok(Frame::Data(data)))) if data.len() == 8);
+
+        assert_matches!(poll_frame_unpin(&mut body), Poll::Ready(None));
+    }
+
+    #[test]
+    fn passthrough_without_algorithm() {
+        let body = StaticBody::<_, !, !>::new([Ok(Frame::Data(Bytes::from_static(PAYLOAD)))]);
+        let mut body = DecompressRequest::new(CompressRequest::new(body, None), None);
+
+        let frame = poll_frame_unpin(&mut body);
+        assert_matches!(frame, Poll::Ready(Some(Ok(Frame::Data(data)))) if data == PAYLOAD);
+    }
+
+    #[test]
+    fn request_incomplete_block() {
+        let compressed = Algorithm::Zstd.compress(Bytes::from_static(PAYLOAD));
+
+        let mut body = DecompressRequest::new(
+            StaticBody::<_, !, !>::new([Ok(Frame::Data(compressed.slice(..compressed.len() - 1)))]),
+            Some(Algorithm::Zstd),
+        );
+
+        let frame = poll_frame_unpin(&mut body);
+        assert_matches!(frame, Poll::Ready(Some(Err(report))) if *report.current_context() == DecompressionError::Incomplete);
+
+        assert_matches!(poll_frame_unpin(&mut body), Poll::Ready(None));
+    }
+
+    #[test]
+    fn request_block_too_large() {
+        let mut header = BytesMut::new();
+        header.put_u32(1);
+        header.put_u32(u32::MAX);
+
+        let mut body = DecompressRequest::new(
+            StaticBody::<_, !, !>::new([Ok(Frame::Data(header.freeze()))]),
+            Some(Algorithm::Lz4),
+        );
+
+        let frame = poll_frame_unpin(&mut body);
+        assert_matches!(
+            frame,
+            Poll::Ready(Some(Err(report))) if *report.current_context() == DecompressionError::BlockTooLarge { limit: Algorithm::MAX_BLOCK_SIZE }
+        );
+    }
+
+    #[test]
+    fn response_errors_are_not_compressed() {
+        let code = ErrorCode::INTERNAL_SERVER_ERROR;
+
+        let body = StaticBody::<_, _, !>::new([
+            Ok(Frame::Data(Bytes::from_static(PAYLOAD))),
+            Ok(Frame::Control(ResponseKind::Err(code))),
+            Ok(Frame::Data(Bytes::from_static(b"error"))),
+        ]);
+
+        let mut body = CompressResponse::new(body, Some(Algorithm::Zstd));
+
+        let frame = poll_frame_unpin(&mut body);
+        assert_matches!(frame, Poll::Ready(Some(Ok(Frame::Data(data)))) if data != PAYLOAD);
+
+        let frame = poll_frame_unpin(&mut body);
+        assert_matches!(frame, Poll::Ready(Some(Ok(Frame::Control(ResponseKind::Err(actual))))) if actual == code);
+
+        let frame = poll_frame_unpin(&mut body);
+        assert_matches!(frame, Poll::Ready(Some(Ok(Frame::Data(data)))) if data == b"error" as &[_]);
+    }
+
+    #[test]
+    fn response_roundtrip() {
+        let code = ErrorCode::INTERNAL_SERVER_ERROR;
+
+        let body = StaticBody::<_, _, !>::new([
+            Ok(Frame::Data(Bytes::from_static(PAYLOAD))),
+            Ok(Frame::Control(ResponseKind::Err(code))),
+            Ok(Frame::Data(Bytes::from_static(b"error"))),
+        ]);
+
+        let body = CompressResponse::new(body, Some(Algorithm::Lz4));
+        let mut body = DecompressResponse::new(body, Some(Algorithm::Lz4));
+
+        let frame = poll_frame_unpin(&mut body);
+        assert_matches!(frame, Poll::Ready(Some(Ok(Frame::Data(data)))) if data == PAYLOAD);
+
+        let frame = poll_frame_unpin(&mut body);
+        assert_matches!(frame, Poll::Ready(Some(Ok(Frame::Control(ResponseKind::Err(actual))))) if actual == code);
+
+        let frame = poll_frame_unpin(&mut body);
+        assert_matches!(frame, Poll::Ready(Some(Ok(Frame::Data(data)))) if data == b"error" as &[_]);
+
+        assert_matches!(poll_frame_unpin(&mut body), Poll::Ready(None));
+    }
+
+    #[test]
+    fn response_malformed_is_replaced_by_error() {
+        // well-formed header, but the block itself is garbage
+        let mut block = BytesMut::new();
+        block.put_u32(4);
+        block.put_u32(4);
+        block.put_slice(b"\xFF\xFF\xFF\xFF");
+
+        let body = StaticBody::<_, ResponseKind, !>::new([
+            Ok(Frame::Data(block.freeze())),
+            Ok(Frame::Data(Bytes::from_static(b"discarded"))),
+        ]);
+        let mut body = DecompressResponse::new(body, Some(Algorithm::Zstd));
+
+        let frame = poll_frame_unpin(&mut body);
+        assert_matches!(
+            frame,
+            Poll::Ready(Some(Ok(Frame::Control(ResponseKind::Err(code))))) if code == ErrorCode::MALFORMED_COMPRESSED_PAYLOAD
+        );
+
+        let frame = poll_frame_unpin(&mut body);
+        assert_matches!(frame, Poll::Ready(Some(Ok(Frame::Data(_)))));
+
+        assert_matches!(poll_frame_unpin(&mut body), Poll::Ready(None));
+    }
+}
@@ -1,4 +1,5 @@
 pub mod boxed;
+pub mod compression;
 pub mod controlled;
 pub mod empty;
 pub mod encode_error;
@@ -0,0 +1,320 @@
+use core::{
+    error::Error,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use futures::TryFutureExt as _;
+use harpc_codec::error::NetworkError;
+use harpc_types::{compression::Compression, error_code::ErrorCode, response_kind::ResponseKind};
+use tower::{Layer, Service, ServiceExt as _};
+
+use crate::{
+    body::{
+        Body,
+        compression::{
+            Algorithm, CompressRequest, CompressResponse, DecompressRequest, DecompressResponse,
+        },
+        controlled::Controlled,
+        full::Full,
+    },
+    either::Either,
+    request::Request,
+    response::{Parts, Response},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, derive_more::Display)]
+#[display("the requested compression {compression} is not supported")]
+struct UnsupportedCompression {
+    compression: Compression,
+}
+
+impl Error for UnsupportedCompression {
+    fn provide<'a>(&'a self, request: &mut core::error::Request<'a>) {
+        request.provide_value(ErrorCode::UNSUPPORTED_COMPRESSION);
+    }
+}
+
+/// Server-side layer that transparently handles the compression announced by the client.
+///
+/// The request payload is decompressed before it reaches the inner service and the payload of
+/// successful responses is compressed using the same algorithm. Requests announcing a compression
+/// that is not supported are rejected with [`ErrorCode::UNSUPPORTED_COMPRESSION`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CompressionLayer {
+    _private: (),
+}
+
+impl CompressionLayer {
+    #[expect(
+        clippy::new_without_default,
+        reason = "layer construction should be explicit and we might add fields in the future"
+    )]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl<S> Layer<S> for CompressionLayer {
+    type Service = CompressionService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CompressionService { inner }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CompressionService<S> {
+    inner: S,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for CompressionService<S>
+where
+    S: Service<Request<DecompressRequest<ReqBody>>, Response = Response<ResBody>> + Clone + Send,
+    ReqBody: Body<Control = !, Error = !>,
+    ResBody: Body<Control: AsRef<ResponseKind>>,
+{
+    type Error = S::Error;
+    type Response =
+        Response<Either<CompressResponse<ResBody>, Controlled<ResponseKind, Full<Bytes>>>>;
+
+    type Future = impl Future<Output = Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // we're always ready because we clone the inner service, therefore it is unused and always
+        // ready
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let clone = self.inner.clone();
+        let inner = core::mem::replace(&mut self.inner, clone);
+
+        let session = req.session();
+        let algorithm = req
+            .compression()
+            .map(|compression| Algorithm::from_compression(compression).ok_or(compression))
+            .transpose();
+
+        async move {
+            let algorithm = match algorithm {
+                Ok(algorithm) => algorithm,
+                Err(compression) => {
+                    let error =
+                        NetworkError::capture_error(&UnsupportedCompression { compression });
+
+                    return Ok(
+                        Response::from_error(Parts::new(session), error).map_body(Either::Right)
+                    );
+                }
+            };
+
+            let req = req.map_body(|body| DecompressRequest::new(body, algorithm));
+            let response = inner.oneshot(req).await?;
+
+            Ok(response.map_body(|body| Either::Left(CompressResponse::new(body, algorithm))))
+        }
+    }
+}
+
+/// Client-side layer that compresses requests and decompresses their responses.
+///
+/// The algorithm is only used if the request doesn't already announce a compression. If no
+/// algorithm is given, requests are sent uncompressed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ClientCompressionLayer {
+    algorithm: Option<Algorithm>,
+}
+
+impl ClientCompressionLayer {
+    #[must_use]
+    pub const fn new(algorithm: Option<Algorithm>) -> Self {
+        Self { algorithm }
+    }
+}
+
+impl<S> Layer<S> for ClientCompressionLayer {
+    type Service = ClientCompressionService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ClientCompressionService {
+            inner,
+            algorithm: self.algorithm,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ClientCompressionService<S> {
+    inner: S,
+    algorithm: Option<Algorithm>,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for ClientCompressionService<S>
+where
+    S: Service<Request<CompressRequest<ReqBody>>, Response = Response<ResBody>>,
+    ReqBody: Body<Control = !>,
+    ResBody: Body<Control: AsRef<ResponseKind>, Error = !>,
+{
+    type Error = S::Error;
+    type Response = Response<DecompressResponse<ResBody>>;
+
+    type Future = impl Future<Output = Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let (mut parts, body) = req.into_parts();
+
+        // compressions we don't know how to apply ourselves are dropped, as the server would
+        // otherwise interpret the uncompressed payload as compressed.
+        let algorithm = match parts.compression {
+            Some(compression) => Algorithm::from_compression(compression),
+            None => self.algorithm,
+        };
+        parts.compression = algorithm.map(Algorithm::compression);
+
+        let req = Request::from_parts(parts, CompressRequest::new(body, algorithm));
+
+        self.inner.call(req).map_ok(move |response| {
+            response.map_body(|body| DecompressResponse::new(body, algorithm))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::num::NonZero;
+
+    use bytes::Bytes;
+    use harpc_types::{
+        compression::Compression, error_code::ErrorCode, response_kind::ResponseKind,
+    };
+    use tokio_test::{assert_pending, assert_ready};
+    use tower::Layer as _;
+    use tower_test::mock::{self, spawn_with};
+
+    use crate::{
+        Extensions,
+        body::{
+            BodyExt as _, Frame,
+            compression::{Algorithm, DecompressRequest},
+            controlled::Controlled,
+            full::Full,
+        },
+        layer::{
+            compression::{CompressionLayer, CompressionService},
+            error::test::{BODY, request},
+        },
+        request::Request,
+        response::{self, Response},
+    };
+
+    type ReqBody = DecompressRequest<Full<Bytes>>;
+    type ResBody = Controlled<ResponseKind, Full<Bytes>>;
+
+    fn service() -> (
+        mock::Spawn<CompressionService<mock::Mock<Request<ReqBody>, Response<ResBody>>>>,
+        mock::Handle<Request<ReqBody>, Response<ResBody>>,
+    ) {
+        spawn_with(|mock| CompressionLayer::new().layer(mock))
+    }
+
+    fn compressed_request(compression: Compression, body: Bytes) -> Request<Full<Bytes>> {
+        let (mut parts, _) = request().into_parts();
+        parts.compression = Some(compression);
+
+        Request::from_parts(parts, Full::new(body))
+    }
+
+    #[tokio::test]
+    async fn compressed() {
+        let (mut service, mut handle) = service();
+
+        assert_pending!(handle.poll_request());
+        assert_ready!(service.poll_ready()).expect("should be ready");
+
+        let response = tokio::spawn(service.call(compressed_request(
+            Compression::LZ4,
+            Algorithm::Lz4.compress(Bytes::from_static(BODY)),
+        )));
+
+        let Some((mut actual, send_response)) = handle.next_request().await else {
+            panic!("expected a request, but non was received.");
+        };
+
+        let body = actual.body_mut();
+        let frame = body
+            .frame()
+            .await
+            .expect("frame should be present")
+            .expect("frame should be decompressed");
+
+        assert_eq!(frame, Frame::Data(Bytes::from_static(BODY)));
+
+        send_response.send_response(Response::from_parts(
+            response::Parts {
+                session: actual.session(),
+                extensions: Extensions::new(),
+            },
+            Controlled::new(
+                ResponseKind::Ok,
+                Full::new(Bytes::from_static(b"response" as &[_])),
+            ),
+        ));
+
+        let mut response = response
+            .await
+            .expect("should be able to join")
+            .expect("response should be present");
+
+        let body = response.body_mut();
+        let Ok(frame) = body.frame().await.expect("frame should be present");
+        let control = frame
+            .into_control()
+            .expect("should be control frame")
+            .into_inner();
+        assert_eq!(control, ResponseKind::Ok);
+
+        let Ok(frame) = body.frame().await.expect("frame should be present");
+        let data = frame
+            .into_data()
+            .expect("should be data frame")
+            .into_inner();
+        assert_eq!(
+            data,
+            Algorithm::Lz4.compress(Bytes::from_static(b"response" as &[_]))
+        );
+    }
+
+    #[tokio::test]
+    async fn unsupported_compression() {
+        let (mut service, mut handle) = service();
+
+        assert_pending!(handle.poll_request());
+        assert_ready!(service.poll_ready()).expect("should be ready");
+
+        let compression = Compression::new(NonZero::new(0xFF).expect("should be non-zero"));
+        let response = service
+            .call(compressed_request(compression, Bytes::from_static(BODY)))
+            .await
+            .expect("response should be present");
+
+        // the inner service is never invoked
+        assert_pending!(handle.poll_request());
+
+        let mut body = response.into_body();
+        let Ok(frame) = body.frame().await.expect("frame should be present");
+        let control = frame
+            .into_control()
+            .expect("should be control frame")
+            .into_inner();
+        assert_eq!(
+            control,
+            ResponseKind::Err(ErrorCode::UNSUPPORTED_COMPRESSION)
+        );
+    }
+}
@@ -204,6 +204,8 @@ pub(crate) mod test {
                 procedure: ProcedureDescriptor {
                     id: ProcedureId::new(0x00),
                 },
+                content_type: None,
+                compression: None,
                 session: mock_session_id(0x00),
                 extensions: Extensions::new(),
             },
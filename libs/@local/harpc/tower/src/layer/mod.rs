@@ -1,6 +1,7 @@
 pub mod body_error;
 pub mod body_report;
 pub mod boxed;
+pub mod compression;
 pub mod decode;
 pub mod decode_error;
 pub mod encode;
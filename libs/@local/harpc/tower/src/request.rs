@@ -1,5 +1,8 @@
 use harpc_net::session::server::{SessionId, transaction::TransactionContext};
-use harpc_types::{procedure::ProcedureDescriptor, subsystem::SubsystemDescriptor};
+use harpc_types::{
+    compression::Compression, content_type::ContentType, procedure::ProcedureDescriptor,
+    subsystem::SubsystemDescriptor,
+};
 
 use crate::extensions::Extensions;
 
@@ -9,6 +12,13 @@ pub struct Parts {
     pub subsystem: SubsystemDescriptor,
     pub procedure: ProcedureDescriptor,
 
+    /// The content type of the request and response payloads, if `None` the default codec is
+    /// used.
+    pub content_type: Option<ContentType>,
+    /// The compression of the request and response payloads, if `None` the payloads are sent
+    /// uncompressed.
+    pub compression: Option<Compression>,
+
     pub session: SessionId,
 
     pub extensions: Extensions,
@@ -20,6 +30,8 @@ impl Parts {
         Self {
             subsystem: context.subsystem(),
             procedure: context.procedure(),
+            content_type: context.content_type(),
+            compression: context.compression(),
             session: context.session(),
             extensions: Extensions::new(),
         }
@@ -51,6 +63,14 @@ impl<B> Request<B> {
         self.head.procedure
     }
 
+    pub const fn content_type(&self) -> Option<ContentType> {
+        self.head.content_type
+    }
+
+    pub const fn compression(&self) -> Option<Compression> {
+        self.head.compression
+    }
+
     pub const fn session(&self) -> SessionId {
         self.head.session
     }
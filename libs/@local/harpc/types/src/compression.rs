@@ -0,0 +1,41 @@
+use core::{fmt::Display, num::NonZero};
+
+/// The algorithm used to compress the payload of a transaction.
+///
+/// The compression is chosen by the client for every transaction and is transmitted in the request
+/// begin frame. Once chosen, it applies to both directions of the transaction: the request payload
+/// is compressed by the client and every successful response payload is compressed by the server.
+/// Error responses are never compressed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub struct Compression(NonZero<u8>);
+
+impl Compression {
+    /// Payload compressed using the [LZ4](https://lz4.org/) block format.
+    pub const LZ4: Self = Self(NonZero::new(0x02).expect("value is not 0"));
+    /// Payload compressed using [Zstandard](https://facebook.github.io/zstd/).
+    pub const ZSTD: Self = Self(NonZero::new(0x01).expect("value is not 0"));
+
+    #[must_use]
+    pub const fn new(value: NonZero<u8>) -> Self {
+        Self(value)
+    }
+
+    #[must_use]
+    pub const fn value(self) -> NonZero<u8> {
+        self.0
+    }
+}
+
+impl Display for Compression {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Self(value) = self;
+
+        match value.get() {
+            0x01 => fmt.write_str("zstd"),
+            0x02 => fmt.write_str("lz4"),
+            _ => write!(fmt, "{value:#04x}"),
+        }
+    }
+}
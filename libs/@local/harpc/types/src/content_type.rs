@@ -0,0 +1,39 @@
+use core::{fmt::Display, num::NonZero};
+
+/// The format used to encode the payload of a transaction.
+///
+/// The content type is chosen by the client for every transaction and is transmitted in the
+/// request begin frame. A server is free to reject a content type it doesn't support.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "proptest", derive(test_strategy::Arbitrary))]
+pub struct ContentType(NonZero<u8>);
+
+impl ContentType {
+    /// Records encoded as JSON, each terminated by the ASCII record separator (`0x1E`).
+    pub const JSON: Self = Self(NonZero::new(0x01).expect("value is not 0"));
+    /// Records encoded as MessagePack, each prefixed by its length as a big-endian `u32`.
+    pub const MESSAGE_PACK: Self = Self(NonZero::new(0x02).expect("value is not 0"));
+
+    #[must_use]
+    pub const fn new(value: NonZero<u8>) -> Self {
+        Self(value)
+    }
+
+    #[must_use]
+    pub const fn value(self) -> NonZero<u8> {
+        self.0
+    }
+}
+
+impl Display for ContentType {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let Self(value) = self;
+
+        match value.get() {
+            0x01 => fmt.write_str("json"),
+            0x02 => fmt.write_str("msgpack"),
+            _ => write!(fmt, "{value:#04x}"),
+        }
+    }
+}
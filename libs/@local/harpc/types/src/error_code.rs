@@ -99,7 +99,15 @@ define_error_code_consts! {
         /// The HTTP equivalent is 403 Forbidden.
         FORBIDDEN,
         /// The amount of items in the request stream does not match the expected amount.
-        REQUEST_EXPECTED_ITEM_COUNT_MISMATCH
+        REQUEST_EXPECTED_ITEM_COUNT_MISMATCH,
+        /// The content type requested by the client is not supported by the server.
+        ///
+        /// The HTTP equivalent is 415 Unsupported Media Type.
+        UNSUPPORTED_CONTENT_TYPE,
+        /// The compression requested by the client is not supported by the server.
+        ///
+        /// The HTTP equivalent is 415 Unsupported Media Type.
+        UNSUPPORTED_COMPRESSION
     ],
     // 0xFF_xx = server errors
     /// Errors that occur in a session and are issued by the server.
@@ -139,7 +147,13 @@ define_error_code_consts! {
         ///
         /// This is a fault in the implementation of the server, either in the `codec` or
         /// the `tower` layer.
-        PARTIAL_NETWORK_ERROR
+        PARTIAL_NETWORK_ERROR,
+        /// Compressed payload could not be decompressed.
+        ///
+        /// The payload was either truncated, or not compressed using the announced algorithm.
+        ///
+        /// This is a fault in the implementation of the peer, most likely in the `tower` layer.
+        MALFORMED_COMPRESSED_PAYLOAD
     ],
     /// Generic server errors.
     0xFF_F0 => [
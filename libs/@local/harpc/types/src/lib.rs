@@ -1,5 +1,7 @@
 #![feature(macro_metavar_expr, never_type)]
 
+pub mod compression;
+pub mod content_type;
 pub mod error_code;
 pub mod procedure;
 pub mod response_kind;
@@ -1,6 +1,11 @@
+use core::num::NonZero;
+
 use bytes::{Buf, BufMut};
 use error_stack::Report;
-use harpc_types::{procedure::ProcedureId, subsystem::SubsystemId, version::Version};
+use harpc_types::{
+    compression::Compression, content_type::ContentType, procedure::ProcedureId,
+    subsystem::SubsystemId, version::Version,
+};
 
 use super::{Buffer, BufferError, Decode};
 use crate::codec::Encode;
@@ -80,11 +85,64 @@ impl Decode for SubsystemId {
     }
 }
 
+// `0x00` signals that the client did not request a specific content type.
+impl Encode for Option<ContentType> {
+    type Error = BufferError;
+
+    fn encode<B>(&self, buffer: &mut Buffer<B>) -> Result<(), Report<Self::Error>>
+    where
+        B: BufMut,
+    {
+        self.map_or(0, |content_type| content_type.value().get())
+            .encode(buffer)
+    }
+}
+
+impl Decode for Option<ContentType> {
+    type Context = ();
+    type Error = BufferError;
+
+    fn decode<B>(buffer: &mut Buffer<B>, (): ()) -> Result<Self, Report<Self::Error>>
+    where
+        B: Buf,
+    {
+        u8::decode(buffer, ()).map(|value| NonZero::new(value).map(ContentType::new))
+    }
+}
+
+// `0x00` signals that the payload is not compressed.
+impl Encode for Option<Compression> {
+    type Error = BufferError;
+
+    fn encode<B>(&self, buffer: &mut Buffer<B>) -> Result<(), Report<Self::Error>>
+    where
+        B: BufMut,
+    {
+        self.map_or(0, |compression| compression.value().get())
+            .encode(buffer)
+    }
+}
+
+impl Decode for Option<Compression> {
+    type Context = ();
+    type Error = BufferError;
+
+    fn decode<B>(buffer: &mut Buffer<B>, (): ()) -> Result<Self, Report<Self::Error>>
+    where
+        B: Buf,
+    {
+        u8::decode(buffer, ()).map(|value| NonZero::new(value).map(Compression::new))
+    }
+}
+
 #[cfg(test)]
 mod test {
     #![expect(clippy::needless_raw_strings)]
     use expect_test::expect;
-    use harpc_types::{subsystem::SubsystemId, version::Version};
+    use harpc_types::{
+        compression::Compression, content_type::ContentType, subsystem::SubsystemId,
+        version::Version,
+    };
 
     use crate::codec::test::{assert_codec, assert_decode, assert_encode};
 
@@ -120,6 +178,51 @@ mod test {
         assert_decode(&[0x12_u8, 0x34] as &[_], &SubsystemId::new(0x1234), ());
     }
 
+    #[test]
+    fn encode_content_type() {
+        assert_encode(&Some(ContentType::MESSAGE_PACK), expect![[r#"
+                0x02
+            "#]]);
+    }
+
+    #[test]
+    fn encode_content_type_unspecified() {
+        assert_encode(&None::<ContentType>, expect![[r#"
+                0x00
+            "#]]);
+    }
+
+    #[test]
+    fn decode_content_type() {
+        assert_decode(&[0x01_u8] as &[_], &Some(ContentType::JSON), ());
+        assert_decode(&[0x00_u8] as &[_], &None::<ContentType>, ());
+    }
+
+    #[test]
+    fn encode_compression() {
+        assert_encode(&Some(Compression::ZSTD), expect![[r#"
+                0x01
+            "#]]);
+    }
+
+    #[test]
+    fn decode_compression() {
+        assert_decode(&[0x02_u8] as &[_], &Some(Compression::LZ4), ());
+        assert_decode(&[0x00_u8] as &[_], &None::<Compression>, ());
+    }
+
+    #[test_strategy::proptest]
+    #[cfg_attr(miri, ignore)]
+    fn codec_compression(compression: Option<Compression>) {
+        assert_codec(&compression, ());
+    }
+
+    #[test_strategy::proptest]
+    #[cfg_attr(miri, ignore)]
+    fn codec_content_type(content_type: Option<ContentType>) {
+        assert_codec(&content_type, ());
+    }
+
     #[test_strategy::proptest]
     #[cfg_attr(miri, ignore)]
     fn codec_subsystem_id(id: SubsystemId) {
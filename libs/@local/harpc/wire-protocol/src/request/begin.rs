@@ -1,6 +1,9 @@
 use bytes::{Buf, BufMut};
 use error_stack::{Report, ResultExt as _};
-use harpc_types::{procedure::ProcedureDescriptor, subsystem::SubsystemDescriptor};
+use harpc_types::{
+    compression::Compression, content_type::ContentType, procedure::ProcedureDescriptor,
+    subsystem::SubsystemDescriptor,
+};
 
 use crate::{
     codec::{Buffer, BufferError, Decode, Encode},
@@ -18,6 +21,12 @@ pub struct RequestBegin {
     pub subsystem: SubsystemDescriptor,
     pub procedure: ProcedureDescriptor,
 
+    /// The content type of the request and response payloads, if `None` the server decides.
+    pub content_type: Option<ContentType>,
+    /// The compression of the request and successful response payloads, if `None` the payloads
+    /// are not compressed.
+    pub compression: Option<Compression>,
+
     pub payload: Payload,
 }
 
@@ -36,9 +45,17 @@ impl Encode for RequestBegin {
             .encode(buffer)
             .change_context(RequestBeginEncodeError)?;
 
-        // write 13 empty bytes (reserved for future use)
+        self.content_type
+            .encode(buffer)
+            .change_context(RequestBeginEncodeError)?;
+
+        self.compression
+            .encode(buffer)
+            .change_context(RequestBeginEncodeError)?;
+
+        // write 11 empty bytes (reserved for future use)
         buffer
-            .push_repeat(0, 13)
+            .push_repeat(0, 11)
             .change_context(RequestBeginEncodeError)?;
 
         self.payload
@@ -57,15 +74,19 @@ impl Decode for RequestBegin {
     {
         let subsystem = SubsystemDescriptor::decode(buffer, ())?;
         let procedure = ProcedureDescriptor::decode(buffer, ())?;
+        let content_type = Option::<ContentType>::decode(buffer, ())?;
+        let compression = Option::<Compression>::decode(buffer, ())?;
 
-        // skip 13 bytes (reserved for future use)
-        buffer.discard(13)?;
+        // skip 11 bytes (reserved for future use)
+        buffer.discard(11)?;
 
         let payload = Payload::decode(buffer, ())?;
 
         Ok(Self {
             subsystem,
             procedure,
+            content_type,
+            compression,
             payload,
         })
     }
@@ -75,6 +96,8 @@ impl Decode for RequestBegin {
 mod test {
     use expect_test::expect;
     use harpc_types::{
+        compression::Compression,
+        content_type::ContentType,
         procedure::{ProcedureDescriptor, ProcedureId},
         subsystem::{SubsystemDescriptor, SubsystemId},
         version::Version,
@@ -97,6 +120,8 @@ mod test {
         procedure: ProcedureDescriptor {
             id: ProcedureId::new(0x05_06),
         },
+        content_type: Some(ContentType::JSON),
+        compression: Some(Compression::LZ4),
         payload: Payload::from_static(b"Hello, world!"),
     };
 
@@ -104,15 +129,16 @@ mod test {
         0x01, 0x02, // subsystem id
         0x03, 0x04, // subsystem version
         0x05, 0x06, // procedure id
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, // reserved
+        0x01, // content type
+        0x02, // compression
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // reserved
         0x00, 0x0D, b'H', b'e', b'l', b'l', b'o', b',', b' ', b'w', b'o', b'r', b'l', b'd', b'!',
     ];
 
     #[test]
     fn encode() {
         assert_encode(&EXAMPLE_REQUEST, expect![[r"
-                0x01 0x02 0x03 0x04 0x05 0x06 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00
+                0x01 0x02 0x03 0x04 0x05 0x06 0x01 0x02 0x00 0x00 0x00 0x00 0x00 0x00 0x00 0x00
                 0x00 0x00 0x00 0x00 '\r' b'H' b'e' b'l' b'l' b'o' b',' b' ' b'w' b'o' b'r' b'l'
                 b'd' b'!'
             "]]);
@@ -133,6 +159,8 @@ mod test {
                 procedure: ProcedureDescriptor {
                     id: ProcedureId::new(0x05_06),
                 },
+                content_type: Some(ContentType::JSON),
+                compression: Some(Compression::LZ4),
                 payload: Payload::from_static(b"Hello, world!"),
             },
             (),
@@ -132,6 +132,8 @@ mod test {
         procedure: ProcedureDescriptor {
             id: ProcedureId::new(0x0506),
         },
+        content_type: None,
+        compression: None,
         payload: Payload::from_static(&[0x07, 0x08]),
     };
 
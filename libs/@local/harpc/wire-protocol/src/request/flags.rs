@@ -16,6 +16,8 @@ pub enum RequestFlag {
     BeginOfRequest = 0b1000_0000,
     // Controlled flags
     EndOfRequest = 0b0000_0001,
+    /// The transaction payload carried by the packet is compressed.
+    Compressed = 0b0000_0010,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -120,6 +122,13 @@ mod test {
                 0x01
             "#
         ]]);
+
+        assert_encode(
+            &RequestFlags::from(RequestFlag::BeginOfRequest | RequestFlag::Compressed),
+            expect![[r#"
+                0x82
+            "#]],
+        );
     }
 
     #[test]
@@ -214,6 +214,8 @@ mod test {
                         id: ProcedureId::new(0x05_06),
                     },
 
+                    content_type: None,
+                    compression: None,
                     payload: Payload::from_static(b"hello world"),
                 }),
             },
@@ -242,6 +244,8 @@ mod test {
                         id: ProcedureId::new(0x05_06),
                     },
 
+                    content_type: None,
+                    compression: None,
                     payload: Payload::from_static(b"hello world"),
                 }),
             },
@@ -311,6 +315,8 @@ mod test {
                         id: ProcedureId::new(0x05_06),
                     },
 
+                    content_type: None,
+                    compression: None,
                     payload: Payload::from_static(b"hello world"),
                 }),
             },
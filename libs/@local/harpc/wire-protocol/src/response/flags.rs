@@ -16,6 +16,8 @@ pub enum ResponseFlag {
     BeginOfResponse = 0b1000_0000,
     // Controlled flags
     EndOfResponse = 0b0000_0001,
+    /// The transaction payload carried by the packet is compressed.
+    Compressed = 0b0000_0010,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -116,6 +118,12 @@ mod test {
             &ResponseFlags::from(ResponseFlag::EndOfResponse),
             (),
         );
+
+        assert_decode(
+            &[0x03_u8] as &[_],
+            &ResponseFlags::from(ResponseFlag::EndOfResponse | ResponseFlag::Compressed),
+            (),
+        );
     }
 
     #[test_strategy::proptest]
@@ -47,6 +47,26 @@ impl<'a, S> Diagnostic<'a, S> {
             help: None,
         }
     }
+
+    /// Transform the spans of all labels of the diagnostic.
+    ///
+    /// This is useful to convert a diagnostic into a span representation, which is independent
+    /// of any span storage, for example to send it to a client.
+    pub fn map_spans<T>(self, mut transform: impl FnMut(S) -> T) -> Diagnostic<'a, T> {
+        Diagnostic {
+            category: self.category,
+            severity: self.severity,
+            message: self.message,
+
+            labels: self
+                .labels
+                .into_iter()
+                .map(|label| label.map_span(&mut transform))
+                .collect(),
+            note: self.note,
+            help: self.help,
+        }
+    }
 }
 
 impl<'a> Diagnostic<'a, SpanId> {
@@ -17,6 +17,11 @@ impl Help {
         }
     }
 
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
     #[must_use]
     pub const fn with_color(mut self, color: Color) -> Self {
         self.color = Some(color);
@@ -33,6 +33,16 @@ impl<S> Label<S> {
         }
     }
 
+    #[must_use]
+    pub const fn span(&self) -> &S {
+        &self.span
+    }
+
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
     #[must_use]
     pub const fn with_order(mut self, order: i32) -> Self {
         self.order = Some(order);
@@ -65,6 +75,17 @@ impl<S> Label<S> {
         self.color = Some(color);
         self
     }
+
+    /// Transform the span of the label, keeping all other properties intact.
+    pub fn map_span<T>(self, transform: impl FnOnce(S) -> T) -> Label<T> {
+        Label {
+            span: transform(self.span),
+            message: self.message,
+            order: self.order,
+            priority: self.priority,
+            color: self.color,
+        }
+    }
 }
 
 impl Label<SpanId> {
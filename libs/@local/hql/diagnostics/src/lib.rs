@@ -15,5 +15,7 @@ pub mod span;
 #[cfg(feature = "serde")]
 pub(crate) mod encoding;
 pub mod rob;
+#[cfg(feature = "sarif")]
+mod sarif;
 
 pub use diagnostic::Diagnostic;
@@ -0,0 +1,152 @@
+use alloc::borrow::Cow;
+
+use ::sarif::schema::{
+    ArtifactContent, ArtifactLocation, Location, Message, PhysicalLocation, PropertyBag, Region,
+    Result as SarifResult, ResultLevel,
+};
+use hql_span::{TextRange, tree::SpanNode};
+
+use crate::{Diagnostic, severity::Severity, span::TransformSpan};
+
+fn text_message(text: impl Into<Cow<'static, str>>) -> Message<'static> {
+    Message {
+        text: Some(text.into()),
+        markdown: None,
+        id: None,
+        arguments: Vec::new(),
+        properties: PropertyBag::new(),
+    }
+}
+
+/// Returns the 1-based line and column of the given byte offset.
+///
+/// Columns are measured in UTF-16 code units, which is the default column kind of SARIF.
+fn line_column(source: &str, offset: usize) -> Option<(usize, usize)> {
+    let prefix = source.get(..offset)?;
+
+    let line_start = prefix.rfind('\n').map_or(0, |index| index + 1);
+    let line = prefix.matches('\n').count() + 1;
+    let column = prefix[line_start..].encode_utf16().count() + 1;
+
+    Some((line, column))
+}
+
+fn region(source: &str, range: TextRange) -> Region<'static> {
+    let start = usize::from(range.start());
+    let end = usize::from(range.end());
+
+    let mut region = match (line_column(source, start), line_column(source, end)) {
+        (Some(start), Some(end)) => Region::new(start, end),
+        _ => Region::default(),
+    };
+
+    region.byte_offset = Some(start);
+    region.byte_length = Some(end - start);
+
+    if let Some(snippet) = source.get(start..end) {
+        region = region.with_snippet(ArtifactContent::from_text(snippet.to_owned()));
+    }
+
+    region
+}
+
+const fn level(severity: &Severity) -> ResultLevel {
+    match severity.code() {
+        400.. => ResultLevel::Error,
+        300..400 => ResultLevel::Warning,
+        200..300 => ResultLevel::Note,
+        _ => ResultLevel::None,
+    }
+}
+
+impl<S> Diagnostic<'_, SpanNode<S>> {
+    /// Convert the diagnostic into a SARIF result.
+    ///
+    /// The canonical id of the category is used as rule id. The first label of the diagnostic is
+    /// reported as the location of the result, every other label is added as related location.
+    /// Notes and help messages are appended to the message of the result.
+    ///
+    /// `source` is the text of the artifact the spans refer to, it is used to determine the line
+    /// and column of the regions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use hql_diagnostics::{
+    ///     Diagnostic, category::Category, label::Label, severity::Severity, span::DiagnosticSpan,
+    /// };
+    /// use hql_span::{TextRange, tree::SpanNode};
+    /// use sarif::schema::{ArtifactLocation, ResultLevel};
+    ///
+    /// const CATEGORY: &Category = &Category {
+    ///     id: Cow::Borrowed("unknown-variable"),
+    ///     name: Cow::Borrowed("Unknown variable"),
+    ///     parent: None,
+    /// };
+    ///
+    /// let source = "[\"let\", \"x\", 1, \"y\"]";
+    ///
+    /// let mut diagnostic = Diagnostic::new(CATEGORY, Severity::ERROR);
+    /// diagnostic.labels.push(Label::new(
+    ///     SpanNode {
+    ///         value: DiagnosticSpan {
+    ///             range: TextRange::new(16.into(), 19.into()),
+    ///             parent_id: None,
+    ///         },
+    ///         parent: None,
+    ///     },
+    ///     "`y` is not defined",
+    /// ));
+    ///
+    /// let result = diagnostic.sarif(ArtifactLocation::new("query.json"), source, ());
+    ///
+    /// assert_eq!(result.rule_id.as_deref(), Some("unknown-variable"));
+    /// assert_eq!(result.level, Some(ResultLevel::Error));
+    /// assert_eq!(result.locations.len(), 1);
+    /// ```
+    pub fn sarif(
+        &self,
+        artifact_location: ArtifactLocation<'static>,
+        source: &str,
+        mut transform_span: impl TransformSpan<S>,
+    ) -> SarifResult<'static> {
+        let category = self.category.as_ref();
+
+        let mut text = self.message.as_deref().unwrap_or(&category.name).to_owned();
+
+        if let Some(note) = &self.note {
+            text.push_str("\n\nNote: ");
+            text.push_str(note.message());
+        }
+
+        if let Some(help) = &self.help {
+            text.push_str("\n\nHelp: ");
+            text.push_str(help.message());
+        }
+
+        let mut result = SarifResult::new(text_message(text))
+            .with_rule_id(category.canonical_id().to_string())
+            .with_level(level(self.severity.as_ref()));
+
+        for (index, label) in self.labels.iter().enumerate() {
+            let range = crate::span::absolute_span(label.span(), &mut transform_span);
+
+            let physical_location =
+                PhysicalLocation::new(artifact_location.clone()).with_region(region(source, range));
+            let location = Location::new(physical_location)
+                .with_message(text_message(label.message().to_owned()));
+
+            result = if index == 0 {
+                result.with_location(location)
+            } else {
+                let mut location = location;
+                location.id = Some(index);
+                result.with_related_location(location)
+            };
+        }
+
+        result
+    }
+}
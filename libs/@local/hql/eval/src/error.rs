@@ -0,0 +1,9 @@
+use alloc::borrow::Cow;
+
+use hql_diagnostics::category::Category;
+
+pub(crate) const EVAL_CATEGORY: &Category = &Category {
+    id: Cow::Borrowed("eval"),
+    name: Cow::Borrowed("Evaluation"),
+    parent: None,
+};
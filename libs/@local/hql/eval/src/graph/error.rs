@@ -0,0 +1,202 @@
+use alloc::borrow::Cow;
+use core::fmt::Display;
+
+use hql_diagnostics::{
+    Diagnostic, category::Category, help::Help, label::Label, note::Note, rob::RefOrBox,
+    severity::Severity,
+};
+use hql_span::SpanId;
+
+use crate::error::EVAL_CATEGORY;
+
+pub(crate) const GRAPH: &Category = &Category {
+    id: Cow::Borrowed("graph"),
+    name: Cow::Borrowed("Graph Query"),
+    parent: Some(RefOrBox::Ref(EVAL_CATEGORY)),
+};
+
+pub(crate) const UNKNOWN_FUNCTION: &Category = &Category {
+    id: Cow::Borrowed("unknown-function"),
+    name: Cow::Borrowed("Unknown Function"),
+    parent: Some(RefOrBox::Ref(GRAPH)),
+};
+
+pub(crate) const INVALID_ARITY: &Category = &Category {
+    id: Cow::Borrowed("invalid-arity"),
+    name: Cow::Borrowed("Invalid number of arguments"),
+    parent: Some(RefOrBox::Ref(GRAPH)),
+};
+
+pub(crate) const UNKNOWN_VARIABLE: &Category = &Category {
+    id: Cow::Borrowed("unknown-variable"),
+    name: Cow::Borrowed("Unknown Variable"),
+    parent: Some(RefOrBox::Ref(GRAPH)),
+};
+
+pub(crate) const INVALID_PATH: &Category = &Category {
+    id: Cow::Borrowed("invalid-path"),
+    name: Cow::Borrowed("Invalid Path"),
+    parent: Some(RefOrBox::Ref(GRAPH)),
+};
+
+pub(crate) const INVALID_KEY: &Category = &Category {
+    id: Cow::Borrowed("invalid-key"),
+    name: Cow::Borrowed("Invalid Key"),
+    parent: Some(RefOrBox::Ref(GRAPH)),
+};
+
+pub(crate) const EXPECTED_CONDITION: &Category = &Category {
+    id: Cow::Borrowed("expected-condition"),
+    name: Cow::Borrowed("Expected Condition"),
+    parent: Some(RefOrBox::Ref(GRAPH)),
+};
+
+pub(crate) const EXPECTED_OPERAND: &Category = &Category {
+    id: Cow::Borrowed("expected-operand"),
+    name: Cow::Borrowed("Expected Operand"),
+    parent: Some(RefOrBox::Ref(GRAPH)),
+};
+
+pub(crate) const INVALID_CONSTANT: &Category = &Category {
+    id: Cow::Borrowed("invalid-constant"),
+    name: Cow::Borrowed("Invalid Constant"),
+    parent: Some(RefOrBox::Ref(GRAPH)),
+};
+
+pub(crate) fn unknown_function(span: SpanId, name: impl Display) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(UNKNOWN_FUNCTION, Severity::ERROR);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, format!("Unknown function `{name}`")));
+
+    diagnostic.help = Some(Help::new(
+        "Available functions are: `and`, `or`, `not`, `==`, `!=`, `>`, `>=`, `<`, `<=`, \
+         `startsWith`, `endsWith`, `containsSegment`, `cosineDistance` and `get`",
+    ));
+
+    diagnostic
+}
+
+pub(crate) fn invalid_arity(
+    span: SpanId,
+    name: &str,
+    expected: impl Display,
+    actual: usize,
+) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(INVALID_ARITY, Severity::ERROR);
+
+    diagnostic.labels.push(Label::new(
+        span,
+        format!("`{name}` expects {expected} arguments, but {actual} were given"),
+    ));
+
+    diagnostic
+}
+
+pub(crate) fn unknown_variable(
+    span: SpanId,
+    name: &str,
+    expected: &str,
+) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(UNKNOWN_VARIABLE, Severity::ERROR);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, format!("Cannot find variable `{name}`")));
+
+    diagnostic.help = Some(Help::new(format!(
+        "Paths must start with the queried record, e.g. `{expected}::<field>`"
+    )));
+
+    diagnostic
+}
+
+pub(crate) fn invalid_path(span: SpanId, error: impl Display) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(INVALID_PATH, Severity::ERROR);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, "Path cannot be resolved"));
+
+    diagnostic.note = Some(Note::new(error.to_string()));
+
+    diagnostic
+}
+
+pub(crate) fn invalid_key(span: SpanId) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(INVALID_KEY, Severity::ERROR);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, "Expected a string or number constant"));
+
+    diagnostic.help = Some(Help::new(
+        "Keys are used to index into a path, e.g. `[\"get\", \"entity::properties\", {\"const\": \
+         \"https://example.com/property-type/name/\"}]`",
+    ));
+
+    diagnostic
+}
+
+pub(crate) fn expected_condition(span: SpanId) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(EXPECTED_CONDITION, Severity::ERROR);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, "Expected condition"));
+
+    diagnostic.help = Some(Help::new(
+        "A condition is a function call, such as `==` or `and`, a path to a boolean value or a \
+         boolean constant",
+    ));
+
+    diagnostic
+}
+
+pub(crate) fn expected_operand(span: SpanId) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(EXPECTED_OPERAND, Severity::ERROR);
+
+    diagnostic.labels.push(Label::new(span, "Expected operand"));
+
+    diagnostic.help = Some(Help::new(
+        "An operand is either a path, a `get` call or a constant",
+    ));
+
+    diagnostic
+}
+
+pub(crate) fn unexpected_null(span: SpanId) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(INVALID_CONSTANT, Severity::ERROR);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, "`null` is not allowed here"));
+
+    diagnostic.help = Some(Help::new("`null` can only be compared using `==` and `!=`"));
+
+    diagnostic
+}
+
+pub(crate) fn unsupported_type_annotation(span: SpanId) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(INVALID_CONSTANT, Severity::ERROR);
+
+    diagnostic.labels.push(Label::new(
+        span,
+        "Type annotations are not supported in filters",
+    ));
+
+    diagnostic
+}
+
+pub(crate) fn invalid_constant(span: SpanId, error: impl Display) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(INVALID_CONSTANT, Severity::ERROR);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, "Constant cannot be used as a parameter"));
+
+    diagnostic.note = Some(Note::new(error.to_string()));
+
+    diagnostic
+}
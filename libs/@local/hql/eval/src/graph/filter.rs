@@ -0,0 +1,484 @@
+use core::str::FromStr as _;
+
+use hash_graph_store::filter::{Filter, FilterExpression, Parameter};
+use hql_cst::{
+    expr::{Expr, ExprKind, call::Call, constant::Constant, path::Path},
+    value::{Value, ValueKind},
+};
+use hql_diagnostics::Diagnostic;
+use hql_span::SpanId;
+use serde::Deserialize;
+use serde_json::{Number as JsonNumber, Value as JsonValue};
+
+use super::{
+    QueryRoot,
+    error::{
+        expected_condition, expected_operand, invalid_arity, invalid_constant, invalid_key,
+        invalid_path, unexpected_null, unknown_function, unknown_variable,
+        unsupported_type_annotation,
+    },
+};
+
+/// Lowers a J-Expr condition into a [`Filter`].
+///
+/// # Errors
+///
+/// Returns a diagnostic pointing at the offending expression if the expression is not a valid
+/// condition, e.g. if an unknown function is called or a path cannot be resolved.
+pub fn lower_filter<'p, R>(
+    expr: &Expr<'_, '_>,
+) -> Result<Filter<'p, R>, Diagnostic<'static, SpanId>>
+where
+    R: QueryRoot<QueryPath<'p>: Deserialize<'p>>,
+{
+    match &expr.kind {
+        ExprKind::Call(call) => lower_call(call, expr.span),
+        ExprKind::Path(path) => Ok(is_true(resolve_path(path, Vec::new())?)),
+        ExprKind::Constant(Constant {
+            value:
+                Value {
+                    kind: ValueKind::Bool(value),
+                    ..
+                },
+            r#type: None,
+        }) => Ok(if *value {
+            Filter::All(Vec::new())
+        } else {
+            Filter::Any(Vec::new())
+        }),
+        ExprKind::Constant(_) | ExprKind::Signature(_) => Err(expected_condition(expr.span)),
+    }
+}
+
+fn is_true<'p, R>(path: FilterExpression<'p, R>) -> Filter<'p, R>
+where
+    R: QueryRoot,
+{
+    Filter::Equal(
+        Some(path),
+        Some(FilterExpression::Parameter {
+            parameter: Parameter::Boolean(true),
+            convert: None,
+        }),
+    )
+}
+
+fn function_name<'c>(call: &'c Call<'_, '_>) -> Result<&'c str, Diagnostic<'static, SpanId>> {
+    let ExprKind::Path(path) = &call.r#fn.kind else {
+        return Err(unknown_function(call.r#fn.span, "<expression>"));
+    };
+
+    match &*path.segments {
+        [symbol] => Ok(symbol.value.as_str()),
+        _ => Err(unknown_function(path.span, path)),
+    }
+}
+
+fn arguments<'c, 'arena, 'source, const N: usize>(
+    call: &'c Call<'arena, 'source>,
+    name: &str,
+    span: SpanId,
+) -> Result<&'c [Expr<'arena, 'source>; N], Diagnostic<'static, SpanId>> {
+    <&[Expr; N]>::try_from(&*call.args).map_err(|_| invalid_arity(span, name, N, call.args.len()))
+}
+
+fn lower_call<'p, R>(
+    call: &Call<'_, '_>,
+    span: SpanId,
+) -> Result<Filter<'p, R>, Diagnostic<'static, SpanId>>
+where
+    R: QueryRoot<QueryPath<'p>: Deserialize<'p>>,
+{
+    let name = function_name(call)?;
+
+    let filter = match name {
+        "and" => Filter::All(
+            call.args
+                .iter()
+                .map(lower_filter::<'p, R>)
+                .collect::<Result<_, _>>()?,
+        ),
+        "or" => Filter::Any(
+            call.args
+                .iter()
+                .map(lower_filter::<'p, R>)
+                .collect::<Result<_, _>>()?,
+        ),
+        "not" => {
+            let [condition] = arguments(call, name, span)?;
+            Filter::Not(Box::new(lower_filter(condition)?))
+        }
+        "==" => {
+            let [lhs, rhs] = arguments(call, name, span)?;
+            Filter::Equal(lower_nullable_operand(lhs)?, lower_nullable_operand(rhs)?)
+        }
+        "!=" => {
+            let [lhs, rhs] = arguments(call, name, span)?;
+            Filter::NotEqual(lower_nullable_operand(lhs)?, lower_nullable_operand(rhs)?)
+        }
+        ">" => {
+            let [lhs, rhs] = arguments(call, name, span)?;
+            Filter::Greater(lower_operand(lhs)?, lower_operand(rhs)?)
+        }
+        ">=" => {
+            let [lhs, rhs] = arguments(call, name, span)?;
+            Filter::GreaterOrEqual(lower_operand(lhs)?, lower_operand(rhs)?)
+        }
+        "<" => {
+            let [lhs, rhs] = arguments(call, name, span)?;
+            Filter::Less(lower_operand(lhs)?, lower_operand(rhs)?)
+        }
+        "<=" => {
+            let [lhs, rhs] = arguments(call, name, span)?;
+            Filter::LessOrEqual(lower_operand(lhs)?, lower_operand(rhs)?)
+        }
+        "startsWith" => {
+            let [lhs, rhs] = arguments(call, name, span)?;
+            Filter::StartsWith(lower_operand(lhs)?, lower_operand(rhs)?)
+        }
+        "endsWith" => {
+            let [lhs, rhs] = arguments(call, name, span)?;
+            Filter::EndsWith(lower_operand(lhs)?, lower_operand(rhs)?)
+        }
+        "containsSegment" => {
+            let [lhs, rhs] = arguments(call, name, span)?;
+            Filter::ContainsSegment(lower_operand(lhs)?, lower_operand(rhs)?)
+        }
+        "cosineDistance" => {
+            let [lhs, rhs, max] = arguments(call, name, span)?;
+            Filter::CosineDistance(
+                lower_operand(lhs)?,
+                lower_operand(rhs)?,
+                lower_operand(max)?,
+            )
+        }
+        "get" => is_true(lower_get(call, span)?),
+        _ => return Err(unknown_function(call.r#fn.span, name)),
+    };
+
+    Ok(filter)
+}
+
+/// Lowers an operand, which may not be `null`.
+fn lower_operand<'p, R>(
+    expr: &Expr<'_, '_>,
+) -> Result<FilterExpression<'p, R>, Diagnostic<'static, SpanId>>
+where
+    R: QueryRoot<QueryPath<'p>: Deserialize<'p>>,
+{
+    lower_nullable_operand(expr)?.ok_or_else(|| unexpected_null(expr.span))
+}
+
+/// Lowers an operand, `null` is represented as `None`.
+fn lower_nullable_operand<'p, R>(
+    expr: &Expr<'_, '_>,
+) -> Result<Option<FilterExpression<'p, R>>, Diagnostic<'static, SpanId>>
+where
+    R: QueryRoot<QueryPath<'p>: Deserialize<'p>>,
+{
+    match &expr.kind {
+        ExprKind::Path(path) => resolve_path(path, Vec::new()).map(Some),
+        ExprKind::Call(call) if function_name(call).is_ok_and(|name| name == "get") => {
+            lower_get(call, expr.span).map(Some)
+        }
+        ExprKind::Constant(constant) => lower_constant(constant),
+        ExprKind::Call(_) | ExprKind::Signature(_) => Err(expected_operand(expr.span)),
+    }
+}
+
+/// Lowers `["get", path, ...keys]` by appending the keys to the path.
+fn lower_get<'p, R>(
+    call: &Call<'_, '_>,
+    span: SpanId,
+) -> Result<FilterExpression<'p, R>, Diagnostic<'static, SpanId>>
+where
+    R: QueryRoot<QueryPath<'p>: Deserialize<'p>>,
+{
+    let [target, keys @ ..] = &*call.args else {
+        return Err(invalid_arity(span, "get", "at least 1", 0));
+    };
+
+    let ExprKind::Path(path) = &target.kind else {
+        return Err(expected_operand(target.span));
+    };
+
+    let keys = keys
+        .iter()
+        .map(|key| match &key.kind {
+            ExprKind::Constant(Constant {
+                value:
+                    value @ Value {
+                        kind: ValueKind::String(_) | ValueKind::Number(_),
+                        ..
+                    },
+                r#type: None,
+            }) => value_to_json(value),
+            _ => Err(invalid_key(key.span)),
+        })
+        .collect::<Result<_, _>>()?;
+
+    resolve_path(path, keys)
+}
+
+/// Resolves a path relative to the queried record.
+///
+/// The first segment has to be the variable of the record, all following segments and the
+/// additional `keys` are used as tokens of the record's query path.
+fn resolve_path<'p, R>(
+    path: &Path<'_>,
+    keys: Vec<JsonValue>,
+) -> Result<FilterExpression<'p, R>, Diagnostic<'static, SpanId>>
+where
+    R: QueryRoot<QueryPath<'p>: Deserialize<'p>>,
+{
+    let [variable, segments @ ..] = &*path.segments else {
+        return Err(invalid_path(path.span, "path is empty"));
+    };
+
+    if variable.value.as_str() != R::VARIABLE {
+        return Err(unknown_variable(
+            variable.span,
+            &variable.value,
+            R::VARIABLE,
+        ));
+    }
+
+    let tokens = segments
+        .iter()
+        .map(|segment| JsonValue::String(segment.value.to_string()))
+        .chain(keys)
+        .collect();
+
+    <R::QueryPath<'p>>::deserialize(JsonValue::Array(tokens))
+        .map(|path| FilterExpression::Path { path })
+        .map_err(|error| invalid_path(path.span, error))
+}
+
+fn lower_constant<'p, R>(
+    constant: &Constant<'_, '_>,
+) -> Result<Option<FilterExpression<'p, R>>, Diagnostic<'static, SpanId>>
+where
+    R: QueryRoot,
+{
+    if let Some(r#type) = &constant.r#type {
+        return Err(unsupported_type_annotation(r#type.span));
+    }
+
+    let value = value_to_json(&constant.value)?;
+    if value.is_null() {
+        return Ok(None);
+    }
+
+    Parameter::deserialize(value)
+        .map(|parameter| {
+            Some(FilterExpression::Parameter {
+                parameter,
+                convert: None,
+            })
+        })
+        .map_err(|error| invalid_constant(constant.value.span, error))
+}
+
+fn value_to_json(value: &Value<'_, '_>) -> Result<JsonValue, Diagnostic<'static, SpanId>> {
+    Ok(match &value.kind {
+        ValueKind::Null => JsonValue::Null,
+        ValueKind::Bool(value) => JsonValue::Bool(*value),
+        ValueKind::Number(number) => JsonValue::Number(
+            JsonNumber::from_str(number.as_str())
+                .map_err(|error| invalid_constant(value.span, error))?,
+        ),
+        ValueKind::String(string) => JsonValue::String(string.to_string()),
+        ValueKind::Array(array) => {
+            JsonValue::Array(array.iter().map(value_to_json).collect::<Result<_, _>>()?)
+        }
+        ValueKind::Object(object) => JsonValue::Object(
+            object
+                .iter()
+                .map(|(key, value)| Ok((key.value.to_string(), value_to_json(value)?)))
+                .collect::<Result<_, _>>()?,
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::borrow::Cow;
+
+    use hash_graph_store::{
+        entity::EntityQueryPath,
+        filter::{Filter, FilterExpression, Parameter},
+    };
+    use hash_graph_types::knowledge::entity::Entity;
+    use hql_span::{TextRange, TextSize};
+    use serde::Deserialize as _;
+    use serde_json::json;
+
+    use crate::graph::compile_filter;
+
+    fn compile(source: &str) -> Filter<'static, Entity> {
+        compile_filter(source.as_bytes()).expect("should be a valid filter")
+    }
+
+    fn text(value: &str) -> Option<FilterExpression<'static, Entity>> {
+        Some(FilterExpression::Parameter {
+            parameter: Parameter::Text(Cow::Owned(value.to_owned())),
+            convert: None,
+        })
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            compile(r#"["==", "entity::uuid", {"const": "b5f2a2d0-5f4b-4b3b-8f3a-0c2d6f7d1e21"}]"#),
+            Filter::Equal(
+                Some(FilterExpression::Path {
+                    path: EntityQueryPath::Uuid
+                }),
+                text("b5f2a2d0-5f4b-4b3b-8f3a-0c2d6f7d1e21"),
+            )
+        );
+    }
+
+    #[test]
+    fn null_comparison() {
+        assert_eq!(
+            compile(r#"["!=", "entity::draftId", {"const": null}]"#),
+            Filter::NotEqual(
+                Some(FilterExpression::Path {
+                    path: EntityQueryPath::DraftId
+                }),
+                None,
+            )
+        );
+    }
+
+    #[test]
+    fn logical_operators() {
+        let archived = Filter::Equal(
+            Some(FilterExpression::Path {
+                path: EntityQueryPath::Archived,
+            }),
+            Some(FilterExpression::Parameter {
+                parameter: Parameter::Boolean(true),
+                convert: None,
+            }),
+        );
+
+        assert_eq!(
+            compile(r#"["and", ["not", "entity::archived"], ["or", "entity::archived"]]"#),
+            Filter::All(vec![
+                Filter::Not(Box::new(archived.clone())),
+                Filter::Any(vec![archived]),
+            ])
+        );
+    }
+
+    #[test]
+    fn property_access() {
+        assert_eq!(
+            compile(
+                r#"[">", ["get", "entity::properties", {"const": "https://example.com/property-type/age/"}], {"const": 21}]"#
+            ),
+            Filter::Greater(
+                FilterExpression::Path {
+                    path: EntityQueryPath::deserialize(json!([
+                        "properties",
+                        "https://example.com/property-type/age/"
+                    ]))
+                    .expect("should be a valid path")
+                },
+                FilterExpression::Parameter {
+                    parameter: Parameter::I32(21),
+                    convert: None,
+                },
+            )
+        );
+    }
+
+    #[track_caller]
+    fn assert_labeled(source: &str, expected_category: &str, expected_label: &str) {
+        let diagnostics =
+            compile_filter::<Entity>(source.as_bytes()).expect_err("should fail to compile");
+        let [diagnostic] = diagnostics.as_slice() else {
+            panic!("expected a single diagnostic, got {diagnostics:?}");
+        };
+
+        assert_eq!(diagnostic.category.as_ref().id, expected_category);
+
+        let [label] = diagnostic.labels.as_slice() else {
+            panic!("expected a single label, got {:?}", diagnostic.labels);
+        };
+
+        let start = source
+            .find(expected_label)
+            .expect("the expected label should be part of the source");
+        let expected = TextRange::at(
+            TextSize::try_from(start).expect("source should be small"),
+            TextSize::of(expected_label),
+        );
+
+        assert_eq!(*label.span(), expected);
+        assert_eq!(&source[*label.span()], expected_label);
+    }
+
+    #[test]
+    fn errors_point_to_source() {
+        assert_labeled(
+            r#"["==", "entity::unknown", {"const": 1}]"#,
+            "invalid-path",
+            "entity::unknown",
+        );
+
+        // function names are labeled including the quotes of the string they're written in
+        assert_labeled(
+            r#"["like", "entity::uuid", {"const": 1}]"#,
+            "unknown-function",
+            r#""like""#,
+        );
+
+        assert_labeled(
+            r#"["==", "web::uuid", {"const": 1}]"#,
+            "unknown-variable",
+            "web",
+        );
+    }
+
+    #[track_caller]
+    fn assert_categories(source: &str, expected_categories: &[&str]) {
+        let diagnostics =
+            compile_filter::<Entity>(source.as_bytes()).expect_err("should fail to type check");
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.category.as_ref().id.as_ref())
+                .collect::<Vec<_>>(),
+            expected_categories
+        );
+    }
+
+    #[test]
+    fn type_errors() {
+        assert_categories(r#"["startsWith", "entity::uuid", {"const": 1}]"#, &[
+            "type-mismatch",
+        ]);
+        assert_categories(r#"["<", {"const": 1}, {"const": "2"}]"#, &["type-mismatch"]);
+        assert_categories(r#"[">", "entity::uuid", {"const": true}]"#, &[
+            "unsatisfied-bound",
+        ]);
+        // All type errors are reported, not only the first one
+        assert_categories(
+            r#"["or", ["endsWith", "entity::uuid", {"const": 1}], ["containsSegment", {"const": 2}, "entity::uuid"]]"#,
+            &["type-mismatch", "type-mismatch"],
+        );
+    }
+
+    #[test]
+    fn record_fields_are_dynamic() {
+        compile(
+            r#"[">", ["get", "entity::properties", {"const": "https://example.com/property-type/age/"}], {"const": 21}]"#,
+        );
+        compile(r#"["startsWith", "entity::uuid", {"const": "b5f2"}]"#);
+        compile(r#"["not", "entity::archived"]"#);
+    }
+}
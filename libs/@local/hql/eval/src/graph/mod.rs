@@ -0,0 +1,164 @@
+//! Lowering of HQL expressions into graph [`Filter`]s.
+//!
+//! A filter is written as a J-Expr condition, which is lowered into the equivalent [`Filter`] of
+//! the graph store. The queried record is bound to a variable (e.g. `entity` when querying
+//! entities), whose fields are accessed using paths, like `entity::archived` or
+//! `entity::type::baseUrl`. The segments after the variable are resolved using the same tokens
+//! as the JSON representation of the record's query path.
+//!
+//! The following functions are available:
+//!
+//! | Function            | Filter                                  |
+//! | ------------------- | --------------------------------------- |
+//! | `and`, `or`, `not`  | [`Filter::All`], [`Filter::Any`], [`Filter::Not`] |
+//! | `==`, `!=`          | [`Filter::Equal`], [`Filter::NotEqual`] |
+//! | `>`, `>=`, `<`, `<=` | [`Filter::Greater`], [`Filter::GreaterOrEqual`], [`Filter::Less`], [`Filter::LessOrEqual`] |
+//! | `startsWith`, `endsWith`, `containsSegment` | [`Filter::StartsWith`], [`Filter::EndsWith`], [`Filter::ContainsSegment`] |
+//! | `cosineDistance`    | [`Filter::CosineDistance`]              |
+//!
+//! Segments, which are not valid symbols, such as property type URLs, are appended to a path with
+//! `get`:
+//!
+//! ```json
+//! ["and",
+//!   ["==", ["get", "entity::properties", {"const": "https://example.com/property-type/name/"}], {"const": "Alice"}],
+//!   ["not", "entity::archived"]
+//! ]
+//! ```
+//!
+//! After lowering, the expression is type checked against the signatures of these functions, e.g.
+//! `startsWith` only accepts strings and `>` only compares numbers or strings with each other.
+//! The fields of the queried record are dynamically typed, as their type depends on the path.
+pub(crate) mod error;
+mod filter;
+
+use alloc::sync::Arc;
+
+use hash_graph_store::filter::{Filter, QueryRecord};
+use hash_graph_types::{
+    knowledge::entity::Entity,
+    ontology::{DataTypeWithMetadata, EntityTypeWithMetadata, PropertyTypeWithMetadata},
+};
+use hql_cst::arena::Arena;
+use hql_diagnostics::Diagnostic;
+use hql_span::{SpanId, TextRange, TextSize, storage::SpanStorage, tree::SpanNode};
+use hql_syntax_jexpr::{Parser, span::Span};
+use hql_typeck::{
+    TypeChecker,
+    symbol::SymbolTable,
+    ty::{FunctionTy, Ty},
+};
+use serde::Deserialize;
+
+pub use self::filter::lower_filter;
+
+/// A record, which can be queried using HQL.
+pub trait QueryRoot: QueryRecord {
+    /// The name of the variable the queried record is bound to.
+    const VARIABLE: &'static str;
+}
+
+impl QueryRoot for Entity {
+    const VARIABLE: &'static str = "entity";
+}
+
+impl QueryRoot for EntityTypeWithMetadata {
+    const VARIABLE: &'static str = "entityType";
+}
+
+impl QueryRoot for PropertyTypeWithMetadata {
+    const VARIABLE: &'static str = "propertyType";
+}
+
+impl QueryRoot for DataTypeWithMetadata {
+    const VARIABLE: &'static str = "dataType";
+}
+
+/// Returns the symbols available in a filter of `R`.
+fn symbols<R: QueryRoot>() -> SymbolTable {
+    let mut symbols = SymbolTable::with_prelude();
+    symbols.define(R::VARIABLE, Ty::Dynamic);
+
+    for function in ["startsWith", "endsWith", "containsSegment"] {
+        symbols.define(
+            function,
+            Ty::Function(Box::new(FunctionTy::new(
+                [("value", Ty::String), ("segment", Ty::String)],
+                Ty::Bool,
+            ))),
+        );
+    }
+
+    symbols.define(
+        "cosineDistance",
+        Ty::Function(Box::new(FunctionTy::new(
+            [("lhs", Ty::Array), ("rhs", Ty::Array), ("max", Ty::Number)],
+            Ty::Bool,
+        ))),
+    );
+    symbols.define(
+        "get",
+        Ty::Function(Box::new(
+            FunctionTy::new([("target", Ty::Dynamic)], Ty::Dynamic)
+                .with_rest("keys", Ty::Union(vec![Ty::String, Ty::Int])),
+        )),
+    );
+
+    symbols
+}
+
+fn absolute_range(node: &SpanNode<Span>) -> TextRange {
+    // Nested spans, such as the segments of a path, are relative to the contents of the string
+    // they have been parsed from, which start after the opening quote of the parent token.
+    let offset = node.parent.as_deref().map_or_else(
+        || TextSize::new(0),
+        |parent| absolute_range(parent).start() + TextSize::new(1),
+    );
+
+    node.value.range + offset
+}
+
+/// Parses the J-Expr `source`, lowers it into a [`Filter`] and type checks it.
+///
+/// The spans of the returned diagnostics are resolved to absolute byte ranges into `source`, so
+/// they can be reported without access to the span storage.
+///
+/// # Errors
+///
+/// Returns a diagnostic if `source` is not a valid J-Expr expression, or if the expression cannot
+/// be lowered into a [`Filter`]. Otherwise, all diagnostics of the type checker are returned if
+/// the expression does not type check.
+pub fn compile_filter<'p, R>(
+    source: &[u8],
+) -> Result<Filter<'p, R>, Vec<Diagnostic<'static, TextRange>>>
+where
+    R: QueryRoot<QueryPath<'p>: Deserialize<'p>>,
+{
+    let arena = Arena::new();
+    let spans = Arc::new(SpanStorage::new());
+    let parser = Parser::new(&arena, Arc::clone(&spans));
+
+    parser
+        .parse_expr(source)
+        .and_then(|expr| Ok((lower_filter(&expr)?, expr)))
+        .map_err(|diagnostic| vec![diagnostic])
+        .and_then(|(filter, expr)| {
+            let mut checker = TypeChecker::new(symbols::<R>());
+            checker.check(&expr);
+            checker.finish().map(|_| filter)
+        })
+        .map_err(|diagnostics: Vec<Diagnostic<'static, SpanId>>| {
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| {
+                    diagnostic.map_spans(|span| {
+                        let node = spans
+                            .resolve(span)
+                            .expect("spans should have been registered by the parser");
+
+                        absolute_range(&node)
+                    })
+                })
+                .collect()
+        })
+}
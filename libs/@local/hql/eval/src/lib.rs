@@ -0,0 +1,8 @@
+//! Evaluation of HQL expressions.
+//!
+//! Currently, the only supported target are the filters of the graph store, see [`graph`].
+
+extern crate alloc;
+
+pub mod error;
+pub mod graph;
@@ -0,0 +1,514 @@
+use std::collections::HashMap;
+
+use ecow::EcoString;
+use hql_cst::{
+    expr::{Expr, ExprKind, call::Call, constant::Constant, path::Path, signature::Signature},
+    symbol::Symbol,
+    r#type::{Type, TypeKind},
+    value::{Value, ValueKind},
+};
+use hql_diagnostics::Diagnostic;
+use hql_span::SpanId;
+
+use crate::{
+    error::{
+        annotation_mismatch, duplicate_name, inconsistent_generic, invalid_arity, invalid_binding,
+        not_callable, type_mismatch, unresolved_name, unresolved_type, unsatisfied_bound,
+    },
+    symbol::SymbolTable,
+    ty::{FunctionTy, GenericParam, Param, Ty},
+};
+
+/// Infers the type of a value.
+fn infer_value(value: &Value<'_, '_>) -> Ty {
+    match &value.kind {
+        ValueKind::Null => Ty::Null,
+        ValueKind::Bool(_) => Ty::Bool,
+        ValueKind::Number(number) => {
+            if number.as_str().contains(['.', 'e', 'E']) {
+                Ty::Number
+            } else {
+                Ty::Int
+            }
+        }
+        ValueKind::String(_) => Ty::String,
+        ValueKind::Array(_) => Ty::Array,
+        ValueKind::Object(_) => Ty::Object,
+    }
+}
+
+/// Semantic analysis of HQL expressions.
+///
+/// The checker infers the type of every expression, resolves names against its [`SymbolTable`]
+/// and checks arguments of calls against the signature of the callee. Errors do not abort the
+/// analysis, instead they are collected and the offending expression is assigned [`Ty::Error`],
+/// so that follow-up errors are suppressed.
+#[derive(Debug)]
+pub struct TypeChecker {
+    symbols: SymbolTable,
+    diagnostics: Vec<Diagnostic<'static, SpanId>>,
+}
+
+impl TypeChecker {
+    #[must_use]
+    pub const fn new(symbols: SymbolTable) -> Self {
+        Self {
+            symbols,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn symbols(&self) -> &SymbolTable {
+        &self.symbols
+    }
+
+    pub const fn symbols_mut(&mut self) -> &mut SymbolTable {
+        &mut self.symbols
+    }
+
+    #[must_use]
+    pub fn diagnostics(&self) -> &[Diagnostic<'static, SpanId>] {
+        &self.diagnostics
+    }
+
+    /// Finishes the analysis.
+    ///
+    /// # Errors
+    ///
+    /// Returns all collected diagnostics if any expression failed to type check.
+    pub fn finish(self) -> Result<SymbolTable, Vec<Diagnostic<'static, SpanId>>> {
+        if self.diagnostics.is_empty() {
+            Ok(self.symbols)
+        } else {
+            Err(self.diagnostics)
+        }
+    }
+
+    /// Declares a function with the given signature in the current scope.
+    pub fn declare(&mut self, name: &Symbol, signature: &Signature<'_>) {
+        let ty = self.resolve_signature(signature);
+        self.symbols.define(name.value.clone(), ty);
+    }
+
+    /// Resolves a type annotation.
+    pub fn resolve_type(&mut self, r#type: &Type<'_>) -> Ty {
+        self.resolve_type_in(r#type, &[])
+    }
+
+    /// Infers the type of an expression.
+    pub fn check(&mut self, expr: &Expr<'_, '_>) -> Ty {
+        match &expr.kind {
+            ExprKind::Call(call) => self.check_call(call, expr.span),
+            ExprKind::Signature(signature) => self.resolve_signature(signature),
+            ExprKind::Path(path) => self.check_path(path),
+            ExprKind::Constant(constant) => self.check_constant(constant),
+        }
+    }
+
+    fn resolve_type_in(&mut self, r#type: &Type<'_>, generics: &[GenericParam]) -> Ty {
+        match &r#type.kind {
+            TypeKind::Path(path) => {
+                let name = path.to_string();
+
+                if let Some(generic) = generics.iter().find(|generic| generic.name == name) {
+                    Ty::Param(generic.name.clone())
+                } else if let Some(ty) = self.symbols.lookup_type(&name) {
+                    ty.clone()
+                } else {
+                    self.diagnostics.push(unresolved_type(path.span, &name));
+                    Ty::Error
+                }
+            }
+            TypeKind::Union(types) => Ty::Union(
+                types
+                    .iter()
+                    .map(|ty| self.resolve_type_in(ty, generics))
+                    .collect(),
+            ),
+            TypeKind::Intersection(types) => Ty::Intersection(
+                types
+                    .iter()
+                    .map(|ty| self.resolve_type_in(ty, generics))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn resolve_signature(&mut self, signature: &Signature<'_>) -> Ty {
+        let mut generics: Vec<GenericParam> = Vec::with_capacity(signature.generics.len());
+        let mut generic_spans = Vec::with_capacity(signature.generics.len());
+
+        for generic in signature.generics.iter() {
+            if let Some(index) = generics
+                .iter()
+                .position(|previous| previous.name == generic.name.value)
+            {
+                self.diagnostics.push(duplicate_name(
+                    generic.name.span,
+                    generic_spans[index],
+                    &generic.name,
+                ));
+                continue;
+            }
+
+            // Bounds may refer to previously declared generics
+            let bound = generic
+                .bound
+                .as_ref()
+                .map_or(Ty::Unknown, |bound| self.resolve_type_in(bound, &generics));
+
+            generics.push(GenericParam {
+                name: generic.name.value.clone(),
+                bound,
+            });
+            generic_spans.push(generic.name.span);
+        }
+
+        let mut params: Vec<Param> = Vec::with_capacity(signature.arguments.len());
+        let mut param_spans = Vec::with_capacity(signature.arguments.len());
+
+        for argument in signature.arguments.iter() {
+            let ty = self.resolve_type_in(&argument.r#type, &generics);
+
+            if let Some(index) = params
+                .iter()
+                .position(|previous| previous.name == argument.name.value)
+            {
+                self.diagnostics.push(duplicate_name(
+                    argument.name.span,
+                    param_spans[index],
+                    &argument.name,
+                ));
+                continue;
+            }
+
+            params.push(Param {
+                name: argument.name.value.clone(),
+                ty,
+            });
+            param_spans.push(argument.name.span);
+        }
+
+        let r#return = self.resolve_type_in(&signature.r#return.r#type, &generics);
+
+        Ty::Function(Box::new(FunctionTy {
+            generics,
+            params,
+            rest: None,
+            r#return,
+        }))
+    }
+
+    /// Infers the type of a path.
+    ///
+    /// A path is either bound as a whole, like `math::abs`, or accesses a member of a
+    /// [`Ty::Dynamic`] value, like `entity::properties`, which is [`Ty::Dynamic`] as well.
+    fn check_path(&mut self, path: &Path<'_>) -> Ty {
+        let name = path.to_string();
+
+        if let Some(ty) = self.symbols.lookup(&name) {
+            return ty.clone();
+        }
+
+        if let [variable, _, ..] = &*path.segments {
+            if self.symbols.lookup(&variable.value) == Some(&Ty::Dynamic) {
+                return Ty::Dynamic;
+            }
+        }
+
+        self.diagnostics.push(unresolved_name(path.span, &name));
+        Ty::Error
+    }
+
+    fn check_constant(&mut self, constant: &Constant<'_, '_>) -> Ty {
+        let inferred = infer_value(&constant.value);
+
+        let Some(annotation) = &constant.r#type else {
+            return inferred;
+        };
+
+        let expected = self.resolve_type(annotation);
+        if !inferred.is_subtype_of(&expected) {
+            self.diagnostics.push(annotation_mismatch(
+                constant.value.span,
+                annotation.span,
+                &expected,
+                &inferred,
+            ));
+        }
+
+        expected
+    }
+
+    /// Checks `["let", name, value, body]`, which binds `value` to `name` while checking `body`.
+    fn check_let(&mut self, call: &Call<'_, '_>, span: SpanId) -> Ty {
+        let [name, value, body] = &*call.args else {
+            self.diagnostics
+                .push(invalid_arity(span, 3, call.args.len()));
+            return Ty::Error;
+        };
+
+        let value = self.check(value);
+
+        let ExprKind::Path(Path { segments, .. }) = &name.kind else {
+            self.diagnostics.push(invalid_binding(name.span));
+            return Ty::Error;
+        };
+
+        let [symbol] = &**segments else {
+            self.diagnostics.push(invalid_binding(name.span));
+            return Ty::Error;
+        };
+
+        self.symbols.enter_scope();
+        self.symbols.define(symbol.value.clone(), value);
+        let ty = self.check(body);
+        self.symbols.exit_scope();
+
+        ty
+    }
+
+    fn check_call(&mut self, call: &Call<'_, '_>, span: SpanId) -> Ty {
+        if matches!(
+            &call.r#fn.kind,
+            ExprKind::Path(path) if matches!(&*path.segments, [symbol] if symbol.value == "let")
+        ) {
+            return self.check_let(call, span);
+        }
+
+        let callee = self.check(&call.r#fn);
+        let arguments: Vec<_> = call.args.iter().map(|arg| self.check(arg)).collect();
+
+        let function = match callee {
+            Ty::Function(function) => function,
+            Ty::Error => return Ty::Error,
+            other => {
+                self.diagnostics.push(not_callable(call.r#fn.span, &other));
+                return Ty::Error;
+            }
+        };
+
+        let expected = function.params.len();
+        let actual = arguments.len();
+        if actual < expected || (actual > expected && function.rest.is_none()) {
+            let diagnostic = if function.rest.is_some() {
+                invalid_arity(span, format_args!("at least {expected}"), actual)
+            } else {
+                invalid_arity(span, expected, actual)
+            };
+
+            self.diagnostics.push(diagnostic);
+            return function.r#return.instantiate(&HashMap::new(), &function);
+        }
+
+        let mut inferred = HashMap::new();
+        let params = function
+            .params
+            .iter()
+            .chain(core::iter::repeat_n(function.rest.iter(), actual - expected).flatten());
+
+        for ((argument, ty), param) in call.args.iter().zip(&arguments).zip(params) {
+            self.check_argument(&function, param, ty, argument.span, &mut inferred);
+        }
+
+        function.r#return.instantiate(&inferred, &function)
+    }
+
+    fn check_argument(
+        &mut self,
+        function: &FunctionTy,
+        param: &Param,
+        actual: &Ty,
+        span: SpanId,
+        inferred: &mut HashMap<EcoString, Ty>,
+    ) {
+        let Ty::Param(name) = &param.ty else {
+            let expected = param.ty.instantiate(inferred, function);
+            if !actual.is_subtype_of(&expected) {
+                self.diagnostics
+                    .push(type_mismatch(span, &expected, actual));
+            }
+
+            return;
+        };
+
+        let bound = function
+            .generic(name)
+            .map_or(Ty::Unknown, |generic| generic.bound.clone());
+
+        if !actual.is_subtype_of(&bound) {
+            self.diagnostics
+                .push(unsatisfied_bound(span, name, &bound, actual));
+            return;
+        }
+
+        match inferred.get(name) {
+            None => {
+                inferred.insert(name.clone(), actual.clone());
+            }
+            Some(previous) if actual.is_subtype_of(previous) => {}
+            Some(previous) if previous.is_subtype_of(actual) => {
+                // Widen the inferred type, e.g. `Int` to `Number`
+                inferred.insert(name.clone(), actual.clone());
+            }
+            Some(previous) => {
+                self.diagnostics
+                    .push(inconsistent_generic(span, name, previous, actual));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+
+    use hql_cst::arena::Arena;
+    use hql_diagnostics::Diagnostic;
+    use hql_span::{SpanId, storage::SpanStorage};
+    use hql_syntax_jexpr::Parser;
+
+    use super::TypeChecker;
+    use crate::{symbol::SymbolTable, ty::Ty};
+
+    fn check(source: &str) -> (Ty, Vec<Diagnostic<'static, SpanId>>) {
+        let arena = Arena::new();
+        let parser = Parser::new(&arena, Arc::new(SpanStorage::new()));
+        let expr = parser
+            .parse_expr(source.as_bytes())
+            .expect("should be a valid expression");
+
+        let mut checker = TypeChecker::new(SymbolTable::with_prelude());
+        let ty = checker.check(&expr);
+
+        (ty, checker.diagnostics().to_vec())
+    }
+
+    #[track_caller]
+    fn check_ok(source: &str) -> Ty {
+        let (ty, diagnostics) = check(source);
+        assert!(
+            diagnostics.is_empty(),
+            "unexpected diagnostics: {diagnostics:?}"
+        );
+
+        ty
+    }
+
+    #[track_caller]
+    fn check_err(source: &str) -> Vec<String> {
+        let (_, diagnostics) = check(source);
+        assert!(!diagnostics.is_empty(), "expected diagnostics");
+
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.category.as_ref().id.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(check_ok(r#"{"const": 42}"#), Ty::Int);
+        assert_eq!(check_ok(r#"{"const": 4.2}"#), Ty::Number);
+        assert_eq!(check_ok(r#"{"const": "foo"}"#), Ty::String);
+        assert_eq!(check_ok(r#"{"const": 42, "type": "Number"}"#), Ty::Number);
+        assert_eq!(
+            check_ok(r#"{"const": null, "type": "Int | Null"}"#),
+            Ty::Union(vec![Ty::Int, Ty::Null])
+        );
+
+        assert_eq!(check_err(r#"{"const": 4.2, "type": "Int"}"#), [
+            "type-mismatch"
+        ]);
+        assert_eq!(check_err(r#"{"const": 42, "type": "Float"}"#), [
+            "unresolved-type"
+        ]);
+    }
+
+    #[test]
+    fn generic_operators() {
+        assert_eq!(check_ok(r#"["+", {"const": 1}, {"const": 2}]"#), Ty::Int);
+        assert_eq!(
+            check_ok(r#"["+", {"const": 1}, {"const": 2.5}]"#),
+            Ty::Number
+        );
+        assert_eq!(
+            check_ok(r#"["<", {"const": "a"}, {"const": "b"}]"#),
+            Ty::Bool
+        );
+
+        assert_eq!(check_err(r#"["+", {"const": 1}, {"const": "2"}]"#), [
+            "unsatisfied-bound"
+        ]);
+        assert_eq!(check_err(r#"["<", {"const": 1}, {"const": "2"}]"#), [
+            "type-mismatch"
+        ]);
+    }
+
+    #[test]
+    fn variadic() {
+        assert_eq!(
+            check_ok(r#"["and", {"const": true}, ["not", {"const": false}], {"const": true}]"#),
+            Ty::Bool
+        );
+        assert_eq!(check_err(r#"["and", {"const": true}, {"const": 1}]"#), [
+            "type-mismatch"
+        ]);
+    }
+
+    #[test]
+    fn let_and_signatures() {
+        assert_eq!(
+            check_ok(
+                r#"["let", "add", "(lhs: Int, rhs: Int) -> Int", ["add", {"const": 1}, {"const": 2}]]"#
+            ),
+            Ty::Int
+        );
+        assert_eq!(
+            check_ok(r#"["let", "id", "<T>(value: T) -> T", ["id", {"const": "foo"}]]"#),
+            Ty::String
+        );
+
+        assert_eq!(
+            check_err(r#"["let", "add", "(lhs: Int, rhs: Int) -> Int", ["add", {"const": 1}]]"#),
+            ["invalid-arity"]
+        );
+        assert_eq!(
+            check_err(r#"["let", "x", {"const": 1}, ["x", {"const": 1}]]"#),
+            ["not-callable"]
+        );
+        assert_eq!(check_err(r#""(a: Int, a: Int) -> Int""#), [
+            "duplicate-name"
+        ]);
+    }
+
+    #[test]
+    fn dynamic_members() {
+        assert_eq!(
+            check_ok(r#"["let", "record", {"const": {}, "type": "Dynamic"}, "record::field"]"#),
+            Ty::Dynamic
+        );
+        assert_eq!(
+            check_ok(
+                r#"["let", "record", {"const": {}, "type": "Dynamic"}, [">", "record::age", {"const": 21}]]"#
+            ),
+            Ty::Bool
+        );
+        assert_eq!(
+            check_err(r#"["let", "record", {"const": {}}, "record::field"]"#),
+            ["unresolved-name"]
+        );
+    }
+
+    #[test]
+    fn scopes() {
+        assert_eq!(check_err(r#"["+", "x", {"const": 1}]"#), [
+            "unresolved-name"
+        ]);
+        assert_eq!(
+            check_err(r#"["+", ["let", "x", {"const": 1}, "x"], "x"]"#),
+            ["unresolved-name"]
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use alloc::borrow::Cow;
+use core::fmt::Display;
+
+use hql_diagnostics::{
+    Diagnostic, category::Category, help::Help, label::Label, rob::RefOrBox, severity::Severity,
+};
+use hql_span::SpanId;
+
+use crate::ty::Ty;
+
+pub(crate) const TYPECK_CATEGORY: &Category = &Category {
+    id: Cow::Borrowed("typeck"),
+    name: Cow::Borrowed("Type Checking"),
+    parent: None,
+};
+
+pub(crate) const UNRESOLVED_NAME: &Category = &Category {
+    id: Cow::Borrowed("unresolved-name"),
+    name: Cow::Borrowed("Unresolved Name"),
+    parent: Some(RefOrBox::Ref(TYPECK_CATEGORY)),
+};
+
+pub(crate) const UNRESOLVED_TYPE: &Category = &Category {
+    id: Cow::Borrowed("unresolved-type"),
+    name: Cow::Borrowed("Unresolved Type"),
+    parent: Some(RefOrBox::Ref(TYPECK_CATEGORY)),
+};
+
+pub(crate) const DUPLICATE_NAME: &Category = &Category {
+    id: Cow::Borrowed("duplicate-name"),
+    name: Cow::Borrowed("Duplicate Name"),
+    parent: Some(RefOrBox::Ref(TYPECK_CATEGORY)),
+};
+
+pub(crate) const TYPE_MISMATCH: &Category = &Category {
+    id: Cow::Borrowed("type-mismatch"),
+    name: Cow::Borrowed("Type Mismatch"),
+    parent: Some(RefOrBox::Ref(TYPECK_CATEGORY)),
+};
+
+pub(crate) const UNSATISFIED_BOUND: &Category = &Category {
+    id: Cow::Borrowed("unsatisfied-bound"),
+    name: Cow::Borrowed("Unsatisfied Bound"),
+    parent: Some(RefOrBox::Ref(TYPE_MISMATCH)),
+};
+
+pub(crate) const NOT_CALLABLE: &Category = &Category {
+    id: Cow::Borrowed("not-callable"),
+    name: Cow::Borrowed("Not Callable"),
+    parent: Some(RefOrBox::Ref(TYPECK_CATEGORY)),
+};
+
+pub(crate) const INVALID_ARITY: &Category = &Category {
+    id: Cow::Borrowed("invalid-arity"),
+    name: Cow::Borrowed("Invalid number of arguments"),
+    parent: Some(RefOrBox::Ref(TYPECK_CATEGORY)),
+};
+
+pub(crate) const INVALID_BINDING: &Category = &Category {
+    id: Cow::Borrowed("invalid-binding"),
+    name: Cow::Borrowed("Invalid Binding"),
+    parent: Some(RefOrBox::Ref(TYPECK_CATEGORY)),
+};
+
+pub(crate) fn unresolved_name(span: SpanId, name: impl Display) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(UNRESOLVED_NAME, Severity::ERROR);
+
+    diagnostic.labels.push(Label::new(
+        span,
+        format!("Cannot find value `{name}` in this scope"),
+    ));
+
+    diagnostic.help = Some(Help::new(
+        "Values need to be bound using `[\"let\", name, value, body]` before they can be used",
+    ));
+
+    diagnostic
+}
+
+pub(crate) fn unresolved_type(span: SpanId, name: impl Display) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(UNRESOLVED_TYPE, Severity::ERROR);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, format!("Cannot find type `{name}`")));
+
+    diagnostic.help = Some(Help::new(
+        "The built-in types are: `Unknown`, `Never`, `Null`, `Bool`, `Int`, `Number`, `String`, \
+         `Array` and `Object`",
+    ));
+
+    diagnostic
+}
+
+pub(crate) fn duplicate_name(
+    span: SpanId,
+    previous: SpanId,
+    name: impl Display,
+) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(DUPLICATE_NAME, Severity::ERROR);
+
+    diagnostic.labels.push(Label::new(
+        span,
+        format!("`{name}` is declared more than once"),
+    ));
+
+    diagnostic.labels.push(Label::new(
+        previous,
+        format!("`{name}` is first declared here"),
+    ));
+
+    diagnostic
+}
+
+pub(crate) fn type_mismatch(
+    span: SpanId,
+    expected: &Ty,
+    actual: &Ty,
+) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(TYPE_MISMATCH, Severity::ERROR);
+
+    diagnostic.labels.push(Label::new(
+        span,
+        format!("Expected `{expected}`, found `{actual}`"),
+    ));
+
+    if *actual == Ty::Number && *expected == Ty::Int {
+        diagnostic.help = Some(Help::new(
+            "Every `Int` is a `Number`, but not every `Number` is an `Int`",
+        ));
+    }
+
+    diagnostic
+}
+
+pub(crate) fn annotation_mismatch(
+    span: SpanId,
+    annotation: SpanId,
+    expected: &Ty,
+    actual: &Ty,
+) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = type_mismatch(span, expected, actual);
+
+    diagnostic
+        .labels
+        .push(Label::new(annotation, "Expected due to this annotation").with_order(1));
+
+    diagnostic
+}
+
+pub(crate) fn unsatisfied_bound(
+    span: SpanId,
+    generic: impl Display,
+    bound: &Ty,
+    actual: &Ty,
+) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(UNSATISFIED_BOUND, Severity::ERROR);
+
+    diagnostic.labels.push(Label::new(
+        span,
+        format!("`{actual}` does not satisfy the bound `{generic}: {bound}`"),
+    ));
+
+    diagnostic
+}
+
+pub(crate) fn inconsistent_generic(
+    span: SpanId,
+    generic: impl Display,
+    inferred: &Ty,
+    actual: &Ty,
+) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = type_mismatch(span, inferred, actual);
+
+    diagnostic.help = Some(Help::new(format!(
+        "`{generic}` has been inferred as `{inferred}` from a previous argument"
+    )));
+
+    diagnostic
+}
+
+pub(crate) fn not_callable(span: SpanId, ty: &Ty) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(NOT_CALLABLE, Severity::ERROR);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, format!("`{ty}` is not a function")));
+
+    diagnostic
+}
+
+pub(crate) fn invalid_arity(
+    span: SpanId,
+    expected: impl Display,
+    actual: usize,
+) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(INVALID_ARITY, Severity::ERROR);
+
+    diagnostic.labels.push(Label::new(
+        span,
+        format!("Expected {expected} arguments, but {actual} were given"),
+    ));
+
+    diagnostic
+}
+
+pub(crate) fn invalid_binding(span: SpanId) -> Diagnostic<'static, SpanId> {
+    let mut diagnostic = Diagnostic::new(INVALID_BINDING, Severity::ERROR);
+
+    diagnostic
+        .labels
+        .push(Label::new(span, "Expected a single identifier"));
+
+    diagnostic.help = Some(Help::new(
+        "`let` binds a value to a name: `[\"let\", name, value, body]`",
+    ));
+
+    diagnostic
+}
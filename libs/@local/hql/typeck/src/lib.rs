@@ -0,0 +1,18 @@
+//! Type checking of HQL expressions.
+//!
+//! The [`TypeChecker`] infers the [`Ty`] of an expression, resolves the names it refers to using a
+//! [`SymbolTable`] and verifies that calls match the signature of the called function. Function
+//! signatures may be generic, e.g. `<T: Number>(lhs: T, rhs: T) -> T`, in which case the generic
+//! parameters are inferred from the arguments of each call.
+//!
+//! [`Ty`]: ty::Ty
+//! [`SymbolTable`]: symbol::SymbolTable
+
+extern crate alloc;
+
+mod check;
+pub mod error;
+pub mod symbol;
+pub mod ty;
+
+pub use self::check::TypeChecker;
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use ecow::EcoString;
+
+use crate::ty::{FunctionTy, Ty};
+
+/// Scoped mapping of names to their types.
+///
+/// Types and values live in separate namespaces. Types are global, while values are bound in
+/// nested scopes, where inner scopes shadow the bindings of outer scopes.
+#[derive(Debug, Clone)]
+pub struct SymbolTable {
+    types: HashMap<EcoString, Ty>,
+    scopes: Vec<HashMap<EcoString, Ty>>,
+}
+
+impl SymbolTable {
+    /// Creates a symbol table, which only contains the built-in types.
+    #[must_use]
+    pub fn new() -> Self {
+        let types = [
+            ("Unknown", Ty::Unknown),
+            ("Never", Ty::Never),
+            ("Dynamic", Ty::Dynamic),
+            ("Null", Ty::Null),
+            ("Bool", Ty::Bool),
+            ("Int", Ty::Int),
+            ("Number", Ty::Number),
+            ("String", Ty::String),
+            ("Array", Ty::Array),
+            ("Object", Ty::Object),
+        ]
+        .into_iter()
+        .map(|(name, ty)| (EcoString::from(name), ty))
+        .collect();
+
+        Self {
+            types,
+            scopes: vec![HashMap::new()],
+        }
+    }
+
+    /// Creates a symbol table with the built-in types and operators.
+    #[must_use]
+    pub fn with_prelude() -> Self {
+        let mut table = Self::new();
+
+        let param = || Ty::Param(EcoString::from("T"));
+
+        for operator in ["+", "-", "*", "/"] {
+            table.define(
+                operator,
+                Ty::Function(Box::new(
+                    FunctionTy::new([("lhs", param()), ("rhs", param())], param())
+                        .with_generic("T", Ty::Number),
+                )),
+            );
+        }
+
+        for operator in ["==", "!="] {
+            table.define(
+                operator,
+                Ty::Function(Box::new(FunctionTy::new(
+                    [("lhs", Ty::Unknown), ("rhs", Ty::Unknown)],
+                    Ty::Bool,
+                ))),
+            );
+        }
+
+        for operator in [">", ">=", "<", "<="] {
+            table.define(
+                operator,
+                Ty::Function(Box::new(
+                    FunctionTy::new([("lhs", param()), ("rhs", param())], Ty::Bool)
+                        .with_generic("T", Ty::Union(vec![Ty::Number, Ty::String])),
+                )),
+            );
+        }
+
+        for operator in ["and", "or"] {
+            table.define(
+                operator,
+                Ty::Function(Box::new(
+                    FunctionTy::new::<&str>([], Ty::Bool).with_rest("operands", Ty::Bool),
+                )),
+            );
+        }
+
+        table.define(
+            "not",
+            Ty::Function(Box::new(FunctionTy::new([("operand", Ty::Bool)], Ty::Bool))),
+        );
+
+        table
+    }
+
+    /// Defines a type, replacing any previous definition with the same name.
+    pub fn define_type(&mut self, name: impl Into<EcoString>, ty: Ty) {
+        self.types.insert(name.into(), ty);
+    }
+
+    #[must_use]
+    pub fn lookup_type(&self, name: &str) -> Option<&Ty> {
+        self.types.get(name)
+    }
+
+    /// Binds a value in the innermost scope, shadowing any outer binding with the same name.
+    pub fn define(&mut self, name: impl Into<EcoString>, ty: Ty) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(name.into(), ty);
+        }
+    }
+
+    /// Looks up the type of a value, starting at the innermost scope.
+    #[must_use]
+    pub fn lookup(&self, name: &str) -> Option<&Ty> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    pub(crate) fn enter_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    pub(crate) fn exit_scope(&mut self) {
+        // The outermost scope is never removed
+        if self.scopes.len() > 1 {
+            self.scopes.pop();
+        }
+    }
+}
+
+impl Default for SymbolTable {
+    fn default() -> Self {
+        Self::with_prelude()
+    }
+}
@@ -0,0 +1,340 @@
+use core::fmt::{self, Display, Write as _};
+use std::collections::HashMap;
+
+use ecow::EcoString;
+
+/// A generic parameter of a function, e.g. `T: Number` in `<T: Number>(value: T) -> T`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenericParam {
+    pub name: EcoString,
+    /// Upper bound of the parameter, [`Ty::Unknown`] if the parameter is unconstrained.
+    pub bound: Ty,
+}
+
+impl Display for GenericParam {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.name, fmt)?;
+
+        if self.bound == Ty::Unknown {
+            Ok(())
+        } else {
+            write!(fmt, ": {}", self.bound)
+        }
+    }
+}
+
+/// A named parameter of a function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: EcoString,
+    pub ty: Ty,
+}
+
+impl Display for Param {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}: {}", self.name, self.ty)
+    }
+}
+
+/// The type of a function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionTy {
+    pub generics: Vec<GenericParam>,
+    pub params: Vec<Param>,
+    /// Parameter, which accepts any number of additional arguments.
+    ///
+    /// Signatures cannot express variadic functions, this is only used by built-in functions.
+    pub rest: Option<Param>,
+    pub r#return: Ty,
+}
+
+impl FunctionTy {
+    pub fn new<N>(params: impl IntoIterator<Item = (N, Ty)>, r#return: Ty) -> Self
+    where
+        N: Into<EcoString>,
+    {
+        Self {
+            generics: Vec::new(),
+            params: params
+                .into_iter()
+                .map(|(name, ty)| Param {
+                    name: name.into(),
+                    ty,
+                })
+                .collect(),
+            rest: None,
+            r#return,
+        }
+    }
+
+    #[must_use]
+    pub fn with_generic(mut self, name: impl Into<EcoString>, bound: Ty) -> Self {
+        self.generics.push(GenericParam {
+            name: name.into(),
+            bound,
+        });
+        self
+    }
+
+    #[must_use]
+    pub fn with_rest(mut self, name: impl Into<EcoString>, ty: Ty) -> Self {
+        self.rest = Some(Param {
+            name: name.into(),
+            ty,
+        });
+        self
+    }
+
+    /// Returns the generic parameter with the given name.
+    #[must_use]
+    pub fn generic(&self, name: &str) -> Option<&GenericParam> {
+        self.generics.iter().find(|generic| generic.name == name)
+    }
+
+    fn is_subtype_of(&self, other: &Self) -> bool {
+        if !self.generics.is_empty() || !other.generics.is_empty() {
+            return self == other;
+        }
+
+        // Parameters are contravariant, the return type is covariant.
+        self.params.len() == other.params.len()
+            && self
+                .params
+                .iter()
+                .zip(&other.params)
+                .all(|(lhs, rhs)| rhs.ty.is_subtype_of(&lhs.ty))
+            && match (&self.rest, &other.rest) {
+                (Some(lhs), Some(rhs)) => rhs.ty.is_subtype_of(&lhs.ty),
+                (None, None) => true,
+                _ => false,
+            }
+            && self.r#return.is_subtype_of(&other.r#return)
+    }
+}
+
+impl Display for FunctionTy {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.generics.is_empty() {
+            fmt.write_char('<')?;
+            for (index, generic) in self.generics.iter().enumerate() {
+                if index > 0 {
+                    fmt.write_str(", ")?;
+                }
+
+                Display::fmt(generic, fmt)?;
+            }
+            fmt.write_char('>')?;
+        }
+
+        fmt.write_char('(')?;
+        for (index, param) in self.params.iter().enumerate() {
+            if index > 0 {
+                fmt.write_str(", ")?;
+            }
+
+            Display::fmt(param, fmt)?;
+        }
+        if let Some(rest) = &self.rest {
+            if !self.params.is_empty() {
+                fmt.write_str(", ")?;
+            }
+
+            write!(fmt, "...{rest}")?;
+        }
+        fmt.write_str(") -> ")?;
+
+        Display::fmt(&self.r#return, fmt)
+    }
+}
+
+/// The semantic type of an expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ty {
+    /// The top type, every type is a subtype of `Unknown`.
+    Unknown,
+    /// The bottom type, `Never` is a subtype of every type.
+    Never,
+    /// The type of an expression, which failed to type check.
+    ///
+    /// `Error` is compatible with every type, so that a single mistake is only reported once.
+    Error,
+    /// The type of a value, which is only known when the expression is evaluated, e.g. a field of
+    /// a queried record.
+    ///
+    /// `Dynamic` is compatible with every type, the value is checked at runtime instead.
+    Dynamic,
+
+    Null,
+    Bool,
+    Int,
+    /// Any JSON number, `Int` is a subtype of `Number`.
+    Number,
+    String,
+    Array,
+    Object,
+
+    /// Reference to a generic parameter of the enclosing function.
+    Param(EcoString),
+
+    Union(Vec<Self>),
+    Intersection(Vec<Self>),
+    Function(Box<FunctionTy>),
+}
+
+impl Ty {
+    /// Returns `true` if a value of this type can be used where `other` is expected.
+    #[must_use]
+    pub fn is_subtype_of(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Error | Self::Never | Self::Dynamic, _)
+            | (_, Self::Error | Self::Unknown | Self::Dynamic) => true,
+            (Self::Union(types), _) => types.iter().all(|ty| ty.is_subtype_of(other)),
+            (_, Self::Intersection(types)) => types.iter().all(|ty| self.is_subtype_of(ty)),
+            (_, Self::Union(types)) => types.iter().any(|ty| self.is_subtype_of(ty)),
+            (Self::Intersection(types), _) => types.iter().any(|ty| ty.is_subtype_of(other)),
+            (Self::Int, Self::Number) => true,
+            (Self::Function(lhs), Self::Function(rhs)) => lhs.is_subtype_of(rhs),
+            _ => self == other,
+        }
+    }
+
+    /// Replaces all generic parameters with their inferred type.
+    ///
+    /// Parameters, which have not been inferred, are replaced with their bound.
+    #[must_use]
+    pub(crate) fn instantiate(
+        &self,
+        inferred: &HashMap<EcoString, Self>,
+        function: &FunctionTy,
+    ) -> Self {
+        match self {
+            Self::Param(name) => inferred.get(name).cloned().unwrap_or_else(|| {
+                function
+                    .generic(name)
+                    .map_or(Self::Unknown, |generic| generic.bound.clone())
+            }),
+            Self::Union(types) => Self::Union(
+                types
+                    .iter()
+                    .map(|ty| ty.instantiate(inferred, function))
+                    .collect(),
+            ),
+            Self::Intersection(types) => Self::Intersection(
+                types
+                    .iter()
+                    .map(|ty| ty.instantiate(inferred, function))
+                    .collect(),
+            ),
+            Self::Function(inner) => {
+                let instantiate = |param: &Param| Param {
+                    name: param.name.clone(),
+                    ty: param.ty.instantiate(inferred, function),
+                };
+
+                Self::Function(Box::new(FunctionTy {
+                    generics: inner.generics.clone(),
+                    params: inner.params.iter().map(instantiate).collect(),
+                    rest: inner.rest.as_ref().map(instantiate),
+                    r#return: inner.r#return.instantiate(inferred, function),
+                }))
+            }
+            Self::Unknown
+            | Self::Never
+            | Self::Error
+            | Self::Dynamic
+            | Self::Null
+            | Self::Bool
+            | Self::Int
+            | Self::Number
+            | Self::String
+            | Self::Array
+            | Self::Object => self.clone(),
+        }
+    }
+}
+
+impl Display for Ty {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let types = match self {
+            Self::Unknown => return fmt.write_str("Unknown"),
+            Self::Never => return fmt.write_str("Never"),
+            Self::Error => return fmt.write_str("{error}"),
+            Self::Dynamic => return fmt.write_str("Dynamic"),
+            Self::Null => return fmt.write_str("Null"),
+            Self::Bool => return fmt.write_str("Bool"),
+            Self::Int => return fmt.write_str("Int"),
+            Self::Number => return fmt.write_str("Number"),
+            Self::String => return fmt.write_str("String"),
+            Self::Array => return fmt.write_str("Array"),
+            Self::Object => return fmt.write_str("Object"),
+            Self::Param(name) => return Display::fmt(name, fmt),
+            Self::Function(function) => return Display::fmt(function, fmt),
+            Self::Union(types) | Self::Intersection(types) => types,
+        };
+
+        let separator = if matches!(self, Self::Union(_)) {
+            " | "
+        } else {
+            " & "
+        };
+
+        fmt.write_char('(')?;
+        for (index, ty) in types.iter().enumerate() {
+            if index > 0 {
+                fmt.write_str(separator)?;
+            }
+
+            Display::fmt(ty, fmt)?;
+        }
+        fmt.write_char(')')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FunctionTy, Ty};
+
+    #[test]
+    fn subtyping() {
+        assert!(Ty::Int.is_subtype_of(&Ty::Number));
+        assert!(!Ty::Number.is_subtype_of(&Ty::Int));
+        assert!(Ty::Never.is_subtype_of(&Ty::String));
+        assert!(Ty::String.is_subtype_of(&Ty::Unknown));
+
+        let union = Ty::Union(vec![Ty::Int, Ty::String]);
+        assert!(Ty::Int.is_subtype_of(&union));
+        assert!(!Ty::Bool.is_subtype_of(&union));
+        assert!(!union.is_subtype_of(&Ty::Int));
+        assert!(union.is_subtype_of(&Ty::Union(vec![Ty::Number, Ty::String])));
+
+        let intersection = Ty::Intersection(vec![Ty::Int, Ty::Number]);
+        assert!(Ty::Int.is_subtype_of(&intersection));
+        assert!(!Ty::Number.is_subtype_of(&intersection));
+
+        assert!(Ty::Dynamic.is_subtype_of(&Ty::Bool));
+        assert!(Ty::Bool.is_subtype_of(&Ty::Dynamic));
+    }
+
+    #[test]
+    fn function_variance() {
+        let number_to_int =
+            Ty::Function(Box::new(FunctionTy::new([("value", Ty::Number)], Ty::Int)));
+        let int_to_number =
+            Ty::Function(Box::new(FunctionTy::new([("value", Ty::Int)], Ty::Number)));
+
+        assert!(number_to_int.is_subtype_of(&int_to_number));
+        assert!(!int_to_number.is_subtype_of(&number_to_int));
+    }
+
+    #[test]
+    fn display() {
+        let function = FunctionTy::new([("lhs", Ty::Param("T".into()))], Ty::Bool)
+            .with_generic("T", Ty::Union(vec![Ty::Number, Ty::String]))
+            .with_rest("rest", Ty::Param("T".into()));
+
+        assert_eq!(
+            function.to_string(),
+            "<T: (Number | String)>(lhs: T, ...rest: T) -> Bool"
+        );
+    }
+}
@@ -0,0 +1,197 @@
+#![expect(
+    clippy::string_slice,
+    reason = "Markers are ASCII, so slicing after them is on a character boundary"
+)]
+
+use core::ops::Range;
+
+use super::{Block, BlockKind, lines};
+
+/// Returns the fence marker if the line opens or closes a code fence.
+fn fence(line: &str) -> Option<(char, usize)> {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+
+    let marker = trimmed
+        .chars()
+        .next()
+        .filter(|char| matches!(char, '`' | '~'))?;
+    let length = trimmed.chars().take_while(|char| *char == marker).count();
+
+    (length >= 3).then_some((marker, length))
+}
+
+/// Returns the level and title if the line is an ATX heading.
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+
+    let level = trimmed.chars().take_while(|char| *char == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+
+    let title = &trimmed[level..];
+    if !title.is_empty() && !title.starts_with([' ', '\t']) {
+        return None;
+    }
+
+    Some((level, title.trim().trim_end_matches('#').trim_end()))
+}
+
+/// Returns `true` if the line starts a bullet or ordered list item.
+fn is_list_item(line: &str) -> bool {
+    let trimmed = line.trim_start();
+
+    let marker_length = if trimmed.starts_with(['-', '*', '+']) {
+        1
+    } else {
+        let digits = trimmed.chars().take_while(char::is_ascii_digit).count();
+        if digits == 0 || digits > 9 || !trimmed[digits..].starts_with(['.', ')']) {
+            return false;
+        }
+        digits + 1
+    };
+
+    let rest = &trimmed[marker_length..];
+    rest.is_empty() || rest.starts_with([' ', '\t'])
+}
+
+enum State {
+    None,
+    Paragraph(Range<usize>),
+    ListItem(Range<usize>),
+    Fence {
+        marker: char,
+        length: usize,
+        range: Range<usize>,
+    },
+}
+
+struct Segmenter<'s> {
+    text: &'s str,
+    state: State,
+    blocks: Vec<Block>,
+}
+
+impl Segmenter<'_> {
+    fn finish_block(&mut self) {
+        match core::mem::replace(&mut self.state, State::None) {
+            State::None => {}
+            State::Paragraph(range) | State::ListItem(range) | State::Fence { range, .. } => {
+                self.blocks
+                    .push(Block::new(self.text, BlockKind::Content, range, None));
+            }
+        }
+    }
+
+    fn line(&mut self, range: Range<usize>, line: &str) {
+        if let State::Fence {
+            marker,
+            length,
+            range: fence_range,
+        } = &mut self.state
+        {
+            fence_range.end = range.end;
+
+            if fence(line).is_some_and(|(closing_marker, closing_length)| {
+                closing_marker == *marker
+                    && closing_length >= *length
+                    && line.trim().chars().all(|char| char == *marker)
+            }) {
+                self.finish_block();
+            }
+            return;
+        }
+
+        if line.trim().is_empty() {
+            self.finish_block();
+            return;
+        }
+
+        if let Some((marker, length)) = fence(line) {
+            self.finish_block();
+            self.state = State::Fence {
+                marker,
+                length,
+                range,
+            };
+            return;
+        }
+
+        if let Some((level, title)) = heading(line) {
+            self.finish_block();
+            self.blocks.push(Block::new(
+                self.text,
+                BlockKind::Heading {
+                    level,
+                    title: title.to_owned(),
+                },
+                range,
+                None,
+            ));
+            return;
+        }
+
+        if is_list_item(line) {
+            self.finish_block();
+            self.state = State::ListItem(range);
+            return;
+        }
+
+        match &mut self.state {
+            State::Paragraph(block) | State::ListItem(block) => block.end = range.end,
+            State::None | State::Fence { .. } => self.state = State::Paragraph(range),
+        }
+    }
+}
+
+/// Segments a Markdown document into headings, paragraphs, list items and code fences.
+pub(super) fn blocks(text: &str) -> Vec<Block> {
+    let mut segmenter = Segmenter {
+        text,
+        state: State::None,
+        blocks: Vec::new(),
+    };
+
+    for (range, line) in lines(text) {
+        segmenter.line(range, line);
+    }
+    segmenter.finish_block();
+
+    segmenter.blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::chunking::{ChunkConfig, chunk_markdown};
+
+    #[test]
+    fn sections() {
+        let text = "# Title\n\nIntro text.\n\n## Usage\n\n- first item\n- second \
+                    item\n\n```rust\nfn main() {}\n\nlet x = 1;\n```\n";
+        let chunks = chunk_markdown(text, ChunkConfig::default());
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].text, "# Title\n\nIntro text.");
+        assert_eq!(chunks[0].headings, ["Title"]);
+        assert_eq!(chunks[1].headings, ["Title", "Usage"]);
+        assert!(chunks[1].text.ends_with("let x = 1;\n```"));
+    }
+
+    #[test]
+    fn code_fences_are_kept_together() {
+        let text = "```\none two\n\nthree four\n```\n\nfive six";
+        let chunks = chunk_markdown(
+            text,
+            ChunkConfig::new(6, 0).expect("should be a valid configuration"),
+        );
+
+        let texts: Vec<_> = chunks.iter().map(|chunk| chunk.text.as_str()).collect();
+        assert_eq!(texts, ["```\none two\n\nthree four\n```", "five six"]);
+    }
+}
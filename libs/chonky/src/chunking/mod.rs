@@ -0,0 +1,388 @@
+//! Splitting of documents into overlapping, token-budgeted chunks.
+//!
+//! A document is first segmented into blocks, such as paragraphs, headings, list items or code
+//! fences. Consecutive blocks are then packed into chunks until the token budget is exhausted.
+//! Subsequent chunks start with the trailing blocks of the previous chunk, as long as they fit into
+//! the configured overlap. Blocks, which on their own exceed the budget, are split at word
+//! boundaries.
+//!
+//! Tokens are approximated by whitespace-separated words.
+#![expect(
+    clippy::string_slice,
+    reason = "Block ranges are always computed from character boundaries"
+)]
+
+mod markdown;
+mod text;
+
+use core::ops::{Range, RangeInclusive};
+
+use error_stack::{Report, ensure};
+
+use crate::ChonkyError;
+
+/// Counts the number of tokens in `text`.
+fn count_tokens(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+/// Configuration of the chunking process.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ChunkConfig {
+    max_tokens: usize,
+    overlap_tokens: usize,
+}
+
+impl ChunkConfig {
+    /// Creates a new configuration with the maximum number of tokens per chunk and the number of
+    /// tokens consecutive chunks may share.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChonkyError::ChunkConfig`] if `max_tokens` is zero or `overlap_tokens` is not
+    /// smaller than `max_tokens`.
+    pub fn new(max_tokens: usize, overlap_tokens: usize) -> Result<Self, Report<ChonkyError>> {
+        ensure!(max_tokens > 0, ChonkyError::ChunkConfig);
+        ensure!(overlap_tokens < max_tokens, ChonkyError::ChunkConfig);
+
+        Ok(Self {
+            max_tokens,
+            overlap_tokens,
+        })
+    }
+
+    #[must_use]
+    pub const fn max_tokens(&self) -> usize {
+        self.max_tokens
+    }
+
+    #[must_use]
+    pub const fn overlap_tokens(&self) -> usize {
+        self.overlap_tokens
+    }
+}
+
+impl Default for ChunkConfig {
+    fn default() -> Self {
+        Self {
+            max_tokens: 512,
+            overlap_tokens: 64,
+        }
+    }
+}
+
+/// A contiguous part of a document.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct Chunk {
+    /// The text of the chunk.
+    pub text: String,
+    /// The byte range of the chunk in the source document.
+    pub range: Range<usize>,
+    /// The number of tokens in the chunk.
+    pub tokens: usize,
+    /// The titles of the Markdown headings the chunk is nested in, the outermost heading first.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub headings: Vec<String>,
+    /// The 1-based pages the chunk spans, if the document is paginated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pages: Option<RangeInclusive<usize>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BlockKind {
+    /// A heading of the given level, starting a new section.
+    Heading { level: usize, title: String },
+    /// Any other block, which is kept together if possible.
+    Content,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Block {
+    kind: BlockKind,
+    range: Range<usize>,
+    tokens: usize,
+    page: Option<usize>,
+}
+
+impl Block {
+    fn new(source: &str, kind: BlockKind, range: Range<usize>, page: Option<usize>) -> Self {
+        Self {
+            tokens: count_tokens(&source[range.clone()]),
+            kind,
+            range,
+            page,
+        }
+    }
+}
+
+/// Returns the byte ranges of the whitespace-separated words in `text`.
+fn words(text: &str, offset: usize) -> Vec<Range<usize>> {
+    let mut words = Vec::new();
+    let mut start = None;
+
+    for (index, char) in text.char_indices() {
+        match (char.is_whitespace(), start) {
+            (true, Some(word_start)) => {
+                words.push(offset + word_start..offset + index);
+                start = None;
+            }
+            (false, None) => start = Some(index),
+            _ => {}
+        }
+    }
+
+    if let Some(word_start) = start {
+        words.push(offset + word_start..offset + text.len());
+    }
+
+    words
+}
+
+/// Iterates over the lines of `text` together with the byte range of each line, excluding the line
+/// terminator.
+fn lines(text: &str) -> impl Iterator<Item = (Range<usize>, &str)> {
+    text.split_inclusive('\n').scan(0, |start, line| {
+        let line_start = *start;
+        *start += line.len();
+
+        let line = line.trim_end_matches(['\n', '\r']);
+        Some((line_start..line_start + line.len(), line))
+    })
+}
+
+struct Packer<'s> {
+    source: &'s str,
+    config: ChunkConfig,
+    headings: Vec<String>,
+    current: Vec<Block>,
+    current_tokens: usize,
+    chunks: Vec<Chunk>,
+}
+
+impl<'s> Packer<'s> {
+    const fn new(source: &'s str, config: ChunkConfig) -> Self {
+        Self {
+            source,
+            config,
+            headings: Vec::new(),
+            current: Vec::new(),
+            current_tokens: 0,
+            chunks: Vec::new(),
+        }
+    }
+
+    fn emit(&mut self, range: Range<usize>, pages: Option<RangeInclusive<usize>>) {
+        let text = self.source[range.clone()].to_owned();
+        let tokens = count_tokens(&text);
+
+        self.chunks.push(Chunk {
+            text,
+            range,
+            tokens,
+            headings: self.headings.clone(),
+            pages,
+        });
+    }
+
+    fn flush(&mut self) {
+        let (Some(first), Some(last)) = (self.current.first(), self.current.last()) else {
+            return;
+        };
+
+        let range = first.range.start..last.range.end;
+        let pages = self.current.iter().filter_map(|block| block.page).fold(
+            None,
+            |pages: Option<RangeInclusive<usize>>, page| {
+                Some(pages.map_or(page..=page, |pages| {
+                    *pages.start().min(&page)..=*pages.end().max(&page)
+                }))
+            },
+        );
+
+        self.emit(range, pages);
+    }
+
+    /// Emits the current chunk and keeps the trailing blocks, which fit into the overlap.
+    fn flush_with_overlap(&mut self) {
+        self.flush();
+
+        let mut overlap_tokens = 0;
+        let keep = self
+            .current
+            .iter()
+            .rev()
+            .take_while(|block| {
+                overlap_tokens += block.tokens;
+                overlap_tokens <= self.config.overlap_tokens
+            })
+            .count();
+
+        self.current.drain(..self.current.len() - keep);
+        self.current_tokens = self.current.iter().map(|block| block.tokens).sum();
+    }
+
+    fn clear(&mut self) {
+        self.flush();
+        self.current.clear();
+        self.current_tokens = 0;
+    }
+
+    /// Splits a block exceeding the token budget at word boundaries.
+    fn split(&mut self, block: &Block) {
+        let words = words(&self.source[block.range.clone()], block.range.start);
+
+        let step = self.config.max_tokens - self.config.overlap_tokens;
+        let mut start = 0;
+        loop {
+            let end = (start + self.config.max_tokens).min(words.len());
+            self.emit(
+                words[start].start..words[end - 1].end,
+                block.page.map(|page| page..=page),
+            );
+
+            if end == words.len() {
+                break;
+            }
+            start += step;
+        }
+    }
+
+    fn push(&mut self, block: Block) {
+        if let BlockKind::Heading { level, title } = &block.kind {
+            // A heading always starts a new chunk, sections are never mixed
+            self.clear();
+            self.headings.truncate(level - 1);
+            self.headings.push(title.clone());
+        }
+
+        if block.tokens > self.config.max_tokens {
+            self.clear();
+            self.split(&block);
+            return;
+        }
+
+        if self.current_tokens + block.tokens > self.config.max_tokens {
+            self.flush_with_overlap();
+
+            if self.current_tokens + block.tokens > self.config.max_tokens {
+                self.current.clear();
+                self.current_tokens = 0;
+            }
+        }
+
+        self.current_tokens += block.tokens;
+        self.current.push(block);
+    }
+
+    fn finish(mut self) -> Vec<Chunk> {
+        self.flush();
+        self.chunks
+    }
+}
+
+fn pack(source: &str, blocks: impl IntoIterator<Item = Block>, config: ChunkConfig) -> Vec<Chunk> {
+    let mut packer = Packer::new(source, config);
+    for block in blocks {
+        if block.tokens > 0 {
+            packer.push(block);
+        }
+    }
+    packer.finish()
+}
+
+/// Splits plain text into chunks.
+///
+/// Paragraphs, separated by blank lines, are kept together if possible.
+#[must_use]
+pub fn chunk_text(text: &str, config: ChunkConfig) -> Vec<Chunk> {
+    pack(text, text::blocks(text, 0, None), config)
+}
+
+/// Splits a Markdown document into chunks.
+///
+/// Headings start a new chunk and are recorded in [`Chunk::headings`] of every chunk in their
+/// section. List items and code fences are kept together if possible.
+#[must_use]
+pub fn chunk_markdown(text: &str, config: ChunkConfig) -> Vec<Chunk> {
+    pack(text, markdown::blocks(text), config)
+}
+
+/// Splits the text of a paginated document, such as the text extracted from a PDF, into chunks.
+///
+/// The pages are joined by a blank line, the [`Chunk::range`] refers to the joined text.
+#[must_use]
+pub fn chunk_pages<S: AsRef<str>>(pages: &[S], config: ChunkConfig) -> Vec<Chunk> {
+    let mut text = String::new();
+    let mut blocks = Vec::new();
+
+    for (index, page) in pages.iter().enumerate() {
+        if index > 0 {
+            text.push_str("\n\n");
+        }
+
+        let offset = text.len();
+        text.push_str(page.as_ref());
+        blocks.extend(text::blocks(page.as_ref(), offset, Some(index + 1)));
+    }
+
+    pack(&text, blocks, config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(max_tokens: usize, overlap_tokens: usize) -> ChunkConfig {
+        ChunkConfig::new(max_tokens, overlap_tokens).expect("should be a valid configuration")
+    }
+
+    #[test]
+    fn invalid_config() {
+        ChunkConfig::new(0, 0).expect_err("zero tokens should be rejected");
+        ChunkConfig::new(10, 10).expect_err("overlap must be smaller than the budget");
+    }
+
+    #[test]
+    fn paragraphs_are_packed() {
+        let text = "one two three\n\nfour five\n\nsix seven eight nine";
+        let chunks = chunk_text(text, config(5, 0));
+
+        let texts: Vec<_> = chunks.iter().map(|chunk| chunk.text.as_str()).collect();
+        assert_eq!(texts, [
+            "one two three\n\nfour five",
+            "six seven eight nine"
+        ]);
+
+        for chunk in &chunks {
+            assert_eq!(&text[chunk.range.clone()], chunk.text);
+        }
+    }
+
+    #[test]
+    fn paragraphs_overlap() {
+        let text = "one two\n\nthree four\n\nfive six";
+        let chunks = chunk_text(text, config(4, 2));
+
+        let texts: Vec<_> = chunks.iter().map(|chunk| chunk.text.as_str()).collect();
+        assert_eq!(texts, ["one two\n\nthree four", "three four\n\nfive six"]);
+    }
+
+    #[test]
+    fn long_paragraph_is_split() {
+        let text = "a b c d e f g";
+        let chunks = chunk_text(text, config(3, 1));
+
+        let texts: Vec<_> = chunks.iter().map(|chunk| chunk.text.as_str()).collect();
+        assert_eq!(texts, ["a b c", "c d e", "e f g"]);
+    }
+
+    #[test]
+    fn pages_are_recorded() {
+        let chunks = chunk_pages(&["first page", "second page"], config(3, 0));
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].pages, Some(1..=1));
+        assert_eq!(chunks[1].pages, Some(2..=2));
+        assert_eq!(chunks[1].range, 12..23);
+    }
+}
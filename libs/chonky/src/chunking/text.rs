@@ -0,0 +1,32 @@
+use core::ops::Range;
+
+use super::{Block, BlockKind, lines};
+
+/// Segments plain text into paragraphs, which are separated by blank lines.
+///
+/// The ranges of the returned blocks are shifted by `offset`.
+pub(super) fn blocks(text: &str, offset: usize, page: Option<usize>) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Option<Range<usize>> = None;
+
+    for (range, line) in lines(text) {
+        if line.trim().is_empty() {
+            if let Some(paragraph) = paragraph.take() {
+                blocks.push(Block::new(text, BlockKind::Content, paragraph, page));
+            }
+        } else {
+            paragraph =
+                Some(paragraph.map_or(range.clone(), |paragraph| paragraph.start..range.end));
+        }
+    }
+
+    if let Some(paragraph) = paragraph {
+        blocks.push(Block::new(text, BlockKind::Content, paragraph, page));
+    }
+
+    for block in &mut blocks {
+        block.range = block.range.start + offset..block.range.end + offset;
+    }
+
+    blocks
+}
@@ -17,12 +17,18 @@ pub enum ChonkyError {
     ReadPdf,
     #[error("pdfium error")]
     Pdfium,
+    #[error("read error from system")]
+    Read,
     #[error("write error to system")]
     Write,
     #[error("Issues with CLI input")]
     Arguments,
+    #[error("invalid chunking configuration")]
+    ChunkConfig,
 }
 
+pub mod chunking;
+
 /// Attempts to link to the `PDFium` library.
 ///
 /// ## Loading strategy
@@ -82,6 +88,25 @@ pub mod pdf_segmentation {
             .map_err(|err| Report::new(err).change_context(ChonkyError::Pdfium))
     }
 
+    /// Extracts the text of every page of a pdf document.
+    ///
+    /// The returned vector contains one entry per page, in page order. The text is not segmented
+    /// any further and is returned in the order `PDFium` reports it.
+    ///
+    /// # Errors
+    ///
+    /// Return an [`ChonkyError::Pdfium`] if the text of a page could not be loaded
+    pub fn extract_page_texts(pdf: &PdfDocument) -> Result<Vec<String>, Report<ChonkyError>> {
+        pdf.pages()
+            .iter()
+            .map(|page| {
+                page.text()
+                    .map(|text| text.all())
+                    .change_context(ChonkyError::Pdfium)
+            })
+            .collect()
+    }
+
     // /// TODO: This function returns the extracted text that is segmented in proper reading order
     // and /// grouped by boundaries such as newline spacing and other layout information,
     // segments can /// contain texts with different formatting (such as a sentence with a
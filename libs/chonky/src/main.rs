@@ -1,29 +1,135 @@
-use std::env;
+use std::{
+    fs,
+    io::{self, BufWriter, Write as _},
+    path::{Path, PathBuf},
+};
 
-use chonky::{ChonkyError, pdf_segmentation};
-use error_stack::{Report, ResultExt as _, ensure};
+use chonky::{
+    ChonkyError,
+    chunking::{self, Chunk, ChunkConfig},
+    pdf_segmentation,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use error_stack::{Report, ResultExt as _};
 
-fn main() -> Result<(), Report<ChonkyError>> {
-    // read file path arguments
-    // TODO: implement with clap
-    let args: Vec<String> = env::args().collect();
+/// General-purpose tooling for segmenting, chunking and embedding files.
+#[derive(Debug, Parser)]
+#[clap(version, about)]
+struct Args {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Split a file into chunks and print them as JSON lines.
+    Chunk(ChunkArgs),
+    /// Render every page of a PDF into a PNG image.
+    Images(ImagesArgs),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Determine the format from the file extension.
+    Auto,
+    Text,
+    Markdown,
+    Pdf,
+}
+
+#[derive(Debug, clap::Args)]
+struct ChunkArgs {
+    /// The file to chunk.
+    path: PathBuf,
+
+    /// The format of the file.
+    #[clap(long, value_enum, default_value_t = Format::Auto)]
+    format: Format,
+
+    /// The maximum number of tokens per chunk.
+    #[clap(long, default_value_t = 512)]
+    max_tokens: usize,
+
+    /// The number of tokens consecutive chunks may share.
+    #[clap(long, default_value_t = 64)]
+    overlap_tokens: usize,
+}
+
+#[derive(Debug, clap::Args)]
+struct ImagesArgs {
+    /// The PDF to render.
+    path: PathBuf,
+
+    /// The folder the page images are written to.
+    #[clap(long, default_value = "./out")]
+    output: PathBuf,
+}
+
+fn detect_format(path: &Path) -> Format {
+    match path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase)
+        .as_deref()
+    {
+        Some("md" | "markdown") => Format::Markdown,
+        Some("pdf") => Format::Pdf,
+        _ => Format::Text,
+    }
+}
+
+fn read_text(path: &Path) -> Result<String, Report<ChonkyError>> {
+    fs::read_to_string(path)
+        .change_context(ChonkyError::Read)
+        .attach_printable_lazy(|| format!("could not read `{}`", path.display()))
+}
+
+fn load_chunks(args: &ChunkArgs) -> Result<Vec<Chunk>, Report<ChonkyError>> {
+    let config = ChunkConfig::new(args.max_tokens, args.overlap_tokens)
+        .change_context(ChonkyError::Arguments)?;
+
+    let format = match args.format {
+        Format::Auto => detect_format(&args.path),
+        format => format,
+    };
+
+    Ok(match format {
+        Format::Auto | Format::Text => chunking::chunk_text(&read_text(&args.path)?, config),
+        Format::Markdown => chunking::chunk_markdown(&read_text(&args.path)?, config),
+        Format::Pdf => {
+            let pdfium = chonky::link_pdfium()?;
+            let path = args.path.to_str().ok_or(ChonkyError::Arguments)?;
+            let pdf = pdf_segmentation::load_pdf(&pdfium, path)?;
+
+            chunking::chunk_pages(&pdf_segmentation::extract_page_texts(&pdf)?, config)
+        }
+    })
+}
 
-    ensure!(args.len() > 1, ChonkyError::Arguments);
+fn chunk(args: &ChunkArgs) -> Result<(), Report<ChonkyError>> {
+    let chunks = load_chunks(args)?;
 
+    let mut stdout = BufWriter::new(io::stdout().lock());
+    for chunk in &chunks {
+        serde_json::to_writer(&mut stdout, chunk).change_context(ChonkyError::Write)?;
+        stdout.write_all(b"\n").change_context(ChonkyError::Write)?;
+    }
+    stdout.flush().change_context(ChonkyError::Write)
+}
+
+fn images(args: &ImagesArgs) -> Result<(), Report<ChonkyError>> {
     let pdfium = chonky::link_pdfium()?;
 
-    let pdf = pdf_segmentation::load_pdf(&pdfium, &args[1]).change_context(ChonkyError::Pdfium)?;
+    let path = args.path.to_str().ok_or(ChonkyError::Arguments)?;
+    let pdf = pdf_segmentation::load_pdf(&pdfium, path)?;
 
-    let preprocessed_pdf =
-        pdf_segmentation::pdf_to_images(&pdf).change_context(ChonkyError::Pdfium)?;
-    //for now we will print all these images to a folder
-    // this will be a seperate function in the future once knowledge about error-stack increases
+    let preprocessed_pdf = pdf_segmentation::pdf_to_images(&pdf)?;
 
-    let output_folder = "./out";
+    fs::create_dir_all(&args.output).change_context(ChonkyError::Write)?;
 
     for (index, image) in preprocessed_pdf.iter().enumerate() {
         // Generate a unique filename for each page image
-        let file_path = format!("{}/page_{}.png", output_folder, index + 1);
+        let file_path = args.output.join(format!("page_{}.png", index + 1));
 
         // Save the image as a PNG file
         image.save(&file_path).change_context(ChonkyError::Write)?;
@@ -31,3 +137,12 @@ fn main() -> Result<(), Report<ChonkyError>> {
 
     Ok(())
 }
+
+fn main() -> Result<(), Report<ChonkyError>> {
+    let args = Args::parse();
+
+    match args.command {
+        Command::Chunk(args) => chunk(&args),
+        Command::Images(args) => images(&args),
+    }
+}
@@ -0,0 +1,1026 @@
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Generics, Ident, LitByteStr};
+
+use crate::{
+    generics::{deserialize_generics, lifetime, with_lifetimes},
+    input::{Body, DefaultValue, Field, Fields, Input, Tagging, Variant},
+};
+
+#[derive(Copy, Clone)]
+enum IdentifierKind {
+    Field,
+    Variant,
+}
+
+/// Generates an enum, which is deserialized from one of the given names.
+///
+/// The identifier is deserialized using `deserialize_identifier`, which means it accepts the name
+/// as a string or bytes, or the index of the name. If `ignore_unknown` is set, any unknown name is
+/// deserialized into the additional `__ignore` variant, instead of returning an error.
+fn identifier(
+    ident: &Ident,
+    names: &[&str],
+    kind: IdentifierKind,
+    ignore_unknown: bool,
+) -> TokenStream {
+    let variants: Vec<_> = (0..names.len())
+        .map(|index| format_ident!("__identifier{index}"))
+        .collect();
+    let bytes: Vec<_> = names
+        .iter()
+        .map(|name| LitByteStr::new(name.as_bytes(), Span::call_site()))
+        .collect();
+    let indices: Vec<_> = (0..names.len()).map(Literal::usize_unsuffixed).collect();
+    let length = names.len();
+
+    let (error, expected, received) = match kind {
+        IdentifierKind::Field => (
+            quote!(::deer::error::UnknownFieldError),
+            quote!(::deer::error::ExpectedField),
+            quote!(::deer::error::ReceivedField),
+        ),
+        IdentifierKind::Variant => (
+            quote!(::deer::error::UnknownVariantError),
+            quote!(::deer::error::ExpectedVariant),
+            quote!(::deer::error::ReceivedVariant),
+        ),
+    };
+
+    let report = quote! {
+        ::deer::export::error_stack::Report::new(::deer::error::Variant::into_error(#error))
+            #(.attach(#expected::new(#names)))*
+    };
+
+    let (ignore, unknown_str, unknown_bytes, unknown_u64) = if ignore_unknown {
+        (
+            quote!(__ignore,),
+            quote!(_ => ::core::result::Result::Ok(#ident::__ignore),),
+            quote!(_ => ::core::result::Result::Ok(#ident::__ignore),),
+            quote!(_ => ::core::result::Result::Ok(#ident::__ignore),),
+        )
+    } else {
+        (
+            TokenStream::new(),
+            quote! {
+                other => ::core::result::Result::Err(
+                    #report
+                        .attach(#received::new(other))
+                        .change_context(::deer::error::VisitorError)
+                ),
+            },
+            quote! {
+                other => {
+                    let mut error = #report;
+
+                    if let ::core::result::Result::Ok(other) = ::core::str::from_utf8(other) {
+                        error = error.attach(#received::new(other));
+                    }
+
+                    ::core::result::Result::Err(error.change_context(::deer::error::VisitorError))
+                }
+            },
+            quote! {
+                _ => ::core::result::Result::Err(
+                    #report.change_context(::deer::error::VisitorError)
+                ),
+            },
+        )
+    };
+
+    quote! {
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, Copy, Clone)]
+        enum #ident {
+            #(#variants,)*
+            #ignore
+        }
+
+        #[automatically_derived]
+        impl ::deer::Reflection for #ident {
+            fn schema(_: &mut ::deer::Document) -> ::deer::Schema {
+                let names: [&'static str; #length] = [#(#names),*];
+
+                ::deer::Schema::new("string").with("enum", names)
+            }
+        }
+
+        #[automatically_derived]
+        impl<'de> ::deer::Deserialize<'de> for #ident {
+            type Reflection = Self;
+
+            fn deserialize<D>(
+                deserializer: D,
+            ) -> ::core::result::Result<
+                Self,
+                ::deer::export::error_stack::Report<::deer::error::DeserializeError>,
+            >
+            where
+                D: ::deer::Deserializer<'de>,
+            {
+                struct IdentifierVisitor;
+
+                impl<'de> ::deer::IdentifierVisitor<'de> for IdentifierVisitor {
+                    type Value = #ident;
+
+                    fn expecting(&self) -> ::deer::Document {
+                        <#ident as ::deer::Reflection>::document()
+                    }
+
+                    fn visit_str(
+                        self,
+                        value: &str,
+                    ) -> ::core::result::Result<
+                        Self::Value,
+                        ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                    > {
+                        match value {
+                            #(#names => ::core::result::Result::Ok(#ident::#variants),)*
+                            #unknown_str
+                        }
+                    }
+
+                    fn visit_bytes(
+                        self,
+                        value: &[u8],
+                    ) -> ::core::result::Result<
+                        Self::Value,
+                        ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                    > {
+                        match value {
+                            #(#bytes => ::core::result::Result::Ok(#ident::#variants),)*
+                            #unknown_bytes
+                        }
+                    }
+
+                    fn visit_u64(
+                        self,
+                        value: u64,
+                    ) -> ::core::result::Result<
+                        Self::Value,
+                        ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                    > {
+                        match value {
+                            #(#indices => ::core::result::Result::Ok(#ident::#variants),)*
+                            #unknown_u64
+                        }
+                    }
+                }
+
+                deserializer
+                    .deserialize_identifier(IdentifierVisitor)
+                    .change_context(::deer::error::DeserializeError)
+            }
+        }
+    }
+}
+
+/// Expression, which is used for a field, that is not present.
+///
+/// `missing` is the expression used for required fields, which deserializes the field from
+/// `NoneDeserializer`, this allows types like `Option` to still be deserialized.
+fn missing_value(field: &Field, missing: &TokenStream) -> TokenStream {
+    match &field.default {
+        DefaultValue::None => missing.clone(),
+        DefaultValue::Trait => quote!(::core::option::Option::Some(
+            ::core::default::Default::default()
+        )),
+        DefaultValue::Path(path) => quote!(::core::option::Option::Some(#path())),
+    }
+}
+
+/// Destructures the fields, after all errors have been checked, and constructs the value.
+fn construct(fields: &[Field], constructor: &TokenStream) -> TokenStream {
+    let idents: Vec<_> = (0..fields.len())
+        .map(|index| format_ident!("__field{index}"))
+        .collect();
+    let members = fields.iter().map(|field| &field.member);
+
+    let destructure = if fields.is_empty() {
+        TokenStream::new()
+    } else {
+        quote! {
+            let (#(::core::option::Option::Some(#idents),)*) = (#(#idents,)*) else {
+                ::core::unreachable!("every field is present if no error has been reported");
+            };
+        }
+    };
+
+    quote! {
+        #destructure
+
+        ::core::result::Result::Ok(#constructor { #(#members: #idents),* })
+    }
+}
+
+/// Body of a `visit_array` implementation, which deserializes the fields by position.
+fn visit_array(fields: &[Field], constructor: &TokenStream) -> TokenStream {
+    let length = fields.len();
+
+    // The bound access substitutes missing items with `none`, which means we'd be unable to
+    // differentiate between a missing value and an explicit `none`, therefore we only bound the
+    // access if no field has a default value.
+    let bound = if fields
+        .iter()
+        .all(|field| matches!(field.default, DefaultValue::None))
+    {
+        quote! {
+            let mut array = array
+                .into_bound(#length)
+                .change_context(::deer::error::VisitorError)?;
+        }
+    } else {
+        quote!(let mut array = array;)
+    };
+
+    let values = fields.iter().enumerate().map(|(index, field)| {
+        let ident = format_ident!("__field{index}");
+        let ty = &field.ty;
+
+        let missing = missing_value(field, &quote! {
+            errors.attempt(
+                <#ty as ::deer::Deserialize<'de>>::deserialize(
+                    ::deer::value::NoneDeserializer::new(array.context())
+                )
+                .attach(::deer::error::Location::Tuple(#index))
+                .change_context(::deer::error::ArrayAccessError)
+            )
+        });
+
+        quote! {
+            let #ident = match array.next::<#ty>() {
+                ::core::option::Option::Some(value) => {
+                    errors.attempt(value.attach(::deer::error::Location::Tuple(#index)))
+                }
+                ::core::option::Option::None => #missing,
+            };
+        }
+    });
+
+    let construct = construct(fields, constructor);
+
+    quote! {
+        #bound
+
+        let mut errors =
+            ::deer::export::error_stack::ReportSink::<::deer::error::ArrayAccessError>::new();
+
+        #(#values)*
+
+        errors.attempt(array.end());
+        errors.finish().change_context(::deer::error::VisitorError)?;
+
+        #construct
+    }
+}
+
+/// Generates `__visit_tag`, which deserializes the discriminant from the first entry of an
+/// object.
+///
+/// `deer` does not buffer values, therefore the tag must always be the first key of the object.
+fn tag_visitor(tag: &str, discriminant: &Ident) -> TokenStream {
+    let identifier = identifier(
+        &format_ident!("__TagKey"),
+        &[tag],
+        IdentifierKind::Field,
+        false,
+    );
+
+    quote! {
+        #identifier
+
+        struct __TagVisitor;
+
+        #[automatically_derived]
+        impl<'de> ::deer::FieldVisitor<'de> for __TagVisitor {
+            type Key = __TagKey;
+            type Value = #discriminant;
+
+            fn visit_value<D>(
+                self,
+                _: Self::Key,
+                deserializer: D,
+            ) -> ::core::result::Result<
+                Self::Value,
+                ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+            >
+            where
+                D: ::deer::Deserializer<'de>,
+            {
+                <#discriminant as ::deer::Deserialize<'de>>::deserialize(deserializer)
+                    .attach(::deer::error::Location::Field(#tag))
+                    .change_context(::deer::error::VisitorError)
+            }
+        }
+
+        fn __visit_tag<'de, A>(
+            object: &mut A,
+        ) -> ::core::result::Result<
+            #discriminant,
+            ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+        >
+        where
+            A: ::deer::ObjectAccess<'de>,
+        {
+            match object.field(__TagVisitor) {
+                ::core::option::Option::Some(discriminant) => {
+                    discriminant.change_context(::deer::error::VisitorError)
+                }
+                ::core::option::Option::None => ::core::result::Result::Err(
+                    ::deer::export::error_stack::Report::new(
+                        ::deer::error::Variant::into_error(::deer::error::MissingError),
+                    )
+                    .attach(::deer::error::ExpectedType::new(
+                        <#discriminant as ::deer::Reflection>::document(),
+                    ))
+                    .attach(::deer::error::Location::Field(#tag))
+                    .change_context(::deer::error::VisitorError),
+                ),
+            }
+        }
+    }
+}
+
+struct Context<'a> {
+    input: &'a Input,
+    /// The type, which is derived, including its generic parameters, e.g. `Example<T>`.
+    this: TokenStream,
+    /// Generics of all `'de` implementations.
+    generics: Generics,
+}
+
+impl Context<'_> {
+    /// Generates a `Visitor`, which deserializes a tuple struct or tuple variant from an array.
+    fn tuple_visitor(
+        &self,
+        visitor: &Ident,
+        fields: &[Field],
+        constructor: &TokenStream,
+    ) -> TokenStream {
+        let this = &self.this;
+        let (struct_generics, ty_generics, struct_where_clause) =
+            self.input.generics.split_for_impl();
+        let (impl_generics, _, where_clause) = self.generics.split_for_impl();
+
+        let body = visit_array(fields, constructor);
+
+        quote! {
+            struct #visitor #struct_generics (
+                ::core::marker::PhantomData<fn() -> #this>
+            ) #struct_where_clause;
+
+            #[automatically_derived]
+            impl #impl_generics ::deer::Visitor<'de> for #visitor #ty_generics #where_clause {
+                type Value = #this;
+
+                fn expecting(&self) -> ::deer::Document {
+                    <#this as ::deer::Reflection>::document()
+                }
+
+                fn visit_array<A>(
+                    self,
+                    array: A,
+                ) -> ::core::result::Result<
+                    Self::Value,
+                    ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                >
+                where
+                    A: ::deer::ArrayAccess<'de>,
+                {
+                    #body
+                }
+            }
+        }
+    }
+
+    /// Generates a `StructVisitor`, which deserializes a struct or struct variant from an object,
+    /// or from an array, in which case the fields are deserialized by position.
+    fn struct_visitor(
+        &self,
+        visitor: &Ident,
+        fields: &[Field],
+        constructor: &TokenStream,
+    ) -> TokenStream {
+        let this = &self.this;
+        let (struct_generics, ty_generics, struct_where_clause) =
+            self.input.generics.split_for_impl();
+        let (impl_generics, _, where_clause) = self.generics.split_for_impl();
+
+        let identifier_ident = format_ident!("{visitor}Field");
+        let field_visitor = format_ident!("{visitor}FieldVisitor");
+
+        let names: Vec<_> = fields.iter().map(|field| field.name.as_str()).collect();
+        let identifier = identifier(
+            &identifier_ident,
+            &names,
+            IdentifierKind::Field,
+            !self.input.deny_unknown_fields,
+        );
+
+        let idents: Vec<_> = (0..fields.len())
+            .map(|index| format_ident!("__field{index}"))
+            .collect();
+        let variants: Vec<_> = (0..fields.len())
+            .map(|index| format_ident!("__identifier{index}"))
+            .collect();
+        let types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+
+        let a = lifetime("'__a");
+        let de = lifetime("'de");
+
+        let field_generics = with_lifetimes(&self.input.generics, &[&a]);
+        let (field_struct_generics, field_ty_generics, field_struct_where_clause) =
+            field_generics.split_for_impl();
+        let field_impl_generics = with_lifetimes(&self.generics, &[&a]);
+        let (field_impl_generics, _, field_where_clause) = field_impl_generics.split_for_impl();
+
+        let ignore = if self.input.deny_unknown_fields {
+            TokenStream::new()
+        } else {
+            quote! {
+                #identifier_ident::__ignore => {
+                    <::deer::helpers::IgnoredAny as ::deer::Deserialize<#de>>::deserialize(
+                        deserializer,
+                    )
+                    .map(|_| ())
+                    .change_context(::deer::error::VisitorError)
+                }
+            }
+        };
+
+        let missing = fields.iter().enumerate().map(|(index, field)| {
+            let ident = &idents[index];
+            let ty = &field.ty;
+            let name = &field.name;
+
+            let missing = missing_value(field, &quote! {
+                errors.attempt(
+                    <#ty as ::deer::Deserialize<#de>>::deserialize(
+                        ::deer::value::NoneDeserializer::new(object.context())
+                    )
+                    .attach(::deer::error::Location::Field(#name))
+                    .change_context(::deer::error::ObjectAccessError)
+                )
+            });
+
+            quote! {
+                let #ident = match #ident {
+                    ::core::option::Option::Some(value) => ::core::option::Option::Some(value),
+                    ::core::option::Option::None => #missing,
+                };
+            }
+        });
+
+        let construct = construct(fields, constructor);
+        let visit_array = visit_array(fields, constructor);
+
+        quote! {
+            #identifier
+
+            struct #field_visitor #field_struct_generics #field_struct_where_clause {
+                #(#idents: &#a mut ::core::option::Option<#types>,)*
+                __marker: ::core::marker::PhantomData<(&#a (), fn() -> #this)>,
+            }
+
+            #[automatically_derived]
+            impl #field_impl_generics ::deer::FieldVisitor<#de>
+                for #field_visitor #field_ty_generics #field_where_clause
+            {
+                type Key = #identifier_ident;
+                type Value = ();
+
+                fn visit_value<D>(
+                    self,
+                    key: Self::Key,
+                    deserializer: D,
+                ) -> ::core::result::Result<
+                    Self::Value,
+                    ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                >
+                where
+                    D: ::deer::Deserializer<#de>,
+                {
+                    match key {
+                        #(
+                            #identifier_ident::#variants => {
+                                let value = <#types as ::deer::Deserialize<#de>>::deserialize(
+                                    deserializer,
+                                )
+                                .attach(::deer::error::Location::Field(#names))
+                                .change_context(::deer::error::VisitorError)?;
+
+                                if self.#idents.is_some() {
+                                    return ::core::result::Result::Err(
+                                        ::deer::export::error_stack::Report::new(
+                                            ::deer::error::Variant::into_error(
+                                                ::deer::error::DuplicateFieldError,
+                                            ),
+                                        )
+                                        .attach(::deer::error::DuplicateField::new(#names))
+                                        .attach(::deer::error::Location::Field(#names))
+                                        .change_context(::deer::error::VisitorError),
+                                    );
+                                }
+
+                                *self.#idents = ::core::option::Option::Some(value);
+
+                                ::core::result::Result::Ok(())
+                            }
+                        )*
+                        #ignore
+                    }
+                }
+            }
+
+            struct #visitor #struct_generics (
+                ::core::marker::PhantomData<fn() -> #this>
+            ) #struct_where_clause;
+
+            #[automatically_derived]
+            impl #impl_generics ::deer::StructVisitor<#de> for #visitor #ty_generics #where_clause {
+                type Value = #this;
+
+                fn expecting(&self) -> ::deer::Document {
+                    <#this as ::deer::Reflection>::document()
+                }
+
+                fn visit_array<A>(
+                    self,
+                    array: A,
+                ) -> ::core::result::Result<
+                    Self::Value,
+                    ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                >
+                where
+                    A: ::deer::ArrayAccess<#de>,
+                {
+                    #visit_array
+                }
+
+                fn visit_object<A>(
+                    self,
+                    mut object: A,
+                ) -> ::core::result::Result<
+                    Self::Value,
+                    ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                >
+                where
+                    A: ::deer::ObjectAccess<#de>,
+                {
+                    #(let mut #idents = ::core::option::Option::None;)*
+
+                    let mut errors = ::deer::export::error_stack::ReportSink::<
+                        ::deer::error::ObjectAccessError,
+                    >::new();
+
+                    while let ::core::option::Option::Some(field) =
+                        object.field(#field_visitor {
+                            #(#idents: &mut #idents,)*
+                            __marker: ::core::marker::PhantomData,
+                        })
+                    {
+                        errors.attempt(field);
+                    }
+
+                    #(#missing)*
+
+                    errors.attempt(object.end());
+                    errors.finish().change_context(::deer::error::VisitorError)?;
+
+                    #construct
+                }
+            }
+        }
+    }
+
+    /// Items needed to deserialize the content of a variant, and an expression, which
+    /// deserializes the variant from `deserializer`.
+    ///
+    /// The error of the expression is a `Report`, which can be converted into a `VisitorError`.
+    fn variant_content(
+        &self,
+        index: usize,
+        variant: &Variant,
+        deserializer: &TokenStream,
+    ) -> (TokenStream, TokenStream) {
+        let ident = &self.input.ident;
+        let variant_ident = &variant.ident;
+        let constructor = quote!(#ident::#variant_ident);
+        let visitor = format_ident!("__Variant{index}Visitor");
+
+        if let Some(field) = variant.fields.as_newtype() {
+            let ty = &field.ty;
+
+            return (TokenStream::new(), quote! {
+                <#ty as ::deer::Deserialize<'de>>::deserialize(#deserializer)
+                    .map(#constructor)
+            });
+        }
+
+        match &variant.fields {
+            Fields::Unit => (TokenStream::new(), quote! {
+                <::deer::helpers::ExpectNone as ::deer::Deserialize<'de>>::deserialize(
+                    #deserializer,
+                )
+                .map(|_| #constructor)
+            }),
+            Fields::Unnamed(fields) => {
+                (self.tuple_visitor(&visitor, fields, &constructor), quote! {
+                    ::deer::Deserializer::deserialize_array(
+                        #deserializer,
+                        #visitor(::core::marker::PhantomData),
+                    )
+                })
+            }
+            Fields::Named(fields) => (
+                self.struct_visitor(&visitor, fields, &constructor),
+                quote! {
+                    ::deer::Deserializer::deserialize_struct(
+                        #deserializer,
+                        #visitor(::core::marker::PhantomData),
+                    )
+                },
+            ),
+        }
+    }
+
+    fn deserialize_struct(&self, fields: &Fields) -> (TokenStream, TokenStream) {
+        let ident = &self.input.ident;
+        let visitor = format_ident!("__Visitor");
+
+        if let Some(field) = fields.as_newtype() {
+            let ty = &field.ty;
+
+            return (TokenStream::new(), quote! {
+                <#ty as ::deer::Deserialize<'de>>::deserialize(deserializer).map(#ident)
+            });
+        }
+
+        match fields {
+            Fields::Unit => (TokenStream::new(), quote! {
+                <() as ::deer::Deserialize<'de>>::deserialize(deserializer).map(|()| #ident)
+            }),
+            Fields::Unnamed(fields) => (
+                self.tuple_visitor(&visitor, fields, &quote!(#ident)),
+                quote! {
+                    deserializer
+                        .deserialize_array(#visitor(::core::marker::PhantomData))
+                        .change_context(::deer::error::DeserializeError)
+                },
+            ),
+            Fields::Named(fields) => (
+                self.struct_visitor(&visitor, fields, &quote!(#ident)),
+                quote! {
+                    deserializer
+                        .deserialize_struct(#visitor(::core::marker::PhantomData))
+                        .change_context(::deer::error::DeserializeError)
+                },
+            ),
+        }
+    }
+
+    fn deserialize_enum(&self, variants: &[Variant]) -> (TokenStream, TokenStream) {
+        let this = &self.this;
+        let (struct_generics, ty_generics, struct_where_clause) =
+            self.input.generics.split_for_impl();
+        let (impl_generics, _, where_clause) = self.generics.split_for_impl();
+
+        let names: Vec<_> = variants
+            .iter()
+            .map(|variant| variant.name.as_str())
+            .collect();
+        let discriminants: Vec<_> = (0..variants.len())
+            .map(|index| format_ident!("__identifier{index}"))
+            .collect();
+
+        let discriminant = format_ident!("__Discriminant");
+        let identifier = identifier(&discriminant, &names, IdentifierKind::Variant, false);
+
+        let mut items = vec![identifier];
+
+        match &self.input.tagging {
+            Tagging::External => {
+                let arms: Vec<_> = variants
+                    .iter()
+                    .enumerate()
+                    .map(|(index, variant)| {
+                        let (item, content) =
+                            self.variant_content(index, variant, &quote!(deserializer));
+                        items.push(item);
+
+                        content
+                    })
+                    .collect();
+
+                items.push(quote! {
+                    struct __EnumVisitor #struct_generics (
+                        ::core::marker::PhantomData<fn() -> #this>
+                    ) #struct_where_clause;
+
+                    #[automatically_derived]
+                    impl #impl_generics ::deer::EnumVisitor<'de>
+                        for __EnumVisitor #ty_generics #where_clause
+                    {
+                        type Discriminant = #discriminant;
+                        type Value = #this;
+
+                        fn expecting(&self) -> ::deer::Document {
+                            <#this as ::deer::Reflection>::document()
+                        }
+
+                        fn visit_value<D>(
+                            self,
+                            discriminant: Self::Discriminant,
+                            deserializer: D,
+                        ) -> ::core::result::Result<
+                            Self::Value,
+                            ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                        >
+                        where
+                            D: ::deer::Deserializer<'de>,
+                        {
+                            match discriminant {
+                                #(
+                                    #discriminant::#discriminants => #arms
+                                        .attach(::deer::error::Location::Variant(#names))
+                                        .change_context(::deer::error::VisitorError),
+                                )*
+                            }
+                        }
+                    }
+                });
+
+                (quote!(#(#items)*), quote! {
+                    deserializer
+                        .deserialize_enum(__EnumVisitor(::core::marker::PhantomData))
+                        .change_context(::deer::error::DeserializeError)
+                })
+            }
+            Tagging::Internal { tag } => {
+                items.push(tag_visitor(tag, &discriminant));
+
+                let arms: Vec<_> = variants
+                    .iter()
+                    .enumerate()
+                    .map(|(index, variant)| self.internal_variant(index, variant, &mut items))
+                    .collect();
+
+                items.push(quote! {
+                    struct __Visitor #struct_generics (
+                        ::core::marker::PhantomData<fn() -> #this>
+                    ) #struct_where_clause;
+
+                    #[automatically_derived]
+                    impl #impl_generics ::deer::Visitor<'de> for __Visitor #ty_generics #where_clause {
+                        type Value = #this;
+
+                        fn expecting(&self) -> ::deer::Document {
+                            <#this as ::deer::Reflection>::document()
+                        }
+
+                        fn visit_object<A>(
+                            self,
+                            mut object: A,
+                        ) -> ::core::result::Result<
+                            Self::Value,
+                            ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                        >
+                        where
+                            A: ::deer::ObjectAccess<'de>,
+                        {
+                            let discriminant = __visit_tag(&mut object)?;
+
+                            match discriminant {
+                                #(
+                                    #discriminant::#discriminants => #arms
+                                        .attach(::deer::error::Location::Variant(#names))
+                                        .change_context(::deer::error::VisitorError),
+                                )*
+                            }
+                        }
+                    }
+                });
+
+                (quote!(#(#items)*), quote! {
+                    deserializer
+                        .deserialize_object(__Visitor(::core::marker::PhantomData))
+                        .change_context(::deer::error::DeserializeError)
+                })
+            }
+            Tagging::Adjacent { tag, content } => {
+                items.push(tag_visitor(tag, &discriminant));
+                items.push(identifier(
+                    &format_ident!("__ContentKey"),
+                    &[content.as_str()],
+                    IdentifierKind::Field,
+                    false,
+                ));
+
+                let arms: Vec<_> = variants
+                    .iter()
+                    .enumerate()
+                    .map(|(index, variant)| {
+                        let (item, content) =
+                            self.variant_content(index, variant, &quote!(deserializer));
+                        items.push(item);
+
+                        content
+                    })
+                    .collect();
+
+                items.push(quote! {
+                    struct __ContentVisitor #struct_generics #struct_where_clause {
+                        discriminant: #discriminant,
+                        __marker: ::core::marker::PhantomData<fn() -> #this>,
+                    }
+
+                    impl #impl_generics __ContentVisitor #ty_generics #where_clause {
+                        fn visit<D>(
+                            self,
+                            deserializer: D,
+                        ) -> ::core::result::Result<
+                            #this,
+                            ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                        >
+                        where
+                            D: ::deer::Deserializer<'de>,
+                        {
+                            match self.discriminant {
+                                #(
+                                    #discriminant::#discriminants => #arms
+                                        .attach(::deer::error::Location::Variant(#names))
+                                        .change_context(::deer::error::VisitorError),
+                                )*
+                            }
+                        }
+                    }
+
+                    #[automatically_derived]
+                    impl #impl_generics ::deer::FieldVisitor<'de>
+                        for __ContentVisitor #ty_generics #where_clause
+                    {
+                        type Key = __ContentKey;
+                        type Value = #this;
+
+                        fn visit_value<D>(
+                            self,
+                            _: Self::Key,
+                            deserializer: D,
+                        ) -> ::core::result::Result<
+                            Self::Value,
+                            ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                        >
+                        where
+                            D: ::deer::Deserializer<'de>,
+                        {
+                            self.visit(deserializer)
+                                .attach(::deer::error::Location::Field(#content))
+                        }
+                    }
+
+                    struct __Visitor #struct_generics (
+                        ::core::marker::PhantomData<fn() -> #this>
+                    ) #struct_where_clause;
+
+                    #[automatically_derived]
+                    impl #impl_generics ::deer::Visitor<'de> for __Visitor #ty_generics #where_clause {
+                        type Value = #this;
+
+                        fn expecting(&self) -> ::deer::Document {
+                            <#this as ::deer::Reflection>::document()
+                        }
+
+                        fn visit_object<A>(
+                            self,
+                            mut object: A,
+                        ) -> ::core::result::Result<
+                            Self::Value,
+                            ::deer::export::error_stack::Report<::deer::error::VisitorError>,
+                        >
+                        where
+                            A: ::deer::ObjectAccess<'de>,
+                        {
+                            let discriminant = __visit_tag(&mut object)?;
+
+                            let visitor = __ContentVisitor {
+                                discriminant,
+                                __marker: ::core::marker::PhantomData,
+                            };
+
+                            // the content is optional, as unit variants have no content
+                            let value = match object.try_field(visitor) {
+                                ::core::result::Result::Ok(value) => {
+                                    value.change_context(::deer::error::VisitorError)
+                                }
+                                ::core::result::Result::Err(visitor) => visitor.visit(
+                                    ::deer::value::NoneDeserializer::new(object.context()),
+                                ),
+                            };
+
+                            (value, object.end().change_context(::deer::error::VisitorError))
+                                .try_collect()
+                                .map(|(value, ())| value)
+                                .change_context(::deer::error::VisitorError)
+                        }
+                    }
+                });
+
+                (quote!(#(#items)*), quote! {
+                    deserializer
+                        .deserialize_object(__Visitor(::core::marker::PhantomData))
+                        .change_context(::deer::error::DeserializeError)
+                })
+            }
+        }
+    }
+
+    /// Expression, which deserializes the variant of an internally tagged enum from the
+    /// remaining entries of `object`.
+    fn internal_variant(
+        &self,
+        index: usize,
+        variant: &Variant,
+        items: &mut Vec<TokenStream>,
+    ) -> TokenStream {
+        let ident = &self.input.ident;
+        let variant_ident = &variant.ident;
+        let constructor = quote!(#ident::#variant_ident);
+
+        if let Some(field) = variant.fields.as_newtype() {
+            let ty = &field.ty;
+
+            return quote! {
+                <#ty as ::deer::Deserialize<'de>>::deserialize(
+                    ::deer::helpers::RemainingObjectDeserializer::new(object),
+                )
+                .map(#constructor)
+            };
+        }
+
+        match &variant.fields {
+            Fields::Named(fields) => {
+                let visitor = format_ident!("__Variant{index}Visitor");
+                items.push(self.struct_visitor(&visitor, fields, &constructor));
+
+                quote! {
+                    ::deer::StructVisitor::visit_object(
+                        #visitor(::core::marker::PhantomData),
+                        object,
+                    )
+                }
+            }
+            // tuple variants are rejected while parsing the input
+            Fields::Unit | Fields::Unnamed(_) => quote! {
+                object.end().map(|()| #constructor)
+            },
+        }
+    }
+}
+
+pub(crate) fn expand(input: &Input) -> TokenStream {
+    let ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let this = quote!(#ident #ty_generics);
+
+    let de = lifetime("'de");
+    let generics = deserialize_generics(&input.generics, &[&de], &de, &this);
+
+    let context = Context {
+        input,
+        this,
+        generics,
+    };
+
+    let (items, body) = match &input.body {
+        Body::Struct(fields) => context.deserialize_struct(fields),
+        Body::Enum(variants) => context.deserialize_enum(variants),
+    };
+
+    let (impl_generics, _, where_clause) = context.generics.split_for_impl();
+
+    quote! {
+        const _: () = {
+            #[allow(unused_imports)]
+            use ::deer::export::error_stack::{ResultExt as _, TryReportTupleExt as _};
+
+            #items
+
+            #[automatically_derived]
+            impl #impl_generics ::deer::Deserialize<'de> for #ident #ty_generics #where_clause {
+                type Reflection = Self;
+
+                fn deserialize<D>(
+                    deserializer: D,
+                ) -> ::core::result::Result<
+                    Self,
+                    ::deer::export::error_stack::Report<::deer::error::DeserializeError>,
+                >
+                where
+                    D: ::deer::Deserializer<'de>,
+                {
+                    #body
+                }
+            }
+        };
+    }
+}
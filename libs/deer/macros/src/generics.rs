@@ -0,0 +1,62 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{GenericParam, Generics, Lifetime, LifetimeParam, WherePredicate, parse_quote};
+
+pub(crate) fn lifetime(name: &str) -> Lifetime {
+    Lifetime::new(name, Span::call_site())
+}
+
+/// Prepends the given lifetimes to the generic parameters.
+pub(crate) fn with_lifetimes(generics: &Generics, lifetimes: &[&Lifetime]) -> Generics {
+    let mut generics = generics.clone();
+
+    for lifetime in lifetimes.iter().rev() {
+        generics.params.insert(
+            0,
+            GenericParam::Lifetime(LifetimeParam::new((*lifetime).clone())),
+        );
+    }
+
+    generics
+}
+
+/// Adds a bound to every type parameter.
+pub(crate) fn with_bound(mut generics: Generics, bound: &TokenStream) -> Generics {
+    let predicates: Vec<WherePredicate> = generics
+        .type_params()
+        .map(|param| {
+            let ident = &param.ident;
+            parse_quote!(#ident: #bound)
+        })
+        .collect();
+
+    generics.make_where_clause().predicates.extend(predicates);
+    generics
+}
+
+/// Generics of an implementation, which is generic over the `'de` lifetime of `Deserialize`.
+///
+/// Every type parameter must implement `Deserialize<'de>`, and if the type is generic, the type
+/// itself must implement `Reflection`, which is used as the reflection of the type.
+pub(crate) fn deserialize_generics(
+    generics: &Generics,
+    lifetimes: &[&Lifetime],
+    de: &Lifetime,
+    this: &TokenStream,
+) -> Generics {
+    let has_type_params = generics.type_params().next().is_some();
+
+    let mut generics = with_bound(
+        with_lifetimes(generics, lifetimes),
+        &quote!(::deer::Deserialize<#de>),
+    );
+
+    if has_type_params {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#this: ::deer::Reflection));
+    }
+
+    generics
+}
@@ -0,0 +1,295 @@
+use syn::{
+    Attribute, Data, DeriveInput, ExprPath, GenericParam, Generics, Ident, LitStr, Member, Type,
+    spanned::Spanned as _,
+};
+
+/// How the variant of an enum is represented.
+pub(crate) enum Tagging {
+    /// `{"Variant": content}`, unit variants are represented as `"Variant"`.
+    External,
+    /// `{"tag": "Variant", ...fields}`
+    Internal { tag: String },
+    /// `{"tag": "Variant", "content": content}`
+    Adjacent { tag: String, content: String },
+}
+
+pub(crate) enum DefaultValue {
+    /// The field is required.
+    None,
+    /// Use [`Default::default`] if the field is missing.
+    Trait,
+    /// Call the function at the given path if the field is missing.
+    Path(ExprPath),
+}
+
+pub(crate) struct Field {
+    pub(crate) member: Member,
+    /// Name of the field in the serialized representation.
+    pub(crate) name: String,
+    pub(crate) ty: Type,
+    pub(crate) default: DefaultValue,
+}
+
+pub(crate) enum Fields {
+    Named(Vec<Field>),
+    Unnamed(Vec<Field>),
+    Unit,
+}
+
+impl Fields {
+    /// Returns the single field of a newtype, e.g. `struct Meters(f64)`.
+    pub(crate) fn as_newtype(&self) -> Option<&Field> {
+        match self {
+            Self::Unnamed(fields) if fields.len() == 1 => fields.first(),
+            Self::Named(_) | Self::Unnamed(_) | Self::Unit => None,
+        }
+    }
+}
+
+pub(crate) struct Variant {
+    pub(crate) ident: Ident,
+    /// Name of the variant in the serialized representation.
+    pub(crate) name: String,
+    pub(crate) fields: Fields,
+}
+
+pub(crate) enum Body {
+    Struct(Fields),
+    Enum(Vec<Variant>),
+}
+
+pub(crate) struct Input {
+    pub(crate) ident: Ident,
+    pub(crate) generics: Generics,
+    pub(crate) tagging: Tagging,
+    pub(crate) deny_unknown_fields: bool,
+    pub(crate) body: Body,
+}
+
+struct ContainerAttributes {
+    tag: Option<LitStr>,
+    content: Option<LitStr>,
+    deny_unknown_fields: bool,
+}
+
+fn parse_container_attributes(attributes: &[Attribute]) -> syn::Result<ContainerAttributes> {
+    let mut output = ContainerAttributes {
+        tag: None,
+        content: None,
+        deny_unknown_fields: false,
+    };
+
+    for attribute in attributes {
+        if !attribute.path().is_ident("deer") {
+            continue;
+        }
+
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tag") {
+                output.tag = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("content") {
+                output.content = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("deny_unknown_fields") {
+                output.deny_unknown_fields = true;
+            } else {
+                return Err(meta.error(
+                    "unknown container attribute, expected one of `tag`, `content` or \
+                     `deny_unknown_fields`",
+                ));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(output)
+}
+
+struct FieldAttributes {
+    rename: Option<String>,
+    default: DefaultValue,
+}
+
+fn parse_field_attributes(attributes: &[Attribute]) -> syn::Result<FieldAttributes> {
+    let mut output = FieldAttributes {
+        rename: None,
+        default: DefaultValue::None,
+    };
+
+    for attribute in attributes {
+        if !attribute.path().is_ident("deer") {
+            continue;
+        }
+
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                let name: LitStr = meta.value()?.parse()?;
+                output.rename = Some(name.value());
+            } else if meta.path.is_ident("default") {
+                output.default = if meta.input.peek(syn::Token![=]) {
+                    let path: LitStr = meta.value()?.parse()?;
+                    DefaultValue::Path(path.parse()?)
+                } else {
+                    DefaultValue::Trait
+                };
+            } else {
+                return Err(
+                    meta.error("unknown field attribute, expected one of `rename` or `default`")
+                );
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(output)
+}
+
+fn parse_variant_attributes(attributes: &[Attribute]) -> syn::Result<Option<String>> {
+    let mut rename = None;
+
+    for attribute in attributes {
+        if !attribute.path().is_ident("deer") {
+            continue;
+        }
+
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                let name: LitStr = meta.value()?.parse()?;
+                rename = Some(name.value());
+
+                Ok(())
+            } else {
+                Err(meta.error("unknown variant attribute, expected `rename`"))
+            }
+        })?;
+    }
+
+    Ok(rename)
+}
+
+fn parse_fields(fields: syn::Fields) -> syn::Result<Fields> {
+    let is_named = matches!(fields, syn::Fields::Named(_));
+    if matches!(fields, syn::Fields::Unit) {
+        return Ok(Fields::Unit);
+    }
+
+    let fields = fields
+        .into_iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let attributes = parse_field_attributes(&field.attrs)?;
+
+            let member = field
+                .ident
+                .map_or_else(|| Member::Unnamed(index.into()), Member::Named);
+
+            let name = attributes.rename.unwrap_or_else(|| match &member {
+                // raw identifiers are serialized without their prefix
+                Member::Named(ident) => ident.to_string().trim_start_matches("r#").to_owned(),
+                Member::Unnamed(index) => index.index.to_string(),
+            });
+
+            Ok(Field {
+                member,
+                name,
+                ty: field.ty,
+                default: attributes.default,
+            })
+        })
+        .collect::<syn::Result<_>>()?;
+
+    Ok(if is_named {
+        Fields::Named(fields)
+    } else {
+        Fields::Unnamed(fields)
+    })
+}
+
+impl Input {
+    pub(crate) fn parse(input: DeriveInput) -> syn::Result<Self> {
+        if let Some(lifetime) = input
+            .generics
+            .params
+            .iter()
+            .find(|param| matches!(param, GenericParam::Lifetime(_)))
+        {
+            return Err(syn::Error::new(
+                lifetime.span(),
+                "deriving for types with lifetime parameters is not supported, as the reflection \
+                 of a type must be `'static`",
+            ));
+        }
+
+        let attributes = parse_container_attributes(&input.attrs)?;
+
+        let tagging = match (attributes.tag, attributes.content) {
+            (None, None) => Tagging::External,
+            (Some(tag), None) => Tagging::Internal { tag: tag.value() },
+            (Some(tag), Some(content)) => Tagging::Adjacent {
+                tag: tag.value(),
+                content: content.value(),
+            },
+            (None, Some(content)) => {
+                return Err(syn::Error::new(
+                    content.span(),
+                    "`content` can only be used together with `tag`",
+                ));
+            }
+        };
+
+        let body = match input.data {
+            Data::Struct(data) => {
+                if !matches!(tagging, Tagging::External) {
+                    return Err(syn::Error::new(
+                        input.ident.span(),
+                        "`tag` and `content` can only be used on enums",
+                    ));
+                }
+
+                Body::Struct(parse_fields(data.fields)?)
+            }
+            Data::Enum(data) => {
+                let variants = data
+                    .variants
+                    .into_iter()
+                    .map(|variant| {
+                        let rename = parse_variant_attributes(&variant.attrs)?;
+                        let fields = parse_fields(variant.fields)?;
+
+                        if matches!(tagging, Tagging::Internal { .. })
+                            && matches!(&fields, Fields::Unnamed(fields) if fields.len() != 1)
+                        {
+                            return Err(syn::Error::new(
+                                variant.ident.span(),
+                                "tuple variants cannot be used with internally tagged enums",
+                            ));
+                        }
+
+                        Ok(Variant {
+                            name: rename.unwrap_or_else(|| variant.ident.to_string()),
+                            ident: variant.ident,
+                            fields,
+                        })
+                    })
+                    .collect::<syn::Result<_>>()?;
+
+                Body::Enum(variants)
+            }
+            Data::Union(data) => {
+                return Err(syn::Error::new(
+                    data.union_token.span(),
+                    "unions are not supported",
+                ));
+            }
+        };
+
+        Ok(Self {
+            ident: input.ident,
+            generics: input.generics,
+            tagging,
+            deny_unknown_fields: attributes.deny_unknown_fields,
+            body,
+        })
+    }
+}
@@ -1,4 +1,45 @@
-//! Intentionally left blank for now!
+//! Derive macros for `deer`.
+//!
+//! This crate should not be used directly, instead enable the `derive` feature of `deer`, which
+//! re-exports [`Deserialize`](macro@Deserialize) and [`Reflection`](macro@Reflection).
+//!
+//! # Attributes
+//!
+//! Both macros are configured using the `#[deer(...)]` attribute.
+//!
+//! Container attributes:
+//!
+//! - `tag = "..."`: (enums only) represent the enum as an internally tagged object, the tag must be
+//!   the first key of the object, as `deer` does not buffer values.
+//! - `content = "..."`: (enums only, requires `tag`) represent the enum as an adjacently tagged
+//!   object, where the variant is stored under the tag and the content under the given key.
+//! - `deny_unknown_fields`: return an error if an unknown field is encountered, instead of ignoring
+//!   it.
+//!
+//! Field attributes:
+//!
+//! - `rename = "..."`: use the given name instead of the name of the field.
+//! - `default`: use [`Default::default`] if the field is missing.
+//! - `default = "path"`: call the function at the given path if the field is missing.
+//!
+//! Variant attributes:
+//!
+//! - `rename = "..."`: use the given name instead of the name of the variant.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use deer::{Deserialize, Reflection};
+//!
+//! #[derive(Deserialize, Reflection)]
+//! #[deer(deny_unknown_fields)]
+//! struct Example {
+//!     #[deer(rename = "type")]
+//!     kind: String,
+//!     #[deer(default)]
+//!     count: u32,
+//! }
+//! ```
 
 #![warn(
     missing_docs,
@@ -8,3 +49,40 @@
     clippy::mod_module_files
 )]
 #![forbid(unsafe_code)]
+
+mod de;
+mod generics;
+mod input;
+mod reflection;
+
+use proc_macro::TokenStream;
+use syn::{DeriveInput, parse_macro_input};
+
+use crate::input::Input;
+
+/// Derives `deer::Deserialize` for a struct or enum.
+///
+/// The `Reflection` of the type is the type itself, therefore [`Reflection`](macro@Reflection)
+/// must be derived as well.
+#[proc_macro_derive(Deserialize, attributes(deer))]
+pub fn derive_deserialize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    Input::parse(input)
+        .map_or_else(syn::Error::into_compile_error, |input| de::expand(&input))
+        .into()
+}
+
+/// Derives `deer::Reflection` for a struct or enum.
+///
+/// The schema mirrors the representation used by [`Deserialize`](macro@Deserialize).
+#[proc_macro_derive(Reflection, attributes(deer))]
+pub fn derive_reflection(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    Input::parse(input)
+        .map_or_else(syn::Error::into_compile_error, |input| {
+            reflection::expand(&input)
+        })
+        .into()
+}
@@ -0,0 +1,268 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Generics, Ident};
+
+use crate::{
+    generics::with_bound,
+    input::{Body, Field, Fields, Input, Tagging, Variant},
+};
+
+/// Expression, which adds the reflection of the type of the field to `doc`.
+fn reference(field: &Field) -> TokenStream {
+    let ty = &field.ty;
+
+    quote!(doc.add::<<#ty as ::deer::Deserialize>::Reflection>())
+}
+
+/// Expression, which evaluates to the `Properties` of the given fields.
+///
+/// `extra` is prepended to the properties, and is used for the tag of enums.
+fn properties(fields: &[Field], extra: &[TokenStream]) -> TokenStream {
+    let names = fields.iter().map(|field| &field.name);
+    let references = fields.iter().map(reference);
+
+    quote! {
+        ::deer::helpers::Properties([
+            #(#extra,)*
+            #((#names, #references),)*
+        ])
+    }
+}
+
+fn additional_properties(deny_unknown_fields: bool) -> TokenStream {
+    if deny_unknown_fields {
+        quote!(.with("additionalProperties", false))
+    } else {
+        TokenStream::new()
+    }
+}
+
+/// Expression, which evaluates to the schema of the given fields.
+fn fields_schema(fields: &Fields, deny_unknown_fields: bool) -> TokenStream {
+    if let Some(field) = fields.as_newtype() {
+        let ty = &field.ty;
+
+        return quote! {
+            <<#ty as ::deer::Deserialize>::Reflection as ::deer::Reflection>::schema(doc)
+        };
+    }
+
+    match fields {
+        Fields::Unit => quote! {
+            <<() as ::deer::Deserialize>::Reflection as ::deer::Reflection>::schema(doc)
+        },
+        Fields::Unnamed(fields) => {
+            let references = fields.iter().map(reference);
+
+            quote! {
+                ::deer::Schema::new("array")
+                    .with("prefixItems", [#(#references),*])
+                    .with("items", false)
+            }
+        }
+        Fields::Named(fields) => {
+            let properties = properties(fields, &[]);
+            let additional_properties = additional_properties(deny_unknown_fields);
+
+            quote! {
+                ::deer::Schema::new("object")
+                    .with("properties", #properties)
+                    #additional_properties
+            }
+        }
+    }
+}
+
+struct Context<'a> {
+    input: &'a Input,
+    /// The type, which is derived, including its generic parameters, e.g. `Example<T>`.
+    this: TokenStream,
+    /// Generics of all `Reflection` implementations.
+    generics: Generics,
+}
+
+impl Context<'_> {
+    /// Generates a type, which only exists to implement `Reflection` with the given schema.
+    fn reflection_type(&self, ident: &Ident, schema: &TokenStream) -> TokenStream {
+        let this = &self.this;
+        let (struct_generics, ty_generics, struct_where_clause) =
+            self.input.generics.split_for_impl();
+        let (impl_generics, _, where_clause) = self.generics.split_for_impl();
+
+        quote! {
+            // only used to reference the schema, therefore never constructed
+            #[allow(dead_code)]
+            struct #ident #struct_generics (
+                ::core::marker::PhantomData<fn() -> #this>
+            ) #struct_where_clause;
+
+            #[automatically_derived]
+            impl #impl_generics ::deer::Reflection for #ident #ty_generics #where_clause {
+                #[allow(unused_variables)]
+                fn schema(doc: &mut ::deer::Document) -> ::deer::Schema {
+                    #schema
+                }
+            }
+        }
+    }
+
+    /// Generates the reflection of a single variant and returns the name of the type, which
+    /// implements `Reflection`.
+    fn variant(&self, index: usize, variant: &Variant, items: &mut Vec<TokenStream>) -> Ident {
+        let (_, ty_generics, _) = self.input.generics.split_for_impl();
+        let turbofish = ty_generics.as_turbofish();
+
+        let name = &variant.name;
+        let deny_unknown_fields = self.input.deny_unknown_fields;
+
+        let ident = format_ident!("__Variant{index}");
+        let name_ident = format_ident!("__VariantName{index}");
+        let content_ident = format_ident!("__VariantContent{index}");
+
+        if !matches!(self.input.tagging, Tagging::External) {
+            items.push(self.reflection_type(
+                &name_ident,
+                &quote!(::deer::Schema::new("string").with("const", #name)),
+            ));
+        }
+
+        if !matches!(variant.fields, Fields::Unit) {
+            items.push(self.reflection_type(
+                &content_ident,
+                &fields_schema(&variant.fields, deny_unknown_fields),
+            ));
+        }
+
+        let name_reference = quote!(doc.add::<#name_ident #turbofish>());
+        let content_reference = quote!(doc.add::<#content_ident #turbofish>());
+
+        let schema = match (&self.input.tagging, &variant.fields) {
+            (Tagging::External, Fields::Unit) => {
+                quote!(::deer::Schema::new("string").with("const", #name))
+            }
+            (Tagging::External, _) => quote! {
+                ::deer::Schema::new("object")
+                    .with("properties", ::deer::helpers::Properties([(#name, #content_reference)]))
+                    .with("additionalProperties", false)
+            },
+            (Tagging::Internal { tag }, Fields::Named(fields)) => {
+                let properties = properties(fields, &[quote!((#tag, #name_reference))]);
+                let additional_properties = additional_properties(deny_unknown_fields);
+
+                quote! {
+                    ::deer::Schema::new("object")
+                        .with("properties", #properties)
+                        #additional_properties
+                }
+            }
+            (Tagging::Internal { tag }, Fields::Unnamed(_)) => quote! {
+                ::deer::Schema::new("object")
+                    .with("properties", ::deer::helpers::Properties([(#tag, #name_reference)]))
+                    .with("allOf", [#content_reference])
+            },
+            (Tagging::Internal { tag }, Fields::Unit) => quote! {
+                ::deer::Schema::new("object")
+                    .with("properties", ::deer::helpers::Properties([(#tag, #name_reference)]))
+                    .with("additionalProperties", false)
+            },
+            (Tagging::Adjacent { tag, .. }, Fields::Unit) => quote! {
+                ::deer::Schema::new("object")
+                    .with("properties", ::deer::helpers::Properties([(#tag, #name_reference)]))
+                    .with("additionalProperties", false)
+            },
+            (Tagging::Adjacent { tag, content }, _) => quote! {
+                ::deer::Schema::new("object")
+                    .with(
+                        "properties",
+                        ::deer::helpers::Properties([
+                            (#tag, #name_reference),
+                            (#content, #content_reference),
+                        ]),
+                    )
+                    .with("additionalProperties", false)
+            },
+        };
+
+        items.push(self.reflection_type(&ident, &schema));
+
+        ident
+    }
+
+    fn enum_schema(&self, variants: &[Variant]) -> (TokenStream, TokenStream) {
+        if matches!(self.input.tagging, Tagging::External)
+            && variants
+                .iter()
+                .all(|variant| matches!(variant.fields, Fields::Unit))
+        {
+            let names = variants.iter().map(|variant| &variant.name);
+            let length = variants.len();
+
+            return (TokenStream::new(), quote! {
+                let names: [&'static str; #length] = [#(#names),*];
+
+                ::deer::Schema::new("string").with("enum", names)
+            });
+        }
+
+        let (_, ty_generics, _) = self.input.generics.split_for_impl();
+        let turbofish = ty_generics.as_turbofish();
+
+        let mut items = Vec::new();
+        let idents: Vec<_> = variants
+            .iter()
+            .enumerate()
+            .map(|(index, variant)| self.variant(index, variant, &mut items))
+            .collect();
+
+        (
+            quote!(#(#items)*),
+            // TODO: externally tagged unit variants are represented as a string, which cannot be
+            //  expressed with a single type, as `Schema` requires a type for every schema
+            quote! {
+                ::deer::Schema::new("object")
+                    .with("oneOf", [#(doc.add::<#idents #turbofish>()),*])
+            },
+        )
+    }
+}
+
+pub(crate) fn expand(input: &Input) -> TokenStream {
+    let ident = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let this = quote!(#ident #ty_generics);
+
+    let generics = with_bound(
+        input.generics.clone(),
+        &quote!(::deer::DeserializeOwned + 'static),
+    );
+
+    let context = Context {
+        input,
+        this,
+        generics,
+    };
+
+    let (items, schema) = match &input.body {
+        Body::Struct(fields) => (
+            TokenStream::new(),
+            fields_schema(fields, input.deny_unknown_fields),
+        ),
+        Body::Enum(variants) => context.enum_schema(variants),
+    };
+
+    let (impl_generics, _, where_clause) = context.generics.split_for_impl();
+
+    quote! {
+        const _: () = {
+            #items
+
+            #[automatically_derived]
+            impl #impl_generics ::deer::Reflection for #ident #ty_generics #where_clause {
+                #[allow(unused_variables)]
+                fn schema(doc: &mut ::deer::Document) -> ::deer::Schema {
+                    #schema
+                }
+            }
+        };
+    }
+}
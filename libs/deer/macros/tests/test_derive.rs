@@ -0,0 +1,309 @@
+use deer::{Deserialize, Reflection, error::MissingError};
+use deer_desert::{Token, assert_tokens, assert_tokens_any_error, assert_tokens_error, error};
+use serde_json::{Value, json};
+
+#[derive(Debug, PartialEq, Eq, Deserialize, Reflection)]
+struct Point {
+    x: u8,
+    #[deer(rename = "ordinate")]
+    y: u8,
+    #[deer(default)]
+    z: u8,
+}
+
+#[test]
+fn struct_object() {
+    assert_tokens(&Point { x: 1, y: 2, z: 3 }, &[
+        Token::Object { length: Some(3) },
+        Token::Str("x"),
+        Token::Number(1.into()),
+        Token::Str("ordinate"),
+        Token::Number(2.into()),
+        Token::Str("z"),
+        Token::Number(3.into()),
+        Token::ObjectEnd,
+    ]);
+}
+
+#[test]
+fn struct_object_default() {
+    assert_tokens(&Point { x: 1, y: 2, z: 0 }, &[
+        Token::Object { length: Some(2) },
+        Token::Str("ordinate"),
+        Token::Number(2.into()),
+        Token::Str("x"),
+        Token::Number(1.into()),
+        Token::ObjectEnd,
+    ]);
+}
+
+#[test]
+fn struct_object_ignores_unknown_fields() {
+    assert_tokens(&Point { x: 1, y: 2, z: 0 }, &[
+        Token::Object { length: Some(3) },
+        Token::Str("x"),
+        Token::Number(1.into()),
+        Token::Str("w"),
+        Token::Array { length: Some(1) },
+        Token::Bool(true),
+        Token::ArrayEnd,
+        Token::Str("ordinate"),
+        Token::Number(2.into()),
+        Token::ObjectEnd,
+    ]);
+}
+
+#[test]
+fn struct_object_missing_field() {
+    assert_tokens_error::<Point>(
+        &error!([{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u8::reflection(),
+                "location": [{"type": "field", "value": "ordinate"}]
+            }
+        }]),
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("x"),
+            Token::Number(1.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn struct_array() {
+    assert_tokens(&Point { x: 1, y: 2, z: 0 }, &[
+        Token::Array { length: Some(2) },
+        Token::Number(1.into()),
+        Token::Number(2.into()),
+        Token::ArrayEnd,
+    ]);
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize, Reflection)]
+#[deer(deny_unknown_fields)]
+struct Strict {
+    a: u8,
+}
+
+#[test]
+fn struct_deny_unknown_fields() {
+    assert_tokens_error::<Strict>(
+        &error!([{
+            ns: "deer",
+            id: ["unknown", "field"],
+            properties: {
+                "expected": ["a"],
+                "received": ["b"],
+                "location": []
+            }
+        }]),
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("a"),
+            Token::Number(1.into()),
+            Token::Str("b"),
+            Token::Number(2.into()),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize, Reflection)]
+struct Pair(u8, bool);
+
+#[derive(Debug, PartialEq, Eq, Deserialize, Reflection)]
+struct Meters(u16);
+
+#[derive(Debug, PartialEq, Eq, Deserialize, Reflection)]
+struct Wrapper<T> {
+    inner: T,
+}
+
+#[test]
+fn tuple_struct() {
+    assert_tokens(&Pair(1, true), &[
+        Token::Array { length: Some(2) },
+        Token::Number(1.into()),
+        Token::Bool(true),
+        Token::ArrayEnd,
+    ]);
+}
+
+#[test]
+fn newtype_struct() {
+    assert_tokens(&Meters(12), &[Token::Number(12.into())]);
+}
+
+#[test]
+fn generic_struct() {
+    assert_tokens(&Wrapper { inner: Meters(12) }, &[
+        Token::Object { length: Some(1) },
+        Token::Str("inner"),
+        Token::Number(12.into()),
+        Token::ObjectEnd,
+    ]);
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize, Reflection)]
+enum External {
+    Unit,
+    Newtype(u8),
+    Tuple(u8, bool),
+    #[deer(rename = "struct")]
+    Struct {
+        value: u8,
+    },
+}
+
+#[test]
+fn enum_external() {
+    assert_tokens(&External::Unit, &[Token::String("Unit")]);
+
+    assert_tokens(&External::Newtype(1), &[
+        Token::Object { length: Some(1) },
+        Token::String("Newtype"),
+        Token::Number(1.into()),
+        Token::ObjectEnd,
+    ]);
+
+    assert_tokens(&External::Tuple(1, false), &[
+        Token::Object { length: Some(1) },
+        Token::String("Tuple"),
+        Token::Array { length: Some(2) },
+        Token::Number(1.into()),
+        Token::Bool(false),
+        Token::ArrayEnd,
+        Token::ObjectEnd,
+    ]);
+
+    assert_tokens(&External::Struct { value: 2 }, &[
+        Token::Object { length: Some(1) },
+        Token::String("struct"),
+        Token::Object { length: Some(1) },
+        Token::String("value"),
+        Token::Number(2.into()),
+        Token::ObjectEnd,
+        Token::ObjectEnd,
+    ]);
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize, Reflection)]
+#[deer(tag = "type")]
+enum Internal {
+    Unit,
+    Newtype(Strict),
+    Struct { value: u8 },
+}
+
+#[test]
+fn enum_internal() {
+    assert_tokens(&Internal::Unit, &[
+        Token::Object { length: Some(1) },
+        Token::Str("type"),
+        Token::Str("Unit"),
+        Token::ObjectEnd,
+    ]);
+
+    assert_tokens(&Internal::Newtype(Strict { a: 1 }), &[
+        Token::Object { length: Some(2) },
+        Token::Str("type"),
+        Token::Str("Newtype"),
+        Token::Str("a"),
+        Token::Number(1.into()),
+        Token::ObjectEnd,
+    ]);
+
+    assert_tokens(&Internal::Struct { value: 2 }, &[
+        Token::Object { length: Some(2) },
+        Token::Str("type"),
+        Token::Str("Struct"),
+        Token::Str("value"),
+        Token::Number(2.into()),
+        Token::ObjectEnd,
+    ]);
+}
+
+#[test]
+fn enum_internal_missing_tag() {
+    let report =
+        assert_tokens_any_error::<Internal>(&[Token::Object { length: Some(0) }, Token::ObjectEnd]);
+
+    assert!(report.contains::<MissingError>());
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize, Reflection)]
+#[deer(tag = "t", content = "c")]
+enum Adjacent {
+    Unit,
+    Tuple(u8, u8),
+}
+
+#[test]
+fn enum_adjacent() {
+    assert_tokens(&Adjacent::Unit, &[
+        Token::Object { length: Some(1) },
+        Token::Str("t"),
+        Token::Str("Unit"),
+        Token::ObjectEnd,
+    ]);
+
+    assert_tokens(&Adjacent::Tuple(1, 2), &[
+        Token::Object { length: Some(2) },
+        Token::Str("t"),
+        Token::Str("Tuple"),
+        Token::Str("c"),
+        Token::Array { length: Some(2) },
+        Token::Number(1.into()),
+        Token::Number(2.into()),
+        Token::ArrayEnd,
+        Token::ObjectEnd,
+    ]);
+}
+
+/// Returns the schema of `T`, with all references left unresolved.
+fn schema<T: Reflection>() -> Value {
+    let document = serde_json::to_value(T::document()).expect("should serialize");
+
+    let reference = document["$ref"]
+        .as_str()
+        .and_then(|reference| reference.strip_prefix("#/$defs/"))
+        .expect("should reference a definition");
+
+    document["$defs"][reference].clone()
+}
+
+#[test]
+fn reflection_struct() {
+    let schema = schema::<Strict>();
+
+    assert_eq!(schema["type"], json!("object"));
+    assert_eq!(schema["additionalProperties"], json!(false));
+    assert!(schema["properties"]["a"].is_object());
+}
+
+#[test]
+fn reflection_unit_enum() {
+    #[derive(Deserialize, Reflection)]
+    enum Color {
+        Red,
+        #[deer(rename = "green")]
+        Green,
+    }
+
+    let schema = schema::<Color>();
+
+    assert_eq!(schema["type"], json!("string"));
+    assert_eq!(schema["enum"], json!(["Red", "green"]));
+}
+
+#[test]
+fn reflection_enum() {
+    let schema = schema::<Internal>();
+
+    assert_eq!(schema["type"], json!("object"));
+    assert_eq!(schema["oneOf"].as_array().map(Vec::len), Some(3));
+}
@@ -1,11 +1,18 @@
+#[cfg_attr(feature = "std", allow(unused_imports))]
+use alloc::{string::String, vec::Vec};
+
 use error_stack::{Report, ResultExt as _, TryReportTupleExt as _};
 use serde::{Serialize, Serializer, ser::SerializeMap as _};
 
 use crate::{
-    Deserialize, Deserializer, Document, EnumVisitor, FieldVisitor, ObjectAccess, Reflection,
-    Schema, Visitor,
-    error::{DeserializeError, VisitorError},
-    schema::Reference,
+    ArrayAccess, Context, Deserialize, Deserializer, Document, EnumVisitor, FieldVisitor,
+    IdentifierVisitor, Number, ObjectAccess, OptionalVisitor, Reflection, Schema, StructVisitor,
+    Visitor,
+    error::{
+        DeserializeError, DeserializerError, ExpectedLength, ExpectedType, ObjectLengthError,
+        ReceivedLength, ReceivedType, TypeError, Variant as _, VisitorError,
+    },
+    schema::{Reference, visitor::ObjectSchema},
 };
 
 struct EnumObjectFieldVisitor<T> {
@@ -126,6 +133,206 @@ impl<'de> Deserialize<'de> for ExpectNone {
     }
 }
 
+struct IgnoredAnyVisitor;
+
+impl<'de> Visitor<'de> for IgnoredAnyVisitor {
+    type Value = IgnoredAny;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    fn visit_none(self) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(IgnoredAny)
+    }
+
+    fn visit_null(self) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(IgnoredAny)
+    }
+
+    fn visit_bool(self, _: bool) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(IgnoredAny)
+    }
+
+    fn visit_number(self, _: Number) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(IgnoredAny)
+    }
+
+    fn visit_char(self, _: char) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(IgnoredAny)
+    }
+
+    fn visit_str(self, _: &str) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(IgnoredAny)
+    }
+
+    fn visit_string(self, _: String) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(IgnoredAny)
+    }
+
+    fn visit_bytes(self, _: &[u8]) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(IgnoredAny)
+    }
+
+    fn visit_bytes_buffer(self, _: Vec<u8>) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(IgnoredAny)
+    }
+
+    fn visit_array<A>(self, mut array: A) -> Result<Self::Value, Report<VisitorError>>
+    where
+        A: ArrayAccess<'de>,
+    {
+        while let Some(item) = array.next::<IgnoredAny>() {
+            item.change_context(VisitorError)?;
+        }
+
+        array.end().change_context(VisitorError)?;
+
+        Ok(IgnoredAny)
+    }
+
+    fn visit_object<A>(self, mut object: A) -> Result<Self::Value, Report<VisitorError>>
+    where
+        A: ObjectAccess<'de>,
+    {
+        while let Some(entry) = object.next::<IgnoredAny, IgnoredAny>() {
+            entry.change_context(VisitorError)?;
+        }
+
+        object.end().change_context(VisitorError)?;
+
+        Ok(IgnoredAny)
+    }
+
+    fn visit_i128(self, _: i128) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(IgnoredAny)
+    }
+
+    fn visit_u128(self, _: u128) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(IgnoredAny)
+    }
+}
+
+/// Type which accepts, and discards, any value.
+///
+/// This is used to skip over the values of unknown fields, and only works with self-describing
+/// formats, as it relies on [`Deserializer::deserialize_any`].
+pub struct IgnoredAny;
+
+impl Reflection for IgnoredAny {
+    fn schema(_: &mut Document) -> Schema {
+        // TODO: we are unable to express "any value" with the current schema
+        Schema::new("any")
+    }
+}
+
+impl<'de> Deserialize<'de> for IgnoredAny {
+    type Reflection = Self;
+
+    fn deserialize<D>(deserializer: D) -> Result<Self, Report<DeserializeError>>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_any(IgnoredAnyVisitor)
+            .change_context(DeserializeError)
+    }
+}
+
+/// [`Deserializer`] over the remaining entries of an object, which has already been partially
+/// consumed.
+///
+/// In contrast to [`ObjectAccessDeserializer`] the [`Context`] is taken from the access itself,
+/// this is used by internally tagged enums, where the tag is consumed before the content of the
+/// variant is deserialized from the same object.
+///
+/// [`ObjectAccessDeserializer`]: crate::value::ObjectAccessDeserializer
+#[derive(Debug)]
+pub struct RemainingObjectDeserializer<A> {
+    access: A,
+}
+
+impl<A> RemainingObjectDeserializer<A> {
+    #[must_use]
+    pub const fn new(access: A) -> Self {
+        Self { access }
+    }
+}
+
+impl<'de, A> Deserializer<'de> for RemainingObjectDeserializer<A>
+where
+    A: ObjectAccess<'de>,
+{
+    forward_to_deserialize_any!(
+        null
+        bool
+        number
+        i8 i16 i32 i64 i128
+        u8 u16 u32 u64 u128
+        f32 f64
+        char str string
+        bytes bytes_buffer
+        array object
+    );
+
+    fn context(&self) -> &Context {
+        self.access.context()
+    }
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: Visitor<'de>,
+    {
+        visitor
+            .visit_object(self.access)
+            .change_context(DeserializerError)
+    }
+
+    fn deserialize_optional<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: OptionalVisitor<'de>,
+    {
+        visitor.visit_some(self).change_context(DeserializerError)
+    }
+
+    fn deserialize_enum<V>(mut self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: EnumVisitor<'de>,
+    {
+        // the access has already been used, so we cannot use `into_bound`
+        let Some(value) = self.access.field(EnumObjectFieldVisitor { visitor }) else {
+            return Err(Report::new(ObjectLengthError.into_error())
+                .attach(ExpectedLength::new(1))
+                .attach(ReceivedLength::new(0))
+                .change_context(DeserializerError));
+        };
+
+        (value, self.access.end())
+            .try_collect()
+            .map(|(value, ())| value)
+            .change_context(DeserializerError)
+    }
+
+    fn deserialize_struct<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: StructVisitor<'de>,
+    {
+        visitor
+            .visit_object(self.access)
+            .change_context(DeserializerError)
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: IdentifierVisitor<'de>,
+    {
+        Err(Report::new(TypeError.into_error())
+            .attach(ExpectedType::new(visitor.expecting()))
+            .attach(ReceivedType::new(ObjectSchema::document()))
+            .change_context(DeserializerError))
+    }
+}
+
 // TODO: consider adding an error attachment marker type for "short-circuit"
 
 pub struct Properties<const N: usize>(pub [(&'static str, Reference); N]);
@@ -0,0 +1,419 @@
+#[cfg_attr(feature = "std", allow(unused_imports))]
+use alloc::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    string::{String, ToString as _},
+    vec::Vec,
+};
+use core::marker::PhantomData;
+
+use error_stack::{Report, ReportSink, ResultExt as _};
+
+use crate::{
+    ArrayAccess, Context, Deserialize, Deserializer, Document, EnumVisitor, FieldVisitor,
+    IdentifierVisitor, Number, ObjectAccess, OptionalVisitor, Reflection, Schema, StructVisitor,
+    Visitor,
+    error::{DeserializeError, DeserializerError, Location, VisitorError},
+};
+
+/// Visitor for any collection, which is deserialized from an array of items.
+///
+/// Every item is deserialized, even if a previous item failed, so that all errors are reported.
+pub(crate) struct SequenceVisitor<C, T>(PhantomData<fn() -> *const (C, T)>);
+
+impl<C, T> SequenceVisitor<C, T> {
+    pub(crate) const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<'de, C, T> Visitor<'de> for SequenceVisitor<C, T>
+where
+    C: Deserialize<'de> + Default + Extend<T>,
+    T: Deserialize<'de>,
+{
+    type Value = C;
+
+    fn expecting(&self) -> Document {
+        C::reflection()
+    }
+
+    fn visit_array<A>(self, mut array: A) -> Result<Self::Value, Report<VisitorError>>
+    where
+        A: ArrayAccess<'de>,
+    {
+        let mut collection = C::default();
+        let mut result = ReportSink::new();
+
+        let mut index = 0;
+        while let Some(value) = array.next::<T>() {
+            match value {
+                Ok(value) => collection.extend(core::iter::once(value)),
+                Err(error) => result.append(error.attach(Location::Array(index))),
+            }
+
+            index += 1;
+        }
+
+        if let Err(error) = array.end() {
+            result.append(error);
+        }
+
+        result
+            .finish()
+            .map(|()| collection)
+            .change_context(VisitorError)
+    }
+}
+
+/// Visitor which forwards to the wrapped visitor, while recording the textual representation of
+/// every scalar it receives.
+struct RecordingVisitor<'a, V> {
+    visitor: V,
+    record: &'a mut Option<String>,
+}
+
+macro_rules! record {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, value: $ty) -> Result<Self::Value, Report<VisitorError>> {
+                *self.record = Some(value.to_string());
+                self.visitor.$method(value)
+            }
+        )*
+    };
+}
+
+impl<'de, V> Visitor<'de> for RecordingVisitor<'_, V>
+where
+    V: Visitor<'de>,
+{
+    type Value = V::Value;
+
+    record![
+        visit_bool(bool),
+        visit_number(Number),
+        visit_char(char),
+        visit_str(&str),
+        visit_borrowed_str(&'de str),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f32(f32),
+        visit_f64(f64),
+    ];
+
+    fn expecting(&self) -> Document {
+        self.visitor.expecting()
+    }
+
+    fn visit_string(self, value: String) -> Result<Self::Value, Report<VisitorError>> {
+        *self.record = Some(value.clone());
+        self.visitor.visit_string(value)
+    }
+
+    fn visit_none(self) -> Result<Self::Value, Report<VisitorError>> {
+        self.visitor.visit_none()
+    }
+
+    fn visit_null(self) -> Result<Self::Value, Report<VisitorError>> {
+        self.visitor.visit_null()
+    }
+
+    fn visit_bytes(self, value: &[u8]) -> Result<Self::Value, Report<VisitorError>> {
+        self.visitor.visit_bytes(value)
+    }
+
+    fn visit_borrowed_bytes(self, value: &'de [u8]) -> Result<Self::Value, Report<VisitorError>> {
+        self.visitor.visit_borrowed_bytes(value)
+    }
+
+    fn visit_bytes_buffer(self, value: Vec<u8>) -> Result<Self::Value, Report<VisitorError>> {
+        self.visitor.visit_bytes_buffer(value)
+    }
+
+    fn visit_array<A>(self, array: A) -> Result<Self::Value, Report<VisitorError>>
+    where
+        A: ArrayAccess<'de>,
+    {
+        self.visitor.visit_array(array)
+    }
+
+    fn visit_object<A>(self, object: A) -> Result<Self::Value, Report<VisitorError>>
+    where
+        A: ObjectAccess<'de>,
+    {
+        self.visitor.visit_object(object)
+    }
+}
+
+/// Deserializer used for the keys of a map, records the key to be able to report the entry in
+/// case it fails to deserialize.
+///
+/// Only scalar keys are recorded, the specialized visitors used for options, enums, structs and
+/// identifiers are forwarded as-is.
+struct KeyDeserializer<'a, D> {
+    deserializer: D,
+    record: &'a mut Option<String>,
+}
+
+macro_rules! forward_recorded {
+    ($($method:ident),* $(,)?) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+            where
+                V: Visitor<'de>,
+            {
+                self.deserializer.$method(RecordingVisitor {
+                    visitor,
+                    record: self.record,
+                })
+            }
+        )*
+    };
+}
+
+impl<'de, D> Deserializer<'de> for KeyDeserializer<'_, D>
+where
+    D: Deserializer<'de>,
+{
+    forward_recorded![
+        deserialize_any,
+        deserialize_null,
+        deserialize_bool,
+        deserialize_number,
+        deserialize_char,
+        deserialize_string,
+        deserialize_str,
+        deserialize_bytes,
+        deserialize_bytes_buffer,
+        deserialize_array,
+        deserialize_object,
+    ];
+
+    fn context(&self) -> &Context {
+        self.deserializer.context()
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.deserializer.is_human_readable()
+    }
+
+    fn deserialize_optional<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: OptionalVisitor<'de>,
+    {
+        self.deserializer.deserialize_optional(visitor)
+    }
+
+    fn deserialize_enum<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: EnumVisitor<'de>,
+    {
+        self.deserializer.deserialize_enum(visitor)
+    }
+
+    fn deserialize_struct<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: StructVisitor<'de>,
+    {
+        self.deserializer.deserialize_struct(visitor)
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Report<DeserializerError>>
+    where
+        V: IdentifierVisitor<'de>,
+    {
+        self.deserializer.deserialize_identifier(visitor)
+    }
+}
+
+fn attach_entry<C>(mut error: Report<C>, record: Option<String>) -> Report<C> {
+    if let Some(record) = record {
+        error = error.attach(Location::Entry(record));
+    }
+
+    error
+}
+
+/// Key of a map entry, together with its textual representation (if available).
+struct RecordedKey<K> {
+    key: K,
+    record: Option<String>,
+}
+
+impl<'de, K> Deserialize<'de> for RecordedKey<K>
+where
+    K: Deserialize<'de>,
+{
+    type Reflection = K::Reflection;
+
+    fn deserialize<D>(deserializer: D) -> Result<Self, Report<DeserializeError>>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut record = None;
+
+        match K::deserialize(KeyDeserializer {
+            deserializer,
+            record: &mut record,
+        }) {
+            Ok(key) => Ok(Self { key, record }),
+            Err(error) => Err(attach_entry(error, record)),
+        }
+    }
+}
+
+struct EntryFieldVisitor<K, V>(PhantomData<fn() -> *const (K, V)>);
+
+impl<'de, K, V> FieldVisitor<'de> for EntryFieldVisitor<K, V>
+where
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    type Key = RecordedKey<K>;
+    type Value = (K, V);
+
+    fn visit_value<D>(
+        self,
+        key: Self::Key,
+        deserializer: D,
+    ) -> Result<Self::Value, Report<VisitorError>>
+    where
+        D: Deserializer<'de>,
+    {
+        match V::deserialize(deserializer) {
+            Ok(value) => Ok((key.key, value)),
+            Err(error) => Err(attach_entry(error, key.record).change_context(VisitorError)),
+        }
+    }
+}
+
+/// Visitor for any map, which is deserialized from an object.
+///
+/// Entries that failed to deserialize are reported using their key. Keys are generic, therefore
+/// only keys which are received as a scalar (string, number, boolean or character) can be
+/// recorded.
+pub(crate) struct MapVisitor<C, K, V>(PhantomData<fn() -> *const (C, K, V)>);
+
+impl<C, K, V> MapVisitor<C, K, V> {
+    pub(crate) const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<'de, C, K, V> Visitor<'de> for MapVisitor<C, K, V>
+where
+    C: Deserialize<'de> + Default + Extend<(K, V)>,
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    type Value = C;
+
+    fn expecting(&self) -> Document {
+        C::reflection()
+    }
+
+    fn visit_object<A>(self, mut object: A) -> Result<Self::Value, Report<VisitorError>>
+    where
+        A: ObjectAccess<'de>,
+    {
+        let mut collection = C::default();
+        let mut result = ReportSink::new();
+
+        while let Some(entry) = object.field(EntryFieldVisitor::<K, V>(PhantomData)) {
+            match entry {
+                Ok(entry) => collection.extend(core::iter::once(entry)),
+                Err(error) => result.append(error),
+            }
+        }
+
+        if let Err(error) = object.end() {
+            result.append(error);
+        }
+
+        result
+            .finish()
+            .map(|()| collection)
+            .change_context(VisitorError)
+    }
+}
+
+pub struct SequenceReflection<T: ?Sized>(PhantomData<fn() -> *const T>);
+
+impl<T: Reflection + ?Sized> Reflection for SequenceReflection<T> {
+    fn schema(doc: &mut Document) -> Schema {
+        Schema::new("array").with("items", doc.add::<T>())
+    }
+}
+
+pub struct SetReflection<T: ?Sized>(PhantomData<fn() -> *const T>);
+
+impl<T: Reflection + ?Sized> Reflection for SetReflection<T> {
+    fn schema(doc: &mut Document) -> Schema {
+        Schema::new("array")
+            .with("items", doc.add::<T>())
+            .with("uniqueItems", true)
+    }
+}
+
+pub struct ObjectReflection<K: ?Sized, V: ?Sized>(PhantomData<fn() -> *const (*const K, *const V)>);
+
+impl<K: Reflection + ?Sized, V: Reflection + ?Sized> Reflection for ObjectReflection<K, V> {
+    fn schema(doc: &mut Document) -> Schema {
+        // TODO: non-string keys cannot be expressed in json-schema, we still record them as
+        //  `propertyNames`, which is only valid for string keys
+        Schema::new("object")
+            .with("propertyNames", doc.add::<K>())
+            .with("additionalProperties", doc.add::<V>())
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for VecDeque<T> {
+    type Reflection = SequenceReflection<T::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        deserializer
+            .deserialize_array(SequenceVisitor::<Self, T>::new())
+            .change_context(DeserializeError)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for BTreeSet<T>
+where
+    T: Deserialize<'de> + Ord,
+{
+    type Reflection = SetReflection<T::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        deserializer
+            .deserialize_array(SequenceVisitor::<Self, T>::new())
+            .change_context(DeserializeError)
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for BTreeMap<K, V>
+where
+    K: Deserialize<'de> + Ord,
+    V: Deserialize<'de>,
+{
+    type Reflection = ObjectReflection<K::Reflection, V::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        deserializer
+            .deserialize_object(MapVisitor::<Self, K, V>::new())
+            .change_context(DeserializeError)
+    }
+}
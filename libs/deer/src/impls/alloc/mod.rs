@@ -0,0 +1,12 @@
+mod borrow;
+mod boxed;
+mod collections;
+mod rc;
+mod string;
+#[cfg(target_has_atomic = "ptr")]
+mod sync;
+mod vec;
+
+pub(crate) use self::collections::{
+    MapVisitor, ObjectReflection, SequenceReflection, SequenceVisitor, SetReflection,
+};
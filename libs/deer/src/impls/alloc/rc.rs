@@ -0,0 +1,35 @@
+use alloc::{rc::Rc, string::String, vec::Vec};
+
+use error_stack::Report;
+
+use crate::{Deserialize, Deserializer, error::DeserializeError, impls::alloc::SequenceReflection};
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Rc<T> {
+    type Reflection = T::Reflection;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        T::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl<'de> Deserialize<'de> for Rc<str> {
+    type Reflection = str;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        String::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Rc<[T]> {
+    type Reflection = SequenceReflection<T::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        Vec::deserialize(deserializer).map(Self::from)
+    }
+}
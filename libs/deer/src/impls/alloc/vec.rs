@@ -0,0 +1,21 @@
+use alloc::vec::Vec;
+
+use error_stack::{Report, ResultExt as _};
+
+use crate::{
+    Deserialize, Deserializer,
+    error::DeserializeError,
+    impls::alloc::{SequenceReflection, SequenceVisitor},
+};
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vec<T> {
+    type Reflection = SequenceReflection<T::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        deserializer
+            .deserialize_array(SequenceVisitor::<Self, T>::new())
+            .change_context(DeserializeError)
+    }
+}
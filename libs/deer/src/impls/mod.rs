@@ -2,7 +2,11 @@ use error_stack::Report;
 
 use crate::{Deserialize, Document, OptionalVisitor, error::VisitorError};
 
+#[cfg(feature = "alloc")]
+mod alloc;
 mod core;
+#[cfg(feature = "std")]
+mod std;
 
 pub(crate) struct UnitVariantVisitor;
 
@@ -0,0 +1,43 @@
+use core::hash::{BuildHasher, Hash};
+use std::collections::{HashMap, HashSet};
+
+use error_stack::{Report, ResultExt as _};
+
+use crate::{
+    Deserialize, Deserializer,
+    error::DeserializeError,
+    impls::alloc::{MapVisitor, ObjectReflection, SequenceVisitor, SetReflection},
+};
+
+impl<'de, T, S> Deserialize<'de> for HashSet<T, S>
+where
+    T: Deserialize<'de> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type Reflection = SetReflection<T::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        deserializer
+            .deserialize_array(SequenceVisitor::<Self, T>::new())
+            .change_context(DeserializeError)
+    }
+}
+
+impl<'de, K, V, S> Deserialize<'de> for HashMap<K, V, S>
+where
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+    S: BuildHasher + Default,
+{
+    type Reflection = ObjectReflection<K::Reflection, V::Reflection>;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        deserializer
+            .deserialize_object(MapVisitor::<Self, K, V>::new())
+            .change_context(DeserializeError)
+    }
+}
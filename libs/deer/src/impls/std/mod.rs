@@ -0,0 +1,2 @@
+mod collections;
+mod path;
@@ -0,0 +1,48 @@
+use std::path::PathBuf;
+
+use error_stack::{Report, ResultExt as _};
+
+use crate::{
+    Deserialize, Deserializer, Document, Reflection, Schema, Visitor,
+    error::{DeserializeError, VisitorError},
+};
+
+struct PathBufVisitor;
+
+impl Visitor<'_> for PathBufVisitor {
+    type Value = PathBuf;
+
+    fn expecting(&self) -> Document {
+        Self::Value::reflection()
+    }
+
+    fn visit_str(self, value: &str) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(PathBuf::from(value))
+    }
+
+    fn visit_string(self, value: String) -> Result<Self::Value, Report<VisitorError>> {
+        Ok(PathBuf::from(value))
+    }
+}
+
+pub struct PathReflection;
+
+impl Reflection for PathReflection {
+    fn schema(_: &mut Document) -> Schema {
+        // paths which are not valid UTF-8 are not supported, as they cannot be represented in a
+        // platform independent way
+        Schema::new("string")
+    }
+}
+
+impl<'de> Deserialize<'de> for PathBuf {
+    type Reflection = PathReflection;
+
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, Report<DeserializeError>> {
+        deserializer
+            .deserialize_string(PathBufVisitor)
+            .change_context(DeserializeError)
+    }
+}
@@ -19,6 +19,8 @@
 use alloc::{string::String, vec::Vec};
 use core::marker::PhantomData;
 
+#[cfg(feature = "derive")]
+pub use deer_macros::{Deserialize, Reflection};
 use error_stack::{Report, ResultExt as _};
 use num_traits::{FromPrimitive as _, ToPrimitive as _};
 pub use schema::{Document, Reflection, Schema};
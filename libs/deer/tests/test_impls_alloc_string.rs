@@ -0,0 +1,41 @@
+use std::borrow::Cow;
+
+use deer::Deserialize as _;
+use deer_desert::{Token, assert_tokens};
+
+#[test]
+fn string_ok() {
+    assert_tokens(&"example".to_owned(), &[Token::String("example")]);
+}
+
+#[test]
+fn string_borrowed_ok() {
+    assert_tokens(&"example".to_owned(), &[Token::BorrowedStr("example")]);
+}
+
+#[test]
+fn string_str_ok() {
+    assert_tokens(&"example".to_owned(), &[Token::Str("example")]);
+}
+
+#[test]
+fn boxed_str_ok() {
+    let expected: Box<str> = "example".into();
+
+    assert_tokens(&expected, &[Token::Str("example")]);
+}
+
+#[test]
+fn cow_str_ok() {
+    let expected: Cow<'_, str> = Cow::Owned("example".to_owned());
+
+    assert_tokens(&expected, &[Token::Str("example")]);
+}
+
+#[test]
+fn string_reflection_is_str() {
+    assert_eq!(
+        serde_json::to_value(String::reflection()).expect("should serialize"),
+        serde_json::to_value(<&str>::reflection()).expect("should serialize")
+    );
+}
@@ -0,0 +1,171 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    rc::Rc,
+    sync::Arc,
+};
+
+use deer::{Deserialize as _, Number};
+use deer_desert::{Token, assert_tokens, assert_tokens_error, error};
+use serde_json::json;
+
+const TOKENS: &[Token] = &[
+    Token::Array { length: Some(3) },
+    Token::Number(Number::from(0)),
+    Token::Number(Number::from(1)),
+    Token::Number(Number::from(2)),
+    Token::ArrayEnd,
+];
+
+#[test]
+fn vec_ok() {
+    assert_tokens(&vec![0_u8, 1, 2], TOKENS);
+}
+
+#[test]
+fn vec_empty_ok() {
+    assert_tokens(&Vec::<u8>::new(), &[
+        Token::Array { length: Some(0) },
+        Token::ArrayEnd,
+    ]);
+}
+
+#[test]
+fn vec_deque_ok() {
+    assert_tokens(&VecDeque::from([0_u8, 1, 2]), TOKENS);
+}
+
+#[test]
+fn btree_set_ok() {
+    assert_tokens(&BTreeSet::from([0_u8, 1, 2]), TOKENS);
+}
+
+#[test]
+fn boxed_slice_ok() {
+    let expected: Box<[u8]> = vec![0, 1, 2].into_boxed_slice();
+
+    assert_tokens(&expected, TOKENS);
+}
+
+#[test]
+fn box_rc_arc_ok() {
+    assert_tokens(&Box::new(1_u8), &[Token::Number(Number::from(1))]);
+    assert_tokens(&Rc::new(1_u8), &[Token::Number(Number::from(1))]);
+    assert_tokens(&Arc::new(1_u8), &[Token::Number(Number::from(1))]);
+}
+
+#[test]
+fn vec_item_err() {
+    assert_tokens_error::<Vec<u8>>(
+        &error! {
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": [{
+                    "type": "array",
+                    "value": 1
+                }]
+            }
+        },
+        &[
+            Token::Array { length: Some(3) },
+            Token::Number(Number::from(0)),
+            Token::Number(Number::from(256)),
+            Token::Number(Number::from(2)),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn btree_map_ok() {
+    assert_tokens(
+        &BTreeMap::from([("a".to_owned(), 1_u8), ("b".to_owned(), 2)]),
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("a"),
+            Token::Number(Number::from(1)),
+            Token::Str("b"),
+            Token::Number(Number::from(2)),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn btree_map_entry_err() {
+    assert_tokens_error::<BTreeMap<String, u8>>(
+        &error! {
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": [{
+                    "type": "array",
+                    "value": 1
+                }]
+            }
+        },
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("a"),
+            Token::Number(Number::from(1)),
+            Token::Str("b"),
+            Token::Number(Number::from(256)),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn vec_deque_item_err() {
+    assert_tokens_error::<VecDeque<u8>>(
+        &error! {
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": [{
+                    "type": "array",
+                    "value": 2
+                }]
+            }
+        },
+        &[
+            Token::Array { length: Some(3) },
+            Token::Number(Number::from(0)),
+            Token::Number(Number::from(1)),
+            Token::Number(Number::from(256)),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn btree_map_value_err() {
+    assert_tokens_error::<BTreeMap<String, u8>>(
+        &error! {
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": [{
+                    "type": "entry",
+                    "value": "b"
+                }]
+            }
+        },
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("a"),
+            Token::Number(Number::from(1)),
+            Token::Str("b"),
+            Token::Number(Number::from(256)),
+            Token::ObjectEnd,
+        ],
+    );
+}
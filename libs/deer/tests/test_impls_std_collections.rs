@@ -0,0 +1,113 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
+
+use deer::{Deserialize as _, Number};
+use deer_desert::{Token, assert_tokens, assert_tokens_error, error};
+use serde_json::json;
+
+#[test]
+fn hash_set_ok() {
+    assert_tokens(&HashSet::from([0_u8, 1, 2]), &[
+        Token::Array { length: Some(3) },
+        Token::Number(Number::from(2)),
+        Token::Number(Number::from(0)),
+        Token::Number(Number::from(1)),
+        Token::ArrayEnd,
+    ]);
+}
+
+#[test]
+fn hash_map_ok() {
+    assert_tokens(
+        &HashMap::from([("a".to_owned(), 1_u8), ("b".to_owned(), 2)]),
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("b"),
+            Token::Number(Number::from(2)),
+            Token::Str("a"),
+            Token::Number(Number::from(1)),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn hash_set_item_err() {
+    assert_tokens_error::<HashSet<u8>>(
+        &error! {
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": [{
+                    "type": "array",
+                    "value": 1
+                }]
+            }
+        },
+        &[
+            Token::Array { length: Some(2) },
+            Token::Number(Number::from(0)),
+            Token::Number(Number::from(256)),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn hash_map_value_err() {
+    assert_tokens_error::<HashMap<String, u8>>(
+        &error! {
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": [{
+                    "type": "entry",
+                    "value": "b"
+                }]
+            }
+        },
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("a"),
+            Token::Number(Number::from(1)),
+            Token::Str("b"),
+            Token::Number(Number::from(256)),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn hash_map_key_err() {
+    assert_tokens_error::<HashMap<u8, u8>>(
+        &error! {
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": [{
+                    "type": "entry",
+                    "value": "256"
+                }]
+            }
+        },
+        &[
+            Token::Object { length: Some(1) },
+            Token::Number(Number::from(256)),
+            Token::Number(Number::from(1)),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn path_buf_ok() {
+    assert_tokens(&PathBuf::from("/usr/bin"), &[Token::Str("/usr/bin")]);
+}
@@ -25,5 +25,8 @@ extern crate std;
 #[warn(missing_docs)]
 pub mod schema;
 
+#[cfg(feature = "error-stack")]
+mod report;
+
 #[cfg(feature = "serde")]
 pub(crate) mod serde;
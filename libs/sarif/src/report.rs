@@ -0,0 +1,242 @@
+//! Conversion of [`error_stack::Report`]s into SARIF results.
+//!
+//! Every context of a [`Report`] is turned into a [`StackFrame`] pointing to the source location at
+//! which the context was created. Printable attachments are appended to the message of the context
+//! they were attached to, opaque attachments are ignored.
+
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString as _},
+    vec::Vec,
+};
+use core::panic::Location as CallerLocation;
+
+use error_stack::{AttachmentKind, Frame, FrameKind, Report};
+
+use crate::schema::{
+    ArtifactLocation, Location, Message, PhysicalLocation, PropertyBag, Region, Result,
+    ResultLevel, Stack, StackFrame,
+};
+
+/// The URI base id used for locations, which are relative to the root of the source tree.
+const SOURCE_ROOT: &str = "SRCROOT";
+
+fn text_message(text: String) -> Message<'static> {
+    Message {
+        text: Some(Cow::Owned(text)),
+        markdown: None,
+        id: None,
+        arguments: Vec::new(),
+        properties: PropertyBag::new(),
+    }
+}
+
+fn caller_location(location: &CallerLocation<'_>) -> Location<'static> {
+    let file = location.file();
+    let is_absolute = file.starts_with('/') || file.contains(':');
+
+    let mut artifact_location = ArtifactLocation::new(file.replace('\\', "/"));
+    if !is_absolute {
+        artifact_location = artifact_location.with_uri_base_id(SOURCE_ROOT);
+    }
+
+    let line = location.line() as usize;
+    let column = location.column() as usize;
+
+    Location::new(
+        PhysicalLocation::new(artifact_location)
+            .with_region(Region::new((line, column), (line, column))),
+    )
+}
+
+/// State which is accumulated while walking down the frames until the next context is reached.
+#[derive(Clone, Default)]
+struct Pending<'f> {
+    location: Option<&'f CallerLocation<'static>>,
+    attachments: Vec<String>,
+}
+
+fn collect_frames<'f>(
+    frame: &'f Frame,
+    mut pending: Pending<'f>,
+    frames: &mut Vec<StackFrame<'static>>,
+) {
+    match frame.kind() {
+        FrameKind::Context(context) => {
+            let mut text = context.to_string();
+            // Attachments are visited from the most recent to the oldest one
+            for attachment in pending.attachments.drain(..).rev() {
+                text.push('\n');
+                text.push_str(&attachment);
+            }
+
+            let location = pending
+                .location
+                .take()
+                .map_or_else(Location::default, caller_location)
+                .with_message(text_message(text));
+
+            frames.push(StackFrame::new(location));
+        }
+        FrameKind::Attachment(AttachmentKind::Printable(attachment)) => {
+            pending.attachments.push(attachment.to_string());
+        }
+        FrameKind::Attachment(_) => {
+            if let Some(location) = frame.downcast_ref::<CallerLocation<'static>>() {
+                pending.location = Some(location);
+            }
+        }
+    }
+
+    match frame.sources() {
+        [] => {}
+        [source] => collect_frames(source, pending, frames),
+        sources => {
+            for source in sources {
+                collect_frames(source, pending.clone(), frames);
+            }
+        }
+    }
+}
+
+impl Result<'static> {
+    /// Create a new `Result` from a single stack of [`Frame`]s.
+    ///
+    /// The message and location of the result are taken from the top-most context of the frame,
+    /// while all contexts are recorded in the [`stacks`] of the result, the most recent one
+    /// first.
+    ///
+    /// This can be used together with [`Report::current_frames`] to convert a [`Report`] holding
+    /// multiple errors into multiple results.
+    ///
+    /// [`stacks`]: Self::stacks
+    #[must_use]
+    pub fn from_frame(frame: &Frame) -> Self {
+        let mut frames = Vec::new();
+        collect_frames(frame, Pending::default(), &mut frames);
+
+        let primary = frames.first().and_then(|frame| frame.location.as_ref());
+        let message = primary
+            .and_then(|location| location.message.clone())
+            .unwrap_or_else(|| text_message(String::new()));
+
+        let mut result = Self::new(message).with_level(ResultLevel::Error);
+        if let Some(physical_location) =
+            primary.and_then(|location| location.physical_location.clone())
+        {
+            result = result.with_location(Location::new(physical_location));
+        }
+
+        result.with_stack(Stack::new(frames))
+    }
+
+    /// Create a new `Result` from a [`Report`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::{error::Error, fmt};
+    ///
+    /// use error_stack::Report;
+    /// use sarif::schema::{Result, ResultLevel};
+    ///
+    /// #[derive(Debug)]
+    /// struct ParseError;
+    ///
+    /// impl fmt::Display for ParseError {
+    ///     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         fmt.write_str("could not parse configuration")
+    ///     }
+    /// }
+    ///
+    /// impl Error for ParseError {}
+    ///
+    /// let report = Report::new(ParseError).attach_printable("line 3 is malformed");
+    /// let result = Result::from_report(&report);
+    ///
+    /// assert_eq!(result.level, Some(ResultLevel::Error));
+    /// assert_eq!(result.locations.len(), 1);
+    /// assert_eq!(result.stacks[0].frames.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn from_report<C>(report: &Report<C>) -> Self {
+        Self::from_frame(report.current_frame())
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+#[coverage(off)]
+mod tests {
+    use core::{error::Error, fmt};
+
+    use error_stack::Report;
+
+    use crate::schema::{
+        Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent, tests::validate_schema,
+    };
+
+    #[derive(Debug)]
+    struct ErrorA;
+
+    impl fmt::Display for ErrorA {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt.write_str("error A")
+        }
+    }
+
+    impl Error for ErrorA {}
+
+    #[derive(Debug)]
+    struct ErrorB;
+
+    impl fmt::Display for ErrorB {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt.write_str("error B")
+        }
+    }
+
+    impl Error for ErrorB {}
+
+    #[test]
+    fn nested_contexts() {
+        let report = Report::new(ErrorA)
+            .attach_printable("first attachment")
+            .attach_printable("second attachment")
+            .change_context(ErrorB);
+
+        let result = Result::from_report(&report);
+
+        assert_eq!(result.message.text.as_deref(), Some("error B"));
+        assert_eq!(result.locations.len(), 1);
+
+        let frames = &result.stacks[0].frames;
+        assert_eq!(frames.len(), 2);
+        assert_eq!(
+            frames[1]
+                .location
+                .as_ref()
+                .and_then(|location| location.message.as_ref())
+                .and_then(|message| message.text.as_deref()),
+            Some("error A\nfirst attachment\nsecond attachment")
+        );
+
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0).with_run(
+                Run::new(Tool::new(ToolComponent::new("error-stack"))).with_result(result),
+            ),
+        );
+    }
+
+    #[test]
+    fn multiple_errors() {
+        let mut report = Report::new(ErrorA).expand();
+        report.push(Report::new(ErrorB).change_context(ErrorA));
+
+        let run = Run::new(Tool::new(ToolComponent::new("error-stack")))
+            .with_results(report.current_frames().iter().map(Result::from_frame));
+
+        assert_eq!(run.results.len(), 2);
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
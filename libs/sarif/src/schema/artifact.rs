@@ -0,0 +1,352 @@
+use alloc::{borrow::Cow, collections::BTreeMap};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Message, MultiformatMessageString, PropertyBag};
+
+/// Specifies the location of an artifact ([§3.4]).
+///
+/// The [`uri`] is either absolute, or relative to the URI referenced by [`uri_base_id`].
+///
+/// [§3.4]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+/// [`uri`]: Self::uri
+/// [`uri_base_id`]: Self::uri_base_id
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ArtifactLocation<'s> {
+    /// A string containing a valid relative or absolute URI.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub uri: Option<Cow<'s, str>>,
+
+    /// A string which indirectly specifies the absolute URI with respect to which a relative URI
+    /// in the [`uri`](Self::uri) property is interpreted.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub uri_base_id: Option<Cow<'s, str>>,
+
+    /// The index within the run artifacts array of the artifact object associated with the
+    /// artifact location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<usize>,
+
+    /// A short description of the artifact location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<Message<'s>>,
+
+    /// Key/value pairs that provide additional information about the artifact location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ArtifactLocation<'s> {
+    /// Create a new `ArtifactLocation` with the given URI.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactLocation;
+    ///
+    /// let location = ArtifactLocation::new("src/lib.rs").with_uri_base_id("SRCROOT");
+    ///
+    /// assert_eq!(location.uri, Some("src/lib.rs".into()));
+    /// assert_eq!(location.uri_base_id, Some("SRCROOT".into()));
+    /// ```
+    #[must_use]
+    pub fn new(uri: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            uri: Some(uri.into()),
+            uri_base_id: None,
+            index: None,
+            description: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the URI base id of the artifact location.
+    #[must_use]
+    pub fn with_uri_base_id(mut self, uri_base_id: impl Into<Cow<'s, str>>) -> Self {
+        self.uri_base_id = Some(uri_base_id.into());
+        self
+    }
+
+    /// Set the index of the artifact in the [`Run::artifacts`] array.
+    ///
+    /// [`Run::artifacts`]: crate::schema::Run::artifacts
+    #[must_use]
+    pub const fn with_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+}
+
+/// Represents the contents of an artifact ([§3.3]).
+///
+/// [§3.3]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ArtifactContent<'s> {
+    /// UTF-8-encoded content from a text artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub text: Option<Cow<'s, str>>,
+
+    /// MIME Base64-encoded content from a binary artifact, or from a text artifact in its original
+    /// encoding.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub binary: Option<Cow<'s, str>>,
+
+    /// An alternate rendered representation of the artifact (e.g., a decompiled representation of
+    /// a binary region).
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rendered: Option<MultiformatMessageString<'s>>,
+
+    /// Key/value pairs that provide additional information about the artifact content.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ArtifactContent<'s> {
+    /// Create a new `ArtifactContent` from the given text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactContent;
+    ///
+    /// let content = ArtifactContent::from_text("let x = 5;");
+    ///
+    /// assert_eq!(content.text, Some("let x = 5;".into()));
+    /// ```
+    #[must_use]
+    pub fn from_text(text: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            text: Some(text.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// A single artifact, which is relevant to the run ([§3.24]).
+///
+/// In some cases, this artifact might be nested within another artifact, in which case
+/// [`parent_index`] refers to the containing artifact.
+///
+/// [§3.24]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+/// [`parent_index`]: Self::parent_index
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Artifact<'s> {
+    /// A short description of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<Message<'s>>,
+
+    /// The location of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub location: Option<ArtifactLocation<'s>>,
+
+    /// Identifies the index of the immediate parent of the artifact, if this artifact is nested.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub parent_index: Option<usize>,
+
+    /// The offset in bytes of the artifact within its containing artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub offset: Option<usize>,
+
+    /// The length of the artifact in bytes.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub length: Option<usize>,
+
+    /// The MIME type (RFC 2045) of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub mime_type: Option<Cow<'s, str>>,
+
+    /// The contents of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub contents: Option<ArtifactContent<'s>>,
+
+    /// Specifies the encoding for an artifact object that refers to a text file.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub encoding: Option<Cow<'s, str>>,
+
+    /// Specifies the source language for any artifact object that refers to a text file that
+    /// contains source code.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub source_language: Option<Cow<'s, str>>,
+
+    /// A dictionary, each of whose keys is the name of a hash function and each of whose values is
+    /// the hashed value of the artifact produced by the specified hash function.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub hashes: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// The Coordinated Universal Time (UTC) date and time at which the artifact was most recently
+    /// modified, in the format specified by ISO 8601.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub last_modified_time_utc: Option<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Artifact<'s> {
+    /// Create a new `Artifact` at the given location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new(ArtifactLocation::new("src/lib.rs")).with_source_language("rust");
+    ///
+    /// assert_eq!(artifact.source_language, Some("rust".into()));
+    /// ```
+    #[must_use]
+    pub fn new(location: ArtifactLocation<'s>) -> Self {
+        Self {
+            location: Some(location),
+            ..Self::default()
+        }
+    }
+
+    /// Set the source language of the artifact.
+    #[must_use]
+    pub fn with_source_language(mut self, source_language: impl Into<Cow<'s, str>>) -> Self {
+        self.source_language = Some(source_language.into());
+        self
+    }
+
+    /// Set the contents of the artifact.
+    #[must_use]
+    pub fn with_contents(mut self, contents: ArtifactContent<'s>) -> Self {
+        self.contents = Some(contents);
+        self
+    }
+
+    /// Add the hash of the artifact produced by the given hash function.
+    #[must_use]
+    pub fn with_hash(
+        mut self,
+        function: impl Into<Cow<'s, str>>,
+        hash: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.hashes.insert(function.into(), hash.into());
+        self
+    }
+}
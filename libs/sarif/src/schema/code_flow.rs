@@ -0,0 +1,283 @@
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Location, Message, MultiformatMessageString, PropertyBag, Stack};
+
+/// Specifies the importance of a [`ThreadFlowLocation`] within the [`CodeFlow`] in which it
+/// occurs ([§3.38.13]).
+///
+/// [§3.38.13]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum ThreadFlowLocationImportance {
+    /// The location is important for understanding the code flow.
+    #[default]
+    Important,
+    /// The location is essential for understanding the code flow.
+    Essential,
+    /// The location is not important for understanding the code flow.
+    Unimportant,
+}
+
+/// A location visited by an analysis tool while simulating or monitoring the execution of a
+/// program ([§3.38]).
+///
+/// [§3.38]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ThreadFlowLocation<'s> {
+    /// The index within the run thread flow locations array.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<usize>,
+
+    /// The code location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub location: Option<Location<'s>>,
+
+    /// The call stack leading to this location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub stack: Option<Stack<'s>>,
+
+    /// A set of distinct strings that categorize the thread flow location, e.g. `"acquire"` or
+    /// `"release"`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub kinds: Vec<Cow<'s, str>>,
+
+    /// The name of the module that contains the code that is executing.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub module: Option<Cow<'s, str>>,
+
+    /// A dictionary, each of whose keys specifies a variable or expression, the associated value
+    /// of which represents the variable or expression value.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub state: BTreeMap<Cow<'s, str>, MultiformatMessageString<'s>>,
+
+    /// An integer representing a containment hierarchy within the thread flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub nesting_level: Option<usize>,
+
+    /// An integer representing the temporal order in which execution reached this location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub execution_order: Option<i64>,
+
+    /// The Coordinated Universal Time (UTC) date and time at which this location was executed.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub execution_time_utc: Option<Cow<'s, str>>,
+
+    /// Specifies the importance of this location in understanding the code flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub importance: Option<ThreadFlowLocationImportance>,
+
+    /// Key/value pairs that provide additional information about the thread flow location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ThreadFlowLocation<'s> {
+    /// Create a new `ThreadFlowLocation` at the given location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{
+    ///     ArtifactLocation, Location, PhysicalLocation, ThreadFlowLocation,
+    ///     ThreadFlowLocationImportance,
+    /// };
+    ///
+    /// let location = ThreadFlowLocation::new(Location::new(PhysicalLocation::new(
+    ///     ArtifactLocation::new("src/lib.rs"),
+    /// )))
+    /// .with_importance(ThreadFlowLocationImportance::Essential);
+    ///
+    /// assert_eq!(
+    ///     location.importance,
+    ///     Some(ThreadFlowLocationImportance::Essential)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn new(location: Location<'s>) -> Self {
+        Self {
+            location: Some(location),
+            ..Self::default()
+        }
+    }
+
+    /// Set the importance of the location.
+    #[must_use]
+    pub const fn with_importance(mut self, importance: ThreadFlowLocationImportance) -> Self {
+        self.importance = Some(importance);
+        self
+    }
+}
+
+/// Describes a sequence of code locations that specify a path through a single thread of
+/// execution such as an operating system or fiber ([§3.37]).
+///
+/// [§3.37]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ThreadFlow<'s> {
+    /// An string that uniquely identifies the thread flow within the code flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub id: Option<Cow<'s, str>>,
+
+    /// A message relevant to the thread flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// A temporally ordered array of locations visited by an analysis tool in the course of
+    /// simulating or monitoring the execution of a program.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub locations: Vec<ThreadFlowLocation<'s>>,
+
+    /// Key/value pairs that provide additional information about the thread flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ThreadFlow<'s> {
+    /// Create a new `ThreadFlow` visiting the given locations.
+    #[must_use]
+    pub fn new(locations: impl IntoIterator<Item = ThreadFlowLocation<'s>>) -> Self {
+        Self {
+            id: None,
+            message: None,
+            locations: locations.into_iter().collect(),
+            properties: PropertyBag::new(),
+        }
+    }
+}
+
+/// A set of threads flows which together describe a pattern of code execution relevant to
+/// detecting a result ([§3.36]).
+///
+/// [§3.36]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct CodeFlow<'s> {
+    /// A message relevant to the code flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// An array of one or more unique thread flow objects, each of which describes the progress of
+    /// a program through a thread of execution.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub thread_flows: Vec<ThreadFlow<'s>>,
+
+    /// Key/value pairs that provide additional information about the code flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> CodeFlow<'s> {
+    /// Create a new `CodeFlow` from the given thread flows.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{CodeFlow, ThreadFlow};
+    ///
+    /// let code_flow = CodeFlow::new([ThreadFlow::new([])]);
+    ///
+    /// assert_eq!(code_flow.thread_flows.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn new(thread_flows: impl IntoIterator<Item = ThreadFlow<'s>>) -> Self {
+        Self {
+            message: None,
+            thread_flows: thread_flows.into_iter().collect(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the message of the code flow.
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+}
@@ -0,0 +1,174 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactContent, ArtifactLocation, Message, PropertyBag, Region};
+
+/// The replacement of a single region of an artifact ([§3.57]).
+///
+/// [§3.57]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Replacement<'s> {
+    /// The region of the artifact to delete.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub deleted_region: Region<'s>,
+
+    /// The content to insert at the location specified by the deleted region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub inserted_content: Option<ArtifactContent<'s>>,
+
+    /// Key/value pairs that provide additional information about the replacement.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Replacement<'s> {
+    /// Create a new `Replacement`, which deletes the given region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactContent, Region, Replacement};
+    ///
+    /// let replacement = Replacement::new(Region::new((1, 5), (1, 8)))
+    ///     .with_inserted_content(ArtifactContent::from_text("value"));
+    ///
+    /// assert!(replacement.inserted_content.is_some());
+    /// ```
+    #[must_use]
+    pub const fn new(deleted_region: Region<'s>) -> Self {
+        Self {
+            deleted_region,
+            inserted_content: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the content, which is inserted in place of the deleted region.
+    #[must_use]
+    pub fn with_inserted_content(mut self, content: ArtifactContent<'s>) -> Self {
+        self.inserted_content = Some(content);
+        self
+    }
+}
+
+/// A change to a single artifact ([§3.56]).
+///
+/// [§3.56]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ArtifactChange<'s> {
+    /// The location of the artifact to change.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub artifact_location: ArtifactLocation<'s>,
+
+    /// An array of replacement objects, each of which represents the replacement of a single
+    /// region in a single artifact specified by the artifact location.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub replacements: Vec<Replacement<'s>>,
+
+    /// Key/value pairs that provide additional information about the change.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ArtifactChange<'s> {
+    /// Create a new `ArtifactChange` applying the replacements to the given artifact.
+    #[must_use]
+    pub fn new(
+        artifact_location: ArtifactLocation<'s>,
+        replacements: impl IntoIterator<Item = Replacement<'s>>,
+    ) -> Self {
+        Self {
+            artifact_location,
+            replacements: replacements.into_iter().collect(),
+            properties: PropertyBag::new(),
+        }
+    }
+}
+
+/// A proposed fix for the problem represented by a result object ([§3.55]).
+///
+/// A fix specifies a set of artifacts to modify. For each artifact, it specifies a set of bytes
+/// to remove, and provides a set of new bytes to replace them.
+///
+/// [§3.55]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Fix<'s> {
+    /// A message that describes the proposed fix, enabling viewers to present the proposed change
+    /// to an end user.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<Message<'s>>,
+
+    /// One or more artifact changes that comprise a fix for a result.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub artifact_changes: Vec<ArtifactChange<'s>>,
+
+    /// Key/value pairs that provide additional information about the fix.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Fix<'s> {
+    /// Create a new `Fix` consisting of the given changes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactChange, ArtifactLocation, Fix, Region, Replacement};
+    ///
+    /// let fix = Fix::new([ArtifactChange::new(ArtifactLocation::new("src/lib.rs"), [
+    ///     Replacement::new(Region::new((1, 1), (1, 4))),
+    /// ])]);
+    ///
+    /// assert_eq!(fix.artifact_changes.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn new(artifact_changes: impl IntoIterator<Item = ArtifactChange<'s>>) -> Self {
+        Self {
+            description: None,
+            artifact_changes: artifact_changes.into_iter().collect(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the description of the fix.
+    #[must_use]
+    pub fn with_description(mut self, description: Message<'s>) -> Self {
+        self.description = Some(description);
+        self
+    }
+}
@@ -0,0 +1,364 @@
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{
+    ArtifactLocation, Location, Message, PropertyBag, ReportingDescriptorReference, ResultLevel,
+};
+
+/// A condition relevant to a tool run, which is not a result, e.g. a failure to load a rule or a
+/// progress message ([§3.58]).
+///
+/// [§3.58]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Notification<'s> {
+    /// The locations relevant to this notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub locations: Vec<Location<'s>>,
+
+    /// A message that describes the condition that was encountered.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub message: Message<'s>,
+
+    /// A value specifying the severity level of the notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub level: Option<ResultLevel>,
+
+    /// The thread identifier of the code that generated the notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub thread_id: Option<i64>,
+
+    /// The Coordinated Universal Time (UTC) date and time at which the analysis tool generated the
+    /// notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub time_utc: Option<Cow<'s, str>>,
+
+    /// A reference used to locate the descriptor relevant to this notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub descriptor: Option<ReportingDescriptorReference<'s>>,
+
+    /// A reference used to locate the rule descriptor associated with this notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub associated_rule: Option<ReportingDescriptorReference<'s>>,
+
+    /// Key/value pairs that provide additional information about the notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Notification<'s> {
+    /// Create a new `Notification` with the given message.
+    #[must_use]
+    pub const fn new(message: Message<'s>) -> Self {
+        Self {
+            locations: Vec::new(),
+            message,
+            level: None,
+            thread_id: None,
+            time_utc: None,
+            descriptor: None,
+            associated_rule: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the severity level of the notification.
+    #[must_use]
+    pub const fn with_level(mut self, level: ResultLevel) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Add a location relevant to the notification.
+    #[must_use]
+    pub fn with_location(mut self, location: Location<'s>) -> Self {
+        self.locations.push(location);
+        self
+    }
+}
+
+/// The runtime environment of the analysis tool run ([§3.20]).
+///
+/// [§3.20]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Invocation<'s> {
+    /// The command line used to invoke the tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub command_line: Option<Cow<'s, str>>,
+
+    /// An array of strings, containing in order the command line arguments passed to the tool
+    /// from the operating system.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub arguments: Vec<Cow<'s, str>>,
+
+    /// The locations of any response files specified on the tool's command line.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub response_files: Vec<ArtifactLocation<'s>>,
+
+    /// The Coordinated Universal Time (UTC) date and time at which the invocation started.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub start_time_utc: Option<Cow<'s, str>>,
+
+    /// The Coordinated Universal Time (UTC) date and time at which the invocation ended.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub end_time_utc: Option<Cow<'s, str>>,
+
+    /// The process exit code.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub exit_code: Option<i32>,
+
+    /// A list of runtime conditions detected by the tool during the analysis.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub tool_execution_notifications: Vec<Notification<'s>>,
+
+    /// A list of conditions detected by the tool that are relevant to the tool's configuration.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub tool_configuration_notifications: Vec<Notification<'s>>,
+
+    /// The reason for the process exit.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub exit_code_description: Option<Cow<'s, str>>,
+
+    /// The name of the signal that caused the process to exit.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub exit_signal_name: Option<Cow<'s, str>>,
+
+    /// The numeric value of the signal that caused the process to exit.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub exit_signal_number: Option<i32>,
+
+    /// The reason given by the operating system that the process failed to start.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub process_start_failure_message: Option<Cow<'s, str>>,
+
+    /// Specifies whether the tool's execution completed successfully.
+    pub execution_successful: bool,
+
+    /// The machine on which the invocation occurred.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub machine: Option<Cow<'s, str>>,
+
+    /// The account under which the invocation occurred.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub account: Option<Cow<'s, str>>,
+
+    /// The id of the process in which the invocation occurred.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub process_id: Option<u32>,
+
+    /// An absolute URI specifying the location of the executable that was invoked.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub executable_location: Option<ArtifactLocation<'s>>,
+
+    /// The working directory for the invocation.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub working_directory: Option<ArtifactLocation<'s>>,
+
+    /// The environment variables associated with the analysis tool process, expressed as
+    /// key/value pairs.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub environment_variables: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the invocation.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Invocation<'s> {
+    /// Create a new `Invocation`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true)
+    ///     .with_command_line("clippy --all-targets")
+    ///     .with_exit_code(0);
+    ///
+    /// assert!(invocation.execution_successful);
+    /// assert_eq!(invocation.exit_code, Some(0));
+    /// ```
+    #[must_use]
+    pub const fn new(execution_successful: bool) -> Self {
+        Self {
+            command_line: None,
+            arguments: Vec::new(),
+            response_files: Vec::new(),
+            start_time_utc: None,
+            end_time_utc: None,
+            exit_code: None,
+            tool_execution_notifications: Vec::new(),
+            tool_configuration_notifications: Vec::new(),
+            exit_code_description: None,
+            exit_signal_name: None,
+            exit_signal_number: None,
+            process_start_failure_message: None,
+            execution_successful,
+            machine: None,
+            account: None,
+            process_id: None,
+            executable_location: None,
+            working_directory: None,
+            environment_variables: BTreeMap::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the command line used to invoke the tool.
+    #[must_use]
+    pub fn with_command_line(mut self, command_line: impl Into<Cow<'s, str>>) -> Self {
+        self.command_line = Some(command_line.into());
+        self
+    }
+
+    /// Set the exit code of the process.
+    #[must_use]
+    pub const fn with_exit_code(mut self, exit_code: i32) -> Self {
+        self.exit_code = Some(exit_code);
+        self
+    }
+
+    /// Add a notification, which was emitted during the execution of the tool.
+    #[must_use]
+    pub fn with_tool_execution_notification(mut self, notification: Notification<'s>) -> Self {
+        self.tool_execution_notifications.push(notification);
+        self
+    }
+}
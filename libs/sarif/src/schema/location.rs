@@ -0,0 +1,565 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactContent, ArtifactLocation, Message, PropertyBag};
+
+/// A region within an artifact where a result was detected ([§3.30]).
+///
+/// A text region is specified by line/column properties or by the character offset properties,
+/// a binary region by the byte offset properties. Lines and columns are 1-based.
+///
+/// [§3.30]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Region<'s> {
+    /// The line number of the first character in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub start_line: Option<usize>,
+
+    /// The column number of the first character in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub start_column: Option<usize>,
+
+    /// The line number of the last character in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub end_line: Option<usize>,
+
+    /// The column number of the character following the end of the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub end_column: Option<usize>,
+
+    /// The zero-based offset from the beginning of the artifact of the first character in the
+    /// region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub char_offset: Option<usize>,
+
+    /// The length of the region in characters.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub char_length: Option<usize>,
+
+    /// The zero-based offset from the beginning of the artifact of the first byte in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub byte_offset: Option<usize>,
+
+    /// The length of the region in bytes.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub byte_length: Option<usize>,
+
+    /// The portion of the artifact contents within the specified region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub snippet: Option<ArtifactContent<'s>>,
+
+    /// A message relevant to the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// Specifies the source language, if any, of the portion of the artifact specified by the
+    /// region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub source_language: Option<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Region<'s> {
+    /// Create a new `Region` spanning from the start to the end position.
+    ///
+    /// Both positions are given as 1-based `(line, column)` pairs, the end column refers to the
+    /// column following the last character in the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new((1, 5), (1, 10));
+    ///
+    /// assert_eq!(region.start_line, Some(1));
+    /// assert_eq!(region.end_column, Some(10));
+    /// ```
+    #[must_use]
+    pub fn new(start: (usize, usize), end: (usize, usize)) -> Self {
+        Self {
+            start_line: Some(start.0),
+            start_column: Some(start.1),
+            end_line: Some(end.0),
+            end_column: Some(end.1),
+            ..Self::default()
+        }
+    }
+
+    /// Create a new `Region` from a zero-based character offset and length.
+    #[must_use]
+    pub fn from_char_range(offset: usize, length: usize) -> Self {
+        Self {
+            char_offset: Some(offset),
+            char_length: Some(length),
+            ..Self::default()
+        }
+    }
+
+    /// Set the snippet of the region.
+    #[must_use]
+    pub fn with_snippet(mut self, snippet: ArtifactContent<'s>) -> Self {
+        self.snippet = Some(snippet);
+        self
+    }
+
+    /// Set the message of the region.
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+}
+
+/// A physical or virtual address, or a range of addresses, in an "addressable region" (memory or
+/// a binary file) ([§3.32]).
+///
+/// [§3.32]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Address<'s> {
+    /// The address expressed as a byte offset from the start of the addressable region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub absolute_address: Option<u64>,
+
+    /// The address expressed as a byte offset from the absolute address of the top-most parent
+    /// object.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub relative_address: Option<i64>,
+
+    /// The number of bytes in this range of addresses.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub length: Option<i64>,
+
+    /// An open-ended string that identifies the address kind, e.g. `"module"` or `"function"`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub kind: Option<Cow<'s, str>>,
+
+    /// A name that is associated with the address, e.g., `".text"`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub name: Option<Cow<'s, str>>,
+
+    /// A human-readable fully qualified name that is associated with the address.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub fully_qualified_name: Option<Cow<'s, str>>,
+
+    /// The byte offset of this address from the absolute or relative address of the parent
+    /// object.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub offset_from_parent: Option<i64>,
+
+    /// The index within the run addresses array of the cached object for this address.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<usize>,
+
+    /// The index within the run addresses array of the parent object.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub parent_index: Option<usize>,
+
+    /// Key/value pairs that provide additional information about the address.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+/// A physical location relevant to a result ([§3.29]).
+///
+/// Specifies a reference to a programming artifact together with a range of bytes or characters
+/// within that artifact.
+///
+/// [§3.29]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct PhysicalLocation<'s> {
+    /// The address of the location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub address: Option<Address<'s>>,
+
+    /// The location of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub artifact_location: Option<ArtifactLocation<'s>>,
+
+    /// Specifies a portion of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub region: Option<Region<'s>>,
+
+    /// Specifies a portion of the artifact that encloses the region. Allows a viewer to display
+    /// additional context around the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub context_region: Option<Region<'s>>,
+
+    /// Key/value pairs that provide additional information about the physical location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> PhysicalLocation<'s> {
+    /// Create a new `PhysicalLocation` referring to the given artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, PhysicalLocation, Region};
+    ///
+    /// let location = PhysicalLocation::new(ArtifactLocation::new("src/lib.rs"))
+    ///     .with_region(Region::new((1, 1), (1, 4)));
+    ///
+    /// assert_eq!(
+    ///     location.region.and_then(|region| region.start_line),
+    ///     Some(1)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn new(artifact_location: ArtifactLocation<'s>) -> Self {
+        Self {
+            artifact_location: Some(artifact_location),
+            ..Self::default()
+        }
+    }
+
+    /// Set the region of the physical location.
+    #[must_use]
+    pub fn with_region(mut self, region: Region<'s>) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Set the context region of the physical location.
+    #[must_use]
+    pub fn with_context_region(mut self, region: Region<'s>) -> Self {
+        self.context_region = Some(region);
+        self
+    }
+}
+
+/// A logical location such as a function, a class, or a module ([§3.33]).
+///
+/// [§3.33]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct LogicalLocation<'s> {
+    /// Identifies the construct in which the result occurred, e.g. the name of a function.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub name: Option<Cow<'s, str>>,
+
+    /// The index within the logical locations array.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<usize>,
+
+    /// The human-readable fully qualified name of the logical location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub fully_qualified_name: Option<Cow<'s, str>>,
+
+    /// The machine-readable name for the logical location, such as a mangled function name.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub decorated_name: Option<Cow<'s, str>>,
+
+    /// Identifies the index of the immediate parent of the construct in which the result was
+    /// detected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub parent_index: Option<usize>,
+
+    /// The type of construct this logical location component refers to, e.g. `"function"`,
+    /// `"module"` or `"namespace"`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub kind: Option<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the logical location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> LogicalLocation<'s> {
+    /// Create a new `LogicalLocation` with the given fully qualified name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::LogicalLocation;
+    ///
+    /// let location = LogicalLocation::new("collections::list::add").with_kind("function");
+    ///
+    /// assert_eq!(
+    ///     location.fully_qualified_name,
+    ///     Some("collections::list::add".into())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn new(fully_qualified_name: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            fully_qualified_name: Some(fully_qualified_name.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the kind of the logical location.
+    #[must_use]
+    pub fn with_kind(mut self, kind: impl Into<Cow<'s, str>>) -> Self {
+        self.kind = Some(kind.into());
+        self
+    }
+}
+
+/// A location within a programming artifact ([§3.28]).
+///
+/// [§3.28]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Location<'s> {
+    /// Value that distinguishes this location from all other locations within a single result
+    /// object.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub id: Option<usize>,
+
+    /// Identifies the artifact and region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub physical_location: Option<PhysicalLocation<'s>>,
+
+    /// The logical locations associated with the result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub logical_locations: Vec<LogicalLocation<'s>>,
+
+    /// A message relevant to the location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// A set of regions relevant to the location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub annotations: Vec<Region<'s>>,
+
+    /// Key/value pairs that provide additional information about the location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Location<'s> {
+    /// Create a new `Location` from the given physical location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Location, PhysicalLocation};
+    ///
+    /// let location = Location::new(PhysicalLocation::new(ArtifactLocation::new("src/lib.rs")));
+    ///
+    /// assert!(location.physical_location.is_some());
+    /// ```
+    #[must_use]
+    pub fn new(physical_location: PhysicalLocation<'s>) -> Self {
+        Self {
+            physical_location: Some(physical_location),
+            ..Self::default()
+        }
+    }
+
+    /// Add a logical location to the location.
+    #[must_use]
+    pub fn with_logical_location(mut self, logical_location: LogicalLocation<'s>) -> Self {
+        self.logical_locations.push(logical_location);
+        self
+    }
+
+    /// Set the message of the location.
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Add an annotation to the location.
+    #[must_use]
+    pub fn with_annotation(mut self, annotation: Region<'s>) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
+}
@@ -1,23 +1,39 @@
 //! The JSON schema of the SARIF log file format as a Rust module.
 
+mod artifact;
+mod code_flow;
+mod fix;
+mod invocation;
+mod location;
 mod log;
 mod message;
 mod multiformat_message_string;
 mod properties;
 mod reporting_descriptor;
+mod reporting_descriptor_reference;
+mod result;
 mod run;
+mod stack;
 mod tool;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 pub use self::{
+    artifact::{Artifact, ArtifactContent, ArtifactLocation},
+    code_flow::{CodeFlow, ThreadFlow, ThreadFlowLocation, ThreadFlowLocationImportance},
+    fix::{ArtifactChange, Fix, Replacement},
+    invocation::{Invocation, Notification},
+    location::{Address, Location, LogicalLocation, PhysicalLocation, Region},
     log::SarifLog,
     message::Message,
     multiformat_message_string::MultiformatMessageString,
     properties::PropertyBag,
     reporting_descriptor::ReportingDescriptor,
+    reporting_descriptor_reference::ReportingDescriptorReference,
+    result::{BaselineState, Result, ResultKind, ResultLevel},
     run::Run,
+    stack::{Stack, StackFrame},
     tool::{Tool, ToolComponent},
 };
 
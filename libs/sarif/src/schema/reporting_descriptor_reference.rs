@@ -0,0 +1,87 @@
+use alloc::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::schema::PropertyBag;
+
+/// Information about how to locate a relevant reporting descriptor ([§3.52]).
+///
+/// At least one of [`id`], [`index`] or [`guid`] **shall** be present.
+///
+/// [§3.52]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+/// [`id`]: Self::id
+/// [`index`]: Self::index
+/// [`guid`]: Self::guid
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ReportingDescriptorReference<'s> {
+    /// The id of the descriptor.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub id: Option<Cow<'s, str>>,
+
+    /// The index into an array of descriptors in the tool component.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<usize>,
+
+    /// A guid that uniquely identifies the descriptor.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub guid: Option<Uuid>,
+
+    /// Key/value pairs that provide additional information about the reporting descriptor
+    /// reference.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ReportingDescriptorReference<'s> {
+    /// Create a new `ReportingDescriptorReference` referring to the descriptor with the given id.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ReportingDescriptorReference;
+    ///
+    /// let reference = ReportingDescriptorReference::new("E0308").with_index(0);
+    ///
+    /// assert_eq!(reference.id, Some("E0308".into()));
+    /// assert_eq!(reference.index, Some(0));
+    /// ```
+    #[must_use]
+    pub fn new(id: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            id: Some(id.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the index of the descriptor.
+    #[must_use]
+    pub const fn with_index(mut self, index: usize) -> Self {
+        self.index = Some(index);
+        self
+    }
+}
@@ -0,0 +1,461 @@
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use url::Url;
+use uuid::Uuid;
+
+use crate::schema::{
+    ArtifactLocation, CodeFlow, Fix, Location, Message, PropertyBag, ReportingDescriptorReference,
+    Stack,
+};
+
+/// The evaluation state of a result ([§3.27.9]).
+///
+/// [§3.27.9]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum ResultKind {
+    /// The rule was not evaluated, because it does not apply to the analysis target.
+    NotApplicable,
+    /// The rule was evaluated, and no problem was found.
+    Pass,
+    /// The result represents a problem whose severity is specified by the [`ResultLevel`].
+    #[default]
+    Fail,
+    /// The result requires review by a human user to decide if it represents a problem.
+    Review,
+    /// The tool cannot determine whether the result represents a problem.
+    Open,
+    /// The result provides information about the analysis target, but does not indicate a
+    /// problem.
+    Informational,
+}
+
+/// The severity of a result or notification ([§3.27.10]).
+///
+/// [§3.27.10]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum ResultLevel {
+    /// No severity applies, e.g. because the result is not a problem.
+    None,
+    /// A minor problem or an opportunity to improve the code was found.
+    Note,
+    /// A problem was found, which is not considered serious.
+    #[default]
+    Warning,
+    /// A serious problem was found.
+    Error,
+}
+
+/// The state of a result relative to a baseline of a previous run ([§3.27.24]).
+///
+/// [§3.27.24]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum BaselineState {
+    /// The result was not detected in the baseline run.
+    New,
+    /// The result was detected in the baseline run and did not change.
+    Unchanged,
+    /// The result was detected in the baseline run, but some of its properties changed.
+    Updated,
+    /// The result was detected in the baseline run, but not in the current run.
+    Absent,
+}
+
+/// A result produced by an analysis tool ([§3.27]).
+///
+/// [§3.27]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Result<'s> {
+    /// The stable, unique identifier of the rule, if any, to which this result is relevant.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub rule_id: Option<Cow<'s, str>>,
+
+    /// The index within the tool component rules array of the rule object associated with this
+    /// result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rule_index: Option<usize>,
+
+    /// A reference used to locate the rule descriptor relevant to this result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rule: Option<ReportingDescriptorReference<'s>>,
+
+    /// A value that categorizes results by evaluation state.
+    ///
+    /// If absent, the kind is [`ResultKind::Fail`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub kind: Option<ResultKind>,
+
+    /// A value specifying the severity level of the result.
+    ///
+    /// If absent, the level is taken from the rule configuration or defaults to
+    /// [`ResultLevel::Warning`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub level: Option<ResultLevel>,
+
+    /// A message that describes the result.
+    ///
+    /// The first sentence of the message only will be displayed when visible space is limited.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub message: Message<'s>,
+
+    /// Identifies the artifact that the analysis tool was instructed to scan, if it differs from
+    /// the artifact the result was detected in.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub analysis_target: Option<ArtifactLocation<'s>>,
+
+    /// The set of locations where the result was detected.
+    ///
+    /// Specify only one location unless the problem indicated by the result can only be corrected
+    /// by making a change at every specified location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub locations: Vec<Location<'s>>,
+
+    /// A stable, unique identifier for the result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub guid: Option<Uuid>,
+
+    /// A stable, unique identifier for the equivalence class of logically identical results to
+    /// which this result belongs.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub correlation_guid: Option<Uuid>,
+
+    /// A positive integer specifying the number of times this logically unique result was
+    /// observed in this run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub occurrence_count: Option<usize>,
+
+    /// A set of strings that contribute to the stable, unique identity of the result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub partial_fingerprints: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// A set of strings each of which individually defines a stable, unique identity for the
+    /// result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub fingerprints: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// An array of stack objects relevant to the result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub stacks: Vec<Stack<'s>>,
+
+    /// An array of code flow objects relevant to the result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub code_flows: Vec<CodeFlow<'s>>,
+
+    /// A set of locations relevant to this result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub related_locations: Vec<Location<'s>>,
+
+    /// The state of the result with respect to a previous baseline run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub baseline_state: Option<BaselineState>,
+
+    /// An absolute URI at which the result can be viewed.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub hosted_viewer_uri: Option<Url>,
+
+    /// The URIs of the work items associated with this result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub work_item_uris: Vec<Url>,
+
+    /// An array of fix objects, each of which represents a proposed fix to the problem indicated
+    /// by the result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub fixes: Vec<Fix<'s>>,
+
+    /// Key/value pairs that provide additional information about the result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Result<'s> {
+    /// Create a new `Result` with the given message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{
+    ///     ArtifactLocation, Location, Message, PhysicalLocation, PropertyBag, Region, Result,
+    ///     ResultLevel,
+    /// };
+    ///
+    /// let message = Message {
+    ///     text: Some("unused variable `x`".into()),
+    ///     markdown: None,
+    ///     id: None,
+    ///     arguments: Vec::new(),
+    ///     properties: PropertyBag::new(),
+    /// };
+    ///
+    /// let result = Result::new(message)
+    ///     .with_rule_id("unused_variables")
+    ///     .with_level(ResultLevel::Warning)
+    ///     .with_location(Location::new(
+    ///         PhysicalLocation::new(ArtifactLocation::new("src/main.rs"))
+    ///             .with_region(Region::new((2, 9), (2, 10))),
+    ///     ));
+    ///
+    /// assert_eq!(result.rule_id, Some("unused_variables".into()));
+    /// assert_eq!(result.locations.len(), 1);
+    /// ```
+    #[must_use]
+    pub const fn new(message: Message<'s>) -> Self {
+        Self {
+            rule_id: None,
+            rule_index: None,
+            rule: None,
+            kind: None,
+            level: None,
+            message,
+            analysis_target: None,
+            locations: Vec::new(),
+            guid: None,
+            correlation_guid: None,
+            occurrence_count: None,
+            partial_fingerprints: BTreeMap::new(),
+            fingerprints: BTreeMap::new(),
+            stacks: Vec::new(),
+            code_flows: Vec::new(),
+            related_locations: Vec::new(),
+            baseline_state: None,
+            hosted_viewer_uri: None,
+            work_item_uris: Vec::new(),
+            fixes: Vec::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Set the id of the rule this result is relevant to.
+    #[must_use]
+    pub fn with_rule_id(mut self, rule_id: impl Into<Cow<'s, str>>) -> Self {
+        self.rule_id = Some(rule_id.into());
+        self
+    }
+
+    /// Set the index of the rule this result is relevant to.
+    #[must_use]
+    pub const fn with_rule_index(mut self, rule_index: usize) -> Self {
+        self.rule_index = Some(rule_index);
+        self
+    }
+
+    /// Set the evaluation state of the result.
+    #[must_use]
+    pub const fn with_kind(mut self, kind: ResultKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// Set the severity level of the result.
+    #[must_use]
+    pub const fn with_level(mut self, level: ResultLevel) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Add a location where the result was detected.
+    #[must_use]
+    pub fn with_location(mut self, location: Location<'s>) -> Self {
+        self.locations.push(location);
+        self
+    }
+
+    /// Add a location, which is relevant to the result.
+    #[must_use]
+    pub fn with_related_location(mut self, location: Location<'s>) -> Self {
+        self.related_locations.push(location);
+        self
+    }
+
+    /// Add a partial fingerprint contributing to the identity of the result.
+    #[must_use]
+    pub fn with_partial_fingerprint(
+        mut self,
+        name: impl Into<Cow<'s, str>>,
+        value: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.partial_fingerprints.insert(name.into(), value.into());
+        self
+    }
+
+    /// Add a stack, which is relevant to the result.
+    #[must_use]
+    pub fn with_stack(mut self, stack: Stack<'s>) -> Self {
+        self.stacks.push(stack);
+        self
+    }
+
+    /// Add a code flow, which is relevant to the result.
+    #[must_use]
+    pub fn with_code_flow(mut self, code_flow: CodeFlow<'s>) -> Self {
+        self.code_flows.push(code_flow);
+        self
+    }
+
+    /// Add a proposed fix for the result.
+    #[must_use]
+    pub fn with_fix(mut self, fix: Fix<'s>) -> Self {
+        self.fixes.push(fix);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+#[coverage(off)]
+pub(crate) mod tests {
+    use alloc::{borrow::Cow, vec::Vec};
+
+    use crate::schema::{
+        ArtifactChange, ArtifactContent, ArtifactLocation, CodeFlow, Fix, Location,
+        LogicalLocation, Message, PhysicalLocation, PropertyBag, Region, Replacement, Result,
+        ResultKind, ResultLevel, Run, SarifLog, SchemaVersion, Stack, StackFrame, ThreadFlow,
+        ThreadFlowLocation, Tool, ToolComponent, tests::validate_schema,
+    };
+
+    fn message(text: &'static str) -> Message<'static> {
+        Message {
+            text: Some(Cow::Borrowed(text)),
+            markdown: None,
+            id: None,
+            arguments: Vec::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    fn location(uri: &'static str, line: usize) -> Location<'static> {
+        Location::new(
+            PhysicalLocation::new(ArtifactLocation::new(uri).with_uri_base_id("SRCROOT"))
+                .with_region(Region::new((line, 5), (line, 12))),
+        )
+    }
+
+    #[test]
+    fn minimal() {
+        let result = Result::new(message("something went wrong"));
+
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0)
+                .with_run(Run::new(Tool::new(ToolComponent::new("clippy"))).with_result(result)),
+        );
+    }
+
+    #[test]
+    fn full() {
+        let result =
+            Result::new(message("use of possibly uninitialized `x`"))
+                .with_rule_id("E0381")
+                .with_kind(ResultKind::Fail)
+                .with_level(ResultLevel::Error)
+                .with_location(location("src/main.rs", 4).with_logical_location(
+                    LogicalLocation::new("crate::main").with_kind("function"),
+                ))
+                .with_related_location(
+                    location("src/main.rs", 2).with_message(message("declared here")),
+                )
+                .with_partial_fingerprint("primaryLocationLineHash", "39fa2ee980eb94b0:1")
+                .with_stack(Stack::new([StackFrame::new(location("src/main.rs", 4))]))
+                .with_code_flow(CodeFlow::new([ThreadFlow::new([
+                    ThreadFlowLocation::new(location("src/main.rs", 2)),
+                    ThreadFlowLocation::new(location("src/main.rs", 4)),
+                ])]))
+                .with_fix(
+                    Fix::new([ArtifactChange::new(ArtifactLocation::new("src/main.rs"), [
+                        Replacement::new(Region::new((2, 5), (2, 10)))
+                            .with_inserted_content(ArtifactContent::from_text("let x = 0")),
+                    ])])
+                    .with_description(message("initialize `x`")),
+                );
+
+        validate_schema(
+            &SarifLog::new(SchemaVersion::V2_1_0)
+                .with_run(Run::new(Tool::new(ToolComponent::new("rustc"))).with_result(result)),
+        );
+    }
+}
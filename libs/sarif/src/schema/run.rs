@@ -1,7 +1,9 @@
+use alloc::vec::Vec;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::schema::Tool;
+use crate::schema::{Artifact, Invocation, LogicalLocation, Result, Tool};
 
 /// Describes a single run of an analysis tool, and contains the reported output of that run.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -19,6 +21,38 @@ pub struct Run<'s> {
     /// command-line arguments and the like) is identical for all aggregated files.
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub tool: Tool<'s>,
+
+    /// Describes the invocations of the analysis tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub invocations: Vec<Invocation<'s>>,
+
+    /// An array of artifact objects relevant to the run.
+    ///
+    /// Artifacts can be referenced from an [`ArtifactLocation`] by their index in this array.
+    ///
+    /// [`ArtifactLocation`]: crate::schema::ArtifactLocation
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub artifacts: Vec<Artifact<'s>>,
+
+    /// An array of logical locations such as namespaces, types or functions.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub logical_locations: Vec<LogicalLocation<'s>>,
+
+    /// The set of results contained in a SARIF log.
+    ///
+    /// The results array can be omitted when a run is solely exporting rules metadata. It must be
+    /// present (but may be empty) if a log file represents an actual scan.
+    #[cfg_attr(feature = "serde", serde(borrow, default))]
+    pub results: Vec<Result<'s>>,
 }
 
 impl<'s> Run<'s> {
@@ -35,7 +69,69 @@ impl<'s> Run<'s> {
     /// ```
     #[must_use]
     pub const fn new(tool: Tool<'s>) -> Self {
-        Self { tool }
+        Self {
+            tool,
+            invocations: Vec::new(),
+            artifacts: Vec::new(),
+            logical_locations: Vec::new(),
+            results: Vec::new(),
+        }
+    }
+
+    /// Add an invocation of the analysis tool to the run.
+    #[must_use]
+    pub fn with_invocation(mut self, invocation: Invocation<'s>) -> Self {
+        self.invocations.push(invocation);
+        self
+    }
+
+    /// Add an artifact, which is relevant to the run.
+    #[must_use]
+    pub fn with_artifact(mut self, artifact: Artifact<'s>) -> Self {
+        self.artifacts.push(artifact);
+        self
+    }
+
+    /// Add a logical location, which is relevant to the run.
+    #[must_use]
+    pub fn with_logical_location(mut self, logical_location: LogicalLocation<'s>) -> Self {
+        self.logical_locations.push(logical_location);
+        self
+    }
+
+    /// Add a result to the run.
+    #[must_use]
+    pub fn with_result(mut self, result: Result<'s>) -> Self {
+        self.results.push(result);
+        self
+    }
+
+    /// Add multiple results to the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use sarif::schema::{Message, PropertyBag, Result, Run, Tool, ToolComponent};
+    ///
+    /// let message = Message {
+    ///     text: Some(Cow::Borrowed("found an issue")),
+    ///     markdown: None,
+    ///     id: None,
+    ///     arguments: Vec::new(),
+    ///     properties: PropertyBag::new(),
+    /// };
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///     .with_results([Result::new(message.clone()), Result::new(message)]);
+    ///
+    /// assert_eq!(run.results.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_results(mut self, results: impl IntoIterator<Item = Result<'s>>) -> Self {
+        self.results.extend(results);
+        self
     }
 }
 
@@ -45,7 +141,8 @@ impl<'s> Run<'s> {
 pub(crate) mod tests {
 
     use crate::schema::{
-        Run, SarifLog, SchemaVersion, Tool, ToolComponent, tests::validate_schema,
+        Artifact, ArtifactContent, ArtifactLocation, Invocation, Run, SarifLog, SchemaVersion,
+        Tool, ToolComponent, tests::validate_schema,
     };
 
     #[test]
@@ -54,4 +151,21 @@ pub(crate) mod tests {
 
         validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
     }
+
+    #[test]
+    fn invocation_and_artifacts() {
+        let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+            .with_invocation(
+                Invocation::new(true)
+                    .with_command_line("cargo clippy --workspace")
+                    .with_exit_code(0),
+            )
+            .with_artifact(
+                Artifact::new(ArtifactLocation::new("src/lib.rs"))
+                    .with_source_language("rust")
+                    .with_contents(ArtifactContent::from_text("fn main() {}")),
+            );
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
 }
@@ -0,0 +1,124 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Location, Message, PropertyBag};
+
+/// A function call within a stack trace ([§3.45]).
+///
+/// [§3.45]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct StackFrame<'s> {
+    /// The location to which this stack frame refers.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub location: Option<Location<'s>>,
+
+    /// The name of the module that contains the code of this stack frame.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            default,
+            skip_serializing_if = "Option::is_none",
+            deserialize_with = "crate::serde::optional"
+        )
+    )]
+    pub module: Option<Cow<'s, str>>,
+
+    /// The thread identifier of the stack frame.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub thread_id: Option<i64>,
+
+    /// The parameters of the call that is executing.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub parameters: Vec<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the stack frame.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> StackFrame<'s> {
+    /// Create a new `StackFrame` at the given location.
+    #[must_use]
+    pub fn new(location: Location<'s>) -> Self {
+        Self {
+            location: Some(location),
+            ..Self::default()
+        }
+    }
+}
+
+/// A call stack that is relevant to a result ([§3.44]).
+///
+/// [§3.44]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Stack<'s> {
+    /// A message relevant to this call stack.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// An array of stack frames that represents a sequence of calls, rendered in reverse
+    /// chronological order, that comprise the call stack.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub frames: Vec<StackFrame<'s>>,
+
+    /// Key/value pairs that provide additional information about the stack.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Stack<'s> {
+    /// Create a new `Stack` from the given frames, the innermost frame first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Location, PhysicalLocation, Stack, StackFrame};
+    ///
+    /// let stack = Stack::new([StackFrame::new(Location::new(PhysicalLocation::new(
+    ///     ArtifactLocation::new("src/main.rs"),
+    /// )))]);
+    ///
+    /// assert_eq!(stack.frames.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn new(frames: impl IntoIterator<Item = StackFrame<'s>>) -> Self {
+        Self {
+            message: None,
+            frames: frames.into_iter().collect(),
+            properties: PropertyBag::new(),
+        }
+    }
+}
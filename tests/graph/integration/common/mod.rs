@@ -0,0 +1,789 @@
+//! Helpers shared between the integration test harnesses of the different store implementations.
+//!
+//! The test modules are written against the store traits only, so the same tests are run against
+//! every store which provides a `DatabaseTestWrapper` and a `DatabaseApi` in its crate root.
+
+use std::collections::HashMap;
+
+use error_stack::Report;
+use hash_graph_authorization::{
+    AuthorizationApi,
+    schema::{
+        DataTypeRelationAndSubject, DataTypeViewerSubject, EntityRelationAndSubject,
+        EntityTypeInstantiatorSubject, EntityTypeRelationAndSubject, EntityTypeSetting,
+        EntityTypeSettingSubject, EntityTypeViewerSubject, PropertyTypeRelationAndSubject,
+        PropertyTypeSetting, PropertyTypeSettingSubject, PropertyTypeViewerSubject,
+        WebOwnerSubject,
+    },
+    zanzibar::Consistency,
+};
+use hash_graph_store::{
+    account::{AccountStore, InsertAccountIdParams, InsertWebIdParams},
+    change_feed::{ChangeFeedStore, ReadGraphChangesParams, ReadGraphChangesResponse},
+    data_type::{
+        ArchiveDataTypeParams, CountDataTypesParams, CreateDataTypeParams, DataTypeStore,
+        GetDataTypeSubgraphParams, GetDataTypeSubgraphResponse, GetDataTypesParams,
+        GetDataTypesResponse, UnarchiveDataTypeParams, UpdateDataTypeEmbeddingParams,
+        UpdateDataTypesParams,
+    },
+    entity::{
+        AggregateEntitiesParams, AggregateEntitiesResponse, CountEntitiesParams,
+        CreateEntityParams, EntityStore, EntityValidationReport, GetEntitiesParams,
+        GetEntitiesResponse, GetEntityHistoryParams, GetEntityHistoryResponse,
+        GetEntitySubgraphParams, GetEntitySubgraphResponse, ImportEntitiesParams,
+        ImportEntitiesResponse, MergeEntitiesParams, MergeEntitiesResponse,
+        MigrateEntityTypeParams, MigrateEntityTypeResponse, PatchEntityParams,
+        UpdateEntityEmbeddingsParams, ValidateEntityParams,
+    },
+    entity_type::{
+        ArchiveEntityTypeParams, CountEntityTypesParams, CreateEntityTypeParams, EntityTypeStore,
+        GetClosedMultiEntityTypeParams, GetClosedMultiEntityTypeResponse,
+        GetEntityTypeSubgraphParams, GetEntityTypeSubgraphResponse, GetEntityTypesParams,
+        GetEntityTypesResponse, UnarchiveEntityTypeParams, UpdateEntityTypeEmbeddingParams,
+        UpdateEntityTypesParams,
+    },
+    error::{InsertionError, QueryError, UpdateError},
+    property_type::{
+        ArchivePropertyTypeParams, CountPropertyTypesParams, CreatePropertyTypeParams,
+        GetPropertyTypeSubgraphParams, GetPropertyTypeSubgraphResponse, GetPropertyTypesParams,
+        GetPropertyTypesResponse, PropertyTypeStore, UnarchivePropertyTypeParams,
+        UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams,
+    },
+    query::ConflictBehavior,
+};
+use hash_graph_temporal_versioning::{DecisionTime, Timestamp, TransactionTime};
+use hash_graph_types::{
+    account::AccountId,
+    knowledge::entity::{Entity, EntityId},
+    ontology::{
+        DataTypeMetadata, EntityTypeMetadata, OntologyTemporalMetadata,
+        OntologyTypeClassificationMetadata, PropertyTypeMetadata,
+        ProvidedOntologyEditionProvenance,
+    },
+    owned_by_id::OwnedById,
+};
+use hash_tracing::logging::env_filter;
+use type_system::schema::{DataType, EntityType, PropertyType};
+use uuid::Uuid;
+
+use crate::DatabaseApi;
+
+pub(crate) const fn data_type_relationships() -> [DataTypeRelationAndSubject; 1] {
+    [DataTypeRelationAndSubject::Viewer {
+        subject: DataTypeViewerSubject::Public,
+        level: 0,
+    }]
+}
+
+pub(crate) const fn property_type_relationships() -> [PropertyTypeRelationAndSubject; 2] {
+    [
+        PropertyTypeRelationAndSubject::Setting {
+            subject: PropertyTypeSettingSubject::Setting {
+                id: PropertyTypeSetting::UpdateFromWeb,
+            },
+            level: 0,
+        },
+        PropertyTypeRelationAndSubject::Viewer {
+            subject: PropertyTypeViewerSubject::Public,
+            level: 0,
+        },
+    ]
+}
+
+pub(crate) const fn entity_type_relationships() -> [EntityTypeRelationAndSubject; 3] {
+    [
+        EntityTypeRelationAndSubject::Setting {
+            subject: EntityTypeSettingSubject::Setting {
+                id: EntityTypeSetting::UpdateFromWeb,
+            },
+            level: 0,
+        },
+        EntityTypeRelationAndSubject::Viewer {
+            subject: EntityTypeViewerSubject::Public,
+            level: 0,
+        },
+        EntityTypeRelationAndSubject::Instantiator {
+            subject: EntityTypeInstantiatorSubject::Public,
+            level: 0,
+        },
+    ]
+}
+
+pub(crate) fn init_logging() {
+    // It's likely that the initialization failed due to a previous initialization attempt. In this
+    // case, we can ignore the error.
+    let _: core::result::Result<_, _> = tracing_subscriber::fmt()
+        .with_ansi(true)
+        .with_env_filter(env_filter(None))
+        .with_file(true)
+        .with_line_number(true)
+        .with_test_writer()
+        .try_init();
+}
+
+/// Creates a new account with its own web and seeds the store with the provided ontology types.
+pub(crate) async fn seed<S, D, P, E>(
+    store: &mut S,
+    data_types: D,
+    property_types: P,
+    entity_types: E,
+) -> Result<AccountId, Report<InsertionError>>
+where
+    S: AccountStore + DataTypeStore + PropertyTypeStore + EntityTypeStore + Send,
+    D: IntoIterator<Item = &'static str, IntoIter: Send> + Send,
+    P: IntoIterator<Item = &'static str, IntoIter: Send> + Send,
+    E: IntoIterator<Item = &'static str, IntoIter: Send> + Send,
+{
+    let account_id = AccountId::new(Uuid::new_v4());
+    store
+        .insert_account_id(account_id, InsertAccountIdParams { account_id })
+        .await
+        .expect("could not insert account id");
+    store
+        .insert_web_id(account_id, InsertWebIdParams {
+            owned_by_id: OwnedById::new(account_id.into_uuid()),
+            owner: WebOwnerSubject::Account { id: account_id },
+        })
+        .await
+        .expect("could not create web id");
+
+    store
+        .create_data_types(
+            account_id,
+            data_types.into_iter().map(|data_type_str| {
+                let schema: DataType = serde_json::from_str(data_type_str)
+                    .expect("could not parse data type representation");
+                CreateDataTypeParams {
+                    schema,
+                    classification: OntologyTypeClassificationMetadata::Owned {
+                        owned_by_id: OwnedById::new(account_id.into_uuid()),
+                    },
+                    relationships: data_type_relationships(),
+                    conflict_behavior: ConflictBehavior::Skip,
+                    provenance: ProvidedOntologyEditionProvenance::default(),
+                    conversions: HashMap::new(),
+                }
+            }),
+        )
+        .await?;
+
+    store
+        .create_property_types(
+            account_id,
+            property_types.into_iter().map(|property_type_str| {
+                let schema: PropertyType = serde_json::from_str(property_type_str)
+                    .expect("could not property data type representation");
+                CreatePropertyTypeParams {
+                    schema,
+                    classification: OntologyTypeClassificationMetadata::Owned {
+                        owned_by_id: OwnedById::new(account_id.into_uuid()),
+                    },
+                    relationships: property_type_relationships(),
+                    conflict_behavior: ConflictBehavior::Skip,
+                    provenance: ProvidedOntologyEditionProvenance::default(),
+                }
+            }),
+        )
+        .await?;
+
+    store
+        .create_entity_types(
+            account_id,
+            entity_types.into_iter().map(|entity_type_str| {
+                let schema: EntityType = serde_json::from_str(entity_type_str)
+                    .expect("could not entity data type representation");
+                CreateEntityTypeParams {
+                    schema,
+                    classification: OntologyTypeClassificationMetadata::Owned {
+                        owned_by_id: OwnedById::new(account_id.into_uuid()),
+                    },
+                    relationships: entity_type_relationships(),
+                    conflict_behavior: ConflictBehavior::Skip,
+                    provenance: ProvidedOntologyEditionProvenance::default(),
+                }
+            }),
+        )
+        .await?;
+
+    Ok(account_id)
+}
+
+impl<A: AuthorizationApi> DataTypeStore for DatabaseApi<'_, A> {
+    async fn create_data_types<P, R>(
+        &mut self,
+        actor_id: AccountId,
+        params: P,
+    ) -> Result<Vec<DataTypeMetadata>, Report<InsertionError>>
+    where
+        P: IntoIterator<Item = CreateDataTypeParams<R>, IntoIter: Send> + Send,
+        R: IntoIterator<Item = DataTypeRelationAndSubject> + Send + Sync,
+    {
+        self.store.create_data_types(actor_id, params).await
+    }
+
+    async fn count_data_types(
+        &self,
+        actor_id: AccountId,
+        params: CountDataTypesParams<'_>,
+    ) -> Result<usize, Report<QueryError>> {
+        self.store.count_data_types(actor_id, params).await
+    }
+
+    async fn get_data_types(
+        &self,
+        actor_id: AccountId,
+        mut params: GetDataTypesParams<'_>,
+    ) -> Result<GetDataTypesResponse, Report<QueryError>> {
+        let include_count = params.include_count;
+        let has_limit = params.limit.is_some();
+        params.include_count = true;
+
+        let count = self
+            .count_data_types(actor_id, CountDataTypesParams {
+                filter: params.filter.clone(),
+                temporal_axes: params.temporal_axes.clone(),
+                include_drafts: params.include_drafts,
+            })
+            .await?;
+
+        let mut response = self.store.get_data_types(actor_id, params).await?;
+
+        // We can ensure that `count_data_types` and `get_data_types` return the same count;
+        assert_eq!(response.count, Some(count));
+        // if the limit is not set, the count should be equal to the number of data types returned
+        if !has_limit {
+            assert_eq!(count, response.data_types.len());
+        }
+
+        if !include_count {
+            response.count = None;
+        }
+        Ok(response)
+    }
+
+    async fn get_data_type_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetDataTypeSubgraphParams<'_>,
+    ) -> Result<GetDataTypeSubgraphResponse, Report<QueryError>> {
+        let include_count = params.include_count;
+        let has_limit = params.limit.is_some();
+        params.include_count = true;
+
+        let count = self
+            .count_data_types(actor_id, CountDataTypesParams {
+                filter: params.filter.clone(),
+                temporal_axes: params.temporal_axes.clone(),
+                include_drafts: params.include_drafts,
+            })
+            .await?;
+
+        let mut response = self.store.get_data_type_subgraph(actor_id, params).await?;
+
+        // We can ensure that `count_data_types` and `get_data_type_subgraph` return the same count;
+        assert_eq!(response.count, Some(count));
+        // if the limit is not set, the count should be equal to the number of data types returned
+        if !has_limit {
+            assert_eq!(count, response.subgraph.roots.len());
+        }
+
+        if !include_count {
+            response.count = None;
+        }
+        Ok(response)
+    }
+
+    async fn update_data_type<R>(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdateDataTypesParams<R>,
+    ) -> Result<DataTypeMetadata, Report<UpdateError>>
+    where
+        R: IntoIterator<Item = DataTypeRelationAndSubject> + Send + Sync,
+    {
+        self.store.update_data_type(actor_id, params).await
+    }
+
+    async fn archive_data_type(
+        &mut self,
+        actor_id: AccountId,
+        params: ArchiveDataTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
+        self.store.archive_data_type(actor_id, params).await
+    }
+
+    async fn unarchive_data_type(
+        &mut self,
+        actor_id: AccountId,
+        params: UnarchiveDataTypeParams,
+    ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
+        self.store.unarchive_data_type(actor_id, params).await
+    }
+
+    async fn update_data_type_embeddings(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdateDataTypeEmbeddingParams<'_>,
+    ) -> Result<(), Report<UpdateError>> {
+        self.store
+            .update_data_type_embeddings(actor_id, params)
+            .await
+    }
+
+    async fn reindex_data_type_cache(&mut self) -> Result<(), Report<UpdateError>> {
+        self.store.reindex_entity_type_cache().await
+    }
+}
+
+impl<A: AuthorizationApi> PropertyTypeStore for DatabaseApi<'_, A> {
+    async fn create_property_types<P, R>(
+        &mut self,
+        actor_id: AccountId,
+        params: P,
+    ) -> Result<Vec<PropertyTypeMetadata>, Report<InsertionError>>
+    where
+        P: IntoIterator<Item = CreatePropertyTypeParams<R>, IntoIter: Send> + Send,
+        R: IntoIterator<Item = PropertyTypeRelationAndSubject> + Send + Sync,
+    {
+        self.store.create_property_types(actor_id, params).await
+    }
+
+    async fn count_property_types(
+        &self,
+        actor_id: AccountId,
+        params: CountPropertyTypesParams<'_>,
+    ) -> Result<usize, Report<QueryError>> {
+        self.store.count_property_types(actor_id, params).await
+    }
+
+    async fn get_property_types(
+        &self,
+        actor_id: AccountId,
+        mut params: GetPropertyTypesParams<'_>,
+    ) -> Result<GetPropertyTypesResponse, Report<QueryError>> {
+        let include_count = params.include_count;
+        let has_limit = params.limit.is_some();
+        params.include_count = true;
+
+        let count = self
+            .count_property_types(actor_id, CountPropertyTypesParams {
+                filter: params.filter.clone(),
+                temporal_axes: params.temporal_axes.clone(),
+                include_drafts: params.include_drafts,
+            })
+            .await?;
+
+        let mut response = self.store.get_property_types(actor_id, params).await?;
+
+        // We can ensure that `count_property_types` and `get_property_types` return the same count;
+        assert_eq!(response.count, Some(count));
+        // if the limit is not set, the count should be equal to the number of property types
+        // returned
+        if !has_limit {
+            assert_eq!(count, response.property_types.len());
+        }
+
+        if !include_count {
+            response.count = None;
+        }
+        Ok(response)
+    }
+
+    async fn get_property_type_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetPropertyTypeSubgraphParams<'_>,
+    ) -> Result<GetPropertyTypeSubgraphResponse, Report<QueryError>> {
+        let include_count = params.include_count;
+        let has_limit = params.limit.is_some();
+        params.include_count = true;
+
+        let count = self
+            .count_property_types(actor_id, CountPropertyTypesParams {
+                filter: params.filter.clone(),
+                temporal_axes: params.temporal_axes.clone(),
+                include_drafts: params.include_drafts,
+            })
+            .await?;
+
+        let mut response = self
+            .store
+            .get_property_type_subgraph(actor_id, params)
+            .await?;
+
+        // We can ensure that `count_property_types` and `get_property_type_subgraph` return the
+        // same count;
+        assert_eq!(response.count, Some(count));
+        // if the limit is not set, the count should be equal to the number of property types
+        // returned
+        if !has_limit {
+            assert_eq!(count, response.subgraph.roots.len());
+        }
+
+        if !include_count {
+            response.count = None;
+        }
+        Ok(response)
+    }
+
+    async fn update_property_type<R>(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdatePropertyTypesParams<R>,
+    ) -> Result<PropertyTypeMetadata, Report<UpdateError>>
+    where
+        R: IntoIterator<Item = PropertyTypeRelationAndSubject> + Send + Sync,
+    {
+        self.store.update_property_type(actor_id, params).await
+    }
+
+    async fn archive_property_type(
+        &mut self,
+        actor_id: AccountId,
+        params: ArchivePropertyTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
+        self.store.archive_property_type(actor_id, params).await
+    }
+
+    async fn unarchive_property_type(
+        &mut self,
+        actor_id: AccountId,
+        params: UnarchivePropertyTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
+        self.store.unarchive_property_type(actor_id, params).await
+    }
+
+    async fn update_property_type_embeddings(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdatePropertyTypeEmbeddingParams<'_>,
+    ) -> Result<(), Report<UpdateError>> {
+        self.store
+            .update_property_type_embeddings(actor_id, params)
+            .await
+    }
+}
+
+impl<A: AuthorizationApi> EntityTypeStore for DatabaseApi<'_, A> {
+    async fn create_entity_types<P, R>(
+        &mut self,
+        actor_id: AccountId,
+        params: P,
+    ) -> Result<Vec<EntityTypeMetadata>, Report<InsertionError>>
+    where
+        P: IntoIterator<Item = CreateEntityTypeParams<R>, IntoIter: Send> + Send,
+        R: IntoIterator<Item = EntityTypeRelationAndSubject> + Send + Sync,
+    {
+        self.store.create_entity_types(actor_id, params).await
+    }
+
+    async fn count_entity_types(
+        &self,
+        actor_id: AccountId,
+        params: CountEntityTypesParams<'_>,
+    ) -> Result<usize, Report<QueryError>> {
+        self.store.count_entity_types(actor_id, params).await
+    }
+
+    async fn get_entity_types(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntityTypesParams<'_>,
+    ) -> Result<GetEntityTypesResponse, Report<QueryError>> {
+        let include_count = params.include_count;
+        let has_limit = params.limit.is_some();
+        params.include_count = true;
+
+        let count = self
+            .count_entity_types(actor_id, CountEntityTypesParams {
+                filter: params.filter.clone(),
+                temporal_axes: params.temporal_axes.clone(),
+                include_drafts: params.include_drafts,
+            })
+            .await?;
+
+        let mut response = self.store.get_entity_types(actor_id, params).await?;
+
+        // We can ensure that `count_entity_types` and `get_entity_types` return the same count;
+        assert_eq!(response.count, Some(count));
+        // if the limit is not set, the count should be equal to the number of entity types returned
+        if !has_limit {
+            assert_eq!(count, response.entity_types.len());
+        }
+
+        if !include_count {
+            response.count = None;
+        }
+        Ok(response)
+    }
+
+    async fn get_closed_multi_entity_types(
+        &self,
+        actor_id: AccountId,
+        params: GetClosedMultiEntityTypeParams,
+    ) -> Result<GetClosedMultiEntityTypeResponse, Report<QueryError>> {
+        self.store
+            .get_closed_multi_entity_types(actor_id, params)
+            .await
+    }
+
+    async fn get_entity_type_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntityTypeSubgraphParams<'_>,
+    ) -> Result<GetEntityTypeSubgraphResponse, Report<QueryError>> {
+        let include_count = params.include_count;
+        let has_limit = params.limit.is_some();
+        params.include_count = true;
+
+        let count = self
+            .count_entity_types(actor_id, CountEntityTypesParams {
+                filter: params.filter.clone(),
+                temporal_axes: params.temporal_axes.clone(),
+                include_drafts: params.include_drafts,
+            })
+            .await?;
+
+        let mut response = self
+            .store
+            .get_entity_type_subgraph(actor_id, params)
+            .await?;
+
+        // We can ensure that `count_entity_types` and `get_entity_type_subgraph` return the same
+        // count;
+        assert_eq!(response.count, Some(count));
+        // if the limit is not set, the count should be equal to the number of entity types returned
+        if !has_limit {
+            assert_eq!(count, response.subgraph.roots.len());
+        }
+
+        if !include_count {
+            response.count = None;
+        }
+        Ok(response)
+    }
+
+    async fn update_entity_type<R>(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdateEntityTypesParams<R>,
+    ) -> Result<EntityTypeMetadata, Report<UpdateError>>
+    where
+        R: IntoIterator<Item = EntityTypeRelationAndSubject> + Send + Sync,
+    {
+        self.store.update_entity_type(actor_id, params).await
+    }
+
+    async fn archive_entity_type(
+        &mut self,
+        actor_id: AccountId,
+        params: ArchiveEntityTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
+        self.store.archive_entity_type(actor_id, params).await
+    }
+
+    async fn unarchive_entity_type(
+        &mut self,
+        actor_id: AccountId,
+        params: UnarchiveEntityTypeParams<'_>,
+    ) -> Result<OntologyTemporalMetadata, Report<UpdateError>> {
+        self.store.unarchive_entity_type(actor_id, params).await
+    }
+
+    async fn update_entity_type_embeddings(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdateEntityTypeEmbeddingParams<'_>,
+    ) -> Result<(), Report<UpdateError>> {
+        self.store
+            .update_entity_type_embeddings(actor_id, params)
+            .await
+    }
+
+    async fn reindex_entity_type_cache(&mut self) -> Result<(), Report<UpdateError>> {
+        self.store.reindex_entity_type_cache().await
+    }
+}
+
+impl<A> EntityStore for DatabaseApi<'_, A>
+where
+    A: AuthorizationApi,
+{
+    async fn create_entities<R>(
+        &mut self,
+        actor_id: AccountId,
+        params: Vec<CreateEntityParams<R>>,
+    ) -> Result<Vec<Entity>, Report<InsertionError>>
+    where
+        R: IntoIterator<Item = EntityRelationAndSubject> + Send,
+    {
+        self.store.create_entities(actor_id, params).await
+    }
+
+    async fn validate_entities(
+        &self,
+        actor_id: AccountId,
+        consistency: Consistency<'_>,
+        params: Vec<ValidateEntityParams<'_>>,
+    ) -> HashMap<usize, EntityValidationReport> {
+        self.store
+            .validate_entities(actor_id, consistency, params)
+            .await
+    }
+
+    async fn import_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: ImportEntitiesParams,
+    ) -> Result<ImportEntitiesResponse, Report<InsertionError>> {
+        self.store.import_entities(actor_id, params).await
+    }
+
+    async fn get_entities(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntitiesParams<'_>,
+    ) -> Result<GetEntitiesResponse<'static>, Report<QueryError>> {
+        let include_count = params.include_count;
+        let has_limit = params.limit.is_some();
+        params.include_count = true;
+
+        let count = self
+            .count_entities(actor_id, CountEntitiesParams {
+                filter: params.filter.clone(),
+                temporal_axes: params.temporal_axes.clone(),
+                include_drafts: params.include_drafts,
+            })
+            .await?;
+
+        let mut response = self.store.get_entities(actor_id, params).await?;
+
+        // We can ensure that `count_entities` and `get_entity` return the same count;
+        assert_eq!(response.count, Some(count));
+        // if the limit is not set, the count should be equal to the number of entities returned
+        if !has_limit {
+            assert_eq!(count, response.entities.len());
+        }
+
+        if !include_count {
+            response.count = None;
+        }
+        Ok(response)
+    }
+
+    async fn get_entity_subgraph(
+        &self,
+        actor_id: AccountId,
+        mut params: GetEntitySubgraphParams<'_>,
+    ) -> Result<GetEntitySubgraphResponse<'static>, Report<QueryError>> {
+        let include_count = params.include_count;
+        let has_limit = params.limit.is_some();
+        params.include_count = true;
+
+        let count = self
+            .count_entities(actor_id, CountEntitiesParams {
+                filter: params.filter.clone(),
+                temporal_axes: params.temporal_axes.clone(),
+                include_drafts: params.include_drafts,
+            })
+            .await?;
+        let mut response = self.store.get_entity_subgraph(actor_id, params).await?;
+
+        // We can ensure that `count_entities` and `get_entity` return the same count;
+        assert_eq!(response.count, Some(count));
+        // if the limit is not set, the count should be equal to the number of entities returned
+        if !has_limit {
+            assert_eq!(count, response.subgraph.roots.len());
+        }
+
+        if !include_count {
+            response.count = None;
+        }
+        Ok(response)
+    }
+
+    async fn count_entities(
+        &self,
+        actor_id: AccountId,
+        params: CountEntitiesParams<'_>,
+    ) -> Result<usize, Report<QueryError>> {
+        self.store.count_entities(actor_id, params).await
+    }
+
+    async fn aggregate_entities(
+        &self,
+        actor_id: AccountId,
+        params: AggregateEntitiesParams<'_>,
+    ) -> Result<AggregateEntitiesResponse, Report<QueryError>> {
+        self.store.aggregate_entities(actor_id, params).await
+    }
+
+    async fn get_entity_by_id(
+        &self,
+        actor_id: AccountId,
+        entity_id: EntityId,
+        transaction_time: Option<Timestamp<TransactionTime>>,
+        decision_time: Option<Timestamp<DecisionTime>>,
+    ) -> Result<Entity, Report<QueryError>> {
+        self.store
+            .get_entity_by_id(actor_id, entity_id, transaction_time, decision_time)
+            .await
+    }
+
+    async fn patch_entity(
+        &mut self,
+        actor_id: AccountId,
+        params: PatchEntityParams,
+    ) -> Result<Entity, Report<UpdateError>> {
+        self.store.patch_entity(actor_id, params).await
+    }
+
+    async fn merge_entities(
+        &mut self,
+        actor_id: AccountId,
+        params: MergeEntitiesParams,
+    ) -> Result<MergeEntitiesResponse, Report<UpdateError>> {
+        self.store.merge_entities(actor_id, params).await
+    }
+
+    async fn migrate_entity_type(
+        &mut self,
+        actor_id: AccountId,
+        params: MigrateEntityTypeParams,
+    ) -> Result<MigrateEntityTypeResponse, Report<UpdateError>> {
+        self.store.migrate_entity_type(actor_id, params).await
+    }
+
+    async fn get_entity_history(
+        &self,
+        actor_id: AccountId,
+        params: GetEntityHistoryParams,
+    ) -> Result<GetEntityHistoryResponse, Report<QueryError>> {
+        self.store.get_entity_history(actor_id, params).await
+    }
+
+    async fn update_entity_embeddings(
+        &mut self,
+        actor_id: AccountId,
+        params: UpdateEntityEmbeddingsParams<'_>,
+    ) -> Result<(), Report<UpdateError>> {
+        self.store.update_entity_embeddings(actor_id, params).await
+    }
+
+    async fn reindex_entity_cache(&mut self) -> Result<(), Report<UpdateError>> {
+        self.store.reindex_entity_cache().await
+    }
+}
+
+impl<A> ChangeFeedStore for DatabaseApi<'_, A>
+where
+    A: AuthorizationApi,
+{
+    async fn read_graph_changes(
+        &self,
+        actor_id: AccountId,
+        params: ReadGraphChangesParams,
+    ) -> Result<ReadGraphChangesResponse, Report<QueryError>> {
+        self.store.read_graph_changes(actor_id, params).await
+    }
+}
@@ -0,0 +1,102 @@
+//! Runs the store-agnostic subset of the Postgres integration tests against the in-memory store.
+
+#![feature(assert_matches)]
+#![expect(
+    clippy::missing_panics_doc,
+    clippy::missing_errors_doc,
+    clippy::unwrap_used
+)]
+#![expect(
+    clippy::significant_drop_tightening,
+    reason = "This should be enabled but it's currently too noisy"
+)]
+
+extern crate alloc;
+
+#[path = "../common/mod.rs"]
+mod common;
+
+#[path = "../postgres/data_type.rs"]
+mod data_type;
+#[path = "../postgres/drafts.rs"]
+mod drafts;
+#[path = "../postgres/entity.rs"]
+mod entity;
+#[path = "../postgres/entity_type.rs"]
+mod entity_type;
+#[path = "../postgres/links.rs"]
+mod links;
+#[path = "../postgres/property_type.rs"]
+mod property_type;
+#[path = "../postgres/sorting.rs"]
+mod sorting;
+#[path = "../postgres/traversal.rs"]
+mod traversal;
+
+use core::marker::PhantomData;
+
+use error_stack::Report;
+use hash_graph_authorization::{AuthorizationApi, NoAuthorization};
+use hash_graph_memory_store::store::{
+    self as store_error, MemoryStore, MemoryStorePool, MemoryStoreSettings,
+};
+use hash_graph_store::{error::InsertionError, pool::StorePool as _};
+use hash_graph_types::account::AccountId;
+
+use self::common::{
+    data_type_relationships, entity_type_relationships, init_logging, property_type_relationships,
+};
+
+pub struct DatabaseTestWrapper<A: AuthorizationApi> {
+    pool: MemoryStorePool,
+    authorization_api: A,
+}
+
+pub struct DatabaseApi<'pool, A: AuthorizationApi> {
+    store: MemoryStore<A>,
+    account_id: AccountId,
+    _marker: PhantomData<&'pool ()>,
+}
+
+impl DatabaseTestWrapper<NoAuthorization> {
+    #[expect(
+        clippy::unused_async,
+        reason = "The constructor mirrors the one of the Postgres test harness"
+    )]
+    pub async fn new() -> Self {
+        init_logging();
+
+        Self {
+            pool: MemoryStorePool::new(MemoryStoreSettings::default()),
+            authorization_api: NoAuthorization,
+        }
+    }
+}
+
+impl<A: AuthorizationApi> DatabaseTestWrapper<A> {
+    pub async fn seed<D, P, E>(
+        &mut self,
+        data_types: D,
+        property_types: P,
+        entity_types: E,
+    ) -> Result<DatabaseApi<'_, &mut A>, Report<InsertionError>>
+    where
+        D: IntoIterator<Item = &'static str, IntoIter: Send> + Send,
+        P: IntoIterator<Item = &'static str, IntoIter: Send> + Send,
+        E: IntoIterator<Item = &'static str, IntoIter: Send> + Send,
+    {
+        let mut store = self
+            .pool
+            .acquire(&mut self.authorization_api, None)
+            .await
+            .expect("could not acquire a memory store");
+
+        let account_id = common::seed(&mut store, data_types, property_types, entity_types).await?;
+
+        Ok(DatabaseApi {
+            store,
+            account_id,
+            _marker: PhantomData,
+        })
+    }
+}
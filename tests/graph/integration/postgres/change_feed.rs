@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+
+use hash_graph_authorization::AuthorizationApi;
+use hash_graph_store::{
+    change_feed::{ChangeFeedStore as _, GraphChangeKind, ReadGraphChangesParams},
+    entity::{CreateEntityParams, EntityStore as _, PatchEntityParams},
+};
+use hash_graph_test_data::{data_type, entity, entity_type, property_type};
+use hash_graph_types::{
+    knowledge::{
+        entity::{EntityId, ProvidedEntityEditionProvenance},
+        property::{PropertyObject, PropertyWithMetadataObject},
+    },
+    owned_by_id::OwnedById,
+};
+use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+/// Reads the whole change feed and returns the changes affecting `entity_id`.
+async fn entity_changes<A: AuthorizationApi>(
+    api: &DatabaseApi<'_, A>,
+    entity_id: EntityId,
+) -> Vec<GraphChangeKind> {
+    let mut changes = Vec::new();
+    let mut after = None;
+    loop {
+        let response = api
+            .read_graph_changes(api.account_id, ReadGraphChangesParams { after, limit: 100 })
+            .await
+            .expect("could not read change feed");
+        if response.changes.is_empty() {
+            break changes;
+        }
+        after = response.cursor;
+        changes.extend(
+            response
+                .changes
+                .into_iter()
+                .map(|change| change.change)
+                .filter(|change| match change {
+                    GraphChangeKind::EntityCreated { entity_id: id, .. }
+                    | GraphChangeKind::EntityUpdated { entity_id: id, .. }
+                    | GraphChangeKind::EntityArchived { entity_id: id, .. } => *id == entity_id,
+                    _ => false,
+                }),
+        );
+    }
+}
+
+#[tokio::test]
+async fn entity_changes_are_recorded_in_order() {
+    let person: PropertyObject =
+        serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let created = api
+        .create_entity(api.account_id, CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([VersionedUrl {
+                base_url: BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                version: OntologyTypeVersion::new(1),
+            }]),
+            properties: PropertyWithMetadataObject::from_parts(person, None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not create entity");
+    let entity_id = created.metadata.record_id.entity_id;
+
+    let archived = api
+        .patch_entity(api.account_id, PatchEntityParams {
+            entity_id,
+            expected_edition_id: None,
+            properties: Vec::new(),
+            entity_type_ids: HashSet::new(),
+            archived: Some(true),
+            draft: None,
+            decision_time: None,
+            confidence: None,
+            link_data: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not archive entity");
+
+    assert_eq!(entity_changes(&api, entity_id).await, [
+        GraphChangeKind::EntityCreated {
+            entity_id,
+            edition_id: created.metadata.record_id.edition_id,
+        },
+        GraphChangeKind::EntityArchived {
+            entity_id,
+            edition_id: archived.metadata.record_id.edition_id,
+        },
+    ]);
+}
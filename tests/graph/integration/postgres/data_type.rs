@@ -1,10 +1,6 @@
 use core::str::FromStr as _;
 use std::collections::{HashMap, HashSet};
 
-use hash_graph_postgres_store::store::error::{
-    BaseUrlAlreadyExists, OntologyTypeIsNotOwned, OntologyVersionDoesNotExist,
-    VersionedUrlAlreadyExists,
-};
 use hash_graph_store::{
     data_type::{
         CreateDataTypeParams, DataTypeStore as _, GetDataTypesParams, UpdateDataTypesParams,
@@ -37,7 +33,13 @@ use type_system::{
     url::{BaseUrl, VersionedUrl},
 };
 
-use crate::{DatabaseTestWrapper, data_type_relationships};
+use crate::{
+    DatabaseTestWrapper, data_type_relationships,
+    store_error::{
+        BaseUrlAlreadyExists, OntologyTypeIsNotOwned, OntologyVersionDoesNotExist,
+        VersionedUrlAlreadyExists,
+    },
+};
 
 #[tokio::test]
 async fn insert() {
@@ -125,6 +125,7 @@ async fn initial_draft() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: vec![PropertyPatchOperation::Replace {
                 path: PropertyPath::default(),
                 property: PropertyWithMetadata::from_parts(Property::Object(bob()), None)
@@ -135,6 +136,7 @@ async fn initial_draft() {
             draft: Some(true),
             decision_time: None,
             confidence: None,
+            link_data: None,
             provenance: ProvidedEntityEditionProvenance::default(),
         })
         .await
@@ -173,6 +175,7 @@ async fn initial_draft() {
     let updated_live_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: updated_entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: vec![PropertyPatchOperation::Replace {
                 path: PropertyPath::default(),
                 property: PropertyWithMetadata::from_parts(Property::Object(charles()), None)
@@ -183,6 +186,7 @@ async fn initial_draft() {
             draft: Some(false),
             decision_time: None,
             confidence: None,
+            link_data: None,
             provenance: ProvidedEntityEditionProvenance::default(),
         })
         .await
@@ -294,6 +298,7 @@ async fn no_initial_draft() {
         let updated_entity = api
             .patch_entity(api.account_id, PatchEntityParams {
                 entity_id: entity.metadata.record_id.entity_id,
+                expected_edition_id: None,
                 properties: vec![PropertyPatchOperation::Replace {
                     path: PropertyPath::default(),
                     property: PropertyWithMetadata::from_parts(Property::Object(bob()), None)
@@ -304,6 +309,7 @@ async fn no_initial_draft() {
                 draft: Some(true),
                 decision_time: None,
                 confidence: None,
+                link_data: None,
                 provenance: ProvidedEntityEditionProvenance::default(),
             })
             .await
@@ -353,6 +359,7 @@ async fn no_initial_draft() {
         let updated_live_entity = api
             .patch_entity(api.account_id, PatchEntityParams {
                 entity_id: updated_entity.metadata.record_id.entity_id,
+                expected_edition_id: None,
                 properties: vec![PropertyPatchOperation::Replace {
                     path: PropertyPath::default(),
                     property: PropertyWithMetadata::from_parts(Property::Object(charles()), None)
@@ -363,6 +370,7 @@ async fn no_initial_draft() {
                 draft: Some(false),
                 decision_time: None,
                 confidence: None,
+                link_data: None,
                 provenance: ProvidedEntityEditionProvenance::default(),
             })
             .await
@@ -463,6 +471,7 @@ async fn multiple_drafts() {
         let updated_entity = api
             .patch_entity(api.account_id, PatchEntityParams {
                 entity_id: entity.metadata.record_id.entity_id,
+                expected_edition_id: None,
                 properties: vec![PropertyPatchOperation::Replace {
                     path: PropertyPath::default(),
                     property: PropertyWithMetadata::from_parts(Property::Object(bob()), None)
@@ -473,6 +482,7 @@ async fn multiple_drafts() {
                 draft: Some(true),
                 decision_time: None,
                 confidence: None,
+                link_data: None,
                 provenance: ProvidedEntityEditionProvenance::default(),
             })
             .await
@@ -525,6 +535,7 @@ async fn multiple_drafts() {
         let updated_live_entity = api
             .patch_entity(api.account_id, PatchEntityParams {
                 entity_id: draft,
+                expected_edition_id: None,
                 properties: vec![PropertyPatchOperation::Replace {
                     path: PropertyPath::default(),
                     property: PropertyWithMetadata::from_parts(Property::Object(charles()), None)
@@ -535,6 +546,7 @@ async fn multiple_drafts() {
                 draft: Some(false),
                 decision_time: None,
                 confidence: None,
+                link_data: None,
                 provenance: ProvidedEntityEditionProvenance::default(),
             })
             .await
@@ -220,6 +220,7 @@ async fn update() {
     let v2_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: v1_entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: vec![PropertyPatchOperation::Replace {
                 path: PropertyPath::default(),
                 property: PropertyWithMetadata::from_parts(Property::Object(page_v2.clone()), None)
@@ -230,6 +231,7 @@ async fn update() {
             draft: None,
             decision_time: None,
             confidence: None,
+            link_data: None,
             provenance: ProvidedEntityEditionProvenance::default(),
         })
         .await
@@ -0,0 +1,338 @@
+use core::{iter::once, str::FromStr as _};
+use std::collections::{HashMap, HashSet};
+
+use hash_graph_authorization::AuthorizationApi;
+use hash_graph_store::{
+    entity::{CreateEntityParams, EntityStore as _, GetEntityHistoryParams, PatchEntityParams},
+    error::EntityHistoryCursorNotFound,
+};
+use hash_graph_test_data::{data_type, entity, entity_type, property_type};
+use hash_graph_types::{
+    knowledge::{
+        entity::{EntityEditionId, ProvidedEntityEditionProvenance},
+        property::{
+            PropertyObject, PropertyPatchOperation, PropertyPathElement, PropertyProvenance,
+            PropertyWithMetadata, PropertyWithMetadataObject, PropertyWithMetadataValue,
+            ValueMetadata,
+        },
+    },
+    owned_by_id::OwnedById,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use type_system::url::{BaseUrl, VersionedUrl};
+use uuid::Uuid;
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+async fn seed<A: AuthorizationApi>(
+    database: &mut DatabaseTestWrapper<A>,
+) -> DatabaseApi<'_, &mut A> {
+    database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::PERSON_V1,
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database")
+}
+
+fn person_entity_type_id() -> VersionedUrl {
+    VersionedUrl::from_str("https://blockprotocol.org/@alice/types/entity-type/person/v/1")
+        .expect("couldn't construct entity type id")
+}
+
+fn age_property_type_id() -> BaseUrl {
+    BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/age/".to_owned())
+        .expect("couldn't construct Base URL")
+}
+
+fn alice() -> PropertyObject {
+    serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity")
+}
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn entity_history() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let created_entity = api
+        .create_entity(api.account_id, CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([person_entity_type_id()]),
+            properties: PropertyWithMetadataObject::from_parts(alice(), None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not create entity");
+    let entity_id = created_entity.metadata.record_id.entity_id;
+
+    let patched_entity = api
+        .patch_entity(api.account_id, PatchEntityParams {
+            entity_id,
+            expected_edition_id: None,
+            decision_time: None,
+            entity_type_ids: HashSet::new(),
+            properties: vec![PropertyPatchOperation::Add {
+                path: once(PropertyPathElement::from(age_property_type_id())).collect(),
+                property: PropertyWithMetadata::Value(PropertyWithMetadataValue {
+                    value: json!(30),
+                    metadata: ValueMetadata {
+                        confidence: None,
+                        data_type_id: None,
+                        original_data_type_id: None,
+                        provenance: PropertyProvenance::default(),
+                        canonical: HashMap::default(),
+                    },
+                }),
+            }],
+            draft: None,
+            archived: None,
+            confidence: None,
+            link_data: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not patch entity");
+
+    let archived_entity = api
+        .patch_entity(api.account_id, PatchEntityParams {
+            entity_id,
+            expected_edition_id: None,
+            decision_time: None,
+            entity_type_ids: HashSet::new(),
+            properties: Vec::new(),
+            draft: None,
+            archived: Some(true),
+            confidence: None,
+            link_data: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not archive entity");
+
+    let history = api
+        .get_entity_history(api.account_id, GetEntityHistoryParams {
+            entity_id,
+            after: None,
+            limit: None,
+        })
+        .await
+        .expect("could not read entity history");
+    assert!(history.cursor.is_none());
+    assert_eq!(
+        history
+            .editions
+            .iter()
+            .map(|entry| entry.edition_id)
+            .collect::<Vec<_>>(),
+        [
+            created_entity.metadata.record_id.edition_id,
+            patched_entity.metadata.record_id.edition_id,
+            archived_entity.metadata.record_id.edition_id,
+        ]
+    );
+
+    let [created, patched, archived] = history.editions.as_slice() else {
+        panic!("unexpected number of editions");
+    };
+    assert_eq!(created.changes.properties.len(), 1);
+    assert_eq!(created.changes.entity_type_ids.len(), 1);
+    assert_eq!(created.archived_state, None);
+    assert_eq!(patched.changes.properties.len(), 1);
+    assert!(patched.changes.entity_type_ids.is_empty());
+    assert_eq!(patched.archived_state, None);
+    assert!(archived.changes.properties.is_empty());
+    assert_eq!(archived.archived_state, Some(true));
+
+    let first_page = api
+        .get_entity_history(api.account_id, GetEntityHistoryParams {
+            entity_id,
+            after: None,
+            limit: Some(2),
+        })
+        .await
+        .expect("could not read entity history");
+    assert_eq!(first_page.editions.len(), 2);
+    assert_eq!(
+        first_page.cursor,
+        Some(patched_entity.metadata.record_id.edition_id)
+    );
+
+    let second_page = api
+        .get_entity_history(api.account_id, GetEntityHistoryParams {
+            entity_id,
+            after: first_page.cursor,
+            limit: Some(2),
+        })
+        .await
+        .expect("could not read entity history");
+    assert_eq!(second_page.editions.len(), 1);
+    assert_eq!(
+        second_page.editions[0].edition_id,
+        archived_entity.metadata.record_id.edition_id
+    );
+    assert!(second_page.cursor.is_none());
+
+    let unknown_cursor = api
+        .get_entity_history(api.account_id, GetEntityHistoryParams {
+            entity_id,
+            after: Some(EntityEditionId::new(Uuid::new_v4())),
+            limit: Some(2),
+        })
+        .await
+        .expect_err("could read entity history after an unknown edition");
+    assert!(unknown_cursor.contains::<EntityHistoryCursorNotFound>());
+}
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn draft_entity_history() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let created_entity = api
+        .create_entity(api.account_id, CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([person_entity_type_id()]),
+            properties: PropertyWithMetadataObject::from_parts(alice(), None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not create entity");
+    let entity_id = created_entity.metadata.record_id.entity_id;
+
+    let draft_entity = api
+        .patch_entity(api.account_id, PatchEntityParams {
+            entity_id,
+            expected_edition_id: None,
+            decision_time: None,
+            entity_type_ids: HashSet::new(),
+            properties: vec![PropertyPatchOperation::Add {
+                path: once(PropertyPathElement::from(age_property_type_id())).collect(),
+                property: PropertyWithMetadata::Value(PropertyWithMetadataValue {
+                    value: json!(30),
+                    metadata: ValueMetadata {
+                        confidence: None,
+                        data_type_id: None,
+                        original_data_type_id: None,
+                        provenance: PropertyProvenance::default(),
+                        canonical: HashMap::default(),
+                    },
+                }),
+            }],
+            draft: Some(true),
+            archived: None,
+            confidence: None,
+            link_data: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not create draft");
+    let draft_entity_id = draft_entity.metadata.record_id.entity_id;
+    assert!(draft_entity_id.draft_id.is_some());
+
+    let archived_entity = api
+        .patch_entity(api.account_id, PatchEntityParams {
+            entity_id,
+            expected_edition_id: None,
+            decision_time: None,
+            entity_type_ids: HashSet::new(),
+            properties: Vec::new(),
+            draft: None,
+            archived: Some(true),
+            confidence: None,
+            link_data: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not archive entity");
+
+    let history = api
+        .get_entity_history(api.account_id, GetEntityHistoryParams {
+            entity_id,
+            after: None,
+            limit: None,
+        })
+        .await
+        .expect("could not read entity history");
+    assert_eq!(
+        history
+            .editions
+            .iter()
+            .map(|entry| entry.edition_id)
+            .collect::<Vec<_>>(),
+        [
+            created_entity.metadata.record_id.edition_id,
+            draft_entity.metadata.record_id.edition_id,
+            archived_entity.metadata.record_id.edition_id,
+        ]
+    );
+
+    let [_, draft, archived] = history.editions.as_slice() else {
+        panic!("unexpected number of editions");
+    };
+    // The draft is compared to the live edition it was created from.
+    assert_eq!(draft.changes.properties.len(), 1);
+    assert!(draft.changes.entity_type_ids.is_empty());
+    // The live edition is compared to the previous live edition, not to the draft.
+    assert!(archived.changes.properties.is_empty());
+    assert_eq!(archived.archived_state, Some(true));
+
+    let draft_history = api
+        .get_entity_history(api.account_id, GetEntityHistoryParams {
+            entity_id: draft_entity_id,
+            after: None,
+            limit: None,
+        })
+        .await
+        .expect("could not read draft history");
+    let [draft] = draft_history.editions.as_slice() else {
+        panic!("unexpected number of draft editions");
+    };
+    assert_eq!(draft.edition_id, draft_entity.metadata.record_id.edition_id);
+    assert_eq!(draft.changes.properties.len(), 1);
+    assert!(draft.changes.entity_type_ids.is_empty());
+
+    let unknown_cursor = api
+        .get_entity_history(api.account_id, GetEntityHistoryParams {
+            entity_id: draft_entity_id,
+            after: Some(archived_entity.metadata.record_id.edition_id),
+            limit: None,
+        })
+        .await
+        .expect_err("could read draft history after a live edition");
+    assert!(unknown_cursor.contains::<EntityHistoryCursorNotFound>());
+}
@@ -0,0 +1,167 @@
+use core::str::FromStr as _;
+use std::collections::{HashMap, HashSet};
+
+use hash_graph_authorization::AuthorizationApi;
+use hash_graph_store::entity::{
+    EntityImportColumn, EntityImportFormat, EntityStore as _, ImportEntitiesParams, ImportRowError,
+};
+use hash_graph_test_data::{data_type, entity_type, property_type};
+use hash_graph_types::{
+    knowledge::{entity::ProvidedEntityEditionProvenance, property::Property},
+    owned_by_id::OwnedById,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use type_system::{
+    schema::JsonSchemaValueType,
+    url::{BaseUrl, VersionedUrl},
+};
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+async fn seed<A: AuthorizationApi>(
+    database: &mut DatabaseTestWrapper<A>,
+) -> DatabaseApi<'_, &mut A> {
+    database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::PERSON_V1,
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database")
+}
+
+fn person_entity_type_id() -> VersionedUrl {
+    VersionedUrl::from_str("https://blockprotocol.org/@alice/types/entity-type/person/v/1")
+        .expect("couldn't construct entity type id")
+}
+
+fn name_property_type_id() -> BaseUrl {
+    BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/name/".to_owned())
+        .expect("couldn't construct Base URL")
+}
+
+fn age_property_type_id() -> BaseUrl {
+    BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/age/".to_owned())
+        .expect("couldn't construct Base URL")
+}
+
+fn number_data_type_id() -> VersionedUrl {
+    VersionedUrl::from_str("https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1")
+        .expect("couldn't construct data type id")
+}
+
+fn import_params(
+    owned_by_id: OwnedById,
+    format: EntityImportFormat,
+    data: &str,
+    dry_run: bool,
+) -> ImportEntitiesParams {
+    ImportEntitiesParams {
+        owned_by_id,
+        entity_type_ids: HashSet::from([person_entity_type_id()]),
+        format,
+        data: data.to_owned(),
+        columns: HashMap::from([
+            ("name".to_owned(), EntityImportColumn {
+                property_type_base_url: name_property_type_id(),
+                data_type_id: None,
+                convert_to: None,
+            }),
+            ("age".to_owned(), EntityImportColumn {
+                property_type_base_url: age_property_type_id(),
+                data_type_id: Some(number_data_type_id()),
+                convert_to: None,
+            }),
+        ]),
+        draft: false,
+        dry_run,
+        relationships: Vec::new(),
+        provenance: ProvidedEntityEditionProvenance::default(),
+    }
+}
+
+#[tokio::test]
+async fn import_csv() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let response = api
+        .import_entities(
+            api.account_id,
+            import_params(
+                OwnedById::new(api.account_id.into_uuid()),
+                EntityImportFormat::Csv,
+                "name,age,comment\r\nAlice,42,ignored\r\n\"Bob, \"\"Junior\"\"\",7,\r\n",
+                false,
+            ),
+        )
+        .await
+        .expect("could not import entities");
+
+    assert_eq!(response.row_count, 2);
+    assert!(response.reports.is_empty());
+    assert_eq!(response.entities.len(), 2);
+    assert_eq!(
+        response.entities[1].properties.properties(),
+        &HashMap::from([
+            (
+                name_property_type_id(),
+                Property::Value(json!("Bob, \"Junior\""))
+            ),
+            (age_property_type_id(), Property::Value(json!(7))),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn import_json_lines_dry_run() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let response = api
+        .import_entities(
+            api.account_id,
+            import_params(
+                OwnedById::new(api.account_id.into_uuid()),
+                EntityImportFormat::JsonLines,
+                "{\"name\": \"Alice\", \"age\": \"42\"}\n{\"name\": \"Bob\", \"age\": \
+                 \"old\"}\n\n{",
+                true,
+            ),
+        )
+        .await
+        .expect("could not import entities");
+
+    assert_eq!(response.row_count, 3);
+    assert!(response.entities.is_empty());
+    assert!(!response.reports.contains_key(&0));
+
+    let ImportRowError::InvalidValue { data } = &response.reports[&1].errors[0] else {
+        panic!("expected an invalid value");
+    };
+    assert_eq!(data.column, "age");
+    assert_eq!(data.expected, JsonSchemaValueType::Number);
+
+    assert!(matches!(
+        response.reports[&2].errors[0],
+        ImportRowError::Malformed { .. }
+    ));
+}
@@ -629,12 +629,14 @@ async fn remove_link() {
 
     api.patch_entity(api.account_id, PatchEntityParams {
         entity_id: link_entity_metadata.metadata.record_id.entity_id,
+        expected_edition_id: None,
         decision_time: None,
         archived: Some(true),
         draft: None,
         entity_type_ids: HashSet::new(),
         properties: vec![],
         confidence: None,
+        link_data: None,
         provenance: ProvidedEntityEditionProvenance::default(),
     })
     .await
@@ -0,0 +1,359 @@
+use core::str::FromStr as _;
+use std::collections::{HashMap, HashSet};
+
+use hash_graph_authorization::AuthorizationApi;
+use hash_graph_store::{
+    entity::{
+        CountEntitiesParams, CreateEntityParams, EntityQueryPath, EntityStore as _,
+        MergeEntitiesParams, PropertyMergeStrategy,
+    },
+    filter::{Filter, FilterExpression, Parameter},
+    subgraph::{
+        edges::{EdgeDirection, KnowledgeGraphEdgeKind},
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
+            VariableTemporalAxisUnresolved,
+        },
+    },
+};
+use hash_graph_temporal_versioning::ClosedTemporalBound;
+use hash_graph_test_data::{data_type, entity, entity_type, property_type};
+use hash_graph_types::{
+    knowledge::{
+        entity::{Entity, ProvidedEntityEditionProvenance},
+        link::LinkData,
+        property::{Property, PropertyObject, PropertyProvenance, PropertyWithMetadataObject},
+    },
+    owned_by_id::OwnedById,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use type_system::url::{BaseUrl, VersionedUrl};
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+async fn seed<A: AuthorizationApi>(
+    database: &mut DatabaseTestWrapper<A>,
+) -> DatabaseApi<'_, &mut A> {
+    database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::PERSON_V1,
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database")
+}
+
+fn person_entity_type_id() -> VersionedUrl {
+    VersionedUrl::from_str("https://blockprotocol.org/@alice/types/entity-type/person/v/1")
+        .expect("couldn't construct entity type id")
+}
+
+fn friend_of_entity_type_id() -> VersionedUrl {
+    VersionedUrl::from_str("https://blockprotocol.org/@alice/types/entity-type/friend-of/v/1")
+        .expect("couldn't construct entity type id")
+}
+
+fn name_property_type_id() -> BaseUrl {
+    BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/name/".to_owned())
+        .expect("couldn't construct Base URL")
+}
+
+fn age_property_type_id() -> BaseUrl {
+    BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/age/".to_owned())
+        .expect("couldn't construct Base URL")
+}
+
+async fn create_person<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    properties: &str,
+) -> Entity {
+    let properties: PropertyObject =
+        serde_json::from_str(properties).expect("could not parse entity");
+    api.create_entity(api.account_id, CreateEntityParams {
+        owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+        entity_uuid: None,
+        decision_time: None,
+        entity_type_ids: HashSet::from([person_entity_type_id()]),
+        properties: PropertyWithMetadataObject::from_parts(properties, None)
+            .expect("could not create property with metadata object"),
+        confidence: None,
+        link_data: None,
+        draft: false,
+        relationships: [],
+        provenance: ProvidedEntityEditionProvenance::default(),
+    })
+    .await
+    .expect("could not create entity")
+}
+
+async fn create_friend_of<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    left_entity: &Entity,
+    right_entity: &Entity,
+) -> Entity {
+    api.create_entity(api.account_id, CreateEntityParams {
+        owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+        entity_uuid: None,
+        decision_time: None,
+        entity_type_ids: HashSet::from([friend_of_entity_type_id()]),
+        properties: PropertyWithMetadataObject::from_parts(PropertyObject::empty(), None)
+            .expect("could not create property with metadata object"),
+        confidence: None,
+        link_data: Some(LinkData {
+            left_entity_id: left_entity.metadata.record_id.entity_id,
+            right_entity_id: right_entity.metadata.record_id.entity_id,
+            left_entity_confidence: None,
+            left_entity_provenance: PropertyProvenance::default(),
+            right_entity_confidence: None,
+            right_entity_provenance: PropertyProvenance::default(),
+        }),
+        draft: false,
+        relationships: [],
+        provenance: ProvidedEntityEditionProvenance::default(),
+    })
+    .await
+    .expect("could not create link")
+}
+
+#[tokio::test]
+async fn merge_entities() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let alice = create_person(&mut api, entity::PERSON_ALICE_V1).await;
+    let bob = create_person(&mut api, entity::PERSON_BOB_V1).await;
+    let charles = create_person(&mut api, entity::PERSON_CHARLES_V1).await;
+
+    let link = create_friend_of(&mut api, &charles, &bob).await;
+
+    let response = api
+        .merge_entities(api.account_id, MergeEntitiesParams {
+            surviving_entity_id: alice.metadata.record_id.entity_id,
+            merged_entity_ids: vec![bob.metadata.record_id.entity_id],
+            default_strategy: PropertyMergeStrategy::KeepSurvivor,
+            property_strategies: HashMap::new(),
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not merge entities");
+
+    assert_eq!(
+        response.entity.properties.properties(),
+        &HashMap::from([
+            (name_property_type_id(), Property::Value(json!("Alice"))),
+            (age_property_type_id(), Property::Value(json!(42))),
+        ])
+    );
+    assert_eq!(
+        response
+            .entity
+            .metadata
+            .provenance
+            .edition
+            .provided
+            .merged_entity_ids,
+        [bob.metadata.record_id.entity_id]
+    );
+    assert_eq!(response.relinked_entity_ids, [link
+        .metadata
+        .record_id
+        .entity_id]);
+    let [created_link_entity_id] = response.created_link_entity_ids[..] else {
+        panic!("expected exactly one created link entity");
+    };
+
+    let created_link = api
+        .get_entity_by_id(api.account_id, created_link_entity_id, None, None)
+        .await
+        .expect("could not get created link");
+    let link_data = created_link
+        .link_data
+        .expect("link entity should have link data");
+    assert_eq!(
+        link_data.left_entity_id,
+        charles.metadata.record_id.entity_id
+    );
+    assert_eq!(
+        link_data.right_entity_id,
+        alice.metadata.record_id.entity_id
+    );
+
+    // The original link entity is archived, but still points to the merged entity
+    let archived_link = api
+        .get_entity_by_id(
+            api.account_id,
+            link.metadata.record_id.entity_id,
+            None,
+            None,
+        )
+        .await
+        .expect("could not get link");
+    assert!(archived_link.metadata.archived);
+    let link_data = archived_link
+        .link_data
+        .expect("link entity should have link data");
+    assert_eq!(link_data.right_entity_id, bob.metadata.record_id.entity_id);
+
+    // Reading the link at a transaction time before the merge is not affected by the merge
+    let ClosedTemporalBound::Inclusive(link_transaction_time) =
+        link.metadata.temporal_versioning.transaction_time.start();
+    let past_link = api
+        .get_entity_by_id(
+            api.account_id,
+            link.metadata.record_id.entity_id,
+            Some(*link_transaction_time),
+            None,
+        )
+        .await
+        .expect("could not get link");
+    assert!(!past_link.metadata.archived);
+    assert_eq!(
+        past_link.metadata.record_id.edition_id,
+        link.metadata.record_id.edition_id
+    );
+    let link_data = past_link
+        .link_data
+        .expect("link entity should have link data");
+    assert_eq!(
+        link_data.left_entity_id,
+        charles.metadata.record_id.entity_id
+    );
+    assert_eq!(link_data.right_entity_id, bob.metadata.record_id.entity_id);
+
+    let bob = api
+        .get_entity_by_id(api.account_id, bob.metadata.record_id.entity_id, None, None)
+        .await
+        .expect("could not get entity");
+    assert!(bob.metadata.archived);
+}
+
+#[tokio::test]
+async fn merge_entities_with_property_strategy() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let alice = create_person(&mut api, entity::PERSON_ALICE_V1).await;
+    let bob = create_person(&mut api, entity::PERSON_BOB_V1).await;
+
+    let response = api
+        .merge_entities(api.account_id, MergeEntitiesParams {
+            surviving_entity_id: alice.metadata.record_id.entity_id,
+            merged_entity_ids: vec![bob.metadata.record_id.entity_id],
+            default_strategy: PropertyMergeStrategy::KeepSurvivor,
+            property_strategies: HashMap::from([(
+                name_property_type_id(),
+                PropertyMergeStrategy::PreferMerged,
+            )]),
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not merge entities");
+
+    assert_eq!(
+        response.entity.properties.properties(),
+        &HashMap::from([
+            (name_property_type_id(), Property::Value(json!("Bob"))),
+            (age_property_type_id(), Property::Value(json!(42))),
+        ])
+    );
+    assert!(response.relinked_entity_ids.is_empty());
+    assert!(response.created_link_entity_ids.is_empty());
+
+    api.merge_entities(api.account_id, MergeEntitiesParams {
+        surviving_entity_id: alice.metadata.record_id.entity_id,
+        merged_entity_ids: vec![alice.metadata.record_id.entity_id],
+        default_strategy: PropertyMergeStrategy::default(),
+        property_strategies: HashMap::new(),
+        provenance: ProvidedEntityEditionProvenance::default(),
+    })
+    .await
+    .expect_err("an entity should not be merged into itself");
+}
+
+#[tokio::test]
+async fn failed_merge_is_rolled_back() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let alice = create_person(&mut api, entity::PERSON_ALICE_V1).await;
+    let bob = create_person(&mut api, entity::PERSON_BOB_V1).await;
+    let charles = create_person(&mut api, entity::PERSON_CHARLES_V1).await;
+    let link = create_friend_of(&mut api, &charles, &bob).await;
+
+    // Bob is listed twice, so archiving him the second time fails because of the outdated edition.
+    // By then the surviving entity is updated and the link entity is already replaced.
+    api.merge_entities(api.account_id, MergeEntitiesParams {
+        surviving_entity_id: alice.metadata.record_id.entity_id,
+        merged_entity_ids: vec![
+            bob.metadata.record_id.entity_id,
+            bob.metadata.record_id.entity_id,
+        ],
+        default_strategy: PropertyMergeStrategy::KeepSurvivor,
+        property_strategies: HashMap::new(),
+        provenance: ProvidedEntityEditionProvenance::default(),
+    })
+    .await
+    .expect_err("an entity should not be archived twice in a merge");
+
+    for entity in [&alice, &bob, &link] {
+        let current = api
+            .get_entity_by_id(
+                api.account_id,
+                entity.metadata.record_id.entity_id,
+                None,
+                None,
+            )
+            .await
+            .expect("could not get entity");
+        assert!(!current.metadata.archived);
+        assert_eq!(
+            current.metadata.record_id.edition_id,
+            entity.metadata.record_id.edition_id
+        );
+    }
+
+    let num_links_to_alice = api
+        .count_entities(api.account_id, CountEntitiesParams {
+            filter: Filter::Equal(
+                Some(FilterExpression::Path {
+                    path: EntityQueryPath::EntityEdge {
+                        edge_kind: KnowledgeGraphEdgeKind::HasRightEntity,
+                        path: Box::new(EntityQueryPath::Uuid),
+                        direction: EdgeDirection::Outgoing,
+                    },
+                }),
+                Some(FilterExpression::Parameter {
+                    parameter: Parameter::Uuid(
+                        alice.metadata.record_id.entity_id.entity_uuid.into_uuid(),
+                    ),
+                    convert: None,
+                }),
+            ),
+            temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                pinned: PinnedTemporalAxisUnresolved::new(None),
+                variable: VariableTemporalAxisUnresolved::new(None, None),
+            },
+            include_drafts: false,
+        })
+        .await
+        .expect("could not count entities");
+    assert_eq!(num_links_to_alice, 0);
+}
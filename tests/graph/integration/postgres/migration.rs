@@ -0,0 +1,172 @@
+use std::collections::{HashMap, HashSet};
+
+use hash_graph_store::{
+    entity::{CreateEntityParams, EntityStore as _, MigrateEntityTypeParams},
+    entity_type::{CreateEntityTypeParams, EntityTypeStore as _, UpdateEntityTypesParams},
+    query::ConflictBehavior,
+};
+use hash_graph_test_data::{data_type, entity, entity_type, property_type};
+use hash_graph_types::{
+    knowledge::{
+        entity::ProvidedEntityEditionProvenance,
+        property::{PropertyObject, PropertyWithMetadataObject},
+    },
+    ontology::{OntologyTypeClassificationMetadata, ProvidedOntologyEditionProvenance},
+    owned_by_id::OwnedById,
+};
+use pretty_assertions::assert_eq;
+use type_system::{schema::EntityType, url::BaseUrl};
+
+use crate::{DatabaseTestWrapper, entity_type_relationships};
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn migrate_entity_type() {
+    let page_et_v1: EntityType = serde_json::from_str(entity_type::PAGE_V1)
+        .expect("could not parse entity type representation");
+    let page_et_v2: EntityType = serde_json::from_str(entity_type::PAGE_V2)
+        .expect("could not parse entity type representation");
+    let page: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::TEXT_V1,
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::WRITTEN_BY_V1,
+                entity_type::link::CONTAINS_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+                entity_type::BLOCK_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    api.create_entity_type(api.account_id, CreateEntityTypeParams {
+        schema: page_et_v1.clone(),
+        classification: OntologyTypeClassificationMetadata::Owned {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+        },
+        relationships: entity_type_relationships(),
+        conflict_behavior: ConflictBehavior::Fail,
+        provenance: ProvidedOntologyEditionProvenance::default(),
+    })
+    .await
+    .expect("could not create entity type");
+
+    let entity = api
+        .create_entity(api.account_id, CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([page_et_v1.id.clone()]),
+            properties: PropertyWithMetadataObject::from_parts(page.clone(), None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not create entity");
+    let entity_id = entity.metadata.record_id.entity_id;
+
+    api.update_entity_type(api.account_id, UpdateEntityTypesParams {
+        schema: page_et_v2.clone(),
+        relationships: entity_type_relationships(),
+        provenance: ProvidedOntologyEditionProvenance::default(),
+    })
+    .await
+    .expect("could not update entity type");
+
+    let invalid_migration = api
+        .migrate_entity_type(api.account_id, MigrateEntityTypeParams {
+            old_entity_type_id: page_et_v1.id.clone(),
+            new_entity_type_id: page_et_v2.id.clone(),
+            property_mapping: HashMap::from([(
+                BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/property-type/text/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/property-type/name/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+            )]),
+            removed_properties: HashSet::new(),
+            dry_run: false,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not check migration");
+    assert_eq!(invalid_migration.entity_count, 1);
+    assert!(invalid_migration.reports.contains_key(&entity_id));
+    assert!(invalid_migration.migrated_entities.is_empty());
+
+    let dry_run = api
+        .migrate_entity_type(api.account_id, MigrateEntityTypeParams {
+            old_entity_type_id: page_et_v1.id.clone(),
+            new_entity_type_id: page_et_v2.id.clone(),
+            property_mapping: HashMap::new(),
+            removed_properties: HashSet::new(),
+            dry_run: true,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not check migration");
+    assert_eq!(dry_run.entity_count, 1);
+    assert!(dry_run.reports.is_empty());
+    assert!(dry_run.migrated_entities.is_empty());
+
+    let migration = api
+        .migrate_entity_type(api.account_id, MigrateEntityTypeParams {
+            old_entity_type_id: page_et_v1.id.clone(),
+            new_entity_type_id: page_et_v2.id.clone(),
+            property_mapping: HashMap::new(),
+            removed_properties: HashSet::new(),
+            dry_run: false,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not migrate entities");
+    assert!(migration.reports.is_empty());
+    assert_eq!(migration.migrated_entities.len(), 1);
+    let migrated_entity = &migration.migrated_entities[0];
+    assert_eq!(migrated_entity.metadata.record_id.entity_id, entity_id);
+    assert_eq!(
+        migrated_entity.metadata.entity_type_ids,
+        HashSet::from([page_et_v2.id.clone()])
+    );
+    assert_eq!(migrated_entity.properties, page);
+
+    let repeated_migration = api
+        .migrate_entity_type(api.account_id, MigrateEntityTypeParams {
+            old_entity_type_id: page_et_v1.id,
+            new_entity_type_id: page_et_v2.id,
+            property_mapping: HashMap::new(),
+            removed_properties: HashSet::new(),
+            dry_run: false,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not migrate entities");
+    assert_eq!(repeated_migration.entity_count, 0);
+}
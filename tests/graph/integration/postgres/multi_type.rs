@@ -158,12 +158,14 @@ async fn initial_person() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             decision_time: None,
             entity_type_ids: HashSet::from([person_entity_type_id(), org_entity_type_id()]),
             properties: vec![],
             draft: None,
             archived: None,
             confidence: None,
+            link_data: None,
             provenance: ProvidedEntityEditionProvenance::default(),
         })
         .await
@@ -332,12 +334,14 @@ async fn create_multi() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             decision_time: None,
             entity_type_ids: HashSet::from([person_entity_type_id()]),
             properties: vec![],
             draft: None,
             archived: None,
             confidence: None,
+            link_data: None,
             provenance: ProvidedEntityEditionProvenance::default(),
         })
         .await
@@ -7,6 +7,7 @@ use hash_graph_store::{
         CreateEntityParams, EntityQuerySorting, EntityStore as _, GetEntitiesParams,
         PatchEntityParams,
     },
+    error::EntityEditionMismatch,
     filter::Filter,
     subgraph::temporal_axes::{
         PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableTemporalAxisUnresolved,
@@ -17,9 +18,9 @@ use hash_graph_types::{
     knowledge::{
         entity::ProvidedEntityEditionProvenance,
         property::{
-            PropertyObject, PropertyPatchOperation, PropertyPathElement, PropertyProvenance,
-            PropertyWithMetadata, PropertyWithMetadataObject, PropertyWithMetadataValue,
-            ValueMetadata,
+            PatchTestFailed, Property, PropertyObject, PropertyPatchOperation, PropertyPathElement,
+            PropertyProvenance, PropertyWithMetadata, PropertyWithMetadataObject,
+            PropertyWithMetadataValue, ValueMetadata,
         },
     },
     owned_by_id::OwnedById,
@@ -108,6 +109,7 @@ async fn properties_add() {
 
     api.patch_entity(api.account_id, PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: HashSet::new(),
         properties: vec![
@@ -141,6 +143,7 @@ async fn properties_add() {
         draft: None,
         archived: None,
         confidence: None,
+        link_data: None,
         provenance: ProvidedEntityEditionProvenance::default(),
     })
     .await
@@ -204,6 +207,7 @@ async fn properties_remove() {
 
     api.patch_entity(api.account_id, PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: HashSet::new(),
         properties: vec![PropertyPatchOperation::Remove {
@@ -212,6 +216,7 @@ async fn properties_remove() {
         draft: None,
         archived: None,
         confidence: None,
+        link_data: None,
         provenance: ProvidedEntityEditionProvenance::default(),
     })
     .await
@@ -273,6 +278,7 @@ async fn properties_replace() {
 
     api.patch_entity(api.account_id, PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: HashSet::new(),
         properties: vec![PropertyPatchOperation::Replace {
@@ -291,6 +297,7 @@ async fn properties_replace() {
         draft: None,
         archived: None,
         confidence: None,
+        link_data: None,
         provenance: ProvidedEntityEditionProvenance::default(),
     })
     .await
@@ -328,6 +335,136 @@ async fn properties_replace() {
     assert_eq!(properties[&name_property_type_id()], json!("Bob"));
 }
 
+#[tokio::test]
+async fn properties_test() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let entity = api
+        .create_entity(api.account_id, CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([person_entity_type_id()]),
+            properties: PropertyWithMetadataObject::from_parts(alice(), None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not create entity");
+    let entity_id = entity.metadata.record_id.entity_id;
+
+    let rename = |expected_name: &str| PatchEntityParams {
+        entity_id,
+        expected_edition_id: None,
+        decision_time: None,
+        entity_type_ids: HashSet::new(),
+        properties: vec![
+            PropertyPatchOperation::Test {
+                path: once(PropertyPathElement::from(name_property_type_id())).collect(),
+                value: Property::Value(json!(expected_name)),
+                metadata: None,
+                confidence: None,
+            },
+            PropertyPatchOperation::Replace {
+                path: once(PropertyPathElement::from(name_property_type_id())).collect(),
+                property: PropertyWithMetadata::Value(PropertyWithMetadataValue {
+                    value: json!("Bob"),
+                    metadata: ValueMetadata {
+                        confidence: None,
+                        data_type_id: None,
+                        original_data_type_id: None,
+                        provenance: PropertyProvenance::default(),
+                        canonical: HashMap::default(),
+                    },
+                }),
+            },
+        ],
+        draft: None,
+        archived: None,
+        confidence: None,
+        link_data: None,
+        provenance: ProvidedEntityEditionProvenance::default(),
+    };
+
+    let report = api
+        .patch_entity(api.account_id, rename("Bob"))
+        .await
+        .expect_err("test operation should fail");
+    assert!(
+        report.contains::<PatchTestFailed>(),
+        "unexpected error: {report:?}"
+    );
+
+    let entity = api
+        .patch_entity(api.account_id, rename("Alice"))
+        .await
+        .expect("could not patch entity");
+
+    let properties = entity.properties.properties();
+    assert_eq!(properties.len(), 1);
+    assert_eq!(properties[&name_property_type_id()], json!("Bob"));
+}
+
+#[tokio::test]
+async fn expected_edition_id() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let entity = api
+        .create_entity(api.account_id, CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([person_entity_type_id()]),
+            properties: PropertyWithMetadataObject::from_parts(alice(), None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not create entity");
+    let entity_id = entity.metadata.record_id.entity_id;
+    let first_edition_id = entity.metadata.record_id.edition_id;
+
+    let remove_name = |expected_edition_id| PatchEntityParams {
+        entity_id,
+        expected_edition_id: Some(expected_edition_id),
+        decision_time: None,
+        entity_type_ids: HashSet::new(),
+        properties: vec![PropertyPatchOperation::Remove {
+            path: once(PropertyPathElement::from(name_property_type_id())).collect(),
+        }],
+        draft: None,
+        archived: None,
+        confidence: None,
+        link_data: None,
+        provenance: ProvidedEntityEditionProvenance::default(),
+    };
+
+    let entity = api
+        .patch_entity(api.account_id, remove_name(first_edition_id))
+        .await
+        .expect("could not patch entity");
+    assert_ne!(entity.metadata.record_id.edition_id, first_edition_id);
+
+    let report = api
+        .patch_entity(api.account_id, remove_name(first_edition_id))
+        .await
+        .expect_err("patch based on an outdated edition should fail");
+    assert!(
+        report.contains::<EntityEditionMismatch>(),
+        "unexpected error: {report:?}"
+    );
+}
+
 #[tokio::test]
 #[expect(clippy::too_many_lines)]
 async fn type_ids() {
@@ -354,12 +491,14 @@ async fn type_ids() {
 
     api.patch_entity(api.account_id, PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: HashSet::new(),
         properties: vec![],
         draft: None,
         archived: None,
         confidence: None,
+        link_data: None,
         provenance: ProvidedEntityEditionProvenance::default(),
     })
     .await
@@ -401,12 +540,14 @@ async fn type_ids() {
 
     api.patch_entity(api.account_id, PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: HashSet::from([person_entity_type_id(), org_entity_type_id()]),
         properties: vec![],
         draft: None,
         archived: None,
         confidence: None,
+        link_data: None,
         provenance: ProvidedEntityEditionProvenance::default(),
     })
     .await
@@ -450,12 +591,14 @@ async fn type_ids() {
 
     api.patch_entity(api.account_id, PatchEntityParams {
         entity_id,
+        expected_edition_id: None,
         decision_time: None,
         entity_type_ids: HashSet::from([person_entity_type_id()]),
         properties: vec![],
         draft: None,
         archived: None,
         confidence: None,
+        link_data: None,
         provenance: ProvidedEntityEditionProvenance::default(),
     })
     .await
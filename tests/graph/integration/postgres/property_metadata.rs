@@ -207,6 +207,7 @@ async fn initial_metadata() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: vec![PropertyPatchOperation::Replace {
                 path: once(PropertyPathElement::Property(Cow::Owned(
                     name_property_type_id(),
@@ -228,6 +229,7 @@ async fn initial_metadata() {
             draft: None,
             decision_time: None,
             confidence: Confidence::new(0.5),
+            link_data: None,
             provenance: edition_provenance(),
         })
         .await
@@ -252,12 +254,14 @@ async fn initial_metadata() {
     let new_updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: Vec::new(),
             entity_type_ids: HashSet::new(),
             archived: None,
             draft: None,
             decision_time: None,
             confidence: None,
+            link_data: None,
             provenance: edition_provenance(),
         })
         .await
@@ -310,12 +314,14 @@ async fn no_initial_metadata() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: Vec::new(),
             entity_type_ids: HashSet::new(),
             archived: None,
             draft: None,
             decision_time: None,
             confidence: None,
+            link_data: None,
             provenance: ProvidedEntityEditionProvenance::default(),
         })
         .await
@@ -326,12 +332,14 @@ async fn no_initial_metadata() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: Vec::new(),
             entity_type_ids: HashSet::new(),
             archived: None,
             draft: None,
             decision_time: None,
             confidence: Confidence::new(0.5),
+            link_data: None,
             provenance: ProvidedEntityEditionProvenance::default(),
         })
         .await
@@ -354,6 +362,7 @@ async fn no_initial_metadata() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: vec![PropertyPatchOperation::Replace {
                 path: once(PropertyPathElement::from(name_property_type_id())).collect(),
                 property: PropertyWithMetadata::Value(PropertyWithMetadataValue {
@@ -372,6 +381,7 @@ async fn no_initial_metadata() {
             draft: None,
             decision_time: None,
             confidence: None,
+            link_data: None,
             provenance: ProvidedEntityEditionProvenance::default(),
         })
         .await
@@ -394,12 +404,14 @@ async fn no_initial_metadata() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id: entity.metadata.record_id.entity_id,
+            expected_edition_id: None,
             properties: Vec::new(),
             entity_type_ids: HashSet::new(),
             archived: None,
             draft: None,
             decision_time: None,
             confidence: Confidence::new(0.5),
+            link_data: None,
             provenance: edition_provenance(),
         })
         .await
@@ -451,6 +463,7 @@ async fn properties_add() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id,
+            expected_edition_id: None,
             decision_time: None,
             entity_type_ids: HashSet::new(),
             properties: vec![PropertyPatchOperation::Add {
@@ -469,6 +482,7 @@ async fn properties_add() {
             draft: None,
             archived: None,
             confidence: None,
+            link_data: None,
             provenance: ProvidedEntityEditionProvenance::default(),
         })
         .await
@@ -535,6 +549,7 @@ async fn properties_remove() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id,
+            expected_edition_id: None,
             decision_time: None,
             entity_type_ids: HashSet::new(),
             properties: vec![
@@ -565,6 +580,7 @@ async fn properties_remove() {
             draft: None,
             archived: None,
             confidence: None,
+            link_data: None,
             provenance: ProvidedEntityEditionProvenance::default(),
         })
         .await
@@ -606,6 +622,7 @@ async fn properties_remove() {
     let updated_entity = api
         .patch_entity(api.account_id, PatchEntityParams {
             entity_id,
+            expected_edition_id: None,
             decision_time: None,
             entity_type_ids: HashSet::new(),
             properties: vec![PropertyPatchOperation::Remove {
@@ -614,6 +631,7 @@ async fn properties_remove() {
             draft: None,
             archived: None,
             confidence: None,
+            link_data: None,
             provenance: ProvidedEntityEditionProvenance::default(),
         })
         .await
@@ -0,0 +1,300 @@
+use core::{iter::once, str::FromStr as _};
+use std::collections::{HashMap, HashSet};
+
+use hash_graph_authorization::AuthorizationApi;
+use hash_graph_store::{
+    entity::{
+        CreateEntityParams, EntityRevertTarget, EntityStore as _, PatchEntityParams,
+        RevertEntityParams,
+    },
+    error::EntityRevertTargetNotFound,
+};
+use hash_graph_temporal_versioning::ClosedTemporalBound;
+use hash_graph_test_data::{data_type, entity, entity_type, property_type};
+use hash_graph_types::{
+    knowledge::{
+        Confidence,
+        entity::{EntityEditionId, ProvidedEntityEditionProvenance},
+        link::LinkData,
+        property::{
+            PropertyObject, PropertyPatchOperation, PropertyPathElement, PropertyProvenance,
+            PropertyWithMetadata, PropertyWithMetadataObject, PropertyWithMetadataValue,
+            ValueMetadata,
+        },
+    },
+    owned_by_id::OwnedById,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use type_system::url::{BaseUrl, VersionedUrl};
+use uuid::Uuid;
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+async fn seed<A: AuthorizationApi>(
+    database: &mut DatabaseTestWrapper<A>,
+) -> DatabaseApi<'_, &mut A> {
+    database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::PERSON_V1,
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database")
+}
+
+fn person_entity_type_id() -> VersionedUrl {
+    VersionedUrl::from_str("https://blockprotocol.org/@alice/types/entity-type/person/v/1")
+        .expect("couldn't construct entity type id")
+}
+
+fn friend_of_entity_type_id() -> VersionedUrl {
+    VersionedUrl::from_str("https://blockprotocol.org/@alice/types/entity-type/friend-of/v/1")
+        .expect("couldn't construct entity type id")
+}
+
+fn age_property_type_id() -> BaseUrl {
+    BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/age/".to_owned())
+        .expect("couldn't construct Base URL")
+}
+
+fn alice() -> PropertyObject {
+    serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity")
+}
+
+fn bob() -> PropertyObject {
+    serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity")
+}
+
+#[tokio::test]
+async fn revert_entity() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let created_entity = api
+        .create_entity(api.account_id, CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([person_entity_type_id()]),
+            properties: PropertyWithMetadataObject::from_parts(alice(), None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not create entity");
+    let entity_id = created_entity.metadata.record_id.entity_id;
+
+    let patched_entity = api
+        .patch_entity(api.account_id, PatchEntityParams {
+            entity_id,
+            expected_edition_id: None,
+            decision_time: None,
+            entity_type_ids: HashSet::new(),
+            properties: vec![PropertyPatchOperation::Add {
+                path: once(PropertyPathElement::from(age_property_type_id())).collect(),
+                property: PropertyWithMetadata::Value(PropertyWithMetadataValue {
+                    value: json!(30),
+                    metadata: ValueMetadata {
+                        confidence: None,
+                        data_type_id: None,
+                        original_data_type_id: None,
+                        provenance: PropertyProvenance::default(),
+                        canonical: HashMap::default(),
+                    },
+                }),
+            }],
+            draft: None,
+            archived: None,
+            confidence: None,
+            link_data: None,
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not patch entity");
+
+    let reverted_entity = api
+        .revert_entity(api.account_id, RevertEntityParams {
+            entity_id,
+            target: EntityRevertTarget::EditionId(created_entity.metadata.record_id.edition_id),
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not revert entity");
+    assert_eq!(reverted_entity.properties, created_entity.properties);
+    assert_eq!(
+        reverted_entity.metadata.properties,
+        created_entity.metadata.properties
+    );
+    assert_ne!(
+        reverted_entity.metadata.record_id.edition_id,
+        created_entity.metadata.record_id.edition_id
+    );
+    assert_ne!(
+        reverted_entity.metadata.record_id.edition_id,
+        patched_entity.metadata.record_id.edition_id
+    );
+
+    let ClosedTemporalBound::Inclusive(patched_at) = *patched_entity
+        .metadata
+        .temporal_versioning
+        .transaction_time
+        .start();
+    let restored_entity = api
+        .revert_entity(api.account_id, RevertEntityParams {
+            entity_id,
+            target: EntityRevertTarget::TransactionTime(patched_at),
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not revert entity");
+    assert_eq!(restored_entity.properties, patched_entity.properties);
+    assert_ne!(
+        restored_entity.metadata.record_id.edition_id,
+        reverted_entity.metadata.record_id.edition_id
+    );
+}
+
+#[tokio::test]
+async fn revert_to_unknown_edition() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let entity = api
+        .create_entity(api.account_id, CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([person_entity_type_id()]),
+            properties: PropertyWithMetadataObject::from_parts(alice(), None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not create entity");
+
+    let report = api
+        .revert_entity(api.account_id, RevertEntityParams {
+            entity_id: entity.metadata.record_id.entity_id,
+            target: EntityRevertTarget::EditionId(EntityEditionId::new(Uuid::new_v4())),
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect_err("reverting to an unknown edition should fail");
+    assert!(report.contains::<EntityRevertTargetNotFound>());
+}
+
+#[tokio::test]
+async fn revert_link_entity() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let mut person_ids = Vec::new();
+    for properties in [alice(), bob()] {
+        let person = api
+            .create_entity(api.account_id, CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: HashSet::from([person_entity_type_id()]),
+                properties: PropertyWithMetadataObject::from_parts(properties, None)
+                    .expect("could not create property with metadata object"),
+                confidence: None,
+                link_data: None,
+                draft: false,
+                relationships: [],
+                provenance: ProvidedEntityEditionProvenance::default(),
+            })
+            .await
+            .expect("could not create entity");
+        person_ids.push(person.metadata.record_id.entity_id);
+    }
+
+    let link_data = LinkData {
+        left_entity_id: person_ids[0],
+        right_entity_id: person_ids[1],
+        left_entity_confidence: Confidence::new(0.5),
+        left_entity_provenance: PropertyProvenance::default(),
+        right_entity_confidence: None,
+        right_entity_provenance: PropertyProvenance::default(),
+    };
+    let created_link = api
+        .create_entity(api.account_id, CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([friend_of_entity_type_id()]),
+            properties: PropertyWithMetadataObject::from_parts(PropertyObject::empty(), None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: Some(link_data.clone()),
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not create link entity");
+    let entity_id = created_link.metadata.record_id.entity_id;
+
+    let patched_link = api
+        .patch_entity(api.account_id, PatchEntityParams {
+            entity_id,
+            expected_edition_id: None,
+            decision_time: None,
+            entity_type_ids: HashSet::new(),
+            properties: Vec::new(),
+            draft: None,
+            archived: None,
+            confidence: None,
+            link_data: Some(LinkData {
+                left_entity_confidence: Confidence::new(0.9),
+                ..link_data.clone()
+            }),
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not patch link entity");
+    assert_eq!(
+        patched_link
+            .link_data
+            .as_ref()
+            .expect("link entity should have link data")
+            .left_entity_confidence,
+        Confidence::new(0.9)
+    );
+
+    let reverted_link = api
+        .revert_entity(api.account_id, RevertEntityParams {
+            entity_id,
+            target: EntityRevertTarget::EditionId(created_link.metadata.record_id.edition_id),
+            provenance: ProvidedEntityEditionProvenance::default(),
+        })
+        .await
+        .expect("could not revert link entity");
+    assert_eq!(reverted_link.link_data, Some(link_data));
+}
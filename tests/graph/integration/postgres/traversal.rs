@@ -0,0 +1,203 @@
+use std::collections::HashSet;
+
+use hash_graph_authorization::AuthorizationApi;
+use hash_graph_store::{
+    entity::{CreateEntityParams, EntityQuerySorting, EntityStore as _, GetEntitySubgraphParams},
+    filter::Filter,
+    subgraph::{
+        edges::{EdgeResolveDepths, GraphResolveDepths},
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
+            VariableTemporalAxisUnresolved,
+        },
+    },
+};
+use hash_graph_temporal_versioning::{ClosedTemporalBound, Timestamp, TransactionTime};
+use hash_graph_test_data::{data_type, entity, entity_type, property_type};
+use hash_graph_types::{
+    knowledge::{
+        entity::{Entity, EntityId, ProvidedEntityEditionProvenance},
+        link::LinkData,
+        property::{PropertyObject, PropertyProvenance, PropertyWithMetadataObject},
+    },
+    owned_by_id::OwnedById,
+};
+use pretty_assertions::assert_eq;
+use type_system::url::{BaseUrl, OntologyTypeVersion, VersionedUrl};
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+fn type_id(name: &str) -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(format!(
+            "https://blockprotocol.org/@alice/types/entity-type/{name}/"
+        ))
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+async fn create_entity<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    entity_type: &str,
+    properties: PropertyObject,
+    link_data: Option<LinkData>,
+) -> Entity {
+    api.create_entity(api.account_id, CreateEntityParams {
+        owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+        entity_uuid: None,
+        decision_time: None,
+        entity_type_ids: HashSet::from([type_id(entity_type)]),
+        properties: PropertyWithMetadataObject::from_parts(properties, None)
+            .expect("could not create property with metadata object"),
+        link_data,
+        draft: false,
+        relationships: [],
+        confidence: None,
+        provenance: ProvidedEntityEditionProvenance::default(),
+    })
+    .await
+    .expect("could not create entity")
+}
+
+async fn traverse<A: AuthorizationApi>(
+    api: &DatabaseApi<'_, A>,
+    entity_id: EntityId,
+    transaction_time: Option<Timestamp<TransactionTime>>,
+    graph_resolve_depths: GraphResolveDepths,
+) -> HashSet<EntityId> {
+    let response = api
+        .get_entity_subgraph(api.account_id, GetEntitySubgraphParams {
+            filter: Filter::for_entity_by_entity_id(entity_id),
+            temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                pinned: PinnedTemporalAxisUnresolved::new(transaction_time),
+                variable: VariableTemporalAxisUnresolved::new(None, None),
+            },
+            graph_resolve_depths,
+            sorting: EntityQuerySorting {
+                paths: Vec::new(),
+                cursor: None,
+            },
+            limit: None,
+            conversions: Vec::new(),
+            include_drafts: false,
+            include_count: false,
+            include_entity_types: None,
+            include_web_ids: false,
+            include_created_by_ids: false,
+            include_edition_created_by_ids: false,
+            include_type_ids: false,
+        })
+        .await
+        .expect("could not get entity subgraph");
+
+    assert_eq!(response.subgraph.roots.len(), 1);
+    response
+        .subgraph
+        .vertices
+        .entities
+        .into_keys()
+        .map(|vertex_id| vertex_id.base_id)
+        .collect()
+}
+
+#[tokio::test]
+async fn outgoing_links() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let alice = create_entity(
+        &mut api,
+        "person",
+        serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity"),
+        None,
+    )
+    .await;
+    let alice_id = alice.metadata.record_id.entity_id;
+    let bob = create_entity(
+        &mut api,
+        "person",
+        serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity"),
+        None,
+    )
+    .await;
+    let bob_id = bob.metadata.record_id.entity_id;
+    let friend_of = create_entity(
+        &mut api,
+        "friend-of",
+        PropertyObject::empty(),
+        Some(LinkData {
+            left_entity_id: alice_id,
+            right_entity_id: bob_id,
+            left_entity_confidence: None,
+            left_entity_provenance: PropertyProvenance::default(),
+            right_entity_confidence: None,
+            right_entity_provenance: PropertyProvenance::default(),
+        }),
+    )
+    .await;
+    let friend_of_id = friend_of.metadata.record_id.entity_id;
+
+    let outgoing_links = GraphResolveDepths {
+        has_left_entity: EdgeResolveDepths {
+            incoming: 1,
+            outgoing: 0,
+        },
+        has_right_entity: EdgeResolveDepths {
+            incoming: 0,
+            outgoing: 1,
+        },
+        ..GraphResolveDepths::default()
+    };
+
+    assert_eq!(
+        traverse(&api, alice_id, None, GraphResolveDepths::default()).await,
+        HashSet::from([alice_id])
+    );
+    assert_eq!(
+        traverse(&api, alice_id, None, outgoing_links).await,
+        HashSet::from([alice_id, friend_of_id, bob_id])
+    );
+    // Bob is only the target of the link, so the outgoing links of Bob don't include it
+    assert_eq!(
+        traverse(&api, bob_id, None, outgoing_links).await,
+        HashSet::from([bob_id])
+    );
+
+    // The link did not exist yet when Alice was created
+    let ClosedTemporalBound::Inclusive(alice_transaction_time) =
+        alice.metadata.temporal_versioning.transaction_time.start();
+    assert_eq!(
+        traverse(
+            &api,
+            alice_id,
+            Some(*alice_transaction_time),
+            outgoing_links
+        )
+        .await,
+        HashSet::from([alice_id])
+    );
+}